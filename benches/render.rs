@@ -0,0 +1,123 @@
+// benches/render.rs
+
+//! Baselines for the hot paths: drawing the module grid off-screen, reading a chart's worth of
+//! telemetry back (query plus decimation), and knowledge search. Run locally before and after a
+//! change that might slow them down:
+//!
+//! ```sh
+//! cargo bench --features test-util --bench render -- --save-baseline before
+//! # ...make the change...
+//! cargo bench --features test-util --bench render -- --baseline before
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ratatui::{buffer::Buffer, layout::Rect};
+use serde_json::json;
+
+use runtime_base_rust::module::strategies::llm::database::{KnowledgeChunk, LlmDatabase};
+use runtime_base_rust::module::strategies::monitoring::database::MonitoringDatabase;
+use runtime_base_rust::module::{Module, ModuleManager};
+use runtime_base_rust::ui::widgets::modules_list::widget::ModulesListWidget;
+use runtime_base_rust::util::test_util::{in_memory_database, TestModule};
+
+/// Module counts for the grid: one screen, a few screens, a very large dashboard
+const MODULE_COUNTS: [usize; 3] = [12, 48, 192];
+
+/// A 7" panel's worth of terminal
+const SCREEN: Rect = Rect { x: 0, y: 0, width: 120, height: 40 };
+
+/// The mix of cards a typical homestead dashboard shows
+fn dashboard_module(i: usize) -> Module {
+    let name = format!("module_{:03}", i);
+    match i % 3 {
+        0 => TestModule::new(&name, "monitoring", "gauge_card")
+            .binding("a", json!(40.0 + (i % 50) as f64))
+            .binding("max_value", json!(100.0))
+            .binding("display_name", json!(format!("Tank {}", i)))
+            .binding("unit_of_measure_label", json!("%")),
+        1 => TestModule::new(&name, "monitoring", "status_badge_card")
+            .binding("status", json!(if i % 2 == 0 { "online" } else { "offline" }))
+            .binding("display_name", json!(format!("Gateway {}", i))),
+        _ => TestModule::new(&name, "valve_control", "toggle_switch")
+            .binding("state", json!(i % 2 == 0))
+            .binding("display_name", json!(format!("Valve {}", i))),
+    }
+    .build()
+}
+
+fn bench_overview(c: &mut Criterion) {
+    let mut group = c.benchmark_group("overview_render");
+    let widget = ModulesListWidget::new();
+
+    for count in MODULE_COUNTS {
+        let mut manager = ModuleManager::new("./manifests/bench".into(), "wasteland".to_string());
+        (0..count).for_each(|i| manager.push_module(dashboard_module(i)));
+
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| {
+            b.iter(|| {
+                let mut buf = Buffer::empty(SCREEN);
+                let mut request_redraw = false;
+                widget.render(&mut manager, SCREEN, &mut buf, Some(true), &mut request_redraw);
+                black_box(buf);
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_chart_decimation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chart_decimation");
+    let database = in_memory_database();
+
+    // A day of samples every 10 seconds
+    let samples = 8_640;
+    for t in 0..samples {
+        let value = 60.0 + (t as f64 / 100.0).sin() * 15.0;
+        database.record_telemetry("bench_temp", value, value / 2.0, 1, (value, value / 2.0), t).unwrap();
+    }
+
+    group.throughput(Throughput::Elements(samples as u64));
+    for max_points in [60, 240] {
+        group.bench_with_input(BenchmarkId::from_parameter(max_points), &max_points, |b, &max_points| {
+            b.iter(|| black_box(database.get_telemetry_since("bench_temp", 0, max_points).unwrap()));
+        });
+    }
+    group.finish();
+}
+
+fn bench_knowledge_search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("knowledge_search");
+    let database = in_memory_database();
+
+    let topics = ["water filtration", "solar panel wiring", "seed saving", "first aid", "food preservation"];
+    for i in 0..2_000 {
+        let topic = topics[i % topics.len()];
+        database.insert_knowledge_chunk(KnowledgeChunk {
+            id: None,
+            source_file: format!("guides/{}.md", i / 20),
+            domain: "survival".to_string(),
+            category: topic.to_string(),
+            title: format!("{} part {}", topic, i),
+            body: format!(
+                "Notes on {} ({}). Check the gravity filter weekly, store seeds dry and cool, \
+                 and keep a charged battery bank for the pump.",
+                topic, i
+            ),
+            chunk_index: (i % 20) as i32,
+            metadata: "{}".to_string(),
+            snippet: None,
+            score: None,
+        }).unwrap();
+    }
+
+    for query in ["filter", "solar battery pump", "nonexistent"] {
+        group.bench_with_input(BenchmarkId::from_parameter(query), &query, |b, &query| {
+            b.iter(|| black_box(database.search_knowledge(query, &[], 10).unwrap()));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_overview, bench_chart_decimation, bench_knowledge_search);
+criterion_main!(benches);
@@ -1,3 +1,29 @@
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Bake the git commit and build time into the binary for `--version` and the about overlay
 fn main() {
-    println!("Placeholder for nuanced build stuff has been called.");
+    let git_hash = Command::new("git")
+        .args(["rev-parse", "--short=10", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|hash| hash.trim().to_string())
+        .filter(|hash| !hash.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    // Reproducible builds pin the timestamp
+    let build_timestamp = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse::<u64>().ok())
+        .unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0));
+
+    println!("cargo:rustc-env=SURVON_GIT_HASH={}", git_hash);
+    println!("cargo:rustc-env=SURVON_BUILD_TIMESTAMP={}", build_timestamp);
+
+    // A new commit or checkout changes the hash
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs/heads");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
 }
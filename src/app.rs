@@ -3,8 +3,8 @@ use ratatui::{
     DefaultTerminal,
 };
 use color_eyre::Result;
-use std::path::{Path, PathBuf};
-use tokio::time::{Duration, Instant};
+use std::path::Path;
+use tokio::time::Instant;
 use std::sync::Arc;
 use gag::Gag;
 use std::collections::HashMap;
@@ -17,10 +17,9 @@ use crate::util::{
         ble_scheduler::{CommandPriority, QueuedCommand},
         bus::{BusMessage, BusReceiver, MessageBus},
         discovery::DiscoveryManager,
-        event::{AppEvent, Event, EventHandler},
+        event::{tick_interval, AppEvent, Event, EventHandler},
         transport::TransportManager,
     },
-    knowledge::KnowledgeIngester
 };
 
 use crate::module::{
@@ -30,26 +29,38 @@ use crate::module::{
 };
 
 use crate::ui::widgets::{
-    jukebox::{
-        actor::JukeboxActor,
-        ingester::JukeboxIngester,
-        widget::JukeboxWidget,
-    },
-    messages_window::{
-        actor::MessagesActor,
-        widget::MessagesWidget,
-    },
+    jukebox::widget::JukeboxWidget,
+    messages_window::widget::MessagesWidget,
     module_detail::widget::ModuleDetailWidget,
     modules_list::widget::ModulesListWidget,
 };
 
 use crate::ui::{
+    about::render_about,
+    audit_log::{AuditLogOverlay, AUDIT_LOG_LIMIT},
+    bus_inspector::BusInspector,
+    bus_stats::BusStatsOverlay,
+    frame_pacer::FramePacer,
+    diagnostics::DiagnosticsOverlay,
     document::manager::DocumentManager,
-    screens::splash::SplashScreen,
-    style::AdaptiveColors
+    key_help::render_key_help,
+    safe_mode_banner::render_safe_mode_banner,
+    screen_snapshot::ScreenSnapshot,
+    toasts::render_toasts,
+    screens::{loading::LoadingScreen, splash::SplashScreen},
+    style::AdaptiveColors,
+    ui_state::UiState,
 };
 
-use crate::{log_debug, log_error, log_info};
+use crate::{log_debug, log_error, log_info, log_warn};
+use crate::runtime::{
+    audit::{self, AuditOutcome, AuditTrigger, CommandOrigin},
+    diagnostics::{self, DiagnosticInputs},
+    safe_mode::{is_safe_mode, safe_mode_message, set_safe_mode},
+    toast::{active_toasts, notify, ToastLevel},
+    undo::{self, UndoDirection},
+    PendingHandlerInit, RuntimeBuilder, SharedObserver,
+};
 use crate::module::strategies::llm::{
     database::ChatMessage,
     handler::LlmHandler
@@ -73,6 +84,8 @@ pub enum OverviewFocus {
 #[derive(Debug, PartialEq)]
 pub enum AppMode {
     Splash,
+    /// Module handlers registering after the splash, with progress on screen
+    Loading,
     Overview,
     ModuleDetail(ModuleSource, usize),
 }
@@ -87,6 +100,10 @@ pub struct App {
 
     pub needs_redraw: bool,
     pub splash_screen: Option<SplashScreen>,
+    /// Handler registration progress, while it runs
+    pub loading_screen: Option<LoadingScreen>,
+    /// Handler registration `build` left for after the splash
+    pub pending_handler_init: Option<PendingHandlerInit>,
     pub image_cache: ImageCache,
     pub start_time: Instant,
     pub palette: AdaptiveColors,
@@ -112,194 +129,33 @@ pub struct App {
 
     pub document_manager: DocumentManager,
     pub overview_focus: OverviewFocus,
+    /// Whether the `?` key help overlay is open
+    pub show_key_help: bool,
+    /// Whether the Ctrl+V about overlay is open
+    pub show_about: bool,
+    /// Per-topic bus rates, while the Ctrl+B overlay is open
+    pub bus_stats: Option<BusStatsOverlay>,
+    /// Ctrl+T live message stream; owns the keyboard while open
+    pub bus_inspector: Option<BusInspector>,
+    /// Ctrl+A audit log browser; owns the keyboard while open
+    pub audit_log: Option<AuditLogOverlay>,
+    /// Ctrl+D self-check results; owns the keyboard while open
+    pub diagnostics: Option<DiagnosticsOverlay>,
+    /// Persisted dashboard preferences (density, ...)
+    pub ui_state: UiState,
+    /// Second since start when the cards' "updated Xs ago" labels were last redrawn
+    pub age_labels_second: u64,
     pub transport_manager: Option<TransportManager>,
     pub discovery_manager: Option<Arc<DiscoveryManager>>,
+    /// Lifecycle observers registered by an embedder
+    pub observers: Vec<SharedObserver>,
 }
 
 impl App {
-    /// Constructs a new instance of [`App`].
+    /// Constructs a new instance of [`App`] with the standard components.
+    /// Use [`RuntimeBuilder`] to supply your own.
     pub async fn new() -> Result<Self> {
-
-        let mut image_cache = ImageCache::new();
-
-        if let Err(e) = image_cache.load_overview_header("assets/images/homestead-scene-3-wide.png") {
-            log_error!("Failed to load overview header image: {}", e);
-        }
-
-        let core_manifests_path = PathBuf::from("./manifests/core/");
-        let core_modules_namespace= "core".to_string();
-        let mut core_module_manager = ModuleManager::new(core_manifests_path, core_modules_namespace);
-
-        let wasteland_manifests_path = PathBuf::from("./manifests/wasteland/");
-        let wasteland_modules_namespace= "wasteland".to_string();
-        let mut wasteland_module_manager = ModuleManager::new(wasteland_manifests_path, wasteland_modules_namespace);
-
-        let (message_bus, bus_receiver) = MessageBus::new();
-        let database = Database::new_implied_all_schemas()?;
-
-        // Discover module manifests on startup
-        if let Err(e) = wasteland_module_manager.discover_modules() {
-            panic!("Failed to discover wasteland modules: {}", e);
-        }
-        if let Err(e) = core_module_manager.discover_modules() {
-            panic!("Failed to discover core modules: {}", e);
-        }
-
-        // Initialize DiscoveryManager for BLE field units
-        let wasteland_manifests_path = PathBuf::from("./manifests/wasteland/");
-        let discovery_manager = Arc::new(DiscoveryManager::new(
-            message_bus.clone(),
-            wasteland_manifests_path.clone(),
-            database.clone(),
-        ));
-
-        // Start discovery in background
-        let discovery_clone = discovery_manager.clone();
-        let discovery_clone_b = discovery_manager.clone();
-        tokio::spawn(async move {
-            // 1. Initialize BLE adapter
-            if let Err(e) = discovery_clone.start().await {
-                log_error!("Discovery manager failed to start: {}", e);
-                return;
-            }
-
-            // 2. Wait for stabilization
-            log_info!("🔌 BLE adapter initializing...");
-            tokio::time::sleep(Duration::from_secs(2)).await;
-
-            // 3. Auto-connect to trusted devices
-            log_info!("🔍 Connecting to trusted devices...");
-            match discovery_clone_b.connect_trusted_devices().await {
-                Ok(_) => log_info!("✅ Trusted device auto-connect complete"),
-                Err(e) => log_error!("❌ Auto-connect failed: {}", e),
-            }
-
-            log_info!("✅ BLE Discovery ready");
-        });
-
-        // Start scheduler maintenance
-        discovery_manager.clone().start_maintenance_task().await;
-
-        // Subscribe module manager to events
-        wasteland_module_manager.subscribe_to_events(&message_bus).await;
-        core_module_manager.subscribe_to_events(&message_bus).await;
-
-        // Initialize handlers for modules
-        if let Err(e) = wasteland_module_manager.initialize_module_handlers(
-            wasteland_manifests_path.clone(),
-            Some(discovery_manager.clone()),
-            &database,
-            &message_bus
-        ).await {
-            panic!("Failed to initialize wasteland module handlers: {}", e);
-        }
-
-        if let Err(e) = core_module_manager.initialize_module_handlers(
-            wasteland_manifests_path.clone(),
-            Some(discovery_manager.clone()),
-            &database,
-            &message_bus
-        ).await {
-            panic!("Failed to initialize core module handlers: {}", e);
-        }
-
-        // Initialize transport manager
-        let transport_manager = TransportManager::new(message_bus.clone());
-
-        // Add any custom outbound topics
-        transport_manager.add_outbound_topic("sensor_data".to_string()).await;
-        transport_manager.add_outbound_topic("arduino_ping".to_string()).await;
-        transport_manager.add_outbound_topic("device_registration".to_string()).await;
-
-        // Start the transport manager (spawns background tasks)
-        let transport_clone = transport_manager.clone();
-        let bus_for_broadcast = message_bus.clone();
-
-        tokio::spawn(async move {
-            // 1. Start transport manager
-            if let Err(e) = transport_clone.start().await {
-                log_error!("Transport manager failed to start: {}", e);
-                return; // abort early
-            }
-
-            // 2. Give devices time to connect
-            tokio::time::sleep(Duration::from_secs(5)).await;
-
-            // 3. Broadcast registration request
-            log_info!("Broadcasting device registration request to all field units...");
-
-            let _ = bus_for_broadcast.publish(BusMessage::new(
-                "device_registration".to_string(),
-                serde_json::json!({
-                "request": "capabilities",
-                "hub_id": "survon_hub",
-                "timestamp": std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs()
-                    }).to_string(),
-                "survon_hub".to_string(),
-            )).await;
-        });
-
-        // Knowledge ingestion
-        let ingester = KnowledgeIngester::new(&database);
-        if ingester.should_reingest()? {
-            ingester.ingest_all_knowledge()?;
-        }
-
-        // Initialize Jukebox
-        let jukebox_ingester = JukeboxIngester::new(&database);
-        if jukebox_ingester.should_reingest()? {
-            log_info!("🎵 Ingesting album library...");
-            jukebox_ingester.ingest_albums(&core_module_manager)?;
-        }
-
-        let (jukebox_actor, jukebox_intent_tx) = JukeboxActor::new(message_bus.clone());
-        tokio::spawn(async move {
-            jukebox_actor.run().await;
-        });
-
-        let jukebox_widget = JukeboxWidget::new(
-            database.clone(),
-            &message_bus,
-            jukebox_intent_tx,
-        ).await?;
-
-        // Initialize Messages Window
-        let (messages_actor, messages_intent_tx) = MessagesActor::new(message_bus.clone());
-        tokio::spawn(async move {
-            messages_actor.run().await;
-        });
-
-        let messages_widget = MessagesWidget::new(
-            &message_bus,
-            messages_intent_tx,
-        ).await?;
-
-        Ok(Self {
-            running: true,
-            mode: AppMode::Splash,
-            needs_redraw: false,
-            splash_screen: Some(SplashScreen::new()),
-            image_cache,
-            start_time: Instant::now(),
-            palette: AdaptiveColors::detect(),
-            jukebox_widget: Some(jukebox_widget),
-            messages_widget: Some(messages_widget),
-            modules_list_widget: ModulesListWidget::new(),
-            module_detail_widget: ModuleDetailWidget::new(),
-            wasteland_module_manager,
-            core_module_manager,
-            message_bus,
-            bus_receiver,
-            database,
-            events: EventHandler::new(),
-            document_manager: DocumentManager::new()?,
-            overview_focus: OverviewFocus::CoreModules,
-            transport_manager: Some(transport_manager),
-            discovery_manager: Some(discovery_manager),
-        })
+        RuntimeBuilder::new().build().await
     }
 
     /// Queue a command for a BLE device (uses scheduler)
@@ -317,11 +173,20 @@ impl App {
                 action,
                 payload,
                 priority,
+                CommandOrigin::new("survon_tui", AuditTrigger::Api),
             ).await?;
 
             log_info!("✅ Command queued: {} -> {}", device_id_clone, action);
         } else {
             log_error!("❌ Discovery manager not available");
+            audit::record(
+                "survon_tui",
+                AuditTrigger::Api,
+                &device_id,
+                action,
+                AuditOutcome::Failed,
+                Some("discovery manager not available".to_string()),
+            );
         }
 
         Ok(())
@@ -403,6 +268,7 @@ impl App {
         }
     }
 
+    /// Ask for a render. Any number of requests within one tick are drawn as a single frame.
     pub fn request_redraw(&mut self) {
         self.needs_redraw = true;
     }
@@ -416,19 +282,59 @@ impl App {
     }
 
     fn handle_tick(&mut self) -> bool {
+        // A splash with `SPLASH_AUTO_ADVANCE_MS` moves on by itself
+        let advanced = matches!(self.mode, AppMode::Splash)
+            && self.splash_screen.as_mut().is_some_and(|splash| splash.advance());
+        if advanced {
+            self.leave_splash();
+        }
+
         let should_animate: bool = {
             match self.mode {
                 AppMode::Splash => true,
+                AppMode::Loading => false,
                 AppMode::Overview => {
-                    self.has_animating_child()
+                    self.has_animating_child() || !active_toasts().is_empty()
                 },
-                _ => false,
+                // Keep redrawing while toasts are up so they disappear when they expire
+                AppMode::ModuleDetail(..) => !active_toasts().is_empty(),
             }
         };
 
-        self.needs_redraw = self.needs_redraw || should_animate;
+        // Modules with `auto_refresh_secs` re-read their manifests in place, leaving navigation alone
+        let refreshed = !matches!(self.mode, AppMode::Splash)
+            && (self.wasteland_module_manager.auto_refresh_modules() | self.core_module_manager.auto_refresh_modules());
+
+        // "updated Xs ago" labels move on once a second even when nothing else changes
+        let second = self.start_time.elapsed().as_secs();
+        let ages_moved = second != self.age_labels_second
+            && !matches!(self.mode, AppMode::Splash)
+            && [&self.wasteland_module_manager, &self.core_module_manager]
+                .iter()
+                .any(|manager| manager.get_modules().iter().any(|m| m.last_updated().is_some()));
+        self.age_labels_second = second;
+
+        // The bus overlay's rates are resampled once a second
+        let bus_sampled = match &mut self.bus_stats {
+            Some(bus_stats) => bus_stats.sample(self.message_bus.stats()),
+            None => false,
+        };
+
+        // Diagnostics results arrive from a background task
+        let diagnosed = self.diagnostics.as_mut().is_some_and(|diagnostics| diagnostics.poll());
+
+        let inspected = self.bus_inspector.as_mut().is_some_and(|inspector| inspector.poll());
+
+        let redraw = advanced || should_animate || refreshed || ages_moved || bus_sampled || diagnosed || inspected;
+        self.needs_redraw = self.needs_redraw || redraw;
 
-        should_animate
+        redraw
+    }
+
+    /// Drop the splash for the loading screen, or the overview if handlers are already registered
+    fn leave_splash(&mut self) {
+        self.splash_screen = None;
+        self.mode = if self.handlers_pending() { AppMode::Loading } else { AppMode::Overview };
     }
 
     fn handle_crossterm_event(&mut self, event: crossterm::event::Event) -> Result<bool> {
@@ -445,8 +351,7 @@ impl App {
 
                         if dismissed {
                             if splash.is_complete() {
-                                self.mode = AppMode::Overview;
-                                self.splash_screen = None;
+                                self.leave_splash();
                                 self.needs_redraw = true;
                             }
 
@@ -480,6 +385,10 @@ impl App {
                 Some("refresh_modules"),
                 String::new()
             ),
+            AppEvent::RemoveModule(name) => (
+                Some("remove_module"),
+                name.clone()
+            ),
             AppEvent::Quit => (
                 Some("quit"),
                 String::new()
@@ -539,6 +448,15 @@ impl App {
                 self.handle_refresh_modules().await;
                 Ok(true)
             }
+            AppEvent::RemoveModule(name) => {
+                match self.wasteland_module_manager.remove_module(&name) {
+                    Ok(removed) => Ok(removed),
+                    Err(e) => {
+                        log_error!("Failed to remove module '{}': {}", name, e);
+                        Ok(false)
+                    }
+                }
+            }
             AppEvent::ShowOverview => {
                 self.mode = AppMode::Overview;
                 Ok(true)
@@ -550,7 +468,7 @@ impl App {
                 Ok(true)
             }
             AppEvent::CloseDocument => {
-                /// TODO something someday.
+                self.document_manager.close_document();
                 Ok(true)
             }
 
@@ -569,35 +487,9 @@ impl App {
 
     fn handle_select(&mut self) -> bool {
         match self.overview_focus {
-            OverviewFocus::WastelandModules => {
-                let source = ModuleSource::Wasteland;
-                let module_index = self.wasteland_module_manager.selected_module;
-
-                match self.wasteland_module_manager.select_current_module() {
-                    Some(_) => {
-                        self.wasteland_module_manager.current_view = ModuleManagerView::ModuleDetail(source, module_index);
-                        true
-                    }
-                    None => {
-                        self.wasteland_module_manager.current_view = ModuleManagerView::ModuleListView;
-                        true
-                    }
-                }
-            },
-            OverviewFocus::CoreModules => {
-                let source = ModuleSource::Core;
-                let module_index = self.core_module_manager.selected_module;
-
-                match self.core_module_manager.select_current_module() {
-                    Some(_) => {
-                        self.core_module_manager.current_view = ModuleManagerView::ModuleDetail(source, module_index);
-                        true
-                    }
-                    None => {
-                        self.core_module_manager.current_view = ModuleManagerView::ModuleListView;
-                        true
-                    }
-                }
+            OverviewFocus::WastelandModules | OverviewFocus::CoreModules => {
+                self.enter_detail();
+                true
             },
             _ => {
                 // no handler yet for full-screen ModuleDetail
@@ -608,6 +500,72 @@ impl App {
         }
     }
 
+    /// The module panel that has focus, if a module panel does
+    fn focused_module_panel(&mut self) -> Option<(ModuleSource, &mut ModuleManager)> {
+        match self.overview_focus {
+            OverviewFocus::WastelandModules => Some((ModuleSource::Wasteland, &mut self.wasteland_module_manager)),
+            OverviewFocus::CoreModules => Some((ModuleSource::Core, &mut self.core_module_manager)),
+            _ => None,
+        }
+    }
+
+    /// Move overview focus to `focus`, as Tab does
+    pub fn set_overview_focus(&mut self, focus: OverviewFocus) {
+        self.overview_focus = focus;
+        self.needs_redraw = true;
+    }
+
+    /// Focus `source`'s panel and select the dashboard module called `name` (case-insensitive),
+    /// returning the panel to its list. False, changing nothing, if there's no such module.
+    pub fn focus_module(&mut self, source: ModuleSource, name: &str) -> bool {
+        let (focus, module_manager) = match source {
+            ModuleSource::Wasteland => (OverviewFocus::WastelandModules, &mut self.wasteland_module_manager),
+            ModuleSource::Core => (OverviewFocus::CoreModules, &mut self.core_module_manager),
+        };
+        if !module_manager.select_module_named(name) {
+            return false;
+        }
+        module_manager.current_view = ModuleManagerView::ModuleListView;
+        self.set_overview_focus(focus);
+        true
+    }
+
+    /// Open the focused panel's selected module in the panel, as Enter does. False when no
+    /// module panel has focus or the selection isn't a module.
+    pub fn enter_detail(&mut self) -> bool {
+        let Some((source, module_manager)) = self.focused_module_panel() else {
+            return false;
+        };
+        let module_index = module_manager.selected_module;
+        let opened = module_manager.select_current_module().is_some();
+        module_manager.current_view = if opened {
+            ModuleManagerView::ModuleDetail(source, module_index)
+        } else {
+            ModuleManagerView::ModuleListView
+        };
+        self.needs_redraw = true;
+        opened
+    }
+
+    /// Leave the detail being shown, as Esc does: fullscreen back to the overview, or the
+    /// focused panel back to its list. False when no detail was open.
+    pub fn exit_detail(&mut self) -> bool {
+        if matches!(self.mode, AppMode::ModuleDetail(..)) {
+            self.back_to_overview();
+            self.needs_redraw = true;
+            return true;
+        }
+        let Some((_, module_manager)) = self.focused_module_panel() else {
+            return false;
+        };
+        if matches!(module_manager.current_view, ModuleManagerView::ModuleListView) {
+            return false;
+        }
+        module_manager.current_view = ModuleManagerView::ModuleListView;
+        self.needs_redraw = true;
+        true
+    }
+
     pub fn toggle_overview_focus(&mut self, step_direction: i32) {
         let screens = [
             OverviewFocus::None,
@@ -629,6 +587,94 @@ impl App {
         self.overview_focus = screens[new_index].clone();
     }
 
+    /// Switch between compact and expanded overview cards and remember the choice
+    pub fn toggle_density(&mut self) {
+        self.ui_state.density = self.ui_state.density.toggle();
+        self.modules_list_widget.set_density(self.ui_state.density);
+        self.module_detail_widget.set_density(self.ui_state.density);
+        self.ui_state.save(&self.database);
+        self.needs_redraw = true;
+    }
+
+    /// Cycle the status color palette and remember the choice
+    pub fn cycle_palette(&mut self) {
+        self.ui_state.palette = self.ui_state.palette.next();
+        self.ui_state.palette.activate();
+        self.ui_state.save(&self.database);
+        self.needs_redraw = true;
+    }
+
+    /// Open or close the overlay listing the keys that work right now
+    pub fn toggle_key_help(&mut self) {
+        self.show_key_help = !self.show_key_help;
+        self.needs_redraw = true;
+    }
+
+    /// Open or close the per-topic message rate overlay
+    pub fn toggle_bus_stats(&mut self) {
+        self.bus_stats = match self.bus_stats {
+            Some(_) => None,
+            None => Some(BusStatsOverlay::new(self.message_bus.stats())),
+        };
+        self.needs_redraw = true;
+    }
+
+    /// Open the audit log browser on its newest entries, or close it
+    pub fn toggle_audit_log(&mut self) {
+        self.audit_log = match self.audit_log {
+            Some(_) => None,
+            None => Some(AuditLogOverlay::new(audit::recent(AUDIT_LOG_LIMIT))),
+        };
+        self.needs_redraw = true;
+    }
+
+    /// Open the diagnostics screen and run the self-check, or close it
+    pub fn toggle_diagnostics(&mut self) {
+        match self.diagnostics {
+            Some(_) => self.diagnostics = None,
+            None => self.run_diagnostics(),
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Start a self-check in the background; the overlay fills in when it finishes
+    fn run_diagnostics(&mut self) {
+        let inputs = DiagnosticInputs::gather(
+            [&self.wasteland_module_manager, &self.core_module_manager],
+            &self.database,
+            &self.transport_manager,
+            &self.discovery_manager,
+        );
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let _ = tx.send(diagnostics::run(inputs).await);
+        });
+        self.diagnostics = Some(DiagnosticsOverlay::new(rx));
+    }
+
+    /// Freeze or release every actuator, announcing the change on the bus
+    pub fn toggle_safe_mode(&mut self) {
+        let enabled = !is_safe_mode();
+        set_safe_mode(enabled);
+
+        if enabled {
+            log_warn!("🛑 Safe mode on: actuator commands are refused");
+            notify(ToastLevel::Warning, "🛑 Safe mode on: actuators frozen");
+        } else {
+            log_info!("✅ Safe mode off: actuator commands resume");
+            notify(ToastLevel::Success, "✅ Safe mode off: actuators resume");
+        }
+
+        let message = safe_mode_message("survon_tui", enabled);
+        let bus = self.message_bus.clone();
+        tokio::spawn(async move {
+            if let Err(e) = bus.publish(message).await {
+                log_error!("Failed to publish safe mode change: {}", e);
+            }
+        });
+        self.needs_redraw = true;
+    }
+
     async fn handle_refresh_modules(&mut self) {
         self.wasteland_module_manager.refresh_modules().await;
         self.core_module_manager.refresh_modules().await;
@@ -650,6 +696,11 @@ impl App {
     fn render_current_mode(&mut self, frame: &mut Frame) {
         match &self.mode {
             AppMode::Splash => self.render_splash(frame),
+            AppMode::Loading => {
+                if let Some(loading_screen) = &self.loading_screen {
+                    loading_screen.render(frame.area(), frame.buffer_mut());
+                }
+            }
             AppMode::Overview => self.render_widget_mode(frame),
 
             // Support full-screen module mode
@@ -658,6 +709,46 @@ impl App {
                 self.render_module_detail(frame, source.clone(), *module_idx, is_focused)
             },
         }
+
+        // The overview title already says so; a detail view has no title of its own
+        if is_safe_mode() && matches!(self.mode, AppMode::ModuleDetail(..)) {
+            render_safe_mode_banner(frame.area(), frame.buffer_mut());
+        }
+
+        if !matches!(self.mode, AppMode::Splash | AppMode::Loading) {
+            render_toasts(&active_toasts(), frame.area(), frame.buffer_mut());
+        }
+
+        if let Some(bus_stats) = &self.bus_stats {
+            bus_stats.render(frame.area(), frame.buffer_mut());
+        }
+
+        if let Some(bus_inspector) = &mut self.bus_inspector {
+            bus_inspector.render(frame.area(), frame.buffer_mut());
+        }
+
+        if let Some(audit_log) = &self.audit_log {
+            audit_log.render(frame.area(), frame.buffer_mut());
+        }
+
+        if let Some(diagnostics) = &self.diagnostics {
+            diagnostics.render(frame.area(), frame.buffer_mut());
+        }
+
+        if self.show_about {
+            render_about(frame.area(), frame.buffer_mut());
+        }
+
+        if self.show_key_help {
+            let sections = self.key_help_sections();
+            render_key_help(&sections, frame.area(), frame.buffer_mut());
+        }
+
+        // Documents that couldn't open externally are shown over whatever is on screen
+        if self.document_manager.inline_document().is_some() {
+            let area = frame.area();
+            self.document_manager.render_inline(area, frame.buffer_mut());
+        }
     }
 
     fn render_splash(&mut self, frame: &mut Frame) {
@@ -738,27 +829,49 @@ impl App {
 
     /// Run the application's main loop.
     pub async fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
-        let mut needs_redraw = true;
+        // However many redraws are requested, render at most once per tick
+        let mut frame_pacer = FramePacer::new(tick_interval());
+        let mut screen_snapshot = ScreenSnapshot::from_env();
 
         while self.running {
 
-            if needs_redraw || self.needs_redraw {
-                terminal.draw(|frame| {
+            // Handlers register once the splash is out of the way
+            if self.handlers_pending() && !matches!(self.mode, AppMode::Splash) {
+                self.initialize_handlers(&mut terminal).await?;
+                frame_pacer.request();
+            }
+
+            // Taken before rendering, so requests made while rendering get the next frame
+            if std::mem::take(&mut self.needs_redraw) {
+                frame_pacer.request();
+            }
+            if frame_pacer.begin_frame(std::time::Instant::now()) {
+                let completed = terminal.draw(|frame| {
                     self.render_current_mode(frame);
                 })?;
-                needs_redraw = false;
-                self.needs_redraw = false;
+                if let Some(snapshot) = &mut screen_snapshot {
+                    snapshot.capture(completed.buffer);
+                }
+            }
+            // A frame held back by the snapshot interval is written once it allows, redraw or not
+            if let Some(snapshot) = &mut screen_snapshot {
+                snapshot.flush(tokio::time::Instant::now());
             }
 
             // Poll for events from subscribed topics
             self.wasteland_module_manager.poll_events();
             self.core_module_manager.poll_events();
 
+            if self.document_manager.poll_launch_outcomes() {
+                frame_pacer.request();
+            }
+
+            // A deferred frame is drawn after the next tick at the latest
             tokio::select! {
                 event = self.events.next() => {
                     if let Ok(event) = event {
                         if self.handle_event(event).await? {
-                            needs_redraw = true;
+                            frame_pacer.request();
                         }
                     } else if let Err(e) = event {
                         panic!("Event error: {}", e);
@@ -767,20 +880,181 @@ impl App {
                 message = self.bus_receiver.recv() => {
                     if let Some(msg) = message {
                         self.handle_bus_message(msg);
-                        needs_redraw = true;
+                        frame_pacer.request();
                     }
                 }
             }
         }
+
+        for observer in &self.observers {
+            observer.on_shutdown();
+        }
         Ok(())
     }
 
+    /// Register a lifecycle observer after startup. It only sees events from now on.
+    pub fn add_observer(&mut self, observer: SharedObserver) {
+        self.wasteland_module_manager.add_observer(observer.clone());
+        self.core_module_manager.add_observer(observer.clone());
+        self.observers.push(observer);
+    }
+
     /// Handles the key events and updates the state of [`App`].
     pub fn handle_key_events(&mut self, key_event: KeyEvent) -> Result<()> {
         let key_code = key_event.code;
 
+        // An inline document sits on top of everything and owns the keyboard
+        if self.document_manager.inline_document().is_some() {
+            match key_code {
+                KeyCode::Esc | KeyCode::Char('q') => self.events.send(AppEvent::CloseDocument),
+                KeyCode::Up => self.document_manager.scroll_inline(-1),
+                KeyCode::Down => self.document_manager.scroll_inline(1),
+                KeyCode::PageUp => self.document_manager.scroll_inline(-10),
+                KeyCode::PageDown => self.document_manager.scroll_inline(10),
+                _ => {}
+            }
+            self.needs_redraw = true;
+            return Ok(());
+        }
+
+        // The audit log browser scrolls until closed
+        if let Some(audit_log) = &mut self.audit_log {
+            let ctrl_a = key_code == KeyCode::Char('a') && key_event.modifiers.contains(KeyModifiers::CONTROL);
+            match key_code {
+                KeyCode::Esc | KeyCode::Char('q') => self.audit_log = None,
+                _ if ctrl_a => self.audit_log = None,
+                KeyCode::Up => audit_log.scroll(-1),
+                KeyCode::Down => audit_log.scroll(1),
+                KeyCode::PageUp => audit_log.scroll(-10),
+                KeyCode::PageDown => audit_log.scroll(10),
+                _ => {}
+            }
+            self.needs_redraw = true;
+            return Ok(());
+        }
+
+        // The bus inspector takes typing for its topic prompt, then pause, scroll and clear keys
+        if let Some(inspector) = &mut self.bus_inspector {
+            let ctrl_t = key_code == KeyCode::Char('t') && key_event.modifiers.contains(KeyModifiers::CONTROL);
+            if ctrl_t {
+                self.bus_inspector = None;
+            } else if inspector.is_editing() {
+                match key_code {
+                    KeyCode::Enter => inspector.watch(&self.message_bus),
+                    KeyCode::Esc => {
+                        if !inspector.cancel_edit() {
+                            self.bus_inspector = None;
+                        }
+                    }
+                    KeyCode::Backspace => inspector.backspace(),
+                    KeyCode::Char(c) => inspector.push_char(c),
+                    _ => {}
+                }
+            } else {
+                match key_code {
+                    KeyCode::Esc | KeyCode::Char('q') => self.bus_inspector = None,
+                    KeyCode::Char(' ') => inspector.toggle_pause(),
+                    KeyCode::Char('/') => inspector.edit_prefix(),
+                    KeyCode::Char('c') => inspector.clear(),
+                    KeyCode::Up => inspector.scroll(1),
+                    KeyCode::Down => inspector.scroll(-1),
+                    KeyCode::PageUp => inspector.scroll(10),
+                    KeyCode::PageDown => inspector.scroll(-10),
+                    KeyCode::End => inspector.follow(),
+                    _ => {}
+                }
+            }
+            self.needs_redraw = true;
+            return Ok(());
+        }
+
+        // The diagnostics screen stays up until closed; `r` runs the checks again, `s` saves a support bundle
+        if self.diagnostics.is_some() {
+            let ctrl_d = key_code == KeyCode::Char('d') && key_event.modifiers.contains(KeyModifiers::CONTROL);
+            match key_code {
+                KeyCode::Esc | KeyCode::Char('q') => self.diagnostics = None,
+                _ if ctrl_d => self.diagnostics = None,
+                KeyCode::Char('r') => self.run_diagnostics(),
+                KeyCode::Char('s') => self.export_support_bundle(),
+                _ => {}
+            }
+            self.needs_redraw = true;
+            return Ok(());
+        }
+
+        // So does the about overlay
+        if self.show_about {
+            let ctrl_v = key_code == KeyCode::Char('v') && key_event.modifiers.contains(KeyModifiers::CONTROL);
+            if ctrl_v || matches!(key_code, KeyCode::Esc | KeyCode::Char('q')) {
+                self.show_about = false;
+            }
+            self.needs_redraw = true;
+            return Ok(());
+        }
+
+        // The key help overlay stays up until dismissed
+        if self.show_key_help {
+            if matches!(key_code, KeyCode::Esc | KeyCode::Char('?' | 'q')) {
+                self.show_key_help = false;
+            }
+            self.needs_redraw = true;
+            return Ok(());
+        }
+
+        // Ctrl+F expands the focused module to the whole terminal (and back)
+        if key_code == KeyCode::Char('f') && key_event.modifiers.contains(KeyModifiers::CONTROL) {
+            self.toggle_fullscreen_detail();
+            return Ok(());
+        }
+
+        // Ctrl+Z / Ctrl+Y step back and forth through destructive operations; Ctrl+E writes the view as text;
+        // Ctrl+B shows bus traffic per topic; Ctrl+P pauses every actuator (safe mode); Ctrl+A browses the audit log;
+        // Ctrl+D runs the diagnostics self-check; Ctrl+V shows the version; Ctrl+T inspects bus messages
+        if key_event.modifiers.contains(KeyModifiers::CONTROL) {
+            if key_code == KeyCode::Char('e') {
+                self.export_view_text_with_toast();
+                return Ok(());
+            }
+            if key_code == KeyCode::Char('b') {
+                self.toggle_bus_stats();
+                return Ok(());
+            }
+            if key_code == KeyCode::Char('p') {
+                self.toggle_safe_mode();
+                return Ok(());
+            }
+            if key_code == KeyCode::Char('a') {
+                self.toggle_audit_log();
+                return Ok(());
+            }
+            if key_code == KeyCode::Char('d') {
+                self.toggle_diagnostics();
+                return Ok(());
+            }
+            if key_code == KeyCode::Char('v') {
+                self.show_about = true;
+                self.needs_redraw = true;
+                return Ok(());
+            }
+            if key_code == KeyCode::Char('t') {
+                self.bus_inspector = Some(BusInspector::new());
+                self.needs_redraw = true;
+                return Ok(());
+            }
+
+            let direction = match key_code {
+                KeyCode::Char('z') => Some(UndoDirection::Undo),
+                KeyCode::Char('y') => Some(UndoDirection::Redo),
+                _ => None,
+            };
+            if let Some(direction) = direction {
+                self.step_undo_history(direction);
+                return Ok(());
+            }
+        }
+
         match &self.mode {
-            AppMode::Splash => {},
+            AppMode::Splash | AppMode::Loading => {},
             AppMode::Overview => {
                 // Handle focused widget keys first (these can consume the event)
                 let event_handled = match self.overview_focus {
@@ -814,11 +1088,7 @@ impl App {
                                         true
                                     } else {
                                         match key_code {
-                                            KeyCode::Esc => {
-                                                self.wasteland_module_manager.current_view = ModuleManagerView::ModuleListView;
-                                                self.needs_redraw = true;
-                                                true
-                                            },
+                                            KeyCode::Esc => self.exit_detail(),
                                             _ => false
                                         }
                                     }
@@ -829,6 +1099,7 @@ impl App {
                             match key_code {
                                 KeyCode::Left => { self.wasteland_module_manager.prev_module(); true },
                                 KeyCode::Right => { self.wasteland_module_manager.next_module(); true },
+                                KeyCode::Char(ch) => self.run_quick_action(ModuleSource::Wasteland, ch),
                                 _ => false
                             }
                         }
@@ -845,11 +1116,7 @@ impl App {
                                         true
                                     } else {
                                         match key_code {
-                                            KeyCode::Esc => {
-                                                self.core_module_manager.current_view = ModuleManagerView::ModuleListView;
-                                                self.needs_redraw = true;
-                                                true
-                                            },
+                                            KeyCode::Esc => self.exit_detail(),
                                             _ => false
                                         }
                                     }
@@ -860,6 +1127,7 @@ impl App {
                             match key_code {
                                 KeyCode::Left => { self.core_module_manager.prev_module(); true },
                                 KeyCode::Right => { self.core_module_manager.next_module(); true },
+                                KeyCode::Char(ch) => self.run_quick_action(ModuleSource::Core, ch),
                                 _ => false
                             }
                         }
@@ -869,6 +1137,9 @@ impl App {
                             match key_code {
                                 KeyCode::Up => { messages.scroll_up(); true },
                                 KeyCode::Down => { messages.scroll_down(); true },
+                                KeyCode::End => { messages.scroll_to_bottom(); true },
+                                KeyCode::Enter => { messages.activate(); true },
+                                KeyCode::Esc => messages.dismiss(),
                                 _ => false
                             }
                         } else {
@@ -884,6 +1155,9 @@ impl App {
                         KeyCode::Enter => self.events.send(AppEvent::Select),
                         KeyCode::Char('c' | 'C') => self.events.send(AppEvent::Quit),
                         KeyCode::Char('r' | 'R') => self.events.send(AppEvent::RefreshModules),
+                        KeyCode::Char('d' | 'D') => self.toggle_density(),
+                        KeyCode::Char('p' | 'P') => self.cycle_palette(),
+                        KeyCode::Char('?') => self.toggle_key_help(),
                         KeyCode::Tab => self.toggle_overview_focus(1),
                         KeyCode::BackTab => self.toggle_overview_focus(-1),
                         _ => {}
@@ -900,6 +1174,10 @@ impl App {
                         };
                         if let Some(event) = module_manager.handle_key_for_module(*module_idx, key_code) {
                             self.events.send(event);
+                        } else if key_code == KeyCode::Esc {
+                            self.exit_detail();
+                        } else if key_code == KeyCode::Char('?') {
+                            self.toggle_key_help();
                         }
                     }
                 }
@@ -908,6 +1186,98 @@ impl App {
         Ok(())
     }
 
+    /// Run a quick action on the selected module of a list panel. Returns false when
+    /// the module offers nothing for `trigger`, so global keys still apply.
+    fn run_quick_action(&mut self, source: ModuleSource, trigger: char) -> bool {
+        let module_manager = match source {
+            ModuleSource::Core => &mut self.core_module_manager,
+            ModuleSource::Wasteland => &mut self.wasteland_module_manager,
+        };
+
+        let module_idx = module_manager.selected_module;
+        let Some((action, event)) = module_manager.run_quick_action(module_idx, trigger) else {
+            return false;
+        };
+
+        if action.opens_detail {
+            module_manager.current_view = ModuleManagerView::ModuleDetail(source, module_idx);
+        }
+        if let Some(event) = event {
+            self.events.send(event);
+        }
+        self.needs_redraw = true;
+        true
+    }
+
+    /// Show the focused panel's selected module over the whole terminal, or return to the overview
+    pub fn toggle_fullscreen_detail(&mut self) {
+        match self.mode {
+            AppMode::Overview => {
+                let (source, module_manager) = match self.overview_focus {
+                    OverviewFocus::WastelandModules => (ModuleSource::Wasteland, &mut self.wasteland_module_manager),
+                    OverviewFocus::CoreModules => (ModuleSource::Core, &mut self.core_module_manager),
+                    _ => return,
+                };
+
+                let module_idx = module_manager.selected_module;
+                if module_manager.select_current_module().is_some() {
+                    self.mode = AppMode::ModuleDetail(source, module_idx);
+                    self.needs_redraw = true;
+                }
+            }
+            AppMode::ModuleDetail(..) => {
+                self.back_to_overview();
+                self.needs_redraw = true;
+            }
+            AppMode::Splash | AppMode::Loading => {}
+        }
+    }
+
+    /// Undo (or redo) the newest recorded operation through the handler that performed it
+    pub fn step_undo_history(&mut self, direction: UndoDirection) {
+        let Some(mut entry) = undo::take(direction) else {
+            notify(ToastLevel::Info, match direction {
+                UndoDirection::Undo => "Nothing to undo",
+                UndoDirection::Redo => "Nothing to redo",
+            });
+            return;
+        };
+
+        let result = match self.wasteland_module_manager.apply_undo(&entry.handler, &mut entry.op, direction) {
+            Some(result) => result,
+            None => self.core_module_manager
+                .apply_undo(&entry.handler, &mut entry.op, direction)
+                .unwrap_or_else(|| Err(color_eyre::eyre::eyre!("'{}' is no longer loaded", entry.handler))),
+        };
+
+        let (done, failed) = match direction {
+            UndoDirection::Undo => ("↩ Undid", "undo"),
+            UndoDirection::Redo => ("↪ Redid", "redo"),
+        };
+        match result {
+            Ok(()) => {
+                notify(ToastLevel::Success, format!("{} {}", done, entry.label));
+                undo::finish(direction, entry);
+            }
+            Err(e) => {
+                log_error!("Failed to {} '{}': {}", failed, entry.label, e);
+                notify(ToastLevel::Error, format!("Couldn't {} {}: {}", failed, entry.label, e));
+                undo::restore(direction, entry);
+            }
+        }
+        self.needs_redraw = true;
+    }
+
+    fn export_view_text_with_toast(&self) {
+        match self.export_view_text() {
+            Ok(path) => notify(ToastLevel::Success, format!("✓ View saved as text to {}", path.display())),
+            Err(e) => {
+                log_error!("Failed to export view text: {}", e);
+                notify(ToastLevel::Error, format!("❌ Couldn't save view text: {}", e));
+            }
+        }
+    }
+
     /// Helper to get device_id from current module
     fn get_current_device_id(&self, source: &ModuleSource, module_idx: usize) -> Option<String> {
         let module_manager = match source {
@@ -918,9 +1288,7 @@ impl App {
         module_manager.get_modules()
             .get(module_idx)
             .and_then(|m| {
-                m.config.bindings
-                    .get("device_id")
-                    .and_then(|v| v.as_str())
+                m.config.binding_str("device_id")
                     .map(|s| s.to_string())
             })
     }
@@ -989,3 +1357,66 @@ impl App {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::{RuntimeBuilder, StartupView};
+    use crate::util::dirs::DataDirs;
+    use crate::util::test_util::{in_memory_database, TestModule};
+
+    /// An app past the splash with one dashboard module in each panel and nothing attached
+    async fn test_app() -> App {
+        let root = std::env::temp_dir().join(format!("survon_app_{}", uuid::Uuid::new_v4()));
+        let mut app = RuntimeBuilder::new()
+            .with_database(in_memory_database())
+            .with_data_dirs(DataDirs::rooted(&root))
+            .with_startup_view(StartupView::default())
+            .without_transport()
+            .without_discovery()
+            .build()
+            .await
+            .unwrap();
+        app.mode = AppMode::Overview;
+        app.core_module_manager.push_module(TestModule::new("Tank", "monitoring", "gauge_card").build());
+        app.wasteland_module_manager.push_module(TestModule::new("Pump", "valve_control", "toggle_switch").build());
+        app
+    }
+
+    #[tokio::test]
+    async fn test_focus_and_panel_detail_round_trip() {
+        let mut app = test_app().await;
+
+        assert!(!app.focus_module(ModuleSource::Wasteland, "Tank"));
+        assert_eq!(app.overview_focus, OverviewFocus::CoreModules);
+
+        assert!(app.focus_module(ModuleSource::Wasteland, "pump"));
+        assert_eq!(app.overview_focus, OverviewFocus::WastelandModules);
+
+        assert!(app.enter_detail());
+        assert_eq!(app.wasteland_module_manager.current_view, ModuleManagerView::ModuleDetail(ModuleSource::Wasteland, 0));
+        assert_eq!(app.mode, AppMode::Overview);
+
+        assert!(app.exit_detail());
+        assert_eq!(app.wasteland_module_manager.current_view, ModuleManagerView::ModuleListView);
+        assert!(!app.exit_detail());
+
+        // Nothing to open without a module panel focused
+        app.set_overview_focus(OverviewFocus::Messages);
+        assert!(!app.enter_detail());
+    }
+
+    #[tokio::test]
+    async fn test_exit_detail_leaves_fullscreen() {
+        let mut app = test_app().await;
+
+        assert!(app.focus_module(ModuleSource::Core, "Tank"));
+        app.toggle_fullscreen_detail();
+        assert_eq!(app.mode, AppMode::ModuleDetail(ModuleSource::Core, 0));
+
+        assert!(app.exit_detail());
+        assert_eq!(app.mode, AppMode::Overview);
+        // The panel itself was never opened
+        assert!(!app.exit_detail());
+    }
+}
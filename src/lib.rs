@@ -0,0 +1,5 @@
+pub mod app;
+pub mod runtime;
+pub mod ui;
+pub mod module;
+pub mod util;
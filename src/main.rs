@@ -1,19 +1,35 @@
-use crate::app::App;
-
-pub mod app;
-pub mod ui;
-pub mod module;
-pub mod util;
+use runtime_base_rust::{app::App, log_info, runtime::{features, isolation}, ui::text_export::status_text, util, util::version};
 
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    // `survon --version` names the build for bug reports
+    if args.iter().any(|a| a == "--version" || a == "-V") {
+        println!("{}", version::version_line());
+        println!("features: {}", features::features_summary());
+        return Ok(());
+    }
+
+    // `survon status --text` prints module state for scripts without starting the TUI
+    if args.first().map(String::as_str) == Some("status") && args.iter().any(|a| a == "--text") {
+        color_eyre::install()?;
+        let dirs = util::dirs::data_dirs();
+        println!("{}", status_text(&dirs.core_modules, &dirs.modules)?);
+        return Ok(());
+    }
+
     let _ = &*util::log::LOGGER;
-    log_info!("Survon runtime starting...");
+    log_info!("Survon runtime starting: {}", version::version_line());
 
     tracing_subscriber::fmt::init();
     color_eyre::install()?;
     let terminal = ratatui::init();
-    let result = App::new().await?.run(terminal).await;
+    // After ratatui's hook, so contained handler panics don't restore the terminal mid-run
+    isolation::install_panic_hook();
+    let result = match App::new().await {
+        Ok(app) => app.run(terminal).await,
+        Err(e) => Err(e),
+    };
     ratatui::restore();
     result
 }
@@ -0,0 +1,33 @@
+use std::str::FromStr;
+use ratatui::style::Color;
+
+use crate::module::ModuleConfig;
+
+impl ModuleConfig {
+    /// Icon shown beside the module on the dashboard, falling back to one per `module_type`
+    pub fn display_icon(&self) -> &str {
+        if let Some(icon) = self.icon.as_deref().filter(|i| !i.trim().is_empty()) {
+            return icon;
+        }
+
+        match self.module_type.as_str() {
+            "album" => "🎵",
+            "com" => "🔌",
+            "entertainment" => "🎮",
+            "knowledge" => "📚",
+            "llm" => "🤖",
+            "monitoring" => "📊",
+            "overseer" => "🗂️",
+            "side_quest" => "🗺️",
+            "valve_control" => "🚰",
+            _ => "⚙️",
+        }
+    }
+
+    /// Parsed `accent_color` (named colors like `light-blue`, or `#rrggbb`)
+    pub fn accent_color(&self) -> Option<Color> {
+        self.accent_color
+            .as_deref()
+            .and_then(|c| Color::from_str(c.trim()).ok())
+    }
+}
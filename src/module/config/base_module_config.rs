@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::ModuleCapabilities;
+
 /// Base configuration that all modules must have
 ///
 /// *Note: `module_type` is NOT included because it's consumed by the enum's tag
@@ -18,4 +20,18 @@ pub struct BaseModuleConfig {
     pub description: Option<String>,
     #[serde(default)]
     pub is_blinkable: Option<bool>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub accent_color: Option<String>,
+    #[serde(default)]
+    pub pinned: Option<bool>,
+    #[serde(default)]
+    pub sort_order: Option<i32>,
+    #[serde(default)]
+    pub capabilities: Option<ModuleCapabilities>,
+    #[serde(default)]
+    pub log_level: Option<String>,
+    #[serde(default)]
+    pub auto_refresh_secs: Option<u64>,
 }
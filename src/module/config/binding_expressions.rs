@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use super::{ModuleConfig, ValidationError};
+
+const OPEN: &str = "{{";
+const CLOSE: &str = "}}";
+/// `\{{` stands for a literal `{{`
+const ESCAPED_OPEN: &str = "\\{{";
+
+/// Expand `{{name}}` references in binding values, in place.
+///
+/// `name` is another binding (string, number or bool) or one of the runtime variables
+/// `module.name`, `module.type`, `module.bus_topic` and `module.dir` (the manifest's directory
+/// name). Referenced bindings may contain references themselves. A value that is exactly one
+/// reference to a number or bool keeps that type. Unknown and circular references are errors.
+pub fn resolve_binding_expressions(config: &mut ModuleConfig, module_dir: &str) -> Result<(), ValidationError> {
+    let variables = HashMap::from([
+        ("module.name", config.name.clone()),
+        ("module.type", config.module_type.clone()),
+        ("module.bus_topic", config.bus_topic.clone()),
+        ("module.dir", module_dir.to_string()),
+    ]);
+    resolve_bindings(&mut config.bindings, variables)
+}
+
+/// [`resolve_binding_expressions`] on a config still in its generic form, as validation sees it.
+/// The YAML alone doesn't say which directory it lives in, so `module.dir` expands to the name
+/// as a bus topic would be derived from it.
+pub(crate) fn resolve_generic_binding_expressions(config: &mut Value) -> Result<(), ValidationError> {
+    let Some(Value::Object(bindings)) = config.get("bindings") else {
+        return Ok(());
+    };
+    let mut bindings: HashMap<String, Value> = bindings.clone().into_iter().collect();
+
+    let field = |key: &str| config.get(key).and_then(Value::as_str).unwrap_or_default().to_string();
+    let variables = HashMap::from([
+        ("module.name", field("name")),
+        ("module.type", field("module_type")),
+        ("module.bus_topic", field("bus_topic")),
+        ("module.dir", field("name").to_lowercase().replace(' ', "_")),
+    ]);
+    resolve_bindings(&mut bindings, variables)?;
+
+    config["bindings"] = Value::Object(bindings.into_iter().collect());
+    Ok(())
+}
+
+fn resolve_bindings(
+    bindings: &mut HashMap<String, Value>,
+    variables: HashMap<&'static str, String>,
+) -> Result<(), ValidationError> {
+    let raw = bindings.clone();
+    let mut resolver = Resolver {
+        raw: &raw,
+        variables,
+        resolved: HashMap::new(),
+        stack: Vec::new(),
+    };
+
+    for (key, value) in bindings.iter_mut() {
+        resolver.resolve_value(value, &format!("bindings.{}", key))?;
+    }
+
+    Ok(())
+}
+
+struct Resolver<'a> {
+    raw: &'a HashMap<String, Value>,
+    variables: HashMap<&'static str, String>,
+    /// Expanded string bindings, so each is only expanded once
+    resolved: HashMap<String, String>,
+    /// Bindings currently being expanded, to catch cycles
+    stack: Vec<String>,
+}
+
+impl Resolver<'_> {
+    fn resolve_value(&mut self, value: &mut Value, field: &str) -> Result<(), ValidationError> {
+        match value {
+            Value::String(text) if text.contains(OPEN) => {
+                if let Some(typed) = self.typed_reference(text) {
+                    *value = typed;
+                } else {
+                    *text = self.expand(text, field)?;
+                }
+            }
+            Value::Array(items) => {
+                for (index, item) in items.iter_mut().enumerate() {
+                    self.resolve_value(item, &format!("{}[{}]", field, index))?;
+                }
+            }
+            Value::Object(map) => {
+                for (key, item) in map.iter_mut() {
+                    self.resolve_value(item, &format!("{}.{}", field, key))?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// `"{{name}}"` on its own pointing at a number or bool binding
+    fn typed_reference(&self, text: &str) -> Option<Value> {
+        let name = text.strip_prefix(OPEN)?.strip_suffix(CLOSE)?.trim();
+        if name.contains(OPEN) || name.contains(CLOSE) {
+            return None;
+        }
+        match self.raw.get(name)? {
+            value @ (Value::Number(_) | Value::Bool(_)) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    fn expand(&mut self, text: &str, field: &str) -> Result<String, ValidationError> {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix(ESCAPED_OPEN) {
+                out.push_str(OPEN);
+                rest = after;
+            } else if let Some(after) = rest.strip_prefix(OPEN) {
+                let end = after.find(CLOSE).ok_or_else(|| ValidationError {
+                    field: field.to_string(),
+                    error: format!("Unclosed '{{{{' in '{}'", text),
+                })?;
+                out.push_str(&self.lookup(after[..end].trim(), field)?);
+                rest = &after[end + CLOSE.len()..];
+            } else {
+                let ch = rest.chars().next().unwrap();
+                out.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn lookup(&mut self, name: &str, field: &str) -> Result<String, ValidationError> {
+        if let Some(value) = self.variables.get(name) {
+            return Ok(value.clone());
+        }
+        if let Some(value) = self.resolved.get(name) {
+            return Ok(value.clone());
+        }
+
+        let error = |message: String| ValidationError { field: field.to_string(), error: message };
+
+        if self.stack.iter().any(|n| n == name) {
+            return Err(error(format!("Circular reference: {} -> {}", self.stack.join(" -> "), name)));
+        }
+
+        match self.raw.get(name) {
+            Some(Value::String(text)) => {
+                self.stack.push(name.to_string());
+                let expanded = self.expand(text, &format!("bindings.{}", name));
+                self.stack.pop();
+                let expanded = expanded?;
+                self.resolved.insert(name.to_string(), expanded.clone());
+                Ok(expanded)
+            }
+            Some(value @ (Value::Number(_) | Value::Bool(_))) => Ok(value.to_string()),
+            Some(_) => Err(error(format!("'{{{{{}}}}}' must refer to a string, number or bool binding", name))),
+            None => Err(error(format!("Unresolved reference '{{{{{}}}}}'", name))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn config(bindings: Value) -> ModuleConfig {
+        serde_json::from_value(json!({
+            "name": "Pump House",
+            "module_type": "monitoring",
+            "bus_topic": "a01",
+            "template": "gauge_card",
+            "bindings": bindings,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_resolves_references_and_escapes() {
+        let mut config = config(json!({
+            "device_id": "a01",
+            "display_name": "{{module.name}} ({{device_id}})",
+            "label": "{{display_name}} pressure",
+            "max": 120,
+            "max_value": "{{max}}",
+            "hint": "type \\{{x}} literally",
+            "series": ["{{device_id}}-a"],
+        }));
+
+        resolve_binding_expressions(&mut config, "pump_house").unwrap();
+
+        let bindings = &config.bindings;
+        assert_eq!(bindings["label"], json!("Pump House (a01) pressure"));
+        assert_eq!(bindings["max_value"], json!(120));
+        assert_eq!(bindings["hint"], json!("type {{x}} literally"));
+        assert_eq!(bindings["series"], json!(["a01-a"]));
+    }
+
+    #[test]
+    fn test_unresolved_and_circular_references_are_errors() {
+        let mut missing = config(json!({ "label": "{{device}}" }));
+        let err = resolve_binding_expressions(&mut missing, "x").unwrap_err();
+        assert_eq!(err.field, "bindings.label");
+        assert!(err.error.contains("{{device}}"));
+
+        let mut circular = config(json!({ "a": "{{b}}", "b": "{{a}}" }));
+        assert!(resolve_binding_expressions(&mut circular, "x").unwrap_err().error.contains("Circular"));
+    }
+
+    #[test]
+    fn test_configs_with_references_validate_and_load() {
+        let yaml = r#"
+name: "Pump House"
+module_type: "monitoring"
+bus_topic: "a01"
+template: "gauge_card"
+bindings:
+  device_id: "{{module.bus_topic}}"
+  display_name: "{{module.name}} ({{device_id}})"
+  max: 120
+  max_value: "{{max}}"
+  warn_threshold: 90
+  danger_threshold: 110
+  a: 0.0
+  b: 0.0
+  c: 0.0
+  device_type: "pressure"
+  firmware_version: "1.0.0"
+  unit_of_measure_label: "psi"
+"#;
+        crate::module::ConfigValidator::validate(yaml).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("survon_binding_expressions_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("config.yml"), yaml).unwrap();
+        let module = crate::module::Module::load_from_manifest_path(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(module.config.binding_f64("max_value"), Some(120.0));
+        assert_eq!(module.config.binding_str("display_name"), Some("Pump House (a01)"));
+
+        let unresolved = yaml.replace("{{max}}", "{{maximum}}");
+        assert!(crate::module::ConfigValidator::validate(&unresolved).is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::module::{get_supported_templates, ModuleCapabilities, ModuleConfig, ValidationError};
+use crate::util::log::LogLevel;
+
+/// Fluent construction of a [`ModuleConfig`] for generated modules and tests.
+/// Everything not set is left out, as if omitted from `config.yml`.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleConfigBuilder {
+    name: String,
+    module_type: String,
+    bus_topic: Option<String>,
+    template: String,
+    bindings: HashMap<String, serde_json::Value>,
+    icon: Option<String>,
+    accent_color: Option<String>,
+    pinned: Option<bool>,
+    sort_order: Option<i32>,
+    capabilities: Option<ModuleCapabilities>,
+    log_level: Option<String>,
+}
+
+impl ModuleConfig {
+    pub fn builder() -> ModuleConfigBuilder {
+        ModuleConfigBuilder::default()
+    }
+}
+
+impl ModuleConfigBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn module_type(mut self, module_type: impl Into<String>) -> Self {
+        self.module_type = module_type.into();
+        self
+    }
+
+    /// Defaults to the name, lowercased with spaces as underscores
+    pub fn bus_topic(mut self, bus_topic: impl Into<String>) -> Self {
+        self.bus_topic = Some(bus_topic.into());
+        self
+    }
+
+    /// Left empty for modules without a dashboard card
+    pub fn template(mut self, template: impl Into<String>) -> Self {
+        self.template = template.into();
+        self
+    }
+
+    pub fn binding(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.bindings.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn bindings(mut self, bindings: HashMap<String, serde_json::Value>) -> Self {
+        self.bindings.extend(bindings);
+        self
+    }
+
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn accent_color(mut self, accent_color: impl Into<String>) -> Self {
+        self.accent_color = Some(accent_color.into());
+        self
+    }
+
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = Some(pinned);
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: i32) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    pub fn capabilities(mut self, capabilities: ModuleCapabilities) -> Self {
+        self.capabilities = Some(capabilities);
+        self
+    }
+
+    pub fn log_level(mut self, log_level: impl Into<String>) -> Self {
+        self.log_level = Some(log_level.into());
+        self
+    }
+
+    /// The config, after the checks `ConfigValidator` makes of every manifest: a name and type,
+    /// a known template (or none), and a parseable accent color and log level
+    pub fn build(self) -> Result<ModuleConfig, ValidationError> {
+        let invalid = |field: &str, error: String| ValidationError { field: field.to_string(), error };
+
+        if self.name.trim().is_empty() {
+            return Err(invalid("name", "Missing required field".to_string()));
+        }
+        if self.module_type.trim().is_empty() {
+            return Err(invalid("module_type", "Missing required field".to_string()));
+        }
+        if !self.template.is_empty() && !get_supported_templates().contains(&self.template.as_str()) {
+            return Err(invalid("template", format!(
+                "Unsupported template: {}. Must be one of: {:?}", self.template, get_supported_templates()
+            )));
+        }
+        if let Some(color) = &self.accent_color {
+            if ratatui::style::Color::from_str(color.trim()).is_err() {
+                return Err(invalid("accent_color", format!(
+                    "Unrecognized color: {}. Use a name like 'light-blue' or '#rrggbb'", color
+                )));
+            }
+        }
+        if let Some(level) = &self.log_level {
+            if LogLevel::parse(level).is_none() {
+                return Err(invalid("log_level", format!(
+                    "Unrecognized log level: {}. Must be one of: error, warn, info, debug", level
+                )));
+            }
+        }
+
+        let bus_topic = self.bus_topic.unwrap_or_else(|| self.name.to_lowercase().replace(' ', "_"));
+        Ok(ModuleConfig {
+            name: self.name,
+            module_type: self.module_type,
+            bus_topic,
+            template: self.template,
+            bindings: self.bindings,
+            ports: None,
+            messages: None,
+            game_type: None,
+            model: None,
+            view_type: None,
+            thresholds: None,
+            rules: None,
+            icon: self.icon,
+            accent_color: self.accent_color,
+            pinned: self.pinned,
+            sort_order: self.sort_order,
+            capabilities: self.capabilities,
+            log_level: self.log_level,
+            auto_refresh_secs: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module::ModuleConfig;
+
+    #[test]
+    fn test_builds_valid_configs_only() {
+        let config = ModuleConfig::builder()
+            .name("Pump House")
+            .module_type("monitoring")
+            .template("gauge_card")
+            .binding("max_value", 120.0)
+            .build()
+            .unwrap();
+        assert_eq!(config.bus_topic, "pump_house");
+        assert_eq!(config.binding_f64("max_value"), Some(120.0));
+
+        let yaml = serde_yaml::to_string(&config).unwrap();
+        assert!(!yaml.contains("null"), "unset fields are left out: {}", yaml);
+
+        let unsupported = ModuleConfig::builder().name("Pump").module_type("monitoring").template("dial").build();
+        assert_eq!(unsupported.unwrap_err().field, "template");
+        assert_eq!(ModuleConfig::builder().module_type("monitoring").build().unwrap_err().field, "name");
+    }
+}
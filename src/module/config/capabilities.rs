@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::util::io::bus::topic_in_scope;
+
+/// What a module declares it needs from the host. Reviewed at install time,
+/// and `publish_topics` is enforced by the module's scoped message bus.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModuleCapabilities {
+    #[serde(default)]
+    pub network: bool,
+    #[serde(default)]
+    pub gpio: bool,
+    #[serde(default)]
+    pub audio: bool,
+    /// Topics the module may publish to. `*` allows everything, a trailing `*` matches a prefix.
+    #[serde(default)]
+    pub publish_topics: Vec<String>,
+}
+
+impl ModuleCapabilities {
+    pub fn allows_publish(&self, topic: &str) -> bool {
+        topic_in_scope(&self.publish_topics, topic)
+    }
+
+    /// One line per declared capability, for the install review
+    pub fn summary_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.network {
+            lines.push("🌐 Network access".to_string());
+        }
+        if self.gpio {
+            lines.push("🔧 GPIO / hardware pins".to_string());
+        }
+        if self.audio {
+            lines.push("🔊 Audio output".to_string());
+        }
+        if self.publish_topics.is_empty() {
+            lines.push("📡 Publishes: nothing".to_string());
+        } else {
+            lines.push(format!("📡 Publishes: {}", self.publish_topics.join(", ")));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_scope_matching() {
+        let caps = ModuleCapabilities {
+            publish_topics: vec!["valve_*".to_string(), "com_input".to_string()],
+            ..Default::default()
+        };
+
+        assert!(caps.allows_publish("valve_main"));
+        assert!(caps.allows_publish("com_input"));
+        assert!(!caps.allows_publish("com_input_extra"));
+        assert!(!caps.allows_publish("app.event.quit"));
+        assert!(ModuleCapabilities { publish_topics: vec!["*".to_string()], ..Default::default() }
+            .allows_publish("anything"));
+        assert!(!ModuleCapabilities::default().allows_publish("anything"));
+    }
+}
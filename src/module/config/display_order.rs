@@ -0,0 +1,40 @@
+use std::cmp::Ordering;
+
+use crate::module::ModuleConfig;
+
+impl ModuleConfig {
+    /// Dashboard ordering: pinned modules first, then explicit `sort_order`
+    /// (ascending, unset last), then case-insensitive name
+    pub fn display_order(&self, other: &Self) -> Ordering {
+        let key = |c: &Self| (
+            !c.pinned.unwrap_or(false),
+            c.sort_order.is_none(),
+            c.sort_order.unwrap_or(0),
+        );
+
+        key(self)
+            .cmp(&key(other))
+            .then_with(|| self.name.to_lowercase().cmp(&other.name.to_lowercase()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test_util::TestModule;
+
+    #[test]
+    fn test_pinned_then_sort_order_then_name() {
+        let mut configs = vec![
+            TestModule::new("beta", "system", "").build().config,
+            TestModule::new("Alpha", "system", "").build().config,
+            TestModule::new("water", "system", "").sort_order(2).build().config,
+            TestModule::new("power", "system", "").sort_order(1).build().config,
+            TestModule::new("zeta", "system", "").pinned().build().config,
+        ];
+
+        configs.sort_by(|a, b| a.display_order(b));
+        let names: Vec<&str> = configs.iter().map(|c| c.name.as_str()).collect();
+
+        assert_eq!(names, vec!["zeta", "power", "water", "Alpha", "beta"]);
+    }
+}
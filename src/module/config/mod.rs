@@ -3,6 +3,13 @@ mod service_discovery_config;
 mod generic_config;
 mod typed_module_config;
 mod is_blinkable;
+mod appearance;
+mod display_order;
+mod capabilities;
+mod binding_expressions;
+mod secret_interpolation;
+mod builder;
+mod typed_bindings;
 mod validation;
 
 use std::collections::HashMap;
@@ -13,13 +20,19 @@ pub use crate::module::strategies::{
     com::{ComConfig, ComBindings},
     knowledge::{KnowledgeConfig, KnowledgeBindings},
     llm::{LlmConfig, LlmBindings},
-    monitoring::{MonitoringConfig, MonitoringBindings},
+    monitoring::{MonitoringConfig, MonitoringBindings, StatusState},
     overseer::{OverseerConfig, OverseerBindings},
     side_quest::{SideQuestConfig, SideQuestBindings},
     valve_control::{ValveControlConfig, ValveControlBindings},
 };
 
 pub use base_module_config::BaseModuleConfig;
+pub use builder::ModuleConfigBuilder;
+pub use capabilities::ModuleCapabilities;
+pub use binding_expressions::resolve_binding_expressions;
+pub(crate) use binding_expressions::resolve_generic_binding_expressions;
+pub use secret_interpolation::interpolate_secrets;
+pub use typed_bindings::FromBinding;
 pub use generic_config::GenericConfig;
 pub use typed_module_config::TypedModuleConfig;
 pub use service_discovery_config::ServiceDiscoveryConfig;
@@ -34,13 +47,44 @@ pub struct ModuleConfig {
     pub bindings: HashMap<String, serde_json::Value>,
 
     // Optional fields for different module types
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ports: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub game_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub view_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thresholds: Option<HashMap<String, f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rules: Option<HashMap<String, String>>,
+
+    // Optional dashboard appearance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent_color: Option<String>,
+
+    // Optional dashboard ordering - pinned first, then sort_order, then name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<i32>,
+
+    // Optional sandboxing - modules without a declaration are unrestricted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<ModuleCapabilities>,
+
+    // Optional log level for this module's handler (error, warn, info, debug)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<String>,
+
+    // Optional interval for re-reading this module's manifest from disk (off when unset or 0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_refresh_secs: Option<u64>,
 }
 
 
@@ -53,6 +97,7 @@ pub fn get_supported_templates() -> Vec<&'static str> {
         "toggle_switch",
         "activity_card",
         "llm_card",
+        "knowledge_card",
         "side_quest_card",
         "overseer_card",
         "",  // Empty template for hidden modules
@@ -0,0 +1,173 @@
+use std::{cell::OnceCell, collections::HashMap, fs, path::PathBuf};
+
+use serde_json::Value;
+
+use super::{ModuleConfig, ValidationError};
+use crate::util::log::register_secret;
+
+const OPEN: &str = "${";
+const CLOSE: &str = "}";
+/// `\${` stands for a literal `${`
+const ESCAPED_OPEN: &str = "\\${";
+
+/// Replace `${NAME}` references in binding values with environment variables, in place.
+///
+/// Variables missing from the environment are looked up in the `KEY=value` file named by
+/// `SECRETS_FILE`, when set; the file is only read if one is missing. Every substituted value is
+/// registered with the logger so it is masked in log output. A reference that resolves nowhere
+/// is an error naming the field and the variable, which fails only this module's load.
+pub fn interpolate_secrets(config: &mut ModuleConfig) -> Result<(), ValidationError> {
+    let secrets_file = OnceCell::new();
+    interpolate_with(config, |name| {
+        if let Ok(value) = std::env::var(name) {
+            return Ok(value);
+        }
+        match secrets_file.get_or_init(load_secrets_file) {
+            None => Err(format!("Environment variable '{}' is not set (and SECRETS_FILE isn't either)", name)),
+            Some(Err(e)) => Err(format!("Environment variable '{}' is not set and {}", name, e)),
+            Some(Ok((path, secrets))) => secrets.get(name).cloned().ok_or_else(|| {
+                format!("Environment variable '{}' is not set and not in {}", name, path.display())
+            }),
+        }
+    })
+}
+
+fn interpolate_with(
+    config: &mut ModuleConfig,
+    lookup: impl Fn(&str) -> Result<String, String>,
+) -> Result<(), ValidationError> {
+    for (key, value) in config.bindings.iter_mut() {
+        interpolate_value(value, &format!("bindings.{}", key), &lookup)?;
+    }
+    Ok(())
+}
+
+fn interpolate_value(
+    value: &mut Value,
+    field: &str,
+    lookup: &impl Fn(&str) -> Result<String, String>,
+) -> Result<(), ValidationError> {
+    match value {
+        Value::String(text) if text.contains(OPEN) => {
+            *text = interpolate(text, field, lookup)?;
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                interpolate_value(item, &format!("{}[{}]", field, index), lookup)?;
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                interpolate_value(item, &format!("{}.{}", field, key), lookup)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn interpolate(
+    text: &str,
+    field: &str,
+    lookup: &impl Fn(&str) -> Result<String, String>,
+) -> Result<String, ValidationError> {
+    let error = |message: String| ValidationError { field: field.to_string(), error: message };
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix(ESCAPED_OPEN) {
+            out.push_str(OPEN);
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix(OPEN) {
+            let end = after.find(CLOSE).ok_or_else(|| error("Unclosed '${'".to_string()))?;
+            let name = after[..end].trim();
+            if name.is_empty() {
+                return Err(error("Empty '${}' reference".to_string()));
+            }
+            let secret = lookup(name).map_err(error)?;
+            register_secret(&secret);
+            out.push_str(&secret);
+            rest = &after[end + CLOSE.len()..];
+        } else {
+            let ch = rest.chars().next().unwrap();
+            out.push(ch);
+            rest = &rest[ch.len_utf8()..];
+        }
+    }
+
+    Ok(out)
+}
+
+/// `KEY=value` lines from `SECRETS_FILE` with its path, or None when it isn't set.
+/// Blank lines and `#` comments are skipped.
+fn load_secrets_file() -> Option<Result<(PathBuf, HashMap<String, String>), String>> {
+    let path = std::env::var_os("SECRETS_FILE").map(PathBuf::from)?;
+    Some(match fs::read_to_string(&path) {
+        Ok(content) => Ok((path, parse_secrets(&content))),
+        Err(e) => Err(format!("SECRETS_FILE {} could not be read: {}", path.display(), e)),
+    })
+}
+
+fn parse_secrets(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| {
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+                .unwrap_or(value);
+            (key.trim().to_string(), value.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn config(bindings: Value) -> ModuleConfig {
+        serde_json::from_value(json!({
+            "name": "Assistant",
+            "module_type": "llm",
+            "bus_topic": "llm",
+            "template": "llm_card",
+            "bindings": bindings,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_interpolates_variables_and_escapes() {
+        let secrets = parse_secrets("# comment\nAPI_KEY=\"sk-live-123\"\n\nREGION = us-east\n");
+        let mut config = config(json!({
+            "api_key": "${API_KEY}",
+            "endpoint": "https://${REGION}.example.com",
+            "hint": "use \\${API_KEY} in yaml",
+            "headers": [{ "x-region": "${ REGION }" }],
+        }));
+
+        interpolate_with(&mut config, |name| secrets.get(name).cloned().ok_or_else(|| name.to_string())).unwrap();
+
+        let bindings = &config.bindings;
+        assert_eq!(bindings["api_key"], json!("sk-live-123"));
+        assert_eq!(bindings["endpoint"], json!("https://us-east.example.com"));
+        assert_eq!(bindings["hint"], json!("use ${API_KEY} in yaml"));
+        assert_eq!(bindings["headers"], json!([{ "x-region": "us-east" }]));
+    }
+
+    #[test]
+    fn test_missing_variable_names_field() {
+        let mut config = config(json!({ "api_key": "${MISSING_KEY}" }));
+        let err = interpolate_with(&mut config, |name| Err(format!("'{}' is not set", name))).unwrap_err();
+
+        assert_eq!(err.field, "bindings.api_key");
+        assert!(err.error.contains("MISSING_KEY"));
+    }
+}
@@ -0,0 +1,151 @@
+use serde_json::Value;
+
+use crate::module::{ModuleConfig, ValidationError};
+
+/// A type a binding can be read as. Values of another type read as `None`, except that
+/// numbers and booleans written as strings (`"42.5"`, `"true"`) are accepted, and whole
+/// floats (`500.0`) read as integers.
+pub trait FromBinding<'a>: Sized {
+    fn from_binding(value: &'a Value) -> Option<Self>;
+}
+
+impl<'a> FromBinding<'a> for &'a str {
+    fn from_binding(value: &'a Value) -> Option<Self> {
+        value.as_str()
+    }
+}
+
+impl<'a> FromBinding<'a> for String {
+    fn from_binding(value: &'a Value) -> Option<Self> {
+        value.as_str().map(str::to_string)
+    }
+}
+
+impl<'a> FromBinding<'a> for &'a [Value] {
+    fn from_binding(value: &'a Value) -> Option<Self> {
+        value.as_array().map(Vec::as_slice)
+    }
+}
+
+impl<'a> FromBinding<'a> for f64 {
+    fn from_binding(value: &'a Value) -> Option<Self> {
+        match value {
+            Value::String(s) => s.trim().parse().ok(),
+            _ => value.as_f64(),
+        }
+    }
+}
+
+impl<'a> FromBinding<'a> for i64 {
+    fn from_binding(value: &'a Value) -> Option<Self> {
+        value.as_i64().or_else(|| whole(f64::from_binding(value)?, i64::MIN as f64, i64::MAX as f64).map(|n| n as i64))
+    }
+}
+
+impl<'a> FromBinding<'a> for u64 {
+    fn from_binding(value: &'a Value) -> Option<Self> {
+        value.as_u64().or_else(|| whole(f64::from_binding(value)?, 0.0, u64::MAX as f64).map(|n| n as u64))
+    }
+}
+
+impl<'a> FromBinding<'a> for bool {
+    fn from_binding(value: &'a Value) -> Option<Self> {
+        match value {
+            Value::Bool(b) => Some(*b),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+fn whole(n: f64, min: f64, max: f64) -> Option<f64> {
+    (n.fract() == 0.0 && (min..=max).contains(&n)).then_some(n)
+}
+
+impl ModuleConfig {
+    /// Binding `key` as `T`, or `None` when it's missing or holds another type
+    pub fn binding<'a, T: FromBinding<'a>>(&'a self, key: &str) -> Option<T> {
+        self.bindings.get(key).and_then(T::from_binding)
+    }
+
+    /// Binding `key` as `T`, or `default` when it's missing or holds another type
+    pub fn binding_or<'a, T: FromBinding<'a>>(&'a self, key: &str, default: T) -> T {
+        self.binding(key).unwrap_or(default)
+    }
+
+    /// Binding `key` as `T`, or `None` when it's missing or null. Any other type is an error on
+    /// `bindings.<key>` saying it must be `expected` ("a number", "a string").
+    pub fn try_binding<'a, T: FromBinding<'a>>(&'a self, key: &str, expected: &str) -> Result<Option<T>, ValidationError> {
+        match self.bindings.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => T::from_binding(value).map(Some).ok_or_else(|| ValidationError {
+                field: format!("bindings.{}", key),
+                error: format!("Must be {}", expected),
+            }),
+        }
+    }
+
+    pub fn binding_str(&self, key: &str) -> Option<&str> {
+        self.binding(key)
+    }
+
+    pub fn binding_f64(&self, key: &str) -> Option<f64> {
+        self.binding(key)
+    }
+
+    pub fn binding_i64(&self, key: &str) -> Option<i64> {
+        self.binding(key)
+    }
+
+    pub fn binding_u64(&self, key: &str) -> Option<u64> {
+        self.binding(key)
+    }
+
+    pub fn binding_bool(&self, key: &str) -> Option<bool> {
+        self.binding(key)
+    }
+
+    pub fn binding_array(&self, key: &str) -> Option<&[Value]> {
+        self.binding(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::util::test_util::TestModule;
+
+    #[test]
+    fn test_typed_bindings_coerce_consistently() {
+        let config = TestModule::new("Tank", "monitoring", "gauge_card")
+            .binding("a", json!(42.5))
+            .binding("max_value", json!("150"))
+            .binding("blink_interval_ms", json!(500.0))
+            .binding("is_connected", json!("true"))
+            .binding("display_name", json!("Water Tank"))
+            .binding("c", json!(-3))
+            .build()
+            .config;
+
+        assert_eq!(config.binding_f64("a"), Some(42.5));
+        assert_eq!(config.binding_f64("max_value"), Some(150.0));
+        assert_eq!(config.binding_u64("blink_interval_ms"), Some(500));
+        assert_eq!(config.binding_u64("a"), None);
+        assert_eq!(config.binding_u64("c"), None);
+        assert_eq!(config.binding_i64("c"), Some(-3));
+        assert_eq!(config.binding_bool("is_connected"), Some(true));
+        assert_eq!(config.binding_str("display_name"), Some("Water Tank"));
+        // Wrong type and missing both fall back
+        assert_eq!(config.binding_str("a"), None);
+        assert_eq!(config.binding_or("display_name", 0.0), 0.0);
+        assert_eq!(config.binding_or("unit", "%"), "%");
+        assert!(!config.binding_or("show_ticks", false));
+
+        // Missing is fine, the wrong type names the field
+        assert_eq!(config.try_binding::<f64>("unit", "a number"), Ok(None));
+        assert_eq!(config.try_binding::<f64>("max_value", "a number"), Ok(Some(150.0)));
+        let error = config.try_binding::<f64>("display_name", "a number").unwrap_err();
+        assert_eq!((error.field.as_str(), error.error.as_str()), ("bindings.display_name", "Must be a number"));
+    }
+}
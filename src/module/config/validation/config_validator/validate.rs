@@ -1,20 +1,26 @@
+use std::str::FromStr;
+
 use crate::{
     log_debug,
     module::{
-        config::validation::{
-            config_validator::ConfigValidator,
-            error::ValidationError
+        config::{
+            resolve_generic_binding_expressions,
+            validation::{
+                config_validator::ConfigValidator,
+                error::ValidationError
+            },
         },
         get_supported_templates,
         TypedModuleConfig,
-    }
+    },
+    util::log::LogLevel,
 };
 
 impl ConfigValidator {
     /// Validate a module config against its declared type
     pub fn validate(config_yaml: &str) -> color_eyre::Result<TypedModuleConfig> {
         // First, deserialize as generic to get module_type
-        let generic: serde_json::Value = serde_yaml::from_str(config_yaml)
+        let mut generic: serde_json::Value = serde_yaml::from_str(config_yaml)
             .map_err(|e| ValidationError {
                 field: "yaml".to_string(),
                 error: format!("Failed to parse YAML: {}", e),
@@ -42,8 +48,31 @@ impl ConfigValidator {
             }
         }
 
+        // Validate accent color if present
+        if let Some(color) = generic.get("accent_color").and_then(|v| v.as_str()) {
+            if ratatui::style::Color::from_str(color.trim()).is_err() {
+                return Err(ValidationError {
+                    field: "accent_color".to_string(),
+                    error: format!("Unrecognized color: {}. Use a name like 'light-blue' or '#rrggbb'", color),
+                }.into());
+            }
+        }
+
+        // Validate log level if present
+        if let Some(level) = generic.get("log_level").and_then(|v| v.as_str()) {
+            if LogLevel::parse(level).is_none() {
+                return Err(ValidationError {
+                    field: "log_level".to_string(),
+                    error: format!("Unrecognized log level: {}. Must be one of: error, warn, info, debug", level),
+                }.into());
+            }
+        }
+
+        // Check bindings as loading will see them, with `{{name}}` references expanded
+        resolve_generic_binding_expressions(&mut generic)?;
+
         // Now deserialize with proper type
-        let typed_config: TypedModuleConfig = serde_yaml::from_str(config_yaml)?;
+        let typed_config: TypedModuleConfig = serde_yaml::from_str(&serde_yaml::to_string(&generic)?)?;
 
         // Type-specific validation
         match &typed_config {
@@ -59,6 +88,9 @@ impl ConfigValidator {
             TypedModuleConfig::Com(cfg) => {
                 Self::validate_com(cfg)?;
             }
+            TypedModuleConfig::Overseer(cfg) => {
+                Self::validate_overseer(cfg)?;
+            }
             _ => {
                 // Other types have minimal validation requirements
             }
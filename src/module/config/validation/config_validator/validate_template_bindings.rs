@@ -26,6 +26,9 @@ impl ConfigValidator {
             "llm_card" => vec![
                 "model_info", "chat_history", "chat_input",
             ],
+            "knowledge_card" => vec![
+                "recent_documents", "search_query", "search_results",
+            ],
             _ => vec![],
         };
 
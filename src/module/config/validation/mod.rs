@@ -69,6 +69,36 @@ module_type: invalid_type
 bus_topic: "test"
 template: ""
 bindings: {}
+"#;
+
+        let result = ConfigValidator::validate(yaml);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_invalid_accent_color() {
+        let yaml = r#"
+name: "Test"
+module_type: system
+bus_topic: "test"
+template: ""
+accent_color: "not-a-color"
+bindings: {}
+"#;
+
+        let result = ConfigValidator::validate(yaml);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_invalid_log_level() {
+        let yaml = r#"
+name: "Test"
+module_type: system
+bus_topic: "test"
+template: ""
+log_level: "verbose"
+bindings: {}
 "#;
 
         let result = ConfigValidator::validate(yaml);
@@ -4,6 +4,7 @@ use crate::{
 };
 
 impl Module {
+    /// The module's template, created the first time the card is drawn (or after it was evicted)
     pub fn get_template(&mut self) -> std::result::Result<&Box<dyn UiTemplate>, String> {
         if self.cached_template.is_none() {
             let template = get_template(&self.config.template)
@@ -0,0 +1,87 @@
+use ratatui::crossterm::event::KeyCode;
+
+use crate::module::Module;
+
+/// Short label for a key as shown in hints: `[Esc]`, `[↑]`, `[t]`
+pub fn key_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "Spc".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Enter => "Ent".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => "⇧Tab".to_string(),
+        KeyCode::Backspace => "Bksp".to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::Delete => "Del".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        other => format!("{:?}", other),
+    }
+}
+
+/// Hints with consecutive keys sharing a label merged: `("[↑]/[↓]", "Select")`
+pub fn group_key_hints<S: AsRef<str>>(hints: &[(KeyCode, S)]) -> Vec<(String, String)> {
+    let mut groups: Vec<(Vec<String>, &str)> = Vec::new();
+    for (key, label) in hints {
+        let label = label.as_ref();
+        match groups.last_mut() {
+            Some((keys, last)) if *last == label => keys.push(format!("[{}]", key_label(*key))),
+            _ => groups.push((vec![format!("[{}]", key_label(*key))], label)),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(keys, label)| (keys.join("/"), label.to_string()))
+        .collect()
+}
+
+/// One footer line: `[↑]/[↓] Select  [Esc] Back`
+pub fn format_key_hints<S: AsRef<str>>(hints: &[(KeyCode, S)]) -> String {
+    group_key_hints(hints)
+        .iter()
+        .map(|(keys, label)| format!("{} {}", keys, label))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+impl Module {
+    /// Mirror a handler's key hints into the `key_hints` binding templates use as their help footer
+    pub fn apply_key_hints(&mut self, hints: &[(KeyCode, &str)]) {
+        if hints.is_empty() {
+            self.config.bindings.remove("key_hints");
+        } else {
+            self.config.bindings.insert("key_hints".to_string(), serde_json::json!(format_key_hints(hints)));
+        }
+    }
+
+    pub fn key_hints_text(&self) -> Option<&str> {
+        self.config.binding_str("key_hints")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_collapses_keys_sharing_a_label() {
+        let hints = [
+            (KeyCode::Up, "Select"),
+            (KeyCode::Down, "Select"),
+            (KeyCode::Enter, "Open"),
+            (KeyCode::Char('t'), "Trust"),
+            (KeyCode::Esc, "Back"),
+        ];
+
+        assert_eq!(format_key_hints(&hints), "[↑]/[↓] Select  [Ent] Open  [t] Trust  [Esc] Back");
+        assert_eq!(format_key_hints::<&str>(&[]), "");
+    }
+}
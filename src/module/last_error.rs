@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex};
+
+use crate::module::Module;
+
+/// Most recent failure reported by a handler or one of its background tasks.
+///
+/// Clones share the same slot, so a handler can hand a clone to a spawned task
+/// and surface whatever it records on the next `update_bindings`.
+#[derive(Debug, Clone, Default)]
+pub struct LastError(Arc<Mutex<Option<(DateTime<Utc>, String)>>>);
+
+impl LastError {
+    pub fn set(&self, message: impl Into<String>) {
+        *self.0.lock().unwrap() = Some((Utc::now(), message.into()));
+    }
+
+    pub fn clear(&self) {
+        *self.0.lock().unwrap() = None;
+    }
+
+    pub fn get(&self) -> Option<(DateTime<Utc>, String)> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl Module {
+    /// Mirror a handler's last error into the `last_error` / `last_error_at` bindings
+    pub fn apply_last_error(&mut self, last_error: Option<(DateTime<Utc>, String)>) {
+        match last_error {
+            Some((at, message)) => {
+                self.config.bindings.insert("last_error".to_string(), serde_json::json!(message));
+                self.config.bindings.insert("last_error_at".to_string(), serde_json::json!(at.to_rfc3339()));
+            }
+            None => {
+                self.config.bindings.remove("last_error");
+                self.config.bindings.remove("last_error_at");
+            }
+        }
+    }
+
+    pub fn last_error_message(&self) -> Option<&str> {
+        self.config.binding_str("last_error")
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+
+use crate::module::Module;
+
+impl Module {
+    /// Mirror when a handler's data last changed into the `last_updated` binding
+    pub fn apply_last_updated(&mut self, at: Option<DateTime<Utc>>) {
+        match at {
+            Some(at) => {
+                self.config.bindings.insert("last_updated".to_string(), serde_json::json!(at.to_rfc3339()));
+            }
+            None => {
+                self.config.bindings.remove("last_updated");
+            }
+        }
+    }
+
+    /// `last_updated` as set by a handler (RFC 3339) or written into a manifest (RFC 3339 or unix seconds)
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        match self.config.bindings.get("last_updated")? {
+            serde_json::Value::String(s) => DateTime::parse_from_rfc3339(s).ok().map(|at| at.with_timezone(&Utc)),
+            serde_json::Value::Number(n) => DateTime::from_timestamp(n.as_i64()?, 0),
+            _ => None,
+        }
+    }
+}
+
+/// Coarse age for card chrome: "4s ago", "12m ago", "3h ago", "2d ago"
+pub fn format_age(age: chrono::Duration) -> String {
+    let secs = age.num_seconds().max(0);
+    match secs {
+        0..=59 => format!("{}s ago", secs),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86399 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_util::TestModule;
+
+    #[test]
+    fn test_last_updated_round_trips_and_formats() {
+        let at = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let mut module = TestModule::new("Tank", "monitoring", "gauge_card").build();
+        assert_eq!(module.last_updated(), None);
+
+        module.apply_last_updated(Some(at));
+        assert_eq!(module.last_updated(), Some(at));
+        module.apply_last_updated(None);
+        assert_eq!(module.last_updated(), None);
+
+        let module = TestModule::new("Tank", "monitoring", "gauge_card")
+            .binding("last_updated", serde_json::json!(1_700_000_000))
+            .build();
+        assert_eq!(module.last_updated(), Some(at));
+
+        assert_eq!(format_age(chrono::Duration::seconds(4)), "4s ago");
+        assert_eq!(format_age(chrono::Duration::seconds(150)), "2m ago");
+        assert_eq!(format_age(chrono::Duration::hours(5)), "5h ago");
+        assert_eq!(format_age(chrono::Duration::seconds(-3)), "0s ago");
+    }
+}
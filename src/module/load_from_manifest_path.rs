@@ -4,12 +4,17 @@ use std::{
 };
 
 use super::{Module, ModuleConfig, ModuleRenderState};
+use super::config::{interpolate_secrets, resolve_binding_expressions};
 
 impl Module {
     pub fn load_from_manifest_path(path: &Path) -> color_eyre::Result<Self> {
         let config_path = path.join("config.yml");
         let config_content = fs::read_to_string(&config_path)?;
-        let config: ModuleConfig = serde_yaml::from_str(&config_content)?;
+        let mut config: ModuleConfig = serde_yaml::from_str(&config_content)?;
+
+        let module_dir = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        resolve_binding_expressions(&mut config, &module_dir)?;
+        interpolate_secrets(&mut config)?;
 
         Ok(Module {
             config,
@@ -1,6 +1,10 @@
 pub mod trait_module_handler;
 pub mod strategies;
 pub mod config;
+pub mod last_error;
+pub mod last_updated;
+pub mod key_hints;
+pub mod quick_action;
 
 mod render_state;
 mod module_manager;
@@ -11,6 +15,7 @@ mod render_overview_cta;
 mod render_detail;
 mod has_knowledge_dir;
 mod get_view_type;
+mod text_summary;
 
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -18,8 +23,10 @@ use ratatui::prelude::*;
 
 pub use config::*;
 pub use render_state::ModuleRenderState;
-pub use module_manager::{ModuleManager, ModuleManagerView};
+pub use module_manager::{HandlerInitProgress, InitProgressSender, ModuleManager, ModuleManagerView};
 pub use trait_module_handler::ModuleHandler;
+pub use last_error::LastError;
+pub use quick_action::QuickAction;
 
 use crate::ui::template::UiTemplate;
 
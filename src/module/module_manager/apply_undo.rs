@@ -0,0 +1,13 @@
+use color_eyre::Result;
+
+use crate::module::ModuleManager;
+use crate::runtime::undo::UndoDirection;
+
+impl ModuleManager {
+    /// Hand a recorded operation back to the handler registered as `handler_key`.
+    /// `None` when this manager has no such handler.
+    pub fn apply_undo(&mut self, handler_key: &str, op: &mut serde_json::Value, direction: UndoDirection) -> Option<Result<()>> {
+        let handler = self.handlers.get_mut(handler_key)?;
+        Some(handler.apply_undo(op, direction))
+    }
+}
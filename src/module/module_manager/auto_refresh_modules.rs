@@ -0,0 +1,87 @@
+use std::time::{Duration, Instant};
+
+use crate::log_warn;
+use crate::module::ModuleManager;
+
+impl ModuleManager {
+    /// Reload every module whose `auto_refresh_secs` has elapsed since it was last read.
+    /// Returns whether any module was reloaded, so the caller can redraw.
+    pub fn auto_refresh_modules(&mut self) -> bool {
+        let now = Instant::now();
+        let mut reloaded = false;
+
+        for idx in 0..self.modules.len() {
+            let module = &self.modules[idx];
+            let Some(secs) = module.config.auto_refresh_secs.filter(|secs| *secs > 0) else {
+                continue;
+            };
+
+            // The first sighting starts the clock; the manifest was just read by discovery
+            let last = *self.auto_refreshed.entry(module.path.clone()).or_insert(now);
+            if now.duration_since(last) < Duration::from_secs(secs) {
+                continue;
+            }
+            self.auto_refreshed.insert(module.path.clone(), now);
+
+            match self.reload_module(idx) {
+                Ok(()) => reloaded = true,
+                Err(e) => log_warn!(
+                    "Auto-refresh of {} failed, keeping the last good config: {}",
+                    self.modules[idx].config.name,
+                    e
+                ),
+            }
+        }
+
+        reloaded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::util::test_util::MockHandler;
+
+    fn write_manifest(dir: &std::path::Path, reading: u32) {
+        fs::write(
+            dir.join("tank").join("config.yml"),
+            format!(
+                "name: \"Tank\"\nmodule_type: \"system\"\nbus_topic: \"\"\ntemplate: \"\"\n\
+                 auto_refresh_secs: 60\nbindings:\n  level: {}\n",
+                reading
+            ),
+        ).unwrap();
+    }
+
+    #[test]
+    fn test_reloads_only_after_the_interval() {
+        let dir: PathBuf = std::env::temp_dir().join(format!("survon_auto_refresh_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(dir.join("tank")).unwrap();
+        write_manifest(&dir, 40);
+
+        let mut manager = ModuleManager::new(dir.clone(), "wasteland".to_string());
+        manager.discover_modules().unwrap();
+        manager.register_handler(Box::new(MockHandler::new("system")));
+        assert!(!manager.auto_refresh_modules());
+
+        write_manifest(&dir, 75);
+        assert!(!manager.auto_refresh_modules());
+        assert_eq!(manager.get_modules()[0].config.bindings["level"], 40);
+
+        // Pretend the last read was a minute ago
+        let path = manager.get_modules()[0].path.clone();
+        manager.auto_refreshed.insert(path, Instant::now() - Duration::from_secs(61));
+        assert!(manager.auto_refresh_modules());
+        assert_eq!(manager.get_modules()[0].config.bindings["level"], 75);
+
+        // The handler re-read its own data and refilled its bindings
+        let handler = manager.get_handler("system").unwrap().as_any().downcast_ref::<MockHandler>().unwrap();
+        assert_eq!(handler.reloads, 1);
+        assert_eq!(manager.get_modules()[0].config.bindings["mock_updates"], 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -1,8 +1,12 @@
 use std::fs;
 
+use crate::log_error;
 use crate::module::{Module, ModuleManager};
+use crate::runtime::toast::{notify, ToastLevel};
 
 impl ModuleManager {
+    /// Load every module under the manifests directory. A manifest that fails to load is logged,
+    /// reported to observers and skipped; only an unreadable directory is an error.
     pub fn discover_modules(&mut self) -> color_eyre::Result<()> {
         self.modules.clear();
 
@@ -15,15 +19,25 @@ impl ModuleManager {
             let entry = entry?;
             let path = entry.path();
 
+            // Dot-directories hold archives and staged updates, not modules
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+
             if path.is_dir() {
                 let config_path = path.join("config.yml");
                 if config_path.exists() {
                     match Module::load_from_manifest_path(&path) {
                         Ok(module) => {
+                            self.notify_observers(|o| o.on_module_loaded(&self.namespace, &module));
                             self.modules.push(module);
                         }
                         Err(e) => {
-                            panic!("Failed to load manifest at {:?}: {}", path, e);
+                            let error = e.to_string();
+                            self.notify_observers(|o| o.on_validation_error(&self.namespace, &path, &error));
+                            log_error!("❌ Skipping manifest at {:?}: {}", path, e);
+                            let dir = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+                            notify(ToastLevel::Error, format!("Module '{}' not loaded: {}", dir, error));
                         }
                     }
                 }
@@ -33,3 +47,36 @@ impl ModuleManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn test_bad_manifest_is_skipped() {
+        let dir: PathBuf = std::env::temp_dir().join(format!("survon_discover_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(dir.join("gauge")).unwrap();
+        fs::create_dir_all(dir.join("broken")).unwrap();
+        fs::write(
+            dir.join("gauge").join("config.yml"),
+            "name: \"Gauge\"\nmodule_type: \"system\"\nbus_topic: \"\"\ntemplate: \"\"\nbindings: {}\n",
+        ).unwrap();
+        fs::write(dir.join("broken").join("config.yml"), "name: [unterminated\n").unwrap();
+        fs::create_dir_all(dir.join("keyed")).unwrap();
+        fs::write(
+            dir.join("keyed").join("config.yml"),
+            "name: \"Keyed\"\nmodule_type: \"system\"\nbus_topic: \"\"\ntemplate: \"\"\nbindings:\n  api_key: \"${SURVON_TEST_UNSET_SECRET}\"\n",
+        ).unwrap();
+
+        let mut manager = ModuleManager::new(dir.clone(), "wasteland".to_string());
+        manager.discover_modules().unwrap();
+
+        let names: Vec<_> = manager.get_modules().iter().map(|m| m.config.name.clone()).collect();
+        assert_eq!(names, vec!["Gauge".to_string()]);
+        let err = Module::load_from_manifest_path(&dir.join("keyed")).unwrap_err().to_string();
+        assert!(err.starts_with("bindings.api_key: Environment variable 'SURVON_TEST_UNSET_SECRET' is not set"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
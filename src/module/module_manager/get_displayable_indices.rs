@@ -1,12 +1,16 @@
 use crate::module::ModuleManager;
 
 impl ModuleManager {
-    pub(super) fn get_displayable_indices(&self) -> Vec<usize> {
-        self.modules
+    /// Indices into `modules` of everything shown on the dashboard, in display order
+    pub fn get_displayable_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self.modules
             .iter()
             .enumerate()
             .filter(|(_, m)| Self::is_displayable_module(m))
             .map(|(i, _)| i)
-            .collect()
+            .collect();
+
+        indices.sort_by(|&a, &b| self.modules[a].config.display_order(&self.modules[b].config));
+        indices
     }
 }
@@ -2,9 +2,12 @@ use crate::module::{Module, ModuleManager};
 
 impl ModuleManager {
     pub fn get_displayable_modules(&self) -> Vec<&Module> {
-        self.modules
+        let mut modules: Vec<&Module> = self.modules
             .iter()
             .filter(|m| Self::is_displayable_module(m))
-            .collect()
+            .collect();
+
+        modules.sort_by(|a, b| a.config.display_order(&b.config));
+        modules
     }
 }
@@ -2,9 +2,12 @@ use crate::module::{Module, ModuleManager};
 
 impl ModuleManager {
     pub fn get_displayable_modules_mut(&mut self) -> Vec<&mut Module> {
-        self.modules
+        let mut modules: Vec<&mut Module> = self.modules
             .iter_mut()
             .filter(|m| Self::is_displayable_module(m))
-            .collect()
+            .collect();
+
+        modules.sort_by(|a, b| a.config.display_order(&b.config));
+        modules
     }
 }
@@ -10,18 +10,7 @@ use crate::{
 impl ModuleManager {
     pub fn handle_key_for_module(&mut self, module_idx: usize, key_code: KeyCode) -> Option<AppEvent> {
         if let Some(module) = self.modules.get(module_idx) {
-            let module_type = module.config.module_type.clone();
-
-            // For monitoring modules, use device_id for handler key (NOT module_idx!)
-            let handler_key = if module_type == "monitoring" {
-                let device_id = module.config.bindings
-                    .get("device_id")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("");
-                format!("monitoring_{}", device_id)  // ← Must match registration! TODO scale this
-            } else {
-                module_type.clone()
-            };
+            let handler_key = Self::handler_key_for(module);
 
             log_debug!("🔑 Looking up handler: '{}' for module at index {}", handler_key, module_idx);
 
@@ -32,6 +21,7 @@ impl ModuleManager {
             log_debug!("✓ Found handler, calling handle_key with {:?}", key_code);
 
             let result = handler.handle_key(key_code, module);
+            let result = Self::scope_event(module, result);
 
             if result.is_some() {
                 log_debug!("✓ Handler returned event: {:?}", result);
@@ -0,0 +1,20 @@
+use crate::module::{Module, ModuleManager};
+
+impl ModuleManager {
+    /// Key under which `module`'s handler is registered.
+    /// Monitoring handlers are per-device and com handlers per topic;
+    /// everything else is one handler per module type.
+    pub(super) fn handler_key_for(module: &Module) -> String {
+        if module.config.module_type == "monitoring" {
+            let device_id = module.config.binding_str("device_id").unwrap_or("");
+            format!("monitoring_{}", device_id)  // ← Must match registration!
+        } else if module.config.module_type == "valve_control" {
+            let device_id = module.config.binding_str("device_id").unwrap_or("");
+            format!("valve_control_{}", device_id)
+        } else if module.config.module_type == "com" {
+            format!("com_{}", module.config.bus_topic)
+        } else {
+            module.config.module_type.clone()
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use crate::module::ModuleManager;
+use crate::runtime::reduced_motion::is_reduced_motion;
 
 impl ModuleManager {
+    /// Whether some card is mid-blink and needs redrawing. Never with reduced motion on,
+    /// since blinking cards then hold still.
     pub fn has_active_blinks(&self) -> bool {
-        self.modules
+        !is_reduced_motion() && self.modules
             .iter()
             .any(|m| m.render_state.is_actively_blinking)
     }
@@ -0,0 +1,35 @@
+use tokio::sync::mpsc;
+
+use crate::module::ModuleManager;
+
+/// One step of `initialize_module_handlers`, sent before the handler is set up
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandlerInitProgress {
+    pub namespace: String,
+    /// Registry key of the handler being set up (e.g. `monitoring_a01`)
+    pub handler_key: String,
+    /// 1-based position of this module among `total`
+    pub step: usize,
+    pub total: usize,
+}
+
+pub type InitProgressSender = mpsc::UnboundedSender<HandlerInitProgress>;
+
+impl ModuleManager {
+    /// Report each handler registration step on `sender` (e.g. for a loading screen)
+    pub fn set_init_progress(&mut self, sender: Option<InitProgressSender>) {
+        self.init_progress = sender;
+    }
+
+    pub(super) fn report_init_progress(&self, handler_key: &str, step: usize, total: usize) {
+        if let Some(sender) = &self.init_progress {
+            // Nobody listening any more (e.g. after startup) is fine
+            let _ = sender.send(HandlerInitProgress {
+                namespace: self.namespace.clone(),
+                handler_key: handler_key.to_string(),
+                step,
+                total,
+            });
+        }
+    }
+}
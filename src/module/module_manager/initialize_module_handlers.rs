@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     path::PathBuf,
     sync::Arc,
 };
@@ -8,8 +9,9 @@ use crate::{
     log_info,
     log_warn,
     module::{
+        ModuleCapabilities,
         ModuleManager,
-        strategies::{llm, monitoring, overseer, side_quest, valve_control},
+        strategies::{com, llm, monitoring, overseer, side_quest, valve_control},
     },
     util::{
         database::Database,
@@ -17,6 +19,7 @@ use crate::{
             bus::MessageBus,
             discovery::DiscoveryManager,
         },
+        log::{set_target_level, LogLevel},
     }
 };
 
@@ -28,21 +31,50 @@ impl ModuleManager {
         database: &Database,
         message_bus: &MessageBus
     ) -> color_eyre::Result<()> {
-        let modules_info: Vec<(String, String, String)> = self.modules
+        let modules_info: Vec<(String, String, String, String, Option<ModuleCapabilities>)> = self.modules
             .iter()
             .map(|m| {
-                let device_id = m.config.bindings
-                    .get("device_id")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("")
+                let device_id = m.config.binding_str("device_id").unwrap_or("")
                     .to_string();
-                (m.config.module_type.clone(), device_id, m.config.bus_topic.clone())
+                (
+                    m.config.module_type.clone(),
+                    device_id,
+                    m.config.bus_topic.clone(),
+                    Self::handler_key_for(m),
+                    m.config.capabilities.clone(),
+                )
             })
             .collect();
 
         log_info!("🔧 Initializing module handlers for namespace: {}", self.namespace);
 
-        for (module_type, device_id, bus_topic) in modules_info {
+        for module in &self.modules {
+            if let Some(level) = &module.config.log_level {
+                let handler_key = Self::handler_key_for(module);
+                match LogLevel::parse(level) {
+                    Some(level) => set_target_level(&handler_key, level),
+                    None => log_warn!("⚠️ Ignoring unrecognized log_level '{}' for {}", level, handler_key),
+                }
+            }
+        }
+
+        // Modules that declare capabilities only get to publish inside them. A handler shared by
+        // several modules publishes for each of them, so it's held to every scope they declare.
+        let mut handler_buses: HashMap<String, MessageBus> = HashMap::new();
+        for (_, _, _, handler_key, capabilities) in &modules_info {
+            let bus = handler_buses.entry(handler_key.clone()).or_insert_with(|| message_bus.clone());
+            if let Some(caps) = capabilities {
+                log_debug!("🔒 Scoping {} to publish topics {:?}", handler_key, caps.publish_topics);
+                *bus = bus.with_publish_scope(caps.publish_topics.clone());
+            }
+        }
+
+        let total = modules_info.len();
+        for (index, (module_type, device_id, bus_topic, handler_key, _)) in modules_info.into_iter().enumerate() {
+            self.report_init_progress(&handler_key, index + 1, total);
+
+            let module_bus = handler_buses[&handler_key].clone();
+
             match module_type.as_str() {
                 "llm" => {
                     if !self.handlers.contains_key("llm") {
@@ -69,7 +101,7 @@ impl ModuleManager {
                         let handler = Box::new(
                             side_quest::handler::SideQuestHandler::new(
                                 database.clone(),
-                                message_bus.clone()
+                                module_bus.clone()
                             )
                         );
                         self.register_handler(handler);
@@ -78,44 +110,68 @@ impl ModuleManager {
                     }
                 }
 
+                "knowledge" => {
+                    if !self.handlers.contains_key("knowledge") {
+                        use crate::module::strategies::knowledge;
+
+                        log_info!("📖 Registering Knowledge handler");
+
+                        self.register_handler(Box::new(
+                            knowledge::handler::KnowledgeHandler::new(database.clone())
+                        ));
+
+                        log_info!("✅ Knowledge handler registered");
+                    }
+                }
+
                 "overseer" => {
                     if !self.handlers.contains_key("overseer") {
                         log_info!("🗂️ Registering Wasteland Manager handler");
 
-                        self.register_handler(Box::new(
-                            overseer::handler::OverseerHandler::new(
-                                wasteland_path.clone(),
-                                discovery_manager.clone(),
-                                database.clone(),
-                                message_bus.clone()
-                            )
-                        ));
+                        let (registries, active_registry) = self.modules
+                            .iter()
+                            .find(|m| m.config.module_type == "overseer")
+                            .map(|m| overseer::RegistryEndpoint::from_config(&m.config))
+                            .unwrap_or_default();
+
+                        let mut handler = overseer::handler::OverseerHandler::new(
+                            wasteland_path.clone(),
+                            discovery_manager.clone(),
+                            database.clone(),
+                            module_bus.clone()
+                        )
+                        .with_registries(registries, active_registry.as_deref());
+
+                        // Startup housekeeping, once the handler is actually being put to use
+                        handler.purge_expired_archives();
+
+                        self.register_handler(Box::new(handler));
 
                         log_info!("✅ Wasteland Manager handler registered");
                     }
                 }
 
                 "valve_control" => {
-                    if !self.handlers.contains_key("valve_control") && !device_id.is_empty() {
+                    if !self.handlers.contains_key(&handler_key) && !device_id.is_empty() {
                         use crate::module::strategies::valve_control;
 
                         log_info!("🚰 Registering valve_control handler for device: {}", device_id);
 
                         let handler = Box::new(
                             valve_control::handler::ValveControlHandler::new(
-                                message_bus.clone(),
+                                module_bus.clone(),
                                 device_id.clone(),
                                 bus_topic.clone(),
                                 discovery_manager.clone(),  // ← NEW!
                             )
                         );
-                        self.register_handler(handler);
+                        self.handlers.insert(handler_key.clone(), handler);
+                        self.notify_observers(|o| o.on_handler_registered(&self.namespace, &handler_key));
 
-                        log_info!("✅ Valve control handler registered");
+                        log_info!("✅ Valve control handler registered: {}", handler_key);
                     }
                 }
                 "monitoring" => {
-                    let handler_key = format!("monitoring_{}", device_id);
 
                     if !self.handlers.contains_key(&handler_key) && !device_id.is_empty() {
                         use crate::module::strategies::monitoring;
@@ -125,15 +181,37 @@ impl ModuleManager {
                         log_info!("   - Device ID: {}", device_id);
                         log_info!("   - Bus topic: {}", bus_topic);
 
+                        // Calibration and plausible ranges were validated with the manifest; if they still
+                        // fail, show raw values and keep every sample
+                        let module = self.modules.iter().find(|m| Self::handler_key_for(m) == handler_key);
+                        let conversions = module
+                            .and_then(|m| {
+                                monitoring::conversion::Conversions::from_config(&m.config)
+                                    .map_err(|e| log_warn!("⚠️ Ignoring calibration for {}: {}", handler_key, e))
+                                    .ok()
+                            })
+                            .unwrap_or_default();
+                        let outlier_filter = module
+                            .and_then(|m| {
+                                monitoring::outliers::OutlierFilter::from_config(&m.config)
+                                    .map_err(|e| log_warn!("⚠️ Ignoring plausible ranges for {}: {}", handler_key, e))
+                                    .ok()
+                            })
+                            .unwrap_or_default();
+
                         let handler = Box::new(
                             monitoring::handler::MonitoringHandler::new(
-                                message_bus.clone(),
+                                module_bus.clone(),
+                                database.clone(),
                                 device_id.clone(),
                                 bus_topic.clone(),
                             )
+                            .with_conversions(conversions)
+                            .with_outlier_filter(outlier_filter)
                         );
 
                         self.handlers.insert(handler_key.clone(), handler);
+                        self.notify_observers(|o| o.on_handler_registered(&self.namespace, &handler_key));
                         log_info!("✅ Monitoring handler registered: {}", handler_key);
                     } else if device_id.is_empty() {
                         log_warn!("⚠️ Skipping monitoring module with empty device_id");
@@ -142,6 +220,25 @@ impl ModuleManager {
                     }
                 }
 
+                "com" => {
+                    if !self.handlers.contains_key(&handler_key) && !bus_topic.is_empty() {
+                        log_info!("🔌 Registering com handler for topic: {}", bus_topic);
+
+                        let handler = Box::new(
+                            com::handler::ComHandler::new(
+                                module_bus.clone(),
+                                bus_topic.clone(),
+                            )
+                        );
+
+                        self.handlers.insert(handler_key.clone(), handler);
+                        self.notify_observers(|o| o.on_handler_registered(&self.namespace, &handler_key));
+                        log_info!("✅ Com handler registered: {}", handler_key);
+                    } else if bus_topic.is_empty() {
+                        log_warn!("⚠️ Skipping com module with empty bus_topic");
+                    }
+                }
+
                 "system" => {
                     // System modules don't need handlers yet
                 }
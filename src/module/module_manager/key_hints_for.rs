@@ -0,0 +1,14 @@
+use crossterm::event::KeyCode;
+
+use crate::module::ModuleManager;
+
+impl ModuleManager {
+    /// Key hints from the handler behind the module at `module_idx` (empty when it declares none)
+    pub fn key_hints_for(&self, module_idx: usize) -> Vec<(KeyCode, &str)> {
+        self.modules
+            .get(module_idx)
+            .and_then(|module| self.handlers.get(&Self::handler_key_for(module)))
+            .map(|handler| handler.key_hints())
+            .unwrap_or_default()
+    }
+}
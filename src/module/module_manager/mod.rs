@@ -4,6 +4,7 @@ mod register_handler;
 mod get_handler;
 mod get_handler_mut;
 mod handle_key_for_module;
+mod key_hints_for;
 mod update_module_bindings;
 mod subscribe_to_events;
 mod poll_events;
@@ -21,10 +22,23 @@ mod get_displayable_indices;
 mod prev_module;
 mod next_module;
 mod select_current_module;
+mod select_module_named;
 mod refresh_modules;
+mod reload_module;
+mod remove_module;
+mod auto_refresh_modules;
+mod handler_key_for;
+mod run_quick_action;
+mod apply_undo;
+mod scope_event;
+mod init_progress;
+mod observers;
+#[cfg(any(test, feature = "test-util"))]
+mod push_module;
 
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Instant;
 
 use crate::{
     module::{
@@ -34,6 +48,9 @@ use crate::{
     util::io::bus::BusReceiver,
 };
 use crate::app::ModuleSource;
+use crate::runtime::observer::SharedObserver;
+
+pub use init_progress::{HandlerInitProgress, InitProgressSender};
 
 #[derive(Debug, PartialEq)]
 pub enum ModuleManagerView {
@@ -48,6 +65,12 @@ pub struct ModuleManager {
     pub namespace: String,
     pub selected_module: usize,
     pub current_view: ModuleManagerView,
+    /// First grid row on screen in the list view; moves to keep the selection visible
+    pub first_visible_row: usize,
     event_receivers: Vec<BusReceiver>,
     handlers: HashMap<String, Box<dyn ModuleHandler>>,
+    init_progress: Option<InitProgressSender>,
+    observers: Vec<SharedObserver>,
+    /// When each auto-refreshing module (by manifest path) was last re-read
+    auto_refreshed: HashMap<PathBuf, Instant>,
 }
@@ -17,8 +17,12 @@ impl ModuleManager {
             namespace,
             selected_module: 0,
             current_view: ModuleManagerView::ModuleListView,
+            first_visible_row: 0,
             event_receivers: Vec::new(),
             handlers: HashMap::new(),
+            init_progress: None,
+            observers: Vec::new(),
+            auto_refreshed: HashMap::new(),
         }
     }
 }
@@ -0,0 +1,54 @@
+use crate::module::ModuleManager;
+use crate::runtime::observer::{RuntimeObserver, SharedObserver};
+
+impl ModuleManager {
+    /// Register an observer for module and handler lifecycle events
+    pub fn add_observer(&mut self, observer: SharedObserver) {
+        self.observers.push(observer);
+    }
+
+    pub(super) fn notify_observers(&self, f: impl Fn(&dyn RuntimeObserver)) {
+        for observer in &self.observers {
+            f(observer.as_ref());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::module::Module;
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        loaded: Mutex<Vec<String>>,
+    }
+
+    impl RuntimeObserver for Recorder {
+        fn on_module_loaded(&self, namespace: &str, module: &Module) {
+            self.loaded.lock().unwrap().push(format!("{}:{}", namespace, module.config.name));
+        }
+    }
+
+    #[test]
+    fn test_discover_notifies_observers() {
+        let dir: PathBuf = std::env::temp_dir().join(format!("survon_observers_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(dir.join("gauge")).unwrap();
+        fs::write(
+            dir.join("gauge").join("config.yml"),
+            "name: \"Gauge\"\nmodule_type: \"system\"\nbus_topic: \"\"\ntemplate: \"\"\nbindings: {}\n",
+        ).unwrap();
+
+        let recorder = Arc::new(Recorder::default());
+        let mut manager = ModuleManager::new(dir.clone(), "wasteland".to_string());
+        manager.add_observer(recorder.clone());
+        manager.discover_modules().unwrap();
+
+        assert_eq!(*recorder.loaded.lock().unwrap(), vec!["wasteland:Gauge".to_string()]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,8 @@
+use crate::module::{Module, ModuleManager};
+
+impl ModuleManager {
+    /// Add a module built in memory (see `test_util::TestModule`) instead of discovered from a manifest
+    pub fn push_module(&mut self, module: Module) {
+        self.modules.push(module);
+    }
+}
@@ -1,9 +1,12 @@
+use crate::log_error;
 use crate::module::ModuleManager;
+use crate::runtime::toast::{notify, ToastLevel};
 
 impl ModuleManager {
     pub async fn refresh_modules(&mut self) {
         if let Err(e) = self.discover_modules() {
-            panic!("Failed to refresh modules: {}", e);
+            log_error!("❌ Failed to refresh modules: {}", e);
+            notify(ToastLevel::Error, format!("Failed to refresh modules: {}", e));
         }
 
         let module_count = self.get_modules().len();
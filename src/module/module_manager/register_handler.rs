@@ -6,6 +6,7 @@ use crate::module::{
 impl ModuleManager {
     pub fn register_handler(&mut self, handler: Box<dyn ModuleHandler>) {
         let module_type = handler.module_type().to_string();
-        self.handlers.insert(module_type, handler);
+        self.handlers.insert(module_type.clone(), handler);
+        self.notify_observers(|o| o.on_handler_registered(&self.namespace, &module_type));
     }
 }
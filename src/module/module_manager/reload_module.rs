@@ -0,0 +1,25 @@
+use crate::module::{Module, ModuleManager};
+
+impl ModuleManager {
+    /// Re-read one module's manifest in place, keeping its position and selection,
+    /// then have its handler re-read its own data source and refresh its bindings.
+    pub fn reload_module(&mut self, module_idx: usize) -> color_eyre::Result<()> {
+        let Some(module) = self.modules.get_mut(module_idx) else {
+            return Ok(());
+        };
+
+        let reloaded = Module::load_from_manifest_path(&module.path)?;
+        if reloaded.config.template != module.config.template {
+            module.cached_template = None;
+        }
+        module.config = reloaded.config;
+
+        let handler_key = Self::handler_key_for(module);
+        if let Some(handler) = self.handlers.get_mut(&handler_key) {
+            handler.reload(module);
+        }
+        self.update_module_bindings(module_idx);
+
+        Ok(())
+    }
+}
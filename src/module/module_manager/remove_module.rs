@@ -0,0 +1,104 @@
+use crate::module::{ModuleManager, ModuleManagerView};
+use crate::runtime::{isolation, watchdog};
+
+impl ModuleManager {
+    /// Take the module called `name` (its config name or manifest directory) off the dashboard
+    /// without re-scanning the directory. Its handler goes too, along with the background tasks
+    /// and bus subscriptions it started, unless another module still uses it.
+    /// `Ok(false)` when no module matches.
+    pub fn remove_module(&mut self, name: &str) -> color_eyre::Result<bool> {
+        let Some(idx) = self.modules.iter().position(|m| {
+            m.config.name == name || m.path.file_name().is_some_and(|dir| dir == name)
+        }) else {
+            return Ok(false);
+        };
+
+        let module = self.modules.remove(idx);
+        self.auto_refreshed.remove(&module.path);
+
+        // Type-keyed handlers serve every module of their type
+        let handler_key = Self::handler_key_for(&module);
+        if !self.modules.iter().any(|m| Self::handler_key_for(m) == handler_key)
+            && self.handlers.remove(&handler_key).is_some()
+        {
+            // Dropping the tasks drops their receivers, which unsubscribes them
+            watchdog::stop(&handler_key);
+            isolation::abort(&handler_key);
+        }
+
+        if idx <= self.selected_module {
+            self.selected_module = self.selected_module.saturating_sub(1);
+        }
+        if let ModuleManagerView::ModuleDetail(source, detail_idx) = &self.current_view {
+            self.current_view = match (*detail_idx).cmp(&idx) {
+                std::cmp::Ordering::Less => ModuleManagerView::ModuleDetail(source.clone(), *detail_idx),
+                std::cmp::Ordering::Equal => ModuleManagerView::ModuleListView,
+                std::cmp::Ordering::Greater => ModuleManagerView::ModuleDetail(source.clone(), detail_idx - 1),
+            };
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module::ModuleManager;
+    use crate::util::test_util::{MockHandler, TestModule};
+
+    #[test]
+    fn test_removes_module_and_its_handler() {
+        let mut manager = ModuleManager::new("./manifests/test".into(), "wasteland".to_string());
+        manager.push_module(TestModule::new("Tank", "monitoring", "gauge_card").binding("device_id", "a01".into()).build());
+        manager.push_module(TestModule::new("Valve", "valve_control", "toggle_switch").binding("device_id", "v01".into()).build());
+        manager.push_module(TestModule::new("Garden Valve", "valve_control", "toggle_switch").binding("device_id", "v01".into()).build());
+        manager.register_handler(Box::new(MockHandler::new("monitoring_a01")));
+        manager.register_handler(Box::new(MockHandler::new("valve_control_v01")));
+        manager.selected_module = 2;
+
+        assert!(!manager.remove_module("Pump").unwrap());
+        assert!(manager.remove_module("Tank").unwrap());
+        assert_eq!(manager.get_modules().len(), 2);
+        assert_eq!(manager.selected_module, 1);
+        assert!(manager.get_handler("monitoring_a01").is_none());
+
+        // Still serving the other valve on the same device
+        assert!(manager.remove_module("Valve").unwrap());
+        assert!(manager.get_handler("valve_control_v01").is_some());
+        assert_eq!(manager.selected_module, 0);
+    }
+
+    #[tokio::test]
+    async fn test_removing_a_module_unsubscribes_its_listeners() {
+        use crate::module::strategies::com::handler::ComHandler;
+        use crate::util::io::bus::{BusMessage, MessageBus};
+
+        let (bus, _receiver) = MessageBus::new();
+        let mut manager = ModuleManager::new("./manifests/test".into(), "wasteland".to_string());
+        manager.push_module(TestModule::new("Gate Radio", "com", "activity_card").bus_topic("gate_radio").build());
+        manager.handlers.insert("com_gate_radio".to_string(), Box::new(ComHandler::new(bus.clone(), "gate_radio".to_string())));
+
+        let subscribers = |bus: &MessageBus| bus.stats().get("gate_radio").map_or(0, |stats| stats.subscribers);
+        let ping = || BusMessage::new("gate_radio".to_string(), "{}".to_string(), "test".to_string());
+
+        // Let the activity listener subscribe
+        for _ in 0..50 {
+            bus.publish(ping()).await.unwrap();
+            if subscribers(&bus) > 0 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(subscribers(&bus), 1);
+
+        assert!(manager.remove_module("Gate Radio").unwrap());
+        for _ in 0..50 {
+            tokio::task::yield_now().await;
+            bus.publish(ping()).await.unwrap();
+            if subscribers(&bus) == 0 {
+                break;
+            }
+        }
+        assert_eq!(subscribers(&bus), 0);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    log_debug,
+    module::{ModuleManager, QuickAction},
+    util::io::event::AppEvent,
+};
+
+impl ModuleManager {
+    /// Quick actions offered by the handler behind the module at `module_idx`
+    pub fn quick_actions_for(&self, module_idx: usize) -> Vec<QuickAction> {
+        self.modules
+            .get(module_idx)
+            .and_then(|module| self.handlers.get(&Self::handler_key_for(module)))
+            .map(|handler| handler.quick_actions().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Run the quick action bound to `trigger`, if the module's handler offers one.
+    /// Returns the action plus whatever event the handler produced.
+    pub fn run_quick_action(&mut self, module_idx: usize, trigger: char) -> Option<(QuickAction, Option<AppEvent>)> {
+        let handler_key = Self::handler_key_for(self.modules.get(module_idx)?);
+        let handler = self.handlers.get_mut(&handler_key)?;
+        let action = *handler.quick_actions().iter().find(|a| a.trigger == trigger)?;
+
+        log_debug!("⚡ Quick action '{}' on module {}", action.label, module_idx);
+
+        let module = self.modules.get_mut(module_idx)?;
+        let event = action.key.and_then(|key| handler.handle_key(key, module));
+        let event = Self::scope_event(module, event);
+
+        Some((action, event))
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    log_warn,
+    module::{Module, ModuleManager},
+    util::io::event::AppEvent,
+};
+
+impl ModuleManager {
+    /// Drop `SendCommand` events aimed outside the module's declared publish topics.
+    /// Modules without a `capabilities` block pass through untouched.
+    pub(super) fn scope_event(module: &Module, event: Option<AppEvent>) -> Option<AppEvent> {
+        match (&event, &module.config.capabilities) {
+            (Some(AppEvent::SendCommand(topic, _)), Some(caps)) if !caps.allows_publish(topic) => {
+                log_warn!(
+                    "🚫 '{}' tried to send to '{}' outside its declared capabilities",
+                    module.config.name,
+                    topic
+                );
+                None
+            }
+            _ => event,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::module::ModuleManager;
+
+impl ModuleManager {
+    /// Select the dashboard module called `name` (case-insensitive). False if there's none.
+    pub fn select_module_named(&mut self, name: &str) -> bool {
+        let found = self
+            .get_displayable_indices()
+            .into_iter()
+            .find(|&idx| self.modules[idx].config.name.eq_ignore_ascii_case(name));
+
+        match found {
+            Some(idx) => {
+                self.selected_module = idx;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module::ModuleManager;
+    use crate::util::test_util::TestModule;
+
+    #[test]
+    fn test_selects_displayable_module_by_name() {
+        let mut manager = ModuleManager::new("./manifests/test".into(), "wasteland".to_string());
+        manager.push_module(TestModule::new("Tank", "monitoring", "gauge_card").build());
+        manager.push_module(TestModule::new("Notes", "knowledge", "").build());
+        manager.push_module(TestModule::new("Valve", "valve_control", "toggle_switch").build());
+
+        assert!(manager.select_module_named("valve"));
+        assert_eq!(manager.selected_module, 2);
+        // Not on the dashboard
+        assert!(!manager.select_module_named("Notes"));
+        assert_eq!(manager.selected_module, 2);
+    }
+}
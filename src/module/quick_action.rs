@@ -0,0 +1,15 @@
+use ratatui::crossterm::event::KeyCode;
+
+/// A shortcut available on the overview while a module card is selected,
+/// without opening the module's detail view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuickAction {
+    /// Key pressed on the overview (must not clash with global keys: q, c, r, d)
+    pub trigger: char,
+    /// Key forwarded to the handler's `handle_key`, if any
+    pub key: Option<KeyCode>,
+    /// Shown in the overview help footer
+    pub label: &'static str,
+    /// Open the detail view afterwards (for actions that need input)
+    pub opens_detail: bool,
+}
@@ -4,6 +4,9 @@ use ratatui::{
 };
 
 use crate::module::Module;
+use crate::runtime::isolation;
+use crate::ui::template::calibration_overlay::render_calibration_overlay;
+use crate::ui::template::empty_state::{is_awaiting_data, render_empty_state};
 
 impl Module {
     pub fn render_detail(&mut self, area: Rect, buf: &mut Buffer) -> std::result::Result<(), String> {
@@ -12,10 +15,17 @@ impl Module {
         let mut template = self.cached_template.take()
             .ok_or_else(|| "Template not loaded".to_string())?;
 
-        template.render_detail(area, buf, self);
+        let rendered = isolation::guard(&format!("{} ({} template)", self.config.name, self.config.template), || {
+            if template.has_data(self) && !is_awaiting_data(self) {
+                template.render_detail(area, buf, self);
+            } else {
+                render_empty_state(None, area, buf, self);
+            }
+            render_calibration_overlay(area, buf, self);
+        });
 
         self.cached_template = Some(template);
 
-        Ok(())
+        rendered.map_err(|message| format!("Template panicked: {}", message))
     }
 }
@@ -1,10 +1,13 @@
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
+    style::{Color, Style},
 };
 
 use crate::module::Module;
+use crate::runtime::isolation;
+use crate::ui::template::empty_state::{is_awaiting_data, render_empty_state};
 
 impl Module {
     pub fn render_overview_cta(&mut self, is_selected: bool, area: Rect, buf: &mut Buffer) -> std::result::Result<(), String> {
@@ -12,25 +15,30 @@ impl Module {
 
         if self.config.is_blinkable() {
             // Get blink interval from bindings (default 500ms)
-            let blink_interval_ms = self.config.bindings
-                .get("blink_interval_ms")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(500);
+            let blink_interval_ms = self.config.binding_u64("blink_interval_ms").unwrap_or(500);
 
-            let blink_interval = Duration::from_millis(blink_interval_ms);
-
-            if self.render_state.last_blink.elapsed() >= blink_interval {
-                self.render_state.blink_state = !self.render_state.blink_state;
-                self.render_state.last_blink = Instant::now();
-            }
+            self.render_state.update_blink(Duration::from_millis(blink_interval_ms));
         }
 
         let mut template = self.cached_template.take()
             .ok_or_else(|| "Template not loaded".to_string())?;
 
-        template.render_overview_cta(is_selected, area, buf, self);
+        // A panicking template fails only this card, which then shows the error widget
+        let rendered = isolation::guard(&format!("{} ({} template)", self.config.name, self.config.template), || {
+            if template.has_data(self) && !is_awaiting_data(self) {
+                template.render_overview_cta(is_selected, area, buf, self);
+            } else {
+                render_empty_state(Some(is_selected), area, buf, self);
+            }
+        });
 
         self.cached_template = Some(template);
+        rendered.map_err(|message| format!("Template panicked: {}", message))?;
+
+        // Flag handler failures in the card's top-right corner; the message is shown in detail view
+        if self.last_error_message().is_some() && area.width > 4 {
+            buf.set_string(area.x + area.width - 3, area.y, "⚠", Style::default().fg(Color::Red));
+        }
 
         Ok(())
     }
@@ -2,9 +2,12 @@ mod update_blink;
 mod start_blinking;
 mod stop_blinking;
 mod trait_default;
+mod with_clock;
 
 use std::time::Instant;
 
+use crate::util::clock::SharedClock;
+
 /// Runtime rendering state for modules (not serialized)
 #[derive(Debug, Clone)]
 pub struct ModuleRenderState {
@@ -13,4 +16,5 @@ pub struct ModuleRenderState {
     pub animation_frame: usize,
     pub is_focused: bool,
     pub is_actively_blinking: bool,
+    pub clock: SharedClock,
 }
@@ -1,15 +1,9 @@
-use std::time::Instant;
+use crate::util::clock::system_clock;
 
 use super::ModuleRenderState;
 
 impl Default for ModuleRenderState {
     fn default() -> Self {
-        Self {
-            blink_state: false,
-            last_blink: Instant::now(),
-            animation_frame: 0,
-            is_focused: false,
-            is_actively_blinking: false,
-        }
+        Self::with_clock(system_clock())
     }
 }
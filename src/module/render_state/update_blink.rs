@@ -1,12 +1,21 @@
-use std::time::{Duration, Instant};
+use std::time::Duration;
+
+use crate::runtime::reduced_motion::is_reduced_motion;
 
 use super::ModuleRenderState;
 
 impl ModuleRenderState {
     pub fn update_blink(&mut self, interval: Duration) -> bool {
-        if self.last_blink.elapsed() >= interval {
+        // Hold the "on" state instead of flashing; only the first switch needs a redraw
+        if is_reduced_motion() {
+            let changed = !self.blink_state;
+            self.blink_state = true;
+            return changed;
+        }
+
+        if self.clock.elapsed_since(self.last_blink) >= interval {
             self.blink_state = !self.blink_state;
-            self.last_blink = Instant::now();
+            self.last_blink = self.clock.now();
             true
         } else {
             false
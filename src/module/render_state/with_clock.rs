@@ -0,0 +1,16 @@
+use crate::util::clock::SharedClock;
+
+use super::ModuleRenderState;
+
+impl ModuleRenderState {
+    pub fn with_clock(clock: SharedClock) -> Self {
+        Self {
+            blink_state: false,
+            last_blink: clock.now(),
+            animation_frame: 0,
+            is_focused: false,
+            is_actively_blinking: false,
+            clock,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warn,
+    Error,
+}
+
+impl Severity {
+    /// Accepts the usual spellings (`warning`, `err`, `fatal`, ...)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "info" | "debug" | "trace" | "notice" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warn),
+            "error" | "err" | "fail" | "failed" | "fatal" | "critical" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    /// Guess from free text, the way the activity card colours plain lines
+    pub fn detect(text: &str) -> Self {
+        if text.contains("ERROR") || text.contains("FAIL") {
+            Self::Error
+        } else if text.contains("WARN") {
+            Self::Warn
+        } else {
+            Self::Info
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+/// Minimum severity shown in the activity card
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeverityFilter {
+    All,
+    WarnAndAbove,
+    ErrorOnly,
+}
+
+impl SeverityFilter {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::All => Self::WarnAndAbove,
+            Self::WarnAndAbove => Self::ErrorOnly,
+            Self::ErrorOnly => Self::All,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::WarnAndAbove => "warn+",
+            Self::ErrorOnly => "error",
+        }
+    }
+
+    pub fn allows(&self, severity: Severity) -> bool {
+        match self {
+            Self::All => true,
+            Self::WarnAndAbove => severity >= Severity::Warn,
+            Self::ErrorOnly => severity == Severity::Error,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    pub at: DateTime<Utc>,
+    pub severity: Severity,
+    pub text: String,
+}
+
+impl ActivityEntry {
+    pub fn new(severity: Severity, text: impl Into<String>) -> Self {
+        Self { at: Utc::now(), severity, text: text.into() }
+    }
+
+    /// Build an entry from a bus payload: JSON with `message`/`text` and
+    /// `severity`/`level`, or any other payload as a plain line
+    pub fn from_payload(payload: &str) -> Self {
+        if let Ok(data) = serde_json::from_str::<serde_json::Value>(payload) {
+            let text = data
+                .get("message")
+                .or_else(|| data.get("text"))
+                .and_then(|v| v.as_str());
+
+            if let Some(text) = text {
+                let severity = data
+                    .get("severity")
+                    .or_else(|| data.get("level"))
+                    .and_then(|v| v.as_str())
+                    .and_then(Severity::parse)
+                    .unwrap_or_else(|| Severity::detect(text));
+                return Self::new(severity, text);
+            }
+        }
+
+        let text = payload.trim();
+        Self::new(Severity::detect(text), text)
+    }
+
+    /// `14:02:11 [WARN] link degraded`
+    pub fn display_line(&self) -> String {
+        format!(
+            "{} [{}] {}",
+            self.at.with_timezone(&chrono::Local).format("%H:%M:%S"),
+            self.severity.label(),
+            self.text,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_parsing_and_filter() {
+        let entry = ActivityEntry::from_payload(r#"{"message":"link lost","level":"warning"}"#);
+        assert_eq!(entry.severity, Severity::Warn);
+        assert_eq!(entry.text, "link lost");
+
+        assert_eq!(ActivityEntry::from_payload("TX FAIL ch 3").severity, Severity::Error);
+        assert_eq!(ActivityEntry::from_payload("beacon sent").severity, Severity::Info);
+
+        assert!(SeverityFilter::WarnAndAbove.allows(Severity::Error));
+        assert!(!SeverityFilter::WarnAndAbove.allows(Severity::Info));
+        assert!(!SeverityFilter::ErrorOnly.allows(Severity::Warn));
+    }
+}
@@ -0,0 +1,48 @@
+use crossterm::event::KeyCode;
+
+use crate::{
+    module::Module,
+    util::io::event::AppEvent,
+};
+use crate::module::strategies::com::handler::ComHandler;
+
+impl ComHandler {
+    pub(in crate::module) fn _handle_key(&mut self, key_code: KeyCode, module: &mut Module) -> Option<AppEvent> {
+        match key_code {
+            KeyCode::Char('f') => {
+                self.filter = self.filter.next();
+                self.scroll_offset = 0;
+                None
+            }
+            KeyCode::Up => {
+                // Clamped against the visible entries in update_bindings
+                self.scroll_offset += 1;
+                None
+            }
+            KeyCode::Down => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                None
+            }
+            KeyCode::End => {
+                self.scroll_offset = 0;
+                None
+            }
+            KeyCode::Left => {
+                self.selected_message = self.selected_message.saturating_sub(1);
+                None
+            }
+            KeyCode::Right | KeyCode::Tab => {
+                let count = Self::outbound_messages(module).len();
+                if count > 0 {
+                    self.selected_message = (self.selected_message + 1) % count;
+                }
+                None
+            }
+            KeyCode::Enter => {
+                self.send_selected_message(module);
+                None
+            }
+            _ => None,
+        }
+    }
+}
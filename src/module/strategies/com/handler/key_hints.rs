@@ -0,0 +1,18 @@
+use crossterm::event::KeyCode;
+
+use crate::module::strategies::com::handler::ComHandler;
+
+impl ComHandler {
+    /// Keys usable in the activity view, shown as the card's footer and in the `?` overlay
+    pub(in crate::module) fn _key_hints(&self) -> Vec<(KeyCode, &'static str)> {
+        vec![
+            (KeyCode::Char('f'), "Filter"),
+            (KeyCode::Up, "Scroll"),
+            (KeyCode::Down, "Scroll"),
+            (KeyCode::End, "Latest"),
+            (KeyCode::Left, "Message"),
+            (KeyCode::Right, "Message"),
+            (KeyCode::Enter, "Send"),
+        ]
+    }
+}
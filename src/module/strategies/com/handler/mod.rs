@@ -0,0 +1,50 @@
+mod trait_module_handler;
+mod new;
+mod activity;
+mod start_activity_listener;
+mod process_messages;
+mod record_activity;
+mod send_selected_message;
+mod handle_key;
+mod key_hints;
+mod update_bindings;
+
+use std::collections::VecDeque;
+
+use tokio::sync::mpsc;
+
+use crate::module::LastError;
+use crate::util::io::bus::MessageBus;
+
+pub use activity::{ActivityEntry, Severity, SeverityFilter};
+
+/// Oldest entries are dropped once the log holds this many
+const ACTIVITY_LOG_CAPACITY: usize = 200;
+/// Source stamped on outbound messages, so the listener skips our own sends
+const COM_SOURCE: &str = "com_handler";
+
+#[derive(Debug, Clone)]
+enum HandlerMessage {
+    Activity(ActivityEntry),
+}
+
+/// Keeps a bounded, filterable activity log for a com module, fed from its `bus_topic`.
+/// One instance per module, keyed by topic.
+#[derive(Debug)]
+pub struct ComHandler {
+    bus_topic: String,
+    /// Handler key (`com_<bus_topic>`), used to register its tasks and as its log target
+    handler_key: String,
+    activity: VecDeque<ActivityEntry>,
+    /// Configured `activity_log` lines have been copied into the buffer
+    seeded: bool,
+    filter: SeverityFilter,
+    /// Entries scrolled back from the newest
+    scroll_offset: usize,
+    /// Index into the module's configured outbound `messages`
+    selected_message: usize,
+    message_bus: MessageBus,
+    message_tx: mpsc::UnboundedSender<HandlerMessage>,
+    message_rx: mpsc::UnboundedReceiver<HandlerMessage>,
+    last_error: LastError,
+}
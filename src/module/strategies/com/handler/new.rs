@@ -0,0 +1,32 @@
+use std::collections::VecDeque;
+
+use tokio::sync::mpsc;
+
+use crate::module::LastError;
+use crate::util::io::bus::MessageBus;
+
+use super::{ComHandler, SeverityFilter, ACTIVITY_LOG_CAPACITY};
+
+impl ComHandler {
+    pub fn new(message_bus: MessageBus, bus_topic: String) -> Self {
+        let (message_tx, message_rx) = mpsc::unbounded_channel();
+
+        let mut handler = Self {
+            handler_key: format!("com_{}", bus_topic),
+            bus_topic,
+            activity: VecDeque::with_capacity(ACTIVITY_LOG_CAPACITY),
+            seeded: false,
+            filter: SeverityFilter::All,
+            scroll_offset: 0,
+            selected_message: 0,
+            message_bus,
+            message_tx,
+            message_rx,
+            last_error: LastError::default(),
+        };
+
+        handler.start_activity_listener();
+
+        handler
+    }
+}
@@ -0,0 +1,11 @@
+use super::{ComHandler, HandlerMessage};
+
+impl ComHandler {
+    pub(in crate::module) fn process_messages(&mut self) {
+        while let Ok(msg) = self.message_rx.try_recv() {
+            match msg {
+                HandlerMessage::Activity(entry) => self.record_activity(entry),
+            }
+        }
+    }
+}
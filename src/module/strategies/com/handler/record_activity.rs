@@ -0,0 +1,17 @@
+use super::{ActivityEntry, ComHandler, ACTIVITY_LOG_CAPACITY};
+
+impl ComHandler {
+    /// Append to the ring buffer, dropping the oldest entry when full
+    pub(in crate::module) fn record_activity(&mut self, entry: ActivityEntry) {
+        if self.activity.len() == ACTIVITY_LOG_CAPACITY {
+            self.activity.pop_front();
+        }
+
+        // Keep a scrolled-back view pinned to the same entries
+        if self.scroll_offset > 0 && self.filter.allows(entry.severity) {
+            self.scroll_offset += 1;
+        }
+
+        self.activity.push_back(entry);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    log_error,
+    log_info,
+    module::Module,
+    runtime::isolation::spawn_isolated,
+    util::io::bus::BusMessage,
+};
+
+use super::{ActivityEntry, ComHandler, HandlerMessage, Severity, COM_SOURCE};
+
+impl ComHandler {
+    /// Configured outbound `messages` for this module
+    pub(in crate::module) fn outbound_messages(module: &Module) -> Vec<String> {
+        module.config.binding_array("messages")
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    /// Publish the selected outbound message to the module's `bus_topic` for a transport to pick up
+    pub(in crate::module) fn send_selected_message(&mut self, module: &Module) {
+        let messages = Self::outbound_messages(module);
+        let Some(text) = messages.get(self.selected_message).cloned() else {
+            return;
+        };
+
+        log_info!(target: &self.handler_key, "📤 Sending '{}' on {}", text, self.bus_topic);
+        self.record_activity(ActivityEntry::new(Severity::Info, format!("→ TX: {}", text)));
+
+        let bus = self.message_bus.clone();
+        let topic = self.bus_topic.clone();
+        let tx = self.message_tx.clone();
+        let last_error = self.last_error.clone();
+        let log_target = self.handler_key.clone();
+
+        spawn_isolated(&self.handler_key, async move {
+            let payload = serde_json::json!({
+                "action": "send",
+                "message": text,
+            });
+
+            let bus_msg = BusMessage::new(topic.clone(), payload.to_string(), COM_SOURCE.to_string());
+
+            match bus.publish(bus_msg).await {
+                Ok(_) => last_error.clear(),
+                Err(e) => {
+                    log_error!(target: &log_target, "Failed to send on {}: {}", topic, e);
+                    last_error.set(format!("Failed to send '{}': {}", text, e));
+                    let _ = tx.send(HandlerMessage::Activity(ActivityEntry::new(
+                        Severity::Error,
+                        format!("✗ TX FAILED: {} ({})", text, e),
+                    )));
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,31 @@
+use crate::log_info;
+use crate::runtime::isolation::spawn_isolated;
+
+use super::{ActivityEntry, ComHandler, HandlerMessage, COM_SOURCE};
+
+impl ComHandler {
+    pub(in crate::module) fn start_activity_listener(&mut self) {
+        let tx = self.message_tx.clone();
+        let bus = self.message_bus.clone();
+        let bus_topic = self.bus_topic.clone();
+        let log_target = self.handler_key.clone();
+
+        spawn_isolated(&self.handler_key, async move {
+            log_info!(target: &log_target, "🔌 Starting com activity listener for topic: {}", bus_topic);
+            // Our own sends are logged when they go out
+            let mut receiver = bus
+                .subscribe_filtered(bus_topic, |msg| msg.source != COM_SOURCE)
+                .await;
+
+            while let Some(msg) = receiver.recv().await {
+                let entry = ActivityEntry::from_payload(&msg.payload);
+                if entry.text.is_empty() {
+                    continue;
+                }
+                if tx.send(HandlerMessage::Activity(entry)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,53 @@
+use std::any::Any;
+use crossterm::event::KeyCode;
+
+use crate::{
+    module::{
+        trait_module_handler::ModuleHandler,
+        Module,
+        QuickAction,
+    },
+    util::io::event::AppEvent,
+};
+use crate::module::strategies::com::handler::ComHandler;
+
+impl ModuleHandler for ComHandler {
+    fn handle_key(&mut self, key_code: KeyCode, module: &mut Module) -> Option<AppEvent> {
+        self._handle_key(key_code, module)
+    }
+
+    fn handle_event(&mut self, _event: &AppEvent, _module: &mut Module) -> color_eyre::Result<bool> {
+        Ok(false)
+    }
+
+    fn update_bindings(&mut self, module: &mut Module) {
+        self._update_bindings(module)
+    }
+
+    fn module_type(&self) -> &str {
+        "com"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn last_error(&self) -> Option<(chrono::DateTime<chrono::Utc>, String)> {
+        self.last_error.get()
+    }
+
+    fn key_hints(&self) -> Vec<(KeyCode, &str)> {
+        self._key_hints()
+    }
+
+    fn quick_actions(&self) -> &'static [QuickAction] {
+        &[
+            QuickAction { trigger: 'f', key: Some(KeyCode::Char('f')), label: "Filter", opens_detail: false },
+            QuickAction { trigger: 's', key: Some(KeyCode::Enter), label: "Send", opens_detail: false },
+        ]
+    }
+}
@@ -0,0 +1,59 @@
+use crate::module::Module;
+
+use super::{ActivityEntry, ComHandler};
+
+impl ComHandler {
+    pub(in crate::module) fn _update_bindings(&mut self, module: &mut Module) {
+        // Lines configured in the module file become the start of the log
+        if !self.seeded {
+            self.seeded = true;
+            let configured: Vec<String> = module.config.binding_array("activity_log")
+                .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(str::to_string).collect())
+                .unwrap_or_default();
+
+            let received: Vec<ActivityEntry> = self.activity.drain(..).collect();
+            for line in configured {
+                self.record_activity(ActivityEntry::from_payload(&line));
+            }
+            for entry in received {
+                self.record_activity(entry);
+            }
+        }
+
+        self.process_messages();
+
+        let visible: Vec<&ActivityEntry> = self.activity
+            .iter()
+            .filter(|e| self.filter.allows(e.severity))
+            .collect();
+
+        self.scroll_offset = self.scroll_offset.min(visible.len().saturating_sub(1));
+
+        module.config.bindings.insert(
+            "activity_log".to_string(),
+            serde_json::json!(visible.iter().map(|e| e.display_line()).collect::<Vec<_>>()),
+        );
+
+        module.config.bindings.insert(
+            "activity_entries".to_string(),
+            serde_json::json!(visible),
+        );
+
+        module.config.bindings.insert(
+            "severity_filter".to_string(),
+            serde_json::json!(self.filter.label()),
+        );
+
+        module.config.bindings.insert(
+            "scroll_offset".to_string(),
+            serde_json::json!(self.scroll_offset),
+        );
+
+        let message_count = Self::outbound_messages(module).len();
+        self.selected_message = self.selected_message.min(message_count.saturating_sub(1));
+        module.config.bindings.insert(
+            "selected_message".to_string(),
+            serde_json::json!(self.selected_message),
+        );
+    }
+}
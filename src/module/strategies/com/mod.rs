@@ -1,3 +1,4 @@
+pub mod handler;
 mod validation;
 
 use serde::{Deserialize, Serialize};
@@ -6,11 +6,12 @@ impl ConfigValidator {
 
         let has_toggle = b.state.is_some() && b.label.is_some();
         let has_activity = b.activity_log.is_some();
+        let has_outbound = b.messages.as_ref().is_some_and(|m| !m.is_empty());
 
-        if !has_toggle && !has_activity {
+        if !has_toggle && !has_activity && !has_outbound {
             return Err(ValidationError {
                 field: "bindings".to_string(),
-                error: "Must have either toggle switch fields or activity_log".to_string(),
+                error: "Must have toggle switch fields, activity_log or messages".to_string(),
             }.into());
         }
 
@@ -0,0 +1,57 @@
+use crossterm::event::KeyCode;
+
+use crate::{
+    module::{
+        strategies::knowledge::handler::KnowledgeHandler,
+        Module,
+    },
+    util::io::event::AppEvent,
+};
+
+impl KnowledgeHandler {
+    pub(in crate::module) fn _handle_key(&mut self, key_code: KeyCode, module: &mut Module) -> Option<AppEvent> {
+        let module_name = module.config.name.clone();
+        let min_score = module.config.binding_f64("min_score");
+        let session = self.session_for(module);
+
+        match key_code {
+            KeyCode::Char(ch) => {
+                session.query.push(ch);
+                None
+            }
+            KeyCode::Backspace => {
+                session.query.pop();
+                if session.query.is_empty() {
+                    // Clearing the search box goes back to the document list
+                    session.searched_query = None;
+                    session.results.clear();
+                    session.selected = 0;
+                }
+                None
+            }
+            KeyCode::Up => {
+                session.selected = session.selected.saturating_sub(1);
+                None
+            }
+            KeyCode::Down => {
+                if session.selected + 1 < session.entry_count() {
+                    session.selected += 1;
+                }
+                None
+            }
+            KeyCode::Enter => {
+                let query = session.query.trim().to_string();
+                let is_new_query = !query.is_empty()
+                    && session.searched_query.as_deref() != Some(query.as_str());
+
+                if is_new_query {
+                    self.run_search(&module_name, min_score);
+                    None
+                } else {
+                    session.selected_path().map(AppEvent::OpenDocument)
+                }
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crossterm::event::KeyCode;
+
+use crate::module::strategies::knowledge::handler::KnowledgeHandler;
+
+impl KnowledgeHandler {
+    /// Keys usable in the search view, shown as the card's footer and in the `?` overlay
+    pub(in crate::module) fn _key_hints(&self) -> Vec<(KeyCode, &'static str)> {
+        vec![
+            (KeyCode::Enter, "Search/Open"),
+            (KeyCode::Up, "Select"),
+            (KeyCode::Down, "Select"),
+            (KeyCode::Backspace, "Erase"),
+        ]
+    }
+}
@@ -0,0 +1,50 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{
+    log_warn,
+    module::{
+        strategies::knowledge::handler::{KnowledgeHandler, RECENT_DOCUMENT_LIMIT},
+        Module,
+    },
+};
+
+impl KnowledgeHandler {
+    /// Files directly under `knowledge_dir`, newest first
+    pub(super) fn list_documents(knowledge_dir: &Path) -> Vec<PathBuf> {
+        let entries = match fs::read_dir(knowledge_dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                log_warn!("Could not read knowledge directory {}: {}", knowledge_dir.display(), e);
+                return Vec::new();
+            }
+        };
+
+        let mut documents: Vec<(SystemTime, PathBuf)> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+            .map(|entry| {
+                let modified = entry.metadata()
+                    .and_then(|m| m.modified())
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                (modified, entry.path())
+            })
+            .collect();
+
+        documents.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        documents.truncate(RECENT_DOCUMENT_LIMIT);
+        documents.into_iter().map(|(_, path)| path).collect()
+    }
+
+    /// Re-list `module`'s documents, keeping the selection in range
+    pub(super) fn reload_documents(&mut self, module: &Module) {
+        let documents = Self::list_documents(&module.path.join("knowledge"));
+        let session = self.session_for(module);
+        session.documents = documents;
+        session.selected = session.selected.min(session.entry_count().saturating_sub(1));
+    }
+}
@@ -0,0 +1,65 @@
+mod new;
+mod handle_key;
+mod key_hints;
+mod update_bindings;
+mod list_documents;
+mod run_search;
+mod trait_module_handler;
+
+use std::{collections::HashMap, path::PathBuf};
+
+use crate::{
+    module::strategies::llm::database::KnowledgeChunk,
+    util::database::Database,
+};
+
+/// How many documents the card lists before the search box takes over
+const RECENT_DOCUMENT_LIMIT: usize = 20;
+/// How many FTS hits to keep per query
+const SEARCH_RESULT_LIMIT: usize = 15;
+
+/// Browses and searches knowledge modules without involving an LLM.
+///
+/// A single handler serves every `knowledge` module, so browse/search state
+/// is kept per module name.
+#[derive(Debug)]
+pub struct KnowledgeHandler {
+    database: Database,
+    sessions: HashMap<String, KnowledgeSession>,
+}
+
+/// Per-module browse/search state
+#[derive(Debug, Default)]
+struct KnowledgeSession {
+    /// Files under the module's `knowledge/` directory, most recently modified first
+    documents: Vec<PathBuf>,
+    query: String,
+    /// Query the current `results` were produced for
+    searched_query: Option<String>,
+    results: Vec<KnowledgeChunk>,
+    selected: usize,
+}
+
+impl KnowledgeSession {
+    fn is_showing_results(&self) -> bool {
+        self.searched_query.is_some()
+    }
+
+    fn entry_count(&self) -> usize {
+        if self.is_showing_results() {
+            self.results.len()
+        } else {
+            self.documents.len()
+        }
+    }
+
+    fn selected_path(&self) -> Option<String> {
+        if self.is_showing_results() {
+            self.results.get(self.selected).map(|chunk| chunk.source_file.clone())
+        } else {
+            self.documents
+                .get(self.selected)
+                .map(|path| path.to_string_lossy().to_string())
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use std::collections::HashMap;
+
+use crate::{
+    module::strategies::knowledge::handler::KnowledgeHandler,
+    util::database::Database,
+};
+
+impl KnowledgeHandler {
+    pub fn new(database: Database) -> Self {
+        Self {
+            database,
+            sessions: HashMap::new(),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    log_debug,
+    log_error,
+    module::strategies::{
+        knowledge::handler::{KnowledgeHandler, SEARCH_RESULT_LIMIT},
+        llm::database::LlmDatabase,
+    },
+};
+
+impl KnowledgeHandler {
+    /// Run the session's query against the FTS index, keeping only this module's chunks
+    /// that score at least `min_score`
+    pub(super) fn run_search(&mut self, module_name: &str, min_score: Option<f64>) {
+        let Some(session) = self.sessions.get_mut(module_name) else {
+            return;
+        };
+
+        let query = session.query.trim().to_string();
+        session.selected = 0;
+
+        if query.is_empty() {
+            session.searched_query = None;
+            session.results.clear();
+            return;
+        }
+
+        // Other knowledge modules share the index, so over-fetch before filtering
+        let results = match self.database.search_knowledge_filtered(&query, &[], SEARCH_RESULT_LIMIT * 4, min_score) {
+            Ok(chunks) => chunks
+                .into_iter()
+                .filter(|chunk| chunk.category == module_name)
+                .take(SEARCH_RESULT_LIMIT)
+                .collect(),
+            Err(e) => {
+                log_error!("Knowledge search failed for '{}': {}", module_name, e);
+                Vec::new()
+            }
+        };
+
+        log_debug!("Knowledge search '{}' in '{}': {} results", query, module_name, results.len());
+
+        session.results = results;
+        session.searched_query = Some(query);
+    }
+}
@@ -0,0 +1,52 @@
+use std::any::Any;
+use crossterm::event::KeyCode;
+
+use crate::{
+    module::{
+        strategies::knowledge::handler::KnowledgeHandler,
+        trait_module_handler::ModuleHandler,
+        Module,
+        QuickAction,
+    },
+    util::io::event::AppEvent,
+};
+
+impl ModuleHandler for KnowledgeHandler {
+    fn handle_key(&mut self, key_code: KeyCode, module: &mut Module) -> Option<AppEvent> {
+        self._handle_key(key_code, module)
+    }
+
+    fn handle_event(&mut self, _event: &AppEvent, _module: &mut Module) -> color_eyre::Result<bool> {
+        Ok(false)
+    }
+
+    fn update_bindings(&mut self, module: &mut Module) {
+        self._update_bindings(module);
+    }
+
+    fn reload(&mut self, module: &mut Module) {
+        self.reload_documents(module);
+    }
+
+    fn module_type(&self) -> &str {
+        "knowledge"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn key_hints(&self) -> Vec<(KeyCode, &str)> {
+        self._key_hints()
+    }
+
+    fn quick_actions(&self) -> &'static [QuickAction] {
+        &[
+            QuickAction { trigger: 's', key: None, label: "Search", opens_detail: true },
+        ]
+    }
+}
@@ -0,0 +1,54 @@
+use serde_json::{json, Value};
+
+use crate::module::{
+    strategies::knowledge::handler::{KnowledgeHandler, KnowledgeSession},
+    Module,
+};
+
+impl KnowledgeHandler {
+    /// Session for `module`, listing its documents the first time it is seen
+    pub(super) fn session_for(&mut self, module: &Module) -> &mut KnowledgeSession {
+        self.sessions
+            .entry(module.config.name.clone())
+            .or_insert_with(|| KnowledgeSession {
+                documents: Self::list_documents(&module.path.join("knowledge")),
+                ..Default::default()
+            })
+    }
+
+    pub(in crate::module) fn _update_bindings(&mut self, module: &mut Module) {
+        let session = self.session_for(module);
+
+        let recent_documents: Vec<Value> = session.documents
+            .iter()
+            .map(|path| {
+                json!({
+                    "name": path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+                    "path": path.to_string_lossy(),
+                })
+            })
+            .collect();
+
+        let search_results: Vec<Value> = session.results
+            .iter()
+            .map(|chunk| {
+                json!({
+                    "title": chunk.title,
+                    "path": chunk.source_file,
+                    "snippet": chunk.snippet
+                        .clone()
+                        .unwrap_or_else(|| chunk.body.chars().take(160).collect()),
+                    "score": chunk.score,
+                })
+            })
+            .collect();
+
+        let bindings = &mut module.config.bindings;
+        bindings.insert("document_count".to_string(), json!(session.documents.len()));
+        bindings.insert("recent_documents".to_string(), Value::Array(recent_documents));
+        bindings.insert("search_query".to_string(), json!(session.query));
+        bindings.insert("searched_query".to_string(), json!(session.searched_query));
+        bindings.insert("search_results".to_string(), Value::Array(search_results));
+        bindings.insert("selected_index".to_string(), json!(session.selected));
+    }
+}
@@ -1,3 +1,7 @@
+pub mod handler;
+
+pub use handler::KnowledgeHandler;
+
 use serde::{Deserialize, Serialize};
 
 use crate::module::BaseModuleConfig;
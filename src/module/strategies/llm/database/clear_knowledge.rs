@@ -2,8 +2,9 @@ use crate::util::database::Database;
 
 impl Database {
     pub(in crate::module) fn _llm__clear_knowledge(&self) -> rusqlite::Result<()> {
-        let conn = self.knowledge_conn.lock().unwrap();
+        let conn = Self::connection(&self.knowledge_conn)?;
         conn.execute("DELETE FROM knowledge", [])?;
+        conn.execute("DELETE FROM knowledge_sources", [])?;
         Ok(())
     }
 }
@@ -5,7 +5,7 @@ use crate::module::strategies::llm::database::ChatMessage;
 
 impl Database {
     pub(in crate::module) fn _llm__get_chat_history(&self, session_id: &str, limit: usize) -> rusqlite::Result<Vec<ChatMessage>> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         let mut stmt = conn.prepare(
             "SELECT id, session_id, role, content, timestamp, module_name
              FROM chat_messages
@@ -1,9 +1,11 @@
 use crate::util::database::Database;
 
+use super::KnowledgeTokenizer;
+
 impl Database {
     pub(in crate::module) fn _llm__init_schema(&self) -> rusqlite::Result<()> {
         {
-            let conn = self.app_conn.lock().unwrap();
+            let conn = Self::connection(&self.app_conn)?;
             conn.execute(
                 "CREATE TABLE IF NOT EXISTS chat_messages (
                         id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -17,26 +19,17 @@ impl Database {
             )?;
         }
 
-        // Knowledge base FTS5 virtual table
-        {
-            let conn = self.knowledge_conn.lock().unwrap();
-            conn.execute(
-                "CREATE VIRTUAL TABLE IF NOT EXISTS knowledge USING fts5(
-                        source_file,
-                        domain,
-                        category,
-                        title,
-                        body,
-                        chunk_index UNINDEXED,
-                        metadata UNINDEXED
-                    )",
-                [],
-            )?;
+        self.apply_migrations(&self.knowledge_conn, "llm_knowledge", super::migrations::KNOWLEDGE_MIGRATIONS)?;
+
+        // Knowledge base FTS5 virtual table. A rebuilt index is empty, so force the
+        // ingester's checksum comparison to fail and re-ingest on this startup.
+        if self._llm__init_knowledge_table(KnowledgeTokenizer::from_env())? {
+            self.save_module_state("knowledge_checksum", "0")?;
         }
 
         // Analytics tables
         {
-            let conn = self.analytics_conn.lock().unwrap();
+            let conn = Self::connection(&self.analytics_conn)?;
             conn.execute(
                 "CREATE TABLE IF NOT EXISTS query_stats (
                         id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -60,6 +53,6 @@ impl Database {
             )?;
         }
 
-        Ok(())
+        self.apply_migrations(&self.app_conn, "llm", super::migrations::APP_MIGRATIONS)
     }
 }
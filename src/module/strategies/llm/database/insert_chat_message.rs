@@ -5,7 +5,7 @@ use crate::module::strategies::llm::database::ChatMessage;
 
 impl Database {
     pub(in crate::module) fn _llm__insert_chat_message(&self, message: ChatMessage) -> rusqlite::Result<i64> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         conn.execute(
             "INSERT INTO chat_messages (session_id, role, content, timestamp, module_name)
              VALUES (?1, ?2, ?3, ?4, ?5)",
@@ -5,7 +5,7 @@ use crate::module::strategies::llm::database::KnowledgeChunk;
 
 impl Database {
     pub(in crate::module) fn _llm__insert_knowledge_chunk(&self, chunk: KnowledgeChunk) -> rusqlite::Result<()> {
-        let conn = self.knowledge_conn.lock().unwrap();
+        let conn = Self::connection(&self.knowledge_conn)?;
         conn.execute(
             "INSERT INTO knowledge (source_file, domain, category, title, body, chunk_index, metadata)
              VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
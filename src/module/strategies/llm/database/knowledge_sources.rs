@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+/// What a source file looked like when it was last indexed
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnowledgeSource {
+    pub source_file: String,
+    /// Knowledge module the file belongs to
+    pub category: String,
+    /// Modified time, seconds since the epoch
+    pub modified: i64,
+    pub size: u64,
+    pub content_hash: String,
+}
+
+impl Database {
+    pub(in crate::module) fn _llm__get_knowledge_sources(&self) -> rusqlite::Result<HashMap<String, KnowledgeSource>> {
+        let conn = Self::connection(&self.knowledge_conn)?;
+        let mut stmt = conn.prepare(
+            "SELECT source_file, category, modified, size, content_hash FROM knowledge_sources"
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            Ok(KnowledgeSource {
+                source_file: row.get(0)?,
+                category: row.get(1)?,
+                modified: row.get(2)?,
+                size: row.get::<_, i64>(3)? as u64,
+                content_hash: row.get(4)?,
+            })
+        })?;
+
+        let mut sources = HashMap::new();
+        for row in rows {
+            let source = row?;
+            sources.insert(source.source_file.clone(), source);
+        }
+
+        Ok(sources)
+    }
+
+    pub(in crate::module) fn _llm__upsert_knowledge_source(&self, source: &KnowledgeSource) -> rusqlite::Result<()> {
+        let indexed_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        let conn = Self::connection(&self.knowledge_conn)?;
+        conn.execute(
+            "INSERT OR REPLACE INTO knowledge_sources (source_file, category, modified, size, content_hash, indexed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                source.source_file,
+                source.category,
+                source.modified,
+                source.size as i64,
+                source.content_hash,
+                indexed_at
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Remove one file's chunks and its tracking row, leaving everything else indexed
+    pub(in crate::module) fn _llm__clear_knowledge_for_source(&self, source_file: &str) -> rusqlite::Result<()> {
+        let mut conn = Self::connection(&self.knowledge_conn)?;
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM knowledge WHERE source_file = ?1", params![source_file])?;
+        tx.execute("DELETE FROM knowledge_sources WHERE source_file = ?1", params![source_file])?;
+        tx.commit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::strategies::llm::database::{KnowledgeChunk, LlmDatabase};
+
+    fn chunk(source_file: &str) -> KnowledgeChunk {
+        KnowledgeChunk {
+            id: None,
+            source_file: source_file.to_string(),
+            domain: "general".to_string(),
+            category: "manuals".to_string(),
+            title: "Water".to_string(),
+            body: "Boil water before drinking".to_string(),
+            chunk_index: 0,
+            metadata: "{}".to_string(),
+            snippet: None,
+            score: None,
+        }
+    }
+
+    fn source(source_file: &str) -> KnowledgeSource {
+        KnowledgeSource {
+            source_file: source_file.to_string(),
+            category: "manuals".to_string(),
+            modified: 1,
+            size: 26,
+            content_hash: "abc".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_clear_for_source_leaves_other_files() {
+        let db = Database::in_memory().unwrap();
+        for file in ["a.txt", "b.txt"] {
+            db.insert_knowledge_chunk(chunk(file)).unwrap();
+            db.upsert_knowledge_source(&source(file)).unwrap();
+        }
+
+        db.clear_knowledge_for_source("a.txt").unwrap();
+
+        let results = db.search_knowledge("water", &[], 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source_file, "b.txt");
+
+        let sources = db.get_knowledge_sources().unwrap();
+        assert_eq!(sources.keys().collect::<Vec<_>>(), vec!["b.txt"]);
+    }
+}
@@ -0,0 +1,140 @@
+use rusqlite::OptionalExtension;
+
+use crate::log_info;
+use crate::util::database::Database;
+use crate::module::strategies::llm::LOG_TARGET;
+
+/// Tokenizer for the `knowledge` FTS5 table, chosen with `KNOWLEDGE_TOKENIZER`.
+///
+/// - `default`: SQLite's plain `unicode61`, what the table has always used. Exact words
+///   only, and some accented letters are kept distinct.
+/// - `unicode61`: also strips every diacritic, so "café" matches "cafe". Good for mixed-language
+///   manuals; the cost is that words differing only by accents become indistinguishable.
+/// - `porter`: English stemming on top of `unicode61`, so "generating" matches "generator".
+///   Best recall for English text, but stems non-English words badly and matches more loosely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KnowledgeTokenizer {
+    #[default]
+    Default,
+    Unicode61,
+    Porter,
+}
+
+impl KnowledgeTokenizer {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "" | "default" => Some(Self::Default),
+            "unicode61" => Some(Self::Unicode61),
+            "porter" => Some(Self::Porter),
+            _ => None,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        std::env::var("KNOWLEDGE_TOKENIZER")
+            .ok()
+            .and_then(|v| Self::parse(&v))
+            .unwrap_or_default()
+    }
+
+    /// Value of the FTS5 `tokenize` option, or `None` to leave it out
+    pub fn fts5_option(&self) -> Option<&'static str> {
+        match self {
+            Self::Default => None,
+            Self::Unicode61 => Some("unicode61 remove_diacritics 2"),
+            Self::Porter => Some("porter unicode61 remove_diacritics 2"),
+        }
+    }
+
+    fn create_table_sql(&self) -> String {
+        let tokenize = self
+            .fts5_option()
+            .map(|option| format!(",\n                        tokenize = '{}'", option))
+            .unwrap_or_default();
+
+        format!(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS knowledge USING fts5(
+                        source_file,
+                        domain,
+                        category,
+                        title,
+                        body,
+                        chunk_index UNINDEXED,
+                        metadata UNINDEXED{}
+                    )",
+            tokenize
+        )
+    }
+}
+
+/// The `tokenize` option in a `CREATE VIRTUAL TABLE` statement, if any
+fn declared_tokenizer(create_sql: &str) -> Option<String> {
+    let start = create_sql.find("tokenize")?;
+    let rest = &create_sql[start..];
+    let open = rest.find('\'')? + 1;
+    let close = rest[open..].find('\'')? + open;
+    Some(rest[open..close].to_string())
+}
+
+impl Database {
+    /// Create the `knowledge` table with `tokenizer`. If it exists with a different
+    /// tokenizer it is dropped and rebuilt, and `true` is returned so the caller can
+    /// schedule a re-ingest.
+    pub(in crate::module) fn _llm__init_knowledge_table(&self, tokenizer: KnowledgeTokenizer) -> rusqlite::Result<bool> {
+        let conn = Self::connection(&self.knowledge_conn)?;
+
+        let existing: Option<String> = conn
+            .query_row(
+                "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'knowledge'",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        let rebuilt = match existing {
+            Some(sql) if declared_tokenizer(&sql).as_deref() != tokenizer.fts5_option() => {
+                log_info!(target: LOG_TARGET, "🔤 Knowledge tokenizer changed to {:?}; rebuilding the index", tokenizer);
+                conn.execute("DROP TABLE knowledge", [])?;
+                // Nothing is indexed any more, so every file counts as changed
+                conn.execute("DELETE FROM knowledge_sources", [])?;
+                true
+            }
+            _ => false,
+        };
+
+        conn.execute(&tokenizer.create_table_sql(), [])?;
+        Ok(rebuilt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changing_tokenizer_rebuilds_table() {
+        let db = Database::in_memory().unwrap();
+
+        assert!(!db._llm__init_knowledge_table(KnowledgeTokenizer::Default).unwrap());
+        assert!(db._llm__init_knowledge_table(KnowledgeTokenizer::Porter).unwrap());
+        assert!(!db._llm__init_knowledge_table(KnowledgeTokenizer::Porter).unwrap());
+
+        let conn = db.knowledge_conn.get().unwrap();
+        conn.execute(
+            "INSERT INTO knowledge (source_file, domain, category, title, body, chunk_index, metadata)
+             VALUES ('a.md', 'power', 'manual', 'Generator', 'Starting the generator', 0, '{}')",
+            [],
+        ).unwrap();
+        let hits: i64 = conn
+            .query_row("SELECT COUNT(*) FROM knowledge WHERE knowledge MATCH 'generating'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(hits, 1);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(KnowledgeTokenizer::parse("Porter"), Some(KnowledgeTokenizer::Porter));
+        assert_eq!(KnowledgeTokenizer::parse(""), Some(KnowledgeTokenizer::Default));
+        assert_eq!(KnowledgeTokenizer::parse("trigram"), None);
+    }
+}
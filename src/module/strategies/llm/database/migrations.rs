@@ -0,0 +1,26 @@
+use crate::util::database::Migration;
+
+/// LLM schema changes for the app database (`chat_messages`). Append only.
+pub(super) const APP_MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "index chat messages by session",
+        sql: "CREATE INDEX IF NOT EXISTS idx_chat_messages_session ON chat_messages(session_id, timestamp)",
+    },
+];
+
+/// LLM schema changes for the knowledge database. Append only.
+pub(super) const KNOWLEDGE_MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "track indexed knowledge source files",
+        sql: "CREATE TABLE IF NOT EXISTS knowledge_sources (
+                source_file TEXT PRIMARY KEY,
+                category TEXT NOT NULL,
+                modified INTEGER NOT NULL,
+                size INTEGER NOT NULL,
+                content_hash TEXT NOT NULL,
+                indexed_at INTEGER NOT NULL
+            )",
+    },
+];
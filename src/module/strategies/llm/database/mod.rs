@@ -1,10 +1,13 @@
 mod chat_message;
 mod init_llm_schema;
+mod migrations;
 mod insert_chat_message;
 mod get_chat_history;
 mod insert_knowledge_chunk;
 mod search_knowledge;
 mod clear_knowledge;
+mod knowledge_tokenizer;
+mod knowledge_sources;
 mod trait_llm_database;
 
 use rusqlite::{params, Result};
@@ -15,6 +18,8 @@ use crate::util::database::Database;
 
 pub use chat_message::{ChatMessage};
 pub use trait_llm_database::LlmDatabase;
+pub use knowledge_tokenizer::KnowledgeTokenizer;
+pub use knowledge_sources::KnowledgeSource;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnowledgeChunk {
@@ -26,8 +31,22 @@ pub struct KnowledgeChunk {
     pub body: String,
     pub chunk_index: i32,
     pub metadata: String, // JSON string
+    /// Excerpt of `body` around the matched terms, wrapped in [`MATCH_START`]/[`MATCH_END`].
+    /// Only set on search results.
+    #[serde(default)]
+    pub snippet: Option<String>,
+    /// BM25 relevance of a search result, negated so higher is better. Only set on search results.
+    #[serde(default)]
+    pub score: Option<f64>,
 }
 
+/// Markers `execute_search` puts around matched terms in [`KnowledgeChunk::snippet`]
+pub const MATCH_START: &str = "⟦";
+pub const MATCH_END: &str = "⟧";
+
+/// Tokens of context FTS5 keeps around matches in a snippet
+const SNIPPET_TOKENS: i32 = 16;
+
 fn sanitize_fts5_query(query: &str) -> String {
     // More permissive sanitization - keep common search terms
     query
@@ -40,27 +59,30 @@ fn sanitize_fts5_query(query: &str) -> String {
 }
 
 fn execute_search(db: &Database, search_query: &str, domains: &[String], limit: usize) -> Result<Vec<KnowledgeChunk>> {
-    let conn = db.knowledge_conn.lock().unwrap();
+    let conn = Database::connection(&db.knowledge_conn)?;
 
     let sql = if domains.is_empty() {
-        "SELECT rowid, source_file, domain, category, title, body, chunk_index, metadata
+        "SELECT rowid, source_file, domain, category, title, body, chunk_index, metadata,
+                snippet(knowledge, 4, ?3, ?4, '…', ?5), -bm25(knowledge)
          FROM knowledge WHERE knowledge MATCH ?1 ORDER BY rank LIMIT ?2".to_string()
     } else {
         let domain_placeholders = domains.iter().map(|_| "?").collect::<Vec<_>>().join(",");
         format!(
-            "SELECT rowid, source_file, domain, category, title, body, chunk_index, metadata
-             FROM knowledge WHERE knowledge MATCH ?1 AND domain IN ({}) ORDER BY rank LIMIT ?{}",
-            domain_placeholders,
-            domains.len() + 2
+            "SELECT rowid, source_file, domain, category, title, body, chunk_index, metadata,
+                    snippet(knowledge, 4, ?3, ?4, '…', ?5), -bm25(knowledge)
+             FROM knowledge WHERE knowledge MATCH ?1 AND domain IN ({}) ORDER BY rank LIMIT ?2",
+            domain_placeholders
         )
     };
 
     let mut stmt = conn.prepare(&sql)?;
 
+    // Snippet arguments (?3-?5) come right after the query and limit so they stay
+    // numbered the same whether or not domains follow
     let limit_str = limit.to_string();
-    let mut params_vec = vec![search_query];
+    let snippet_tokens = SNIPPET_TOKENS.to_string();
+    let mut params_vec = vec![search_query, limit_str.as_str(), MATCH_START, MATCH_END, snippet_tokens.as_str()];
     params_vec.extend(domains.iter().map(|d| d.as_str()));
-    params_vec.push(&limit_str);
 
     let rows = stmt.query_map(rusqlite::params_from_iter(params_vec), |row| {
         Ok(KnowledgeChunk {
@@ -72,6 +94,8 @@ fn execute_search(db: &Database, search_query: &str, domains: &[String], limit:
             body: row.get(5)?,
             chunk_index: row.get(6)?,
             metadata: row.get(7)?,
+            snippet: row.get(8)?,
+            score: row.get(9)?,
         })
     })?;
 
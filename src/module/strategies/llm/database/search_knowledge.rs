@@ -7,17 +7,26 @@ use crate::module::strategies::llm::database::{
     sanitize_fts5_query,
     KnowledgeChunk
 };
+use crate::module::strategies::llm::LOG_TARGET;
 
 impl Database {
-    pub(in crate::module) fn _llm__search_knowledge(&self, query: &str, domains: &[String], limit: usize) -> rusqlite::Result<Vec<KnowledgeChunk>> {
+    /// Search the knowledge index, best matches first. With `min_score`, results whose
+    /// [`KnowledgeChunk::score`] falls below it are dropped.
+    pub(in crate::module) fn _llm__search_knowledge(
+        &self,
+        query: &str,
+        domains: &[String],
+        limit: usize,
+        min_score: Option<f64>,
+    ) -> rusqlite::Result<Vec<KnowledgeChunk>> {
         let clean_query = sanitize_fts5_query(query);
         if clean_query.trim().is_empty() {
             return Ok(Vec::new());
         }
 
-        log_debug!("Searching knowledge with query: '{}' (sanitized from '{}')", clean_query, query);
+        log_debug!(target: LOG_TARGET, "Searching knowledge with query: '{}' (sanitized from '{}')", clean_query, query);
         if !domains.is_empty() {
-            log_debug!("Filtering by domains: {:?}", domains);
+            log_debug!(target: LOG_TARGET, "Filtering by domains: {:?}", domains);
         }
 
         // Try different search strategies
@@ -25,23 +34,23 @@ impl Database {
 
         // Strategy 1: Try exact phrase match with AND
         results = execute_search(self, &clean_query, domains, limit * 2)?;
-        log_debug!("Strategy 1 (AND search): found {} results", results.len());
+        log_debug!(target: LOG_TARGET, "Strategy 1 (AND search): found {} results", results.len());
 
         // Strategy 2: If no results, try OR search
         if results.is_empty() && clean_query.contains(' ') {
             let or_query = clean_query.split_whitespace().collect::<Vec<_>>().join(" OR ");
-            log_debug!("Strategy 2 (OR search): trying '{}'", or_query);
+            log_debug!(target: LOG_TARGET, "Strategy 2 (OR search): trying '{}'", or_query);
             results = execute_search(self, &or_query, domains, limit * 2)?;
-            log_debug!("Strategy 2 (OR search): found {} results", results.len());
+            log_debug!(target: LOG_TARGET, "Strategy 2 (OR search): found {} results", results.len());
         }
 
         // Strategy 3: If still no results, try each word individually
         if results.is_empty() {
             let words: Vec<&str> = clean_query.split_whitespace().collect();
-            log_debug!("Strategy 3 (individual words): trying {} words", words.len());
+            log_debug!(target: LOG_TARGET, "Strategy 3 (individual words): trying {} words", words.len());
             for word in &words {
                 let word_results = execute_search(self, word, domains, limit)?;
-                log_debug!("  Word '{}': found {} results", word, word_results.len());
+                log_debug!(target: LOG_TARGET, "  Word '{}': found {} results", word, word_results.len());
                 if !word_results.is_empty() {
                     results.extend(word_results);
                     break; // Use first successful word
@@ -62,7 +71,51 @@ impl Database {
                 .collect();
         }
 
-        log_debug!("Final results: {} chunks", results.len());
+        if let Some(min_score) = min_score {
+            results.retain(|chunk| chunk.score.map_or(true, |score| score >= min_score));
+        }
+
+        log_debug!(target: LOG_TARGET, "Final results: {} chunks", results.len());
         Ok(results.into_iter().take(limit).collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::strategies::llm::database::LlmDatabase;
+
+    fn chunk(title: &str, body: &str) -> KnowledgeChunk {
+        KnowledgeChunk {
+            id: None,
+            source_file: "manual.md".to_string(),
+            domain: "power".to_string(),
+            category: "manuals".to_string(),
+            title: title.to_string(),
+            body: body.to_string(),
+            chunk_index: 0,
+            metadata: "{}".to_string(),
+            snippet: None,
+            score: None,
+        }
+    }
+
+    #[test]
+    fn test_results_carry_highlighted_snippet() {
+        let db = Database::in_memory().unwrap();
+        db.insert_knowledge_chunk(chunk("Generator", "Check the oil, then start the generator outside.")).unwrap();
+
+        let results = db.search_knowledge("generator", &[], 5).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].body.starts_with("Check the oil"));
+        assert!(results[0].snippet.as_deref().unwrap().contains("⟦generator⟧"));
+
+        let filtered = db.search_knowledge("generator", &["power".to_string()], 5).unwrap();
+        assert_eq!(filtered.len(), 1);
+
+        let score = results[0].score.unwrap();
+        assert!(score > 0.0);
+        assert_eq!(db.search_knowledge_filtered("generator", &[], 5, Some(score)).unwrap().len(), 1);
+        assert!(db.search_knowledge_filtered("generator", &[], 5, Some(score + 1.0)).unwrap().is_empty());
+    }
+}
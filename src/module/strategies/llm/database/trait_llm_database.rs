@@ -1,6 +1,8 @@
 use crate::util::database::Database;
 
-use super::{ChatMessage, KnowledgeChunk};
+use std::collections::HashMap;
+
+use super::{ChatMessage, KnowledgeChunk, KnowledgeSource};
 
 /// Trait to add LLM-specific database operations to Database
 pub trait LlmDatabase {
@@ -13,7 +15,14 @@ pub trait LlmDatabase {
     // Knowledge base operations
     fn insert_knowledge_chunk(&self, chunk: KnowledgeChunk) -> rusqlite::Result<()>;
     fn search_knowledge(&self, query: &str, domains: &[String], limit: usize) -> rusqlite::Result<Vec<KnowledgeChunk>>;
+    /// Like `search_knowledge`, but drops results scoring below `min_score` (see `KnowledgeChunk::score`)
+    fn search_knowledge_filtered(&self, query: &str, domains: &[String], limit: usize, min_score: Option<f64>) -> rusqlite::Result<Vec<KnowledgeChunk>>;
     fn clear_knowledge(&self) -> rusqlite::Result<()>;
+    fn clear_knowledge_for_source(&self, source_file: &str) -> rusqlite::Result<()>;
+
+    // Per-file index tracking for incremental ingestion
+    fn get_knowledge_sources(&self) -> rusqlite::Result<HashMap<String, KnowledgeSource>>;
+    fn upsert_knowledge_source(&self, source: &KnowledgeSource) -> rusqlite::Result<()>;
 }
 
 impl LlmDatabase for Database {
@@ -34,10 +43,26 @@ impl LlmDatabase for Database {
     }
 
     fn search_knowledge(&self, query: &str, domains: &[String], limit: usize) -> rusqlite::Result<Vec<KnowledgeChunk>> {
-        self._llm__search_knowledge(query, domains, limit)
+        self._llm__search_knowledge(query, domains, limit, None)
+    }
+
+    fn search_knowledge_filtered(&self, query: &str, domains: &[String], limit: usize, min_score: Option<f64>) -> rusqlite::Result<Vec<KnowledgeChunk>> {
+        self._llm__search_knowledge(query, domains, limit, min_score)
     }
 
     fn clear_knowledge(&self) -> rusqlite::Result<()> {
         self._llm__clear_knowledge()
     }
+
+    fn clear_knowledge_for_source(&self, source_file: &str) -> rusqlite::Result<()> {
+        self._llm__clear_knowledge_for_source(source_file)
+    }
+
+    fn get_knowledge_sources(&self) -> rusqlite::Result<HashMap<String, KnowledgeSource>> {
+        self._llm__get_knowledge_sources()
+    }
+
+    fn upsert_knowledge_source(&self, source: &KnowledgeSource) -> rusqlite::Result<()> {
+        self._llm__upsert_knowledge_source(source)
+    }
 }
@@ -0,0 +1,20 @@
+use crossterm::event::KeyCode;
+
+use super::LlmHandler;
+
+impl LlmHandler {
+    /// Keys usable in the chat, shown as the card's footer and in the `?` overlay
+    pub(in crate::module) fn _key_hints(&self) -> Vec<(KeyCode, &'static str)> {
+        let enter = match self.chat_manager.get_current_link() {
+            Some(_) => "Open Link",
+            None => "Send",
+        };
+        vec![
+            (KeyCode::Enter, enter),
+            (KeyCode::Tab, "Links"),
+            (KeyCode::BackTab, "Links"),
+            (KeyCode::Up, "Scroll"),
+            (KeyCode::Down, "Scroll"),
+        ]
+    }
+}
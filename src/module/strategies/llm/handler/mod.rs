@@ -4,6 +4,7 @@ mod submit_message;
 mod format_chat_history;
 mod trait_module_handler;
 mod handle_key;
+mod key_hints;
 mod update_bindings;
 
 use std::any::Any;
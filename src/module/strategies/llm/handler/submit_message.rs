@@ -1,4 +1,5 @@
 use crate::log_warn;
+use crate::module::strategies::llm::LOG_TARGET;
 
 use super::LlmHandler;
 
@@ -16,7 +17,7 @@ impl LlmHandler {
         let service = match &self.llm_service {
             Some(s) => s,
             None => {
-                log_warn!("No LLM service available");
+                log_warn!(target: LOG_TARGET, "No LLM service available");
                 return Ok(());
             }
         };
@@ -5,6 +5,7 @@ use crate::{
     module::{
         trait_module_handler::ModuleHandler,
         Module,
+        QuickAction,
     },
     util::io::event::AppEvent
 };
@@ -34,4 +35,14 @@ impl ModuleHandler for LlmHandler {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn key_hints(&self) -> Vec<(KeyCode, &str)> {
+        self._key_hints()
+    }
+
+    fn quick_actions(&self) -> &'static [QuickAction] {
+        &[
+            QuickAction { trigger: 'a', key: None, label: "Ask", opens_detail: true },
+        ]
+    }
 }
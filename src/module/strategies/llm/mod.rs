@@ -21,6 +21,9 @@ use crate::{
     module::{BaseModuleConfig, ModuleManager, ServiceDiscoveryConfig},
 };
 
+/// Log target for LLM handlers and knowledge search, so `LOG_LEVELS="llm=warn"` covers them
+const LOG_TARGET: &str = "llm";
+
 /// LLM module (chat interfaces)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LlmConfig {
@@ -57,20 +60,17 @@ pub async fn create_llm_service_if_available(
     let llm_modules = module_manager.get_modules_by_type("llm");
 
     if let Some(llm_module) = llm_modules.first() {
-        let model = llm_module.config.bindings
-            .get("model")
-            .and_then(|v| v.as_str())
-            .unwrap_or("search");
+        let model = llm_module.config.binding_str("model").unwrap_or("search");
 
         match model {
             "council" => {
                 // Council mode - service will be created per-advisor dynamically
-                log_debug!("Council mode: services created on-demand");
+                log_debug!(target: LOG_TARGET, "Council mode: services created on-demand");
                 Ok(Some(LlmService::new(database.clone())))
             },
             _ => {
                 // Existing local search/summarizer mode
-                log_debug!("Creating search-powered knowledge service");
+                log_debug!(target: LOG_TARGET, "Creating search-powered knowledge service");
                 let service = LlmService::new(database.clone());
                 Ok(Some(service))
             }
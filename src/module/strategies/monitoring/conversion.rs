@@ -0,0 +1,264 @@
+// src/module/strategies/monitoring/conversion.rs
+
+//! Calibration from raw sensor readings (ADC counts, say) to engineering units, applied as
+//! telemetry is ingested so history, thresholds and charts all see converted values.
+//!
+//! Per value (`a`, `b`) a module sets either `<key>_scale`/`<key>_offset` (`raw * scale + offset`)
+//! or `<key>_expr`, an arithmetic expression of the raw reading `x` such as `(x - 400) / 16.3`.
+//! `c` is a count and is never converted.
+
+use crate::module::{ModuleConfig, ValidationError};
+
+/// Functions an expression may call, each taking one argument
+const FUNCTIONS: &[(&str, fn(f64) -> f64)] = &[
+    ("abs", f64::abs),
+    ("sqrt", f64::sqrt),
+    ("ln", f64::ln),
+    ("log10", f64::log10),
+    ("exp", f64::exp),
+    ("round", f64::round),
+];
+
+/// How one raw reading becomes a value in display units
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conversion {
+    Linear { scale: f64, offset: f64 },
+    Expression(Expr),
+}
+
+impl Conversion {
+    /// The conversion for value `key` given its `_scale`, `_offset` and `_expr` bindings, if any
+    pub fn from_parts(
+        key: &str,
+        scale: Option<f64>,
+        offset: Option<f64>,
+        expr: Option<&str>,
+    ) -> Result<Option<Self>, ValidationError> {
+        let Some(source) = expr else {
+            return Ok((scale.is_some() || offset.is_some()).then(|| Conversion::Linear {
+                scale: scale.unwrap_or(1.0),
+                offset: offset.unwrap_or(0.0),
+            }));
+        };
+
+        let error = |error: String| ValidationError { field: format!("bindings.{}_expr", key), error };
+        if scale.is_some() || offset.is_some() {
+            return Err(error(format!("Use either {0}_expr or {0}_scale/{0}_offset, not both", key)));
+        }
+        Expr::parse(source).map(|expr| Some(Conversion::Expression(expr))).map_err(error)
+    }
+
+    pub fn apply(&self, raw: f64) -> f64 {
+        match self {
+            Conversion::Linear { scale, offset } => raw * scale + offset,
+            Conversion::Expression(expr) => expr.eval(raw),
+        }
+    }
+}
+
+/// Conversions for `a` and `b`; values without one pass through unchanged
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Conversions {
+    pub a: Option<Conversion>,
+    pub b: Option<Conversion>,
+}
+
+impl Conversions {
+    /// Read `a_scale`, `a_offset`, `a_expr` and the `b_` equivalents from a module's bindings
+    pub fn from_config(config: &ModuleConfig) -> Result<Self, ValidationError> {
+        Ok(Self { a: conversion_for("a", config)?, b: conversion_for("b", config)? })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.a.is_none() && self.b.is_none()
+    }
+
+    /// Converted `(a, b)` for a raw reading
+    pub fn apply(&self, raw_a: f64, raw_b: f64) -> (f64, f64) {
+        let convert = |conversion: &Option<Conversion>, raw: f64| conversion.as_ref().map_or(raw, |c| c.apply(raw));
+        (convert(&self.a, raw_a), convert(&self.b, raw_b))
+    }
+}
+
+fn conversion_for(key: &str, config: &ModuleConfig) -> Result<Option<Conversion>, ValidationError> {
+    let scale = config.try_binding(&format!("{}_scale", key), "a number")?;
+    let offset = config.try_binding(&format!("{}_offset", key), "a number")?;
+    let expr = config.try_binding(&format!("{}_expr", key), "a string")?;
+
+    Conversion::from_parts(key, scale, offset, expr)
+}
+
+/// Parsed arithmetic over the raw reading `x`: numbers, `+ - * / ^`, parentheses and [`FUNCTIONS`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Raw,
+    Neg(Box<Expr>),
+    Binary(char, Box<Expr>, Box<Expr>),
+    Call(&'static str, Box<Expr>),
+}
+
+impl Expr {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut parser = Parser { chars: source.chars().filter(|c| !c.is_whitespace()).collect(), pos: 0 };
+        let expr = parser.sum()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(c) => Err(format!("Unexpected '{}' in '{}'", c, source)),
+        }
+    }
+
+    pub fn eval(&self, x: f64) -> f64 {
+        match self {
+            Expr::Number(n) => *n,
+            Expr::Raw => x,
+            Expr::Neg(inner) => -inner.eval(x),
+            Expr::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.eval(x), rhs.eval(x));
+                match op {
+                    '+' => lhs + rhs,
+                    '-' => lhs - rhs,
+                    '*' => lhs * rhs,
+                    '/' => lhs / rhs,
+                    _ => lhs.powf(rhs),
+                }
+            }
+            Expr::Call(name, arg) => {
+                let arg = arg.eval(x);
+                FUNCTIONS.iter().find(|(n, _)| n == name).map_or(f64::NAN, |(_, function)| function(arg))
+            }
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn sum(&mut self) -> Result<Expr, String> {
+        let mut expr = self.product()?;
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.pos += 1;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.product()?));
+        }
+        Ok(expr)
+    }
+
+    fn product(&mut self) -> Result<Expr, String> {
+        let mut expr = self.power()?;
+        while let Some(op @ ('*' | '/')) = self.peek() {
+            self.pos += 1;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.power()?));
+        }
+        Ok(expr)
+    }
+
+    /// Right-associative, binding tighter than unary minus: `-x^2` is `-(x^2)`
+    fn power(&mut self) -> Result<Expr, String> {
+        if self.eat('-') {
+            return Ok(Expr::Neg(Box::new(self.power()?)));
+        }
+        let base = self.atom()?;
+        if self.eat('^') {
+            return Ok(Expr::Binary('^', Box::new(base), Box::new(self.power()?)));
+        }
+        Ok(base)
+    }
+
+    fn atom(&mut self) -> Result<Expr, String> {
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let expr = self.sum()?;
+                if !self.eat(')') {
+                    return Err("Missing ')'".to_string());
+                }
+                Ok(expr)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+                    self.pos += 1;
+                }
+                let text: String = self.chars[start..self.pos].iter().collect();
+                text.parse().map(Expr::Number).map_err(|_| format!("Bad number '{}'", text))
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_alphanumeric()) {
+                    self.pos += 1;
+                }
+                let name: String = self.chars[start..self.pos].iter().collect();
+                if name == "x" {
+                    return Ok(Expr::Raw);
+                }
+                let (function, _) = *FUNCTIONS
+                    .iter()
+                    .find(|(n, _)| *n == name)
+                    .ok_or_else(|| format!("Unknown name '{}'; the raw reading is 'x'", name))?;
+                if !self.eat('(') {
+                    return Err(format!("Expected '(' after {}", name));
+                }
+                let arg = self.sum()?;
+                if !self.eat(')') {
+                    return Err("Missing ')'".to_string());
+                }
+                Ok(Expr::Call(function, Box::new(arg)))
+            }
+            Some(c) => Err(format!("Unexpected '{}'", c)),
+            None => Err("Expression ends too early".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    use crate::util::test_util::TestModule;
+
+    /// Config of a module with only `bindings`; `system` so the builder doesn't validate them first
+    fn config(bindings: &[(&str, serde_json::Value)]) -> ModuleConfig {
+        let mut module = TestModule::new("Tank", "system", "");
+        for (key, value) in bindings {
+            module = module.binding(key, value.clone());
+        }
+        module.build().config
+    }
+
+    #[test]
+    fn test_linear_and_expression_conversions() {
+        let conversions = Conversions::from_config(&config(&[
+            ("a_scale", json!(0.5)),
+            ("a_offset", json!(-10)),
+            ("b_expr", json!("-(x - 400) / 16 + sqrt(x) * 2^2")),
+        ]))
+        .unwrap();
+        assert_eq!(conversions.apply(100.0, 400.0), (40.0, 80.0));
+        assert_eq!(Expr::parse("-x^2").unwrap().eval(3.0), -9.0);
+
+        assert!(Conversions::from_config(&config(&[])).unwrap().is_empty());
+        for bad in [
+            vec![("a_expr", json!("x +"))],
+            vec![("a_expr", json!("y * 2"))],
+            vec![("a_expr", json!("x")), ("a_scale", json!(2))],
+        ] {
+            assert_eq!(Conversions::from_config(&config(&bad)).unwrap_err().field, "bindings.a_expr");
+        }
+    }
+}
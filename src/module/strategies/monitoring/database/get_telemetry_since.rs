@@ -0,0 +1,68 @@
+use rusqlite::params;
+
+use crate::module::strategies::monitoring::database::TelemetrySample;
+use crate::util::database::Database;
+
+impl Database {
+    /// Samples for `device_id` from `since` to `until`, oldest first, averaged in SQL into at most
+    /// `max_points` equal time buckets so a long range never loads every row. `c` keeps each
+    /// bucket's last value.
+    pub(in crate::module) fn _monitoring__get_telemetry_since(
+        &self,
+        device_id: &str,
+        since: i64,
+        until: i64,
+        max_points: usize,
+    ) -> rusqlite::Result<Vec<TelemetrySample>> {
+        let span = (until - since).max(1);
+        let bucket_secs = (span as u64).div_ceil(max_points.max(1) as u64).max(1) as i64;
+
+        let conn = Self::connection(&self.analytics_conn)?;
+        // With MAX() the bare `c` comes from the row holding the maximum, i.e. the bucket's last
+        let mut stmt = conn.prepare(
+            "SELECT AVG(a), AVG(b), c, MAX(recorded_at) FROM telemetry_samples
+             WHERE device_id = ?1 AND recorded_at >= ?2 AND recorded_at <= ?3
+             GROUP BY (recorded_at - ?2) / ?4
+             ORDER BY MAX(recorded_at) ASC",
+        )?;
+
+        let samples = stmt
+            .query_map(params![device_id, since, until, bucket_secs], |row| {
+                Ok(TelemetrySample {
+                    a: row.get(0)?,
+                    b: row.get(1)?,
+                    c: row.get(2)?,
+                    recorded_at: row.get(3)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        Ok(samples)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module::strategies::monitoring::database::{MonitoringDatabase, PendingTelemetry, TelemetrySample};
+    use crate::util::test_util::in_memory_database;
+
+    #[test]
+    fn test_batches_are_written_and_averaged_into_buckets() {
+        let database = in_memory_database();
+        let batch: Vec<PendingTelemetry> = (0..100)
+            .map(|i| PendingTelemetry {
+                sample: TelemetrySample { a: i as f64, b: 1.0, c: i, recorded_at: 1_000 + i },
+                raw: (i as f64, 1.0),
+            })
+            .collect();
+        assert_eq!(database.record_telemetry("a01", &batch).unwrap(), 100);
+
+        let samples = database.get_telemetry_since("a01", 1_000, 1_099, 10).unwrap();
+        assert_eq!(samples.len(), 10);
+        assert_eq!(samples[0], TelemetrySample { a: 4.5, b: 1.0, c: 9, recorded_at: 1_009 });
+        assert_eq!(samples[9].recorded_at, 1_099);
+
+        assert_eq!(database.prune_telemetry(1_050).unwrap(), 50);
+        assert_eq!(database.get_telemetry_since("a01", 0, 2_000, 10_000).unwrap().len(), 50);
+    }
+}
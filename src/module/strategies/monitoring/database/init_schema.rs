@@ -0,0 +1,27 @@
+use crate::util::database::Database;
+
+impl Database {
+    pub(in crate::module) fn _monitoring__init_schema(&self) -> rusqlite::Result<()> {
+        let conn = Self::connection(&self.analytics_conn)?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS telemetry_samples (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                device_id TEXT NOT NULL,
+                a REAL NOT NULL,
+                b REAL NOT NULL,
+                c INTEGER NOT NULL,
+                recorded_at INTEGER NOT NULL
+            )",
+            [],
+        )?;
+
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_telemetry_device_time
+             ON telemetry_samples(device_id, recorded_at)",
+            [],
+        )?;
+
+        self.apply_migrations(&self.analytics_conn, "monitoring", super::migrations::MIGRATIONS)
+    }
+}
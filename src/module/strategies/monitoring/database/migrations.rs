@@ -0,0 +1,11 @@
+use crate::util::database::Migration;
+
+/// Monitoring schema changes applied on top of `telemetry_samples`. Append only.
+pub(super) const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "keep uncalibrated readings beside converted ones",
+        sql: "ALTER TABLE telemetry_samples ADD COLUMN raw_a REAL;
+              ALTER TABLE telemetry_samples ADD COLUMN raw_b REAL;",
+    },
+];
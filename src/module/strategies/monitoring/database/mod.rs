@@ -0,0 +1,24 @@
+mod trait_monitoring_database;
+mod init_schema;
+mod migrations;
+mod record_telemetry;
+mod get_telemetry_since;
+mod prune_telemetry;
+
+pub use trait_monitoring_database::MonitoringDatabase;
+
+/// One persisted telemetry reading (SSP `a`/`b`/`c`), `recorded_at` in unix seconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TelemetrySample {
+    pub a: f64,
+    pub b: f64,
+    pub c: i64,
+    pub recorded_at: i64,
+}
+
+/// A converted reading waiting to be written, with `a`/`b` as the device sent them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PendingTelemetry {
+    pub sample: TelemetrySample,
+    pub raw: (f64, f64),
+}
@@ -0,0 +1,13 @@
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+impl Database {
+    pub(in crate::module) fn _monitoring__prune_telemetry(&self, older_than: i64) -> rusqlite::Result<usize> {
+        let conn = Self::connection(&self.analytics_conn)?;
+        conn.execute(
+            "DELETE FROM telemetry_samples WHERE recorded_at < ?1",
+            params![older_than],
+        )
+    }
+}
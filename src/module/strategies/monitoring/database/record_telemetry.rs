@@ -0,0 +1,27 @@
+use rusqlite::params;
+
+use crate::module::strategies::monitoring::database::PendingTelemetry;
+use crate::util::database::Database;
+
+impl Database {
+    /// Write a batch of readings for `device_id` in one transaction
+    pub(in crate::module) fn _monitoring__record_telemetry(
+        &self,
+        device_id: &str,
+        samples: &[PendingTelemetry],
+    ) -> rusqlite::Result<usize> {
+        let mut conn = Self::connection(&self.analytics_conn)?;
+        let tx = conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO telemetry_samples (device_id, a, b, c, raw_a, raw_b, recorded_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )?;
+            for PendingTelemetry { sample, raw: (raw_a, raw_b) } in samples {
+                stmt.execute(params![device_id, sample.a, sample.b, sample.c, raw_a, raw_b, sample.recorded_at])?;
+            }
+        }
+        tx.commit()?;
+        Ok(samples.len())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::module::strategies::monitoring::database::{PendingTelemetry, TelemetrySample};
+use crate::util::database::Database;
+
+/// Trait to add Monitoring-specific database operations to Database
+pub trait MonitoringDatabase {
+    fn init_monitoring_schema(&self) -> rusqlite::Result<()>;
+
+    // Telemetry history
+    fn record_telemetry(&self, device_id: &str, samples: &[PendingTelemetry]) -> rusqlite::Result<usize>;
+    fn get_telemetry_since(&self, device_id: &str, since: i64, until: i64, max_points: usize) -> rusqlite::Result<Vec<TelemetrySample>>;
+    fn prune_telemetry(&self, older_than: i64) -> rusqlite::Result<usize>;
+}
+
+impl MonitoringDatabase for Database {
+    fn init_monitoring_schema(&self) -> rusqlite::Result<()> {
+        self._monitoring__init_schema()
+    }
+
+    fn record_telemetry(&self, device_id: &str, samples: &[PendingTelemetry]) -> rusqlite::Result<usize> {
+        self._monitoring__record_telemetry(device_id, samples)
+    }
+
+    fn get_telemetry_since(&self, device_id: &str, since: i64, until: i64, max_points: usize) -> rusqlite::Result<Vec<TelemetrySample>> {
+        self._monitoring__get_telemetry_since(device_id, since, until, max_points)
+    }
+
+    fn prune_telemetry(&self, older_than: i64) -> rusqlite::Result<usize> {
+        self._monitoring__prune_telemetry(older_than)
+    }
+}
@@ -0,0 +1,107 @@
+use crossterm::event::KeyCode;
+
+/// In-progress calibration from the monitoring detail view: known values entered at live raw
+/// readings, fitted to `<key>_scale`/`<key>_offset`
+#[derive(Debug, Clone)]
+pub(super) struct CalibrationWizard {
+    /// Value being calibrated, `a` or `b`
+    pub key: &'static str,
+    /// Known value typed for the current reading
+    pub input: String,
+    /// Captured `(raw, known)` reference points
+    pub points: Vec<(f64, f64)>,
+}
+
+/// What a key press in the wizard asks the handler to do
+pub(super) enum CalibrationAction {
+    None,
+    Capture,
+    Save,
+    Cancel,
+}
+
+impl CalibrationWizard {
+    pub fn new() -> Self {
+        Self { key: "a", input: String::new(), points: Vec::new() }
+    }
+
+    pub fn handle_key(&mut self, key_code: KeyCode) -> CalibrationAction {
+        match key_code {
+            KeyCode::Enter => return CalibrationAction::Capture,
+            KeyCode::Esc => return CalibrationAction::Cancel,
+            KeyCode::Char('s') => return CalibrationAction::Save,
+            KeyCode::Tab => {
+                // Points only make sense for the value they were taken against
+                self.key = if self.key == "a" { "b" } else { "a" };
+                self.points.clear();
+                self.input.clear();
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() || c == '.' || c == '-' => self.input.push(c),
+            _ => {}
+        }
+        CalibrationAction::None
+    }
+
+    /// The raw reading for the value being calibrated, out of `(raw_a, raw_b)`
+    pub fn raw_reading(&self, raw_values: (f64, f64)) -> f64 {
+        if self.key == "a" { raw_values.0 } else { raw_values.1 }
+    }
+
+    /// Scale and offset mapping the captured raw readings onto their known values.
+    /// A single point keeps `current_scale` and only corrects the offset.
+    pub fn fit(&self, current_scale: f64) -> Result<(f64, f64), String> {
+        match self.points.as_slice() {
+            [] => Err("Capture a reference point first".to_string()),
+            [(raw, known)] => Ok((current_scale, known - current_scale * raw)),
+            [(raw1, known1), (raw2, known2), ..] => {
+                if raw1 == raw2 {
+                    return Err("Both points have the same raw reading; take the second at a different level".to_string());
+                }
+                let scale = (known2 - known1) / (raw2 - raw1);
+                Ok((scale, known1 - scale * raw1))
+            }
+        }
+    }
+
+    /// Wizard lines for the detail view; the first is the heading
+    pub fn lines(&self, raw: Option<f64>) -> Vec<String> {
+        let mut lines = vec![
+            format!("Calibrate {}", self.key),
+            format!("Raw reading now: {}", raw.map_or("none yet".to_string(), |raw| raw.to_string())),
+        ];
+        for (i, (raw, known)) in self.points.iter().enumerate() {
+            lines.push(format!("Point {}: raw {} = {}", i + 1, raw, known));
+        }
+        lines.push(format!("Known value at this reading: {}_", self.input));
+        if self.points.len() == 1 {
+            lines.push("Capture a second point to fit the scale, or save to correct the offset only".to_string());
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_one_and_two_points() {
+        let mut wizard = CalibrationWizard::new();
+        assert!(wizard.fit(1.0).is_err());
+
+        // 4-20 mA loop on a 12-bit ADC: 819 counts is 0 bar, 4095 is 10 bar
+        wizard.points.push((819.0, 0.0));
+        assert_eq!(wizard.fit(0.5).unwrap(), (0.5, -409.5));
+
+        wizard.points.push((4095.0, 10.0));
+        let (scale, offset) = wizard.fit(0.5).unwrap();
+        assert!((819.0 * scale + offset).abs() < 1e-9);
+        assert!((4095.0 * scale + offset - 10.0).abs() < 1e-9);
+
+        wizard.points[1].0 = 819.0;
+        assert!(wizard.fit(1.0).is_err());
+    }
+}
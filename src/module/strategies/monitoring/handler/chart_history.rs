@@ -0,0 +1,40 @@
+use crate::log_warn;
+use crate::module::strategies::monitoring::database::MonitoringDatabase;
+use crate::module::strategies::monitoring::handler::{
+    MonitoringHandler,
+    RANGE_MAX_POINTS,
+};
+
+impl MonitoringHandler {
+    /// History for the selected time range: the live buffer, or persisted telemetry
+    /// re-queried at most every `range_poll_interval`.
+    pub(in crate::module) fn chart_history(&mut self) -> Vec<(f64, f64, i64)> {
+        let Some(window) = self.time_range.duration() else {
+            return self.history.iter().copied().collect();
+        };
+
+        let stale = self
+            .range_queried_at
+            .map_or(true, |at| self.clock.elapsed_since(at) >= self.range_poll_interval);
+
+        if stale {
+            // Write what's buffered first, so the range includes the latest readings
+            self.persist_telemetry(true);
+
+            let until = self.clock.utc_now().timestamp();
+            let since = until - window.as_secs() as i64;
+            match self.database.get_telemetry_since(&self.device_id, since, until, RANGE_MAX_POINTS) {
+                Ok(samples) => {
+                    self.range_history = samples.into_iter().map(|s| (s.a, s.b, s.c)).collect();
+                }
+                Err(e) => {
+                    log_warn!(target: &self.log_target, "Failed to load {} telemetry for {}: {}", self.time_range.label(), self.device_id, e);
+                    self.last_error.set(format!("Telemetry history unavailable: {}", e));
+                }
+            }
+            self.range_queried_at = Some(self.clock.now());
+        }
+
+        self.range_history.clone()
+    }
+}
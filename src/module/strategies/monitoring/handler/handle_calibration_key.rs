@@ -0,0 +1,42 @@
+use crossterm::event::KeyCode;
+
+use crate::module::Module;
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::util::io::event::AppEvent;
+
+use super::{
+    calibration::CalibrationAction,
+    MonitoringHandler,
+};
+
+impl MonitoringHandler {
+    /// Keys while the calibration wizard is open. Every key is consumed so typing a value
+    /// never reaches the app's own shortcuts.
+    pub(in crate::module) fn handle_calibration_key(&mut self, key_code: KeyCode, module: &mut Module) -> Option<AppEvent> {
+        let action = self.calibration.as_mut()?.handle_key(key_code);
+        let has_reading = self.last_update.is_some();
+        let raw_values = self.raw_values;
+
+        match action {
+            CalibrationAction::Capture => {
+                let wizard = self.calibration.as_mut()?;
+                match wizard.input.trim().parse::<f64>() {
+                    _ if !has_reading => notify(ToastLevel::Warning, "No reading yet to calibrate against"),
+                    Err(_) => notify(ToastLevel::Warning, "Enter the known value as a number"),
+                    Ok(known) => {
+                        wizard.points.push((wizard.raw_reading(raw_values), known));
+                        wizard.input.clear();
+                        if wizard.points.len() >= 2 {
+                            self.save_calibration(module);
+                        }
+                    }
+                }
+            }
+            CalibrationAction::Save => self.save_calibration(module),
+            CalibrationAction::Cancel => self.calibration = None,
+            CalibrationAction::None => {}
+        }
+
+        Some(AppEvent::NoOp)
+    }
+}
@@ -1,12 +1,10 @@
-use crate::module::strategies::monitoring::handler::{
-    CONNECTION_TIMEOUT,
-    MonitoringHandler
-};
+use crate::module::strategies::monitoring::handler::MonitoringHandler;
+use crate::runtime::connection_timeout::connection_timeout;
 
 impl MonitoringHandler {
     pub(in crate::module) fn is_connected(&self) -> bool {
         if let Some(last_update) = self.last_update {
-            last_update.elapsed() < CONNECTION_TIMEOUT
+            self.clock.elapsed_since(last_update) < connection_timeout()
         } else {
             false
         }
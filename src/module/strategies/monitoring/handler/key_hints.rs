@@ -0,0 +1,27 @@
+use crossterm::event::KeyCode;
+
+use super::MonitoringHandler;
+
+impl MonitoringHandler {
+    /// Keys usable in the detail view, shown as the card's footer and in the `?` overlay
+    pub(in crate::module) fn _key_hints(&self) -> Vec<(KeyCode, &'static str)> {
+        match &self.calibration {
+            Some(wizard) if !wizard.points.is_empty() => vec![
+                (KeyCode::Enter, "Capture"),
+                (KeyCode::Char('s'), "Save"),
+                (KeyCode::Tab, "a/b"),
+                (KeyCode::Esc, "Cancel"),
+            ],
+            Some(_) => vec![
+                (KeyCode::Enter, "Capture"),
+                (KeyCode::Tab, "a/b"),
+                (KeyCode::Esc, "Cancel"),
+            ],
+            None => vec![
+                (KeyCode::Char('r'), "Refresh"),
+                (KeyCode::Char('t'), "Range"),
+                (KeyCode::Char('k'), "Calibrate"),
+            ],
+        }
+    }
+}
@@ -6,6 +6,15 @@ mod is_connected;
 mod time_since_last_update;
 mod is_in_cmd_window;
 mod update_bindings;
+mod time_range;
+mod chart_history;
+mod with_conversions;
+mod with_outlier_filter;
+mod calibration;
+mod handle_calibration_key;
+mod save_calibration;
+mod key_hints;
+mod persist_telemetry;
 
 use std::{
     any::Any,
@@ -16,12 +25,26 @@ use std::{
 use tokio::sync::mpsc;
 
 use crate::{
-    module::trait_module_handler::ModuleHandler,
-    util::io::bus::MessageBus,
+    module::{
+        strategies::monitoring::{conversion::Conversions, database::PendingTelemetry, outliers::OutlierFilter},
+        trait_module_handler::ModuleHandler,
+        LastError,
+    },
+    util::{clock::SharedClock, database::Database, io::bus::MessageBus},
 };
 
-const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
+use calibration::CalibrationWizard;
+
+pub use time_range::TimeRange;
+
 const MAX_HISTORY: usize = 50;
+/// Points a persisted time range is averaged down to
+const RANGE_MAX_POINTS: usize = 100;
+/// Persisted telemetry older than this is pruned on startup and then hourly (longest range is 24h)
+const TELEMETRY_RETENTION: Duration = Duration::from_secs(2 * 24 * 60 * 60);
+const TELEMETRY_PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// Readings are buffered and written in one transaction this often, not one INSERT per sample
+const TELEMETRY_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
 
 #[derive(Debug, Clone)]
 enum HandlerMessage {
@@ -43,13 +66,35 @@ enum HandlerMessage {
 #[derive(Debug)]
 pub struct MonitoringHandler {
     device_id: String,
+    /// Handler key, used as the log target so `monitoring_<device>` can get its own level
+    log_target: String,
     last_update: Option<Instant>,
     current_values: (f64, f64, i64), // (a, b, c)
+    /// Latest `a`/`b` as the device sent them, before `conversions`
+    raw_values: (f64, f64),
+    conversions: Conversions,
+    outlier_filter: OutlierFilter,
+    /// Samples `outlier_filter` rejected or clamped
+    outliers: u64,
+    /// Open while calibrating from the detail view
+    calibration: Option<CalibrationWizard>,
     history: VecDeque<(f64, f64, i64)>,
+    database: Database,
+    /// Readings not yet written to `database`
+    pending_telemetry: Vec<PendingTelemetry>,
+    flushed_at: Instant,
+    pruned_at: Instant,
+    time_range: TimeRange,
+    range_history: Vec<(f64, f64, i64)>,
+    range_queried_at: Option<Instant>,
+    /// How often `range_history` is re-queried; the module's `poll_interval_ms` when set
+    range_poll_interval: Duration,
     message_bus: MessageBus,
     message_tx: mpsc::UnboundedSender<HandlerMessage>,
     message_rx: mpsc::UnboundedReceiver<HandlerMessage>,
     current_mode: Option<String>,        // "data" or "cmd"
     cmd_window_opens_in: Option<u64>,    // seconds until CMD window
     cmd_window_duration: Option<u64>,    // duration of CMD window
+    last_error: LastError,
+    clock: SharedClock,
 }
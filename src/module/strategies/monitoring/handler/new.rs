@@ -1,25 +1,70 @@
 use std::collections::VecDeque;
 use tokio::sync::mpsc;
 
-use crate::util::io::bus::MessageBus;
+use crate::{
+    log_warn,
+    module::{
+        strategies::monitoring::{conversion::Conversions, database::MonitoringDatabase, outliers::OutlierFilter},
+        LastError,
+    },
+    runtime::poll_interval::{poll_interval, PollTask},
+    util::{
+        clock::{system_clock, SharedClock},
+        database::Database,
+        io::bus::MessageBus,
+    },
+};
 
-use super::MonitoringHandler;
+use super::{MonitoringHandler, TimeRange, TELEMETRY_RETENTION};
 
 impl MonitoringHandler {
-    pub fn new(message_bus: MessageBus, device_id: String, bus_topic: String) -> Self {
+    pub fn new(message_bus: MessageBus, database: Database, device_id: String, bus_topic: String) -> Self {
+        Self::with_clock(message_bus, database, device_id, bus_topic, system_clock())
+    }
+
+    /// `new` with an injected clock, so tests can step through connection timeouts
+    pub fn with_clock(
+        message_bus: MessageBus,
+        database: Database,
+        device_id: String,
+        bus_topic: String,
+        clock: SharedClock,
+    ) -> Self {
         let (message_tx, message_rx) = mpsc::unbounded_channel();
+        let log_target = format!("monitoring_{}", device_id);
+
+        let cutoff = clock.utc_now().timestamp() - TELEMETRY_RETENTION.as_secs() as i64;
+        if let Err(e) = database.prune_telemetry(cutoff) {
+            log_warn!(target: &log_target, "Failed to prune telemetry history: {}", e);
+        }
 
         let mut handler = Self {
             device_id: device_id.clone(),
+            log_target,
             last_update: None,
             current_values: (0.0, 0.0, 0),
+            raw_values: (0.0, 0.0),
+            conversions: Conversions::default(),
+            outlier_filter: OutlierFilter::default(),
+            outliers: 0,
+            calibration: None,
             history: VecDeque::new(),
+            database,
+            pending_telemetry: Vec::new(),
+            flushed_at: clock.now(),
+            pruned_at: clock.now(),
+            time_range: TimeRange::default(),
+            range_history: Vec::new(),
+            range_queried_at: None,
+            range_poll_interval: poll_interval(PollTask::Monitoring),
             message_bus: message_bus.clone(),
             message_tx,
             message_rx,
             current_mode: None,
             cmd_window_opens_in: None,
             cmd_window_duration: None,
+            last_error: LastError::default(),
+            clock,
         };
 
         handler.start_telemetry_listener(bus_topic);
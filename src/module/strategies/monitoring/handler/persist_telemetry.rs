@@ -0,0 +1,79 @@
+use crate::log_warn;
+use crate::module::strategies::monitoring::database::MonitoringDatabase;
+use crate::module::strategies::monitoring::handler::{
+    MonitoringHandler,
+    TELEMETRY_FLUSH_INTERVAL,
+    TELEMETRY_PRUNE_INTERVAL,
+    TELEMETRY_RETENTION,
+};
+use crate::util::database::Database;
+
+impl MonitoringHandler {
+    /// Write buffered readings in one transaction once `TELEMETRY_FLUSH_INTERVAL` has passed
+    /// (or now, with `force`), and prune expired history every `TELEMETRY_PRUNE_INTERVAL`
+    pub(in crate::module) fn persist_telemetry(&mut self, force: bool) {
+        let due = force || self.clock.elapsed_since(self.flushed_at) >= TELEMETRY_FLUSH_INTERVAL;
+        if due && !self.pending_telemetry.is_empty() {
+            let batch = std::mem::take(&mut self.pending_telemetry);
+            if let Err(e) = self.database.record_telemetry(&self.device_id, &batch) {
+                if Database::is_disk_full(&e) {
+                    log_warn!(target: &self.log_target, "Disk full: telemetry for {} isn't being saved", self.device_id);
+                } else {
+                    log_warn!(target: &self.log_target, "Failed to persist telemetry for {}: {}", self.device_id, e);
+                }
+            }
+        }
+        if due {
+            self.flushed_at = self.clock.now();
+        }
+
+        if self.clock.elapsed_since(self.pruned_at) >= TELEMETRY_PRUNE_INTERVAL {
+            let cutoff = self.clock.utc_now().timestamp() - TELEMETRY_RETENTION.as_secs() as i64;
+            if let Err(e) = self.database.prune_telemetry(cutoff) {
+                log_warn!(target: &self.log_target, "Failed to prune telemetry history: {}", e);
+            }
+            self.pruned_at = self.clock.now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::module::strategies::monitoring::database::{MonitoringDatabase, PendingTelemetry, TelemetrySample};
+    use crate::module::strategies::monitoring::handler::{HandlerMessage, MonitoringHandler, TELEMETRY_PRUNE_INTERVAL};
+    use crate::util::clock::MockClock;
+    use crate::util::database::Database;
+    use crate::util::test_util::{in_memory_database, loopback_bus};
+
+    #[tokio::test]
+    async fn test_telemetry_is_written_in_batches_and_pruned_periodically() {
+        let (bus, _receiver) = loopback_bus();
+        let database = in_memory_database();
+        let clock = MockClock::new();
+        let mut handler = MonitoringHandler::with_clock(bus, database.clone(), "a01".to_string(), "a01".to_string(), clock.shared());
+        let stored = |database: &Database| -> i64 {
+            let conn = Database::connection(&database.analytics_conn).unwrap();
+            conn.query_row("SELECT COUNT(*) FROM telemetry_samples", [], |row| row.get(0)).unwrap()
+        };
+
+        for value in [1.0, 2.0, 3.0] {
+            let sample = HandlerMessage::TelemetryReceived { value_a: value, value_b: 0.0, value_c: 0, timestamp: Instant::now() };
+            handler.message_tx.send(sample).unwrap();
+        }
+        handler.process_messages();
+        assert_eq!(stored(&database), 0);
+
+        clock.advance(Duration::from_secs(5));
+        handler.process_messages();
+        assert_eq!(stored(&database), 3);
+
+        // A reading from long ago goes on the next hourly prune, without a restart
+        let old = PendingTelemetry { sample: TelemetrySample { a: 0.0, b: 0.0, c: 0, recorded_at: 1 }, raw: (0.0, 0.0) };
+        database.record_telemetry("a01", &[old]).unwrap();
+        clock.advance(TELEMETRY_PRUNE_INTERVAL);
+        handler.process_messages();
+        assert_eq!(stored(&database), 3);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::log_info;
+use crate::{log_info, log_warn};
+use crate::module::strategies::monitoring::database::{PendingTelemetry, TelemetrySample};
+use crate::module::strategies::monitoring::outliers::Screened;
 use crate::module::strategies::monitoring::handler::{
     HandlerMessage,
     MonitoringHandler,
@@ -11,7 +13,31 @@ impl MonitoringHandler {
         while let Ok(msg) = self.message_rx.try_recv() {
             message_count += 1;
             match msg {
-                HandlerMessage::TelemetryReceived { value_a, value_b, value_c, timestamp } => {
+                HandlerMessage::TelemetryReceived { value_a: raw_a, value_b: raw_b, value_c, timestamp } => {
+                    // The calibration wizard needs the reading even when screening rejects it,
+                    // which is exactly when the sensor is miscalibrated
+                    self.raw_values = (raw_a, raw_b);
+
+                    // Calibrate once here so history, thresholds and charts all share units
+                    let (value_a, value_b) = self.conversions.apply(raw_a, raw_b);
+
+                    // Then drop glitch readings before they reach history
+                    let (value_a, value_b) = match self.outlier_filter.screen(value_a, value_b) {
+                        Screened::Accepted(a, b) => (a, b),
+                        Screened::Clamped(a, b, reason) => {
+                            self.outliers += 1;
+                            log_warn!(target: &self.log_target, "⚠️ [{}] Telemetry {} ({} outliers so far)",
+                                self.device_id, reason, self.outliers);
+                            (a, b)
+                        }
+                        Screened::Rejected(reason) => {
+                            self.outliers += 1;
+                            log_warn!(target: &self.log_target, "⚠️ [{}] Dropped telemetry: {} ({} outliers so far)",
+                                self.device_id, reason, self.outliers);
+                            continue;
+                        }
+                    };
+
                     self.current_values = (value_a, value_b, value_c);
                     self.last_update = Some(timestamp);
 
@@ -21,7 +47,13 @@ impl MonitoringHandler {
                         self.history.pop_front();
                     }
 
-                    log_info!("🟢 Updated values for {}: a={}, b={}, c={}, history_size={}",
+                    // Persisted in batches for the longer chart time ranges
+                    self.pending_telemetry.push(PendingTelemetry {
+                        sample: TelemetrySample { a: value_a, b: value_b, c: value_c, recorded_at: self.clock.utc_now().timestamp() },
+                        raw: (raw_a, raw_b),
+                    });
+
+                    log_info!(target: &self.log_target, "🟢 Updated values for {}: a={}, b={}, c={}, history_size={}",
                         self.device_id, value_a, value_b, value_c, self.history.len());
                 }
 
@@ -31,14 +63,43 @@ impl MonitoringHandler {
                     self.cmd_window_opens_in = Some(cmd_in);
                     self.cmd_window_duration = Some(cmd_dur);
 
-                    log_info!("📅 [{}] Schedule updated: mode={}, window_in={}s",
+                    log_info!(target: &self.log_target, "📅 [{}] Schedule updated: mode={}, window_in={}s",
                         self.device_id, mode, cmd_in);
                 }
             }
         }
 
         if message_count > 0 {
-            log_info!("🟢 Processed {} messages for {}", message_count, self.device_id);
+            log_info!(target: &self.log_target, "🟢 Processed {} messages for {}", message_count, self.device_id);
         }
+
+        self.persist_telemetry(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use crate::module::strategies::monitoring::handler::{HandlerMessage, MonitoringHandler};
+    use crate::module::strategies::monitoring::outliers::{OutlierFilter, PlausibleRange};
+    use crate::util::test_util::{in_memory_database, loopback_bus};
+
+    #[tokio::test]
+    async fn test_rejected_sample_still_updates_raw_values() {
+        let (bus, _receiver) = loopback_bus();
+        let mut handler = MonitoringHandler::new(bus, in_memory_database(), "a01".to_string(), "a01".to_string())
+            .with_outlier_filter(OutlierFilter {
+                a: Some(PlausibleRange { min: None, max: Some(100.0) }),
+                ..OutlierFilter::default()
+            });
+
+        let sample = HandlerMessage::TelemetryReceived { value_a: 512.0, value_b: 3.0, value_c: 0, timestamp: Instant::now() };
+        handler.message_tx.send(sample).unwrap();
+        handler.process_messages();
+
+        assert_eq!(handler.outliers, 1);
+        assert!(handler.history.is_empty());
+        assert_eq!(handler.raw_values, (512.0, 3.0));
     }
 }
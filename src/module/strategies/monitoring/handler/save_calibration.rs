@@ -0,0 +1,78 @@
+use std::{fs, path::Path};
+
+use color_eyre::eyre::eyre;
+
+use crate::{
+    log_info,
+    module::{config::ConfigValidator, strategies::monitoring::conversion::Conversion, Module},
+    runtime::toast::{notify, ToastLevel},
+    util::fs::write_atomic,
+};
+
+use super::MonitoringHandler;
+
+impl MonitoringHandler {
+    /// Fit the wizard's points and persist them as `<key>_scale`/`<key>_offset` in the module's
+    /// config.yml, replacing any `<key>_expr`. New readings use the calibration straight away.
+    /// On failure the wizard stays open, minus a second point that couldn't be used.
+    pub(in crate::module) fn save_calibration(&mut self, module: &mut Module) {
+        let Some(wizard) = self.calibration.as_mut() else {
+            return;
+        };
+        let key = wizard.key;
+        let current = if key == "a" { &self.conversions.a } else { &self.conversions.b };
+        let current_scale = match current {
+            Some(Conversion::Linear { scale, .. }) => *scale,
+            _ => 1.0,
+        };
+
+        let saved = wizard.fit(current_scale).and_then(|(scale, offset)| {
+            write_calibration(&module.path.join("config.yml"), key, scale, offset)
+                .map(|_| (scale, offset))
+                .map_err(|e| e.to_string())
+        });
+
+        let (scale, offset) = match saved {
+            Ok(fitted) => fitted,
+            Err(e) => {
+                wizard.points.truncate(1);
+                notify(ToastLevel::Error, format!("Calibration not saved: {}", e));
+                return;
+            }
+        };
+
+        let conversion = Some(Conversion::Linear { scale, offset });
+        if key == "a" {
+            self.conversions.a = conversion;
+        } else {
+            self.conversions.b = conversion;
+        }
+
+        let bindings = &mut module.config.bindings;
+        bindings.insert(format!("{}_scale", key), serde_json::json!(scale));
+        bindings.insert(format!("{}_offset", key), serde_json::json!(offset));
+        bindings.remove(&format!("{}_expr", key));
+
+        self.calibration = None;
+        log_info!(target: &self.log_target, "Calibrated {} of {}: scale={}, offset={}", key, self.device_id, scale, offset);
+        notify(ToastLevel::Success, format!("✓ Calibrated {}: {} × raw + {}", key, scale, offset));
+    }
+}
+
+/// Set the calibration bindings in `config_path`, validating before the atomic write
+fn write_calibration(config_path: &Path, key: &str, scale: f64, offset: f64) -> color_eyre::Result<()> {
+    let mut config: serde_yaml::Value = serde_yaml::from_str(&fs::read_to_string(config_path)?)?;
+    let bindings = config
+        .get_mut("bindings")
+        .and_then(|b| b.as_mapping_mut())
+        .ok_or_else(|| eyre!("{} has no bindings", config_path.display()))?;
+
+    bindings.insert(format!("{}_scale", key).into(), scale.into());
+    bindings.insert(format!("{}_offset", key).into(), offset.into());
+    bindings.remove(format!("{}_expr", key).as_str());
+
+    let yaml = serde_yaml::to_string(&config)?;
+    ConfigValidator::validate(&yaml)?;
+    write_atomic(config_path, yaml)?;
+    Ok(())
+}
@@ -1,8 +1,7 @@
-use std::time::Instant;
-
 use crate::{
     log_debug, log_error, log_info, log_warn,
-    util::io::ble_scheduler::extract_schedule_metadata
+    runtime::watchdog,
+    util::io::ssp,
 };
 use crate::module::strategies::monitoring::handler::{
     HandlerMessage,
@@ -13,82 +12,117 @@ impl MonitoringHandler {
     pub(in crate::module) fn start_telemetry_listener(&mut self, bus_topic: String) {
         let tx = self.message_tx.clone();
         let bus = self.message_bus.clone();
+        let clock = self.clock.clone();
         let device_id = self.device_id.clone();
+        let last_error = self.last_error.clone();
+        let log_target = self.log_target.clone();
 
-        tokio::spawn(async move {
-            log_info!("📻 Starting monitoring telemetry listener for device: {} on topic: {}", device_id, bus_topic);
-            let mut receiver = bus.subscribe(bus_topic.clone()).await;
-            log_info!("📻 Subscribed to topic: {}", bus_topic);
+        // Restarted by the watchdog if it exits or wedges, so each start clones what it needs
+        watchdog::supervise(&self.log_target, move |heartbeat| {
+            let (tx, bus, clock) = (tx.clone(), bus.clone(), clock.clone());
+            let (device_id, last_error, log_target) = (device_id.clone(), last_error.clone(), log_target.clone());
+            let bus_topic = bus_topic.clone();
+            async move {
+                log_info!(target: &log_target, "📻 Starting monitoring telemetry listener for device: {} on topic: {}", device_id, bus_topic);
+                // Boards often report under an id other than the module's device_id (a01 on w01), so
+                // everything on the topic is this device's telemetry
+                let mut receiver = bus.subscribe(bus_topic.clone()).await;
+                log_info!(target: &log_target, "📻 Subscribed to topic: {}", bus_topic);
 
-            while let Some(msg) = receiver.recv().await {
-                log_info!("📻 [{}] Received message on topic {}", device_id, bus_topic);
-                log_debug!("Raw payload: {}", msg.payload);
+                while let Some(msg) = heartbeat.idle(receiver.recv()).await {
+                    log_info!(target: &log_target, "📻 [{}] Received message on topic {}", device_id, bus_topic);
+                    log_debug!(target: &log_target, "Raw payload: {}", msg.payload);
 
-                if let Ok(data) = serde_json::from_str::<serde_json::Value>(&msg.payload) {
-                    if let Some(metadata) = extract_schedule_metadata(&data) {
-                        log_info!("📅 [{}] Schedule metadata found!", device_id);
+                    match ssp::decode(&msg.payload) {
+                        Ok(frame) => {
+                            if let Some(metadata) = &frame.schedule {
+                                log_info!(target: &log_target, "📅 [{}] Schedule metadata found!", device_id);
 
-                        let mode = metadata.get("mode")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("unknown")
-                            .to_string();
+                                let mode = metadata.get("mode")
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or("unknown")
+                                    .to_string();
 
-                        let cmd_in = metadata.get("cmd_in")
-                            .and_then(|v| v.as_u64())
-                            .unwrap_or(0);
+                                let cmd_in = metadata.get("cmd_in")
+                                    .and_then(|v| v.as_u64())
+                                    .unwrap_or(0);
 
-                        let cmd_dur = metadata.get("cmd_dur")
-                            .and_then(|v| v.as_u64())
-                            .unwrap_or(10);
+                                let cmd_dur = metadata.get("cmd_dur")
+                                    .and_then(|v| v.as_u64())
+                                    .unwrap_or(10);
 
-                        log_info!("📅 [{}] Mode: {}, CMD window in: {}s, Duration: {}s",
-                            device_id, mode, cmd_in, cmd_dur);
+                                log_info!(target: &log_target, "📅 [{}] Mode: {}, CMD window in: {}s, Duration: {}s",
+                                    device_id, mode, cmd_in, cmd_dur);
 
-                        let _ = tx.send(HandlerMessage::ScheduleUpdate {
-                            mode,
-                            cmd_in,
-                            cmd_dur,
-                        });
-                    }
+                                let _ = tx.send(HandlerMessage::ScheduleUpdate {
+                                    mode,
+                                    cmd_in,
+                                    cmd_dur,
+                                });
+                            }
 
-                    let values = if let Some(d) = data.get("d").and_then(|v| v.as_object()) {
-                        // Full SSP format with "d" object
-                        log_debug!("Parsing SSP format with 'd' object");
-                        Some((
-                            d.get("a").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                            d.get("b").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                            d.get("c").and_then(|v| v.as_i64()).unwrap_or(0)
-                        ))
-                    } else if data.is_object() {
-                        // Direct payload format: {"a":72,"b":45,"c":335}
-                        log_debug!("Parsing direct payload format");
-                        Some((
-                            data.get("a").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                            data.get("b").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                            data.get("c").and_then(|v| v.as_i64()).unwrap_or(0)
-                        ))
-                    } else {
-                        log_warn!("📻 [{}] Unrecognized message format", device_id);
-                        None
-                    };
-
-                    if let Some((value_a, value_b, value_c)) = values {
-                        log_info!("📻 Monitoring telemetry [{}]: a={}, b={}, c={}",
-                            device_id, value_a, value_b, value_c);
-
-                        let _ = tx.send(HandlerMessage::TelemetryReceived {
-                            value_a,
-                            value_b,
-                            value_c,
-                            timestamp: Instant::now(),
-                        });
+                            let (value_a, value_b, value_c) =
+                                (frame.a.unwrap_or(0.0), frame.b.unwrap_or(0.0), frame.c.unwrap_or(0));
+                            log_info!(target: &log_target, "📻 Monitoring telemetry [{}]: a={}, b={}, c={}",
+                                device_id, value_a, value_b, value_c);
+                            if !frame.extended.is_empty() {
+                                log_debug!(target: &log_target, "Extended values: {:?}", frame.extended);
+                            }
+
+                            last_error.clear();
+                            let _ = tx.send(HandlerMessage::TelemetryReceived {
+                                value_a,
+                                value_b,
+                                value_c,
+                                timestamp: clock.now(),
+                            });
+                        }
+                        Err(e) => {
+                            log_warn!(target: &log_target, "📻 [{}] Rejected telemetry ({}): {}", device_id, e, msg.payload);
+                            last_error.set(format!("Bad telemetry: {}", e));
+                        }
                     }
-                } else {
-                    log_warn!("📻 [{}] Failed to parse JSON: {}", device_id, msg.payload);
                 }
-            }
 
-            log_error!("📻 [{}] Telemetry listener ended!", device_id);
+                log_error!(target: &log_target, "📻 [{}] Telemetry listener ended!", device_id);
+                last_error.set("Telemetry listener ended");
+            }
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::module::strategies::monitoring::handler::MonitoringHandler;
+    use crate::util::io::bus::{BusMessage, MessageBus};
+    use crate::util::test_util::in_memory_database;
+
+    #[tokio::test]
+    async fn test_accepts_telemetry_from_a_sender_named_differently_than_the_device() {
+        let (bus, _receiver) = MessageBus::new();
+        let mut handler = MonitoringHandler::new(
+            bus.clone(), in_memory_database(), "a01_listener".to_string(), "w01_listener".to_string(),
+        );
+
+        let subscribed = async {
+            while bus.stats().get("w01_listener").map_or(true, |stats| stats.subscribers == 0) {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(2), subscribed).await.unwrap();
+
+        let telemetry = r#"{"a":42.0,"b":7.5,"c":3}"#.to_string();
+        bus.publish(BusMessage::new("w01_listener".to_string(), telemetry, "w01".to_string())).await.unwrap();
+
+        for _ in 0..200 {
+            handler.process_messages();
+            if handler.last_update.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(handler.current_values, (42.0, 7.5, 3));
+    }
+}
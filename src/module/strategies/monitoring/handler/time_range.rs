@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+/// Window shown by chart templates. `Live` is the in-memory buffer; the rest read persisted telemetry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeRange {
+    #[default]
+    Live,
+    FiveMinutes,
+    OneHour,
+    SixHours,
+    Day,
+}
+
+impl TimeRange {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Live => Self::FiveMinutes,
+            Self::FiveMinutes => Self::OneHour,
+            Self::OneHour => Self::SixHours,
+            Self::SixHours => Self::Day,
+            Self::Day => Self::Live,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Live => "live",
+            Self::FiveMinutes => "5m",
+            Self::OneHour => "1h",
+            Self::SixHours => "6h",
+            Self::Day => "24h",
+        }
+    }
+
+    pub fn duration(self) -> Option<Duration> {
+        match self {
+            Self::Live => None,
+            Self::FiveMinutes => Some(Duration::from_secs(5 * 60)),
+            Self::OneHour => Some(Duration::from_secs(60 * 60)),
+            Self::SixHours => Some(Duration::from_secs(6 * 60 * 60)),
+            Self::Day => Some(Duration::from_secs(24 * 60 * 60)),
+        }
+    }
+}
@@ -6,16 +6,35 @@ use crate::{
     module::{
         trait_module_handler::ModuleHandler,
         Module,
+        QuickAction,
     },
     util::io::event::AppEvent,
 };
-use crate::module::strategies::monitoring::handler::MonitoringHandler;
+use crate::module::strategies::monitoring::handler::{CalibrationWizard, MonitoringHandler};
 
 impl ModuleHandler for MonitoringHandler {
-    fn handle_key(&mut self, key_code: KeyCode, _module: &mut Module) -> Option<AppEvent> {
+    fn handle_key(&mut self, key_code: KeyCode, module: &mut Module) -> Option<AppEvent> {
+        if self.calibration.is_some() {
+            return self.handle_calibration_key(key_code, module);
+        }
+
         match key_code {
             KeyCode::Char('r') => {
-                log_info!("Manual refresh requested for {}", self.device_id);
+                // Take in telemetry that's already queued and re-query stored history on the next render
+                self.process_messages();
+                self.range_queried_at = None;
+                log_info!(target: &self.log_target, "Manual refresh requested for {}", self.device_id);
+                None
+            }
+            KeyCode::Char('t') => {
+                self.time_range = self.time_range.next();
+                self.range_queried_at = None;
+                log_info!(target: &self.log_target, "Chart range for {} set to {}", self.device_id, self.time_range.label());
+                None
+            }
+            KeyCode::Char('k') => {
+                self.calibration = Some(CalibrationWizard::new());
+                log_info!(target: &self.log_target, "Calibrating {}", self.device_id);
                 None
             }
             _ => None,
@@ -41,4 +60,42 @@ impl ModuleHandler for MonitoringHandler {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn last_error(&self) -> Option<(chrono::DateTime<chrono::Utc>, String)> {
+        self.last_error.get()
+    }
+
+    fn key_hints(&self) -> Vec<(KeyCode, &str)> {
+        self._key_hints()
+    }
+
+    fn quick_actions(&self) -> &'static [QuickAction] {
+        &[
+            QuickAction { trigger: 'u', key: Some(KeyCode::Char('r')), label: "Refresh", opens_detail: false },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyCode;
+
+    use crate::module::{strategies::monitoring::handler::{MonitoringHandler, TimeRange}, ModuleHandler};
+    use crate::util::test_util::{in_memory_database, loopback_bus, TestModule};
+
+    #[tokio::test]
+    async fn test_refresh_quick_action_requeries_history() {
+        let (bus, _receiver) = loopback_bus();
+        let mut handler = MonitoringHandler::new(bus, in_memory_database(), "a01".to_string(), "a01".to_string());
+        let mut module = TestModule::new("Tank", "monitoring", "gauge_card").build();
+
+        handler.time_range = TimeRange::OneHour;
+        handler.chart_history();
+        assert!(handler.range_queried_at.is_some());
+
+        let refresh = handler.quick_actions()[0].key.unwrap();
+        handler.handle_key(refresh, &mut module);
+        assert_eq!(refresh, KeyCode::Char('r'));
+        assert!(handler.range_queried_at.is_none());
+    }
 }
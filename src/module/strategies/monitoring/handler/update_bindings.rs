@@ -1,13 +1,16 @@
 use crate::{
     log_warn,
-    module::Module
+    module::Module,
+    ui::template::{calibration_overlay::CALIBRATION, empty_state::AWAITING_DATA},
 };
 use crate::module::strategies::monitoring::handler::MonitoringHandler;
+use crate::runtime::poll_interval::{module_poll_interval, PollTask};
 
 impl MonitoringHandler {
     pub(in crate::module) fn _update_bindings(&mut self, module: &mut Module) {
         // Process any queued async messages first
         self.process_messages();
+        self.range_poll_interval = module_poll_interval(&module.config, PollTask::Monitoring);
 
         let (value_a, value_b, value_c) = self.current_values;
 
@@ -27,8 +30,30 @@ impl MonitoringHandler {
             serde_json::json!(value_c),
         );
 
+        // Uncalibrated readings, for exports and checking a calibration against the device
+        if !self.conversions.is_empty() {
+            let (raw_a, raw_b) = self.raw_values;
+            module.config.bindings.insert("raw_a".to_string(), serde_json::json!(raw_a));
+            module.config.bindings.insert("raw_b".to_string(), serde_json::json!(raw_b));
+        }
+
+        // Glitch readings dropped or clamped so far, for spotting a failing sensor
+        if !self.outlier_filter.is_empty() {
+            module.config.bindings.insert("outliers".to_string(), serde_json::json!(self.outliers));
+        }
+
+        match &self.calibration {
+            Some(wizard) => {
+                let raw = self.last_update.map(|_| wizard.raw_reading(self.raw_values));
+                module.config.bindings.insert(CALIBRATION.to_string(), serde_json::json!(wizard.lines(raw)));
+            }
+            None => {
+                module.config.bindings.remove(CALIBRATION);
+            }
+        }
+
         // Export history to bindings for chart templates
-        let history_json: Vec<serde_json::Value> = self.history.iter()
+        let history_json: Vec<serde_json::Value> = self.chart_history().iter()
             .map(|(a, b, c)| {
                 serde_json::json!({
                     "a": a,
@@ -43,6 +68,11 @@ impl MonitoringHandler {
             serde_json::json!(history_json),
         );
 
+        module.config.bindings.insert(
+            "time_range".to_string(),
+            serde_json::json!(self.time_range.label()),
+        );
+
         // Add connection status
         let is_connected = self.is_connected();
         module.config.bindings.insert(
@@ -99,6 +129,18 @@ impl MonitoringHandler {
             );
         }
 
+        // Wall-clock time of the last telemetry, for the card's "updated Xs ago"
+        let last_updated = self.time_since_last_update()
+            .and_then(|elapsed| chrono::Duration::from_std(elapsed).ok())
+            .map(|elapsed| chrono::Utc::now() - elapsed);
+        module.apply_last_updated(last_updated);
+
+        // Until the first telemetry the manifest's `a`/`b`/`c` are placeholders, not readings
+        module.config.bindings.insert(
+            AWAITING_DATA.to_string(),
+            serde_json::json!(self.last_update.is_none()),
+        );
+
         // Update display name to show connection status
         if !is_connected {
             module.config.bindings.insert(
@@ -107,9 +149,9 @@ impl MonitoringHandler {
             );
 
             if self.last_update.is_none() {
-                log_warn!("Device {} has never sent telemetry", self.device_id);
+                log_warn!(target: &self.log_target, "Device {} has never sent telemetry", self.device_id);
             } else {
-                log_warn!("Device {} connection lost ({}s since last update)",
+                log_warn!(target: &self.log_target, "Device {} connection lost ({}s since last update)",
                     self.device_id,
                     self.time_since_last_update().unwrap().as_secs()
                 );
@@ -0,0 +1,11 @@
+use crate::module::strategies::monitoring::conversion::Conversions;
+
+use super::MonitoringHandler;
+
+impl MonitoringHandler {
+    /// Convert incoming `a`/`b` readings to display units before they're stored or shown
+    pub fn with_conversions(mut self, conversions: Conversions) -> Self {
+        self.conversions = conversions;
+        self
+    }
+}
@@ -0,0 +1,11 @@
+use crate::module::strategies::monitoring::outliers::OutlierFilter;
+
+use super::MonitoringHandler;
+
+impl MonitoringHandler {
+    /// Reject or clamp implausible readings before they're stored or shown
+    pub fn with_outlier_filter(mut self, outlier_filter: OutlierFilter) -> Self {
+        self.outlier_filter = outlier_filter;
+        self
+    }
+}
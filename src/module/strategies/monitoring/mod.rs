@@ -1,7 +1,11 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::module::BaseModuleConfig;
 
+pub mod conversion;
+pub mod database;
 pub mod handler;
+pub mod outliers;
 mod validation;
 
 /// Monitoring module (gauges, charts, status badges)
@@ -36,6 +40,62 @@ pub struct MonitoringBindings {
     #[serde(default)]
     pub danger_threshold: Option<f64>,
 
+    // Value presentation (optional, see `util::format::ValueFormat`)
+    #[serde(default)]
+    pub display_unit: Option<String>,
+    #[serde(default)]
+    pub auto_scale_unit: Option<bool>,
+    #[serde(default)]
+    pub significant_figures: Option<u32>,
+    #[serde(default)]
+    pub decimal_places: Option<u32>,
+    #[serde(default)]
+    pub display_precision: Option<u32>,
+    #[serde(default)]
+    pub rounding: Option<String>, // "nearest", "half_even", "truncate", "floor", "ceil"
+    #[serde(default)]
+    pub thousands_separator: Option<bool>,
+
+    // Raw-to-display calibration (optional, see `conversion`)
+    #[serde(default)]
+    pub a_scale: Option<f64>,
+    #[serde(default)]
+    pub a_offset: Option<f64>,
+    #[serde(default)]
+    pub a_expr: Option<String>,
+    #[serde(default)]
+    pub b_scale: Option<f64>,
+    #[serde(default)]
+    pub b_offset: Option<f64>,
+    #[serde(default)]
+    pub b_expr: Option<String>,
+
+    // Data cleaning (optional, see `outliers`)
+    #[serde(default)]
+    pub a_plausible_min: Option<f64>,
+    #[serde(default)]
+    pub a_plausible_max: Option<f64>,
+    #[serde(default)]
+    pub b_plausible_min: Option<f64>,
+    #[serde(default)]
+    pub b_plausible_max: Option<f64>,
+    #[serde(default)]
+    pub outlier_action: Option<String>, // "reject", "clamp"
+
+    // Gauge dial (optional, detail view)
+    #[serde(default)]
+    pub gauge_style: Option<String>, // "bar", "arc", "needle"
+    #[serde(default)]
+    pub start_angle: Option<f64>,
+    #[serde(default)]
+    pub end_angle: Option<f64>,
+    #[serde(default)]
+    pub show_ticks: Option<bool>,
+
+    // Status badge (optional): reading -> label/color, keyed by the value as text ("2", "fault")
+    #[serde(default)]
+    pub states: Option<HashMap<String, StatusState>>,
+
     // Chart-specific (optional)
     #[serde(default)]
     pub chart_type: Option<String>, // "line", "bar", "sparkline"
@@ -52,3 +112,14 @@ pub struct MonitoringBindings {
     #[serde(default)]
     pub _chart_history: Option<Vec<f64>>,
 }
+
+/// How the status badge presents one reading
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusState {
+    pub label: String,
+    /// Named color like `red`, or `#rrggbb`
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+}
@@ -0,0 +1,154 @@
+// src/module/strategies/monitoring/outliers.rs
+
+//! Data cleaning for physically impossible readings, applied after calibration and before a
+//! sample reaches history. Unlike display thresholds these bounds decide whether a sample is kept.
+//!
+//! Per value (`a`, `b`) a module sets `<key>_plausible_min` and/or `<key>_plausible_max`.
+//! `outlier_action` is `reject` (the default, the sample is dropped) or `clamp` (it's pulled
+//! back to the nearest bound). Non-finite readings are always dropped once bounds are set.
+
+use crate::module::{ModuleConfig, ValidationError};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum OutlierAction {
+    #[default]
+    Reject,
+    Clamp,
+}
+
+impl OutlierAction {
+    pub fn parse(action: Option<&str>) -> Result<Self, ValidationError> {
+        match action {
+            None | Some("reject") => Ok(OutlierAction::Reject),
+            Some("clamp") => Ok(OutlierAction::Clamp),
+            Some(other) => Err(ValidationError {
+                field: "bindings.outlier_action".to_string(),
+                error: format!("Must be 'reject' or 'clamp', not '{}'", other),
+            }),
+        }
+    }
+}
+
+/// The readings a sensor can physically produce; either end may be open
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlausibleRange {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl PlausibleRange {
+    /// The range for value `key` from its `_plausible_min`/`_plausible_max` bindings, if either is set
+    pub fn from_parts(key: &str, min: Option<f64>, max: Option<f64>) -> Result<Option<Self>, ValidationError> {
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return Err(ValidationError {
+                    field: format!("bindings.{}_plausible_max", key),
+                    error: format!("Cannot be below {}_plausible_min ({})", key, min),
+                });
+            }
+        }
+        Ok((min.is_some() || max.is_some()).then_some(Self { min, max }))
+    }
+
+    fn contains(&self, value: f64) -> bool {
+        value.is_finite() && self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min.unwrap_or(f64::NEG_INFINITY)).min(self.max.unwrap_or(f64::INFINITY))
+    }
+}
+
+/// What became of one sample
+#[derive(Debug, Clone, PartialEq)]
+pub enum Screened {
+    Accepted(f64, f64),
+    /// Kept, with out-of-range values pulled to their bounds; the reason names the original reading
+    Clamped(f64, f64, String),
+    Rejected(String),
+}
+
+/// Plausible ranges for `a` and `b` and what to do with samples outside them
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OutlierFilter {
+    pub a: Option<PlausibleRange>,
+    pub b: Option<PlausibleRange>,
+    pub action: OutlierAction,
+}
+
+impl OutlierFilter {
+    /// Read `a_plausible_min`, `a_plausible_max`, the `b_` equivalents and `outlier_action` from a module's bindings
+    pub fn from_config(config: &ModuleConfig) -> Result<Self, ValidationError> {
+        let number = |field: &str| config.try_binding::<f64>(field, "a number");
+        let action = config.try_binding("outlier_action", "a string")?;
+
+        Ok(Self {
+            a: PlausibleRange::from_parts("a", number("a_plausible_min")?, number("a_plausible_max")?)?,
+            b: PlausibleRange::from_parts("b", number("b_plausible_min")?, number("b_plausible_max")?)?,
+            action: OutlierAction::parse(action)?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.a.is_none() && self.b.is_none()
+    }
+
+    pub fn screen(&self, a: f64, b: f64) -> Screened {
+        let outside = |key: &str, range: &Option<PlausibleRange>, value: f64| {
+            range.filter(|range| !range.contains(value)).map(|range| (format!("{}={}", key, value), range))
+        };
+        let (out_a, out_b) = (outside("a", &self.a, a), outside("b", &self.b, b));
+        if out_a.is_none() && out_b.is_none() {
+            return Screened::Accepted(a, b);
+        }
+
+        let reason = [&out_a, &out_b]
+            .into_iter()
+            .flatten()
+            .map(|(reading, _)| reading.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        // NaN and infinity have no nearest bound to clamp to
+        let clampable = |out: &Option<(String, PlausibleRange)>, value: f64| out.is_none() || value.is_finite();
+        if self.action == OutlierAction::Reject || !clampable(&out_a, a) || !clampable(&out_b, b) {
+            return Screened::Rejected(format!("implausible {}", reason));
+        }
+        let clamp = |out: &Option<(String, PlausibleRange)>, value: f64| {
+            out.as_ref().map_or(value, |(_, range)| range.clamp(value))
+        };
+        Screened::Clamped(clamp(&out_a, a), clamp(&out_b, b), format!("clamped {}", reason))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    use crate::util::test_util::TestModule;
+
+    #[test]
+    fn test_rejects_or_clamps_implausible_samples() {
+        let config = TestModule::new("Tank", "system", "")
+            .binding("a_plausible_min", json!(0))
+            .binding("a_plausible_max", json!(200))
+            .build()
+            .config;
+        let mut filter = OutlierFilter::from_config(&config).unwrap();
+        assert_eq!(filter.screen(72.5, 1e9), Screened::Accepted(72.5, 1e9));
+        assert_eq!(filter.screen(1e9, 3.0), Screened::Rejected("implausible a=1000000000".to_string()));
+        assert!(matches!(filter.screen(f64::NAN, 3.0), Screened::Rejected(_)));
+
+        filter.action = OutlierAction::Clamp;
+        assert_eq!(filter.screen(-4.0, 3.0), Screened::Clamped(0.0, 3.0, "clamped a=-4".to_string()));
+        assert!(matches!(filter.screen(f64::INFINITY, 3.0), Screened::Rejected(_)));
+
+        assert!(OutlierFilter::from_config(&TestModule::new("Tank", "system", "").build().config).unwrap().is_empty());
+        let inverted = TestModule::new("Tank", "system", "")
+            .binding("b_plausible_min", json!(10))
+            .binding("b_plausible_max", json!(5))
+            .build()
+            .config;
+        assert_eq!(OutlierFilter::from_config(&inverted).unwrap_err().field, "bindings.b_plausible_max");
+    }
+}
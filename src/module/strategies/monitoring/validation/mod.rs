@@ -1,3 +1,8 @@
+use std::str::FromStr;
+
+use crate::module::strategies::monitoring::conversion::Conversion;
+use crate::module::strategies::monitoring::outliers::{OutlierAction, PlausibleRange};
+use crate::util::format::Rounding;
 use crate::module::{
     ValidationError,
     ConfigValidator,
@@ -42,6 +47,29 @@ impl ConfigValidator {
             }
         }
 
+        // Calibration: scale/offset or an expression per value, not both
+        for (key, scale, offset, expr) in [
+            ("a", b.a_scale, b.a_offset, &b.a_expr),
+            ("b", b.b_scale, b.b_offset, &b.b_expr),
+        ] {
+            Conversion::from_parts(key, scale, offset, expr.as_deref())?;
+        }
+
+        // Plausible ranges must not be inverted
+        PlausibleRange::from_parts("a", b.a_plausible_min, b.a_plausible_max)?;
+        PlausibleRange::from_parts("b", b.b_plausible_min, b.b_plausible_max)?;
+        OutlierAction::parse(b.outlier_action.as_deref())?;
+
+        // Validate rounding if present
+        if let Some(rounding) = &b.rounding {
+            if Rounding::parse(rounding).is_none() {
+                return Err(ValidationError {
+                    field: "bindings.rounding".to_string(),
+                    error: format!("Must be one of: {:?}", Rounding::NAMES),
+                }.into());
+            }
+        }
+
         // Validate chart_type if present
         if let Some(chart_type) = &b.chart_type {
             let valid_types = ["line", "bar", "sparkline"];
@@ -53,6 +81,20 @@ impl ConfigValidator {
             }
         }
 
+        // Validate status badge state colors if present
+        if let Some(states) = &b.states {
+            for (value, state) in states {
+                if let Some(color) = &state.color {
+                    if ratatui::style::Color::from_str(color.trim()).is_err() {
+                        return Err(ValidationError {
+                            field: format!("bindings.states.{}.color", value),
+                            error: format!("Unrecognized color: {}. Use a name like 'red' or '#rrggbb'", color),
+                        }.into());
+                    }
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,89 @@
+use serde_json::Value;
+
+use super::ConfigEditor;
+
+/// One field that differs between the on-disk config and the editor's version
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    /// Dotted path, e.g. `bindings.warn_threshold`
+    pub path: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+impl ConfigChange {
+    pub fn describe(&self) -> String {
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => format!("{}: {} → {}", self.path, old, new),
+            (None, Some(new)) => format!("{}: + {}", self.path, new),
+            (Some(old), None) => format!("{}: - {}", self.path, old),
+            (None, None) => self.path.clone(),
+        }
+    }
+}
+
+impl ConfigEditor {
+    /// Field-level changes the editor would write over `original_config`
+    pub fn diff_against(&self, original_config: &Value) -> Vec<ConfigChange> {
+        let mut changes = Vec::new();
+        diff_values("", original_config, &self.to_full_config(original_config), &mut changes);
+        changes
+    }
+}
+
+fn diff_values(path: &str, old: &Value, new: &Value, changes: &mut Vec<ConfigChange>) {
+    match (old, new) {
+        (Value::Object(old_map), Value::Object(new_map)) => {
+            let mut keys: Vec<&String> = old_map.keys().chain(new_map.keys()).collect();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let child = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                match (old_map.get(key), new_map.get(key)) {
+                    (Some(o), Some(n)) => diff_values(&child, o, n, changes),
+                    (o, n) => changes.push(ConfigChange {
+                        path: child,
+                        old: o.map(display_value),
+                        new: n.map(display_value),
+                    }),
+                }
+            }
+        }
+        // The editor stores every number as f64, so `100` on disk and `100.0` are the same value
+        (Value::Number(o), Value::Number(n)) if o.as_f64() == n.as_f64() => {}
+        (o, n) if o == n => {}
+        (o, n) => changes.push(ConfigChange {
+            path: path.to_string(),
+            old: Some(display_value(o)),
+            new: Some(display_value(n)),
+        }),
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_ignores_int_float_and_reports_changes() {
+        let mut changes = Vec::new();
+        diff_values(
+            "",
+            &serde_json::json!({"name": "Tank", "bindings": {"max_value": 100, "warn_threshold": 80}}),
+            &serde_json::json!({"name": "Tank", "bindings": {"max_value": 100.0, "warn_threshold": 8.0, "unit_label": "psi"}}),
+            &mut changes,
+        );
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].describe(), "bindings.unit_label: + psi");
+        assert_eq!(changes[1].describe(), "bindings.warn_threshold: 80 → 8.0");
+    }
+}
@@ -37,7 +37,7 @@ impl ConfigEditor {
         let default_template = match module_type {
             "monitoring" => "gauge_card",
             "valve_control" | "com" => "toggle_switch",
-            "llm" => "chat_interface",
+            "llm" => "llm_card",
             _ => "gauge_card",
         };
         let selected = template_options.iter()
@@ -5,7 +5,7 @@ use super::{ConfigEditor, EditorAction, EditorField, FieldValue};
 impl ConfigEditor {
     pub fn handle_key(&mut self, key: KeyCode) -> EditorAction {
         // Special handling for new modules in initial setup
-        if self.is_new_module && self.fields.len() == 2 {
+        if self.is_new_module && self.fields.len() == 2 && !self.is_editing {
             // We're in the initial "pick module type" phase
             match key {
                 KeyCode::Up => {
@@ -14,6 +14,7 @@ mod add_text_field;
 mod add_number_field;
 mod add_bool_field;
 mod to_full_config;
+mod config_diff;
 mod handle_key;
 mod start_editing;
 mod apply_edit;
@@ -26,6 +27,7 @@ use ratatui::{
 };
 
 pub use field_value::*;
+pub use config_diff::ConfigChange;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum EditorField {
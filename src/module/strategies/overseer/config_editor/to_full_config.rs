@@ -4,8 +4,8 @@ impl ConfigEditor {
     /// Convert editor fields back to full module config
     pub fn to_full_config(&self, original_config: &serde_json::Value) -> serde_json::Value {
         let mut config = if self.is_new_module {
-            // Start with empty config for new modules
-            serde_json::json!({})
+            // Start with empty config for new modules; the type field is gone once expanded
+            serde_json::json!({ "module_type": self.module_type })
         } else {
             original_config.clone()
         };
@@ -0,0 +1,60 @@
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+use super::KnownDevice;
+
+impl Database {
+    /// One page of known devices, most recently seen first
+    pub(in crate::module) fn _overseer__get_known_devices_page(&self, limit: usize, offset: usize) -> rusqlite::Result<Vec<KnownDevice>> {
+        let conn = Self::connection(&self.app_conn)?;
+        let mut stmt = conn.prepare(
+            "SELECT mac_address, device_name, device_type, firmware_version,
+                    first_seen, last_seen, is_trusted, rssi, heartbeat_interval_secs, device_id,
+                    custom_name
+             FROM known_devices
+             ORDER BY last_seen DESC, mac_address
+             LIMIT ?1 OFFSET ?2"
+        )?;
+
+        let devices = stmt
+            .query_map(params![limit as i64, offset as i64], KnownDevice::from_row)?
+            .collect::<rusqlite::Result<Vec<_>, _>>()?;
+
+        Ok(devices)
+    }
+
+    pub(in crate::module) fn _overseer__count_known_devices(&self) -> rusqlite::Result<usize> {
+        let conn = Self::connection(&self.app_conn)?;
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM known_devices", [], |row| row.get(0))?;
+        Ok(count as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module::strategies::overseer::database::OverseerDatabase;
+    use crate::util::database::Database;
+
+    #[test]
+    fn test_pages_cover_every_device_once() {
+        let db = Database::in_memory().unwrap();
+        for i in 0..7 {
+            db.record_device_discovery(&format!("AA:BB:CC:DD:EE:{:02X}", i), &format!("unit {}", i), -60).unwrap();
+        }
+
+        assert_eq!(db.count_known_devices().unwrap(), 7);
+
+        let mut seen: Vec<String> = Vec::new();
+        for page in 0..3 {
+            let devices = db.get_known_devices_page(3, page * 3).unwrap();
+            assert_eq!(devices.len(), if page < 2 { 3 } else { 1 });
+            seen.extend(devices.into_iter().map(|d| d.mac_address));
+        }
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 7);
+
+        assert!(db.get_known_devices_page(3, 9).unwrap().is_empty());
+    }
+}
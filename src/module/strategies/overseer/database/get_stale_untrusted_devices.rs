@@ -0,0 +1,19 @@
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+impl Database {
+    pub(in crate::module) fn _overseer__get_stale_untrusted_devices(&self, last_seen_before: i64) -> rusqlite::Result<Vec<String>> {
+        let conn = Self::connection(&self.app_conn)?;
+        let mut stmt = conn.prepare(
+            "SELECT mac_address FROM known_devices
+             WHERE is_trusted = 0 AND last_seen < ?1"
+        )?;
+
+        let devices = stmt
+            .query_map(params![last_seen_before], |row| row.get(0))?
+            .collect::<rusqlite::Result<Vec<_>, _>>()?;
+
+        Ok(devices)
+    }
+}
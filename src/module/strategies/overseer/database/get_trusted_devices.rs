@@ -2,7 +2,7 @@ use crate::util::database::Database;
 
 impl Database {
     pub(in crate::module) fn _overseer__get_trusted_devices(&self) -> rusqlite::Result<Vec<(String, String)>> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         let mut stmt = conn.prepare(
             "SELECT mac_address, device_name FROM known_devices WHERE is_trusted = 1"
         )?;
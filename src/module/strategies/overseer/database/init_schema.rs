@@ -2,10 +2,11 @@ use crate::{
     log_info,
     util::database::Database,
 };
+use crate::module::strategies::overseer::LOG_TARGET;
 
 impl Database {
     pub(in crate::module) fn _overseer__init_schema(&self) -> rusqlite::Result<()> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
 
         // Known devices table
         conn.execute(
@@ -37,7 +38,7 @@ impl Database {
         ).unwrap_or(0);
 
         if old_table_exists > 0 {
-            log_info!("Migrating data from trusted_devices to known_devices...");
+            log_info!(target: LOG_TARGET, "Migrating data from trusted_devices to known_devices...");
 
             // Copy trusted devices to new table
             conn.execute(
@@ -50,9 +51,10 @@ impl Database {
             // Drop old table
             conn.execute("DROP TABLE IF EXISTS trusted_devices", [])?;
 
-            log_info!("Migration complete!");
+            log_info!(target: LOG_TARGET, "Migration complete!");
         }
+        drop(conn);
 
-        Ok(())
+        self.apply_migrations(&self.app_conn, "overseer", super::migrations::MIGRATIONS)
     }
 }
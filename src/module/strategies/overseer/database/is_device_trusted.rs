@@ -3,7 +3,7 @@ use crate::util::database::Database;
 
 impl Database {
     pub(in crate::module) fn _overseer__is_device_trusted(&self, mac_address: &str) -> rusqlite::Result<bool> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
 
         let trusted: rusqlite::Result<i64, _> = conn.query_row(
             "SELECT is_trusted FROM known_devices WHERE mac_address = ?1",
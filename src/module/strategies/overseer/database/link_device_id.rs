@@ -0,0 +1,14 @@
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+impl Database {
+    pub(in crate::module) fn _overseer__link_device_id(&self, mac_address: &str, device_id: &str) -> rusqlite::Result<()> {
+        let conn = Self::connection(&self.app_conn)?;
+        conn.execute(
+            "UPDATE known_devices SET device_id = ?1 WHERE mac_address = ?2",
+            params![device_id, mac_address],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use crate::util::database::Migration;
+
+/// Overseer schema changes applied on top of `known_devices`. Append only.
+pub(super) const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "index devices by last_seen",
+        sql: "CREATE INDEX IF NOT EXISTS idx_known_devices_last_seen ON known_devices(last_seen)",
+    },
+    Migration {
+        version: 2,
+        description: "per-device heartbeat interval",
+        sql: "ALTER TABLE known_devices ADD COLUMN heartbeat_interval_secs INTEGER",
+    },
+    Migration {
+        version: 3,
+        description: "link devices to their SSP id and keep user-chosen names",
+        sql: "ALTER TABLE known_devices ADD COLUMN device_id TEXT;
+              ALTER TABLE known_devices ADD COLUMN custom_name INTEGER NOT NULL DEFAULT 0;",
+    },
+];
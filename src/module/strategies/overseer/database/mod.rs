@@ -1,5 +1,6 @@
 mod trait_overseer_database;
 mod init_schema;
+mod migrations;
 mod record_device_discovery;
 mod update_device_metadata;
 mod is_device_trusted;
@@ -7,7 +8,13 @@ mod set_device_trust;
 mod trust_device;
 mod get_trusted_devices;
 mod get_all_known_devices;
+mod get_known_devices_page;
 mod delete_device;
+mod restore_device;
+mod record_device_heartbeats;
+mod set_heartbeat_interval;
+mod link_device_id;
+mod get_stale_untrusted_devices;
 
 use rusqlite::{params, Result};
 use serde::{Deserialize, Serialize};
@@ -28,4 +35,34 @@ pub struct KnownDevice {
     pub last_seen: i64,
     pub is_trusted: bool,
     pub rssi: Option<i16>,
+    /// Longest expected gap between messages before the device counts as offline.
+    /// `None` uses the `DEVICE_HEARTBEAT_SECS` default; `0` turns liveness tracking off.
+    #[serde(default)]
+    pub heartbeat_interval_secs: Option<u64>,
+    /// SSP id (`"i"`) the device reports in telemetry; modules bind to it as `device_id`
+    #[serde(default)]
+    pub device_id: Option<String>,
+    /// Renamed by the user, so discovery keeps `device_name` as it is
+    #[serde(default)]
+    pub custom_name: bool,
+}
+
+impl KnownDevice {
+    /// Map a row selected as `mac_address, device_name, device_type, firmware_version,
+    /// first_seen, last_seen, is_trusted, rssi, heartbeat_interval_secs, device_id, custom_name`
+    fn from_row(row: &rusqlite::Row) -> Result<Self> {
+        Ok(KnownDevice {
+            mac_address: row.get(0)?,
+            device_name: row.get(1)?,
+            device_type: row.get(2)?,
+            firmware_version: row.get(3)?,
+            first_seen: row.get(4)?,
+            last_seen: row.get(5)?,
+            is_trusted: row.get::<_, i64>(6)? == 1,
+            rssi: row.get(7)?,
+            heartbeat_interval_secs: row.get::<_, Option<i64>>(8)?.map(|s| s.max(0) as u64),
+            device_id: row.get(9)?,
+            custom_name: row.get::<_, i64>(10)? == 1,
+        })
+    }
 }
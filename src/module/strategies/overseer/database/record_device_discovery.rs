@@ -9,7 +9,7 @@ impl Database {
         device_name: &str,
         rssi: i16,
     ) -> rusqlite::Result<bool> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -23,10 +23,11 @@ impl Database {
         )?;
 
         if exists {
-            // Update last seen, RSSI, and device name
+            // Update last seen, RSSI, and device name (unless the user renamed it)
             conn.execute(
                 "UPDATE known_devices
-                 SET last_seen = ?1, rssi = ?2, device_name = ?3
+                 SET last_seen = ?1, rssi = ?2,
+                     device_name = CASE WHEN custom_name = 1 THEN device_name ELSE ?3 END
                  WHERE mac_address = ?4",
                 params![now, rssi, device_name, mac_address],
             )?;
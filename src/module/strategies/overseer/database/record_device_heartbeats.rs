@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+impl Database {
+    /// Set `last_seen` for each MAC in `heard` (unix seconds) in one transaction
+    pub(in crate::module) fn _overseer__record_device_heartbeats(&self, heard: &HashMap<String, i64>) -> rusqlite::Result<()> {
+        let mut conn = Self::connection(&self.app_conn)?;
+        let tx = conn.transaction()?;
+        {
+            let mut stmt = tx.prepare("UPDATE known_devices SET last_seen = MAX(last_seen, ?1) WHERE mac_address = ?2")?;
+            for (mac_address, seen_at) in heard {
+                stmt.execute(params![seen_at, mac_address])?;
+            }
+        }
+        tx.commit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module::strategies::overseer::database::OverseerDatabase;
+    use super::*;
+
+    #[test]
+    fn test_heartbeats_only_move_last_seen_forward() {
+        let database = Database::in_memory().unwrap();
+        database.record_device_discovery("AA:BB", "Pump", -60).unwrap();
+        let first_seen = database.get_all_known_devices().unwrap()[0].last_seen;
+
+        database.record_device_heartbeats(&HashMap::from([("AA:BB".to_string(), first_seen + 90)])).unwrap();
+        database.record_device_heartbeats(&HashMap::from([("AA:BB".to_string(), first_seen + 30)])).unwrap();
+
+        assert_eq!(database.get_all_known_devices().unwrap()[0].last_seen, first_seen + 90);
+    }
+}
@@ -0,0 +1,57 @@
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+use super::KnownDevice;
+
+impl Database {
+    /// Put back a device row removed by `delete_device`, as it was when deleted
+    pub(in crate::module) fn _overseer__restore_device(&self, device: &KnownDevice) -> rusqlite::Result<()> {
+        let conn = Self::connection(&self.app_conn)?;
+        conn.execute(
+            "INSERT OR REPLACE INTO known_devices
+             (mac_address, device_name, device_type, firmware_version, first_seen, last_seen,
+              is_trusted, rssi, heartbeat_interval_secs, device_id, custom_name)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                device.mac_address,
+                device.device_name,
+                device.device_type,
+                device.firmware_version,
+                device.first_seen,
+                device.last_seen,
+                if device.is_trusted { 1 } else { 0 },
+                device.rssi,
+                device.heartbeat_interval_secs.map(|s| s as i64),
+                device.device_id,
+                if device.custom_name { 1 } else { 0 },
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module::strategies::overseer::database::OverseerDatabase;
+    use crate::util::database::Database;
+
+    #[test]
+    fn test_restored_device_keeps_its_custom_name() {
+        let db = Database::in_memory().unwrap();
+        let mac = "AA:BB:CC:DD:EE:01";
+        db.record_device_discovery(mac, "BLE-UART", -60).unwrap();
+        db.update_device_metadata(mac, "Well pump", "valve").unwrap();
+
+        let device = db.get_all_known_devices().unwrap().remove(0);
+        assert!(device.custom_name);
+        db.delete_device(mac).unwrap();
+        db.restore_device(&device).unwrap();
+
+        // Rediscovery under the advertised name doesn't overwrite the user's
+        db.record_device_discovery(mac, "BLE-UART", -55).unwrap();
+        let restored = db.get_all_known_devices().unwrap().remove(0);
+        assert!(restored.custom_name);
+        assert_eq!(restored.device_name, "Well pump");
+    }
+}
@@ -0,0 +1,18 @@
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+impl Database {
+    pub(in crate::module) fn _overseer__set_heartbeat_interval(
+        &self,
+        mac_address: &str,
+        interval_secs: Option<u64>,
+    ) -> rusqlite::Result<()> {
+        let conn = Self::connection(&self.app_conn)?;
+        conn.execute(
+            "UPDATE known_devices SET heartbeat_interval_secs = ?1 WHERE mac_address = ?2",
+            params![interval_secs.map(|s| s as i64), mac_address],
+        )?;
+        Ok(())
+    }
+}
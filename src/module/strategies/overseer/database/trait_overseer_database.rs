@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use rusqlite::params;
 use crate::log_info;
 use crate::module::strategies::overseer::database::KnownDevice;
@@ -9,7 +11,10 @@ pub trait OverseerDatabase {
 
     // Device discovery and tracking
     fn record_device_discovery(&self, mac_address: &str, device_name: &str, rssi: i16) -> rusqlite::Result<bool>;
-    fn update_device_metadata(&self, mac_address: &str, device_type: &str, firmware_version: &str) -> rusqlite::Result<()>;
+    fn update_device_metadata(&self, mac_address: &str, device_name: &str, device_type: &str) -> rusqlite::Result<()>;
+    fn link_device_id(&self, mac_address: &str, device_id: &str) -> rusqlite::Result<()>;
+    fn record_device_heartbeats(&self, heard: &HashMap<String, i64>) -> rusqlite::Result<()>;
+    fn set_heartbeat_interval(&self, mac_address: &str, interval_secs: Option<u64>) -> rusqlite::Result<()>;
 
     // Device trust management
     fn is_device_trusted(&self, mac_address: &str) -> rusqlite::Result<bool>;
@@ -20,7 +25,11 @@ pub trait OverseerDatabase {
     // Device queries
     fn get_trusted_devices(&self) -> rusqlite::Result<Vec<(String, String)>>;
     fn get_all_known_devices(&self) -> rusqlite::Result<Vec<KnownDevice>>;
+    fn get_known_devices_page(&self, limit: usize, offset: usize) -> rusqlite::Result<Vec<KnownDevice>>;
+    fn count_known_devices(&self) -> rusqlite::Result<usize>;
+    fn get_stale_untrusted_devices(&self, last_seen_before: i64) -> rusqlite::Result<Vec<String>>;
     fn delete_device(&self, mac_address: &str) -> rusqlite::Result<()>;
+    fn restore_device(&self, device: &KnownDevice) -> rusqlite::Result<()>;
 }
 
 impl OverseerDatabase for Database {
@@ -40,10 +49,22 @@ impl OverseerDatabase for Database {
     fn update_device_metadata(
         &self,
         mac_address: &str,
+        device_name: &str,
         device_type: &str,
-        firmware_version: &str,
     ) -> rusqlite::Result<()> {
-        self._overseer__update_device_metadata(mac_address, device_type, firmware_version)
+        self._overseer__update_device_metadata(mac_address, device_name, device_type)
+    }
+
+    fn link_device_id(&self, mac_address: &str, device_id: &str) -> rusqlite::Result<()> {
+        self._overseer__link_device_id(mac_address, device_id)
+    }
+
+    fn record_device_heartbeats(&self, heard: &HashMap<String, i64>) -> rusqlite::Result<()> {
+        self._overseer__record_device_heartbeats(heard)
+    }
+
+    fn set_heartbeat_interval(&self, mac_address: &str, interval_secs: Option<u64>) -> rusqlite::Result<()> {
+        self._overseer__set_heartbeat_interval(mac_address, interval_secs)
     }
 
     fn is_device_trusted(&self, mac_address: &str) -> rusqlite::Result<bool> {
@@ -70,7 +91,23 @@ impl OverseerDatabase for Database {
         self._overseer__get_all_known_devices()
     }
 
+    fn get_known_devices_page(&self, limit: usize, offset: usize) -> rusqlite::Result<Vec<KnownDevice>> {
+        self._overseer__get_known_devices_page(limit, offset)
+    }
+
+    fn count_known_devices(&self) -> rusqlite::Result<usize> {
+        self._overseer__count_known_devices()
+    }
+
+    fn get_stale_untrusted_devices(&self, last_seen_before: i64) -> rusqlite::Result<Vec<String>> {
+        self._overseer__get_stale_untrusted_devices(last_seen_before)
+    }
+
     fn delete_device(&self, mac_address: &str) -> rusqlite::Result<()> {
         self._overseer__delete_device(mac_address)
     }
+
+    fn restore_device(&self, device: &KnownDevice) -> rusqlite::Result<()> {
+        self._overseer__restore_device(device)
+    }
 }
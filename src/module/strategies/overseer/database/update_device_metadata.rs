@@ -3,18 +3,19 @@ use rusqlite::params;
 use crate::util::database::Database;
 
 impl Database {
+    /// Set a user-chosen name and type; later scans keep the name instead of the advertised one
     pub(in crate::module) fn _overseer__update_device_metadata(
         &self,
         mac_address: &str,
+        device_name: &str,
         device_type: &str,
-        firmware_version: &str,
     ) -> rusqlite::Result<()> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         conn.execute(
             "UPDATE known_devices
-             SET device_type = ?1, firmware_version = ?2
+             SET device_name = ?1, device_type = ?2, custom_name = 1
              WHERE mac_address = ?3",
-            params![device_type, firmware_version, mac_address],
+            params![device_name, device_type, mac_address],
         )?;
         Ok(())
     }
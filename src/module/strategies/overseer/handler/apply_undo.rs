@@ -0,0 +1,42 @@
+use color_eyre::Result;
+
+use crate::module::strategies::overseer::database::OverseerDatabase;
+use crate::runtime::undo::UndoDirection;
+
+use super::{OverseerHandler, UndoOp};
+
+impl OverseerHandler {
+    /// Reverse or repeat a trust change, device deletion or archive recorded by this handler
+    pub(in crate::module) fn _apply_undo(&mut self, op: &mut serde_json::Value, direction: UndoDirection) -> Result<()> {
+        let mut undo_op: UndoOp = serde_json::from_value(op.clone())?;
+        let undo = direction == UndoDirection::Undo;
+
+        match &mut undo_op {
+            UndoOp::SetTrust { mac_address, trusted } => {
+                self.database.set_device_trust(mac_address, *trusted != undo)?;
+                self.refresh_known_devices();
+            }
+            UndoOp::DeleteDevice { device } => {
+                if undo {
+                    self.database.restore_device(device)?;
+                } else {
+                    self.database.delete_device(&device.mac_address)?;
+                }
+                self.refresh_known_devices();
+            }
+            UndoOp::Archive { module_name, archive_name } => {
+                if undo {
+                    self.restore_module(archive_name, Some(module_name.clone()))?;
+                } else {
+                    // Archiving again picks a new archive name; keep it for the next undo
+                    *archive_name = self.archive_module(module_name)?;
+                }
+                self.refresh_data_async();
+                self.trigger_module_refresh();
+            }
+        }
+
+        *op = serde_json::to_value(&undo_op)?;
+        Ok(())
+    }
+}
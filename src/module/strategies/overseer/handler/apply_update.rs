@@ -0,0 +1,36 @@
+use crate::log_info;
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::module::strategies::overseer::LOG_TARGET;
+
+use super::{
+    installer::{ModuleInstaller, StagedUpdate},
+    OverseerHandler,
+};
+
+impl OverseerHandler {
+    /// Swap a staged update in with the given conflict choices.
+    /// Returns true when the module changed and needs reloading.
+    pub(in crate::module) fn apply_update(&mut self, staged: StagedUpdate, take_update: &[bool]) -> bool {
+        let installer = ModuleInstaller {
+            wasteland_path: self.wasteland_path.clone(),
+            archive_path: self.archive_path.clone(),
+            registry_url: self.registry_url.clone(),
+            progress: None,
+        };
+        let version = staged.version.clone();
+
+        match installer.finish_update(staged, take_update) {
+            Ok(name) => {
+                log_info!(target: LOG_TARGET, "⬆️ Updated {} to {}", name, version);
+                self.refresh_installed_modules();
+                notify(ToastLevel::Success, format!("✓ Module {} updated to {}", name, version));
+                true
+            }
+            Err(e) => {
+                self.last_error.set(format!("Update failed: {}", e));
+                notify(ToastLevel::Error, format!("❌ Failed to update: {}", e));
+                false
+            }
+        }
+    }
+}
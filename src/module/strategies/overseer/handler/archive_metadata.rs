@@ -0,0 +1,111 @@
+use std::{fs, path::Path};
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Sidecar written next to each archived module directory as `<archive_name>.meta.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveMetadata {
+    /// Directory name the module had in the wasteland
+    pub original_name: String,
+    /// `name` from the module's config at archive time
+    pub display_name: String,
+    pub module_type: String,
+    pub archived_at: DateTime<Utc>,
+}
+
+/// One entry in the archived-modules view
+#[derive(Debug, Clone)]
+pub struct ArchivedModule {
+    /// Directory name under the archive path
+    pub archive_name: String,
+    pub metadata: ArchiveMetadata,
+}
+
+impl ArchiveMetadata {
+    fn sidecar_path(archive_path: &Path, archive_name: &str) -> std::path::PathBuf {
+        archive_path.join(format!("{}.meta.json", archive_name))
+    }
+
+    /// Capture metadata from a module's config before it is moved into the archive
+    pub fn capture(module_path: &Path, original_name: &str) -> Self {
+        let config = fs::read_to_string(module_path.join("config.yml"))
+            .ok()
+            .and_then(|yaml| serde_yaml::from_str::<serde_json::Value>(&yaml).ok());
+        let field = |key: &str| {
+            config
+                .as_ref()
+                .and_then(|c| c.get(key))
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        };
+
+        Self {
+            original_name: original_name.to_string(),
+            display_name: field("name").unwrap_or_else(|| original_name.to_string()),
+            module_type: field("module_type").unwrap_or_else(|| "unknown".to_string()),
+            archived_at: Utc::now(),
+        }
+    }
+
+    pub fn save(&self, archive_path: &Path, archive_name: &str) -> color_eyre::Result<()> {
+        fs::write(
+            Self::sidecar_path(archive_path, archive_name),
+            serde_json::to_string_pretty(self)?,
+        )?;
+        Ok(())
+    }
+
+    /// Whether the archive has a sidecar, so its archive time was recorded rather than guessed
+    pub fn has_sidecar(archive_path: &Path, archive_name: &str) -> bool {
+        Self::sidecar_path(archive_path, archive_name).is_file()
+    }
+
+    pub fn remove(archive_path: &Path, archive_name: &str) {
+        let _ = fs::remove_file(Self::sidecar_path(archive_path, archive_name));
+    }
+
+    /// Read the sidecar, or reconstruct it for archives made before sidecars existed:
+    /// the directory name is `<original>_<unix_secs>` and the config is still inside.
+    pub fn load(archive_path: &Path, archive_name: &str) -> Self {
+        if let Some(metadata) = fs::read_to_string(Self::sidecar_path(archive_path, archive_name))
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+        {
+            return metadata;
+        }
+
+        let (original_name, archived_at) = archive_name
+            .rsplit_once('_')
+            .and_then(|(name, secs)| {
+                let secs = secs.parse::<i64>().ok()?;
+                Some((name, Utc.timestamp_opt(secs, 0).single()?))
+            })
+            .unwrap_or((archive_name, DateTime::<Utc>::UNIX_EPOCH));
+
+        Self {
+            archived_at,
+            ..Self::capture(&archive_path.join(archive_name), original_name)
+        }
+    }
+}
+
+impl ArchivedModule {
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        filter.is_empty()
+            || self.metadata.display_name.to_lowercase().contains(&filter)
+            || self.metadata.original_name.to_lowercase().contains(&filter)
+            || self.metadata.module_type.to_lowercase().contains(&filter)
+    }
+
+    pub fn describe(&self) -> String {
+        format!(
+            "{} ({}) • archived {} • was {}",
+            self.metadata.display_name,
+            self.metadata.module_type,
+            self.metadata.archived_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"),
+            self.metadata.original_name,
+        )
+    }
+}
@@ -1,8 +1,12 @@
 use std::fs;
-use crate::module::strategies::overseer::handler::OverseerHandler;
+
+use crate::log_warn;
+use crate::module::strategies::overseer::handler::{archive_metadata::ArchiveMetadata, OverseerHandler};
+use crate::module::strategies::overseer::LOG_TARGET;
 
 impl OverseerHandler {
-    pub(in crate::module) fn archive_module(&self, module_name: &str) -> color_eyre::Result<()> {
+    /// Move a module into the archive, returning its archive name
+    pub(in crate::module) fn archive_module(&self, module_name: &str) -> color_eyre::Result<String> {
         let module_path = self.wasteland_path.join(module_name);
 
         if !module_path.exists() {
@@ -19,9 +23,16 @@ impl OverseerHandler {
         let archive_name = format!("{}_{}", module_name, timestamp);
         let archive_dest = self.archive_path.join(&archive_name);
 
+        let metadata = ArchiveMetadata::capture(&module_path, module_name);
+
         // Move to archive
         fs::rename(&module_path, &archive_dest)?;
 
-        Ok(())
+        // Sidecar is best-effort; listing falls back to the archived config
+        if let Err(e) = metadata.save(&self.archive_path, &archive_name) {
+            log_warn!(target: LOG_TARGET, "Failed to write archive metadata for {}: {}", archive_name, e);
+        }
+
+        Ok(archive_name)
     }
 }
@@ -0,0 +1,16 @@
+use super::{installer::InstallRecord, OverseerHandler};
+
+impl OverseerHandler {
+    /// Compare each installed module's recorded version with the registry listing
+    pub(in crate::module) fn check_module_updates(&mut self) {
+        self.module_updates = self
+            .installed_modules
+            .iter()
+            .filter_map(|name| {
+                let record = InstallRecord::load(&self.wasteland_path.join(name))?;
+                let available = record.newer_in(&self.registry_manifests)?;
+                Some((name.clone(), (record.version, available.version.clone())))
+            })
+            .collect();
+    }
+}
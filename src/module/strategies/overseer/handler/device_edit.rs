@@ -0,0 +1,113 @@
+use crossterm::event::KeyCode;
+
+/// In-progress edit of a known device from the All Devices view
+#[derive(Debug, Clone)]
+pub(super) struct DeviceEdit {
+    pub mac_address: String,
+    pub device_id: Option<String>,
+    pub name: String,
+    pub device_type: String,
+    /// Expected heartbeat in seconds; empty uses the default, `0` stops liveness tracking
+    pub heartbeat_secs: String,
+    /// The field typing goes to
+    pub field: EditField,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum EditField {
+    Name,
+    Type,
+    Heartbeat,
+}
+
+/// What a key press in the edit form asks the handler to do
+pub(super) enum DeviceEditAction {
+    None,
+    Save,
+    Cancel,
+}
+
+impl DeviceEdit {
+    pub fn handle_key(&mut self, key_code: KeyCode) -> DeviceEditAction {
+        match key_code {
+            KeyCode::Enter => return DeviceEditAction::Save,
+            KeyCode::Esc => return DeviceEditAction::Cancel,
+            KeyCode::Tab | KeyCode::Down => {
+                self.field = match self.field {
+                    EditField::Name => EditField::Type,
+                    EditField::Type => EditField::Heartbeat,
+                    EditField::Heartbeat => EditField::Name,
+                };
+            }
+            KeyCode::Up => {
+                self.field = match self.field {
+                    EditField::Name => EditField::Heartbeat,
+                    EditField::Type => EditField::Name,
+                    EditField::Heartbeat => EditField::Type,
+                };
+            }
+            KeyCode::Backspace => {
+                self.active_field().pop();
+            }
+            KeyCode::Char(c) if self.field != EditField::Heartbeat || c.is_ascii_digit() => self.active_field().push(c),
+            _ => {}
+        }
+        DeviceEditAction::None
+    }
+
+    fn active_field(&mut self) -> &mut String {
+        match self.field {
+            EditField::Name => &mut self.name,
+            EditField::Type => &mut self.device_type,
+            EditField::Heartbeat => &mut self.heartbeat_secs,
+        }
+    }
+
+    /// The heartbeat interval to store: `None` for the default
+    pub fn heartbeat_interval(&self) -> Option<u64> {
+        self.heartbeat_secs.trim().parse().ok()
+    }
+
+    /// Form lines for the overseer card; the first is the heading, `▶` marks the active field
+    pub fn lines(&self) -> Vec<String> {
+        let marker = |field: EditField| if self.field == field { "▶" } else { " " };
+        let heartbeat = match self.heartbeat_interval() {
+            None => "default".to_string(),
+            Some(0) => "off".to_string(),
+            Some(_) => format!("{}s", self.heartbeat_secs.trim()),
+        };
+        vec![
+            format!("Edit {}", self.mac_address),
+            format!("{} Name: {}_", marker(EditField::Name), self.name),
+            format!("{} Type: {}_", marker(EditField::Type), self.device_type),
+            format!("{} Heartbeat: {}_ ({})", marker(EditField::Heartbeat), self.heartbeat_secs, heartbeat),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heartbeat_field_takes_digits_only() {
+        let mut edit = DeviceEdit {
+            mac_address: "AA:BB".to_string(),
+            device_id: None,
+            name: "Pump".to_string(),
+            device_type: String::new(),
+            heartbeat_secs: String::new(),
+            field: EditField::Name,
+        };
+        assert_eq!(edit.heartbeat_interval(), None);
+
+        edit.handle_key(KeyCode::Up);
+        for c in ['3', 'x', '0'] {
+            edit.handle_key(KeyCode::Char(c));
+        }
+
+        assert_eq!(edit.field, EditField::Heartbeat);
+        assert_eq!(edit.heartbeat_interval(), Some(30));
+        assert_eq!(edit.name, "Pump");
+    }
+}
@@ -1,42 +1,94 @@
-use super::{RegistryManifest, OverseerHandler};
+use chrono::Utc;
+use color_eyre::eyre::eyre;
+
+use crate::{log_error, log_warn};
+use crate::module::strategies::overseer::LOG_TARGET;
+use super::{
+    installer::LocalRepo,
+    registry_cache::{CachedRegistry, RegistryFetch, RegistryFetchPolicy},
+    OverseerHandler,
+    RegistryManifest,
+    RegistryResponse,
+};
 
 impl OverseerHandler {
-    pub(in crate::module) async fn fetch_registry_manifests(registry_url: &str) -> color_eyre::Result<Vec<RegistryManifest>> {
-        // Mock implementation
-        Ok(vec![
-            RegistryManifest {
-                id: "pressure_monitor".to_string(),
-                name: "FETCH Pressure Monitor".to_string(),
-                description: "FETCH Monitor hydraulic or pneumatic pressure".to_string(),
-                version: "1.0.0".to_string(),
-                author: "Survon Community".to_string(),
-                module_type: "monitoring".to_string(),
-                template: "gauge_card".to_string(),
-                download_url: format!("{}/manifests/pressure_monitor/download", registry_url),
-                checksum: "abc123".to_string(),
-            },
-            RegistryManifest {
-                id: "temperature_sensor".to_string(),
-                name: "FETCH Temperature Sensor".to_string(),
-                description: "FETCH Monitor ambient or equipment temperature".to_string(),
-                version: "1.2.0".to_string(),
-                author: "Survon Core".to_string(),
-                module_type: "monitoring".to_string(),
-                template: "gauge_card".to_string(),
-                download_url: format!("{}/manifests/temperature_sensor/download", registry_url),
-                checksum: "def456".to_string(),
-            },
-            RegistryManifest {
-                id: "gate_controller".to_string(),
-                name: "Gate Controller".to_string(),
-                description: "Remote gate/door control".to_string(),
-                version: "2.0.0".to_string(),
-                author: "Survon Core".to_string(),
-                module_type: "com".to_string(),
-                template: "toggle_switch".to_string(),
-                download_url: format!("{}/manifests/gate_controller/download", registry_url),
-                checksum: "ghi789".to_string(),
+    /// Fetch the registry listing, retrying with backoff. When every attempt fails,
+    /// fall back to the last successful response cached on disk.
+    /// A `file://` URL is an offline bundle repo and is read directly, without caching.
+    pub(in crate::module) async fn fetch_registry_manifests(registry_url: &str) -> color_eyre::Result<RegistryFetch> {
+        if let Some(repo) = LocalRepo::from_registry_url(registry_url) {
+            return Ok(RegistryFetch {
+                registry_url: registry_url.to_string(),
+                manifests: repo.listing()?,
+                cached_at: None,
+            });
+        }
+
+        let policy = RegistryFetchPolicy::from_env();
+        let cache_path = CachedRegistry::path();
+
+        match Self::fetch_registry_live(registry_url, &policy).await {
+            Ok(manifests) => {
+                let cached = CachedRegistry {
+                    fetched_at: Utc::now(),
+                    registry_url: registry_url.to_string(),
+                    manifests: manifests.clone(),
+                };
+                if let Err(e) = cached.save(&cache_path) {
+                    log_error!(target: LOG_TARGET, "Failed to cache registry at {}: {}", cache_path.display(), e);
+                }
+
+                Ok(RegistryFetch {
+                    registry_url: registry_url.to_string(),
+                    manifests,
+                    cached_at: None,
+                })
+            }
+            Err(e) => match CachedRegistry::load(&cache_path, registry_url) {
+                Some(cached) => {
+                    log_warn!(target: LOG_TARGET, "Registry fetch failed ({}); using cache from {}", e, cached.fetched_at);
+                    Ok(RegistryFetch {
+                        registry_url: cached.registry_url,
+                        manifests: cached.manifests,
+                        cached_at: Some(cached.fetched_at),
+                    })
+                }
+                None => Err(e.wrap_err("Registry unreachable and no cached copy available")),
             },
-        ])
+        }
+    }
+
+    async fn fetch_registry_live(registry_url: &str, policy: &RegistryFetchPolicy) -> color_eyre::Result<Vec<RegistryManifest>> {
+        let client = reqwest::Client::builder()
+            .timeout(policy.timeout)
+            .build()?;
+        let url = format!("{}/manifests", registry_url);
+
+        let mut backoff = policy.initial_backoff;
+        let mut last_error = None;
+
+        for attempt in 1..=policy.attempts {
+            let result = async {
+                let response = client.get(&url).send().await?.error_for_status()?;
+                response.json::<RegistryResponse>().await
+            }.await;
+
+            match result {
+                Ok(registry) => return Ok(registry.modules),
+                Err(e) => {
+                    log_warn!(target: LOG_TARGET, "Registry fetch attempt {}/{} failed: {}", attempt, policy.attempts, e);
+                    last_error = Some(e);
+                }
+            }
+
+            if attempt < policy.attempts {
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+        }
+
+        Err(last_error
+            .map(|e| eyre!(e))
+            .unwrap_or_else(|| eyre!("No registry fetch attempts made")))
     }
 }
@@ -1,22 +1,37 @@
-use super::OverseerHandler;
+use crate::runtime::{
+    toast::{notify, ToastLevel},
+    undo,
+};
+
+use crate::util::io::event::AppEvent;
+
+use super::{OverseerHandler, UndoOp};
 
 impl OverseerHandler {
-    pub(in crate::module) fn handle_archive_module(&mut self) {
-        if self.selected_index < self.installed_modules.len() {
-            let module_name = &self.installed_modules[self.selected_index];
+    /// Archive the selected module; the returned event takes it off the live dashboard
+    pub(in crate::module) fn handle_archive_module(&mut self) -> Option<AppEvent> {
+        if self.selected_index >= self.installed_modules.len() {
+            return None;
+        }
+        let module_name = self.installed_modules[self.selected_index].clone();
 
-            match self.archive_module(module_name) {
-                Ok(_) => {
-                    self.status_message = Some(format!("✓ Archived {}", module_name));
-                    self.installed_modules.remove(self.selected_index);
-                    if self.selected_index > 0 {
-                        self.selected_index -= 1;
-                    }
-                    self.refresh_data_async();
-                }
-                Err(e) => {
-                    self.status_message = Some(format!("Failed to archive: {}", e));
+        match self.archive_module(&module_name) {
+            Ok(archive_name) => {
+                notify(ToastLevel::Success, format!("✓ Archived {} - Ctrl+Z to undo", module_name));
+                undo::record("overseer", format!("archive {}", module_name), &UndoOp::Archive {
+                    module_name: module_name.clone(),
+                    archive_name,
+                });
+                self.installed_modules.remove(self.selected_index);
+                if self.selected_index > 0 {
+                    self.selected_index -= 1;
                 }
+                self.refresh_data_async();
+                Some(AppEvent::RemoveModule(module_name))
+            }
+            Err(e) => {
+                notify(ToastLevel::Error, format!("Failed to archive: {}", e));
+                None
             }
         }
     }
@@ -1,6 +1,8 @@
 use std::fs;
 
 use crate::{log_debug, log_error, log_info};
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::module::strategies::overseer::LOG_TARGET;
 use super::{OverseerHandler, WastelandView};
 
 impl OverseerHandler {
@@ -9,7 +11,7 @@ impl OverseerHandler {
             let module_name = editor.module_name.clone();
             let config_path = self.wasteland_path.join(&module_name).join("config.yml");
 
-            log_info!("Saving config for module: {}", module_name);
+            log_info!(target: LOG_TARGET, "Saving config for module: {}", module_name);
 
             // Read the original config
             match fs::read_to_string(&config_path) {
@@ -19,13 +21,13 @@ impl OverseerHandler {
                             // Get the updated config from editor
                             let updated_config = editor.to_full_config(&original_config);
 
-                            log_debug!("Updated config: {:?}", updated_config);
+                            log_debug!(target: LOG_TARGET, "Updated config: {:?}", updated_config);
 
                             // Use the update_module_config method which validates and writes
                             match self.update_module_config(&module_name, &updated_config) {
                                 Ok(_) => {
-                                    self.status_message = Some(format!("✓ Saved {}", module_name));
-                                    log_info!("Successfully saved config for {}", module_name);
+                                    notify(ToastLevel::Success, format!("✓ Saved {}", module_name));
+                                    log_info!(target: LOG_TARGET, "Successfully saved config for {}", module_name);
 
                                     // Close editor and return to modules view
                                     self.config_editor = None;
@@ -35,21 +37,20 @@ impl OverseerHandler {
                                     self.trigger_module_refresh();
                                 }
                                 Err(e) => {
-                                    self.status_message = Some(format!("❌ Save failed: {}", e));
-                                    log_error!("Failed to save config: {}", e);
+                                    notify(ToastLevel::Error, format!("❌ Save failed: {}", e));
+                                    log_error!(target: LOG_TARGET, "Failed to save config: {}", e);
                                 }
                             }
                         }
                         Err(e) => {
-                            self.status_message =
-                                Some(format!("Failed to parse original config: {}", e));
-                            log_error!("Parse error: {}", e);
+                            notify(ToastLevel::Error, format!("Failed to parse original config: {}", e));
+                            log_error!(target: LOG_TARGET, "Parse error: {}", e);
                         }
                     }
                 }
                 Err(e) => {
-                    self.status_message = Some(format!("Failed to read config: {}", e));
-                    log_error!("Read error: {}", e);
+                    notify(ToastLevel::Error, format!("Failed to read config: {}", e));
+                    log_error!(target: LOG_TARGET, "Read error: {}", e);
                 }
             }
         }
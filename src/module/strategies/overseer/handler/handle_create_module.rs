@@ -0,0 +1,71 @@
+use std::fs;
+
+use crate::{log_error, log_info};
+use crate::module::config::ConfigValidator;
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::util::fs::write_atomic;
+use crate::module::strategies::overseer::LOG_TARGET;
+
+use super::{OverseerHandler, WastelandView};
+
+impl OverseerHandler {
+    /// Validate the new-module editor and write it as a fresh module directory.
+    /// Returns true once the module is on disk.
+    pub(in crate::module) fn handle_create_module(&mut self) -> bool {
+        let Some(editor) = &self.config_editor else {
+            return false;
+        };
+
+        let module_name = editor.module_name.trim().to_string();
+        if module_name.is_empty() {
+            notify(ToastLevel::Error, "❌ Module name is required");
+            return false;
+        }
+
+        let module_path = self.wasteland_path.join(&module_name);
+        if module_path.exists() {
+            notify(ToastLevel::Error, format!("❌ A module named '{}' already exists", module_name));
+            return false;
+        }
+
+        let config = editor.to_full_config(&serde_json::json!({}));
+
+        let result = serde_yaml::to_string(&config)
+            .map_err(color_eyre::Report::from)
+            .and_then(|yaml| ConfigValidator::validate(&yaml).map(|_| yaml));
+
+        let yaml = match result {
+            Ok(yaml) => yaml,
+            Err(e) => {
+                notify(ToastLevel::Error, format!("❌ Invalid config: {}", e));
+                log_error!(target: LOG_TARGET, "New module '{}' failed validation: {}", module_name, e);
+                return false;
+            }
+        };
+
+        // Atomic so a half-written config.yml is never discovered
+        let write = || -> color_eyre::Result<()> {
+            fs::create_dir_all(&module_path)?;
+            write_atomic(&module_path.join("config.yml"), &yaml)?;
+            Ok(())
+        };
+
+        if let Err(e) = write() {
+            let _ = fs::remove_dir_all(&module_path);
+            notify(ToastLevel::Error, format!("❌ Create failed: {}", e));
+            log_error!(target: LOG_TARGET, "Failed to create module '{}': {}", module_name, e);
+            return false;
+        }
+
+        log_info!(target: LOG_TARGET, "Created module: {}", module_name);
+        notify(ToastLevel::Success, format!("✓ Created {}", module_name));
+        self.config_editor = None;
+        self.current_view = WastelandView::ManageModules;
+        self.selected_index = 0;
+
+        self.refresh_installed_modules();
+        self.trigger_module_refresh();
+
+        true
+    }
+}
@@ -1,17 +1,21 @@
 use crate::module::strategies::overseer::database::OverseerDatabase;
+use crate::runtime::{
+    toast::{notify, ToastLevel},
+    undo,
+};
 
-use super::OverseerHandler;
+use super::{OverseerHandler, UndoOp};
 
 impl OverseerHandler {
     pub(in crate::module) fn handle_delete_device(&mut self) {
         if self.selected_index < self.known_devices.len() {
-            let device = &self.known_devices[self.selected_index];
-            let mac = device.mac_address.clone();
+            let device = self.known_devices[self.selected_index].clone();
 
-            if let Err(e) = self.database.delete_device(&mac) {
-                self.status_message = Some(format!("Failed to delete: {}", e));
+            if let Err(e) = self.database.delete_device(&device.mac_address) {
+                notify(ToastLevel::Error, format!("Failed to delete: {}", e));
             } else {
-                self.status_message = Some("Device deleted".to_string());
+                notify(ToastLevel::Info, "Device deleted - Ctrl+Z to undo");
+                undo::record("overseer", format!("delete {}", device.device_name), &UndoOp::DeleteDevice { device });
                 self.refresh_known_devices();
                 if self.selected_index > 0 {
                     self.selected_index -= 1;
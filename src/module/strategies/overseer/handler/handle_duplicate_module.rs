@@ -0,0 +1,101 @@
+use std::fs;
+
+use crate::{log_error, log_info};
+use crate::module::config::ConfigValidator;
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::util::fs::write_atomic;
+use crate::module::strategies::overseer::LOG_TARGET;
+
+use super::{installer::ModuleInstaller, OverseerHandler};
+
+/// Written into `device_id` on a duplicate so the copy doesn't shadow the original sensor
+const DEVICE_ID_PLACEHOLDER: &str = "CHANGE_ME";
+
+/// Copies of one module tried before giving up on finding a free `_copy_N` name
+const MAX_COPIES: usize = 99;
+
+impl OverseerHandler {
+    /// Copy the selected module under a free name, reset its identity fields,
+    /// and open the copy in the config editor.
+    pub(in crate::module) fn handle_duplicate_module(&mut self) {
+        let Some(source_name) = self.installed_modules.get(self.selected_index).cloned() else {
+            return;
+        };
+
+        let Some(target_name) = (1..=MAX_COPIES)
+            .map(|n| match n {
+                1 => format!("{}_copy", source_name),
+                n => format!("{}_copy_{}", source_name, n),
+            })
+            .find(|name| !self.wasteland_path.join(name).exists())
+        else {
+            log_error!(target: LOG_TARGET, "No free name to duplicate {} under", source_name);
+            notify(ToastLevel::Error, format!("❌ Duplicate failed: {} already has {} copies", source_name, MAX_COPIES));
+            return;
+        };
+
+        match self.duplicate_module(&source_name, &target_name) {
+            Ok(()) => {
+                log_info!(target: LOG_TARGET, "Duplicated {} -> {}", source_name, target_name);
+                notify(ToastLevel::Success, format!("✓ Duplicated {} as {}", source_name, target_name));
+                self.refresh_installed_modules();
+                self.trigger_module_refresh();
+
+                // Hand the copy straight to the editor for tweaking
+                if let Some(idx) = self.installed_modules.iter().position(|m| m == &target_name) {
+                    self.selected_index = idx;
+                    self.handle_manage_modules_enter();
+                }
+            }
+            Err(e) => {
+                log_error!(target: LOG_TARGET, "Failed to duplicate {}: {}", source_name, e);
+                notify(ToastLevel::Error, format!("❌ Duplicate failed: {}", e));
+            }
+        }
+    }
+
+    fn duplicate_module(&self, source_name: &str, target_name: &str) -> color_eyre::Result<()> {
+        let source_path = self.wasteland_path.join(source_name);
+        let target_path = self.wasteland_path.join(target_name);
+
+        let yaml = fs::read_to_string(source_path.join("config.yml"))?;
+        let mut config: serde_json::Value = serde_yaml::from_str(&yaml)?;
+
+        if let Some(obj) = config.as_object_mut() {
+            let display_name = obj
+                .get("name")
+                .and_then(|v| v.as_str())
+                .unwrap_or(source_name)
+                .to_string();
+            obj.insert("name".to_string(), serde_json::json!(format!("{} Copy", display_name)));
+            obj.insert("bus_topic".to_string(), serde_json::json!(target_name));
+
+            if let Some(bindings) = obj.get_mut("bindings").and_then(|b| b.as_object_mut()) {
+                if bindings.contains_key("device_id") {
+                    bindings.insert("device_id".to_string(), serde_json::json!(DEVICE_ID_PLACEHOLDER));
+                }
+            }
+        }
+
+        // Validate the rewritten config before anything is copied
+        let new_yaml = serde_yaml::to_string(&config)?;
+        ConfigValidator::validate(&new_yaml)?;
+
+        let installer = ModuleInstaller {
+            wasteland_path: self.wasteland_path.clone(),
+            archive_path: self.archive_path.clone(),
+            registry_url: self.registry_url.clone(),
+            progress: None,
+        };
+
+        let result = installer
+            .copy_dir_recursive(&source_path, &target_path)
+            .and_then(|_| Ok(write_atomic(&target_path.join("config.yml"), &new_yaml)?));
+
+        if result.is_err() {
+            let _ = fs::remove_dir_all(&target_path);
+        }
+
+        result
+    }
+}
@@ -0,0 +1,16 @@
+use super::{device_edit::{DeviceEdit, EditField}, OverseerHandler};
+
+impl OverseerHandler {
+    pub(in crate::module) fn handle_edit_device(&mut self) {
+        if let Some(device) = self.known_devices.get(self.selected_index) {
+            self.device_edit = Some(DeviceEdit {
+                mac_address: device.mac_address.clone(),
+                device_id: device.device_id.clone(),
+                name: device.device_name.clone(),
+                device_type: device.device_type.clone().unwrap_or_default(),
+                heartbeat_secs: device.heartbeat_interval_secs.map(|s| s.to_string()).unwrap_or_default(),
+                field: EditField::Name,
+            });
+        }
+    }
+}
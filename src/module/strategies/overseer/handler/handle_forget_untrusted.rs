@@ -0,0 +1,62 @@
+use chrono::{Duration, Utc};
+
+use crate::log_info;
+use crate::module::strategies::overseer::database::OverseerDatabase;
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::module::strategies::overseer::LOG_TARGET;
+
+use super::OverseerHandler;
+
+/// Untrusted devices not seen for this long are offered for bulk removal.
+/// `FORGET_UNTRUSTED_AFTER_DAYS` (default 7).
+pub(super) fn forget_after_days() -> i64 {
+    std::env::var("FORGET_UNTRUSTED_AFTER_DAYS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .filter(|days| *days >= 0)
+        .unwrap_or(7)
+}
+
+impl OverseerHandler {
+    /// Find stale untrusted devices and ask for confirmation before forgetting them
+    pub(in crate::module) fn handle_forget_untrusted(&mut self) {
+        let days = forget_after_days();
+        let cutoff = (Utc::now() - Duration::days(days)).timestamp();
+
+        match self.database.get_stale_untrusted_devices(cutoff) {
+            Ok(macs) if macs.is_empty() => {
+                notify(ToastLevel::Info, format!("No untrusted devices unseen for {} day(s)", days));
+            }
+            Ok(macs) => {
+                self.pending_forget = Some(macs);
+            }
+            Err(e) => {
+                notify(ToastLevel::Error, format!("Failed to find stale devices: {}", e));
+            }
+        }
+    }
+
+    /// Delete the devices awaiting confirmation and report how many went
+    pub(in crate::module) fn confirm_forget_untrusted(&mut self) {
+        let Some(macs) = self.pending_forget.take() else {
+            return;
+        };
+
+        let mut forgotten = 0;
+        let mut failed = 0;
+        for mac in &macs {
+            match self.database.delete_device(mac) {
+                Ok(()) => forgotten += 1,
+                Err(_) => failed += 1,
+            }
+        }
+
+        log_info!(target: LOG_TARGET, "🧹 Forgot {} stale untrusted device(s), {} failed", forgotten, failed);
+        match failed {
+            0 => notify(ToastLevel::Success, format!("🧹 Forgot {} untrusted device(s)", forgotten)),
+            _ => notify(ToastLevel::Warning, format!("🧹 Forgot {} untrusted device(s), {} failed", forgotten, failed)),
+        }
+        self.selected_index = 0;
+        self.refresh_known_devices();
+    }
+}
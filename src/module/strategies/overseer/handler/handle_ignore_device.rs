@@ -1,3 +1,5 @@
+use crate::runtime::toast::{notify, ToastLevel};
+
 use super::OverseerHandler;
 
 impl OverseerHandler {
@@ -7,7 +9,7 @@ impl OverseerHandler {
             if self.selected_index > 0 {
                 self.selected_index -= 1;
             }
-            self.status_message = Some("Device ignored".to_string());
+            notify(ToastLevel::Info, "Device ignored");
         }
     }
 }
@@ -1,8 +1,14 @@
+use tokio::sync::mpsc;
+
+use crate::runtime::isolation::spawn_isolated;
+use crate::runtime::toast::{notify, ToastLevel};
+
 use super::{
-    installer::ModuleInstaller,
+    installer::{InstallProgress, LocalRepo, ModuleInstaller},
     HandlerMessage,
     InstallSource,
     OverseerHandler,
+    HANDLER_KEY,
 };
 
 impl OverseerHandler {
@@ -16,33 +22,53 @@ impl OverseerHandler {
             let wasteland_path = self.wasteland_path.clone();
             let archive_path = self.archive_path.clone();
             let registry_url = self.registry_url.clone();
+            let source = self.install_source(module_id);
+            let progress = self.install_progress_sender();
             let tx = self.message_tx.clone();
 
-            self.status_message = Some(format!("⏳ Installing {}...", module_name));
+            notify(ToastLevel::Info, format!("⏳ Installing {}...", module_name));
 
-            tokio::spawn(async move {
+            spawn_isolated(HANDLER_KEY, async move {
                 // Create a temporary handler-like struct for the install operation
                 let installer = ModuleInstaller {
                     wasteland_path,
                     archive_path,
                     registry_url,
+                    progress: Some(progress),
                 };
 
                 match installer
-                    .install_module(InstallSource::Registry(module_id.clone()), None)
+                    .install_module(source, None)
                     .await
                 {
                     Ok(name) => {
                         let _ = tx.send(HandlerMessage::ModuleInstalled(name));
                     }
                     Err(e) => {
-                        let _ = tx.send(HandlerMessage::OperationInProgress(format!(
-                            "❌ Failed to install: {}",
-                            e
-                        )));
+                        let _ = tx.send(HandlerMessage::InstallFailed(e.to_string()));
                     }
                 }
             });
         }
     }
+
+    /// Where `module_id` installs from given the active registry
+    pub(in crate::module) fn install_source(&self, module_id: String) -> InstallSource {
+        match LocalRepo::from_registry_url(&self.registry_url) {
+            Some(repo) => InstallSource::LocalRepo(repo.root, module_id),
+            None => InstallSource::Registry(module_id),
+        }
+    }
+
+    /// Channel for installer progress, relayed into the handler's message queue
+    pub(in crate::module) fn install_progress_sender(&self) -> mpsc::UnboundedSender<InstallProgress> {
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+        let tx = self.message_tx.clone();
+        spawn_isolated(HANDLER_KEY, async move {
+            while let Some(progress) = progress_rx.recv().await {
+                let _ = tx.send(HandlerMessage::InstallProgress(progress));
+            }
+        });
+        progress_tx
+    }
 }
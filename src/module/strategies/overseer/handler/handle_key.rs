@@ -6,34 +6,42 @@ use crate::module::{
 };
 use crate::module::strategies::overseer::{
     config_editor::EditorAction,
-    handler::{OverseerHandler, WastelandView}
+    handler::{
+        device_edit::DeviceEditAction,
+        installer::ModuleInstaller,
+        update_review::UpdateReviewAction,
+        OverseerHandler,
+        WastelandView,
+    }
 };
 use crate::util::io::event::AppEvent;
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::module::strategies::overseer::LOG_TARGET;
 
 impl OverseerHandler {
     pub(in crate::module) fn _handle_key(&mut self, key_code: KeyCode, _module: &mut Module) -> Option<AppEvent> {
-        log_debug!("handle_key: {:?}", key_code);
+        log_debug!(target: LOG_TARGET, "handle_key: {:?}", key_code);
         match self.current_view {
             WastelandView::Main => {
-                log_debug!("in main view...");
+                log_debug!(target: LOG_TARGET, "in main view...");
                 match key_code {
                     KeyCode::Up => {
                         if self.selected_index > 0 {
                             self.selected_index -= 1;
                         }
-                        log_debug!("pressed Up. selected index: {}", self.selected_index);
+                        log_debug!(target: LOG_TARGET, "pressed Up. selected index: {}", self.selected_index);
                         None
                     }
                     KeyCode::Down => {
-                        let max = 5;
+                        let max = 6;
                         if self.selected_index < max {
                             self.selected_index += 1;
                         }
-                        log_debug!("pressed Down. selected index: {}", self.selected_index);
+                        log_debug!(target: LOG_TARGET, "pressed Down. selected index: {}", self.selected_index);
                         None
                     }
                     KeyCode::Enter => {
-                        if self.selected_index == 5 {
+                        if self.selected_index == 6 {
                             Some(AppEvent::Back)
                         } else {
                             self.handle_main_menu_select();
@@ -76,6 +84,7 @@ impl OverseerHandler {
                 KeyCode::Char('v') => {
                     self.current_view = WastelandView::AllDevices;
                     self.selected_index = 0;
+                    self.device_page = 0;
                     self.refresh_known_devices();
                     None
                 }
@@ -94,10 +103,41 @@ impl OverseerHandler {
                 }
                 _ => None,
             },
+            WastelandView::AllDevices if self.device_edit.is_some() => {
+                let action = self.device_edit.as_mut().map(|edit| edit.handle_key(key_code));
+                match action {
+                    Some(DeviceEditAction::Save) => {
+                        if self.handle_save_device_edit() {
+                            Some(AppEvent::RefreshModules)
+                        } else {
+                            None
+                        }
+                    }
+                    Some(DeviceEditAction::Cancel) => {
+                        self.device_edit = None;
+                        Some(AppEvent::NoOp)
+                    }
+                    _ => None,
+                }
+            }
+            WastelandView::AllDevices if self.pending_forget.is_some() => match key_code {
+                KeyCode::Enter | KeyCode::Char('y') => {
+                    self.confirm_forget_untrusted();
+                    None
+                }
+                KeyCode::Esc | KeyCode::Char('n') => {
+                    self.pending_forget = None;
+                    notify(ToastLevel::Info, "Forget cancelled");
+                    Some(AppEvent::NoOp)
+                }
+                _ => None,
+            },
             WastelandView::AllDevices => match key_code {
                 KeyCode::Up => {
                     if self.selected_index > 0 {
                         self.selected_index -= 1;
+                    } else if self.turn_device_page(false) {
+                        self.selected_index = self.known_devices.len().saturating_sub(1);
                     }
                     None
                 }
@@ -105,6 +145,20 @@ impl OverseerHandler {
                     let max = self.known_devices.len().saturating_sub(1);
                     if self.selected_index < max {
                         self.selected_index += 1;
+                    } else if self.turn_device_page(true) {
+                        self.selected_index = 0;
+                    }
+                    None
+                }
+                KeyCode::PageUp => {
+                    if self.turn_device_page(false) {
+                        self.selected_index = 0;
+                    }
+                    None
+                }
+                KeyCode::PageDown => {
+                    if self.turn_device_page(true) {
+                        self.selected_index = 0;
                     }
                     None
                 }
@@ -116,6 +170,18 @@ impl OverseerHandler {
                     self.handle_delete_device();
                     None
                 }
+                KeyCode::Char('c') => {
+                    self.handle_reconnect_device();
+                    None
+                }
+                KeyCode::Char('e') => {
+                    self.handle_edit_device();
+                    None
+                }
+                KeyCode::Char('f') => {
+                    self.handle_forget_untrusted();
+                    None
+                }
                 KeyCode::Char('p') => {
                     self.current_view = WastelandView::PendingTrust;
                     self.selected_index = 0;
@@ -136,6 +202,19 @@ impl OverseerHandler {
                 }
                 _ => None,
             },
+            WastelandView::InstallRegistry if self.pending_install.is_some() => match key_code {
+                KeyCode::Enter | KeyCode::Char('y') => {
+                    self.pending_install = None;
+                    self.handle_install_module();
+                    None
+                }
+                KeyCode::Esc | KeyCode::Char('n') => {
+                    self.pending_install = None;
+                    notify(ToastLevel::Info, "Install cancelled");
+                    Some(AppEvent::NoOp)
+                }
+                _ => None,
+            },
             WastelandView::InstallRegistry => match key_code {
                 KeyCode::Up => {
                     if self.selected_index > 0 {
@@ -151,7 +230,11 @@ impl OverseerHandler {
                     None
                 }
                 KeyCode::Enter => {
-                    self.handle_install_module();
+                    self.review_install();
+                    None
+                }
+                KeyCode::Tab => {
+                    self.handle_switch_registry();
                     None
                 }
                 KeyCode::Esc => {
@@ -161,6 +244,27 @@ impl OverseerHandler {
                 }
                 _ => None,
             },
+            WastelandView::ManageModules if self.update_review.is_some() => {
+                let action = self.update_review.as_mut().map(|review| review.handle_key(key_code));
+                match action {
+                    Some(UpdateReviewAction::Apply) => {
+                        let review = self.update_review.take()?;
+                        if self.apply_update(review.staged, &review.take_update) {
+                            Some(AppEvent::RefreshModules)
+                        } else {
+                            None
+                        }
+                    }
+                    Some(UpdateReviewAction::Cancel) => {
+                        if let Some(review) = self.update_review.take() {
+                            ModuleInstaller::discard_update(&review.staged);
+                        }
+                        notify(ToastLevel::Info, "Update cancelled");
+                        Some(AppEvent::NoOp)
+                    }
+                    _ => None,
+                }
+            }
             WastelandView::ManageModules => match key_code {
                 KeyCode::Up => {
                     if self.selected_index > 0 {
@@ -179,26 +283,43 @@ impl OverseerHandler {
                     self.handle_manage_modules_enter();
                     None
                 }
-                KeyCode::Char('a') => {
-                    self.handle_archive_module();
+                KeyCode::Char('a') => self.handle_archive_module(),
+                KeyCode::Char('d') => {
+                    self.handle_duplicate_module();
+                    None
+                }
+                KeyCode::Char('U') => {
+                    self.handle_update_module();
                     None
                 }
                 KeyCode::Esc => {
-                    log_debug!("Hitting escape in modules list view");
+                    log_debug!(target: LOG_TARGET, "Hitting escape in modules list view");
                     self.current_view = WastelandView::Main;
                     self.selected_index = 0;
                     Some(AppEvent::NoOp)
                 }
                 _ => None,
             },
+            WastelandView::EditConfig if self.pending_changes.is_some() => match key_code {
+                KeyCode::Enter => {
+                    self.pending_changes = None;
+                    self.handle_config_editor_save();
+                    Some(AppEvent::RefreshModules)
+                }
+                KeyCode::Esc => {
+                    self.pending_changes = None;
+                    None
+                }
+                _ => None,
+            },
             WastelandView::EditConfig => {
                 if let Some(editor) = &mut self.config_editor {
                     match editor.handle_key(key_code) {
                         EditorAction::None => None,
                         EditorAction::ValueChanged => None,
                         EditorAction::Save => {
-                            self.handle_config_editor_save();
-                            Some(AppEvent::RefreshModules)
+                            self.review_config_save();
+                            None
                         }
                         EditorAction::ModuleTypeSelected => None,
                         EditorAction::Close => {
@@ -220,7 +341,7 @@ impl OverseerHandler {
                     None
                 }
                 KeyCode::Down => {
-                    let max = self.archived_modules.len().saturating_sub(1);
+                    let max = self.filtered_archived_modules().len().saturating_sub(1);
                     if self.selected_index < max {
                         self.selected_index += 1;
                     }
@@ -230,6 +351,21 @@ impl OverseerHandler {
                     self.handle_restore_module();
                     None
                 }
+                KeyCode::Char(c) => {
+                    self.archive_filter.push(c);
+                    self.selected_index = 0;
+                    None
+                }
+                KeyCode::Backspace => {
+                    self.archive_filter.pop();
+                    self.selected_index = 0;
+                    None
+                }
+                KeyCode::Esc if !self.archive_filter.is_empty() => {
+                    self.archive_filter.clear();
+                    self.selected_index = 0;
+                    None
+                }
                 KeyCode::Esc => {
                     self.current_view = WastelandView::Main;
                     self.selected_index = 0;
@@ -237,7 +373,29 @@ impl OverseerHandler {
                 }
                 _ => None,
             },
-            WastelandView::CreateNewModule => None,
+            WastelandView::CreateNewModule => {
+                if let Some(editor) = &mut self.config_editor {
+                    match editor.handle_key(key_code) {
+                        EditorAction::Save => {
+                            if self.handle_create_module() {
+                                Some(AppEvent::RefreshModules)
+                            } else {
+                                None
+                            }
+                        }
+                        EditorAction::Close => {
+                            self.config_editor = None;
+                            self.current_view = WastelandView::Main;
+                            self.selected_index = 0;
+                            Some(AppEvent::NoOp)
+                        }
+                        _ => None,
+                    }
+                } else {
+                    self.current_view = WastelandView::Main;
+                    None
+                }
+            }
         }
     }
 }
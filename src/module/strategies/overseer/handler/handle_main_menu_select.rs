@@ -13,6 +13,7 @@ impl OverseerHandler {
             1 => {
                 self.current_view = WastelandView::AllDevices;
                 self.selected_index = 0;
+                self.device_page = 0;
                 self.refresh_known_devices();
             }
             2 => {
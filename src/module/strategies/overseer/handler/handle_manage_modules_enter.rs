@@ -1,6 +1,7 @@
 use std::fs;
 
 use crate::module::strategies::overseer::config_editor::ConfigEditor;
+use crate::runtime::toast::{notify, ToastLevel};
 use super::{OverseerHandler, WastelandView};
 
 impl OverseerHandler {
@@ -38,12 +39,12 @@ impl OverseerHandler {
                             self.current_view = WastelandView::EditConfig;
                         }
                         Err(e) => {
-                            self.status_message = Some(format!("Failed to parse config: {}", e));
+                            notify(ToastLevel::Error, format!("Failed to parse config: {}", e));
                         }
                     }
                 }
                 Err(e) => {
-                    self.status_message = Some(format!("Failed to load config: {}", e));
+                    notify(ToastLevel::Error, format!("Failed to load config: {}", e));
                 }
             }
         }
@@ -0,0 +1,43 @@
+use crate::runtime::isolation::spawn_isolated;
+use crate::runtime::toast::{notify, ToastLevel};
+
+use super::{HandlerMessage, OverseerHandler, HANDLER_KEY};
+
+impl OverseerHandler {
+    pub(in crate::module) fn handle_reconnect_device(&mut self) {
+        let Some(device) = self.known_devices.get(self.selected_index) else {
+            return;
+        };
+
+        if !device.is_trusted {
+            notify(ToastLevel::Warning, "Trust the device before reconnecting");
+            return;
+        }
+
+        let Some(discovery) = &self.discovery_manager else {
+            notify(ToastLevel::Error, "❌ BLE discovery is disabled");
+            return;
+        };
+
+        let mac = device.mac_address.clone();
+        let name = device.device_name.clone();
+        let discovery_clone = discovery.clone();
+        let tx = self.message_tx.clone();
+
+        notify(ToastLevel::Info, format!("⏳ Reconnecting to {}...", name));
+
+        spawn_isolated(HANDLER_KEY, async move {
+            match discovery_clone.reconnect_device(&mac).await {
+                Ok(_) => {
+                    let _ = tx.send(HandlerMessage::DeviceReconnected(name));
+                }
+                Err(e) => {
+                    let _ = tx.send(HandlerMessage::OperationInProgress(format!(
+                        "❌ Failed to reconnect {}: {}",
+                        name, e
+                    )));
+                }
+            }
+        });
+    }
+}
@@ -1,22 +1,24 @@
+use crate::runtime::toast::{notify, ToastLevel};
+
 use super::OverseerHandler;
 
 impl OverseerHandler {
     pub(in crate::module) fn handle_restore_module(&mut self) {
-        if self.selected_index < self.archived_modules.len() {
-            let archive_name = &self.archived_modules[self.selected_index];
+        let Some(archived) = self.filtered_archived_modules().get(self.selected_index).map(|m| (*m).clone()) else {
+            return;
+        };
 
-            match self.restore_module(archive_name, None) {
-                Ok(_) => {
-                    self.status_message = Some(format!("✓ Restored {}", archive_name));
-                    self.archived_modules.remove(self.selected_index);
-                    if self.selected_index > 0 {
-                        self.selected_index -= 1;
-                    }
-                    self.refresh_data_async();
-                }
-                Err(e) => {
-                    self.status_message = Some(format!("Failed to restore: {}", e));
+        match self.restore_module(&archived.archive_name, None) {
+            Ok(_) => {
+                notify(ToastLevel::Success, format!("✓ Restored {}", archived.metadata.original_name));
+                self.archived_modules.retain(|m| m.archive_name != archived.archive_name);
+                if self.selected_index > 0 {
+                    self.selected_index -= 1;
                 }
+                self.refresh_data_async();
+            }
+            Err(e) => {
+                notify(ToastLevel::Error, format!("Failed to restore: {}", e));
             }
         }
     }
@@ -0,0 +1,136 @@
+use std::{fs, path::Path};
+
+use crate::{log_info, log_warn};
+use crate::module::config::ConfigValidator;
+use crate::module::strategies::overseer::database::OverseerDatabase;
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::util::fs::write_atomic;
+use crate::module::strategies::overseer::LOG_TARGET;
+
+use super::OverseerHandler;
+
+impl OverseerHandler {
+    /// Store the edited name, type and heartbeat interval, and relabel the modules bound to the device.
+    /// Returns true when modules were rewritten and need reloading.
+    pub(in crate::module) fn handle_save_device_edit(&mut self) -> bool {
+        let Some(edit) = self.device_edit.take() else {
+            return false;
+        };
+
+        let name = edit.name.trim();
+        if name.is_empty() {
+            notify(ToastLevel::Warning, "Device name can't be empty");
+            self.device_edit = Some(edit);
+            return false;
+        }
+
+        if let Err(e) = self.database.update_device_metadata(&edit.mac_address, name, edit.device_type.trim()) {
+            notify(ToastLevel::Error, format!("Failed to update device: {}", e));
+            return false;
+        }
+
+        // Through discovery when it's running, so its liveness checks pick up the new interval right away
+        let interval = edit.heartbeat_interval();
+        let stored = match &self.discovery_manager {
+            Some(discovery) => discovery.set_heartbeat_interval(&edit.mac_address, interval),
+            None => self.database.set_heartbeat_interval(&edit.mac_address, interval).map_err(Into::into),
+        };
+        if let Err(e) = stored {
+            notify(ToastLevel::Error, format!("Failed to update heartbeat interval: {}", e));
+            return false;
+        }
+
+        let relabeled = edit.device_id.as_deref().map_or(0, |id| relabel_device_modules(&self.wasteland_path, id, name));
+        notify(ToastLevel::Success, match relabeled {
+            0 => format!("✓ Device renamed to {}", name),
+            n => format!("✓ Device renamed to {} ({} module(s) updated)", name, n),
+        });
+        self.refresh_known_devices();
+
+        relabeled > 0
+    }
+}
+
+/// Set the `display_name` binding on every module under `wasteland_path` whose `device_id`
+/// binding is `device_id`, leaving the module's name and topic alone
+fn relabel_device_modules(wasteland_path: &Path, device_id: &str, name: &str) -> usize {
+    let Ok(entries) = fs::read_dir(wasteland_path) else {
+        return 0;
+    };
+
+    let mut relabeled = 0;
+    for entry in entries.flatten() {
+        let config_path = entry.path().join("config.yml");
+        let Ok(content) = fs::read_to_string(&config_path) else {
+            continue;
+        };
+        let Ok(mut config) = serde_yaml::from_str::<serde_yaml::Value>(&content) else {
+            continue;
+        };
+        let Some(bindings) = config.get_mut("bindings").and_then(|b| b.as_mapping_mut()) else {
+            continue;
+        };
+        if bindings.get("device_id").and_then(|v| v.as_str()) != Some(device_id) {
+            continue;
+        }
+
+        bindings.insert("display_name".into(), name.into());
+
+        match write_display_name(&config_path, &config) {
+            Ok(()) => {
+                log_info!(target: LOG_TARGET, "Relabeled module at {:?} as '{}'", config_path, name);
+                relabeled += 1;
+            }
+            Err(e) => log_warn!(target: LOG_TARGET, "Failed to relabel module at {:?}: {}", config_path, e),
+        }
+    }
+
+    relabeled
+}
+
+/// Validate the relabeled config before the atomic write, so a bad edit leaves the old one in place
+fn write_display_name(config_path: &Path, config: &serde_yaml::Value) -> color_eyre::Result<()> {
+    let yaml = serde_yaml::to_string(config)?;
+    ConfigValidator::validate(&yaml)?;
+    write_atomic(config_path, yaml)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relabel_sets_only_display_name() {
+        let wasteland = std::env::temp_dir().join(format!("survon_relabel_{}", uuid::Uuid::new_v4()));
+        let module_dir = wasteland.join("tank_level");
+        fs::create_dir_all(&module_dir).unwrap();
+        let config_path = module_dir.join("config.yml");
+        fs::write(&config_path, "\
+name: Tank Level
+module_type: monitoring
+bus_topic: a01
+template: gauge_card
+bindings:
+  a: 0.0
+  b: 0.0
+  c: 0.0
+  device_id: a01
+  device_type: tank
+  firmware_version: 1.0.0
+  display_name: Tank
+  unit_of_measure_label: '%'
+").unwrap();
+
+        assert_eq!(relabel_device_modules(&wasteland, "a01", "North Tank"), 1);
+        assert_eq!(relabel_device_modules(&wasteland, "b02", "Other"), 0);
+
+        let config: serde_yaml::Value = serde_yaml::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(config["name"].as_str(), Some("Tank Level"));
+        assert_eq!(config["bus_topic"].as_str(), Some("a01"));
+        assert_eq!(config["bindings"]["display_name"].as_str(), Some("North Tank"));
+        assert!(config["bindings"].get("device_name").is_none());
+
+        fs::remove_dir_all(&wasteland).unwrap();
+    }
+}
@@ -1,9 +1,12 @@
-use super::{HandlerMessage, OverseerHandler};
+use crate::runtime::isolation::spawn_isolated;
+use crate::runtime::toast::{notify, ToastLevel};
+
+use super::{HandlerMessage, OverseerHandler, HANDLER_KEY};
 
 impl OverseerHandler {
     pub(in crate::module) fn handle_scan_devices(&mut self) {
         if self.is_scanning {
-            self.status_message = Some("⚠️ Scan already in progress".to_string());
+            notify(ToastLevel::Warning, "⚠️ Scan already in progress");
             return;
         }
 
@@ -15,7 +18,7 @@ impl OverseerHandler {
             let discovery_clone = discovery.clone();
             let tx = self.message_tx.clone();
 
-            tokio::spawn(async move {
+            spawn_isolated(HANDLER_KEY, async move {
                 let countdown_task = async {
                     for i in (1..=scan_duration).rev() {
                         let _ = tx.send(HandlerMessage::ScanProgress(i as u8));
@@ -38,9 +41,9 @@ impl OverseerHandler {
                 }
             });
 
-            self.status_message = Some("🔍 Starting BLE scan...".to_string());
+            notify(ToastLevel::Info, "🔍 Starting BLE scan...");
         } else {
-            self.status_message = Some("❌ Discovery manager not available".to_string());
+            notify(ToastLevel::Error, "❌ Discovery manager not available");
         }
     }
 }
@@ -1,6 +1,10 @@
 use crate::module::strategies::overseer::database::OverseerDatabase;
+use crate::runtime::{
+    toast::{notify, ToastLevel},
+    undo,
+};
 
-use super::OverseerHandler;
+use super::{OverseerHandler, UndoOp};
 
 impl OverseerHandler {
     pub(in crate::module) fn handle_toggle_trust(&mut self) {
@@ -10,13 +14,15 @@ impl OverseerHandler {
             let new_trust = !device.is_trusted;
 
             if let Err(e) = self.database.set_device_trust(&mac, new_trust) {
-                self.status_message = Some(format!("Failed to update trust: {}", e));
+                notify(ToastLevel::Error, format!("Failed to update trust: {}", e));
             } else {
-                self.status_message = Some(if new_trust {
+                notify(ToastLevel::Info, if new_trust {
                     "Device trusted".to_string()
                 } else {
                     "Device untrusted".to_string()
                 });
+                let label = format!("{} {}", if new_trust { "trust" } else { "untrust" }, device.device_name);
+                undo::record("overseer", label, &UndoOp::SetTrust { mac_address: mac, trusted: new_trust });
                 self.refresh_known_devices();
             }
         }
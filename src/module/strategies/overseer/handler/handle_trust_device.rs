@@ -1,4 +1,7 @@
-use super::{HandlerMessage, OverseerHandler};
+use crate::runtime::isolation::spawn_isolated;
+use crate::runtime::toast::{notify, ToastLevel};
+
+use super::{HandlerMessage, OverseerHandler, HANDLER_KEY};
 
 impl OverseerHandler {
     pub(in crate::module) fn handle_trust_device(&mut self) {
@@ -8,11 +11,11 @@ impl OverseerHandler {
             let name_clone = name.clone();
             let tx = self.message_tx.clone();
 
-            self.status_message = Some(format!("⏳ Trusting {}...", name_clone));
+            notify(ToastLevel::Info, format!("⏳ Trusting {}...", name_clone));
 
             if let Some(discovery) = &self.discovery_manager {
                 let discovery_clone = discovery.clone();
-                tokio::spawn(async move {
+                spawn_isolated(HANDLER_KEY, async move {
                     let _ = tx.send(HandlerMessage::OperationInProgress(format!(
                         "Connecting to {}...",
                         name_clone
@@ -0,0 +1,50 @@
+use crate::runtime::isolation::spawn_isolated;
+use crate::runtime::toast::{notify, ToastLevel};
+
+use super::{
+    installer::{InstallRecord, ModuleInstaller},
+    HandlerMessage,
+    OverseerHandler,
+    HANDLER_KEY,
+};
+
+impl OverseerHandler {
+    /// Reinstall the selected module at the newer registry version, if there is one
+    pub(in crate::module) fn handle_update_module(&mut self) {
+        let Some(module_name) = self.installed_modules.get(self.selected_index).cloned() else {
+            return;
+        };
+        if !self.module_updates.contains_key(&module_name) {
+            notify(ToastLevel::Info, format!("{} is up to date", module_name));
+            return;
+        }
+
+        let manifest = InstallRecord::load(&self.wasteland_path.join(&module_name))
+            .and_then(|record| record.newer_in(&self.registry_manifests).cloned());
+        let Some(manifest) = manifest else {
+            return;
+        };
+
+        let source = self.install_source(manifest.id.clone());
+        let installer = ModuleInstaller {
+            wasteland_path: self.wasteland_path.clone(),
+            archive_path: self.archive_path.clone(),
+            registry_url: self.registry_url.clone(),
+            progress: Some(self.install_progress_sender()),
+        };
+        let tx = self.message_tx.clone();
+
+        notify(ToastLevel::Info, format!("⏳ Updating {} to {}...", module_name, manifest.version));
+
+        spawn_isolated(HANDLER_KEY, async move {
+            match installer.stage_update(&module_name, &manifest, source).await {
+                Ok(staged) => {
+                    let _ = tx.send(HandlerMessage::UpdateStaged(staged));
+                }
+                Err(e) => {
+                    let _ = tx.send(HandlerMessage::InstallFailed(e.to_string()));
+                }
+            }
+        });
+    }
+}
@@ -1,6 +1,8 @@
-use std::fs;
+use std::collections::HashMap;
 use std::path::Path;
 
+use crate::module::{ModuleCapabilities, ModuleConfig};
+use crate::util::fs::write_atomic;
 use super::ModuleInstaller;
 
 impl ModuleInstaller {
@@ -10,44 +12,28 @@ impl ModuleInstaller {
         name: &str,
         module_type: &str,
         template: &str,
-        bindings: Option<serde_yaml::Mapping>,
+        bindings: Option<HashMap<String, serde_json::Value>>,
+        capabilities: Option<&ModuleCapabilities>,
     ) -> color_eyre::Result<()> {
         let config_path = module_path.join("config.yml");
 
-        let mut config = serde_yaml::Mapping::new();
-        config.insert(
-            serde_yaml::Value::String("name".to_string()),
-            serde_yaml::Value::String(name.to_string()),
-        );
-        config.insert(
-            serde_yaml::Value::String("module_type".to_string()),
-            serde_yaml::Value::String(module_type.to_string()),
-        );
-        config.insert(
-            serde_yaml::Value::String("bus_topic".to_string()),
-            serde_yaml::Value::String(name.to_lowercase().replace(" ", "_")),
-        );
-        config.insert(
-            serde_yaml::Value::String("template".to_string()),
-            serde_yaml::Value::String(template.to_string()),
-        );
+        let mut config = ModuleConfig::builder()
+            .name(name)
+            .module_type(module_type)
+            .template(template);
 
-        let default_bindings = bindings.unwrap_or_else(|| {
-            let mut b = serde_yaml::Mapping::new();
-            b.insert(
-                serde_yaml::Value::String("is_blinkable".to_string()),
-                serde_yaml::Value::Bool(true),
-            );
-            b
-        });
+        config = match bindings {
+            Some(bindings) => config.bindings(bindings),
+            None => config.binding("is_blinkable", true),
+        };
 
-        config.insert(
-            serde_yaml::Value::String("bindings".to_string()),
-            serde_yaml::Value::Mapping(default_bindings),
-        );
+        // Carry the approved capabilities so the runtime can scope the module
+        if let Some(capabilities) = capabilities {
+            config = config.capabilities(capabilities.clone());
+        }
 
-        let yaml_content = serde_yaml::to_string(&config)?;
-        fs::write(config_path, yaml_content)?;
+        let yaml_content = serde_yaml::to_string(&config.build()?)?;
+        write_atomic(&config_path, yaml_content)?;
 
         Ok(())
     }
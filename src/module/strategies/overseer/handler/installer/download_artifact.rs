@@ -0,0 +1,33 @@
+use color_eyre::eyre::eyre;
+
+use crate::module::strategies::overseer::handler::{
+    registry_cache::RegistryFetchPolicy,
+    RegistryManifest,
+};
+
+use super::{InstallStage, ModuleInstaller};
+
+impl ModuleInstaller {
+    /// Raw bytes published at the manifest's `download_url`, reporting progress as chunks arrive
+    pub(in crate::module) async fn download_artifact(&self, manifest: &RegistryManifest) -> color_eyre::Result<Vec<u8>> {
+        if manifest.download_url.is_empty() {
+            return Err(eyre!("Module '{}' has no download URL", manifest.id));
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(RegistryFetchPolicy::from_env().timeout)
+            .build()?;
+
+        let mut response = client.get(&manifest.download_url).send().await?.error_for_status()?;
+        let total = response.content_length();
+        let mut artifact = Vec::with_capacity(total.unwrap_or(0) as usize);
+
+        self.report_progress(&manifest.id, InstallStage::Downloading, 0, total);
+        while let Some(chunk) = response.chunk().await? {
+            artifact.extend_from_slice(&chunk);
+            self.report_progress(&manifest.id, InstallStage::Downloading, artifact.len() as u64, total);
+        }
+
+        Ok(artifact)
+    }
+}
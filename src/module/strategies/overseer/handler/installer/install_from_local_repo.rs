@@ -0,0 +1,54 @@
+use std::fs;
+
+use crate::module::ConfigValidator;
+use super::{InstallRecord, InstallStage, LocalRepo, ModuleInstaller, SignaturePolicy, TrustedPublishers};
+
+impl ModuleInstaller {
+    /// Install a bundle from a local repo after checking every file's checksum (and the signature, per policy)
+    pub(in crate::module) async fn install_from_local_repo(
+        &self,
+        repo: &LocalRepo,
+        manifest_id: &str,
+        custom_name: Option<String>,
+    ) -> color_eyre::Result<String> {
+        let manifests = repo.listing()?;
+        let manifest = manifests
+            .iter()
+            .find(|m| m.id == manifest_id)
+            .ok_or_else(|| color_eyre::eyre::eyre!("Module not found in bundle repo"))?;
+
+        // Read once; everything below checks and writes these bytes, not the repo
+        let config_path = repo.artifact_path(manifest)?;
+        let config_name = config_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let files = repo.read_bundle(manifest)?;
+        let artifact = files
+            .get(&config_name)
+            .ok_or_else(|| color_eyre::eyre::eyre!("Bundle '{}' has no {}", manifest.id, config_name))?;
+
+        self.report_progress(&manifest.id, InstallStage::Verifying, 0, None);
+        Self::verify_checksum(manifest, artifact)?;
+        Self::verify_bundle_files(manifest, &files, &config_name)?;
+        Self::verify_signature(manifest, artifact, SignaturePolicy::from_env(), &TrustedPublishers::from_env())?;
+
+        self.report_progress(&manifest.id, InstallStage::Extracting, 0, None);
+        let installed = custom_name.unwrap_or_else(|| manifest.id.clone());
+        self.write_bundle(&installed, &files)?;
+        let module_path = self.wasteland_path.join(&installed);
+
+        // Bundles are complete configs, so one that doesn't validate is removed again
+        self.report_progress(&manifest.id, InstallStage::Validating, 0, None);
+        let recorded = fs::read_to_string(module_path.join(&config_name))
+            .map_err(color_eyre::Report::from)
+            .and_then(|yaml| ConfigValidator::validate(&yaml))
+            .and_then(|_| InstallRecord::new(manifest, &self.registry_url).save(&module_path));
+        if let Err(e) = recorded {
+            let _ = fs::remove_dir_all(&module_path);
+            return Err(e.wrap_err(format!("Failed to install '{}'", manifest.id)));
+        }
+
+        Ok(installed)
+    }
+}
@@ -1,6 +1,9 @@
 use std::fs;
 
-use super::ModuleInstaller;
+use crate::log_warn;
+use crate::module::ConfigValidator;
+use crate::module::strategies::overseer::LOG_TARGET;
+use super::{InstallRecord, InstallStage, ModuleInstaller, SignaturePolicy, TrustedPublishers};
 
 impl ModuleInstaller {
     pub(in crate::module) async fn install_from_registry(
@@ -22,16 +25,51 @@ impl ModuleInstaller {
             return Err(color_eyre::eyre::eyre!("Module already exists"));
         }
 
+        // Authenticity check before anything lands in the wasteland
+        let policy = SignaturePolicy::from_env();
+        if policy != SignaturePolicy::Off {
+            match self.download_artifact(manifest).await {
+                Ok(artifact) => {
+                    self.report_progress(&manifest.id, InstallStage::Verifying, 0, None);
+                    Self::verify_signature(manifest, &artifact, policy, &TrustedPublishers::from_env())?;
+                }
+                Err(e) if policy == SignaturePolicy::Require => {
+                    return Err(e.wrap_err("Cannot verify module signature"));
+                }
+                Err(e) => {
+                    log_warn!(target: LOG_TARGET, "⚠️ Installing '{}' unverified, artifact download failed: {}", manifest.id, e);
+                }
+            }
+        }
+
+        self.report_progress(&manifest.id, InstallStage::Extracting, 0, None);
         fs::create_dir_all(&manifest_path)?;
 
-        // Generate config.yml based on registry template
-        self.create_module_config(
-            &manifest_path,
-            &manifest.name,
-            &manifest.module_type,
-            &manifest.template,
-            None,
-        )?;
+        // Generate config.yml based on registry template; a manifest the builder rejects
+        // (an unknown template, say) leaves no half-made module behind
+        let written = self
+            .create_module_config(
+                &manifest_path,
+                &manifest.name,
+                &manifest.module_type,
+                &manifest.template,
+                None,
+                manifest.capabilities.as_ref(),
+            )
+            .and_then(|()| InstallRecord::new(manifest, &self.registry_url).save(&manifest_path));
+        if let Err(e) = written {
+            let _ = fs::remove_dir_all(&manifest_path);
+            return Err(e.wrap_err(format!("Failed to install '{}'", manifest.id)));
+        }
+
+        // Registry configs are generated stubs, so missing bindings are expected at this point
+        self.report_progress(&manifest.id, InstallStage::Validating, 0, None);
+        if let Err(e) = fs::read_to_string(manifest_path.join("config.yml"))
+            .map_err(color_eyre::Report::from)
+            .and_then(|yaml| ConfigValidator::validate(&yaml))
+        {
+            log_warn!(target: LOG_TARGET, "⚠️ '{}' installed with an incomplete config: {}", manifest_name, e);
+        }
 
         Ok(manifest_name)
     }
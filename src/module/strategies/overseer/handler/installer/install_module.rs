@@ -1,5 +1,5 @@
 use crate::module::strategies::overseer::handler::{
-    installer::ModuleInstaller,
+    installer::{LocalRepo, ModuleInstaller},
     InstallSource,
 };
 
@@ -14,6 +14,9 @@ impl ModuleInstaller {
                 self.install_from_registry(&module_id, custom_name).await
             }
             InstallSource::LocalFile(path) => self.install_from_file(&path, custom_name).await,
+            InstallSource::LocalRepo(repo, module_id) => {
+                self.install_from_local_repo(&LocalRepo::new(repo), &module_id, custom_name).await
+            }
             InstallSource::DiscoveredDevice(mac) => Err(color_eyre::eyre::eyre!(
                 "Device modules are auto-generated by DiscoveryManager"
             )),
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use super::ModuleInstaller;
+
+/// Bus topic install progress is published on
+pub const INSTALL_PROGRESS_TOPIC: &str = "install_progress";
+
+/// Steps an install goes through, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallStage {
+    Downloading,
+    Verifying,
+    Extracting,
+    Validating,
+}
+
+impl InstallStage {
+    pub const COUNT: usize = 4;
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InstallStage::Downloading => "Downloading",
+            InstallStage::Verifying => "Verifying",
+            InstallStage::Extracting => "Extracting",
+            InstallStage::Validating => "Validating",
+        }
+    }
+
+    /// 1-based position among the stages
+    pub fn step(&self) -> usize {
+        *self as usize + 1
+    }
+}
+
+/// Where an install currently is; `bytes`/`total` are only meaningful while downloading
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallProgress {
+    pub module_id: String,
+    pub stage: InstallStage,
+    pub bytes: u64,
+    pub total: Option<u64>,
+}
+
+impl InstallProgress {
+    /// Overall completion: each stage is an equal share, the download filling its share by bytes
+    pub fn ratio(&self) -> f64 {
+        let within_stage = match (self.stage, self.total) {
+            (InstallStage::Downloading, Some(total)) if total > 0 => self.bytes as f64 / total as f64,
+            _ => 0.0,
+        };
+        ((self.stage.step() - 1) as f64 + within_stage.clamp(0.0, 1.0)) / InstallStage::COUNT as f64
+    }
+
+    pub fn describe(&self) -> String {
+        let detail = match (self.stage, self.total) {
+            (InstallStage::Downloading, Some(total)) => format!(" {} / {}", format_bytes(self.bytes), format_bytes(total)),
+            (InstallStage::Downloading, None) => format!(" {}", format_bytes(self.bytes)),
+            _ => String::new(),
+        };
+        format!(
+            "⏳ {} {}{} ({}/{})",
+            self.stage.label(),
+            self.module_id,
+            detail,
+            self.stage.step(),
+            InstallStage::COUNT
+        )
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1 << 20 => format!("{:.1} MB", b as f64 / (1 << 20) as f64),
+        b if b >= 1 << 10 => format!("{:.1} KB", b as f64 / (1 << 10) as f64),
+        b => format!("{} B", b),
+    }
+}
+
+impl ModuleInstaller {
+    /// Send a progress update if anyone is listening
+    pub(in crate::module) fn report_progress(&self, module_id: &str, stage: InstallStage, bytes: u64, total: Option<u64>) {
+        if let Some(tx) = &self.progress {
+            let _ = tx.send(InstallProgress { module_id: module_id.to_string(), stage, bytes, total });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ratio_advances_through_stages() {
+        let progress = |stage, bytes, total| InstallProgress { module_id: "pump".to_string(), stage, bytes, total };
+
+        assert_eq!(progress(InstallStage::Downloading, 0, Some(100)).ratio(), 0.0);
+        assert_eq!(progress(InstallStage::Downloading, 50, Some(100)).ratio(), 0.125);
+        assert_eq!(progress(InstallStage::Downloading, 50, None).ratio(), 0.0);
+        assert_eq!(progress(InstallStage::Validating, 0, None).ratio(), 0.75);
+
+        assert_eq!(
+            progress(InstallStage::Downloading, 1536, Some(1 << 20)).describe(),
+            "⏳ Downloading pump 1.5 KB / 1.0 MB (1/4)"
+        );
+    }
+}
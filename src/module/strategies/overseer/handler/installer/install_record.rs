@@ -0,0 +1,100 @@
+use std::{fs, path::Path};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::module::strategies::overseer::handler::RegistryManifest;
+
+/// Sidecar written into a module directory installed from a registry, as `.install.json`.
+/// The config as installed is kept beside it as `.install.base.yml`, the common ancestor
+/// for merging the user's edits into later updates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallRecord {
+    /// Registry `id` the module was installed from
+    pub registry_id: String,
+    pub version: String,
+    pub registry_url: String,
+    pub installed_at: DateTime<Utc>,
+}
+
+impl InstallRecord {
+    const FILE_NAME: &'static str = ".install.json";
+    const BASE_CONFIG: &'static str = ".install.base.yml";
+
+    pub fn new(manifest: &RegistryManifest, registry_url: &str) -> Self {
+        Self {
+            registry_id: manifest.id.clone(),
+            version: manifest.version.clone(),
+            registry_url: registry_url.to_string(),
+            installed_at: Utc::now(),
+        }
+    }
+
+    pub fn load(module_path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(module_path.join(Self::FILE_NAME)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Write the record and snapshot the module's current `config.yml` as its base
+    pub fn save(&self, module_path: &Path) -> color_eyre::Result<()> {
+        fs::write(module_path.join(Self::FILE_NAME), serde_json::to_string_pretty(self)?)?;
+        fs::copy(module_path.join("config.yml"), module_path.join(Self::BASE_CONFIG))?;
+        Ok(())
+    }
+
+    /// The config as it was installed, if a snapshot exists
+    pub fn load_base(module_path: &Path) -> Option<serde_json::Value> {
+        let content = fs::read_to_string(module_path.join(Self::BASE_CONFIG)).ok()?;
+        serde_yaml::from_str(&content).ok()
+    }
+
+    /// The registry entry, if it carries a newer semver version than this install
+    pub fn newer_in<'a>(&self, manifests: &'a [RegistryManifest]) -> Option<&'a RegistryManifest> {
+        let installed = parse_version(&self.version)?;
+        manifests
+            .iter()
+            .find(|m| m.id == self.registry_id)
+            .filter(|m| parse_version(&m.version).is_some_and(|available| available > installed))
+    }
+}
+
+/// Lenient semver parse: a leading `v` is allowed
+fn parse_version(version: &str) -> Option<semver::Version> {
+    let version = version.trim();
+    semver::Version::parse(version.strip_prefix('v').unwrap_or(version)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(version: &str) -> RegistryManifest {
+        RegistryManifest {
+            id: "pressure_monitor".to_string(),
+            name: "Pressure Monitor".to_string(),
+            description: String::new(),
+            version: version.to_string(),
+            author: String::new(),
+            module_type: "monitoring".to_string(),
+            template: "gauge_card".to_string(),
+            download_url: String::new(),
+            checksum: String::new(),
+            signature: None,
+            publisher: None,
+            capabilities: None,
+            files: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_newer_in_compares_semver() {
+        let record = InstallRecord::new(&manifest("1.2.0"), "https://registry.survon.io/v1");
+
+        assert!(record.newer_in(&[manifest("1.10.0")]).is_some());
+        assert!(record.newer_in(&[manifest("v1.2.1")]).is_some());
+        assert!(record.newer_in(&[manifest("1.2.0")]).is_none());
+        assert!(record.newer_in(&[manifest("1.3.0-beta.1")]).is_some());
+        assert!(record.newer_in(&[manifest("1.1.9")]).is_none());
+        assert!(record.newer_in(&[manifest("latest")]).is_none());
+    }
+}
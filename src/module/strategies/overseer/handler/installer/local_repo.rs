@@ -0,0 +1,82 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::{eyre, WrapErr};
+
+use crate::module::strategies::overseer::handler::{RegistryManifest, RegistryResponse};
+
+/// Listing file at the root of a bundle repo, shaped like the registry's `/manifests` response
+pub const LOCAL_REPO_LISTING: &str = "registry.json";
+
+/// A directory of module bundles that stands in for the registry when offline.
+///
+/// Each manifest's `download_url` is the bundle's `config.yml`, relative to the repo root,
+/// `checksum` is that file's sha256, and `files` holds the sha256 of every other file in the bundle.
+#[derive(Debug, Clone)]
+pub struct LocalRepo {
+    pub root: PathBuf,
+}
+
+impl LocalRepo {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The repo a `file://` registry URL points at, if it is one
+    pub fn from_registry_url(url: &str) -> Option<Self> {
+        let path = url.strip_prefix("file://")?;
+        (!path.is_empty()).then(|| Self::new(path))
+    }
+
+    pub fn listing(&self) -> color_eyre::Result<Vec<RegistryManifest>> {
+        let path = self.root.join(LOCAL_REPO_LISTING);
+        let content = fs::read_to_string(&path)
+            .wrap_err_with(|| format!("Failed to read bundle listing {}", path.display()))?;
+        let response: RegistryResponse = serde_json::from_str(&content)
+            .wrap_err_with(|| format!("Invalid bundle listing {}", path.display()))?;
+        Ok(response.modules)
+    }
+
+    /// Where the manifest's bundle config lives; it must stay inside the repo
+    pub fn artifact_path(&self, manifest: &RegistryManifest) -> color_eyre::Result<PathBuf> {
+        let relative = Path::new(manifest.download_url.trim_start_matches("file://"));
+        if manifest.download_url.is_empty() || relative.is_absolute()
+            || relative.components().any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            return Err(eyre!("Module '{}' has an invalid bundle path '{}'", manifest.id, manifest.download_url));
+        }
+        Ok(self.root.join(relative))
+    }
+
+    /// Every file in the manifest's bundle directory, read once, keyed by `/`-separated path within
+    /// the bundle. The install verifies and writes these bytes, never re-reading the repo.
+    pub fn read_bundle(&self, manifest: &RegistryManifest) -> color_eyre::Result<BTreeMap<String, Vec<u8>>> {
+        let config_path = self.artifact_path(manifest)?;
+        let bundle_dir = config_path.parent().unwrap_or(&self.root);
+        let mut files = BTreeMap::new();
+        read_files(bundle_dir, "", &mut files)
+            .wrap_err_with(|| format!("Failed to read bundle {}", bundle_dir.display()))?;
+        Ok(files)
+    }
+}
+
+fn read_files(dir: &Path, prefix: &str, files: &mut BTreeMap<String, Vec<u8>>) -> color_eyre::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let relative = format!("{}{}", prefix, name);
+        let file_type = entry.file_type()?;
+
+        if file_type.is_symlink() {
+            return Err(eyre!("'{}' is a symlink; bundles may only hold regular files", relative));
+        } else if file_type.is_dir() {
+            read_files(&entry.path(), &format!("{}/", relative), files)?;
+        } else {
+            files.insert(relative, fs::read(entry.path())?);
+        }
+    }
+    Ok(())
+}
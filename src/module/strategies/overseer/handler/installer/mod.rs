@@ -4,11 +4,32 @@ mod install_from_file;
 mod list_registry_modules;
 mod create_module_config;
 mod copy_dir_recursive;
+mod download_artifact;
+mod verify_signature;
+mod verify_checksum;
+mod local_repo;
+mod verify_bundle_files;
+mod write_bundle;
+mod install_from_local_repo;
+mod install_progress;
+mod install_record;
+mod update_module;
+mod three_way_merge;
 
 use std::path::{PathBuf};
+use tokio::sync::mpsc;
+
+pub use install_progress::{InstallProgress, InstallStage, INSTALL_PROGRESS_TOPIC};
+pub use install_record::InstallRecord;
+pub use three_way_merge::MergeConflict;
+pub use update_module::StagedUpdate;
+pub use local_repo::{LocalRepo, LOCAL_REPO_LISTING};
+pub use verify_signature::{SignaturePolicy, TrustedPublishers};
 
 pub struct ModuleInstaller {
     pub wasteland_path: PathBuf,
     pub archive_path: PathBuf,
     pub registry_url: String,
+    /// Receives stage and download updates while installing, when set
+    pub progress: Option<mpsc::UnboundedSender<InstallProgress>>,
 }
@@ -0,0 +1,135 @@
+use serde_json::{Map, Value};
+
+/// A field both the user and the update changed, to different values
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeConflict {
+    /// Keys from the config root, e.g. `["bindings", "warn_threshold"]`
+    pub path: Vec<String>,
+    /// The user's value; `None` if they removed the field
+    pub ours: Option<Value>,
+    /// The update's value; `None` if the update removed the field
+    pub theirs: Option<Value>,
+}
+
+impl MergeConflict {
+    pub fn describe(&self, take_theirs: bool) -> String {
+        let show = |v: &Option<Value>| v.as_ref().map_or("(removed)".to_string(), |v| v.to_string());
+        let (ours, theirs) = if take_theirs {
+            (show(&self.ours), format!("[{}]", show(&self.theirs)))
+        } else {
+            (format!("[{}]", show(&self.ours)), show(&self.theirs))
+        };
+        format!("{}: yours {} / update {}", self.path.join("."), ours, theirs)
+    }
+}
+
+/// Merge the user's config (`ours`) with a new registry default (`theirs`), using the
+/// default the user started from (`base`) to tell who changed what. Fields only one side
+/// changed take that side's value; fields both changed differently keep the user's value
+/// and are returned as conflicts.
+pub fn three_way_merge(base: &Value, ours: &Value, theirs: &Value) -> (Value, Vec<MergeConflict>) {
+    let mut conflicts = Vec::new();
+    let merged = merge_field(&mut Vec::new(), Some(base), Some(ours), Some(theirs), &mut conflicts)
+        .unwrap_or(Value::Object(Map::new()));
+    (merged, conflicts)
+}
+
+fn merge_field(
+    path: &mut Vec<String>,
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Option<Value> {
+    if ours == theirs || theirs == base {
+        return ours.cloned();
+    }
+    if ours == base {
+        return theirs.cloned();
+    }
+
+    if let (Some(Value::Object(ours_map)), Some(Value::Object(theirs_map))) = (ours, theirs) {
+        let base_map = base.and_then(|b| b.as_object());
+        let mut keys: Vec<&String> = ours_map.keys().chain(theirs_map.keys()).collect();
+        keys.sort();
+        keys.dedup();
+
+        let mut merged = Map::new();
+        for key in keys {
+            path.push(key.clone());
+            let value = merge_field(
+                path,
+                base_map.and_then(|m| m.get(key)),
+                ours_map.get(key),
+                theirs_map.get(key),
+                conflicts,
+            );
+            path.pop();
+            if let Some(value) = value {
+                merged.insert(key.clone(), value);
+            }
+        }
+        return Some(Value::Object(merged));
+    }
+
+    conflicts.push(MergeConflict {
+        path: path.clone(),
+        ours: ours.cloned(),
+        theirs: theirs.cloned(),
+    });
+    ours.cloned()
+}
+
+/// Settle a conflict in `merged` in favour of the update
+pub fn take_theirs(merged: &mut Value, conflict: &MergeConflict) {
+    let Some((last, parents)) = conflict.path.split_last() else {
+        return;
+    };
+
+    let mut target = merged;
+    for key in parents {
+        let Some(map) = target.as_object_mut() else {
+            return;
+        };
+        target = map.entry(key.clone()).or_insert_with(|| Value::Object(Map::new()));
+    }
+
+    if let Some(map) = target.as_object_mut() {
+        match &conflict.theirs {
+            Some(value) => {
+                map.insert(last.clone(), value.clone());
+            }
+            None => {
+                map.remove(last);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_keeps_user_changes_and_adopts_new_fields() {
+        let base = json!({ "name": "Pressure", "bindings": { "max_value": 100, "unit": "PSI", "warn": 80 } });
+        let ours = json!({ "name": "Well", "bindings": { "max_value": 150, "unit": "PSI", "warn": 90 } });
+        let theirs = json!({ "name": "Pressure", "bindings": { "max_value": 100, "unit": "bar", "warn": 70, "min_value": 0 } });
+
+        let (mut merged, conflicts) = three_way_merge(&base, &ours, &theirs);
+
+        assert_eq!(merged["name"], json!("Well"));
+        assert_eq!(merged["bindings"]["max_value"], json!(150));
+        assert_eq!(merged["bindings"]["unit"], json!("bar"));
+        assert_eq!(merged["bindings"]["min_value"], json!(0));
+
+        // Both sides moved the warning threshold
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, vec!["bindings", "warn"]);
+        assert_eq!(merged["bindings"]["warn"], json!(90));
+
+        take_theirs(&mut merged, &conflicts[0]);
+        assert_eq!(merged["bindings"]["warn"], json!(70));
+    }
+}
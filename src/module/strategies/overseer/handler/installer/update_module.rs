@@ -0,0 +1,122 @@
+use std::{fs, path::PathBuf};
+
+use color_eyre::eyre::eyre;
+use serde_json::Value;
+
+use crate::module::{
+    strategies::overseer::handler::{InstallSource, RegistryManifest},
+    ConfigValidator,
+};
+
+use super::{
+    three_way_merge::{take_theirs, three_way_merge, MergeConflict},
+    InstallRecord,
+    ModuleInstaller,
+};
+
+/// A newer version installed beside the module, waiting for conflicts to be settled
+#[derive(Debug, Clone)]
+pub struct StagedUpdate {
+    pub module_name: String,
+    pub version: String,
+    staging_path: PathBuf,
+    /// User config merged with the update, conflicting fields still holding the user's value
+    merged: Value,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// Where updates are installed and old versions set aside while swapping, under the wasteland
+/// so renames stay on one filesystem but out of sight of module discovery
+const STAGING_DIR: &str = ".staging";
+
+impl ModuleInstaller {
+    /// Install the manifest's version beside `module_name` and three-way merge the user's config
+    /// into it, using the config the module was installed with as the common base.
+    pub(in crate::module) async fn stage_update(
+        &self,
+        module_name: &str,
+        manifest: &RegistryManifest,
+        source: InstallSource,
+    ) -> color_eyre::Result<StagedUpdate> {
+        let module_path = self.wasteland_path.join(module_name);
+        let staging = ModuleInstaller {
+            wasteland_path: self.wasteland_path.join(STAGING_DIR),
+            archive_path: self.archive_path.clone(),
+            registry_url: self.registry_url.clone(),
+            progress: self.progress.clone(),
+        };
+        let staging_path = staging.wasteland_path.join(module_name);
+
+        let ours: Value = serde_yaml::from_str(&fs::read_to_string(module_path.join("config.yml"))?)?;
+        // Without a snapshot every differing field counts as changed on both sides
+        let base = InstallRecord::load_base(&module_path).unwrap_or(Value::Object(Default::default()));
+
+        fs::create_dir_all(&staging.wasteland_path)?;
+        let _ = fs::remove_dir_all(&staging_path);
+        let installed = async {
+            staging.install_module(source, Some(module_name.to_string())).await?;
+            let theirs: Value = serde_yaml::from_str(&fs::read_to_string(staging_path.join("config.yml"))?)?;
+            Ok::<_, color_eyre::Report>(theirs)
+        }.await;
+        let theirs = match installed {
+            Ok(theirs) => theirs,
+            Err(e) => {
+                let _ = fs::remove_dir_all(&staging_path);
+                return Err(e);
+            }
+        };
+
+        let (merged, conflicts) = three_way_merge(&base, &ours, &theirs);
+
+        Ok(StagedUpdate {
+            module_name: module_name.to_string(),
+            version: manifest.version.clone(),
+            staging_path,
+            merged,
+            conflicts,
+        })
+    }
+
+    /// Apply the conflict choices (`true` takes the update's value), validate, and swap the
+    /// staged version in. The old directory is restored if the swap fails, and the staged one
+    /// is removed whenever the update doesn't go through.
+    pub(in crate::module) fn finish_update(&self, mut staged: StagedUpdate, take_update: &[bool]) -> color_eyre::Result<String> {
+        for (conflict, &theirs) in staged.conflicts.iter().zip(take_update) {
+            if theirs {
+                take_theirs(&mut staged.merged, conflict);
+            }
+        }
+
+        let result = self.swap_in_update(&staged);
+        if result.is_err() {
+            Self::discard_update(&staged);
+        }
+        result.map(|()| staged.module_name)
+    }
+
+    fn swap_in_update(&self, staged: &StagedUpdate) -> color_eyre::Result<()> {
+        let merged_yaml = serde_yaml::to_string(&staged.merged)?;
+        ConfigValidator::validate(&merged_yaml).map_err(|e| e.wrap_err("Merged config is invalid"))?;
+        fs::write(staged.staging_path.join("config.yml"), merged_yaml)?;
+
+        let module_path = self.wasteland_path.join(&staged.module_name);
+        let previous_path = self.wasteland_path.join(STAGING_DIR).join(format!("{}.previous", staged.module_name));
+
+        let _ = fs::remove_dir_all(&previous_path);
+        fs::rename(&module_path, &previous_path)?;
+        if let Err(e) = fs::rename(&staged.staging_path, &module_path) {
+            fs::rename(&previous_path, &module_path).map_err(|restore| eyre!(
+                "Failed to replace '{}' ({}), and the old version is left at {}: {}",
+                staged.module_name, e, previous_path.display(), restore
+            ))?;
+            return Err(eyre!("Failed to replace '{}': {}", staged.module_name, e));
+        }
+        let _ = fs::remove_dir_all(&previous_path);
+
+        Ok(())
+    }
+
+    pub(in crate::module) fn discard_update(staged: &StagedUpdate) {
+        let _ = fs::remove_dir_all(&staged.staging_path);
+    }
+}
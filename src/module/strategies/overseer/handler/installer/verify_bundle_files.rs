@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+
+use color_eyre::eyre::eyre;
+use sha2::{Digest, Sha256};
+
+use crate::module::strategies::overseer::handler::RegistryManifest;
+
+use super::ModuleInstaller;
+
+impl ModuleInstaller {
+    /// Check every bundle file besides `config_name` against the manifest's `files` digests.
+    /// A file the manifest doesn't list, or a listed file the bundle lacks, fails the check.
+    pub(in crate::module) fn verify_bundle_files(
+        manifest: &RegistryManifest,
+        files: &BTreeMap<String, Vec<u8>>,
+        config_name: &str,
+    ) -> color_eyre::Result<()> {
+        for (path, bytes) in files.iter().filter(|(path, _)| path.as_str() != config_name) {
+            let expected = manifest.files.get(path)
+                .ok_or_else(|| eyre!("Bundle '{}' holds '{}', which its manifest doesn't list", manifest.id, path))?;
+            let expected = expected.trim();
+            let expected = expected.strip_prefix("sha256:").unwrap_or(expected);
+
+            let actual: String = Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect();
+            if !actual.eq_ignore_ascii_case(expected) {
+                return Err(eyre!(
+                    "Checksum mismatch for '{}' in '{}': expected {}, got {}",
+                    path, manifest.id, expected, actual
+                ));
+            }
+        }
+
+        if let Some(missing) = manifest.files.keys().find(|path| !files.contains_key(*path)) {
+            return Err(eyre!("Bundle '{}' is missing '{}'", manifest.id, missing));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_bundle_file_must_match_the_manifest() {
+        // sha256("abc")
+        let digest = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        let mut manifest: RegistryManifest = serde_json::from_value(serde_json::json!({
+            "id": "pressure_monitor", "name": "Pressure Monitor", "description": "", "version": "1.0.0",
+            "author": "", "module_type": "monitoring", "template": "gauge_card",
+            "download_url": "pressure_monitor/config.yml", "checksum": "",
+            "files": { "assets/icon.png": digest },
+        })).unwrap();
+
+        let mut files = BTreeMap::from([
+            ("config.yml".to_string(), b"name: Pressure Monitor".to_vec()),
+            ("assets/icon.png".to_string(), b"abc".to_vec()),
+        ]);
+        assert!(ModuleInstaller::verify_bundle_files(&manifest, &files, "config.yml").is_ok());
+
+        files.insert("assets/icon.png".to_string(), b"abd".to_vec());
+        assert!(ModuleInstaller::verify_bundle_files(&manifest, &files, "config.yml").is_err());
+
+        files.insert("assets/icon.png".to_string(), b"abc".to_vec());
+        files.insert("run.sh".to_string(), b"rm -rf /".to_vec());
+        assert!(ModuleInstaller::verify_bundle_files(&manifest, &files, "config.yml").is_err());
+
+        files.remove("run.sh");
+        manifest.files.insert("assets/sound.wav".to_string(), digest.to_string());
+        assert!(ModuleInstaller::verify_bundle_files(&manifest, &files, "config.yml").is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use color_eyre::eyre::eyre;
+use sha2::{Digest, Sha256};
+
+use crate::module::strategies::overseer::handler::RegistryManifest;
+
+use super::ModuleInstaller;
+
+impl ModuleInstaller {
+    /// Check `artifact` against the manifest's sha256 `checksum` (hex, optionally `sha256:`-prefixed)
+    pub(in crate::module) fn verify_checksum(manifest: &RegistryManifest, artifact: &[u8]) -> color_eyre::Result<()> {
+        let expected = manifest.checksum.trim();
+        let expected = expected.strip_prefix("sha256:").unwrap_or(expected);
+        if expected.is_empty() {
+            return Err(eyre!("Module '{}' has no checksum", manifest.id));
+        }
+
+        let actual: String = Sha256::digest(artifact).iter().map(|b| format!("{:02x}", b)).collect();
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(eyre!(
+                "Checksum mismatch for '{}': expected {}, got {}",
+                manifest.id, expected, actual
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(checksum: &str) -> RegistryManifest {
+        RegistryManifest {
+            id: "pressure_monitor".to_string(),
+            name: "Pressure Monitor".to_string(),
+            description: String::new(),
+            version: "1.0.0".to_string(),
+            author: String::new(),
+            module_type: "monitoring".to_string(),
+            template: "gauge_card".to_string(),
+            download_url: "pressure_monitor/config.yml".to_string(),
+            checksum: checksum.to_string(),
+            signature: None,
+            publisher: None,
+            capabilities: None,
+            files: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_verify_checksum() {
+        // sha256("abc")
+        let digest = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+        assert!(ModuleInstaller::verify_checksum(&manifest(digest), b"abc").is_ok());
+        assert!(ModuleInstaller::verify_checksum(&manifest(&format!("sha256:{}", digest.to_uppercase())), b"abc").is_ok());
+        assert!(ModuleInstaller::verify_checksum(&manifest(digest), b"abd").is_err());
+        assert!(ModuleInstaller::verify_checksum(&manifest(""), b"abc").is_err());
+    }
+}
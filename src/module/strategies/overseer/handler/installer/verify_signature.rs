@@ -0,0 +1,199 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use color_eyre::eyre::eyre;
+use ed25519_dalek::{Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+use crate::{
+    log_warn,
+    module::strategies::overseer::handler::RegistryManifest,
+};
+use crate::module::strategies::overseer::LOG_TARGET;
+
+use super::ModuleInstaller;
+
+/// What to do with registry modules whose signature is missing or wrong,
+/// from `REGISTRY_SIGNATURE_POLICY` (`off`, `warn` - the default - or `require`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignaturePolicy {
+    Off,
+    Warn,
+    Require,
+}
+
+impl SignaturePolicy {
+    pub fn from_env() -> Self {
+        match std::env::var("REGISTRY_SIGNATURE_POLICY").unwrap_or_default().to_lowercase().as_str() {
+            "off" => SignaturePolicy::Off,
+            "require" => SignaturePolicy::Require,
+            _ => SignaturePolicy::Warn,
+        }
+    }
+}
+
+/// Publisher name -> ed25519 public key, from `REGISTRY_TRUSTED_KEYS`
+/// (`publisher=base64key,other=base64key`)
+#[derive(Debug, Clone, Default)]
+pub struct TrustedPublishers(HashMap<String, VerifyingKey>);
+
+impl TrustedPublishers {
+    pub fn from_env() -> Self {
+        Self::parse(&std::env::var("REGISTRY_TRUSTED_KEYS").unwrap_or_default())
+    }
+
+    pub fn parse(spec: &str) -> Self {
+        let mut keys = HashMap::new();
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let Some((publisher, key)) = entry.split_once('=') else {
+                log_warn!(target: LOG_TARGET, "Ignoring malformed trusted key entry: {}", entry);
+                continue;
+            };
+
+            let parsed = STANDARD.decode(key.trim()).ok()
+                .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                .and_then(|bytes| VerifyingKey::from_bytes(&bytes).ok());
+
+            match parsed {
+                Some(key) => { keys.insert(publisher.trim().to_string(), key); }
+                None => log_warn!(target: LOG_TARGET, "Ignoring invalid public key for publisher '{}'", publisher.trim()),
+            }
+        }
+
+        Self(keys)
+    }
+
+    pub fn get(&self, publisher: &str) -> Option<&VerifyingKey> {
+        self.0.get(publisher)
+    }
+}
+
+impl ModuleInstaller {
+    /// The bytes a publisher signs: every manifest field the install writes to disk, plus the
+    /// artifact's sha256 and a bundle's other file digests, one `key=value` per line. Editing any of them breaks the signature.
+    pub(in crate::module) fn signed_payload(manifest: &RegistryManifest, artifact: &[u8]) -> color_eyre::Result<Vec<u8>> {
+        let capabilities = match &manifest.capabilities {
+            Some(capabilities) => serde_json::to_string(capabilities)?,
+            None => String::new(),
+        };
+        let files = if manifest.files.is_empty() {
+            String::new()
+        } else {
+            serde_json::to_string(&manifest.files)?
+        };
+        let digest: String = Sha256::digest(artifact).iter().map(|b| format!("{:02x}", b)).collect();
+
+        let fields: [(&str, &str); 8] = [
+            ("id", manifest.id.as_str()),
+            ("name", &manifest.name),
+            ("version", &manifest.version),
+            ("module_type", &manifest.module_type),
+            ("template", &manifest.template),
+            ("capabilities", &capabilities),
+            ("artifact_sha256", &digest),
+            ("files", &files),
+        ];
+        if let Some((field, _)) = fields.iter().find(|(_, value)| value.contains('\n')) {
+            return Err(eyre!("Manifest field '{}' of '{}' contains a newline", field, manifest.id));
+        }
+
+        let mut payload = String::from("survon-module-signature-v1\n");
+        for (field, value) in fields {
+            payload.push_str(&format!("{}={}\n", field, value));
+        }
+        Ok(payload.into_bytes())
+    }
+
+    /// Check the manifest and `artifact` against the manifest's signature and publisher key.
+    /// Under `Warn` problems are logged and the install proceeds.
+    pub(in crate::module) fn verify_signature(
+        manifest: &RegistryManifest,
+        artifact: &[u8],
+        policy: SignaturePolicy,
+        publishers: &TrustedPublishers,
+    ) -> color_eyre::Result<()> {
+        if policy == SignaturePolicy::Off {
+            return Ok(());
+        }
+
+        match Self::check_signature(manifest, artifact, publishers) {
+            Ok(()) => Ok(()),
+            Err(reason) if policy == SignaturePolicy::Warn => {
+                log_warn!(target: LOG_TARGET, "⚠️ Installing '{}' despite signature problem: {}", manifest.id, reason);
+                Ok(())
+            }
+            Err(reason) => Err(eyre!("Refusing to install '{}': {}", manifest.id, reason)),
+        }
+    }
+
+    fn check_signature(
+        manifest: &RegistryManifest,
+        artifact: &[u8],
+        publishers: &TrustedPublishers,
+    ) -> Result<(), String> {
+        let signature = manifest.signature.as_deref().ok_or("module is unsigned")?;
+        let publisher = manifest.publisher.as_deref().ok_or("module names no publisher")?;
+        let key = publishers.get(publisher)
+            .ok_or_else(|| format!("publisher '{}' is not trusted", publisher))?;
+
+        let signature_bytes: [u8; 64] = STANDARD.decode(signature.trim())
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or("signature is not valid base64 ed25519")?;
+
+        let payload = Self::signed_payload(manifest, artifact).map_err(|e| e.to_string())?;
+        key.verify_strict(&payload, &Signature::from_bytes(&signature_bytes))
+            .map_err(|_| format!("signature does not match publisher '{}'", publisher))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn manifest(signature: Option<String>, publisher: Option<&str>) -> RegistryManifest {
+        RegistryManifest {
+            id: "gate_controller".to_string(),
+            name: "Gate Controller".to_string(),
+            description: String::new(),
+            version: "2.0.0".to_string(),
+            author: "Survon Core".to_string(),
+            module_type: "com".to_string(),
+            template: "toggle_switch".to_string(),
+            download_url: String::new(),
+            checksum: String::new(),
+            signature,
+            publisher: publisher.map(str::to_string),
+            capabilities: None,
+            files: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_require_policy_checks_signature() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let public_key = STANDARD.encode(signing_key.verifying_key().to_bytes());
+        let publishers = TrustedPublishers::parse(&format!("survon-core={}", public_key));
+
+        let artifact = b"name: Gate Controller";
+        let payload = ModuleInstaller::signed_payload(&manifest(None, None), artifact).unwrap();
+        let signature = STANDARD.encode(signing_key.sign(&payload).to_bytes());
+
+        let signed = manifest(Some(signature), Some("survon-core"));
+        assert!(ModuleInstaller::verify_signature(&signed, artifact, SignaturePolicy::Require, &publishers).is_ok());
+        assert!(ModuleInstaller::verify_signature(&signed, b"tampered", SignaturePolicy::Require, &publishers).is_err());
+
+        // Fields written into config.yml are covered too, not just the artifact
+        let mut retyped = signed.clone();
+        retyped.module_type = "llm".to_string();
+        assert!(ModuleInstaller::verify_signature(&retyped, artifact, SignaturePolicy::Require, &publishers).is_err());
+        let mut widened = signed.clone();
+        widened.capabilities = Some(crate::module::ModuleCapabilities { network: true, ..Default::default() });
+        assert!(ModuleInstaller::verify_signature(&widened, artifact, SignaturePolicy::Require, &publishers).is_err());
+
+        let unsigned = manifest(None, Some("survon-core"));
+        assert!(ModuleInstaller::verify_signature(&unsigned, artifact, SignaturePolicy::Require, &publishers).is_err());
+        assert!(ModuleInstaller::verify_signature(&unsigned, artifact, SignaturePolicy::Warn, &publishers).is_ok());
+    }
+}
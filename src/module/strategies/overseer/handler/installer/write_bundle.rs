@@ -0,0 +1,35 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use color_eyre::eyre::eyre;
+
+use super::ModuleInstaller;
+
+impl ModuleInstaller {
+    /// Write already-verified bundle files into a new wasteland module. Nothing is left
+    /// behind if a write fails part way.
+    pub(in crate::module) fn write_bundle(
+        &self,
+        module_name: &str,
+        files: &BTreeMap<String, Vec<u8>>,
+    ) -> color_eyre::Result<()> {
+        let module_path = self.wasteland_path.join(module_name);
+        if module_path.exists() {
+            return Err(eyre!("Module already exists"));
+        }
+
+        let written = files.iter().try_for_each(|(relative, bytes)| {
+            let path = module_path.join(relative);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, bytes)
+        });
+
+        if let Err(e) = written {
+            let _ = fs::remove_dir_all(&module_path);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+}
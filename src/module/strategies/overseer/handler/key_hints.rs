@@ -0,0 +1,108 @@
+use crossterm::event::KeyCode;
+
+use crate::module::strategies::overseer::handler::{OverseerHandler, WastelandView};
+
+impl OverseerHandler {
+    /// Keys usable in the current view, shown as the card's footer and in the `?` overlay
+    pub(in crate::module) fn _key_hints(&self) -> Vec<(KeyCode, &'static str)> {
+        match self.current_view {
+            WastelandView::Main => vec![
+                (KeyCode::Up, "Select"),
+                (KeyCode::Down, "Select"),
+                (KeyCode::Enter, "Open"),
+                (KeyCode::Char('s'), "Scan"),
+                (KeyCode::Char('r'), "Refresh"),
+                (KeyCode::Esc, "Back"),
+            ],
+            WastelandView::PendingTrust => vec![
+                (KeyCode::Up, "Select"),
+                (KeyCode::Down, "Select"),
+                (KeyCode::Enter, "Trust"),
+                (KeyCode::Char('i'), "Ignore"),
+                (KeyCode::Char('s'), "Scan"),
+                (KeyCode::Char('v'), "View All"),
+                (KeyCode::Esc, "Back"),
+            ],
+            WastelandView::AllDevices if self.device_edit.is_some() => vec![
+                (KeyCode::Tab, "Next Field"),
+                (KeyCode::Enter, "Save"),
+                (KeyCode::Esc, "Cancel"),
+            ],
+            WastelandView::AllDevices if self.pending_forget.is_some() => vec![
+                (KeyCode::Char('y'), "Forget"),
+                (KeyCode::Char('n'), "Cancel"),
+            ],
+            WastelandView::AllDevices => vec![
+                (KeyCode::Char('t'), "Trust"),
+                (KeyCode::Char('e'), "Edit"),
+                (KeyCode::Char('c'), "Reconnect"),
+                (KeyCode::Char('d'), "Delete"),
+                (KeyCode::Char('f'), "Forget Stale"),
+                (KeyCode::Char('s'), "Scan"),
+                (KeyCode::Char('p'), "Pending"),
+                (KeyCode::PageUp, "Page"),
+                (KeyCode::PageDown, "Page"),
+                (KeyCode::Esc, "Back"),
+            ],
+            WastelandView::InstallRegistry if self.pending_install.is_some() => vec![
+                (KeyCode::Char('y'), "Approve & Install"),
+                (KeyCode::Char('n'), "Cancel"),
+            ],
+            WastelandView::InstallRegistry => vec![
+                (KeyCode::Up, "Select"),
+                (KeyCode::Down, "Select"),
+                (KeyCode::Enter, "Install"),
+                (KeyCode::Tab, "Switch Registry"),
+                (KeyCode::Esc, "Back"),
+            ],
+            WastelandView::ManageModules if self.update_review.is_some() => vec![
+                (KeyCode::Up, "Select"),
+                (KeyCode::Down, "Select"),
+                (KeyCode::Tab, "Mine/Update"),
+                (KeyCode::Enter, "Apply"),
+                (KeyCode::Esc, "Cancel"),
+            ],
+            WastelandView::ManageModules => vec![
+                (KeyCode::Up, "Select"),
+                (KeyCode::Down, "Select"),
+                (KeyCode::Enter, "Edit"),
+                (KeyCode::Char('d'), "Duplicate"),
+                (KeyCode::Char('a'), "Archive"),
+                (KeyCode::Char('U'), "Update"),
+                (KeyCode::Esc, "Back"),
+            ],
+            WastelandView::EditConfig if self.pending_changes.is_some() => vec![
+                (KeyCode::Enter, "Write changes"),
+                (KeyCode::Esc, "Keep editing"),
+            ],
+            WastelandView::EditConfig | WastelandView::CreateNewModule => match &self.config_editor {
+                Some(editor) if editor.is_editing => vec![
+                    (KeyCode::Enter, "Save"),
+                    (KeyCode::Esc, "Cancel"),
+                ],
+                _ => vec![
+                    (KeyCode::Up, "Select"),
+                    (KeyCode::Down, "Select"),
+                    (KeyCode::Enter, "Edit"),
+                    (KeyCode::Left, "Toggle"),
+                    (KeyCode::Right, "Toggle"),
+                    (KeyCode::Char('s'), "Save"),
+                    (KeyCode::Esc, "Close"),
+                ],
+            },
+            WastelandView::ArchivedModules if self.archive_filter.is_empty() => vec![
+                (KeyCode::Up, "Select"),
+                (KeyCode::Down, "Select"),
+                (KeyCode::Enter, "Restore"),
+                (KeyCode::Esc, "Back"),
+            ],
+            WastelandView::ArchivedModules => vec![
+                (KeyCode::Up, "Select"),
+                (KeyCode::Down, "Select"),
+                (KeyCode::Enter, "Restore"),
+                (KeyCode::Backspace, "Edit filter"),
+                (KeyCode::Esc, "Clear filter"),
+            ],
+        }
+    }
+}
@@ -1,19 +1,34 @@
 use std::fs;
 
-use super::OverseerHandler;
+use super::{archive_metadata::{ArchiveMetadata, ArchivedModule}, OverseerHandler};
 
 impl OverseerHandler {
-    pub(in crate::module) fn list_archived_modules(&self) -> color_eyre::Result<Vec<String>> {
+    /// Archived modules, most recently archived first
+    pub(in crate::module) fn list_archived_modules(&self) -> color_eyre::Result<Vec<ArchivedModule>> {
         if !self.archive_path.exists() {
             return Ok(Vec::new());
         }
 
-        let entries = fs::read_dir(&self.archive_path)?
+        let mut entries: Vec<ArchivedModule> = fs::read_dir(&self.archive_path)?
             .filter_map(|e| e.ok())
             .filter(|e| e.path().is_dir())
-            .map(|e| e.file_name().to_string_lossy().to_string())
+            .map(|e| {
+                let archive_name = e.file_name().to_string_lossy().to_string();
+                let metadata = ArchiveMetadata::load(&self.archive_path, &archive_name);
+                ArchivedModule { archive_name, metadata }
+            })
             .collect();
 
+        entries.sort_by(|a, b| b.metadata.archived_at.cmp(&a.metadata.archived_at));
+
         Ok(entries)
     }
+
+    /// Archived modules matching the current filter, in display order
+    pub(in crate::module) fn filtered_archived_modules(&self) -> Vec<&ArchivedModule> {
+        self.archived_modules
+            .iter()
+            .filter(|m| m.matches(&self.archive_filter))
+            .collect()
+    }
 }
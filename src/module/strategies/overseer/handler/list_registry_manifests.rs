@@ -1,3 +1,4 @@
+use crate::module::ModuleCapabilities;
 use super::{RegistryManifest, OverseerHandler};
 
 impl OverseerHandler {
@@ -19,6 +20,10 @@ impl OverseerHandler {
                 template: "gauge_card".to_string(),
                 download_url: format!("{}/manifests/pressure_monitor/download", self.registry_url),
                 checksum: "abc123".to_string(),
+                signature: None,
+                publisher: None,
+                capabilities: None,
+                files: Default::default(),
             },
             RegistryManifest {
                 id: "temperature_sensor".to_string(),
@@ -30,6 +35,10 @@ impl OverseerHandler {
                 template: "gauge_card".to_string(),
                 download_url: format!("{}/manifests/temperature_sensor/download", self.registry_url),
                 checksum: "def456".to_string(),
+                signature: None,
+                publisher: None,
+                capabilities: None,
+                files: Default::default(),
             },
             RegistryManifest {
                 id: "gate_controller".to_string(),
@@ -41,6 +50,14 @@ impl OverseerHandler {
                 template: "toggle_switch".to_string(),
                 download_url: format!("{}/manifests/gate_controller/download", self.registry_url),
                 checksum: "ghi789".to_string(),
+                signature: None,
+                publisher: None,
+                capabilities: Some(ModuleCapabilities {
+                    gpio: true,
+                    publish_topics: vec!["com_input".to_string()],
+                    ..Default::default()
+                }),
+                files: Default::default(),
             },
         ])
     }
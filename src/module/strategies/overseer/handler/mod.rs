@@ -2,17 +2,23 @@
 mod installer;
 mod list_registry_manifests;
 mod fetch_registry_manifests;
+mod registry_cache;
 mod handle_key;
+mod key_hints;
 mod update_bindings;
 mod trait_module_handler;
 mod update_module_config;
 mod archive_module;
 mod list_archived_modules;
+mod archive_metadata;
+mod purge_expired_archives;
+mod apply_undo;
 mod restore_module;
 mod start_device_listener;
 mod process_messages;
 mod handle_scan_devices;
 mod refresh_data;
+mod switch_registry;
 mod refresh_known_devices;
 mod handle_main_menu_select;
 mod refresh_installed_modules;
@@ -20,12 +26,27 @@ mod handle_trust_device;
 mod handle_ignore_device;
 mod handle_toggle_trust;
 mod handle_delete_device;
+mod handle_reconnect_device;
+mod device_edit;
+mod handle_edit_device;
+mod handle_save_device_edit;
+mod handle_forget_untrusted;
 mod handle_install_module;
+mod probe_installed_module;
+mod check_module_updates;
+mod handle_update_module;
+mod update_review;
+mod apply_update;
+mod publish_install_progress;
+mod review_install;
 mod handle_archive_module;
 mod handle_restore_module;
 mod handle_manage_modules_enter;
 mod get_config_editor;
 mod handle_config_editor_save;
+mod review_config_save;
+mod handle_create_module;
+mod handle_duplicate_module;
 mod trigger_module_refresh;
 mod new;
 
@@ -37,9 +58,11 @@ use ratatui::{
 };
 use std::{
     any::Any,
+    collections::{BTreeMap, HashMap},
     fs,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Instant,
 };
 use tokio::sync::mpsc;
 use serde::{Deserialize, Serialize};
@@ -47,12 +70,13 @@ use serde::{Deserialize, Serialize};
 use crate::{log_debug, log_error, log_info};
 use crate::module::{
     trait_module_handler::ModuleHandler,
-    ConfigValidator, Module,
+    ConfigValidator, LastError, Module, ModuleCapabilities,
 };
 use crate::module::strategies::overseer::{
-    config_editor::{ConfigEditor, EditorAction, FieldValue},
+    config_editor::{ConfigChange, ConfigEditor, EditorAction, FieldValue},
     database::{KnownDevice, OverseerDatabase},
     handler::installer::*,
+    registry_endpoint::RegistryEndpoint,
 };
 use crate::util::{
     database::Database,
@@ -71,12 +95,25 @@ pub struct RegistryManifest {
     pub template: String,
     pub download_url: String,
     pub checksum: String,
+    /// Base64 ed25519 signature over the fields written to disk plus the artifact's digest
+    #[serde(default)]
+    pub signature: Option<String>,
+    /// Key into the trusted publisher set used to check `signature`
+    #[serde(default)]
+    pub publisher: Option<String>,
+    /// Declared sandbox capabilities, shown for approval before install
+    #[serde(default)]
+    pub capabilities: Option<ModuleCapabilities>,
+    /// Offline bundles only: hex sha256 of every bundle file besides `config.yml`, by path within the bundle
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub files: BTreeMap<String, String>,
 }
 
 /// Registry API response
 #[derive(Debug, Deserialize)]
 pub struct RegistryResponse {
     pub modules: Vec<RegistryManifest>,
+    #[serde(default)]
     pub total: usize,
 }
 
@@ -85,28 +122,54 @@ pub struct RegistryResponse {
 pub enum InstallSource {
     Registry(String),         // module_id from registry
     LocalFile(PathBuf),       // Path to config.yml
+    LocalRepo(PathBuf, String), // Bundle repo directory, module_id from its listing
     DiscoveredDevice(String), // MAC address of BLE device
 }
 
+/// Known devices loaded per page in the All Devices view
+const DEVICE_PAGE_SIZE: usize = 50;
+/// Registry key of this handler, for tasks that report failures under it
+const HANDLER_KEY: &str = "overseer";
+
 #[derive(Debug, Clone)]
 enum HandlerMessage {
     DevicesRefreshed(Vec<(String, String, i16)>),
     TrustedDevicesRefreshed(Vec<(String, String)>),
-    KnownDevicesRefreshed(Vec<KnownDevice>),
-    RegistryRefreshed(Vec<RegistryManifest>),
+    KnownDevicesRefreshed {
+        requested_page: usize,
+        page: usize,
+        total: usize,
+        devices: Vec<KnownDevice>,
+    },
+    RegistryRefreshed(registry_cache::RegistryFetch),
+    RegistryFetchFailed(String),
     DeviceTrusted(String), // mac address
+    DeviceReconnected(String), // device name
     DeviceDiscovered {
         mac: String,
         name: String,
         rssi: i16,
     },
     ModuleInstalled(String),
+    UpdateStaged(installer::StagedUpdate),
+    InstallProgress(installer::InstallProgress),
+    InstallFailed(String),
     OperationInProgress(String), // status message
     ScanProgress(u8),            // countdown in seconds
     ScanComplete(usize),
     ScanFailed(String),
 }
 
+/// Overseer operations recorded for Ctrl+Z / Ctrl+Y
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum UndoOp {
+    SetTrust { mac_address: String, trusted: bool },
+    // The whole row, so undo can put it back
+    DeleteDevice { device: KnownDevice },
+    Archive { module_name: String, archive_name: String },
+}
+
 #[derive(Debug, Clone, PartialEq)]
 enum WastelandView {
     Main,
@@ -125,19 +188,42 @@ pub struct OverseerHandler {
     selected_index: usize,
     wasteland_path: PathBuf,
     archive_path: PathBuf,
+    // URL of the active entry in `registries`
     registry_url: String,
+    registries: Vec<RegistryEndpoint>,
+    active_registry: usize,
     discovery_manager: Option<Arc<DiscoveryManager>>,
     database: Database,
     message_bus: MessageBus,
     message_tx: mpsc::UnboundedSender<HandlerMessage>,
     message_rx: mpsc::UnboundedReceiver<HandlerMessage>,
     pending_devices: Vec<(String, String, i16)>, // (mac, name, rssi)
+    // Only the current page of known devices is held in memory
     known_devices: Vec<KnownDevice>,
+    known_device_total: usize,
+    device_page: usize,
     registry_manifests: Vec<RegistryManifest>,
+    // Registry entry whose capabilities are awaiting approval
+    pending_install: Option<usize>,
+    // Latest update from the install running in the background
+    install_progress: Option<installer::InstallProgress>,
+    // Module update whose merge conflicts are awaiting a decision
+    update_review: Option<update_review::UpdateReview>,
     installed_modules: Vec<String>,
-    archived_modules: Vec<String>,
-    status_message: Option<String>,
+    // Installed module -> (installed version, newer registry version)
+    module_updates: HashMap<String, (String, String)>,
+    archived_modules: Vec<archive_metadata::ArchivedModule>,
+    archive_filter: String,
     is_scanning: bool,
     scan_countdown: u8,
+    // Last time discovered devices were fetched, for the periodic refresh
+    devices_refreshed_at: Instant,
     config_editor: Option<ConfigEditor>,
+    // Diff awaiting Enter/Esc before the editor's save is written
+    pending_changes: Option<Vec<ConfigChange>>,
+    // Known device being renamed in the All Devices view
+    device_edit: Option<device_edit::DeviceEdit>,
+    // Stale untrusted devices awaiting confirmation before bulk removal
+    pending_forget: Option<Vec<String>>,
+    last_error: LastError,
 }
@@ -1,5 +1,7 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::mpsc;
 
 use crate::util::{
@@ -9,7 +11,10 @@ use crate::util::{
         discovery::DiscoveryManager,
     }
 };
-use crate::module::strategies::overseer::database::OverseerDatabase;
+use crate::module::{
+    strategies::overseer::registry_endpoint::{RegistryEndpoint, DEFAULT_REGISTRY_URL},
+    LastError,
+};
 
 use super::{OverseerHandler, WastelandView};
 
@@ -23,14 +28,14 @@ impl OverseerHandler {
         let (message_tx, message_rx) = mpsc::unbounded_channel();
         let archive_path = wasteland_path.join(".archive");
 
-        let database_clone = database.clone();
-
         let mut handler = Self {
             current_view: WastelandView::Main,
             selected_index: 0,
             wasteland_path,
             archive_path,
-            registry_url: "https://registry.survon.io/v1".to_string(),
+            registry_url: DEFAULT_REGISTRY_URL.to_string(),
+            registries: vec![RegistryEndpoint::default_endpoint()],
+            active_registry: 0,
             discovery_manager,
             database,
             message_bus: message_bus.clone(),
@@ -38,21 +43,30 @@ impl OverseerHandler {
             message_rx,
             pending_devices: Vec::new(),
             known_devices: Vec::new(),
+            known_device_total: 0,
+            device_page: 0,
             registry_manifests: Vec::new(),
+            pending_install: None,
+            install_progress: None,
+            update_review: None,
             installed_modules: Vec::new(),
+            module_updates: HashMap::new(),
             archived_modules: Vec::new(),
-            status_message: None,
+            archive_filter: String::new(),
             is_scanning: false,
             scan_countdown: 0,
+            devices_refreshed_at: Instant::now(),
             config_editor: None,
+            pending_changes: None,
+            device_edit: None,
+            pending_forget: None,
+            last_error: LastError::default(),
         };
 
         // Start listening for device discovery events
         handler.start_device_listener();
 
-        if let Ok(devices) = database_clone.get_all_known_devices() {
-            handler.known_devices = devices;
-        }
+        let _ = handler.load_known_devices_page();
 
         // Do initial async refresh
         handler.refresh_installed_modules();
@@ -64,8 +78,6 @@ impl OverseerHandler {
         // Now do async refresh for things that need network/async ops
         handler.refresh_async_data_only();
 
-        handler.status_message = Some("Ready - Press '[s]' to scan for devices".to_string());
-
         handler
     }
 }
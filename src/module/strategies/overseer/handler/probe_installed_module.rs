@@ -0,0 +1,107 @@
+use std::fs;
+
+use crate::log_warn;
+use crate::module::{strategies::overseer::database::{KnownDevice, OverseerDatabase}, ConfigValidator};
+use crate::ui::template::get_template;
+use crate::runtime::toast::ToastLevel;
+use crate::module::strategies::overseer::LOG_TARGET;
+
+use super::OverseerHandler;
+
+impl OverseerHandler {
+    /// Check a freshly installed module for things that would leave it doing nothing and
+    /// summarise them as a toast. Never fails the install.
+    pub(in crate::module) fn probe_installed_module(&self, module_name: &str) -> (ToastLevel, String) {
+        let config_path = self.wasteland_path.join(module_name).join("config.yml");
+        let issues = match fs::read_to_string(&config_path) {
+            Ok(yaml) => {
+                let known_devices = self.database.get_all_known_devices().unwrap_or_default();
+                readiness_issues(&yaml, &known_devices)
+            }
+            Err(e) => vec![format!("config.yml unreadable ({})", e)],
+        };
+
+        if issues.is_empty() {
+            (ToastLevel::Success, format!("✓ Module {} installed and ready", module_name))
+        } else {
+            log_warn!(target: LOG_TARGET, "Module {} installed with issues: {}", module_name, issues.join("; "));
+            (ToastLevel::Warning, format!("⚠️ Module {} installed, but {}", module_name, issues.join("; ")))
+        }
+    }
+}
+
+/// Problems that would stop a module config from working: it doesn't validate, its template
+/// doesn't exist, or the device it binds to hasn't been seen or isn't trusted yet
+fn readiness_issues(config_yaml: &str, known_devices: &[KnownDevice]) -> Vec<String> {
+    let mut issues = Vec::new();
+
+    if let Err(e) = ConfigValidator::validate(config_yaml) {
+        issues.push(format!("config is invalid ({})", e));
+    }
+
+    let Ok(config) = serde_yaml::from_str::<serde_json::Value>(config_yaml) else {
+        return issues;
+    };
+
+    if let Some(template) = config.get("template").and_then(|v| v.as_str()) {
+        if get_template(template).is_none() {
+            issues.push(format!("template '{}' is not available", template));
+        }
+    }
+
+    let device_id = config
+        .get("bindings")
+        .and_then(|b| b.get("device_id"))
+        .and_then(|v| v.as_str())
+        .filter(|id| !id.is_empty());
+
+    if let Some(device_id) = device_id {
+        let device = known_devices.iter().find(|d| {
+            d.device_id.as_deref() == Some(device_id) || d.mac_address.eq_ignore_ascii_case(device_id)
+        });
+        match device {
+            None => issues.push(format!("device {} not seen yet", device_id)),
+            Some(device) if !device.is_trusted => {
+                issues.push(format!("device {} is not trusted", device_id))
+            }
+            Some(_) => {}
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(device_id: &str, is_trusted: bool) -> KnownDevice {
+        KnownDevice {
+            mac_address: "AA:BB:CC:DD:EE:01".to_string(),
+            device_name: "Pump".to_string(),
+            device_type: None,
+            firmware_version: None,
+            first_seen: 0,
+            last_seen: 0,
+            is_trusted,
+            rssi: None,
+            heartbeat_interval_secs: None,
+            device_id: Some(device_id.to_string()),
+            custom_name: false,
+        }
+    }
+
+    #[test]
+    fn test_reports_missing_and_untrusted_devices() {
+        let yaml = "name: Pump\nmodule_type: valve_control\nbus_topic: pump\ntemplate: toggle_switch\nbindings:\n  device_id: p01\n  state: false\n  label: Pump\n";
+
+        let issues = readiness_issues(yaml, &[]);
+        assert!(issues.iter().any(|i| i == "device p01 not seen yet"), "{:?}", issues);
+
+        let issues = readiness_issues(yaml, &[device("p01", false)]);
+        assert!(issues.iter().any(|i| i == "device p01 is not trusted"), "{:?}", issues);
+
+        let issues = readiness_issues(yaml, &[device("p01", true)]);
+        assert!(!issues.iter().any(|i| i.contains("p01")), "{:?}", issues);
+    }
+}
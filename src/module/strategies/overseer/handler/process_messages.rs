@@ -1,4 +1,9 @@
-use super::{HandlerMessage, OverseerHandler};
+use crate::runtime::{
+    toast::{notify, ToastLevel},
+    undo,
+};
+
+use super::{update_review, HandlerMessage, OverseerHandler, UndoOp};
 
 impl OverseerHandler {
     pub(in crate::module) fn process_messages(&mut self) {
@@ -8,15 +13,29 @@ impl OverseerHandler {
                 HandlerMessage::DevicesRefreshed(devices) => {
                     // These are discovered (not yet in DB) devices
                     self.pending_devices = devices;
-                    self.status_message = None;
                 }
-                HandlerMessage::KnownDevicesRefreshed(devices) => {
-                    self.known_devices = devices;
-                    self.status_message = None;
+                HandlerMessage::KnownDevicesRefreshed { requested_page, .. } if requested_page != self.device_page => {
+                    // Read for a page that was turned away from mid-read
+                }
+                HandlerMessage::KnownDevicesRefreshed { page, total, devices, .. } => {
+                    self.show_known_devices_page(page, total, devices);
+                }
+                HandlerMessage::RegistryRefreshed(fetch) if fetch.registry_url != self.registry_url => {
+                    // Listing from a registry that was switched away from mid-fetch
                 }
-                HandlerMessage::RegistryRefreshed(modules) => {
-                    self.registry_manifests = modules;
-                    self.status_message = None;
+                HandlerMessage::RegistryRefreshed(fetch) => {
+                    self.registry_manifests = fetch.manifests;
+                    self.check_module_updates();
+                    if let Some(at) = fetch.cached_at {
+                        notify(ToastLevel::Warning, format!(
+                            "⚠️ Registry offline - using cached registry from {}",
+                            at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")
+                        ));
+                    }
+                }
+                HandlerMessage::RegistryFetchFailed(error) => {
+                    self.last_error.set(format!("Registry fetch failed: {}", error));
+                    notify(ToastLevel::Error, "❌ Registry unreachable");
                 }
                 HandlerMessage::DeviceTrusted(mac) => {
                     self.pending_devices.retain(|(m, _, _)| m != &mac);
@@ -24,7 +43,12 @@ impl OverseerHandler {
                     {
                         self.selected_index -= 1;
                     }
-                    self.status_message = Some(format!("✓ Device {} trusted!", mac));
+                    notify(ToastLevel::Success, format!("✓ Device {} trusted!", mac));
+                    undo::record("overseer", format!("trust {}", mac), &UndoOp::SetTrust { mac_address: mac, trusted: true });
+                    self.refresh_known_devices();
+                }
+                HandlerMessage::DeviceReconnected(name) => {
+                    notify(ToastLevel::Success, format!("✓ Reconnected to {}", name));
                     self.refresh_known_devices();
                 }
                 HandlerMessage::DeviceDiscovered { mac, name, rssi } => {
@@ -33,29 +57,51 @@ impl OverseerHandler {
                         self.pending_devices.push((mac, name, rssi));
                     }
                 }
+                HandlerMessage::InstallProgress(progress) => {
+                    self.publish_install_progress(progress);
+                }
+                HandlerMessage::InstallFailed(error) => {
+                    self.install_progress = None;
+                    self.last_error.set(format!("Install failed: {}", error));
+                    notify(ToastLevel::Error, format!("❌ Failed to install: {}", error));
+                }
+                HandlerMessage::UpdateStaged(staged) => {
+                    self.install_progress = None;
+                    if staged.conflicts.is_empty() {
+                        if self.apply_update(staged, &[]) {
+                            self.trigger_module_refresh();
+                        }
+                    } else {
+                        notify(ToastLevel::Warning, format!(
+                            "⚠️ {} field(s) changed by you and the update - choose which to keep",
+                            staged.conflicts.len()
+                        ));
+                        self.update_review = Some(update_review::UpdateReview::new(staged));
+                    }
+                }
                 HandlerMessage::ModuleInstalled(name) => {
+                    self.install_progress = None;
                     self.refresh_installed_modules();
-                    self.status_message = Some(format!("✓ Module {} installed!", name));
+                    let (level, message) = self.probe_installed_module(&name);
+                    notify(level, message);
                 }
                 HandlerMessage::OperationInProgress(msg) => {
-                    self.status_message = Some(msg);
+                    notify(ToastLevel::Info, msg);
                 }
                 HandlerMessage::ScanProgress(seconds) => {
                     self.scan_countdown = seconds;
-                    self.status_message =
-                        Some(format!("🔍 Scanning... {} seconds remaining", seconds));
                 }
                 HandlerMessage::ScanComplete(count) => {
                     self.is_scanning = false;
                     self.scan_countdown = 0;
-                    self.status_message =
-                        Some(format!("✅ Scan complete! {} new device(s) found", count));
+                    notify(ToastLevel::Success, format!("✅ Scan complete! {} new device(s) found", count));
                     self.refresh_data_async();
                 }
                 HandlerMessage::ScanFailed(err_msg) => {
                     self.is_scanning = false;
                     self.scan_countdown = 0;
-                    self.status_message = Some(err_msg);
+                    self.last_error.set(err_msg.clone());
+                    notify(ToastLevel::Error, err_msg);
                 }
                 _ => {}
             }
@@ -0,0 +1,31 @@
+use crate::runtime::isolation::spawn_isolated;
+use crate::util::io::bus::BusMessage;
+
+use super::{
+    installer::{InstallProgress, INSTALL_PROGRESS_TOPIC},
+    OverseerHandler,
+    HANDLER_KEY,
+};
+
+impl OverseerHandler {
+    /// Track install progress for the install view and mirror it on the bus.
+    /// Download updates only go out when the whole percentage changes.
+    pub(in crate::module) fn publish_install_progress(&mut self, progress: InstallProgress) {
+        let percent = |p: &InstallProgress| (p.ratio() * 100.0) as u32;
+        let changed = self
+            .install_progress
+            .as_ref()
+            .map_or(true, |last| last.stage != progress.stage || percent(last) != percent(&progress));
+
+        if changed {
+            let bus = self.message_bus.clone();
+            let payload = serde_json::to_string(&progress).unwrap_or_default();
+            spawn_isolated(HANDLER_KEY, async move {
+                let message = BusMessage::new(INSTALL_PROGRESS_TOPIC.to_string(), payload, "overseer".to_string());
+                let _ = bus.publish(message).await;
+            });
+        }
+
+        self.install_progress = Some(progress);
+    }
+}
@@ -0,0 +1,62 @@
+use std::fs;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{log_info, log_warn};
+use crate::module::strategies::overseer::LOG_TARGET;
+use super::{archive_metadata::ArchiveMetadata, OverseerHandler};
+
+/// How long archived modules are kept before being purged.
+/// `ARCHIVE_RETENTION_DAYS` (default 30); `0` keeps them forever.
+fn archive_retention() -> Option<Duration> {
+    let days = std::env::var("ARCHIVE_RETENTION_DAYS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(30);
+
+    (days > 0).then(|| Duration::days(days))
+}
+
+impl OverseerHandler {
+    /// Permanently delete archived modules older than the retention window, then refresh the
+    /// archive list. Only archives with a sidecar are dated well enough to purge. Returns how
+    /// many were removed.
+    pub(in crate::module) fn purge_expired_archives(&mut self) -> usize {
+        let Some(retention) = archive_retention() else {
+            return 0;
+        };
+        let Ok(archived) = self.list_archived_modules() else {
+            return 0;
+        };
+
+        let cutoff = Utc::now() - retention;
+        let mut removed = 0;
+
+        for entry in archived {
+            // Never purge what we can't date: archives from before sidecars only have the time
+            // in their directory name, which a rename or copy may have carried over
+            if !ArchiveMetadata::has_sidecar(&self.archive_path, &entry.archive_name)
+                || entry.metadata.archived_at == DateTime::<Utc>::UNIX_EPOCH
+                || entry.metadata.archived_at > cutoff
+            {
+                continue;
+            }
+
+            match fs::remove_dir_all(self.archive_path.join(&entry.archive_name)) {
+                Ok(()) => {
+                    ArchiveMetadata::remove(&self.archive_path, &entry.archive_name);
+                    log_info!(target: LOG_TARGET, "🗑️ Purged archived module {} (archived {})", entry.archive_name, entry.metadata.archived_at);
+                    removed += 1;
+                }
+                Err(e) => log_warn!(target: LOG_TARGET, "Failed to purge archived module {}: {}", entry.archive_name, e),
+            }
+        }
+
+        if removed > 0 {
+            if let Ok(archived) = self.list_archived_modules() {
+                self.archived_modules = archived;
+            }
+        }
+        removed
+    }
+}
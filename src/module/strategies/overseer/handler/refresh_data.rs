@@ -1,36 +1,78 @@
-use crate::module::strategies::overseer::database::OverseerDatabase;
-use super::{HandlerMessage, OverseerHandler};
+use std::time::{Duration, Instant};
+
+use crate::log_debug;
+use crate::runtime::isolation::spawn_isolated;
+use crate::module::strategies::overseer::LOG_TARGET;
+
+use super::refresh_known_devices::read_known_devices_page;
+use super::{HandlerMessage, OverseerHandler, HANDLER_KEY};
 
 impl OverseerHandler {
     pub(in crate::module) fn refresh_async_data_only(&mut self) {
-        let tx = self.message_tx.clone();
+        self.refresh_discovered_devices();
+        self.refresh_registry();
+    }
+
+    /// Fetch devices the discovery manager has seen in the background
+    pub(in crate::module) fn refresh_discovered_devices(&mut self) {
+        self.devices_refreshed_at = Instant::now();
 
-        // Refresh discovered devices (from discovery manager)
         if let Some(discovery) = &self.discovery_manager {
             let discovery_clone = discovery.clone();
-            let tx_clone = tx.clone();
-            tokio::spawn(async move {
+            let tx = self.message_tx.clone();
+            spawn_isolated(HANDLER_KEY, async move {
                 let devices = discovery_clone.get_discovered_devices().await;
-                let _ = tx_clone.send(HandlerMessage::DevicesRefreshed(devices));
+                let _ = tx.send(HandlerMessage::DevicesRefreshed(devices));
             });
         }
+    }
+
+    /// Re-read discovered and known devices once `interval` has passed since the last read
+    pub(in crate::module) fn poll_devices(&mut self, interval: Duration) {
+        if self.devices_refreshed_at.elapsed() < interval {
+            return;
+        }
 
-        // Refresh registry (needs network fetch)
+        self.refresh_known_devices_in_background();
+        self.refresh_discovered_devices();
+    }
+
+    /// Re-read the current page of known devices off the render path
+    pub(in crate::module) fn refresh_known_devices_in_background(&mut self) {
+        let database = self.database.clone();
+        let requested_page = self.device_page;
+        let tx = self.message_tx.clone();
+        spawn_isolated(HANDLER_KEY, async move {
+            let read = tokio::task::spawn_blocking(move || read_known_devices_page(&database, requested_page)).await;
+            match read {
+                Ok(Ok((page, total, devices))) => {
+                    let _ = tx.send(HandlerMessage::KnownDevicesRefreshed { requested_page, page, total, devices });
+                }
+                Ok(Err(e)) => log_debug!(target: LOG_TARGET, "Periodic known device refresh failed: {}", e),
+                Err(e) => log_debug!(target: LOG_TARGET, "Periodic known device refresh panicked: {}", e),
+            }
+        });
+    }
+
+    /// Fetch the active registry's listing in the background
+    pub(in crate::module) fn refresh_registry(&mut self) {
+        let tx = self.message_tx.clone();
         let registry_url = self.registry_url.clone();
-        tokio::spawn(async move {
-            // Mock call - in real implementation would fetch from network
-            let modules = Self::fetch_registry_manifests(&registry_url).await;
-            if let Ok(modules) = modules {
-                let _ = tx.send(HandlerMessage::RegistryRefreshed(modules));
+        spawn_isolated(HANDLER_KEY, async move {
+            match Self::fetch_registry_manifests(&registry_url).await {
+                Ok(fetch) => {
+                    let _ = tx.send(HandlerMessage::RegistryRefreshed(fetch));
+                }
+                Err(e) => {
+                    let _ = tx.send(HandlerMessage::RegistryFetchFailed(format!("{:#}", e)));
+                }
             }
         });
     }
 
     pub(in crate::module) fn refresh_data_async(&mut self) {
         // Synchronous updates first (immediate)
-        if let Ok(devices) = self.database.get_all_known_devices() {
-            self.known_devices = devices;
-        }
+        let _ = self.load_known_devices_page();
 
         self.refresh_installed_modules();
 
@@ -19,5 +19,7 @@ impl OverseerHandler {
                 }
             }
         }
+
+        self.check_module_updates();
     }
 }
@@ -1,19 +1,73 @@
 use crate::log_error;
-use crate::module::strategies::overseer::database::OverseerDatabase;
+use crate::module::strategies::overseer::database::{KnownDevice, OverseerDatabase};
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::module::strategies::overseer::LOG_TARGET;
+use crate::util::database::Database;
 
-use super::OverseerHandler;
+use super::{OverseerHandler, DEVICE_PAGE_SIZE};
+
+/// Page `page` of the known devices (stepped back to the last page when it no longer exists),
+/// with the page actually read and the total count
+pub(super) fn read_known_devices_page(database: &Database, page: usize) -> rusqlite::Result<(usize, usize, Vec<KnownDevice>)> {
+    let total = database.count_known_devices()?;
+    let page = page.min(total.saturating_sub(1) / DEVICE_PAGE_SIZE);
+    let devices = database.get_known_devices_page(DEVICE_PAGE_SIZE, page * DEVICE_PAGE_SIZE)?;
+    Ok((page, total, devices))
+}
 
 impl OverseerHandler {
     pub(in crate::module) fn refresh_known_devices(&mut self) {
-        match self.database.get_all_known_devices() {
-            Ok(devices) => {
-                self.known_devices = devices;
-                self.status_message = None;
-            }
-            Err(e) => {
-                log_error!("Failed to load known devices: {}", e);
-                self.status_message = Some(format!("Error loading devices: {}", e));
-            }
+        if let Err(e) = self.load_known_devices_page() {
+            log_error!(target: LOG_TARGET, "Failed to load known devices: {}", e);
+            self.last_error.set(format!("Failed to load known devices: {}", e));
+            notify(ToastLevel::Error, format!("Error loading devices: {}", e));
         }
     }
+
+    /// Load the current page of known devices, stepping back if it no longer exists
+    pub(in crate::module) fn load_known_devices_page(&mut self) -> rusqlite::Result<()> {
+        let (page, total, devices) = read_known_devices_page(&self.database, self.device_page)?;
+        self.show_known_devices_page(page, total, devices);
+        Ok(())
+    }
+
+    pub(in crate::module) fn show_known_devices_page(&mut self, page: usize, total: usize, devices: Vec<KnownDevice>) {
+        self.device_page = page;
+        self.known_device_total = total;
+        self.known_devices = devices;
+
+        if self.current_view == super::WastelandView::AllDevices {
+            self.selected_index = self.selected_index.min(self.known_devices.len().saturating_sub(1));
+        }
+    }
+
+    /// Move to another page of known devices; returns false when there is none
+    pub(in crate::module) fn turn_device_page(&mut self, forward: bool) -> bool {
+        let has_next = (self.device_page + 1) * DEVICE_PAGE_SIZE < self.known_device_total;
+        match forward {
+            true if has_next => self.device_page += 1,
+            false if self.device_page > 0 => self.device_page -= 1,
+            _ => return false,
+        }
+        self.refresh_known_devices();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_past_the_end_steps_back_to_the_last_page() {
+        let db = Database::in_memory().unwrap();
+        for i in 0..DEVICE_PAGE_SIZE + 1 {
+            db.record_device_discovery(&format!("AA:BB:CC:DD:EE:{:02X}", i), &format!("unit {}", i), -60).unwrap();
+        }
+
+        let (page, total, devices) = read_known_devices_page(&db, 5).unwrap();
+        assert_eq!(page, 1);
+        assert_eq!(total, DEVICE_PAGE_SIZE + 1);
+        assert_eq!(devices.len(), 1);
+    }
 }
@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::util::dirs::data_dirs;
+use crate::util::fs::write_atomic;
+
+use super::RegistryManifest;
+
+/// Retry behaviour for registry fetches, from `REGISTRY_FETCH_ATTEMPTS` (default 3),
+/// `REGISTRY_FETCH_BACKOFF_MS` (default 1000, doubled per retry) and
+/// `REGISTRY_FETCH_TIMEOUT_SECS` (default 10, per attempt).
+#[derive(Debug, Clone)]
+pub(in crate::module) struct RegistryFetchPolicy {
+    pub attempts: u32,
+    pub initial_backoff: Duration,
+    pub timeout: Duration,
+}
+
+impl RegistryFetchPolicy {
+    pub fn from_env() -> Self {
+        let env_u64 = |key: &str, default: u64| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(default)
+        };
+
+        Self {
+            attempts: env_u64("REGISTRY_FETCH_ATTEMPTS", 3).max(1) as u32,
+            initial_backoff: Duration::from_millis(env_u64("REGISTRY_FETCH_BACKOFF_MS", 1000)),
+            timeout: Duration::from_secs(env_u64("REGISTRY_FETCH_TIMEOUT_SECS", 10)),
+        }
+    }
+}
+
+/// Registry listing as last fetched, plus whether it came from the disk cache
+#[derive(Debug, Clone)]
+pub(in crate::module) struct RegistryFetch {
+    /// Registry this listing came from, so a switch mid-fetch can ignore it
+    pub registry_url: String,
+    pub manifests: Vec<RegistryManifest>,
+    /// Set when the live fetch failed and this is the cached copy from that time
+    pub cached_at: Option<DateTime<Utc>>,
+}
+
+/// Last successful registry response, kept on disk for offline use
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(in crate::module) struct CachedRegistry {
+    pub fetched_at: DateTime<Utc>,
+    pub registry_url: String,
+    pub manifests: Vec<RegistryManifest>,
+}
+
+impl CachedRegistry {
+    /// `REGISTRY_CACHE_PATH` if set, otherwise `registry.json` in the cache directory
+    pub fn path() -> PathBuf {
+        if let Ok(path) = std::env::var("REGISTRY_CACHE_PATH") {
+            if !path.is_empty() {
+                return PathBuf::from(path);
+            }
+        }
+
+        data_dirs().cache.join("registry.json")
+    }
+
+    /// Cached copy for `registry_url`, if one exists and parses
+    pub fn load(path: &Path, registry_url: &str) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        let cached: Self = serde_json::from_str(&content).ok()?;
+        (cached.registry_url == registry_url).then_some(cached)
+    }
+
+    pub fn save(&self, path: &Path) -> color_eyre::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Atomic so a crash mid-write never leaves a torn cache
+        write_atomic(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
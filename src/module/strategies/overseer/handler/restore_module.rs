@@ -1,19 +1,19 @@
 use std::fs;
 
-use super::OverseerHandler;
+use super::{archive_metadata::ArchiveMetadata, OverseerHandler};
 
 impl OverseerHandler {
-    pub(in crate::module) fn restore_module(&self, archive_name: &str, new_name: Option<String>) -> color_eyre::Result<()> {
+    /// Move an archived module back into the wasteland, returning the name it was restored as
+    pub(in crate::module) fn restore_module(&self, archive_name: &str, new_name: Option<String>) -> color_eyre::Result<String> {
         let archive_source = self.archive_path.join(archive_name);
 
         if !archive_source.exists() {
             return Err(color_eyre::eyre::eyre!("Archived module not found"));
         }
 
-        // Extract original name (remove timestamp suffix)
-        let original_name = archive_name.split('_').next().unwrap_or(archive_name);
+        let metadata = ArchiveMetadata::load(&self.archive_path, archive_name);
 
-        let restore_name = new_name.unwrap_or_else(|| original_name.to_string());
+        let restore_name = new_name.unwrap_or(metadata.original_name);
         let restore_dest = self.wasteland_path.join(&restore_name);
 
         if restore_dest.exists() {
@@ -22,7 +22,8 @@ impl OverseerHandler {
 
         // Move back from archive
         fs::rename(&archive_source, &restore_dest)?;
+        ArchiveMetadata::remove(&self.archive_path, archive_name);
 
-        Ok(())
+        Ok(restore_name)
     }
 }
@@ -0,0 +1,41 @@
+use std::fs;
+
+use crate::log_error;
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::module::strategies::overseer::LOG_TARGET;
+use super::OverseerHandler;
+
+impl OverseerHandler {
+    /// Diff the editor against the on-disk config and hold it for confirmation
+    pub(in crate::module) fn review_config_save(&mut self) {
+        let Some(editor) = &self.config_editor else {
+            return;
+        };
+
+        let config_path = self.wasteland_path.join(&editor.module_name).join("config.yml");
+
+        // New modules have nothing on disk yet, so every field shows as added
+        let original_config = if config_path.exists() {
+            match fs::read_to_string(&config_path)
+                .map_err(color_eyre::Report::from)
+                .and_then(|yaml| serde_yaml::from_str::<serde_json::Value>(&yaml).map_err(Into::into))
+            {
+                Ok(config) => config,
+                Err(e) => {
+                    notify(ToastLevel::Error, format!("Failed to read config: {}", e));
+                    log_error!(target: LOG_TARGET, "Read error: {}", e);
+                    return;
+                }
+            }
+        } else {
+            serde_json::json!({})
+        };
+
+        let changes = editor.diff_against(&original_config);
+        if changes.is_empty() {
+            notify(ToastLevel::Info, "No changes to save");
+        } else {
+            self.pending_changes = Some(changes);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use super::OverseerHandler;
+
+impl OverseerHandler {
+    /// Hold the selected registry module for capability approval before installing
+    pub(in crate::module) fn review_install(&mut self) {
+        if self.selected_index < self.registry_manifests.len() {
+            self.pending_install = Some(self.selected_index);
+        }
+    }
+
+    /// Lines describing what the pending install is asking for
+    pub(in crate::module) fn install_review_lines(&self) -> Option<Vec<String>> {
+        let manifest = self.registry_manifests.get(self.pending_install?)?;
+
+        let mut lines = vec![format!("{} v{} by {}", manifest.name, manifest.version, manifest.author)];
+        match &manifest.capabilities {
+            Some(caps) => lines.extend(caps.summary_lines()),
+            None => lines.push("⚠️ No capabilities declared - module will be unrestricted".to_string()),
+        }
+        Some(lines)
+    }
+}
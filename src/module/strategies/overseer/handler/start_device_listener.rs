@@ -1,11 +1,13 @@
-use super::{HandlerMessage, OverseerHandler};
+use crate::runtime::isolation::spawn_isolated;
+
+use super::{HandlerMessage, OverseerHandler, HANDLER_KEY};
 
 impl OverseerHandler {
     pub(in crate::module) fn start_device_listener(&mut self) {
         let tx = self.message_tx.clone();
         let bus = self.message_bus.clone();
 
-        tokio::spawn(async move {
+        spawn_isolated(HANDLER_KEY, async move {
             let mut receiver = bus.subscribe("device_discovered".to_string()).await;
 
             while let Some(msg) = receiver.recv().await {
@@ -0,0 +1,53 @@
+use crate::log_info;
+use crate::module::strategies::overseer::registry_endpoint::{validate_registry_url, RegistryEndpoint};
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::module::strategies::overseer::LOG_TARGET;
+
+use super::OverseerHandler;
+
+impl OverseerHandler {
+    /// Use the configured registries, starting on `active` (or the first one)
+    pub fn with_registries(mut self, registries: Vec<RegistryEndpoint>, active: Option<&str>) -> Self {
+        if registries.is_empty() {
+            return self;
+        }
+
+        self.active_registry = active
+            .and_then(|name| registries.iter().position(|r| r.name == name))
+            .unwrap_or(0);
+        self.registry_url = registries[self.active_registry].url.clone();
+        self.registries = registries;
+        self
+    }
+
+    /// Cycle to the next configured registry and reload its listing.
+    /// A registry with a malformed URL is reported and not switched to.
+    pub(in crate::module) fn handle_switch_registry(&mut self) {
+        if self.registries.len() < 2 {
+            notify(ToastLevel::Info, "Only one registry configured");
+            return;
+        }
+
+        let next = (self.active_registry + 1) % self.registries.len();
+        let registry = &self.registries[next];
+
+        if let Err(e) = validate_registry_url(&registry.url) {
+            self.last_error.set(format!("Registry '{}': {}", registry.name, e));
+            notify(ToastLevel::Error, format!("❌ Not switching to '{}': {}", registry.name, e));
+            return;
+        }
+
+        log_info!(target: LOG_TARGET, "📦 Switching registry to {} ({})", registry.name, registry.url);
+        self.active_registry = next;
+        self.registry_url = registry.url.clone();
+        self.registry_manifests.clear();
+        self.pending_install = None;
+        self.selected_index = 0;
+        notify(ToastLevel::Info, format!("📦 Loading registry '{}'...", registry.name));
+        self.refresh_registry();
+    }
+
+    pub(in crate::module) fn active_registry(&self) -> &RegistryEndpoint {
+        &self.registries[self.active_registry]
+    }
+}
@@ -6,6 +6,7 @@ use crate::module::{
     Module,
 };
 use crate::module::strategies::overseer::handler::OverseerHandler;
+use crate::runtime::undo::UndoDirection;
 use crate::util::io::event::AppEvent;
 
 impl ModuleHandler for OverseerHandler {
@@ -32,4 +33,16 @@ impl ModuleHandler for OverseerHandler {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn last_error(&self) -> Option<(chrono::DateTime<chrono::Utc>, String)> {
+        self.last_error.get()
+    }
+
+    fn key_hints(&self) -> Vec<(KeyCode, &str)> {
+        self._key_hints()
+    }
+
+    fn apply_undo(&mut self, op: &mut serde_json::Value, direction: UndoDirection) -> color_eyre::Result<()> {
+        self._apply_undo(op, direction)
+    }
 }
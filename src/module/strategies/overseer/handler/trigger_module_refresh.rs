@@ -1,11 +1,13 @@
 use crate::log_debug;
-use super::OverseerHandler;
+use crate::runtime::isolation::spawn_isolated;
+use crate::module::strategies::overseer::LOG_TARGET;
+use super::{OverseerHandler, HANDLER_KEY};
 
 impl OverseerHandler {
     pub(in crate::module) fn trigger_module_refresh(&self) {
         let bus = self.message_bus.clone();
-        tokio::spawn(async move {
-            log_debug!("Trigger module refresh");
+        spawn_isolated(HANDLER_KEY, async move {
+            log_debug!(target: LOG_TARGET, "Trigger module refresh");
             let _ = bus.publish_app_event("refresh_modules", "").await;
         });
     }
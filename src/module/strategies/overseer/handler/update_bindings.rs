@@ -4,9 +4,14 @@ use crate::module::strategies::overseer::{
     handler::OverseerHandler,
 };
 
+use crate::runtime::poll_interval::{module_poll_interval, PollTask};
+
+use super::handle_forget_untrusted::forget_after_days;
+
 impl OverseerHandler {
     pub(in crate::module) fn _update_bindings(&mut self, module: &mut Module) {
         self.process_messages();
+        self.poll_devices(module_poll_interval(&module.config, PollTask::Overseer));
 
         module.config.bindings.insert(
             "current_view".to_string(),
@@ -33,16 +38,21 @@ impl OverseerHandler {
             serde_json::json!(self.config_editor.is_some()),
         );
 
-        if let Some(status) = &self.status_message {
-            module
-                .config
-                .bindings
-                .insert("status_message".to_string(), serde_json::json!(status));
-        } else {
-            module
-                .config
-                .bindings
-                .insert("status_message".to_string(), serde_json::json!(""));
+        // Confirmation for a pending bulk forget, shown on the device list until answered
+        match &self.pending_forget {
+            Some(macs) => {
+                module.config.bindings.insert(
+                    "forget_prompt".to_string(),
+                    serde_json::json!(format!(
+                        "Forget {} untrusted device(s) unseen for {} day(s)?",
+                        macs.len(),
+                        forget_after_days()
+                    )),
+                );
+            }
+            None => {
+                module.config.bindings.remove("forget_prompt");
+            }
         }
 
         let pending_list: Vec<String> = self
@@ -61,14 +71,20 @@ impl OverseerHandler {
             .iter()
             .map(|device| {
                 let trust_icon = if device.is_trusted { "✓" } else { "✗" };
+                let type_str = device
+                    .device_type
+                    .as_deref()
+                    .filter(|t| !t.is_empty())
+                    .map(|t| format!(" [{}]", t))
+                    .unwrap_or_default();
                 let rssi_str = device
                     .rssi
                     .map(|r| format!(" RSSI: {} dBm", r))
                     .unwrap_or_default();
 
                 format!(
-                    "{} {} ({}){}",
-                    trust_icon, device.device_name, device.mac_address, rssi_str
+                    "{} {}{} ({}){}",
+                    trust_icon, device.device_name, type_str, device.mac_address, rssi_str
                 )
             })
             .collect();
@@ -78,6 +94,22 @@ impl OverseerHandler {
             .bindings
             .insert("known_devices".to_string(), serde_json::json!(known_list));
 
+        module.config.bindings.insert(
+            "known_device_total".to_string(),
+            serde_json::json!(self.known_device_total),
+        );
+
+        module.config.bindings.insert(
+            "known_device_offset".to_string(),
+            serde_json::json!(self.device_page * super::DEVICE_PAGE_SIZE),
+        );
+
+        let registry = self.active_registry();
+        module.config.bindings.insert(
+            "active_registry_label".to_string(),
+            serde_json::json!(format!("{} ({})", registry.name, registry.url)),
+        );
+
         let module_list: Vec<String> = self
             .registry_manifests
             .iter()
@@ -89,16 +121,104 @@ impl OverseerHandler {
             .bindings
             .insert("module_list".to_string(), serde_json::json!(module_list));
 
+        match &self.device_edit {
+            Some(edit) => {
+                module
+                    .config
+                    .bindings
+                    .insert("device_edit".to_string(), serde_json::json!(edit.lines()));
+            }
+            None => {
+                module.config.bindings.remove("device_edit");
+            }
+        }
+
+        match &self.install_progress {
+            Some(progress) => {
+                module.config.bindings.insert(
+                    "install_progress".to_string(),
+                    serde_json::json!({
+                        "label": progress.describe(),
+                        "ratio": progress.ratio(),
+                    }),
+                );
+            }
+            None => {
+                module.config.bindings.remove("install_progress");
+            }
+        }
+
+        match &self.update_review {
+            Some(review) => {
+                module
+                    .config
+                    .bindings
+                    .insert("update_review".to_string(), serde_json::json!(review.lines()));
+            }
+            None => {
+                module.config.bindings.remove("update_review");
+            }
+        }
+
+        match self.install_review_lines() {
+            Some(lines) => {
+                module
+                    .config
+                    .bindings
+                    .insert("install_review".to_string(), serde_json::json!(lines));
+            }
+            None => {
+                module.config.bindings.remove("install_review");
+            }
+        }
+
         module.config.bindings.insert(
             "installed_modules".to_string(),
             serde_json::json!(self.installed_modules),
         );
 
+        let module_updates: serde_json::Map<String, serde_json::Value> = self
+            .module_updates
+            .iter()
+            .map(|(name, (installed, available))| {
+                (name.clone(), serde_json::json!(format!("{} → {}", installed, available)))
+            })
+            .collect();
+
+        module.config.bindings.insert(
+            "module_updates".to_string(),
+            serde_json::Value::Object(module_updates),
+        );
+
+        let archived_list: Vec<String> = self
+            .filtered_archived_modules()
+            .iter()
+            .map(|m| m.describe())
+            .collect();
+
         module.config.bindings.insert(
             "archived_modules".to_string(),
-            serde_json::json!(self.archived_modules),
+            serde_json::json!(archived_list),
         );
 
+        module.config.bindings.insert(
+            "archive_filter".to_string(),
+            serde_json::json!(self.archive_filter),
+        );
+
+        match &self.pending_changes {
+            Some(changes) => {
+                let lines: Vec<String> = changes.iter().map(|c| c.describe()).collect();
+                module
+                    .config
+                    .bindings
+                    .insert("config_diff".to_string(), serde_json::json!(lines));
+            }
+            None => {
+                module.config.bindings.remove("config_diff");
+            }
+        }
+
         if let Some(editor) = &self.config_editor {
             module.config.bindings.insert(
                 "editor_module_name".to_string(),
@@ -1,7 +1,7 @@
-use std::fs;
-
 use crate::{log_debug, log_error, log_info};
 use crate::module::config::ConfigValidator;
+use crate::util::fs::write_atomic;
+use crate::module::strategies::overseer::LOG_TARGET;
 
 use super::OverseerHandler;
 
@@ -25,23 +25,23 @@ impl OverseerHandler {
 
         let yaml_str = serde_yaml::to_string(&yaml_value)?;
 
-        log_debug!("YAML to validate:\n{}", yaml_str);
+        log_debug!(target: LOG_TARGET, "YAML to validate:\n{}", yaml_str);
 
         // Validate before writing
         match ConfigValidator::validate(&yaml_str) {
             Ok(_) => {
-                log_info!("Config validation passed for {}", module_name);
+                log_info!(target: LOG_TARGET, "Config validation passed for {}", module_name);
             }
             Err(e) => {
-                log_error!("Config validation failed: {}", e);
+                log_error!(target: LOG_TARGET, "Config validation failed: {}", e);
                 return Err(color_eyre::eyre::eyre!("Validation failed: {}", e));
             }
         }
 
         // Write to file
-        fs::write(&config_path, yaml_str)?;
+        write_atomic(&config_path, yaml_str)?;
 
-        log_info!("Updated config for module: {}", module_name);
+        log_info!(target: LOG_TARGET, "Updated config for module: {}", module_name);
 
         Ok(())
     }
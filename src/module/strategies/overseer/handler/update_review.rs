@@ -0,0 +1,59 @@
+use crossterm::event::KeyCode;
+
+use super::installer::StagedUpdate;
+
+/// Conflicts from a staged module update, each settled as "keep mine" or "take the update's"
+#[derive(Debug, Clone)]
+pub(super) struct UpdateReview {
+    pub staged: StagedUpdate,
+    /// Per conflict: true takes the update's value; starts out keeping the user's
+    pub take_update: Vec<bool>,
+    pub selected: usize,
+}
+
+/// What a key press in the conflict review asks the handler to do
+pub(super) enum UpdateReviewAction {
+    None,
+    Apply,
+    Cancel,
+}
+
+impl UpdateReview {
+    pub fn new(staged: StagedUpdate) -> Self {
+        let take_update = vec![false; staged.conflicts.len()];
+        Self { staged, take_update, selected: 0 }
+    }
+
+    pub fn handle_key(&mut self, key_code: KeyCode) -> UpdateReviewAction {
+        match key_code {
+            KeyCode::Enter => return UpdateReviewAction::Apply,
+            KeyCode::Esc => return UpdateReviewAction::Cancel,
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(self.take_update.len().saturating_sub(1)),
+            KeyCode::Tab | KeyCode::Char(' ') => {
+                if let Some(choice) = self.take_update.get_mut(self.selected) {
+                    *choice = !*choice;
+                }
+            }
+            _ => {}
+        }
+        UpdateReviewAction::None
+    }
+
+    /// Review lines for the overseer card; the first is the heading, `▶` marks the selected conflict
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![format!(
+            "Update {} to {}: {} conflict(s), [bracketed] value is kept",
+            self.staged.module_name,
+            self.staged.version,
+            self.staged.conflicts.len()
+        )];
+        lines.extend(self.staged.conflicts.iter().zip(&self.take_update).enumerate().map(
+            |(i, (conflict, &theirs))| {
+                let marker = if i == self.selected { "▶" } else { " " };
+                format!("{} {}", marker, conflict.describe(theirs))
+            },
+        ));
+        lines
+    }
+}
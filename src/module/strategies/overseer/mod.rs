@@ -4,6 +4,13 @@ use crate::module::BaseModuleConfig;
 pub mod config_editor;
 pub mod database;
 pub mod handler;
+pub mod registry_endpoint;
+mod validation;
+
+pub use registry_endpoint::RegistryEndpoint;
+
+/// Log target for the overseer, so `LOG_LEVELS="overseer=debug"` covers it
+const LOG_TARGET: &str = "overseer";
 
 /// Wasteland Manager module
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,8 +30,13 @@ pub struct OverseerBindings {
     pub installed_modules: Vec<String>,
     pub archived_modules: Vec<String>,
 
-    #[serde(default)]
-    pub status_message: Option<String>,
     #[serde(default)]
     pub is_blinkable: Option<bool>,
+
+    /// Registries to install from; defaults to the production registry
+    #[serde(default)]
+    pub registries: Vec<RegistryEndpoint>,
+    /// Name of the registry selected at startup; defaults to the first one
+    #[serde(default)]
+    pub active_registry: Option<String>,
 }
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use crate::module::ModuleConfig;
+
+/// Registry used when the overseer config doesn't list any
+pub const DEFAULT_REGISTRY_URL: &str = "https://registry.survon.io/v1";
+
+/// A named module registry the overseer can install from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegistryEndpoint {
+    pub name: String,
+    pub url: String,
+}
+
+impl RegistryEndpoint {
+    pub fn default_endpoint() -> Self {
+        Self { name: "production".to_string(), url: DEFAULT_REGISTRY_URL.to_string() }
+    }
+
+    /// `registries` and `active_registry` from an overseer module's bindings.
+    /// Entries that don't parse are skipped here; the config validator reports them.
+    pub fn from_config(config: &ModuleConfig) -> (Vec<Self>, Option<String>) {
+        let registries = config
+            .bindings
+            .get("registries")
+            .and_then(|v| serde_json::from_value::<Vec<Self>>(v.clone()).ok())
+            .unwrap_or_default();
+        let active = config.binding::<String>("active_registry");
+
+        (registries, active)
+    }
+}
+
+/// Registry URLs must be absolute http(s) URLs with a host, or `file://` paths to a local bundle repo
+pub fn validate_registry_url(url: &str) -> Result<(), String> {
+    let parsed = reqwest::Url::parse(url).map_err(|e| format!("Invalid URL '{}': {}", url, e))?;
+
+    match parsed.scheme() {
+        "http" | "https" if parsed.host_str().unwrap_or("").is_empty() => {
+            Err(format!("Missing host in '{}'", url))
+        }
+        "http" | "https" => Ok(()),
+        "file" if parsed.path().trim_matches('/').is_empty() => {
+            Err(format!("Missing bundle repo path in '{}'", url))
+        }
+        "file" => Ok(()),
+        scheme => Err(format!("Unsupported scheme '{}' in '{}'; use http, https or file", scheme, url)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_registry_url() {
+        assert!(validate_registry_url("https://registry.survon.io/v1").is_ok());
+        assert!(validate_registry_url("http://localhost:8080").is_ok());
+        assert!(validate_registry_url("file:///media/usb/survon").is_ok());
+        assert!(validate_registry_url("file:///").is_err());
+
+        assert!(validate_registry_url("ftp://registry.survon.io").unwrap_err().contains("scheme"));
+        assert!(validate_registry_url("registry.survon.io/v1").is_err());
+        assert!(validate_registry_url("").is_err());
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::HashSet;
+
+use crate::module::{ConfigValidator, OverseerConfig, ValidationError};
+
+use super::registry_endpoint::validate_registry_url;
+
+impl ConfigValidator {
+    pub fn validate_overseer(cfg: &OverseerConfig) -> color_eyre::Result<()> {
+        let b = &cfg.bindings;
+
+        let mut names = HashSet::new();
+        for (i, registry) in b.registries.iter().enumerate() {
+            if registry.name.trim().is_empty() {
+                return Err(ValidationError {
+                    field: format!("bindings.registries[{}].name", i),
+                    error: "Registry name cannot be empty".to_string(),
+                }.into());
+            }
+            if !names.insert(registry.name.as_str()) {
+                return Err(ValidationError {
+                    field: format!("bindings.registries[{}].name", i),
+                    error: format!("Duplicate registry name '{}'", registry.name),
+                }.into());
+            }
+            if let Err(error) = validate_registry_url(&registry.url) {
+                return Err(ValidationError {
+                    field: format!("bindings.registries[{}].url", i),
+                    error,
+                }.into());
+            }
+        }
+
+        if let Some(active) = &b.active_registry {
+            if !b.registries.iter().any(|r| &r.name == active) {
+                return Err(ValidationError {
+                    field: "bindings.active_registry".to_string(),
+                    error: format!("No registry named '{}' in bindings.registries", active),
+                }.into());
+            }
+        }
+
+        Ok(())
+    }
+}
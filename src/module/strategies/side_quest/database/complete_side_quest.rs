@@ -6,7 +6,7 @@ use crate::util::database::Database;
 impl Database {
     pub(in crate::module) fn _side_quest__complete_side_quest(&self, quest_id: i64) -> rusqlite::Result<()> {
         let now = Utc::now().to_rfc3339();
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
 
         conn.execute(
             "UPDATE side_quests
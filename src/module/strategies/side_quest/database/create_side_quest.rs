@@ -16,7 +16,7 @@ impl Database {
     ) -> rusqlite::Result<i64> {
         let now = Utc::now().to_rfc3339();
         let trigger_str = trigger_date.map(|d| d.to_rfc3339());
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
 
         conn.execute(
             "INSERT INTO side_quests (title, description, topic, urgency, trigger_date, created_at, is_active)
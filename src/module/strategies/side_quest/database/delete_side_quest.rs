@@ -1,13 +1,19 @@
+use chrono::Utc;
 use rusqlite::params;
 
 use crate::util::database::Database;
 
 impl Database {
+    /// Soft delete: the quest drops out of every listing but `restore_side_quest` can bring it back
+    /// as it was, until `purge_deleted_side_quests` removes it for good
     pub(in crate::module) fn _side_quest__delete_side_quest(&self, quest_id: i64) -> rusqlite::Result<()> {
-        let conn = self.app_conn.lock().unwrap();
+        let now = Utc::now().to_rfc3339();
+        let conn = Self::connection(&self.app_conn)?;
         conn.execute(
-            "DELETE FROM side_quests WHERE id = ?1",
-            params![quest_id],
+            "UPDATE side_quests
+             SET deleted_at = ?1
+             WHERE id = ?2",
+            params![now, quest_id],
         )?;
 
         Ok(())
@@ -5,11 +5,11 @@ use crate::module::strategies::side_quest::{
 
 impl Database {
     pub(in crate::module) fn _side_quest__get_active_side_quests(&self) -> rusqlite::Result<Vec<SideQuest>> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         let mut stmt = conn.prepare(
             "SELECT id, title, description, topic, urgency, trigger_date, created_at, completed_at, is_active
              FROM side_quests
-             WHERE is_active = 1
+             WHERE is_active = 1 AND deleted_at IS NULL
              ORDER BY
                 CASE urgency
                     WHEN 'Critical' THEN 0
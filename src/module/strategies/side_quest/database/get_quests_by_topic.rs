@@ -6,11 +6,11 @@ use crate::module::strategies::side_quest::{
 
 impl Database {
     pub(in crate::module) fn _side_quest__get_quests_by_topic(&self, topic: &str) -> rusqlite::Result<Vec<SideQuest>> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         let mut stmt = conn.prepare(
             "SELECT id, title, description, topic, urgency, trigger_date, created_at, completed_at, is_active
              FROM side_quests
-             WHERE topic = ?1 AND is_active = 1
+             WHERE topic = ?1 AND is_active = 1 AND deleted_at IS NULL
              ORDER BY created_at DESC"
         )?;
 
@@ -1,4 +1,4 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 
 use crate::util::database::Database;
 use crate::module::strategies::side_quest::{
@@ -7,14 +7,21 @@ use crate::module::strategies::side_quest::{
 };
 
 impl Database {
-    pub(in crate::module) fn _side_quest__get_quests_with_deadlines(&self, days_ahead: i64) -> rusqlite::Result<Vec<SideQuest>> {
-        let cutoff = (Utc::now() + chrono::Duration::days(days_ahead)).to_rfc3339();
-        let conn = self.app_conn.lock().unwrap();
+    /// Active quests due within `days_ahead` days of `now`, or already overdue; `now` comes from
+    /// the caller's clock so tests can pin it
+    pub(in crate::module) fn _side_quest__get_quests_with_deadlines(
+        &self,
+        now: DateTime<Utc>,
+        days_ahead: i64,
+    ) -> rusqlite::Result<Vec<SideQuest>> {
+        let cutoff = (now + chrono::Duration::days(days_ahead)).to_rfc3339();
+        let conn = Self::connection(&self.app_conn)?;
 
         let mut stmt = conn.prepare(
             "SELECT id, title, description, topic, urgency, trigger_date, created_at, completed_at, is_active
              FROM side_quests
              WHERE is_active = 1
+             AND deleted_at IS NULL
              AND trigger_date IS NOT NULL
              AND trigger_date <= ?1
              ORDER BY trigger_date ASC"
@@ -27,3 +34,30 @@ impl Database {
         quests.collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use crate::module::strategies::side_quest::{database::SideQuestDatabase, QuestUrgency};
+    use crate::util::clock::{Clock, MockClock};
+    use crate::util::test_util::in_memory_database;
+
+    use super::*;
+
+    #[test]
+    fn test_deadline_window_follows_the_clock() {
+        let database = in_memory_database();
+        let clock = MockClock::starting_at(Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap());
+        let soon = clock.utc_now() + chrono::Duration::days(3);
+        let later = clock.utc_now() + chrono::Duration::days(10);
+        database.create_side_quest("Check filters", None, "maintenance", &QuestUrgency::Casual, Some(soon)).unwrap();
+        database.create_side_quest("Drain tank", None, "maintenance", &QuestUrgency::Casual, Some(later)).unwrap();
+
+        let due = database.get_quests_with_deadlines(clock.utc_now(), 7).unwrap();
+        assert_eq!(due.iter().map(|q| q.title.as_str()).collect::<Vec<_>>(), ["Check filters"]);
+
+        clock.advance(std::time::Duration::from_secs(5 * 24 * 3600));
+        assert_eq!(database.get_quests_with_deadlines(clock.utc_now(), 7).unwrap().len(), 2);
+    }
+}
@@ -2,7 +2,7 @@ use crate::util::database::Database;
 
 impl Database {
     pub(in crate::module) fn _side_quest__init_schema(&self) -> rusqlite::Result<()> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
 
         conn.execute(
             "CREATE TABLE IF NOT EXISTS side_quests (
@@ -18,7 +18,8 @@ impl Database {
             )",
             [],
         )?;
+        drop(conn);
 
-        Ok(())
+        self.apply_migrations(&self.app_conn, "side_quest", super::migrations::MIGRATIONS)
     }
 }
@@ -0,0 +1,15 @@
+use crate::util::database::Migration;
+
+/// Side quest schema changes applied on top of `side_quests`. Append only.
+pub(super) const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "index active quests",
+        sql: "CREATE INDEX IF NOT EXISTS idx_side_quests_active ON side_quests(is_active)",
+    },
+    Migration {
+        version: 2,
+        description: "soft-delete quests so deletion can be undone",
+        sql: "ALTER TABLE side_quests ADD COLUMN deleted_at TEXT",
+    },
+];
@@ -1,9 +1,13 @@
 mod trait_side_quest_database;
 mod init_schema;
+mod migrations;
 mod create_side_quest;
 mod get_active_side_quests;
 mod complete_side_quest;
+mod reopen_side_quest;
 mod delete_side_quest;
+mod restore_side_quest;
+mod purge_deleted_side_quests;
 mod get_quests_by_topic;
 mod get_quests_with_deadlines;
 
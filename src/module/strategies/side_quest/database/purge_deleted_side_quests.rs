@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+impl Database {
+    /// Permanently remove quests soft-deleted before `deleted_before`
+    pub(in crate::module) fn _side_quest__purge_deleted_side_quests(&self, deleted_before: DateTime<Utc>) -> rusqlite::Result<usize> {
+        let conn = Self::connection(&self.app_conn)?;
+        conn.execute(
+            "DELETE FROM side_quests WHERE deleted_at IS NOT NULL AND deleted_at < ?1",
+            params![deleted_before.to_rfc3339()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::module::strategies::side_quest::{database::SideQuestDatabase, QuestUrgency};
+    use crate::util::test_util::in_memory_database;
+
+    use super::*;
+
+    #[test]
+    fn test_restore_keeps_completion_and_purge_drops_old_deletes() {
+        let database = in_memory_database();
+        let done = database.create_side_quest("Mend fence", None, "outdoor", &QuestUrgency::Casual, None).unwrap();
+        database.complete_side_quest(done).unwrap();
+        database.delete_side_quest(done).unwrap();
+        database.restore_side_quest(done).unwrap();
+
+        let conn = Database::connection(&database.app_conn).unwrap();
+        let (is_active, deleted_at): (bool, Option<String>) = conn
+            .query_row("SELECT is_active, deleted_at FROM side_quests WHERE id = ?1", [done], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert!(!is_active);
+        assert_eq!(deleted_at, None);
+
+        let open = database.create_side_quest("Bake bread", None, "food", &QuestUrgency::Chill, None).unwrap();
+        database.delete_side_quest(open).unwrap();
+        assert!(database.get_active_side_quests().unwrap().is_empty());
+
+        assert_eq!(database.purge_deleted_side_quests(Utc::now() - Duration::days(1)).unwrap(), 0);
+        assert_eq!(database.purge_deleted_side_quests(Utc::now() + Duration::seconds(1)).unwrap(), 1);
+    }
+}
@@ -0,0 +1,17 @@
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+impl Database {
+    pub(in crate::module) fn _side_quest__reopen_side_quest(&self, quest_id: i64) -> rusqlite::Result<()> {
+        let conn = Self::connection(&self.app_conn)?;
+        conn.execute(
+            "UPDATE side_quests
+             SET completed_at = NULL, is_active = 1
+             WHERE id = ?1",
+            params![quest_id],
+        )?;
+
+        Ok(())
+    }
+}
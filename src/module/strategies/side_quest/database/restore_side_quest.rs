@@ -0,0 +1,18 @@
+use rusqlite::params;
+
+use crate::util::database::Database;
+
+impl Database {
+    /// Undo a soft delete; the quest comes back open or completed, as it was deleted
+    pub(in crate::module) fn _side_quest__restore_side_quest(&self, quest_id: i64) -> rusqlite::Result<()> {
+        let conn = Self::connection(&self.app_conn)?;
+        conn.execute(
+            "UPDATE side_quests
+             SET deleted_at = NULL
+             WHERE id = ?1",
+            params![quest_id],
+        )?;
+
+        Ok(())
+    }
+}
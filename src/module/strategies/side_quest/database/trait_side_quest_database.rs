@@ -21,12 +21,15 @@ pub trait SideQuestDatabase {
     ) -> rusqlite::Result<i64>;
 
     fn complete_side_quest(&self, quest_id: i64) -> rusqlite::Result<()>;
+    fn reopen_side_quest(&self, quest_id: i64) -> rusqlite::Result<()>;
     fn delete_side_quest(&self, quest_id: i64) -> rusqlite::Result<()>;
+    fn restore_side_quest(&self, quest_id: i64) -> rusqlite::Result<()>;
+    fn purge_deleted_side_quests(&self, deleted_before: DateTime<Utc>) -> rusqlite::Result<usize>;
 
     // Quest queries
     fn get_active_side_quests(&self) -> rusqlite::Result<Vec<SideQuest>>;
     fn get_quests_by_topic(&self, topic: &str) -> rusqlite::Result<Vec<SideQuest>>;
-    fn get_quests_with_deadlines(&self, days_ahead: i64) -> rusqlite::Result<Vec<SideQuest>>;
+    fn get_quests_with_deadlines(&self, now: DateTime<Utc>, days_ahead: i64) -> rusqlite::Result<Vec<SideQuest>>;
 }
 
 impl SideQuestDatabase for Database {
@@ -49,10 +52,22 @@ impl SideQuestDatabase for Database {
         self._side_quest__complete_side_quest(quest_id)
     }
 
+    fn reopen_side_quest(&self, quest_id: i64) -> rusqlite::Result<()> {
+        self._side_quest__reopen_side_quest(quest_id)
+    }
+
     fn delete_side_quest(&self, quest_id: i64) -> rusqlite::Result<()> {
         self._side_quest__delete_side_quest(quest_id)
     }
 
+    fn restore_side_quest(&self, quest_id: i64) -> rusqlite::Result<()> {
+        self._side_quest__restore_side_quest(quest_id)
+    }
+
+    fn purge_deleted_side_quests(&self, deleted_before: DateTime<Utc>) -> rusqlite::Result<usize> {
+        self._side_quest__purge_deleted_side_quests(deleted_before)
+    }
+
     fn get_active_side_quests(&self) -> rusqlite::Result<Vec<SideQuest>> {
         self._side_quest__get_active_side_quests()
     }
@@ -61,7 +76,7 @@ impl SideQuestDatabase for Database {
         self._side_quest__get_quests_by_topic(topic)
     }
 
-    fn get_quests_with_deadlines(&self, days_ahead: i64) -> rusqlite::Result<Vec<SideQuest>> {
-       self._side_quest__get_quests_with_deadlines(days_ahead)
+    fn get_quests_with_deadlines(&self, now: DateTime<Utc>, days_ahead: i64) -> rusqlite::Result<Vec<SideQuest>> {
+       self._side_quest__get_quests_with_deadlines(now, days_ahead)
     }
 }
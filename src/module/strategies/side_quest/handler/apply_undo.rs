@@ -0,0 +1,23 @@
+use color_eyre::Result;
+
+use crate::runtime::undo::UndoDirection;
+
+use super::{SideQuestDatabase, SideQuestHandler, UndoOp};
+
+impl SideQuestHandler {
+    /// Reopen or re-complete, restore or re-delete a quest
+    pub(in crate::module) fn _apply_undo(&mut self, op: &serde_json::Value, direction: UndoDirection) -> Result<()> {
+        let undo_op: UndoOp = serde_json::from_value(op.clone())?;
+        let undo = direction == UndoDirection::Undo;
+        match undo_op {
+            UndoOp::Complete { quest_id } if undo => self.database.reopen_side_quest(quest_id)?,
+            UndoOp::Complete { quest_id } => self.database.complete_side_quest(quest_id)?,
+            UndoOp::Delete { quest_id } if undo => self.database.restore_side_quest(quest_id)?,
+            UndoOp::Delete { quest_id } => self.database.delete_side_quest(quest_id)?,
+        }
+
+        self.load_quests();
+        self.selected_index = self.selected_index.min(self.quests.len().saturating_sub(1));
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{Duration, Utc};
+use chrono::Duration;
 use crossterm::event::KeyCode;
 
 use crate::util::io::event::AppEvent;
@@ -22,7 +22,6 @@ impl SideQuestHandler {
                     }
                     KeyCode::Enter if !self.form_title.is_empty() => {
                         self.create_step = CreateStep::Description;
-                        self.status_message = Some("Enter description (optional, Enter to skip)...".to_string());
                         None
                     }
                     KeyCode::Esc => {
@@ -44,7 +43,6 @@ impl SideQuestHandler {
                     }
                     KeyCode::Enter => {
                         self.create_step = CreateStep::Topic;
-                        self.status_message = Some("Select topic...".to_string());
                         None
                     }
                     KeyCode::Esc => {
@@ -73,7 +71,6 @@ impl SideQuestHandler {
                             self.form_topic = topic.clone();
                             self.create_step = CreateStep::Urgency;
                             self.selected_index = 1; // Default to Casual
-                            self.status_message = Some("Select urgency...".to_string());
                         }
                         None
                     }
@@ -103,7 +100,6 @@ impl SideQuestHandler {
                         if let Some(urgency) = urgencies.get(self.selected_index) {
                             self.form_urgency = urgency.clone();
                             self.create_step = CreateStep::TriggerDate;
-                            self.status_message = Some("Set trigger date (Enter to skip)...".to_string());
                         }
                         None
                     }
@@ -118,19 +114,19 @@ impl SideQuestHandler {
                 match key_code {
                     KeyCode::Char('1') => {
                         // 1 week from now
-                        self.form_trigger_date = Some(Utc::now() + Duration::weeks(1));
+                        self.form_trigger_date = Some(self.clock.utc_now() + Duration::weeks(1));
                         self.create_step = CreateStep::Confirm;
                         None
                     }
                     KeyCode::Char('2') => {
                         // 1 month from now
-                        self.form_trigger_date = Some(Utc::now() + Duration::weeks(4));
+                        self.form_trigger_date = Some(self.clock.utc_now() + Duration::weeks(4));
                         self.create_step = CreateStep::Confirm;
                         None
                     }
                     KeyCode::Char('3') => {
                         // 3 months from now
-                        self.form_trigger_date = Some(Utc::now() + Duration::weeks(12));
+                        self.form_trigger_date = Some(self.clock.utc_now() + Duration::weeks(12));
                         self.create_step = CreateStep::Confirm;
                         None
                     }
@@ -1,9 +1,13 @@
 use crossterm::event::KeyCode;
 
+use crate::runtime::{
+    toast::{notify, ToastLevel},
+    undo,
+};
 use crate::util::io::event::AppEvent;
 use crate::module::strategies::side_quest::{
     database::SideQuestDatabase,
-    handler::{SideQuestHandler, SideQuestView},
+    handler::{SideQuestHandler, SideQuestView, UndoOp},
 };
 
 impl SideQuestHandler {
@@ -17,10 +21,11 @@ impl SideQuestHandler {
                 // Complete quest from detail view
                 if let Some(quest) = self.quests.get_mut(self.selected_index) {
                     if let Err(e) = self.database.complete_side_quest(quest.id) {
-                        self.status_message = Some(format!("Error: {}", e));
+                        notify(ToastLevel::Error, format!("Error: {}", e));
                     } else {
                         quest.complete();
-                        self.status_message = Some(format!("✓ Completed: {}", quest.title));
+                        notify(ToastLevel::Success, format!("✓ Completed: {}", quest.title));
+                        undo::record("side_quest", format!("complete {}", quest.title), &UndoOp::Complete { quest_id: quest.id });
                         self.current_view = SideQuestView::QuestList;
                         self.load_quests();
                     }
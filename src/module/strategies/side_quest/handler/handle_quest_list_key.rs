@@ -1,12 +1,17 @@
 use crossterm::event::KeyCode;
 
+use crate::runtime::{
+    toast::{notify, ToastLevel},
+    undo,
+};
 use crate::util::io::event::AppEvent;
 use crate::module::strategies::side_quest::{
     database::SideQuestDatabase,
     handler::{
         CreateStep,
         SideQuestHandler,
-        SideQuestView
+        SideQuestView,
+        UndoOp,
     }
 };
 
@@ -30,17 +35,17 @@ impl SideQuestHandler {
                 self.current_view = SideQuestView::CreateQuest;
                 self.create_step = CreateStep::Title;
                 self.reset_form();
-                self.status_message = Some("Enter quest title...".to_string());
                 None
             }
             KeyCode::Char('c') => {
                 // Complete selected quest
                 if let Some(quest) = self.quests.get_mut(self.selected_index) {
                     if let Err(e) = self.database.complete_side_quest(quest.id) {
-                        self.status_message = Some(format!("Error: {}", e));
+                        notify(ToastLevel::Error, format!("Error: {}", e));
                     } else {
                         quest.complete();
-                        self.status_message = Some(format!("✓ Completed: {}", quest.title));
+                        notify(ToastLevel::Success, format!("✓ Completed: {}", quest.title));
+                        undo::record("side_quest", format!("complete {}", quest.title), &UndoOp::Complete { quest_id: quest.id });
                         self.load_quests();
                     }
                 }
@@ -50,9 +55,10 @@ impl SideQuestHandler {
                 // Delete selected quest
                 if let Some(quest) = self.quests.get(self.selected_index) {
                     if let Err(e) = self.database.delete_side_quest(quest.id) {
-                        self.status_message = Some(format!("Error: {}", e));
+                        notify(ToastLevel::Error, format!("Error: {}", e));
                     } else {
-                        self.status_message = Some(format!("Deleted: {}", quest.title));
+                        notify(ToastLevel::Success, format!("Deleted: {} - Ctrl+Z to undo", quest.title));
+                        undo::record("side_quest", format!("delete {}", quest.title), &UndoOp::Delete { quest_id: quest.id });
                         self.load_quests();
                         if self.selected_index > 0 {
                             self.selected_index -= 1;
@@ -0,0 +1,67 @@
+use crossterm::event::KeyCode;
+
+use crate::module::strategies::side_quest::handler::{CreateStep, SideQuestHandler, SideQuestView};
+
+impl SideQuestHandler {
+    /// Keys usable in the current view and form step, shown as the card's footer and in the `?` overlay
+    pub(in crate::module) fn _key_hints(&self) -> Vec<(KeyCode, &'static str)> {
+        match (&self.current_view, &self.create_step) {
+            (SideQuestView::QuestList, _) => vec![
+                (KeyCode::Up, "Select"),
+                (KeyCode::Down, "Select"),
+                (KeyCode::Enter, "Details"),
+                (KeyCode::Char('n'), "New"),
+                (KeyCode::Char('c'), "Complete"),
+                (KeyCode::Char('d'), "Delete"),
+            ],
+            (SideQuestView::QuestDetail, _) => vec![
+                (KeyCode::Char('c'), "Complete"),
+                (KeyCode::Esc, "Back"),
+            ],
+            (SideQuestView::CreateQuest, CreateStep::Title | CreateStep::Description) => vec![
+                (KeyCode::Enter, "Next"),
+                (KeyCode::Esc, "Cancel"),
+            ],
+            (SideQuestView::CreateQuest, CreateStep::Topic | CreateStep::Urgency) => vec![
+                (KeyCode::Up, "Select"),
+                (KeyCode::Down, "Select"),
+                (KeyCode::Enter, "Next"),
+                (KeyCode::Esc, "Cancel"),
+            ],
+            (SideQuestView::CreateQuest, CreateStep::TriggerDate) => vec![
+                (KeyCode::Char('1'), "1 Week"),
+                (KeyCode::Char('2'), "1 Month"),
+                (KeyCode::Char('3'), "3 Months"),
+                (KeyCode::Enter, "No Deadline"),
+                (KeyCode::Esc, "Cancel"),
+            ],
+            (SideQuestView::CreateQuest, CreateStep::Confirm) => vec![
+                (KeyCode::Char('y'), "Save"),
+                (KeyCode::Char('n'), "Cancel"),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyCode;
+
+    use crate::module::strategies::side_quest::handler::SideQuestHandler;
+    use crate::module::ModuleHandler;
+    use crate::util::test_util::{in_memory_database, loopback_bus, TestModule};
+
+    #[test]
+    fn test_hints_follow_the_create_form() {
+        let (bus, _receiver) = loopback_bus();
+        let mut handler = SideQuestHandler::new(in_memory_database(), bus);
+        let mut module = TestModule::new("Quests", "side_quest", "side_quest_card").build();
+        assert!(handler.key_hints().contains(&(KeyCode::Char('n'), "New")));
+
+        handler.handle_key(KeyCode::Char('n'), &mut module);
+        assert_eq!(handler.key_hints(), vec![(KeyCode::Enter, "Next"), (KeyCode::Esc, "Cancel")]);
+
+        handler.handle_key(KeyCode::Esc, &mut module);
+        assert!(handler.key_hints().contains(&(KeyCode::Char('n'), "New")));
+    }
+}
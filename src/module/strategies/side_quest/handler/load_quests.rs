@@ -1,4 +1,5 @@
 use crate::{log_error, log_info};
+use crate::runtime::toast::{notify, ToastLevel};
 
 use super::{SideQuestHandler,SideQuestDatabase};
 
@@ -11,7 +12,7 @@ impl SideQuestHandler {
             }
             Err(e) => {
                 log_error!("Failed to load side quests: {}", e);
-                self.status_message = Some(format!("Error loading quests: {}", e));
+                notify(ToastLevel::Error, format!("Error loading quests: {}", e));
             }
         }
     }
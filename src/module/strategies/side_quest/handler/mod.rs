@@ -5,26 +5,39 @@ mod load_quests;
 mod handle_quest_list_key;
 mod handle_create_quest_key;
 mod handle_detail_key;
+mod key_hints;
 mod reset_form;
 mod save_quest;
 mod publish_calendar_event;
+mod apply_undo;
 
 use color_eyre::Result;
 use ratatui::crossterm::event::KeyCode;
 use std::any::Any;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 use crate::module::{
     trait_module_handler::ModuleHandler,
     Module,
+    QuickAction,
 };
+use crate::runtime::undo::UndoDirection;
 use crate::util::{
+    clock::SharedClock,
     database::Database,
     io::{bus::MessageBus, event::AppEvent},
 };
 use crate::module::strategies::side_quest::database::SideQuestDatabase;
 use super::{QuestUrgency, SideQuest};
 
+/// Registry key of this handler, for tasks that report failures under it
+const HANDLER_KEY: &str = "side_quest";
+
+/// Soft-deleted quests are purged on startup once deleted this many days ago; undo history
+/// doesn't outlive the session, so this only bounds how long they can be recovered by hand
+const DELETED_QUEST_RETENTION_DAYS: i64 = 7;
+
 #[derive(Debug, Clone, PartialEq)]
 enum SideQuestView {
     QuestList,      // Main list of quests
@@ -32,6 +45,14 @@ enum SideQuestView {
     QuestDetail,    // View individual quest details
 }
 
+/// Quest changes recorded for Ctrl+Z / Ctrl+Y
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum UndoOp {
+    Complete { quest_id: i64 },
+    Delete { quest_id: i64 },
+}
+
 #[derive(Debug, Clone, PartialEq)]
 enum CreateStep {
     Title,
@@ -61,7 +82,7 @@ pub struct SideQuestHandler {
     // Available topics (could be from DB later)
     available_topics: Vec<String>,
 
-    status_message: Option<String>,
+    clock: SharedClock,
 }
 
 
@@ -78,6 +99,11 @@ impl ModuleHandler for SideQuestHandler {
         Ok(false)
     }
 
+    fn reload(&mut self, _module: &mut Module) {
+        self.load_quests();
+        self.selected_index = self.selected_index.min(self.quests.len().saturating_sub(1));
+    }
+
     fn update_bindings(&mut self, module: &mut Module) {
         // Update view state
         module.config.bindings.insert(
@@ -178,19 +204,6 @@ impl ModuleHandler for SideQuestHandler {
                 serde_json::json!(trigger_str),
             );
         }
-
-        // Status message
-        if let Some(status) = &self.status_message {
-            module.config.bindings.insert(
-                "status_message".to_string(),
-                serde_json::json!(status),
-            );
-        } else {
-            module.config.bindings.insert(
-                "status_message".to_string(),
-                serde_json::json!(""),
-            );
-        }
     }
 
     fn module_type(&self) -> &str {
@@ -204,4 +217,18 @@ impl ModuleHandler for SideQuestHandler {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn key_hints(&self) -> Vec<(KeyCode, &str)> {
+        self._key_hints()
+    }
+
+    fn quick_actions(&self) -> &'static [QuickAction] {
+        &[
+            QuickAction { trigger: 'n', key: Some(KeyCode::Char('n')), label: "New Quest", opens_detail: true },
+        ]
+    }
+
+    fn apply_undo(&mut self, op: &mut serde_json::Value, direction: UndoDirection) -> Result<()> {
+        self._apply_undo(op, direction)
+    }
 }
@@ -1,14 +1,27 @@
+use crate::log_warn;
 use crate::util::{
+    clock::{system_clock, SharedClock},
     database::Database,
     io::bus::MessageBus,
 };
 use crate::module::strategies::side_quest::{
-    handler::{CreateStep, SideQuestHandler, SideQuestView},
+    database::SideQuestDatabase,
+    handler::{CreateStep, SideQuestHandler, SideQuestView, DELETED_QUEST_RETENTION_DAYS, HANDLER_KEY},
     QuestUrgency
 };
 
 impl SideQuestHandler {
     pub fn new(database: Database, message_bus: MessageBus) -> Self {
+        Self::with_clock(database, message_bus, system_clock())
+    }
+
+    /// `new` with an injected clock, so deadline presets are deterministic in tests
+    pub fn with_clock(database: Database, message_bus: MessageBus, clock: SharedClock) -> Self {
+        let cutoff = clock.utc_now() - chrono::Duration::days(DELETED_QUEST_RETENTION_DAYS);
+        if let Err(e) = database.purge_deleted_side_quests(cutoff) {
+            log_warn!(target: HANDLER_KEY, "Failed to purge deleted side quests: {}", e);
+        }
+
         let mut handler = Self {
             current_view: SideQuestView::QuestList,
             selected_index: 0,
@@ -31,7 +44,7 @@ impl SideQuestHandler {
                 "adventure".to_string(),
                 "hobby".to_string(),
             ],
-            status_message: None,
+            clock,
         };
 
         // Load quests from database
@@ -1,7 +1,8 @@
 use chrono::{DateTime, Utc};
 
 use crate::log_info;
-use crate::module::strategies::side_quest::handler::SideQuestHandler;
+use crate::module::strategies::side_quest::handler::{SideQuestHandler, HANDLER_KEY};
+use crate::runtime::isolation::spawn_isolated;
 
 impl SideQuestHandler {
     pub(in crate::module) fn publish_calendar_event(&self, quest_id: i64, trigger_date: DateTime<Utc>) {
@@ -20,7 +21,7 @@ impl SideQuestHandler {
         );
 
         let bus = self.message_bus.clone();
-        tokio::spawn(async move {
+        spawn_isolated(HANDLER_KEY, async move {
             let _ = bus.publish(message).await;
         });
 
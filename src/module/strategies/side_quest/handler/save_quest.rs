@@ -1,4 +1,5 @@
 use crate::log_error;
+use crate::runtime::toast::{notify, ToastLevel};
 use crate::module::strategies::side_quest::{
     database::SideQuestDatabase,
     handler::{SideQuestHandler, SideQuestView},
@@ -20,7 +21,7 @@ impl SideQuestHandler {
             self.form_trigger_date,
         ) {
             Ok(quest_id) => {
-                self.status_message = Some(format!("✓ Created: {}", self.form_title));
+                notify(ToastLevel::Success, format!("✓ Created: {}", self.form_title));
 
                 // Publish calendar event if trigger date exists
                 if let Some(date) = self.form_trigger_date {
@@ -33,7 +34,7 @@ impl SideQuestHandler {
             }
             Err(e) => {
                 log_error!("Failed to create quest: {}", e);
-                self.status_message = Some(format!("Error: {}", e));
+                notify(ToastLevel::Error, format!("Error: {}", e));
             }
         }
     }
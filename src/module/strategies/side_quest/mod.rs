@@ -57,8 +57,6 @@ pub struct SideQuestBindings {
     pub selected_quest_urgency: String,
     pub selected_quest_trigger: String,
 
-    #[serde(default)]
-    pub status_message: Option<String>,
     #[serde(default)]
     pub is_blinkable: Option<bool>,
 }
@@ -2,6 +2,7 @@ use crossterm::event::KeyCode;
 
 use crate::{
     module::Module,
+    runtime::audit::AuditTrigger,
     util::io::event::AppEvent,
 };
 use crate::module::strategies::valve_control::handler::ValveControlHandler;
@@ -10,18 +11,18 @@ impl ValveControlHandler {
     pub(in crate::module) fn _handle_key(&mut self, key_code: KeyCode, _module: &mut Module) -> Option<AppEvent> {
         match key_code {
             KeyCode::Enter | KeyCode::Char(' ') => {
-                self.toggle_valve();
+                self.toggle_valve(AuditTrigger::ManualKey);
                 None
             }
             KeyCode::Char('o') => {
                 if !self.current_state {
-                    self.toggle_valve();
+                    self.toggle_valve(AuditTrigger::ManualKey);
                 }
                 None
             }
             KeyCode::Char('c') => {
                 if self.current_state {
-                    self.toggle_valve();
+                    self.toggle_valve(AuditTrigger::ManualKey);
                 }
                 None
             }
@@ -0,0 +1,15 @@
+use crossterm::event::KeyCode;
+
+use crate::module::strategies::valve_control::handler::ValveControlHandler;
+
+impl ValveControlHandler {
+    /// Keys usable in the detail view, shown as the card's footer and in the `?` overlay.
+    /// Only the key that changes the valve's state is offered.
+    pub(in crate::module) fn _key_hints(&self) -> Vec<(KeyCode, &'static str)> {
+        let change = match self.current_state {
+            true => (KeyCode::Char('c'), "Close"),
+            false => (KeyCode::Char('o'), "Open"),
+        };
+        vec![(KeyCode::Char(' '), "Toggle"), change]
+    }
+}
@@ -1,24 +1,31 @@
 mod new;
 mod start_telemetry_listener;
+mod start_delivery_listener;
 mod process_messages;
 mod toggle_valve;
 mod is_in_cmd_window;
 mod handle_key;
+mod key_hints;
 mod update_bindings;
 mod trait_module_handler;
 
+use chrono::{DateTime, Utc};
 use std::any::Any;
 use tokio::sync::mpsc;
 
 use crate::{
-    module::{trait_module_handler::ModuleHandler},
+    module::{trait_module_handler::ModuleHandler, LastError},
     util::io::bus::{MessageBus},
 };
 
+/// Log target shared by every valve handler
+const LOG_TARGET: &str = "valve_control";
+/// Source of the commands this handler publishes directly on the bus
+const COMMAND_SOURCE: &str = "valve_control_handler";
+
 #[derive(Debug, Clone)]
 enum HandlerMessage {
     StateChanged(bool),
-    StatusUpdate(String),
     TelemetryReceived {
         valve_open: bool,
         sensor_value: f64
@@ -35,7 +42,6 @@ enum HandlerMessage {
 pub struct ValveControlHandler {
     current_state: bool,  // true = open, false = closed
     target_state: bool,   // What state we're trying to achieve
-    status_message: Option<String>,
     message_bus: MessageBus,
     device_id: String,
     message_tx: mpsc::UnboundedSender<HandlerMessage>,
@@ -43,5 +49,10 @@ pub struct ValveControlHandler {
     current_mode: Option<String>,
     cmd_window_opens_in: Option<u64>,
     cmd_window_duration: Option<u64>,
+    /// When the device last reported state, telemetry or schedule
+    last_updated: Option<DateTime<Utc>>,
     discovery_manager: Option<std::sync::Arc<crate::util::io::discovery::DiscoveryManager>>,
+    last_error: LastError,
+    /// `valve_control_<device_id>`, which its background tasks are supervised and isolated under
+    handler_key: String,
 }
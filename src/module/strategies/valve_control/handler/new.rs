@@ -1,5 +1,6 @@
 use tokio::sync::mpsc;
 
+use crate::module::LastError;
 use crate::util::io::{
     bus::MessageBus,
     discovery::DiscoveryManager,
@@ -19,7 +20,6 @@ impl ValveControlHandler {
         let mut handler = Self {
             current_state: false,
             target_state: false,
-            status_message: None,
             message_bus: message_bus.clone(),
             device_id: device_id.clone(),
             message_tx,
@@ -27,10 +27,14 @@ impl ValveControlHandler {
             current_mode: None,
             cmd_window_opens_in: None,
             cmd_window_duration: None,
+            last_updated: None,
             discovery_manager,
+            last_error: LastError::default(),
+            handler_key: format!("valve_control_{}", device_id),
         };
 
         handler.start_telemetry_listener(bus_topic);
+        handler.start_delivery_listener();
 
         handler
     }
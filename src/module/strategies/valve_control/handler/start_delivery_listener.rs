@@ -0,0 +1,47 @@
+use crate::{
+    log_warn,
+    runtime::{
+        isolation::spawn_isolated,
+        toast::{notify, ToastLevel},
+    },
+    util::io::retry::{DeliveryResult, DELIVERY_RESULT_TOPIC},
+};
+
+use super::{ValveControlHandler, COMMAND_SOURCE, LOG_TARGET};
+
+impl ValveControlHandler {
+    /// Surface commands the transport or BLE scheduler gave up on after exhausting its retries
+    pub(in crate::module) fn start_delivery_listener(&mut self) {
+        let bus = self.message_bus.clone();
+        let device_id = self.device_id.clone();
+        let last_error = self.last_error.clone();
+
+        spawn_isolated(&self.handler_key, async move {
+            let mut receiver = bus
+                .subscribe_filtered(DELIVERY_RESULT_TOPIC.to_string(), |msg| {
+                    msg.source == "transport_manager" || msg.source == "ble_scheduler"
+                })
+                .await;
+
+            while let Some(msg) = receiver.recv().await {
+                let Ok(result) = serde_json::from_str::<DeliveryResult>(&msg.payload) else {
+                    continue;
+                };
+                // The scheduler only sends what `send_command` queued, which for this device is us
+                let ours = result.source == COMMAND_SOURCE || result.source == "ble_scheduler";
+                if !ours || result.device_id != device_id || result.delivered {
+                    continue;
+                }
+
+                let error = format!(
+                    "Command failed after {} tries: {}",
+                    result.attempts,
+                    result.error.unwrap_or_default()
+                );
+                log_warn!(target: LOG_TARGET, "🚰 [{}] {}", device_id, error);
+                last_error.set(error.clone());
+                notify(ToastLevel::Error, format!("❌ {}", error));
+            }
+        });
+    }
+}
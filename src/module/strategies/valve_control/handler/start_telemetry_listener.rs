@@ -1,8 +1,9 @@
-use crate::{log_debug, log_info};
+use crate::{log_debug, log_info, log_warn};
+use crate::runtime::watchdog;
 
-use crate::util::io::ble_scheduler::extract_schedule_metadata;
+use crate::util::io::ssp;
 
-use super::{HandlerMessage, ValveControlHandler};
+use super::{HandlerMessage, ValveControlHandler, LOG_TARGET};
 
 impl ValveControlHandler {
     pub(in crate::module) fn start_telemetry_listener(&mut self, bus_topic: String) {
@@ -10,21 +11,28 @@ impl ValveControlHandler {
         let bus = self.message_bus.clone();
         let device_id = self.device_id.clone();
 
-        tokio::spawn(async move {
-            log_info!("🚰 Starting valve telemetry listener for topic: {}", bus_topic);
-            let mut receiver = bus.subscribe(bus_topic).await;
-
-            while let Some(msg) = receiver.recv().await {
-                log_debug!("Received valve telemetry message");
-
-                if let Ok(data) = serde_json::from_str::<serde_json::Value>(&msg.payload) {
-                    log_debug!("Received message payload: {}", msg.payload);
-
-                    // ========================================
-                    // NEW: Extract schedule metadata FIRST
-                    // ========================================
-                    if let Some(metadata) = extract_schedule_metadata(&data) {
-                        log_info!("📅 [{}] Valve schedule metadata found!", device_id);
+        // Restarted by the watchdog if it exits or wedges, so each start clones what it needs
+        watchdog::supervise(&self.handler_key, move |heartbeat| {
+            let (tx, bus, device_id, bus_topic) = (tx.clone(), bus.clone(), device_id.clone(), bus_topic.clone());
+            async move {
+                log_info!(target: LOG_TARGET, "🚰 Starting valve telemetry listener for topic: {}", bus_topic);
+                // The valve may report under another id than its device_id; the topic is what's its own
+                let mut receiver = bus.subscribe(bus_topic).await;
+
+                while let Some(msg) = heartbeat.idle(receiver.recv()).await {
+                    log_debug!(target: LOG_TARGET, "Received valve telemetry message");
+
+                    log_debug!(target: LOG_TARGET, "Received message payload: {}", msg.payload);
+                    let frame = match ssp::decode(&msg.payload) {
+                        Ok(frame) => frame,
+                        Err(e) => {
+                            log_warn!(target: LOG_TARGET, "🚰 [{}] Rejected valve telemetry: {}", device_id, e);
+                            continue;
+                        }
+                    };
+
+                    if let Some(metadata) = &frame.schedule {
+                        log_info!(target: LOG_TARGET, "📅 [{}] Valve schedule metadata found!", device_id);
 
                         let mode = metadata.get("mode")
                             .and_then(|v| v.as_str())
@@ -39,7 +47,7 @@ impl ValveControlHandler {
                             .and_then(|v| v.as_u64())
                             .unwrap_or(10);
 
-                        log_info!("📅 [{}] Mode: {}, CMD window in: {}s", device_id, mode, cmd_in);
+                        log_info!(target: LOG_TARGET, "📅 [{}] Mode: {}, CMD window in: {}s", device_id, mode, cmd_in);
 
                         let _ = tx.send(HandlerMessage::ScheduleUpdate {
                             mode,
@@ -48,21 +56,12 @@ impl ValveControlHandler {
                         });
                     }
 
-                    // ========================================
-                    // EXISTING: Extract valve state
-                    // ========================================
-                    // SSP Compact Format: {"p":"ssp/1.0","t":"tel","i":"v01","d":{"a":1,"b":95,"c":123}}
-                    if let Some(d) = data.get("d").and_then(|v| v.as_object()) {
-                        let valve_open = d.get("a")
-                            .and_then(|v| v.as_i64())
-                            .map(|i| i != 0)
-                            .unwrap_or(false);
-
-                        let sensor_value = d.get("b")
-                            .and_then(|v| v.as_f64())
-                            .unwrap_or(0.0);
+                    // `a` is the valve state (non-zero is open), `b` its position
+                    if frame.a.is_some() || frame.b.is_some() {
+                        let valve_open = frame.a.is_some_and(|a| a != 0.0);
+                        let sensor_value = frame.b.unwrap_or(0.0);
 
-                        log_info!("🚰 Valve telemetry: open={}, position={}%", valve_open, sensor_value);
+                        log_info!(target: LOG_TARGET, "🚰 Valve telemetry: open={}, position={}%", valve_open, sensor_value);
 
                         let _ = tx.send(HandlerMessage::TelemetryReceived {
                             valve_open,
@@ -1,31 +1,66 @@
 use crate::{
     log_error,
     log_info,
+    log_warn,
+    runtime::{
+        audit::{self, AuditOutcome, AuditTrigger, CommandOrigin},
+        dry_run::{is_dry_run, report_intended_action},
+        isolation::spawn_isolated,
+        safe_mode::is_safe_mode,
+        toast::{notify, ToastLevel},
+    },
     util::io::{
         ble_scheduler::CommandPriority,
         bus::BusMessage,
+        transport::DEVICE_COMMAND_TOPIC,
     }
 };
 
-use super::{HandlerMessage, ValveControlHandler};
+use super::{ValveControlHandler, COMMAND_SOURCE, LOG_TARGET};
 
 impl ValveControlHandler {
-    pub(in crate::module) fn toggle_valve(&mut self) {
+    /// Open a closed valve or close an open one. Refusals and dry runs are audited here; a command that
+    /// goes out carries its origin so the scheduler or transport audits it once delivered.
+    pub(in crate::module) fn toggle_valve(&mut self, trigger: AuditTrigger) {
         let new_state = !self.current_state;
-        self.target_state = new_state;
-
         let action = if new_state { "open" } else { "close" };
 
+        if is_safe_mode() {
+            log_warn!(target: LOG_TARGET, "🛑 Safe mode: refused to {} valve {}", action, self.device_id);
+            let state = if self.current_state { "open" } else { "closed" };
+            notify(ToastLevel::Warning, format!("🛑 Safe mode: valve stays {}", state));
+            audit::record(LOG_TARGET, trigger, &self.device_id, action, AuditOutcome::Refused, Some("safe mode".to_string()));
+            return;
+        }
+
+        if is_dry_run() {
+            notify(ToastLevel::Info, format!("🧪 Dry run: would {} valve", action));
+            audit::record(LOG_TARGET, trigger, &self.device_id, action, AuditOutcome::DryRun, None);
+
+            let bus = self.message_bus.clone();
+            let device_id = self.device_id.clone();
+
+            spawn_isolated(&self.handler_key, async move {
+                let payload = serde_json::json!({ "action": action });
+                if let Err(e) = report_intended_action(&bus, COMMAND_SOURCE, &device_id, "valve_control", Some(&payload)).await {
+                    log_error!(target: LOG_TARGET, "Failed to publish dry-run valve action: {}", e);
+                }
+            });
+            return;
+        }
+
+        self.target_state = new_state;
+
         if let Some(discovery) = &self.discovery_manager {
-            self.status_message = Some(format!("⏳ Queueing {} command...", action));
+            notify(ToastLevel::Info, format!("⏳ Queueing {} command...", action));
 
-            log_info!("🚰 Queueing valve {} command via scheduler", action);
+            log_info!(target: LOG_TARGET, "🚰 Queueing valve {} command via scheduler", action);
 
             let discovery_clone = discovery.clone();
             let device_id = self.device_id.clone();
-            let tx = self.message_tx.clone();
+            let last_error = self.last_error.clone();
 
-            tokio::spawn(async move {
+            spawn_isolated(&self.handler_key, async move {
                 let payload = serde_json::json!({
                     "action": if new_state { "open" } else { "close" }
                 });
@@ -35,31 +70,30 @@ impl ValveControlHandler {
                     "valve_control",
                     Some(payload),
                     CommandPriority::High,  // Valve control is HIGH priority
+                    CommandOrigin::new(LOG_TARGET, trigger),
                 ).await {
                     Ok(_) => {
-                        log_info!("✓ Valve command queued");
-                        let _ = tx.send(HandlerMessage::StatusUpdate(
-                            "Command queued, will send during CMD window".to_string()
-                        ));
+                        log_info!(target: LOG_TARGET, "✓ Valve command queued");
+                        last_error.clear();
+                        notify(ToastLevel::Info, "Command queued, will send during CMD window");
                     }
                     Err(e) => {
-                        log_error!("Failed to queue valve command: {}", e);
-                        let _ = tx.send(HandlerMessage::StatusUpdate(
-                            format!("❌ Failed to queue command: {}", e)
-                        ));
+                        log_error!(target: LOG_TARGET, "Failed to queue valve command: {}", e);
+                        last_error.set(format!("Failed to queue valve command: {}", e));
+                        notify(ToastLevel::Error, format!("❌ Failed to queue command: {}", e));
                     }
                 }
             });
         } else {
-            self.status_message = Some(format!("⏳ Sending {} command...", action));
+            notify(ToastLevel::Info, format!("⏳ Sending {} command...", action));
 
-            log_info!("🚰 Sending valve {} command directly (no scheduler)", action);
+            log_info!(target: LOG_TARGET, "🚰 Sending valve {} command directly (no scheduler)", action);
 
-            let tx = self.message_tx.clone();
             let bus = self.message_bus.clone();
             let device_id = self.device_id.clone();
+            let last_error = self.last_error.clone();
 
-            tokio::spawn(async move {
+            spawn_isolated(&self.handler_key, async move {
                 let command = serde_json::json!({
                     "p": "ssp/1.0",
                     "t": "cmd",
@@ -71,23 +105,23 @@ impl ValveControlHandler {
                 });
 
                 let bus_msg = BusMessage::new(
-                    device_id.clone(),
+                    DEVICE_COMMAND_TOPIC.to_string(),
                     command.to_string(),
-                    "valve_control_handler".to_string(),
-                );
+                    COMMAND_SOURCE.to_string(),
+                ).with_origin(CommandOrigin::new(LOG_TARGET, trigger));
 
                 match bus.publish(bus_msg).await {
                     Ok(_) => {
-                        log_info!("✓ Valve command published");
-                        let _ = tx.send(HandlerMessage::StatusUpdate(
-                            "Command sent, waiting for response...".to_string()
-                        ));
+                        log_info!(target: LOG_TARGET, "✓ Valve command published");
+                        last_error.clear();
+                        notify(ToastLevel::Info, "Command sent, waiting for response...");
                     }
                     Err(e) => {
-                        log_error!("Failed to publish valve command: {}", e);
-                        let _ = tx.send(HandlerMessage::StatusUpdate(
-                            format!("❌ Failed to send command: {}", e)
-                        ));
+                        // Never reached the transport, so nothing else will audit it
+                        log_error!(target: LOG_TARGET, "Failed to publish valve command: {}", e);
+                        audit::record(LOG_TARGET, trigger, &device_id, action, AuditOutcome::Failed, Some(e.to_string()));
+                        last_error.set(format!("Failed to publish valve command: {}", e));
+                        notify(ToastLevel::Error, format!("❌ Failed to send command: {}", e));
                     }
                 }
             });
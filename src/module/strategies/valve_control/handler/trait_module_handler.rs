@@ -5,6 +5,7 @@ use crate::{
     module::{
         trait_module_handler::ModuleHandler,
         Module,
+        QuickAction,
     },
     util::io::event::AppEvent,
 };
@@ -34,4 +35,20 @@ impl ModuleHandler for ValveControlHandler {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn last_error(&self) -> Option<(chrono::DateTime<chrono::Utc>, String)> {
+        self.last_error.get()
+    }
+
+    fn key_hints(&self) -> Vec<(KeyCode, &str)> {
+        self._key_hints()
+    }
+
+    fn quick_actions(&self) -> &'static [QuickAction] {
+        &[
+            QuickAction { trigger: 't', key: Some(KeyCode::Char(' ')), label: "Toggle", opens_detail: false },
+            QuickAction { trigger: 'o', key: Some(KeyCode::Char('o')), label: "Open", opens_detail: false },
+            QuickAction { trigger: 'x', key: Some(KeyCode::Char('c')), label: "Close", opens_detail: false },
+        ]
+    }
 }
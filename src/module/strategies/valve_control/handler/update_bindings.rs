@@ -1,26 +1,22 @@
 use crate::module::Module;
+use crate::runtime::safe_mode::is_safe_mode;
 use crate::module::strategies::valve_control::handler::ValveControlHandler;
 
 impl ValveControlHandler {
     pub(in crate::module) fn _update_bindings(&mut self, module: &mut Module) {
         self.process_messages();
+        module.apply_last_updated(self.last_updated);
 
         module.config.bindings.insert(
             "state".to_string(),
             serde_json::json!(self.current_state),
         );
 
-        if let Some(status) = &self.status_message {
-            module.config.bindings.insert(
-                "status_message".to_string(),
-                serde_json::json!(status),
-            );
-        }
-
-        let description = if self.current_state {
-            "Valve is OPEN - Flow active"
-        } else {
-            "Valve is CLOSED - Flow stopped"
+        let description = match (self.current_state, is_safe_mode()) {
+            (true, false) => "Valve is OPEN - Flow active",
+            (false, false) => "Valve is CLOSED - Flow stopped",
+            (true, true) => "Valve is OPEN - SAFE MODE, controls locked",
+            (false, true) => "Valve is CLOSED - SAFE MODE, controls locked",
         };
 
         module.config.bindings.insert(
@@ -0,0 +1,63 @@
+use serde_json::Value;
+
+use crate::module::Module;
+
+/// Bindings that drive rendering rather than describe state
+const PRESENTATION_BINDINGS: &[&str] = &[
+    "key_hints", "is_blinkable", "blink_interval_ms", "display_name", "gauge_style",
+    "start_angle", "end_angle", "show_ticks", "chart_type",
+];
+
+impl Module {
+    /// The card's meaning as text: a `name (type)` line, then one indented `key: value` line per
+    /// scalar binding in key order. Lists and objects are counted rather than spelled out.
+    pub fn text_summary(&self) -> String {
+        let name = self.config.binding_str("display_name").unwrap_or(&self.config.name);
+        let mut lines = vec![format!("{} ({})", name, self.config.module_type)];
+
+        let mut keys: Vec<&String> = self.config.bindings
+            .keys()
+            .filter(|key| !PRESENTATION_BINDINGS.contains(&key.as_str()))
+            .collect();
+        keys.sort();
+
+        for key in keys {
+            let text = match &self.config.bindings[key] {
+                Value::Null => continue,
+                Value::String(s) if s.is_empty() => continue,
+                Value::String(s) => s.clone(),
+                Value::Bool(b) => if *b { "yes".to_string() } else { "no".to_string() },
+                Value::Number(n) => n.to_string(),
+                Value::Array(items) => format!("{} items", items.len()),
+                Value::Object(map) => format!("{} entries", map.len()),
+            };
+            lines.push(format!("    {}: {}", key, text));
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::util::test_util::TestModule;
+
+    #[test]
+    fn test_summary_lists_state_bindings_in_order() {
+        let module = TestModule::new("pump_1", "monitoring", "gauge_card")
+            .binding("display_name", json!("Well Pump"))
+            .binding("is_connected", json!(true))
+            .binding("a", json!(42.5))
+            .binding("history", json!([1, 2, 3]))
+            .binding("status_suffix", json!(""))
+            .binding("key_hints", json!("[t] Range"))
+            .build();
+
+        assert_eq!(
+            module.text_summary(),
+            "Well Pump (monitoring)\n    a: 42.5\n    history: 3 items\n    is_connected: yes"
+        );
+    }
+}
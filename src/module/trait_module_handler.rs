@@ -1,5 +1,6 @@
 // src/modules/module_handler.rs
 
+use chrono::{DateTime, Utc};
 use color_eyre::Result;
 use ratatui::crossterm::event::KeyCode;
 use std::{
@@ -9,7 +10,8 @@ use std::{
 
 use crate::{
     util::io::event::AppEvent,
-    module::Module
+    module::{Module, QuickAction},
+    runtime::undo::UndoDirection,
 };
 
 /// Trait for handling module-specific logic
@@ -20,4 +22,31 @@ pub trait ModuleHandler: Send + Sync + Debug {
     fn module_type(&self) -> &str;
     fn as_any(&self) -> &dyn Any;
     fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Most recent failure from this handler or its background tasks, if any.
+    /// Surfaced to templates as the `last_error` / `last_error_at` bindings.
+    fn last_error(&self) -> Option<(DateTime<Utc>, String)> {
+        None
+    }
+
+    /// Re-read whatever this handler loads from disk for `module` (documents, rows, files).
+    /// Called after `ModuleManager::reload_module` has re-read the module's manifest.
+    fn reload(&mut self, _module: &mut Module) {}
+
+    /// Shortcuts usable from the overview while this module's card is selected
+    fn quick_actions(&self) -> &'static [QuickAction] {
+        &[]
+    }
+
+    /// Keys this handler responds to in its current state, for the help footer and `?` overlay.
+    /// Listed in display order; keys sharing a label are shown together.
+    fn key_hints(&self) -> Vec<(KeyCode, &str)> {
+        Vec::new()
+    }
+
+    /// Reverse (`Undo`) or repeat (`Redo`) an operation this handler recorded with
+    /// `runtime::undo::record`. `op` may be rewritten so the next step still applies.
+    fn apply_undo(&mut self, _op: &mut serde_json::Value, _direction: UndoDirection) -> Result<()> {
+        Err(color_eyre::eyre::eyre!("{} has nothing to undo", self.module_type()))
+    }
 }
@@ -0,0 +1,173 @@
+// src/runtime/audit.rs
+
+//! Append-only record of every actuation: what triggered it, which handler and device, and how it
+//! turned out. Rows live in the `audit_log` table, which refuses updates and deletes. Browse them
+//! with Ctrl+A.
+
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::log_warn;
+use crate::util::database::Database;
+
+/// Where entries are written once the runtime has a database
+static AUDIT_DB: Mutex<Option<Database>> = Mutex::new(None);
+
+/// What set an actuation off
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditTrigger {
+    /// A key in a module's detail view or an overview quick action
+    ManualKey,
+    /// A device schedule or timed automation
+    Schedule,
+    /// A command that arrived on the message bus
+    BusCommand,
+    /// An embedding application calling the runtime
+    Api,
+}
+
+impl AuditTrigger {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AuditTrigger::ManualKey => "manual_key",
+            AuditTrigger::Schedule => "schedule",
+            AuditTrigger::BusCommand => "bus_command",
+            AuditTrigger::Api => "api",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuditOutcome {
+    /// Published for the device
+    Sent,
+    /// Handed to the BLE scheduler for the next command window
+    Queued,
+    /// Announced on `dry_run` instead of sent
+    DryRun,
+    /// Turned away, by safe mode for instance
+    Refused,
+    Failed,
+}
+
+impl AuditOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AuditOutcome::Sent => "sent",
+            AuditOutcome::Queued => "queued",
+            AuditOutcome::DryRun => "dry_run",
+            AuditOutcome::Refused => "refused",
+            AuditOutcome::Failed => "failed",
+        }
+    }
+}
+
+/// Who asked for a command and why. It travels with the command so the one audit row is written
+/// where the outcome is known, under the handler and trigger that started it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandOrigin {
+    pub handler: String,
+    pub trigger: AuditTrigger,
+}
+
+impl CommandOrigin {
+    pub fn new(handler: &str, trigger: AuditTrigger) -> Self {
+        Self { handler: handler.to_string(), trigger }
+    }
+
+    /// Append the audit entry for this command's outcome
+    pub fn record(&self, device_id: &str, action: &str, outcome: AuditOutcome, detail: Option<String>) {
+        record(&self.handler, self.trigger, device_id, action, outcome, detail);
+    }
+}
+
+/// One `audit_log` row; `recorded_at` in unix seconds
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub recorded_at: i64,
+    pub handler: String,
+    pub trigger: String,
+    pub device_id: String,
+    pub action: String,
+    pub outcome: String,
+    /// Error or reason, when there is one
+    pub detail: Option<String>,
+}
+
+/// Write entries to `database` from now on
+pub fn init(database: Database) {
+    *AUDIT_DB.lock().unwrap() = Some(database);
+}
+
+/// Append an entry for an actuation by `handler`. Failures to write are logged, never raised:
+/// auditing must not stop the equipment from being controlled.
+pub fn record(
+    handler: &str,
+    trigger: AuditTrigger,
+    device_id: &str,
+    action: &str,
+    outcome: AuditOutcome,
+    detail: Option<String>,
+) {
+    let entry = AuditEntry {
+        recorded_at: chrono::Utc::now().timestamp(),
+        handler: handler.to_string(),
+        trigger: trigger.as_str().to_string(),
+        device_id: device_id.to_string(),
+        action: action.to_string(),
+        outcome: outcome.as_str().to_string(),
+        detail,
+    };
+
+    let Some(database) = AUDIT_DB.lock().unwrap().clone() else {
+        log_warn!("📝 Audit log not initialized; dropped {} '{}' on {}", entry.handler, entry.action, entry.device_id);
+        return;
+    };
+    if let Err(e) = database.append_audit_entry(&entry) {
+        log_warn!("📝 Failed to write audit entry for {} '{}': {}", entry.handler, entry.action, e);
+    }
+}
+
+/// The newest `limit` entries, newest first
+pub fn recent(limit: usize) -> Vec<AuditEntry> {
+    let Some(database) = AUDIT_DB.lock().unwrap().clone() else {
+        return Vec::new();
+    };
+    database.recent_audit_entries(limit).unwrap_or_else(|e| {
+        log_warn!("📝 Failed to read the audit log: {}", e);
+        Vec::new()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_append_and_cannot_be_rewritten() {
+        let database = Database::in_memory().unwrap();
+        for action in ["open", "close"] {
+            database
+                .append_audit_entry(&AuditEntry {
+                    recorded_at: 100,
+                    handler: "valve_control".to_string(),
+                    trigger: AuditTrigger::ManualKey.as_str().to_string(),
+                    device_id: "v01".to_string(),
+                    action: action.to_string(),
+                    outcome: AuditOutcome::Queued.as_str().to_string(),
+                    detail: None,
+                })
+                .unwrap();
+        }
+
+        let entries = database.recent_audit_entries(10).unwrap();
+        assert_eq!(entries.iter().map(|e| e.action.as_str()).collect::<Vec<_>>(), ["close", "open"]);
+        assert_eq!(entries[0].trigger, "manual_key");
+
+        let conn = database.app_conn.get().unwrap();
+        assert!(conn.execute("UPDATE audit_log SET outcome = 'sent'", []).is_err());
+        assert!(conn.execute("DELETE FROM audit_log", []).is_err());
+    }
+}
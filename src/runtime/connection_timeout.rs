@@ -0,0 +1,35 @@
+// src/runtime/connection_timeout.rs
+
+//! How long a monitoring device may go without telemetry before its card shows it disconnected.
+//! This is a deadline rather than a wake-up, so it is set on its own with `CONNECTION_TIMEOUT_MS`
+//! (or [`RuntimeBuilder::with_connection_timeout`](super::RuntimeBuilder::with_connection_timeout))
+//! instead of in `POLL_INTERVAL_MS`.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Once;
+use std::time::Duration;
+
+use crate::log_warn;
+
+const DEFAULT_CONNECTION_TIMEOUT_MS: u64 = 10_000;
+
+static CONNECTION_TIMEOUT_MS: AtomicU64 = AtomicU64::new(DEFAULT_CONNECTION_TIMEOUT_MS);
+static FROM_ENV: Once = Once::new();
+
+/// Starts from `CONNECTION_TIMEOUT_MS` unless set explicitly
+pub fn connection_timeout() -> Duration {
+    FROM_ENV.call_once(|| {
+        if let Ok(value) = std::env::var("CONNECTION_TIMEOUT_MS") {
+            match value.trim().parse::<u64>() {
+                Ok(ms) if ms > 0 => CONNECTION_TIMEOUT_MS.store(ms, Ordering::Relaxed),
+                _ => log_warn!("Ignoring CONNECTION_TIMEOUT_MS='{}'; expected a positive number of milliseconds", value),
+            }
+        }
+    });
+    Duration::from_millis(CONNECTION_TIMEOUT_MS.load(Ordering::Relaxed))
+}
+
+pub fn set_connection_timeout(timeout: Duration) {
+    FROM_ENV.call_once(|| {});
+    CONNECTION_TIMEOUT_MS.store((timeout.as_millis() as u64).max(1), Ordering::Relaxed);
+}
@@ -0,0 +1,238 @@
+// src/runtime/diagnostics.rs
+
+//! The Ctrl+D self-check: one pass, warn or fail line per subsystem, built from signals the
+//! runtime already keeps (the audio probe, database pools, transports, BLE discovery, the
+//! overseer's registry and handler errors). The first place to look when "nothing works".
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::module::{strategies::overseer::RegistryEndpoint, ModuleManager};
+use crate::runtime::features::features_summary;
+use crate::util::{
+    audio::audio_device_available,
+    database::Database,
+    io::{
+        discovery::DiscoveryManager,
+        transport::{PortState, TransportManager},
+    },
+};
+
+/// How long the registry gets to answer before it's reported unreachable
+const REGISTRY_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CheckStatus {
+    Pass,
+    /// Works, but not fully: disabled, degraded or nothing connected yet
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Fail => "FAIL",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagnosticCheck {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl DiagnosticCheck {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self { name, status, detail: detail.into() }
+    }
+}
+
+/// What the checks read, gathered up front so they can run off the UI loop
+#[derive(Debug, Clone)]
+pub struct DiagnosticInputs {
+    pub database: Database,
+    pub transport_manager: Option<TransportManager>,
+    pub discovery_manager: Option<Arc<DiscoveryManager>>,
+    /// The overseer's active registry, when there's an overseer module
+    pub registry: Option<RegistryEndpoint>,
+    pub modules_loaded: usize,
+    /// `name: error` for each module whose handler reported an error or was marked failed
+    pub module_failures: Vec<String>,
+}
+
+impl DiagnosticInputs {
+    pub fn gather(
+        module_managers: [&ModuleManager; 2],
+        database: &Database,
+        transport_manager: &Option<TransportManager>,
+        discovery_manager: &Option<Arc<DiscoveryManager>>,
+    ) -> Self {
+        let modules = || module_managers.into_iter().flat_map(|manager| manager.get_modules());
+
+        let registry = modules().find(|m| m.config.module_type == "overseer").map(|overseer| {
+            let (registries, active) = RegistryEndpoint::from_config(&overseer.config);
+            let position = active
+                .and_then(|name| registries.iter().position(|r| r.name == name))
+                .unwrap_or(0);
+            registries.into_iter().nth(position).unwrap_or_else(RegistryEndpoint::default_endpoint)
+        });
+
+        Self {
+            database: database.clone(),
+            transport_manager: transport_manager.clone(),
+            discovery_manager: discovery_manager.clone(),
+            registry,
+            modules_loaded: modules().count(),
+            module_failures: modules()
+                .filter_map(|m| Some(format!("{}: {}", m.config.name, m.last_error_message()?)))
+                .collect(),
+        }
+    }
+}
+
+/// Run every check, in the order they're shown
+pub async fn run(inputs: DiagnosticInputs) -> Vec<DiagnosticCheck> {
+    let transports = match &inputs.transport_manager {
+        Some(transport_manager) => Some((
+            transport_manager.port_states().await,
+            transport_manager.routed_device_count().await,
+        )),
+        None => None,
+    };
+    let discovery = match &inputs.discovery_manager {
+        Some(discovery_manager) => Some((
+            discovery_manager.has_adapter().await,
+            discovery_manager.get_trusted_devices().await.map(|devices| devices.len()).unwrap_or(0),
+        )),
+        None => None,
+    };
+
+    vec![
+        audio_check(audio_device_available()),
+        database_check(inputs.database.check_writable()),
+        transport_check(transports),
+        discovery_check(discovery),
+        registry_check(&inputs.registry).await,
+        modules_check(inputs.modules_loaded, &inputs.module_failures),
+        // Not a health check, but the answer to "why is this subsystem missing"
+        DiagnosticCheck::new("Features", CheckStatus::Pass, features_summary()),
+    ]
+}
+
+/// The results as plain text, one check per line
+pub fn report(checks: &[DiagnosticCheck]) -> String {
+    checks
+        .iter()
+        .map(|check| format!("{}  {:<16} {}", check.status.as_str(), check.name, check.detail))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn audio_check(available: bool) -> DiagnosticCheck {
+    if available {
+        DiagnosticCheck::new("Audio device", CheckStatus::Pass, "Default output device opened")
+    } else {
+        DiagnosticCheck::new("Audio device", CheckStatus::Warn, "No output device; sound is disabled")
+    }
+}
+
+fn database_check(result: color_eyre::Result<()>) -> DiagnosticCheck {
+    match result {
+        Ok(()) => DiagnosticCheck::new("Database", CheckStatus::Pass, "All databases reachable and writable"),
+        Err(e) => DiagnosticCheck::new("Database", CheckStatus::Fail, e.to_string()),
+    }
+}
+
+/// `transports` is the listened ports and routed device count, or `None` when transports are disabled
+fn transport_check(transports: Option<(Vec<PortState>, usize)>) -> DiagnosticCheck {
+    let Some((ports, devices)) = transports else {
+        return DiagnosticCheck::new("Transports", CheckStatus::Warn, "Disabled");
+    };
+    if ports.is_empty() {
+        return DiagnosticCheck::new("Transports", CheckStatus::Warn, "No serial ports found; is a device plugged in?");
+    }
+
+    let detail = ports
+        .iter()
+        .map(|port| format!("{} {}", port.path, if port.attached { "attached" } else { "detached" }))
+        .chain(std::iter::once(format!("{} device(s) routed", devices)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let status = if ports.iter().any(|port| port.attached) { CheckStatus::Pass } else { CheckStatus::Fail };
+    DiagnosticCheck::new("Transports", status, detail)
+}
+
+/// `discovery` is whether there's a BLE adapter and how many devices are trusted
+fn discovery_check(discovery: Option<(bool, usize)>) -> DiagnosticCheck {
+    match discovery {
+        None => DiagnosticCheck::new("BLE discovery", CheckStatus::Warn, "Disabled"),
+        Some((false, _)) => DiagnosticCheck::new("BLE discovery", CheckStatus::Fail, "No BLE adapter found"),
+        Some((true, trusted)) => {
+            DiagnosticCheck::new("BLE discovery", CheckStatus::Pass, format!("Adapter ready, {} trusted device(s)", trusted))
+        }
+    }
+}
+
+async fn registry_check(registry: &Option<RegistryEndpoint>) -> DiagnosticCheck {
+    let Some(registry) = registry else {
+        return DiagnosticCheck::new("Module registry", CheckStatus::Warn, "No overseer module configured");
+    };
+
+    // An offline bundle repo only has to be on disk
+    if let Some(path) = registry.url.strip_prefix("file://").map(std::path::Path::new) {
+        return if path.is_dir() {
+            DiagnosticCheck::new("Module registry", CheckStatus::Pass, format!("{} ({})", registry.name, path.display()))
+        } else {
+            DiagnosticCheck::new("Module registry", CheckStatus::Fail, format!("{}: {} is missing", registry.name, path.display()))
+        };
+    }
+
+    let probe = async {
+        let client = reqwest::Client::builder().timeout(REGISTRY_TIMEOUT).build()?;
+        client.get(format!("{}/manifests", registry.url)).send().await?.error_for_status()
+    };
+    match probe.await {
+        Ok(_) => DiagnosticCheck::new("Module registry", CheckStatus::Pass, format!("{} reachable ({})", registry.name, registry.url)),
+        Err(e) => DiagnosticCheck::new("Module registry", CheckStatus::Fail, format!("{} unreachable: {}", registry.name, e)),
+    }
+}
+
+fn modules_check(loaded: usize, failures: &[String]) -> DiagnosticCheck {
+    if loaded == 0 {
+        return DiagnosticCheck::new("Modules", CheckStatus::Warn, "No modules loaded; check the manifests directories");
+    }
+    match failures {
+        [] => DiagnosticCheck::new("Modules", CheckStatus::Pass, format!("{} loaded, none failing", loaded)),
+        _ => DiagnosticCheck::new(
+            "Modules",
+            CheckStatus::Fail,
+            format!("{} loaded, {} failing: {}", loaded, failures.len(), failures.join("; ")),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checks_report_pass_warn_and_fail() {
+        assert_eq!(transport_check(None).status, CheckStatus::Warn);
+        let unplugged = transport_check(Some((vec![PortState { path: "/dev/ttyUSB0".to_string(), attached: false }], 0)));
+        assert_eq!(unplugged.status, CheckStatus::Fail);
+        assert_eq!(unplugged.detail, "/dev/ttyUSB0 detached, 0 device(s) routed");
+
+        assert_eq!(discovery_check(Some((false, 2))).status, CheckStatus::Fail);
+        assert_eq!(discovery_check(Some((true, 2))).detail, "Adapter ready, 2 trusted device(s)");
+
+        assert_eq!(modules_check(4, &[]).status, CheckStatus::Pass);
+        let failing = modules_check(4, &["Pump House: serial port vanished".to_string()]);
+        assert_eq!(failing.status, CheckStatus::Fail);
+        assert!(failing.detail.ends_with("1 failing: Pump House: serial port vanished"));
+    }
+}
@@ -0,0 +1,96 @@
+// src/runtime/disk_watch.rs
+
+//! Keeps an eye on free space where the runtime writes, measured at the database directory (see
+//! [`dirs`](crate::util::dirs)). Checked at startup and then every minute; dropping below
+//! `LOW_DISK_SPACE_MB` raises a warning toast and flags the overview title until space is freed.
+
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::util::dirs::data_dirs;
+use crate::util::disk::{format_bytes, DiskSpace};
+use crate::{log_info, log_warn};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The last reading, when it was low
+static LOW_SPACE: Mutex<Option<DiskSpace>> = Mutex::new(None);
+
+/// The last reading if it was below the threshold, for the UI to flag
+pub fn low_disk_space() -> Option<DiskSpace> {
+    *LOW_SPACE.lock().unwrap()
+}
+
+/// Measure now, then keep measuring in the background
+pub fn start() {
+    check();
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        // The first tick is immediate and `check` just ran
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            check();
+        }
+    });
+}
+
+/// Take a reading and announce it if it crossed the threshold either way
+pub fn check() {
+    // The directory may not have been created yet; its filesystem is the nearest one that exists
+    let database_dir = data_dirs().database;
+    let measured = database_dir.ancestors().find(|dir| dir.exists()).unwrap_or(Path::new("."));
+    let space = match DiskSpace::of(measured) {
+        Ok(space) => space,
+        // Not measurable here; nothing to warn about
+        Err(_) => return,
+    };
+
+    let mut low = LOW_SPACE.lock().unwrap();
+    if let Some((level, message)) = transition(low.is_some(), &space) {
+        match level {
+            ToastLevel::Warning => log_warn!("{}", message),
+            _ => log_info!("{}", message),
+        }
+        notify(level, message);
+    }
+    *low = space.is_low().then_some(space);
+}
+
+/// The announcement for going from `was_low` to `space`, if it changed
+fn transition(was_low: bool, space: &DiskSpace) -> Option<(ToastLevel, String)> {
+    match (was_low, space.is_low()) {
+        (false, true) => Some((
+            ToastLevel::Warning,
+            format!(
+                "💾 Low disk space: {} free of {}; saves may start failing",
+                format_bytes(space.available),
+                format_bytes(space.total)
+            ),
+        )),
+        (true, false) => Some((ToastLevel::Info, format!("💾 Disk space recovered: {} free", format_bytes(space.available)))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_crossing_the_threshold_is_announced() {
+        let gb = 1024 * 1024 * 1024;
+        let low = DiskSpace { available: 1024 * 1024, total: 8 * gb };
+        let plenty = DiskSpace { available: 4 * gb, total: 8 * gb };
+
+        let (level, message) = transition(false, &low).unwrap();
+        assert_eq!(level, ToastLevel::Warning);
+        assert_eq!(message, "💾 Low disk space: 1.0 MB free of 8.0 GB; saves may start failing");
+        assert_eq!(transition(true, &low), None);
+
+        assert_eq!(transition(true, &plenty).unwrap().0, ToastLevel::Info);
+        assert_eq!(transition(false, &plenty), None);
+    }
+}
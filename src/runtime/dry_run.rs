@@ -0,0 +1,74 @@
+// src/runtime/dry_run.rs
+
+//! Global dry-run switch: actuating handlers announce what they would do instead of doing it.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+use crate::log_info;
+use crate::util::io::bus::{BusMessage, MessageBus};
+
+/// Topic intended actions are published on while dry-run is on
+pub const DRY_RUN_TOPIC: &str = "dry_run";
+
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+static FROM_ENV: Once = Once::new();
+
+/// Whether actuators should be left alone. Starts from `DRY_RUN=true` unless set explicitly.
+pub fn is_dry_run() -> bool {
+    FROM_ENV.call_once(|| {
+        if std::env::var("DRY_RUN").unwrap_or_default() == "true" {
+            DRY_RUN.store(true, Ordering::Relaxed);
+        }
+    });
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+pub fn set_dry_run(enabled: bool) {
+    FROM_ENV.call_once(|| {});
+    DRY_RUN.store(enabled, Ordering::Relaxed);
+}
+
+/// The bus message announcing that `source` would have sent `action` to `device_id`
+pub fn intended_action_message(source: &str, device_id: &str, action: &str, payload: Option<&serde_json::Value>) -> BusMessage {
+    BusMessage::new(
+        DRY_RUN_TOPIC.to_string(),
+        serde_json::json!({
+            "device_id": device_id,
+            "action": action,
+            "payload": payload,
+        }).to_string(),
+        source.to_string(),
+    )
+}
+
+/// Log and publish an action that was skipped because dry-run is on
+pub async fn report_intended_action(
+    bus: &MessageBus,
+    source: &str,
+    device_id: &str,
+    action: &str,
+    payload: Option<&serde_json::Value>,
+) -> color_eyre::Result<()> {
+    log_info!("🧪 [dry run] {} would send '{}' to {}", source, action, device_id);
+    bus.publish(intended_action_message(source, device_id, action, payload)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intended_action_message_describes_the_skipped_command() {
+        let payload = serde_json::json!({ "action": "open" });
+        let message = intended_action_message("valve_control_handler", "v01", "valve_control", Some(&payload));
+
+        assert_eq!(message.topic, DRY_RUN_TOPIC);
+        assert_eq!(message.source, "valve_control_handler");
+
+        let body: serde_json::Value = serde_json::from_str(&message.payload).unwrap();
+        assert_eq!(body["device_id"], "v01");
+        assert_eq!(body["action"], "valve_control");
+        assert_eq!(body["payload"]["action"], "open");
+    }
+}
@@ -0,0 +1,72 @@
+// src/runtime/features.rs
+
+//! Which optional cargo features this binary was compiled with, so "why doesn't template export
+//! work" can be answered from the about overlay, the diagnostics screen or `--version` instead
+//! of the build log.
+
+use crate::app::App;
+
+/// An optional subsystem behind a cargo feature
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeatureFlag {
+    pub name: &'static str,
+    pub enabled: bool,
+    /// What turning it on adds
+    pub description: &'static str,
+}
+
+/// Every optional feature in `Cargo.toml`; add new ones here as they're introduced
+pub const FEATURES: &[FeatureFlag] = &[
+    FeatureFlag {
+        name: "template-preview",
+        enabled: cfg!(feature = "template-preview"),
+        description: "Export rendered template previews as SVG",
+    },
+    FeatureFlag {
+        name: "template-preview-png",
+        enabled: cfg!(feature = "template-preview-png"),
+        description: "Template previews as PNG too",
+    },
+    FeatureFlag {
+        name: "test-util",
+        enabled: cfg!(feature = "test-util"),
+        description: "In-memory database, loopback bus and mock handlers for module tests",
+    },
+];
+
+/// Names of the features compiled in
+pub fn enabled_features() -> Vec<&'static str> {
+    FEATURES.iter().filter(|feature| feature.enabled).map(|feature| feature.name).collect()
+}
+
+impl App {
+    /// Names of the features compiled in, for embedders holding a [`Runtime`](super::Runtime)
+    pub fn enabled_features() -> Vec<&'static str> {
+        enabled_features()
+    }
+}
+
+/// `on: a, b; off: c`, for one-line summaries
+pub fn features_summary() -> String {
+    let list = |enabled: bool| {
+        let names: Vec<&str> = FEATURES.iter().filter(|f| f.enabled == enabled).map(|f| f.name).collect();
+        if names.is_empty() { "none".to_string() } else { names.join(", ") }
+    };
+    format!("on: {}; off: {}", list(true), list(false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_feature_is_listed_on_or_off() {
+        let summary = features_summary();
+        let (on, off) = summary.trim_start_matches("on: ").split_once("; off: ").unwrap();
+        let names = |list: &str| list.split(", ").filter(|name| *name != "none").map(str::to_string).collect::<Vec<_>>();
+
+        assert_eq!(names(on), enabled_features());
+        assert_eq!(crate::runtime::Runtime::enabled_features(), enabled_features());
+        assert_eq!(names(on).len() + names(off).len(), FEATURES.len());
+    }
+}
@@ -0,0 +1,102 @@
+// src/runtime/handler_init.rs
+
+//! Module handler registration, deferred until the splash is dismissed. `build` returns as soon
+//! as modules are discovered; the app then shows [`LoadingScreen`] progress between the splash
+//! and the overview while each handler registers.
+
+use color_eyre::{eyre::WrapErr, Result};
+use ratatui::DefaultTerminal;
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+
+use crate::app::{App, AppMode};
+use crate::module::HandlerInitProgress;
+use crate::ui::screens::loading::LoadingScreen;
+
+use super::{watchdog, RuntimeBuilder};
+
+/// What `build` leaves for [`App::initialize_handlers`]
+#[derive(Debug)]
+pub struct PendingHandlerInit {
+    pub(super) manifests_path: PathBuf,
+    pub(super) progress: mpsc::UnboundedReceiver<HandlerInitProgress>,
+}
+
+impl App {
+    /// Whether handler registration is still waiting to run
+    pub fn handlers_pending(&self) -> bool {
+        self.pending_handler_init.is_some()
+    }
+
+    /// Register module handlers, drawing the loading screen as each one does, then start the
+    /// watchdog and transports that rely on them and move on to the overview. Does nothing
+    /// once handlers are registered.
+    pub async fn initialize_handlers(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        let Some(PendingHandlerInit { manifests_path, mut progress }) = self.pending_handler_init.take() else {
+            return Ok(());
+        };
+
+        let loading_screen = self.loading_screen.insert(LoadingScreen::new());
+        terminal.draw(|frame| loading_screen.render(frame.area(), frame.buffer_mut()))?;
+        {
+            let wasteland_module_manager = &mut self.wasteland_module_manager;
+            let core_module_manager = &mut self.core_module_manager;
+            let discovery_manager = &self.discovery_manager;
+            let database = &self.database;
+            let message_bus = &self.message_bus;
+
+            let init_handlers = async move {
+                wasteland_module_manager.initialize_module_handlers(
+                    manifests_path.clone(),
+                    discovery_manager.clone(),
+                    database,
+                    message_bus
+                ).await.wrap_err("Failed to initialize wasteland module handlers")?;
+
+                core_module_manager.initialize_module_handlers(
+                    manifests_path,
+                    discovery_manager.clone(),
+                    database,
+                    message_bus
+                ).await.wrap_err("Failed to initialize core module handlers")
+            };
+            tokio::pin!(init_handlers);
+
+            loop {
+                tokio::select! {
+                    registered = &mut init_handlers => {
+                        registered?;
+                        break;
+                    }
+                    Some(step) = progress.recv() => {
+                        loading_screen.record(step);
+                        terminal.draw(|frame| loading_screen.render(frame.area(), frame.buffer_mut()))?;
+                    }
+                }
+            }
+        }
+
+        // Steps that registered without yielding arrive all at once
+        while let Ok(step) = progress.try_recv() {
+            loading_screen.record(step);
+        }
+        loading_screen.finish_current();
+        self.loading_screen = None;
+        self.wasteland_module_manager.set_init_progress(None);
+        self.core_module_manager.set_init_progress(None);
+
+        // Handlers have registered their background tasks; keep them running
+        watchdog::start(self.message_bus.clone());
+
+        // Telemetry only flows once there are handlers subscribed to receive it
+        if let Some(transport_manager) = &self.transport_manager {
+            RuntimeBuilder::start_transport(transport_manager.clone(), self.message_bus.clone());
+        }
+
+        if self.mode == AppMode::Loading {
+            self.mode = AppMode::Overview;
+        }
+        self.needs_redraw = true;
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+// src/runtime/isolation.rs
+
+//! Keeps one handler's panic from taking down the TUI.
+//!
+//! Background tasks started with [`spawn_isolated`] catch their panics: the panic is logged, the
+//! handler is marked failed (its card shows the message as `last_error`) and everything else keeps
+//! running. They're tracked per handler so [`abort`] can stop them when the handler goes away. Template renders run through [`guard`] and show the error widget instead.
+//! A render that panics every frame is logged once, and again only after it has recovered.
+//! [`install_panic_hook`] stops the terminal-restoring hook from firing for these contained panics.
+
+use chrono::{DateTime, Utc};
+use futures::FutureExt;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll};
+use tokio::task::{AbortHandle, JoinHandle};
+
+use crate::{log_error, log_info};
+use crate::runtime::toast::{notify, ToastLevel};
+
+/// Handler key -> when and why it was marked failed
+static FAILED: Mutex<Option<HashMap<String, (DateTime<Utc>, String)>>> = Mutex::new(None);
+
+/// Handler key -> its tasks started with [`spawn_isolated`], so they can be stopped with the handler
+static SPAWNED: Mutex<Option<HashMap<String, Vec<AbortHandle>>>> = Mutex::new(None);
+
+/// [`guard`] names whose last run panicked, so a render failing every frame is logged once
+static PANICKING: Mutex<Option<HashSet<String>>> = Mutex::new(None);
+
+thread_local! {
+    /// Nesting depth of isolated code running on this thread
+    static ISOLATED: Cell<u32> = const { Cell::new(0) };
+    /// Set while a [`guard`] that already panicked last time runs, so the panic hook stays quiet
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+struct IsolationScope;
+
+impl IsolationScope {
+    fn enter() -> Self {
+        ISOLATED.with(|depth| depth.set(depth.get() + 1));
+        Self
+    }
+}
+
+impl Drop for IsolationScope {
+    fn drop(&mut self) {
+        ISOLATED.with(|depth| depth.set(depth.get().saturating_sub(1)));
+    }
+}
+
+/// Marks the thread isolated for each poll, so the panic hook knows the panic will be caught
+struct Isolated<F> {
+    inner: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for Isolated<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let _scope = IsolationScope::enter();
+        self.inner.as_mut().poll(cx)
+    }
+}
+
+/// Run `future` for the handler `handler` so a panic inside it resolves to `Err(message)`
+/// and marks the handler failed instead of unwinding further
+pub fn isolate<F>(handler: &str, future: F) -> impl Future<Output = Result<F::Output, String>> + Send + 'static
+where
+    F: Future + Send + 'static,
+    F::Output: Send,
+{
+    let handler = handler.to_string();
+    AssertUnwindSafe(Isolated { inner: Box::pin(future) })
+        .catch_unwind()
+        .map(move |result| result.map_err(|payload| mark_failed(&handler, payload_message(payload.as_ref()))))
+}
+
+/// `tokio::spawn` for a handler's background task, with its panics contained.
+/// The task runs until it finishes or [`abort`] is called for `handler`.
+pub fn spawn_isolated<F>(handler: &str, future: F) -> JoinHandle<()>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let isolated = isolate(handler, future);
+    let handle = tokio::spawn(async move {
+        let _ = isolated.await;
+    });
+
+    let mut spawned = SPAWNED.lock().unwrap();
+    let tasks = spawned.get_or_insert_with(HashMap::new).entry(handler.to_string()).or_default();
+    tasks.retain(|task| !task.is_finished());
+    tasks.push(handle.abort_handle());
+
+    handle
+}
+
+/// Abort `handler`'s unfinished [`spawn_isolated`] tasks, e.g. when its module is removed.
+/// Returns how many were still running.
+pub fn abort(handler: &str) -> usize {
+    let tasks = SPAWNED.lock().unwrap().as_mut().and_then(|spawned| spawned.remove(handler)).unwrap_or_default();
+    let running: Vec<_> = tasks.into_iter().filter(|task| !task.is_finished()).collect();
+    for task in &running {
+        task.abort();
+    }
+    running.len()
+}
+
+/// Run `f` (a template render, say), turning a panic into `Err(message)`. Nothing is marked
+/// failed: a render that panics will panic again next frame and show its error there. Only the
+/// first panic is logged until a run of `what` succeeds again.
+pub fn guard<R>(what: &str, f: impl FnOnce() -> R) -> Result<R, String> {
+    let was_quiet = QUIET.with(|quiet| quiet.replace(is_panicking(what)));
+    let result = {
+        let _scope = IsolationScope::enter();
+        std::panic::catch_unwind(AssertUnwindSafe(f))
+    };
+    QUIET.with(|quiet| quiet.set(was_quiet));
+
+    let mut panicking = PANICKING.lock().unwrap();
+    let panicking = panicking.get_or_insert_with(HashSet::new);
+    match result {
+        Ok(value) => {
+            if panicking.remove(what) {
+                log_info!("{} recovered", what);
+            }
+            Ok(value)
+        }
+        Err(payload) => {
+            let message = payload_message(payload.as_ref());
+            if panicking.insert(what.to_string()) {
+                log_error!("💥 {} panicked: {}", what, message);
+            }
+            Err(message)
+        }
+    }
+}
+
+/// Whether the last [`guard`] run of `what` panicked
+pub fn is_panicking(what: &str) -> bool {
+    PANICKING.lock().unwrap().as_ref().is_some_and(|panicking| panicking.contains(what))
+}
+
+/// When and why `handler` was marked failed, if it was
+pub fn failure(handler: &str) -> Option<(DateTime<Utc>, String)> {
+    FAILED.lock().unwrap().as_ref()?.get(handler).cloned()
+}
+
+/// Forget a failure once the handler's task has been restarted
+pub fn clear_failure(handler: &str) {
+    if let Some(failed) = FAILED.lock().unwrap().as_mut() {
+        failed.remove(handler);
+    }
+}
+
+/// Wrap the current panic hook (ratatui's, which restores the terminal) so panics caught by
+/// [`isolate`] or [`guard`] are only logged. Call after `ratatui::init`.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if ISOLATED.with(|depth| depth.get()) > 0 {
+            if !QUIET.with(Cell::get) {
+                log_error!("💥 Contained panic: {}", info);
+            }
+        } else {
+            previous(info);
+        }
+    }));
+}
+
+fn mark_failed(handler: &str, message: String) -> String {
+    log_error!(target: handler, "💥 {} panicked: {}", handler, message);
+    notify(ToastLevel::Error, format!("{} failed: {}", handler, message));
+    FAILED
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .insert(handler.to_string(), (Utc::now(), message.clone()));
+    message
+}
+
+fn payload_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_panics_are_contained_and_mark_the_handler() {
+        let result = isolate("isolation_task", async { panic!("serial port vanished") }).await;
+        assert_eq!(result.unwrap_err(), "serial port vanished");
+        assert_eq!(failure("isolation_task").unwrap().1, "serial port vanished");
+
+        clear_failure("isolation_task");
+        assert_eq!(failure("isolation_task"), None);
+
+        let rendered = guard("isolation_render", || -> u8 { panic!("bad binding {}", 7) });
+        assert_eq!(rendered.unwrap_err(), "bad binding 7");
+        assert!(is_panicking("isolation_render"));
+        assert!(guard("isolation_render", || -> u8 { panic!("bad binding {}", 8) }).is_err());
+        assert_eq!(guard("isolation_render", || 3), Ok(3));
+        assert!(!is_panicking("isolation_render"));
+    }
+}
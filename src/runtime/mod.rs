@@ -0,0 +1,569 @@
+// src/runtime/mod.rs
+
+//! Embeddable entry point: assemble an [`App`] from your own bus, database,
+//! transports and discovery backend instead of the defaults `App::new` wires up.
+//!
+//! ```ignore
+//! let (bus, receiver) = MessageBus::new();
+//! let app = RuntimeBuilder::new()
+//!     .with_bus(bus, receiver)
+//!     .with_database(Database::in_memory()?)
+//!     .without_discovery()
+//!     .with_modules_path("./my_modules/")
+//!     .build()
+//!     .await?;
+//! ```
+
+use color_eyre::{eyre::WrapErr, Result};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::time::{Duration, Instant};
+
+use crate::app::{App, AppMode, OverviewFocus};
+use crate::module::ModuleManager;
+use crate::ui::{
+    document::{
+        manager::DocumentManager,
+        viewer::cache_gc::{collect_cache_garbage, CacheGcPolicy},
+    },
+    screens::splash::{SplashScreen, SplashTiming},
+    style::AdaptiveColors,
+    ui_state::UiState,
+    widgets::{
+        jukebox::{actor::JukeboxActor, ingester::JukeboxIngester, widget::JukeboxWidget},
+        messages_window::{actor::MessagesActor, widget::MessagesWidget},
+        module_detail::widget::ModuleDetailWidget,
+        modules_list::widget::ModulesListWidget,
+    },
+};
+use crate::util::{
+    database::Database,
+    dirs::{set_data_dirs, DataDirs},
+    image::ImageCache,
+    io::{
+        bus::{BusMessage, BusReceiver, MessageBus},
+        discovery::DiscoveryManager,
+        event::EventHandler,
+        event_topics::set_event_topics,
+        transport::TransportManager,
+    },
+    knowledge::KnowledgeIngester,
+};
+use crate::{log_error, log_info};
+
+pub mod audit;
+pub mod connection_timeout;
+pub mod diagnostics;
+pub mod disk_watch;
+pub mod dry_run;
+pub mod features;
+pub mod handler_init;
+pub mod isolation;
+pub mod observer;
+pub mod poll_interval;
+pub mod reduced_motion;
+pub mod template_eviction;
+pub mod safe_mode;
+pub mod startup;
+pub mod support_bundle;
+pub mod toast;
+pub mod undo;
+pub mod watchdog;
+mod publish;
+
+pub use features::enabled_features;
+pub use handler_init::PendingHandlerInit;
+pub use observer::{RuntimeObserver, SharedObserver};
+pub use startup::StartupView;
+
+/// The runtime is the application itself; the builder is how embedders configure it
+pub type Runtime = App;
+
+/// How often the knowledge cache is trimmed after the startup pass
+const CACHE_GC_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// A pluggable component: the standard one, one supplied by the embedder, or none
+#[derive(Default)]
+enum Component<T> {
+    #[default]
+    Default,
+    Custom(T),
+    Disabled,
+}
+
+/// Wires up an [`App`]; anything not supplied falls back to the standard component
+pub struct RuntimeBuilder {
+    database: Option<Database>,
+    bus: Option<(MessageBus, BusReceiver)>,
+    transport: Component<TransportManager>,
+    discovery: Component<Arc<DiscoveryManager>>,
+    data_dirs: Option<DataDirs>,
+    modules_path: Option<PathBuf>,
+    core_modules_path: Option<PathBuf>,
+    event_topics: Option<Vec<String>>,
+    poll_intervals: Vec<(poll_interval::PollTask, Duration)>,
+    connection_timeout: Option<Duration>,
+    observers: Vec<SharedObserver>,
+    dry_run: Option<bool>,
+    safe_mode: Option<bool>,
+    reduced_motion: Option<bool>,
+    template_evict_rows: Option<Option<usize>>,
+    startup_view: Option<StartupView>,
+    splash_timing: Option<SplashTiming>,
+}
+
+impl Default for RuntimeBuilder {
+    fn default() -> Self {
+        Self {
+            database: None,
+            bus: None,
+            transport: Component::Default,
+            discovery: Component::Default,
+            data_dirs: None,
+            modules_path: None,
+            core_modules_path: None,
+            event_topics: None,
+            poll_intervals: Vec::new(),
+            connection_timeout: None,
+            observers: Vec::new(),
+            dry_run: None,
+            safe_mode: None,
+            reduced_motion: None,
+            template_evict_rows: None,
+            startup_view: None,
+            splash_timing: None,
+        }
+    }
+}
+
+impl RuntimeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use this database instead of the on-disk one (or `IN_MEMORY_DB`)
+    pub fn with_database(mut self, database: Database) -> Self {
+        self.database = Some(database);
+        self
+    }
+
+    /// Use this bus; `receiver` is the bus's main receiver from `MessageBus::new`
+    pub fn with_bus(mut self, bus: MessageBus, receiver: BusReceiver) -> Self {
+        self.bus = Some((bus, receiver));
+        self
+    }
+
+    /// Use this transport manager (built on the same bus) instead of the serial/USB default.
+    /// It is started once module handlers have registered (see [`App::initialize_handlers`]), so
+    /// telemetry has somewhere to go, and then asks field units to register.
+    pub fn with_transport(mut self, transport: TransportManager) -> Self {
+        self.transport = Component::Custom(transport);
+        self
+    }
+
+    pub fn without_transport(mut self) -> Self {
+        self.transport = Component::Disabled;
+        self
+    }
+
+    /// Use this discovery backend instead of the BLE default. It is started by `build`.
+    pub fn with_discovery(mut self, discovery: Arc<DiscoveryManager>) -> Self {
+        self.discovery = Component::Custom(discovery);
+        self
+    }
+
+    pub fn without_discovery(mut self) -> Self {
+        self.discovery = Component::Disabled;
+        self
+    }
+
+    /// Start with actuating handlers in dry-run mode (overrides `DRY_RUN`).
+    /// See [`dry_run`].
+    pub fn with_dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = Some(enabled);
+        self
+    }
+
+    /// Start with actuators frozen for maintenance (overrides `SAFE_MODE`).
+    /// See [`safe_mode`].
+    pub fn with_safe_mode(mut self, enabled: bool) -> Self {
+        self.safe_mode = Some(enabled);
+        self
+    }
+
+    /// Render blinking and animations as static frames (overrides `REDUCED_MOTION`).
+    /// See [`reduced_motion`].
+    pub fn with_reduced_motion(mut self, enabled: bool) -> Self {
+        self.reduced_motion = Some(enabled);
+        self
+    }
+
+    /// Drop the templates of cards scrolled more than `rows` rows out of view, or keep them all
+    /// with `None` (overrides `TEMPLATE_EVICT_ROWS`). See [`template_eviction`].
+    pub fn with_template_evict_rows(mut self, rows: Option<usize>) -> Self {
+        self.template_evict_rows = Some(rows);
+        self
+    }
+
+    /// Open with this focus, selection and fullscreen state (overrides the `STARTUP_*` variables).
+    /// See [`startup`].
+    pub fn with_startup_view(mut self, startup_view: StartupView) -> Self {
+        self.startup_view = Some(startup_view);
+        self
+    }
+
+    /// Hold the splash screen this long and optionally advance past it without a keypress
+    /// (overrides `SPLASH_MIN_MS` and `SPLASH_AUTO_ADVANCE_MS`)
+    pub fn with_splash_timing(mut self, splash_timing: SplashTiming) -> Self {
+        self.splash_timing = Some(splash_timing);
+        self
+    }
+
+    /// Keep modules, databases, caches and exports in these directories (overrides the
+    /// `SURVON_*_DIR` variables). See [`dirs`](crate::util::dirs).
+    pub fn with_data_dirs(mut self, data_dirs: DataDirs) -> Self {
+        self.data_dirs = Some(data_dirs);
+        self
+    }
+
+    /// Directory of installable (wasteland) module manifests
+    pub fn with_modules_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.modules_path = Some(path.into());
+        self
+    }
+
+    /// Directory of built-in (core) module manifests
+    pub fn with_core_modules_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.core_modules_path = Some(path.into());
+        self
+    }
+
+    /// Listen on these bus topics instead of the built-in list (overrides `EVENT_TOPICS` and
+    /// `EXTRA_EVENT_TOPICS`). See [`event_topics`](crate::util::io::event_topics).
+    pub fn with_event_topics(mut self, topics: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.event_topics = Some(topics.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Wake `task` this often (overrides its `POLL_INTERVAL_MS` entry). See [`poll_interval`].
+    pub fn with_poll_interval(mut self, task: poll_interval::PollTask, interval: Duration) -> Self {
+        self.poll_intervals.push((task, interval));
+        self
+    }
+
+    /// Show a monitoring device as disconnected after this long without telemetry (overrides
+    /// `CONNECTION_TIMEOUT_MS`). See [`connection_timeout`].
+    pub fn with_connection_timeout(mut self, timeout: Duration) -> Self {
+        self.connection_timeout = Some(timeout);
+        self
+    }
+
+    /// Notify `observer` of module loads, handler registration, manifest errors and shutdown.
+    /// May be called more than once.
+    pub fn with_observer(mut self, observer: SharedObserver) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    /// Wire everything up. Module handlers are registered by [`App::run`] once the splash is
+    /// dismissed, behind a loading screen (or earlier with [`App::initialize_handlers`]).
+    pub async fn build(self) -> Result<App> {
+        if let Some(enabled) = self.dry_run {
+            dry_run::set_dry_run(enabled);
+        }
+        if dry_run::is_dry_run() {
+            log_info!("🧪 Dry run: actuating handlers will publish intended actions on '{}' instead", dry_run::DRY_RUN_TOPIC);
+        }
+        if let Some(enabled) = self.safe_mode {
+            safe_mode::set_safe_mode(enabled);
+        }
+        if safe_mode::is_safe_mode() {
+            log_info!("🛑 Safe mode: actuating handlers will refuse commands until it's turned off");
+        }
+        if let Some(enabled) = self.reduced_motion {
+            reduced_motion::set_reduced_motion(enabled);
+        }
+        if let Some(rows) = self.template_evict_rows {
+            template_eviction::set_template_evict_rows(rows);
+        }
+
+        let startup_view = match self.startup_view {
+            Some(startup_view) => startup_view,
+            None => StartupView::from_env()?,
+        };
+        let splash_timing = match self.splash_timing {
+            Some(splash_timing) => splash_timing,
+            None => SplashTiming::from_env()?,
+        };
+
+        let mut data_dirs = self.data_dirs.unwrap_or_else(DataDirs::from_env);
+        if let Some(path) = self.modules_path {
+            data_dirs.modules = path;
+        }
+        if let Some(path) = self.core_modules_path {
+            data_dirs.core_modules = path;
+        }
+        log_info!("📁 Modules in {}, databases in {}", data_dirs.modules.display(), data_dirs.database.display());
+        set_data_dirs(data_dirs.clone());
+
+        let mut image_cache = ImageCache::new();
+        if let Err(e) = image_cache.load_overview_header(data_dirs.assets.join("images").join("homestead-scene-3-wide.png")) {
+            log_error!("Failed to load overview header image: {}", e);
+        }
+
+        let core_modules_namespace= "core".to_string();
+        let mut core_module_manager = ModuleManager::new(data_dirs.core_modules.clone(), core_modules_namespace);
+
+        let wasteland_manifests_path = data_dirs.modules.clone();
+        let wasteland_modules_namespace= "wasteland".to_string();
+        let mut wasteland_module_manager = ModuleManager::new(wasteland_manifests_path.clone(), wasteland_modules_namespace);
+
+        for observer in &self.observers {
+            core_module_manager.add_observer(observer.clone());
+            wasteland_module_manager.add_observer(observer.clone());
+        }
+
+        for (task, interval) in self.poll_intervals {
+            poll_interval::set_poll_interval(task, interval);
+        }
+        if let Some(timeout) = self.connection_timeout {
+            connection_timeout::set_connection_timeout(timeout);
+        }
+        if let Some(topics) = self.event_topics {
+            set_event_topics(topics);
+        }
+
+        let (message_bus, bus_receiver) = match self.bus {
+            Some(bus) => bus,
+            None => MessageBus::new(),
+        };
+        // Before anything is written, so a nearly full card is flagged from the start
+        disk_watch::start();
+
+        let database = match self.database {
+            Some(database) => database,
+            None if std::env::var("IN_MEMORY_DB").unwrap_or_default() == "true" => {
+                log_info!("💾 Using in-memory database (nothing will be persisted)");
+                Database::in_memory()?
+            }
+            None => Database::new_implied_all_schemas()?,
+        };
+        audit::init(database.clone());
+
+        // Discover module manifests on startup
+        wasteland_module_manager.discover_modules().wrap_err("Failed to discover wasteland modules")?;
+        core_module_manager.discover_modules().wrap_err("Failed to discover core modules")?;
+
+        // Initialize DiscoveryManager for BLE field units
+        let discovery_manager = match self.discovery {
+            Component::Default => Some(Arc::new(DiscoveryManager::new(
+                message_bus.clone(),
+                wasteland_manifests_path.clone(),
+                database.clone(),
+            ))),
+            Component::Custom(discovery) => Some(discovery),
+            Component::Disabled => {
+                log_info!("🔌 BLE discovery disabled");
+                None
+            }
+        };
+
+        if let Some(discovery_manager) = &discovery_manager {
+            Self::start_discovery(discovery_manager.clone()).await;
+        }
+
+        // Subscribe module manager to events
+        wasteland_module_manager.subscribe_to_events(&message_bus).await;
+        core_module_manager.subscribe_to_events(&message_bus).await;
+
+        // Handlers register behind the loading screen once the splash is dismissed
+        let (progress_tx, progress_rx) = mpsc::unbounded_channel();
+        wasteland_module_manager.set_init_progress(Some(progress_tx.clone()));
+        core_module_manager.set_init_progress(Some(progress_tx));
+        let pending_handler_init = PendingHandlerInit {
+            manifests_path: wasteland_manifests_path.clone(),
+            progress: progress_rx,
+        };
+
+        // Initialize transport manager
+        let transport_manager = match self.transport {
+            Component::Default => {
+                let transport_manager = TransportManager::new(message_bus.clone());
+
+                // Add any custom outbound topics
+                transport_manager.add_outbound_topic("sensor_data".to_string()).await;
+                transport_manager.add_outbound_topic("arduino_ping".to_string()).await;
+                transport_manager.add_outbound_topic("device_registration".to_string()).await;
+
+                Some(transport_manager)
+            }
+            Component::Custom(transport_manager) => Some(transport_manager),
+            Component::Disabled => {
+                log_info!("🔌 Transports disabled");
+                None
+            }
+        };
+
+        // Knowledge ingestion
+        let ingester = KnowledgeIngester::new(&database);
+        if ingester.should_reingest()? {
+            ingester.ingest_changed_knowledge()?;
+        }
+
+        // Initialize Jukebox
+        let jukebox_ingester = JukeboxIngester::new(&database);
+        if jukebox_ingester.should_reingest()? {
+            log_info!("🎵 Ingesting album library...");
+            jukebox_ingester.ingest_albums(&core_module_manager)?;
+        }
+
+        let (jukebox_actor, jukebox_intent_tx) = JukeboxActor::new(message_bus.clone());
+        let jukebox_last_error = jukebox_actor.last_error();
+        tokio::spawn(async move {
+            jukebox_actor.run().await;
+        });
+
+        let jukebox_widget = JukeboxWidget::new(
+            database.clone(),
+            &message_bus,
+            jukebox_intent_tx,
+        ).await?.with_last_error(jukebox_last_error);
+
+        // Initialize Messages Window
+        let (messages_actor, messages_intent_tx) = MessagesActor::new(message_bus.clone());
+        tokio::spawn(async move {
+            messages_actor.run().await;
+        });
+
+        let messages_widget = MessagesWidget::new(
+            &message_bus,
+            messages_intent_tx,
+        ).await?;
+
+        let ui_state = UiState::load(&database);
+        ui_state.palette.activate();
+        let mut modules_list_widget = ModulesListWidget::new();
+        modules_list_widget.set_density(ui_state.density);
+        let mut module_detail_widget = ModuleDetailWidget::new();
+        module_detail_widget.set_density(ui_state.density);
+
+        // Knowledge cache GC: once at startup, then periodically
+        let document_manager = DocumentManager::new()?;
+        let cache_dir = document_manager.cache_dir();
+        tokio::spawn(async move {
+            let policy = CacheGcPolicy::from_env();
+            let mut interval = tokio::time::interval(CACHE_GC_INTERVAL);
+            loop {
+                interval.tick().await;
+                // Walking and deleting files blocks, so keep it off the async workers
+                let (cache_dir, policy) = (cache_dir.clone(), policy.clone());
+                match tokio::task::spawn_blocking(move || collect_cache_garbage(&cache_dir, &policy)).await {
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => log_error!("Knowledge cache GC failed: {}", e),
+                    Err(e) => log_error!("Knowledge cache GC task failed: {}", e),
+                }
+            }
+        });
+
+        let mut app = App {
+            running: true,
+            mode: AppMode::Splash,
+            needs_redraw: false,
+            splash_screen: Some(SplashScreen::new().with_timing(splash_timing)),
+            loading_screen: None,
+            pending_handler_init: Some(pending_handler_init),
+            image_cache,
+            start_time: Instant::now(),
+            palette: AdaptiveColors::detect(),
+            jukebox_widget: Some(jukebox_widget),
+            messages_widget: Some(messages_widget),
+            modules_list_widget,
+            module_detail_widget,
+            wasteland_module_manager,
+            core_module_manager,
+            message_bus,
+            bus_receiver,
+            database,
+            events: EventHandler::new(),
+            document_manager,
+            overview_focus: OverviewFocus::CoreModules,
+            show_key_help: false,
+            show_about: false,
+            bus_stats: None,
+            bus_inspector: None,
+            audit_log: None,
+            diagnostics: None,
+            ui_state,
+            age_labels_second: 0,
+            transport_manager,
+            discovery_manager,
+            observers: self.observers,
+        };
+        app.apply_startup_view(&startup_view)?;
+
+        Ok(app)
+    }
+
+    /// Bring up the adapter, reconnect trusted devices and start scheduler maintenance
+    async fn start_discovery(discovery_manager: Arc<DiscoveryManager>) {
+        let discovery_clone = discovery_manager.clone();
+        let discovery_clone_b = discovery_manager.clone();
+        tokio::spawn(async move {
+            // 1. Initialize BLE adapter
+            if let Err(e) = discovery_clone.start().await {
+                log_error!("Discovery manager failed to start: {}", e);
+                return;
+            }
+
+            // 2. Wait for stabilization
+            log_info!("🔌 BLE adapter initializing...");
+            tokio::time::sleep(Duration::from_secs(2)).await;
+
+            // 3. Auto-connect to trusted devices
+            log_info!("🔍 Connecting to trusted devices...");
+            match discovery_clone_b.connect_trusted_devices().await {
+                Ok(_) => log_info!("✅ Trusted device auto-connect complete"),
+                Err(e) => log_error!("❌ Auto-connect failed: {}", e),
+            }
+
+            log_info!("✅ BLE Discovery ready");
+        });
+
+        // Start scheduler maintenance
+        discovery_manager.clone().start_maintenance_task().await;
+    }
+
+    /// Start the transport manager (spawns background tasks), then ask field units to register
+    fn start_transport(transport_manager: TransportManager, message_bus: MessageBus) {
+        let transport_clone = transport_manager;
+        let bus_for_broadcast = message_bus;
+
+        tokio::spawn(async move {
+            // 1. Start transport manager
+            if let Err(e) = transport_clone.start().await {
+                log_error!("Transport manager failed to start: {}", e);
+                return; // abort early
+            }
+
+            // 2. Give devices time to connect
+            tokio::time::sleep(Duration::from_secs(5)).await;
+
+            // 3. Broadcast registration request
+            log_info!("Broadcasting device registration request to all field units...");
+
+            let _ = bus_for_broadcast.publish(BusMessage::new(
+                "device_registration".to_string(),
+                serde_json::json!({
+                "request": "capabilities",
+                "hub_id": "survon_hub",
+                "timestamp": std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs()
+                    }).to_string(),
+                "survon_hub".to_string(),
+            )).await;
+        });
+    }
+}
@@ -0,0 +1,27 @@
+// src/runtime/observer.rs
+
+use std::fmt::Debug;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::module::Module;
+
+/// Callbacks for embedders that want to follow the runtime's lifecycle.
+///
+/// Every method has an empty default, so implement only what you need.
+/// Callbacks run inline on the runtime's task; keep them short.
+pub trait RuntimeObserver: Send + Sync + Debug {
+    /// A manifest in `namespace` parsed and was added to the module list
+    fn on_module_loaded(&self, _namespace: &str, _module: &Module) {}
+
+    /// A handler was registered under `handler_key` (e.g. `monitoring_a01`, `llm`)
+    fn on_handler_registered(&self, _namespace: &str, _handler_key: &str) {}
+
+    /// The manifest in `path` could not be loaded
+    fn on_validation_error(&self, _namespace: &str, _path: &Path, _error: &str) {}
+
+    /// The run loop has exited
+    fn on_shutdown(&self) {}
+}
+
+pub type SharedObserver = Arc<dyn RuntimeObserver>;
@@ -0,0 +1,144 @@
+// src/runtime/poll_interval.rs
+
+//! How often each periodic task wakes up. Slow them down on battery, speed them up for a snappier
+//! dashboard: `POLL_INTERVAL_MS="scheduler=120000,ports=30000"` (or
+//! [`RuntimeBuilder::with_poll_interval`](super::RuntimeBuilder::with_poll_interval)). Tasks read
+//! their interval on every pass, so a change applies from the next wake-up. Monitoring and overseer
+//! modules can also set their own with a `poll_interval_ms` binding (see [`module_poll_interval`]).
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+use std::time::Duration;
+
+use crate::log_warn;
+use crate::module::ModuleConfig;
+
+/// The tasks that poll, named as in `POLL_INTERVAL_MS`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PollTask {
+    /// Monitoring handlers re-querying stored telemetry for the longer chart ranges
+    Monitoring,
+    /// The overseer re-reading discovered and known devices while its card is shown
+    Overseer,
+    /// Checking trusted BLE devices against their heartbeat windows
+    Liveness,
+    /// Pruning stale BLE command schedules
+    Scheduler,
+    /// Gap between queued BLE commands sent to the same device
+    Send,
+    /// Looking for newly plugged-in serial ports
+    Ports,
+    /// Checking for bus messages to send out over serial and BLE
+    Outbound,
+}
+
+impl PollTask {
+    pub const ALL: [PollTask; 7] = [
+        PollTask::Monitoring,
+        PollTask::Overseer,
+        PollTask::Liveness,
+        PollTask::Scheduler,
+        PollTask::Send,
+        PollTask::Ports,
+        PollTask::Outbound,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PollTask::Monitoring => "monitoring",
+            PollTask::Overseer => "overseer",
+            PollTask::Liveness => "liveness",
+            PollTask::Scheduler => "scheduler",
+            PollTask::Send => "send",
+            PollTask::Ports => "ports",
+            PollTask::Outbound => "outbound",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|task| task.name() == name.trim())
+    }
+
+    pub fn default_interval(self) -> Duration {
+        match self {
+            PollTask::Monitoring => Duration::from_secs(5),
+            PollTask::Overseer => Duration::from_secs(5),
+            PollTask::Liveness => Duration::from_secs(10),
+            PollTask::Scheduler => Duration::from_secs(60),
+            PollTask::Send => Duration::from_millis(100),
+            PollTask::Ports => Duration::from_secs(5),
+            PollTask::Outbound => Duration::from_millis(10),
+        }
+    }
+}
+
+static OVERRIDES: LazyLock<RwLock<HashMap<PollTask, Duration>>> =
+    LazyLock::new(|| RwLock::new(parse_overrides(&std::env::var("POLL_INTERVAL_MS").unwrap_or_default())));
+
+/// How long `task` sleeps between passes
+pub fn poll_interval(task: PollTask) -> Duration {
+    OVERRIDES
+        .read()
+        .ok()
+        .and_then(|overrides| overrides.get(&task).copied())
+        .unwrap_or_else(|| task.default_interval())
+}
+
+/// `task`'s interval for one module: its `poll_interval_ms` binding when set, otherwise [`poll_interval`]
+pub fn module_poll_interval(config: &ModuleConfig, task: PollTask) -> Duration {
+    config
+        .binding_u64("poll_interval_ms")
+        .filter(|ms| *ms > 0)
+        .map(Duration::from_millis)
+        .unwrap_or_else(|| poll_interval(task))
+}
+
+pub fn set_poll_interval(task: PollTask, interval: Duration) {
+    if let Ok(mut overrides) = OVERRIDES.write() {
+        overrides.insert(task, interval.max(Duration::from_millis(1)));
+    }
+}
+
+/// `name=ms` pairs, comma-separated. Unknown names and bad numbers are skipped with a warning.
+fn parse_overrides(spec: &str) -> HashMap<PollTask, Duration> {
+    let mut overrides = HashMap::new();
+    for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let parsed = entry
+            .split_once('=')
+            .and_then(|(name, ms)| Some((PollTask::parse(name)?, ms.trim().parse::<u64>().ok()?)));
+        match parsed {
+            Some((task, ms)) => {
+                overrides.insert(task, Duration::from_millis(ms.max(1)));
+            }
+            None => log_warn!("Ignoring POLL_INTERVAL_MS entry '{}'; expected one of {} as name=milliseconds",
+                entry, PollTask::ALL.map(PollTask::name).join(", ")),
+        }
+    }
+    overrides
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overrides_parse_by_task_name() {
+        let overrides = parse_overrides("scheduler=120000, ports = 30000,outbound=0,bogus=5,liveness=soon");
+        assert_eq!(overrides.len(), 3);
+        assert_eq!(overrides[&PollTask::Scheduler], Duration::from_secs(120));
+        assert_eq!(overrides[&PollTask::Ports], Duration::from_secs(30));
+        // Zero would spin
+        assert_eq!(overrides[&PollTask::Outbound], Duration::from_millis(1));
+    }
+
+    #[test]
+    fn test_module_binding_overrides_the_task_interval() {
+        use crate::util::test_util::TestModule;
+
+        let module = TestModule::new("Tank", "monitoring", "gauge_card").build();
+        assert_eq!(module_poll_interval(&module.config, PollTask::Monitoring), Duration::from_secs(5));
+
+        let module = TestModule::new("Tank", "monitoring", "gauge_card").binding("poll_interval_ms", 250.into()).build();
+        assert_eq!(module_poll_interval(&module.config, PollTask::Monitoring), Duration::from_millis(250));
+    }
+}
@@ -0,0 +1,47 @@
+// src/runtime/publish.rs
+
+use color_eyre::Result;
+
+use crate::app::App;
+use crate::util::io::bus::BusMessage;
+
+impl App {
+    /// Feed a reading into the runtime as if a device had sent it.
+    ///
+    /// Goes out on `topic` with `topic` as the source, which is what serial and BLE
+    /// devices do (their device id is both), so monitoring and valve handlers whose
+    /// `device_id` is `topic` pick it up. `c` is the integer field of the SSP payload.
+    pub async fn publish_telemetry(&self, topic: &str, a: f64, b: f64, c: i64) -> Result<()> {
+        self.publish(telemetry_message(topic, a, b, c)).await
+    }
+
+    /// Publish an arbitrary message on the runtime's bus, subject to the usual routing
+    pub async fn publish(&self, message: BusMessage) -> Result<()> {
+        self.message_bus.publish(message).await
+    }
+}
+
+fn telemetry_message(topic: &str, a: f64, b: f64, c: i64) -> BusMessage {
+    BusMessage::new(
+        topic.to_string(),
+        serde_json::json!({ "a": a, "b": b, "c": c }).to_string(),
+        topic.to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_telemetry_message_uses_direct_payload_format() {
+        let message = telemetry_message("a01", 72.5, 45.0, 335);
+        assert_eq!(message.topic, "a01");
+        assert_eq!(message.source, "a01");
+
+        let payload: serde_json::Value = serde_json::from_str(&message.payload).unwrap();
+        assert_eq!(payload["a"].as_f64(), Some(72.5));
+        assert_eq!(payload["b"].as_f64(), Some(45.0));
+        assert_eq!(payload["c"].as_i64(), Some(335));
+    }
+}
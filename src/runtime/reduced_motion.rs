@@ -0,0 +1,25 @@
+// src/runtime/reduced_motion.rs
+
+//! Global reduced-motion switch: blinking cards, the splash rainbow and other frame-driven
+//! effects render a static equivalent instead of moving.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+static FROM_ENV: Once = Once::new();
+
+/// Whether animations should hold still. Starts from `REDUCED_MOTION=true` unless set explicitly.
+pub fn is_reduced_motion() -> bool {
+    FROM_ENV.call_once(|| {
+        if std::env::var("REDUCED_MOTION").unwrap_or_default() == "true" {
+            REDUCED_MOTION.store(true, Ordering::Relaxed);
+        }
+    });
+    REDUCED_MOTION.load(Ordering::Relaxed)
+}
+
+pub fn set_reduced_motion(enabled: bool) {
+    FROM_ENV.call_once(|| {});
+    REDUCED_MOTION.store(enabled, Ordering::Relaxed);
+}
@@ -0,0 +1,56 @@
+// src/runtime/safe_mode.rs
+
+//! Global safe-mode switch for maintenance: actuating handlers refuse commands so valves and
+//! relays hold their current state, while telemetry and monitoring carry on as usual.
+//!
+//! Unlike dry-run nothing is announced as intended; commands are rejected outright. Toggled with
+//! Ctrl+P, and every change is published on [`SAFE_MODE_TOPIC`].
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+use crate::util::io::bus::BusMessage;
+
+/// Topic safe-mode changes are published on, with `{"enabled": bool}`
+pub const SAFE_MODE_TOPIC: &str = "safe_mode";
+
+static SAFE_MODE: AtomicBool = AtomicBool::new(false);
+static FROM_ENV: Once = Once::new();
+
+/// Whether actuators are frozen. Starts from `SAFE_MODE=true` unless set explicitly.
+pub fn is_safe_mode() -> bool {
+    FROM_ENV.call_once(|| {
+        if std::env::var("SAFE_MODE").unwrap_or_default() == "true" {
+            SAFE_MODE.store(true, Ordering::Relaxed);
+        }
+    });
+    SAFE_MODE.load(Ordering::Relaxed)
+}
+
+pub fn set_safe_mode(enabled: bool) {
+    FROM_ENV.call_once(|| {});
+    SAFE_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// The bus message announcing that safe mode was turned on or off by `source`
+pub fn safe_mode_message(source: &str, enabled: bool) -> BusMessage {
+    BusMessage::new(
+        SAFE_MODE_TOPIC.to_string(),
+        serde_json::json!({ "enabled": enabled }).to_string(),
+        source.to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_mode_message_carries_the_new_state() {
+        let message = safe_mode_message("survon_tui", true);
+
+        assert_eq!(message.topic, SAFE_MODE_TOPIC);
+        let body: serde_json::Value = serde_json::from_str(&message.payload).unwrap();
+        assert_eq!(body["enabled"], true);
+    }
+}
@@ -0,0 +1,95 @@
+// src/runtime/startup.rs
+
+//! Where the dashboard opens: which panel has focus, which module is selected and whether that
+//! module starts fullscreen, so a kiosk can boot straight into its main card.
+
+use color_eyre::{eyre::eyre, Result};
+
+use crate::app::{App, AppMode, OverviewFocus};
+
+/// Startup focus and selection, from `STARTUP_FOCUS`, `STARTUP_MODULE` and `STARTUP_FULLSCREEN`
+/// or [`RuntimeBuilder::with_startup_view`](super::RuntimeBuilder::with_startup_view)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StartupView {
+    /// Panel focused on the overview; defaults to the selected module's panel, else core modules
+    pub focus: Option<OverviewFocus>,
+    /// Name of the module to select (case-insensitive)
+    pub module: Option<String>,
+    /// Open the selected module fullscreen, skipping the splash screen
+    pub fullscreen: bool,
+}
+
+impl StartupView {
+    pub fn from_env() -> Result<Self> {
+        let focus = match std::env::var("STARTUP_FOCUS") {
+            Ok(focus) if !focus.is_empty() => Some(parse_focus(&focus)?),
+            _ => None,
+        };
+
+        Ok(Self {
+            focus,
+            module: std::env::var("STARTUP_MODULE").ok().filter(|name| !name.is_empty()),
+            fullscreen: std::env::var("STARTUP_FULLSCREEN").unwrap_or_default() == "true",
+        })
+    }
+}
+
+fn parse_focus(focus: &str) -> Result<OverviewFocus> {
+    match focus.to_lowercase().replace('-', "_").as_str() {
+        "none" => Ok(OverviewFocus::None),
+        "wasteland" | "wasteland_modules" => Ok(OverviewFocus::WastelandModules),
+        "core" | "core_modules" => Ok(OverviewFocus::CoreModules),
+        "messages" => Ok(OverviewFocus::Messages),
+        "jukebox" => Ok(OverviewFocus::Jukebox),
+        _ => Err(eyre!(
+            "Unrecognized STARTUP_FOCUS '{}'. Must be one of: none, wasteland, core, messages, jukebox",
+            focus
+        )),
+    }
+}
+
+impl App {
+    /// Apply `startup` to freshly loaded modules. Fails if the named module isn't on the
+    /// dashboard or fullscreen is asked for without a module panel to take it from.
+    pub fn apply_startup_view(&mut self, startup: &StartupView) -> Result<()> {
+        let mut module_focus = None;
+
+        if let Some(name) = &startup.module {
+            let found = [
+                (OverviewFocus::WastelandModules, &mut self.wasteland_module_manager),
+                (OverviewFocus::CoreModules, &mut self.core_module_manager),
+            ]
+            .into_iter()
+            .find_map(|(focus, module_manager)| module_manager.select_module_named(name).then_some(focus));
+
+            module_focus = Some(found.ok_or_else(|| eyre!("STARTUP_MODULE '{}' is not a dashboard module", name))?);
+        }
+
+        if let Some(focus) = startup.focus.clone().or(module_focus) {
+            self.overview_focus = focus;
+        }
+
+        if startup.fullscreen {
+            if !matches!(self.overview_focus, OverviewFocus::WastelandModules | OverviewFocus::CoreModules) {
+                return Err(eyre!("STARTUP_FULLSCREEN needs a module panel focused or a STARTUP_MODULE"));
+            }
+            self.mode = AppMode::Overview;
+            self.splash_screen = None;
+            self.toggle_fullscreen_detail();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_focus() {
+        assert_eq!(parse_focus("Wasteland").unwrap(), OverviewFocus::WastelandModules);
+        assert_eq!(parse_focus("core-modules").unwrap(), OverviewFocus::CoreModules);
+        assert!(parse_focus("sidebar").is_err());
+    }
+}
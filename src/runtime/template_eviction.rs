@@ -0,0 +1,50 @@
+// src/runtime/template_eviction.rs
+
+//! How far out of view a module card can scroll before its template instance is dropped. Templates
+//! are only created once a card is about to be drawn; on a large dashboard, `TEMPLATE_EVICT_ROWS=3`
+//! also frees the ones more than three rows beyond the visible grid. A card's blink and animation
+//! state live on the module, so an evicted card looks the same when it's scrolled back.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+
+/// Rows beyond the viewport, plus one; 0 keeps every template once created
+static EVICT_ROWS: AtomicUsize = AtomicUsize::new(0);
+static FROM_ENV: Once = Once::new();
+
+/// Rows out of view after which a card's template is dropped, or `None` to keep them all.
+/// Starts from `TEMPLATE_EVICT_ROWS` unless set explicitly.
+pub fn template_evict_rows() -> Option<usize> {
+    FROM_ENV.call_once(|| {
+        if let Some(rows) = parse_rows(&std::env::var("TEMPLATE_EVICT_ROWS").unwrap_or_default()) {
+            EVICT_ROWS.store(rows + 1, Ordering::Relaxed);
+        }
+    });
+    EVICT_ROWS.load(Ordering::Relaxed).checked_sub(1)
+}
+
+pub fn set_template_evict_rows(rows: Option<usize>) {
+    FROM_ENV.call_once(|| {});
+    EVICT_ROWS.store(rows.map_or(0, |rows| rows + 1), Ordering::Relaxed);
+}
+
+fn parse_rows(value: &str) -> Option<usize> {
+    value.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rows_parse_and_round_trip() {
+        assert_eq!(parse_rows(" 3 "), Some(3));
+        assert_eq!(parse_rows("far"), None);
+        assert_eq!(parse_rows(""), None);
+
+        set_template_evict_rows(Some(0));
+        assert_eq!(template_evict_rows(), Some(0));
+        set_template_evict_rows(None);
+        assert_eq!(template_evict_rows(), None);
+    }
+}
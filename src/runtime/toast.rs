@@ -0,0 +1,120 @@
+// src/runtime/toast.rs
+
+//! Transient notifications: handlers push a message with a severity and the app shows it over
+//! whatever is on screen until it expires, instead of each card keeping its own status line.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Base lifetime of info and success toasts, overridable with `TOAST_SECS`
+const DEFAULT_TOAST_SECS: u64 = 4;
+/// Older toasts are dropped past this many
+const MAX_TOASTS: usize = 4;
+
+static TOASTS: Mutex<ToastQueue> = Mutex::new(ToastQueue::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    /// Warnings linger twice as long as info, errors three times
+    pub fn ttl(self) -> Duration {
+        let base = std::env::var("TOAST_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_TOAST_SECS);
+        let factor = match self {
+            ToastLevel::Info | ToastLevel::Success => 1,
+            ToastLevel::Warning => 2,
+            ToastLevel::Error => 3,
+        };
+        Duration::from_secs(base * factor)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub level: ToastLevel,
+    pub message: String,
+    pub expires_at: Instant,
+}
+
+/// Live toasts, oldest first
+#[derive(Debug)]
+pub struct ToastQueue {
+    toasts: VecDeque<Toast>,
+}
+
+impl ToastQueue {
+    pub const fn new() -> Self {
+        Self { toasts: VecDeque::new() }
+    }
+
+    /// Add a toast living `ttl` from `now`. Repeating the newest message just extends it.
+    pub fn push(&mut self, level: ToastLevel, message: String, ttl: Duration, now: Instant) {
+        let expires_at = now + ttl;
+        if let Some(last) = self.toasts.back_mut() {
+            if last.level == level && last.message == message {
+                last.expires_at = expires_at;
+                return;
+            }
+        }
+
+        self.toasts.push_back(Toast { level, message, expires_at });
+        while self.toasts.len() > MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+    }
+
+    /// Drop what has expired by `now` and return the rest
+    pub fn active(&mut self, now: Instant) -> Vec<Toast> {
+        self.toasts.retain(|toast| toast.expires_at > now);
+        self.toasts.iter().cloned().collect()
+    }
+}
+
+/// Show `message` to the user for the level's lifetime
+pub fn notify(level: ToastLevel, message: impl Into<String>) {
+    let mut toasts = TOASTS.lock().unwrap();
+    toasts.push(level, message.into(), level.ttl(), Instant::now());
+}
+
+/// Toasts that haven't expired yet, oldest first
+pub fn active_toasts() -> Vec<Toast> {
+    TOASTS.lock().unwrap().active(Instant::now())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toasts_expire_and_repeats_extend() {
+        let mut queue = ToastQueue::new();
+        let start = Instant::now();
+        let ttl = Duration::from_secs(4);
+
+        queue.push(ToastLevel::Success, "✓ Saved".to_string(), ttl, start);
+        queue.push(ToastLevel::Error, "❌ Save failed".to_string(), ttl * 3, start);
+        queue.push(ToastLevel::Error, "❌ Save failed".to_string(), ttl * 3, start + Duration::from_secs(2));
+        assert_eq!(queue.active(start).len(), 2);
+
+        let later = queue.active(start + Duration::from_secs(5));
+        assert_eq!(later.len(), 1);
+        assert_eq!(later[0].level, ToastLevel::Error);
+        assert_eq!(later[0].expires_at, start + Duration::from_secs(14));
+
+        for i in 0..6 {
+            queue.push(ToastLevel::Info, format!("step {}", i), ttl, start);
+        }
+        let active = queue.active(start);
+        assert_eq!(active.len(), MAX_TOASTS);
+        assert_eq!(active.last().unwrap().message, "step 5");
+    }
+}
@@ -0,0 +1,168 @@
+// src/runtime/undo.rs
+
+//! App-wide undo/redo: handlers record each destructive operation as a reversible command and
+//! Ctrl+Z / Ctrl+Y hand it back to the same handler to reverse or repeat.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::log_warn;
+
+/// Older operations fall off the bottom of the history past this many
+const MAX_UNDO: usize = 50;
+
+static HISTORY: Mutex<UndoHistory> = Mutex::new(UndoHistory::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UndoDirection {
+    Undo,
+    Redo,
+}
+
+/// One recorded operation
+#[derive(Debug, Clone, PartialEq)]
+pub struct UndoEntry {
+    /// Key of the handler that performed it and knows its inverse
+    pub handler: String,
+    /// What was done, shown as "Undid <label>"
+    pub label: String,
+    /// The handler's own description of the operation
+    pub op: serde_json::Value,
+}
+
+/// Operations that can be undone (newest last) and ones that were undone and can be redone
+#[derive(Debug)]
+pub struct UndoHistory {
+    done: VecDeque<UndoEntry>,
+    undone: Vec<UndoEntry>,
+}
+
+impl UndoHistory {
+    pub const fn new() -> Self {
+        Self { done: VecDeque::new(), undone: Vec::new() }
+    }
+
+    /// Record a fresh operation. Anything undone before it can no longer be redone.
+    pub fn push(&mut self, entry: UndoEntry) {
+        self.undone.clear();
+        self.push_done(entry);
+    }
+
+    /// The newest operation to undo or redo, removed until [`Self::finish`] puts it back
+    pub fn take(&mut self, direction: UndoDirection) -> Option<UndoEntry> {
+        match direction {
+            UndoDirection::Undo => self.done.pop_back(),
+            UndoDirection::Redo => self.undone.pop(),
+        }
+    }
+
+    /// File an entry that was just undone (or redone) so it can be redone (or undone) next
+    pub fn finish(&mut self, direction: UndoDirection, entry: UndoEntry) {
+        match direction {
+            UndoDirection::Undo => self.undone.push(entry),
+            UndoDirection::Redo => self.push_done(entry),
+        }
+    }
+
+    /// Put back an entry that couldn't be undone (or redone), so the user can try again
+    pub fn restore(&mut self, direction: UndoDirection, entry: UndoEntry) {
+        match direction {
+            UndoDirection::Undo => self.push_done(entry),
+            UndoDirection::Redo => self.undone.push(entry),
+        }
+    }
+
+    fn push_done(&mut self, entry: UndoEntry) {
+        self.done.push_back(entry);
+        while self.done.len() > MAX_UNDO {
+            self.done.pop_front();
+        }
+    }
+}
+
+/// Remember an operation `handler` just performed so Ctrl+Z can reverse it
+pub fn record(handler: &str, label: impl Into<String>, op: &impl Serialize) {
+    let label = label.into();
+    match serde_json::to_value(op) {
+        Ok(op) => HISTORY.lock().unwrap().push(UndoEntry { handler: handler.to_string(), label, op }),
+        Err(e) => log_warn!("Not recording '{}' for undo: {}", label, e),
+    }
+}
+
+pub fn take(direction: UndoDirection) -> Option<UndoEntry> {
+    HISTORY.lock().unwrap().take(direction)
+}
+
+pub fn finish(direction: UndoDirection, entry: UndoEntry) {
+    HISTORY.lock().unwrap().finish(direction, entry);
+}
+
+pub fn restore(direction: UndoDirection, entry: UndoEntry) {
+    HISTORY.lock().unwrap().restore(direction, entry);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(label: &str) -> UndoEntry {
+        UndoEntry { handler: "overseer".to_string(), label: label.to_string(), op: serde_json::json!({}) }
+    }
+
+    #[test]
+    fn test_undo_redo_and_new_operations_clear_redo() {
+        let mut history = UndoHistory::new();
+        history.push(entry("trust"));
+        history.push(entry("archive"));
+
+        let undone = history.take(UndoDirection::Undo).unwrap();
+        assert_eq!(undone.label, "archive");
+        history.finish(UndoDirection::Undo, undone);
+
+        let redone = history.take(UndoDirection::Redo).unwrap();
+        assert_eq!(redone.label, "archive");
+        history.finish(UndoDirection::Redo, redone);
+        assert!(history.take(UndoDirection::Redo).is_none());
+
+        let undone = history.take(UndoDirection::Undo).unwrap();
+        history.finish(UndoDirection::Undo, undone);
+        history.push(entry("delete"));
+        assert!(history.take(UndoDirection::Redo).is_none());
+        assert_eq!(history.take(UndoDirection::Undo).unwrap().label, "delete");
+        assert_eq!(history.take(UndoDirection::Undo).unwrap().label, "trust");
+    }
+
+    #[test]
+    fn test_failed_steps_go_back_where_they_came_from() {
+        let mut history = UndoHistory::new();
+        history.push(entry("trust"));
+
+        let failed = history.take(UndoDirection::Undo).unwrap();
+        history.restore(UndoDirection::Undo, failed);
+        assert!(history.take(UndoDirection::Redo).is_none());
+
+        let undone = history.take(UndoDirection::Undo).unwrap();
+        history.finish(UndoDirection::Undo, undone);
+        let failed = history.take(UndoDirection::Redo).unwrap();
+        history.restore(UndoDirection::Redo, failed);
+        assert_eq!(history.take(UndoDirection::Redo).unwrap().label, "trust");
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut history = UndoHistory::new();
+        for i in 0..MAX_UNDO + 5 {
+            history.push(entry(&format!("op {}", i)));
+        }
+
+        let mut count = 0;
+        let mut oldest = None;
+        while let Some(entry) = history.take(UndoDirection::Undo) {
+            count += 1;
+            oldest = Some(entry.label);
+        }
+        assert_eq!(count, MAX_UNDO);
+        assert_eq!(oldest.as_deref(), Some("op 5"));
+    }
+}
@@ -0,0 +1,335 @@
+// src/runtime/watchdog.rs
+
+//! Restarts handler background tasks that died or stopped making progress.
+//!
+//! A handler hands [`supervise`] a function that starts its task. The task calls [`Heartbeat::beat`]
+//! as it works and waits for input through [`Heartbeat::idle`], so a quiet bus isn't mistaken for a
+//! wedge. The watchdog started by the runtime respawns a task whose heartbeat is older than
+//! `WATCHDOG_STALL_SECS` or that has exited, and publishes a [`RestartEvent`] on `handler.restarted`.
+//! Repeat restarts back off exponentially, and a task that keeps failing is given up on.
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+use crate::runtime::isolation;
+use crate::runtime::toast::{notify, ToastLevel};
+use crate::util::io::bus::{BusMessage, MessageBus};
+use crate::{log_error, log_warn};
+
+pub const HANDLER_RESTARTED_TOPIC: &str = "handler.restarted";
+
+/// How long a task may go without a heartbeat before it's restarted, overridable with `WATCHDOG_STALL_SECS`
+const DEFAULT_STALL_SECS: u64 = 120;
+/// Idle tasks beat at least this often
+const IDLE_BEAT: Duration = Duration::from_secs(30);
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+/// Consecutive restarts after which the watchdog gives up on a task
+const MAX_RESTARTS: u32 = 5;
+/// The wait before a repeat restart, doubling with each consecutive one up to `MAX_BACKOFF`
+const BASE_BACKOFF: Duration = Duration::from_secs(10);
+const MAX_BACKOFF: Duration = Duration::from_secs(10 * 60);
+/// A task that has run this long since its last restart is healthy again: no backoff next time
+const HEALTHY_AFTER: Duration = MAX_BACKOFF;
+
+static SUPERVISOR: Mutex<Supervisor> = Mutex::new(Supervisor::new());
+
+/// Time of a supervised task's last progress, on the monotonic clock so a wall-clock step
+/// can't make every task look stalled
+#[derive(Debug, Clone)]
+pub struct Heartbeat(Arc<HeartbeatState>);
+
+#[derive(Debug)]
+struct HeartbeatState {
+    started: Instant,
+    /// Millis after `started` of the last beat
+    last_beat: AtomicU64,
+}
+
+impl Heartbeat {
+    fn new() -> Self {
+        Self(Arc::new(HeartbeatState { started: Instant::now(), last_beat: AtomicU64::new(0) }))
+    }
+
+    pub fn beat(&self) {
+        self.0.last_beat.store(self.0.started.elapsed().as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Await `next` (typically a bus `recv`), beating while it's pending
+    pub async fn idle<F: Future>(&self, next: F) -> F::Output {
+        tokio::pin!(next);
+        loop {
+            self.beat();
+            if let Ok(output) = tokio::time::timeout(IDLE_BEAT, &mut next).await {
+                self.beat();
+                return output;
+            }
+        }
+    }
+
+    fn silent_for(&self) -> Duration {
+        let last_beat = Duration::from_millis(self.0.last_beat.load(Ordering::Relaxed));
+        self.0.started.elapsed().saturating_sub(last_beat)
+    }
+}
+
+/// Payload of `handler.restarted`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RestartEvent {
+    pub handler: String,
+    pub reason: String,
+    /// Restarts of this task so far, including this one
+    pub restarts: u32,
+    /// Set on the last event for a task the watchdog stopped restarting
+    #[serde(default)]
+    pub gave_up: bool,
+}
+
+type Start = Box<dyn Fn(Heartbeat) -> BoxFuture<'static, ()> + Send + Sync>;
+
+struct SupervisedTask {
+    handler: String,
+    start: Start,
+    heartbeat: Heartbeat,
+    handle: JoinHandle<Result<(), String>>,
+    restarts: u32,
+    /// Restarts since the task last ran for `HEALTHY_AFTER`
+    consecutive: u32,
+    restarted_at: Option<Instant>,
+    /// Why the task needs restarting, once noticed, and when the backoff lets it
+    pending: Option<(String, Instant)>,
+    gave_up: bool,
+}
+
+impl SupervisedTask {
+    /// Why the task needs restarting, if it does
+    fn failure(&mut self, stall_after: Duration) -> Option<String> {
+        if self.handle.is_finished() {
+            let panic = (&mut self.handle).now_or_never().and_then(|joined| joined.ok()?.err());
+            return Some(panic.map_or("task exited".to_string(), |message| format!("panicked: {}", message)));
+        }
+        let silent = self.heartbeat.silent_for();
+        (silent > stall_after).then(|| format!("no progress for {}s", silent.as_secs()))
+    }
+
+    /// The earliest a restart may follow the last one
+    fn retry_at(&self, now: Instant) -> Instant {
+        let Some(restarted_at) = self.restarted_at else {
+            return now;
+        };
+        let doublings = self.consecutive.saturating_sub(1).min(16);
+        restarted_at + BASE_BACKOFF.saturating_mul(1 << doublings).min(MAX_BACKOFF)
+    }
+
+    fn restart(&mut self, now: Instant) {
+        self.handle.abort();
+        isolation::clear_failure(&self.handler);
+        self.heartbeat = Heartbeat::new();
+        self.handle = tokio::spawn(isolation::isolate(&self.handler, (self.start)(self.heartbeat.clone())));
+        self.restarts += 1;
+        self.consecutive += 1;
+        self.restarted_at = Some(now);
+    }
+
+    fn give_up(&mut self, reason: &str) {
+        self.handle.abort();
+        self.gave_up = true;
+        log_error!(target: &self.handler, "🐕 Giving up on {} task after {} restarts: {}", self.handler, self.consecutive, reason);
+        notify(ToastLevel::Error, format!("{} stopped after {} restarts: {}", self.handler, self.consecutive, reason));
+    }
+}
+
+/// The supervised tasks; the runtime's live in one global, tests keep their own
+struct Supervisor {
+    tasks: Vec<SupervisedTask>,
+}
+
+impl Supervisor {
+    const fn new() -> Self {
+        Self { tasks: Vec::new() }
+    }
+
+    fn supervise(&mut self, handler: &str, start: Start) {
+        let heartbeat = Heartbeat::new();
+        let handle = tokio::spawn(isolation::isolate(handler, start(heartbeat.clone())));
+
+        self.tasks.push(SupervisedTask {
+            handler: handler.to_string(),
+            start,
+            heartbeat,
+            handle,
+            restarts: 0,
+            consecutive: 0,
+            restarted_at: None,
+            pending: None,
+            gave_up: false,
+        });
+    }
+
+    fn stop(&mut self, handler: &str) -> usize {
+        let before = self.tasks.len();
+        self.tasks.retain(|task| {
+            if task.handler != handler {
+                return true;
+            }
+            task.handle.abort();
+            false
+        });
+        before - self.tasks.len()
+    }
+
+    fn check(&mut self, stall_after: Duration, now: Instant) -> Vec<RestartEvent> {
+        let mut events = Vec::new();
+
+        for task in self.tasks.iter_mut().filter(|task| !task.gave_up) {
+            if task.pending.is_none() {
+                match task.failure(stall_after) {
+                    Some(reason) => task.pending = Some((reason, task.retry_at(now))),
+                    None => {
+                        if task.restarted_at.is_some_and(|at| now.saturating_duration_since(at) >= HEALTHY_AFTER) {
+                            task.consecutive = 0;
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            let Some((reason, retry_at)) = task.pending.take() else {
+                continue;
+            };
+            if now < retry_at {
+                task.pending = Some((reason, retry_at));
+                continue;
+            }
+
+            if task.consecutive >= MAX_RESTARTS {
+                task.give_up(&reason);
+                events.push(RestartEvent { handler: task.handler.clone(), reason, restarts: task.restarts, gave_up: true });
+                continue;
+            }
+
+            log_warn!(target: &task.handler, "🐕 Restarting {} task: {}", task.handler, reason);
+            task.restart(now);
+            events.push(RestartEvent { handler: task.handler.clone(), reason, restarts: task.restarts, gave_up: false });
+        }
+
+        events
+    }
+}
+
+/// Spawn `start`'s task for `handler` now, and again whenever the watchdog finds it dead or stuck
+pub fn supervise<F, Fut>(handler: &str, start: F)
+where
+    F: Fn(Heartbeat) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    SUPERVISOR.lock().unwrap().supervise(handler, boxed(start));
+}
+
+fn boxed<F, Fut>(start: F) -> Start
+where
+    F: Fn(Heartbeat) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    Box::new(move |heartbeat| Box::pin(start(heartbeat)))
+}
+
+/// Stop `handler`'s tasks for good, e.g. when its module is removed. Returns how many were running.
+pub fn stop(handler: &str) -> usize {
+    SUPERVISOR.lock().unwrap().stop(handler)
+}
+
+/// Restart every supervised task that exited or went quiet for longer than `stall_after`, backing
+/// off exponentially for one that keeps failing and giving up after `MAX_RESTARTS` in a row
+pub fn check(stall_after: Duration) -> Vec<RestartEvent> {
+    SUPERVISOR.lock().unwrap().check(stall_after, Instant::now())
+}
+
+/// Check supervised tasks periodically for the life of the process, reporting restarts on the bus
+pub fn start(message_bus: MessageBus) {
+    let stall_after = Duration::from_secs(
+        std::env::var("WATCHDOG_STALL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_STALL_SECS),
+    );
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            for event in check(stall_after) {
+                let payload = match serde_json::to_string(&event) {
+                    Ok(payload) => payload,
+                    Err(e) => {
+                        log_error!("Failed to encode restart of {}: {}", event.handler, e);
+                        continue;
+                    }
+                };
+                if let Err(e) = message_bus
+                    .publish(BusMessage::new(HANDLER_RESTARTED_TOPIC.to_string(), payload, "watchdog".to_string()))
+                    .await
+                {
+                    log_error!("Failed to publish restart of {}: {}", event.handler, e);
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    #[tokio::test]
+    async fn test_restarts_with_backoff_then_gives_up() {
+        let mut supervisor = Supervisor::new();
+        let starts = Arc::new(AtomicU32::new(0));
+        let counter = starts.clone();
+        supervisor.supervise("watchdog_test", boxed(move |_heartbeat| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            async {}
+        }));
+
+        let stall = Duration::from_secs(60);
+        let mut at = Instant::now();
+        for restarts in 1..=MAX_RESTARTS {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let events = supervisor.check(stall, at);
+            let exited = RestartEvent { handler: "watchdog_test".to_string(), reason: "task exited".to_string(), restarts, gave_up: false };
+            assert_eq!(events, vec![exited]);
+
+            // It exits again straight away, but waits out the backoff
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            assert!(supervisor.check(stall, at + BASE_BACKOFF / 2).is_empty());
+            at += MAX_BACKOFF;
+        }
+        assert_eq!(starts.load(Ordering::Relaxed), MAX_RESTARTS + 1);
+
+        let events = supervisor.check(stall, at);
+        assert!(events[0].gave_up);
+        assert!(supervisor.check(stall, at + MAX_BACKOFF).is_empty());
+        assert_eq!(starts.load(Ordering::Relaxed), MAX_RESTARTS + 1);
+    }
+
+    #[tokio::test]
+    async fn test_restarts_stuck_tasks_until_stopped() {
+        let mut supervisor = Supervisor::new();
+
+        // A task that never beats after starting counts as stuck once the threshold passes
+        supervisor.supervise("watchdog_stuck", boxed(|_heartbeat| std::future::pending::<()>()));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let events = supervisor.check(Duration::from_millis(5), Instant::now());
+        assert!(events.iter().any(|e| e.handler == "watchdog_stuck" && e.reason.starts_with("no progress")));
+
+        // Stopped tasks aren't restarted
+        assert_eq!(supervisor.stop("watchdog_stuck"), 1);
+        assert!(supervisor.check(Duration::from_millis(5), Instant::now() + MAX_BACKOFF).is_empty());
+    }
+}
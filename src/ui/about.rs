@@ -0,0 +1,81 @@
+// src/ui/about.rs
+//! The Ctrl+V about overlay: version, git commit, build date and which optional features are
+//! compiled in, for quoting in bug reports. `survon --version` prints the same.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, Paragraph, Widget},
+};
+
+use crate::runtime::features::FEATURES;
+use crate::util::version::{build_date, GIT_HASH, VERSION};
+
+const WIDTH: u16 = 100;
+
+/// Centered box over whatever is on screen
+pub fn render_about(area: Rect, buf: &mut Buffer) {
+    let row = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<10}", label), Style::default().fg(Color::Cyan)),
+            Span::raw(value),
+        ])
+    };
+    let mut lines = vec![
+        Line::from("Survon runtime").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Line::from(""),
+        row("Version", VERSION.to_string()),
+        row("Commit", GIT_HASH.to_string()),
+        row("Built", build_date()),
+        Line::from(""),
+        Line::from("Features").style(Style::default().fg(Color::Cyan)),
+    ];
+    // Features that are off are listed too, dimmed, so a missing subsystem is explained
+    lines.extend(FEATURES.iter().map(|feature| {
+        let (mark, style) = if feature.enabled {
+            ("✓", Style::default().fg(Color::Green))
+        } else {
+            ("·", Style::default().fg(Color::DarkGray))
+        };
+        Line::from(format!("  {} {:<22} {}", mark, feature.name, feature.description)).style(style)
+    }));
+
+    let width = area.width.min(WIDTH);
+    let height = area.height.min(lines.len() as u16 + 2);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    Clear.render(popup, buf);
+    Paragraph::new(lines)
+        .block(
+            Block::bordered()
+                .title(" ℹ About - [Esc] Close ")
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(Color::Yellow)),
+        )
+        .render(popup, buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_about_shows_the_commit() {
+        let area = Rect::new(0, 0, 100, 16);
+        let mut buf = Buffer::empty(area);
+        render_about(area, &mut buf);
+
+        let text: String = (0..area.height)
+            .flat_map(|y| (0..area.width).map(move |x| (x, y)))
+            .map(|(x, y)| buf[(x, y)].symbol().to_string())
+            .collect();
+        assert!(text.contains(VERSION) && text.contains(GIT_HASH));
+    }
+}
@@ -0,0 +1,121 @@
+// src/ui/audit_log.rs
+//! The Ctrl+A overlay: recent entries of the actuation audit log, newest first, loaded when it opens.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, BorderType, Clear, Paragraph, Widget},
+};
+
+use crate::runtime::audit::AuditEntry;
+
+/// Entries loaded when the overlay opens
+pub const AUDIT_LOG_LIMIT: usize = 200;
+const WIDTH: u16 = 110;
+
+#[derive(Debug)]
+pub struct AuditLogOverlay {
+    entries: Vec<AuditEntry>,
+    scroll: usize,
+}
+
+impl AuditLogOverlay {
+    pub fn new(entries: Vec<AuditEntry>) -> Self {
+        Self { entries, scroll: 0 }
+    }
+
+    /// Move `delta` entries down (negative is up), staying within the list
+    pub fn scroll(&mut self, delta: isize) {
+        let last = self.entries.len().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(delta).min(last);
+    }
+
+    /// Centered box over whatever is on screen
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        let header = Line::from(format!(
+            "{:<15} {:<14} {:<11} {:<12} {:<8} {:<8} {}",
+            "When", "Handler", "Trigger", "Device", "Action", "Result", "Detail"
+        ))
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+        let mut lines = vec![header];
+
+        if self.entries.is_empty() {
+            lines.push(Line::from("No control actions recorded yet").style(Style::default().fg(Color::DarkGray)));
+        }
+        for entry in self.entries.iter().skip(self.scroll) {
+            let style = match entry.outcome.as_str() {
+                "failed" => Style::default().fg(Color::Red),
+                "refused" => Style::default().fg(Color::Yellow),
+                "dry_run" => Style::default().fg(Color::Cyan),
+                _ => Style::default().fg(Color::White),
+            };
+            lines.push(Line::from(entry_line(entry)).style(style));
+        }
+
+        let width = area.width.min(WIDTH);
+        let height = area.height.min(lines.len() as u16 + 2);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        Clear.render(popup, buf);
+        Paragraph::new(lines)
+            .block(
+                Block::bordered()
+                    .title(format!(" 📝 Control Audit Log ({}) - [↑↓] Scroll  [Esc] Close ", self.entries.len()))
+                    .border_type(BorderType::Rounded)
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .render(popup, buf);
+    }
+}
+
+fn entry_line(entry: &AuditEntry) -> String {
+    let when = chrono::DateTime::from_timestamp(entry.recorded_at, 0)
+        .map(|utc| utc.with_timezone(&chrono::Local).format("%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default();
+    format!(
+        "{:<15} {:<14} {:<11} {:<12} {:<8} {:<8} {}",
+        when,
+        entry.handler,
+        entry.trigger,
+        entry.device_id,
+        entry.action,
+        entry.outcome,
+        entry.detail.as_deref().unwrap_or("")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(action: &str) -> AuditEntry {
+        AuditEntry {
+            recorded_at: 0,
+            handler: "valve_control".to_string(),
+            trigger: "manual_key".to_string(),
+            device_id: "v01".to_string(),
+            action: action.to_string(),
+            outcome: "refused".to_string(),
+            detail: Some("safe mode".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_scroll_stays_within_the_entries() {
+        let mut overlay = AuditLogOverlay::new(vec![entry("open"), entry("close")]);
+        overlay.scroll(-3);
+        assert_eq!(overlay.scroll, 0);
+        overlay.scroll(10);
+        assert_eq!(overlay.scroll, 1);
+
+        let line = entry_line(&entry("open"));
+        assert!(line.contains("valve_control") && line.ends_with("safe mode"));
+    }
+}
@@ -0,0 +1,253 @@
+// src/ui/bus_inspector.rs
+//! The Ctrl+T debug screen: messages on the topics starting with a chosen prefix, as they're
+//! published, with JSON payloads pretty-printed. Taps the bus through
+//! [`MessageBus::subscribe_prefix`], so it sees exactly what handlers see.
+
+use std::collections::VecDeque;
+
+use chrono::Local;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, Paragraph, Widget},
+};
+
+use crate::util::io::bus::{BusMessage, BusReceiver, MessageBus};
+
+/// Messages kept, oldest dropped first
+const MAX_MESSAGES: usize = 500;
+
+#[derive(Debug)]
+pub struct BusInspector {
+    /// Topic prefix being watched; empty for every topic
+    prefix: String,
+    /// Typing a new prefix
+    editing: bool,
+    receiver: Option<BusReceiver>,
+    messages: VecDeque<BusMessage>,
+    /// Arrived while paused, shown on resume
+    held: VecDeque<BusMessage>,
+    paused: bool,
+    /// Lines scrolled up from the newest; 0 follows new messages
+    scroll: usize,
+}
+
+impl Default for BusInspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BusInspector {
+    /// Open on the prefix prompt
+    pub fn new() -> Self {
+        Self {
+            prefix: String::new(),
+            editing: true,
+            receiver: None,
+            messages: VecDeque::new(),
+            held: VecDeque::new(),
+            paused: false,
+            scroll: 0,
+        }
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    pub fn is_watching(&self) -> bool {
+        self.receiver.is_some()
+    }
+
+    /// Go back to the prompt; the current stream keeps running until a new prefix is confirmed
+    pub fn edit_prefix(&mut self) {
+        self.editing = true;
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.prefix.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.prefix.pop();
+    }
+
+    /// Leave the prompt without changing what's watched. False if nothing is watched yet.
+    pub fn cancel_edit(&mut self) -> bool {
+        self.editing = false;
+        self.is_watching()
+    }
+
+    /// Start watching the typed prefix, replacing any earlier stream
+    pub fn watch(&mut self, bus: &MessageBus) {
+        self.receiver = Some(bus.subscribe_prefix(self.prefix.trim()));
+        self.messages.clear();
+        self.held.clear();
+        self.scroll = 0;
+        self.editing = false;
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        if !self.paused {
+            let held: Vec<_> = self.held.drain(..).collect();
+            held.into_iter().for_each(|message| self.keep(message));
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.messages.clear();
+        self.held.clear();
+        self.scroll = 0;
+    }
+
+    /// Move `delta` lines towards older messages (negative is newer); 0 follows the stream
+    pub fn scroll(&mut self, delta: isize) {
+        self.scroll = self.scroll.saturating_add_signed(delta);
+    }
+
+    pub fn follow(&mut self) {
+        self.scroll = 0;
+    }
+
+    /// Take in what's been published since the last poll. True when the view changed.
+    pub fn poll(&mut self) -> bool {
+        let Some(receiver) = &mut self.receiver else {
+            return false;
+        };
+        let mut arrived = Vec::new();
+        while let Ok(message) = receiver.try_recv() {
+            arrived.push(message);
+        }
+        if arrived.is_empty() {
+            return false;
+        }
+
+        if self.paused {
+            self.held.extend(arrived);
+            while self.held.len() > MAX_MESSAGES {
+                self.held.pop_front();
+            }
+            // The title's held count changed
+            return true;
+        }
+        arrived.into_iter().for_each(|message| self.keep(message));
+        true
+    }
+
+    fn keep(&mut self, message: BusMessage) {
+        // Hold the view still while scrolled back
+        if self.scroll > 0 {
+            self.scroll += message_lines(&message).len();
+        }
+        self.messages.push_back(message);
+        while self.messages.len() > MAX_MESSAGES {
+            self.messages.pop_front();
+        }
+    }
+
+    /// Nearly full-screen box over whatever is on screen
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let popup = Rect {
+            x: area.x + area.width / 20,
+            y: area.y + area.height / 20,
+            width: area.width - area.width / 10,
+            height: area.height - area.height / 10,
+        };
+        let visible = popup.height.saturating_sub(3) as usize;
+
+        let mut lines: Vec<Line> = self.messages.iter().flat_map(message_lines).collect();
+        if lines.is_empty() && !self.editing {
+            lines.push(Line::from("Waiting for messages…").style(Style::default().fg(Color::DarkGray)));
+        }
+        let max_scroll = lines.len().saturating_sub(visible);
+        self.scroll = self.scroll.min(max_scroll);
+        let end = lines.len() - self.scroll;
+        let mut shown: Vec<Line> = lines.drain(end.saturating_sub(visible)..end).collect();
+
+        let prompt = if self.editing {
+            Line::from(vec![
+                Span::styled("Topic prefix (empty for all): ", Style::default().fg(Color::Yellow)),
+                Span::styled(format!("{}▏", self.prefix), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+                Span::styled("  [Enter] Watch  [Esc] Cancel", Style::default().fg(Color::DarkGray)),
+            ])
+        } else {
+            let mut state = match self.prefix.trim() {
+                "" => "All topics".to_string(),
+                prefix => format!("Topics starting with '{}'", prefix),
+            };
+            if self.paused {
+                state.push_str(&format!(" - PAUSED, {} held", self.held.len()));
+            } else if self.scroll > 0 {
+                state.push_str(" - scrolled back, [End] to follow");
+            }
+            Line::from(state).style(Style::default().fg(Color::Yellow))
+        };
+        shown.insert(0, prompt);
+
+        Clear.render(popup, buf);
+        Paragraph::new(shown)
+            .block(
+                Block::bordered()
+                    .title(" 🔎 Bus Inspector - [Space] Pause  [/] Topic  [c] Clear  [↑↓] Scroll  [Esc] Close ")
+                    .border_type(BorderType::Rounded)
+                    .style(Style::default().fg(Color::Magenta)),
+            )
+            .render(popup, buf);
+    }
+}
+
+/// A timestamped header line, then the payload: pretty-printed when it's JSON, as sent otherwise
+fn message_lines(message: &BusMessage) -> Vec<Line<'static>> {
+    let when = message.timestamp.with_timezone(&Local).format("%H:%M:%S%.3f");
+    let mut lines = vec![Line::from(vec![
+        Span::styled(format!("{} ", when), Style::default().fg(Color::DarkGray)),
+        Span::styled(message.topic.clone(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Span::styled(format!("  from {}  #{}", message.source, message.sequence), Style::default().fg(Color::DarkGray)),
+    ])];
+    lines.extend(
+        pretty_payload(&message.payload)
+            .lines()
+            .map(|line| Line::from(format!("  {}", line)).style(Style::default().fg(Color::White))),
+    );
+    lines
+}
+
+fn pretty_payload(payload: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(payload)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| payload.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_watches_prefix_and_holds_while_paused() {
+        assert_eq!(pretty_payload(r#"{"a":1}"#), "{\n  \"a\": 1\n}");
+        assert_eq!(pretty_payload("close_gate"), "close_gate");
+
+        let (bus, _receiver) = MessageBus::new();
+        let mut inspector = BusInspector::new();
+        "valve_".chars().for_each(|c| inspector.push_char(c));
+        inspector.watch(&bus);
+
+        let publish = |topic: &str| bus.publish(BusMessage::new(topic.to_string(), "{}".to_string(), "test".to_string()));
+        publish("valve_main").await.unwrap();
+        publish("a01").await.unwrap();
+        assert!(inspector.poll());
+        assert_eq!(inspector.messages.len(), 1);
+
+        inspector.toggle_pause();
+        publish("valve_garden").await.unwrap();
+        assert!(inspector.poll());
+        assert_eq!((inspector.messages.len(), inspector.held.len()), (1, 1));
+        inspector.toggle_pause();
+        assert_eq!(inspector.messages.back().unwrap().topic, "valve_garden");
+    }
+}
@@ -0,0 +1,166 @@
+// src/ui/bus_stats.rs
+//! The Ctrl+B debug overlay: messages per second, subscribers and drops for each bus topic,
+//! sampled from [`MessageBus::stats`](crate::util::io::bus::MessageBus::stats) once a second,
+//! plus how many inbound SSP frames were accepted or dropped by checksum.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, BorderType, Clear, Paragraph, Widget},
+};
+
+use crate::util::io::bus::TopicStats;
+use crate::util::io::ssp::{self, FrameStats};
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+const WIDTH: u16 = 60;
+
+/// Rates from the difference between two snapshots of the bus counters
+#[derive(Debug)]
+pub struct BusStatsOverlay {
+    sampled_at: Instant,
+    previous: HashMap<String, u64>,
+    /// Topic, messages/sec over the last interval, and its latest counters; busiest first
+    rows: Vec<(String, f64, TopicStats)>,
+    frames: FrameStats,
+}
+
+impl BusStatsOverlay {
+    pub fn new(stats: HashMap<String, TopicStats>) -> Self {
+        let mut overlay = Self {
+            sampled_at: Instant::now(),
+            previous: HashMap::new(),
+            rows: Vec::new(),
+            frames: FrameStats::default(),
+        };
+        overlay.record(stats, None);
+        overlay
+    }
+
+    /// Take a new sample once the interval has passed. True when the numbers changed.
+    pub fn sample(&mut self, stats: HashMap<String, TopicStats>) -> bool {
+        self.sample_at(Instant::now(), stats)
+    }
+
+    fn sample_at(&mut self, now: Instant, stats: HashMap<String, TopicStats>) -> bool {
+        let elapsed = now.duration_since(self.sampled_at);
+        if elapsed < SAMPLE_INTERVAL {
+            return false;
+        }
+        self.sampled_at = now;
+        self.record(stats, Some(elapsed));
+        true
+    }
+
+    fn record(&mut self, stats: HashMap<String, TopicStats>, elapsed: Option<Duration>) {
+        self.frames = ssp::frame_stats();
+        self.rows = stats
+            .into_iter()
+            .map(|(topic, stats)| {
+                let before = self.previous.insert(topic.clone(), stats.published);
+                let rate = match (before, elapsed) {
+                    (Some(before), Some(elapsed)) => (stats.published - before) as f64 / elapsed.as_secs_f64(),
+                    _ => 0.0,
+                };
+                (topic, rate, stats)
+            })
+            .collect();
+        self.rows.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    }
+
+    /// Box in the top-right corner; topics quiet over the last interval are dimmed
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        let header = Line::from(format!("{:<30} {:>8} {:>6} {:>9}", "Topic", "msg/s", "subs", "dropped"))
+            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+        let mut lines = vec![header];
+
+        if self.rows.is_empty() {
+            lines.push(Line::from("No messages yet").style(Style::default().fg(Color::DarkGray)));
+        }
+        for (topic, rate, stats) in &self.rows {
+            let style = match (stats.dropped, *rate > 0.0) {
+                (dropped, _) if dropped > 0 => Style::default().fg(Color::Red),
+                (_, true) => Style::default().fg(Color::White),
+                (_, false) => Style::default().fg(Color::DarkGray),
+            };
+            lines.push(
+                Line::from(format!(
+                    "{:<30} {:>8.1} {:>6} {:>9}",
+                    truncate(topic, 30),
+                    rate,
+                    stats.subscribers,
+                    stats.dropped
+                ))
+                .style(style),
+            );
+        }
+
+        let frames = &self.frames;
+        let frame_style = match frames.rejected() {
+            0 => Style::default().fg(Color::DarkGray),
+            _ => Style::default().fg(Color::Red),
+        };
+        lines.push(
+            Line::from(format!(
+                "SSP frames: {} ok, {} bad checksum, {} unsigned",
+                frames.accepted, frames.checksum_failures, frames.missing_checksums
+            ))
+            .style(frame_style),
+        );
+
+        let width = area.width.min(WIDTH);
+        let height = area.height.min(lines.len() as u16 + 2);
+        let popup = Rect { x: area.right() - width, y: area.y, width, height };
+
+        Clear.render(popup, buf);
+        Paragraph::new(lines)
+            .block(
+                Block::bordered()
+                    .title(" 📊 Bus Topics - [^B] Close ")
+                    .border_type(BorderType::Rounded)
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .render(popup, buf);
+    }
+}
+
+/// `topic` cut to `max` characters, ending in `…` when shortened
+fn truncate(topic: &str, max: usize) -> String {
+    if topic.chars().count() <= max {
+        return topic.to_string();
+    }
+    let mut short: String = topic.chars().take(max - 1).collect();
+    short.push('…');
+    short
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counters(published: u64) -> TopicStats {
+        TopicStats { published, dropped: 0, subscribers: 1 }
+    }
+
+    #[test]
+    fn test_rates_come_from_counter_deltas() {
+        let mut overlay = BusStatsOverlay::new(HashMap::from([("a01".to_string(), counters(10))]));
+        let start = overlay.sampled_at;
+        assert!(!overlay.sample_at(start, HashMap::from([("a01".to_string(), counters(12))])));
+
+        assert!(overlay.sample_at(start + Duration::from_secs(2), HashMap::from([
+            ("a01".to_string(), counters(30)),
+            ("valve_main".to_string(), counters(1)),
+        ])));
+
+        assert_eq!(overlay.rows[0].0, "a01");
+        assert_eq!(overlay.rows[0].1, 10.0);
+        assert_eq!(overlay.rows[1].1, 0.0);
+        assert_eq!(truncate("telemetry.pump_house.pressure.raw", 10), "telemetry…");
+    }
+}
@@ -0,0 +1,111 @@
+// src/ui/diagnostics.rs
+//! The Ctrl+D overlay: the self-check results from [`diagnostics`](crate::runtime::diagnostics),
+//! one line per check, filled in when the background run finishes.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, Paragraph, Widget, Wrap},
+};
+use tokio::sync::oneshot;
+
+use crate::runtime::diagnostics::{CheckStatus, DiagnosticCheck};
+
+const WIDTH: u16 = 90;
+
+#[derive(Debug)]
+pub struct DiagnosticsOverlay {
+    checks: Vec<DiagnosticCheck>,
+    /// Results of the run in progress
+    pending: Option<oneshot::Receiver<Vec<DiagnosticCheck>>>,
+}
+
+impl DiagnosticsOverlay {
+    /// Show "running" until `results` arrive
+    pub fn new(results: oneshot::Receiver<Vec<DiagnosticCheck>>) -> Self {
+        Self { checks: Vec::new(), pending: Some(results) }
+    }
+
+    /// Pick up finished results. True when they just arrived.
+    pub fn poll(&mut self) -> bool {
+        let Some(pending) = &mut self.pending else {
+            return false;
+        };
+        match pending.try_recv() {
+            Ok(checks) => self.checks = checks,
+            Err(oneshot::error::TryRecvError::Empty) => return false,
+            Err(oneshot::error::TryRecvError::Closed) => {}
+        }
+        self.pending = None;
+        true
+    }
+
+    /// Centered box over whatever is on screen
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        let mut lines = Vec::new();
+        if self.pending.is_some() {
+            lines.push(Line::from("Running checks…").style(Style::default().fg(Color::DarkGray)));
+        }
+        for check in &self.checks {
+            let (mark, color) = status_mark(check.status);
+            lines.push(Line::from(vec![
+                Span::styled(format!("{} ", mark), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{:<16}", check.name), Style::default().fg(Color::White)),
+                Span::styled(check.detail.clone(), Style::default().fg(color)),
+            ]));
+        }
+
+        let width = area.width.min(WIDTH);
+        // Long details wrap; leave room for a couple of them
+        let height = area.height.min(lines.len() as u16 + 6);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        Clear.render(popup, buf);
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::bordered()
+                    .title(" 🩺 Diagnostics - [r] Re-run  [s] Save Support Bundle  [Esc] Close ")
+                    .border_type(BorderType::Rounded)
+                    .style(Style::default().fg(Color::Cyan)),
+            )
+            .render(popup, buf);
+    }
+}
+
+fn status_mark(status: CheckStatus) -> (&'static str, Color) {
+    match status {
+        CheckStatus::Pass => ("✓", Color::Green),
+        CheckStatus::Warn => ("!", Color::Yellow),
+        CheckStatus::Fail => ("✗", Color::Red),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_picks_up_results_once() {
+        let (tx, rx) = oneshot::channel();
+        let mut overlay = DiagnosticsOverlay::new(rx);
+        assert!(!overlay.poll());
+
+        tx.send(vec![DiagnosticCheck {
+            name: "Database",
+            status: CheckStatus::Pass,
+            detail: "All databases reachable and writable".to_string(),
+        }])
+        .unwrap();
+        assert!(overlay.poll());
+        assert!(!overlay.poll());
+        assert_eq!(overlay.checks.len(), 1);
+    }
+}
@@ -0,0 +1,15 @@
+use super::{DocumentManager, InlineDocument};
+
+impl DocumentManager {
+    pub fn close_document(&mut self) {
+        self.inline_document = None;
+    }
+
+    pub fn inline_document(&self) -> Option<&InlineDocument> {
+        self.inline_document.as_ref()
+    }
+
+    pub fn cache_dir(&self) -> std::path::PathBuf {
+        self.viewer.cache_dir().to_path_buf()
+    }
+}
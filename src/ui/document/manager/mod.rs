@@ -1,18 +1,54 @@
 mod new;
 mod open_document;
+mod poll_launch_outcomes;
+mod close_document;
+mod scroll_inline;
+mod render_inline;
 
 use std::sync::Arc;
-use tokio::io::AsyncBufReadExt;
+use tokio::{
+    io::AsyncBufReadExt,
+    sync::mpsc,
+    time::Duration,
+};
 
 use crate::ui::document::{
+    content::DocumentContent,
     viewer::{
         DocumentViewer,
         external::ExternalViewer,
     },
 };
 
+/// How long the external viewer gets to launch before we fall back to the TUI
+const EXTERNAL_LAUNCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Result reported back from the spawned external-viewer launch
+#[derive(Debug)]
+enum LaunchOutcome {
+    Launched,
+    Failed {
+        path: String,
+        reason: String,
+        /// Already parsed, so the inline fallback doesn't read the file again
+        content: DocumentContent,
+    },
+}
+
+/// Document shown inside the TUI when the external viewer is unavailable
+#[derive(Debug)]
+pub struct InlineDocument {
+    pub path: String,
+    pub content: Option<DocumentContent>,
+    pub error: Option<String>,
+    pub scroll: u16,
+}
+
 #[derive(Debug)]
 pub struct DocumentManager {
     viewer: DocumentViewer,
     external_viewer: Option<Arc<ExternalViewer>>,
+    outcome_tx: mpsc::UnboundedSender<LaunchOutcome>,
+    outcome_rx: mpsc::UnboundedReceiver<LaunchOutcome>,
+    inline_document: Option<InlineDocument>,
 }
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use tokio::sync::mpsc;
 
 use crate::ui::document::{
     manager::DocumentManager,
@@ -10,9 +11,14 @@ use crate::ui::document::{
 
 impl DocumentManager {
     pub fn new() -> color_eyre::Result<Self> {
+        let (outcome_tx, outcome_rx) = mpsc::unbounded_channel();
+
         Ok(Self {
             viewer: DocumentViewer::new(),
             external_viewer: Some(Arc::new(ExternalViewer::new()?)),
+            outcome_tx,
+            outcome_rx,
+            inline_document: None,
         })
     }
 }
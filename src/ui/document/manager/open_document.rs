@@ -1,7 +1,9 @@
 use std::path::Path;
 use tokio::io::AsyncBufReadExt;
 
-use super::DocumentManager;
+use crate::log_error;
+
+use super::{DocumentManager, InlineDocument, LaunchOutcome, EXTERNAL_LAUNCH_TIMEOUT};
 
 impl DocumentManager {
     pub fn open_document(&mut self, file_path: String) {
@@ -19,17 +21,43 @@ impl DocumentManager {
                 self.viewer.view_document(path).ok()
             });
 
-        if let Some(content) = content {
-            if let Some(external_viewer) = &self.external_viewer {
-                let viewer = external_viewer.clone();
-                let path_clone = file_path.clone();
-
-                tokio::spawn(async move {
-                    if let Err(e) = viewer.show_document_external(&path_clone, &content).await {
-                        panic!("Failed to launch external viewer: {}", e);
-                    }
-                });
-            }
-        }
+        let Some(content) = content else {
+            log_error!("Failed to open document: unsupported file {}", actual_path);
+            self.inline_document = Some(InlineDocument {
+                path: actual_path,
+                content: None,
+                error: Some("Failed to open document: unsupported file type".to_string()),
+                scroll: 0,
+            });
+            return;
+        };
+
+        let Some(external_viewer) = &self.external_viewer else {
+            self.show_inline_fallback(actual_path, "no external viewer available".to_string(), content);
+            return;
+        };
+
+        let viewer = external_viewer.clone();
+        let path_clone = file_path.clone();
+        let outcome_tx = self.outcome_tx.clone();
+
+        tokio::spawn(async move {
+            let launch = tokio::time::timeout(
+                EXTERNAL_LAUNCH_TIMEOUT,
+                viewer.show_document_external(&path_clone, &content),
+            ).await;
+
+            let outcome = match launch {
+                Ok(Ok(())) => LaunchOutcome::Launched,
+                Ok(Err(e)) => LaunchOutcome::Failed { path: actual_path, reason: e.to_string(), content },
+                Err(_) => LaunchOutcome::Failed {
+                    path: actual_path,
+                    reason: format!("external viewer timed out after {}s", EXTERNAL_LAUNCH_TIMEOUT.as_secs()),
+                    content,
+                },
+            };
+
+            let _ = outcome_tx.send(outcome);
+        });
     }
 }
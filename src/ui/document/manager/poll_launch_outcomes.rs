@@ -0,0 +1,38 @@
+use crate::{log_debug, log_error};
+use crate::ui::document::content::DocumentContent;
+
+use super::{DocumentManager, InlineDocument, LaunchOutcome};
+
+impl DocumentManager {
+    /// Drain results from external-viewer launches. Returns true if the UI needs a redraw.
+    pub fn poll_launch_outcomes(&mut self) -> bool {
+        let mut changed = false;
+
+        while let Ok(outcome) = self.outcome_rx.try_recv() {
+            match outcome {
+                LaunchOutcome::Launched => {
+                    log_debug!("External document viewer launched");
+                }
+                LaunchOutcome::Failed { path, reason, content } => {
+                    log_error!("Failed to open document {}: {}", path, reason);
+                    self.show_inline_fallback(path, reason, content);
+                    changed = true;
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// Show the document's parsed text inside the TUI along with why the external viewer failed
+    pub(super) fn show_inline_fallback(&mut self, path: String, reason: String, content: DocumentContent) {
+        let content = Some(content).filter(|content| !content.text.is_empty());
+
+        self.inline_document = Some(InlineDocument {
+            path,
+            content,
+            error: Some(format!("Failed to open document: {}", reason)),
+            scroll: 0,
+        });
+    }
+}
@@ -0,0 +1,49 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, BorderType, Clear, Paragraph, Widget, Wrap},
+};
+
+use super::DocumentManager;
+
+impl DocumentManager {
+    /// Render the inline document (or launch error) over the current screen
+    pub fn render_inline(&self, area: Rect, buf: &mut Buffer) {
+        let Some(document) = &self.inline_document else {
+            return;
+        };
+
+        let mut lines = Vec::new();
+
+        if let Some(error) = &document.error {
+            lines.push(Line::from(format!("⚠ {}", error)).style(Style::default().fg(Color::Red)));
+            lines.push(Line::from(""));
+        }
+
+        match &document.content {
+            Some(content) => {
+                lines.extend(content.text.lines().map(|line| Line::from(line.to_string())));
+            }
+            None => {
+                lines.push(Line::from("No text preview available for this document.").style(Style::default().fg(Color::Gray)));
+            }
+        }
+
+        let title = format!(" 📄 {} - [↑/↓] Scroll [Esc] Close ", document.path);
+
+        Clear.render(area, buf);
+        Paragraph::new(lines)
+            .block(
+                Block::bordered()
+                    .title(title)
+                    .border_type(BorderType::Rounded)
+                    .style(Style::default().fg(Color::Yellow))
+            )
+            .style(Style::default().fg(Color::White))
+            .wrap(Wrap { trim: false })
+            .scroll((document.scroll, 0))
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,9 @@
+use super::DocumentManager;
+
+impl DocumentManager {
+    pub fn scroll_inline(&mut self, delta: i32) {
+        if let Some(document) = &mut self.inline_document {
+            document.scroll = (document.scroll as i32 + delta).max(0) as u16;
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use crate::ui::document::viewer::DocumentViewer;
+use crate::util::dirs::data_dirs;
+
+impl DocumentViewer {
+    /// `KNOWLEDGE_CACHE_DIR` if set, otherwise `knowledge` in the cache directory
+    /// (e.g. `~/.cache/survon/knowledge`; see [`crate::util::dirs`]).
+    pub fn default_cache_dir() -> PathBuf {
+        if let Ok(dir) = std::env::var("KNOWLEDGE_CACHE_DIR") {
+            if !dir.is_empty() {
+                return PathBuf::from(dir);
+            }
+        }
+
+        data_dirs().cache.join("knowledge")
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Create the cache directory on demand, with a readable error when we can't
+    pub(super) fn ensure_cache_dir(&self) -> color_eyre::Result<&Path> {
+        match fs::create_dir_all(&self.cache_dir) {
+            Ok(()) => Ok(&self.cache_dir),
+            Err(e) if e.kind() == ErrorKind::PermissionDenied => Err(color_eyre::eyre::eyre!(
+                "Knowledge cache directory {} is not writable; set KNOWLEDGE_CACHE_DIR to a writable location",
+                self.cache_dir.display()
+            )),
+            Err(e) => Err(color_eyre::eyre::eyre!(
+                "Failed to create knowledge cache directory {}: {}",
+                self.cache_dir.display(),
+                e
+            )),
+        }
+    }
+}
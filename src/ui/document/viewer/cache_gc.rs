@@ -0,0 +1,159 @@
+use std::{
+    fs,
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::{log_info, log_warn};
+
+/// Limits applied to the knowledge cache by [`collect_cache_garbage`]
+#[derive(Debug, Clone)]
+pub struct CacheGcPolicy {
+    /// Entries untouched for longer than this are removed
+    pub max_age: Duration,
+    /// After age-based removal, the oldest entries are dropped until the cache fits
+    pub max_bytes: u64,
+}
+
+impl CacheGcPolicy {
+    /// `KNOWLEDGE_CACHE_MAX_AGE_DAYS` (default 7) and `KNOWLEDGE_CACHE_MAX_MB` (default 256)
+    pub fn from_env() -> Self {
+        let days = std::env::var("KNOWLEDGE_CACHE_MAX_AGE_DAYS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(7);
+
+        let megabytes = std::env::var("KNOWLEDGE_CACHE_MAX_MB")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(256);
+
+        Self {
+            max_age: Duration::from_secs(days * 24 * 60 * 60),
+            max_bytes: megabytes * 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CacheGcReport {
+    pub removed: usize,
+    pub freed_bytes: u64,
+    pub remaining_bytes: u64,
+}
+
+/// Remove stale or over-budget entries (e.g. `pdf_{uuid}` dirs) from the cache dir.
+/// A missing cache dir is not an error; there's simply nothing to collect.
+pub fn collect_cache_garbage(cache_dir: &Path, policy: &CacheGcPolicy) -> io::Result<CacheGcReport> {
+    let mut report = CacheGcReport::default();
+
+    let read_dir = match fs::read_dir(cache_dir) {
+        Ok(read_dir) => read_dir,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(report),
+        Err(e) => return Err(e),
+    };
+
+    let now = SystemTime::now();
+    let mut entries: Vec<(PathBuf, SystemTime, u64)> = Vec::new();
+
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        let modified = entry.metadata()
+            .and_then(|m| m.modified())
+            .unwrap_or(now);
+        entries.push((path.clone(), modified, entry_size(&path)));
+    }
+
+    // Oldest first, so budget trimming drops the least recently written entries
+    entries.sort_by_key(|(_, modified, _)| *modified);
+
+    let mut total: u64 = entries.iter().map(|(_, _, size)| size).sum();
+
+    for (path, modified, size) in entries {
+        let age = now.duration_since(modified).unwrap_or_default();
+        let expired = age > policy.max_age;
+        let over_budget = total > policy.max_bytes;
+
+        if !expired && !over_budget {
+            continue;
+        }
+
+        match remove_entry(&path) {
+            Ok(()) => {
+                report.removed += 1;
+                report.freed_bytes += size;
+                total -= size;
+            }
+            Err(e) => log_warn!("Failed to remove cache entry {}: {}", path.display(), e),
+        }
+    }
+
+    report.remaining_bytes = total;
+
+    if report.removed > 0 {
+        log_info!(
+            "🧹 Knowledge cache GC removed {} entries ({} KB), {} KB remaining",
+            report.removed,
+            report.freed_bytes / 1024,
+            report.remaining_bytes / 1024
+        );
+    }
+
+    Ok(report)
+}
+
+fn entry_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+
+    fs::read_dir(path)
+        .map(|read_dir| read_dir.flatten().map(|entry| entry_size(&entry.path())).sum())
+        .unwrap_or(0)
+}
+
+fn remove_entry(path: &Path) -> io::Result<()> {
+    if fs::symlink_metadata(path)?.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gc_keeps_fresh_entries_within_budget_and_trims_over_budget() {
+        let cache_dir = std::env::temp_dir().join(format!("survon_cache_gc_{}", uuid::Uuid::new_v4()));
+        for name in ["pdf_a", "pdf_b"] {
+            fs::create_dir_all(cache_dir.join(name)).unwrap();
+            fs::write(cache_dir.join(name).join("page.txt"), vec![0u8; 1024]).unwrap();
+        }
+
+        let generous = CacheGcPolicy { max_age: Duration::from_secs(3600), max_bytes: 1024 * 1024 };
+        let report = collect_cache_garbage(&cache_dir, &generous).unwrap();
+        assert_eq!(report.removed, 0);
+        assert_eq!(report.remaining_bytes, 2048);
+
+        let tight = CacheGcPolicy { max_age: Duration::from_secs(3600), max_bytes: 1024 };
+        let report = collect_cache_garbage(&cache_dir, &tight).unwrap();
+        assert_eq!(report.removed, 1);
+        assert_eq!(report.remaining_bytes, 1024);
+
+        let _ = fs::remove_dir_all(&cache_dir);
+    }
+
+    #[test]
+    fn test_gc_missing_dir_is_noop() {
+        let missing = std::env::temp_dir().join(format!("survon_missing_{}", uuid::Uuid::new_v4()));
+        let policy = CacheGcPolicy::from_env();
+        assert_eq!(collect_cache_garbage(&missing, &policy).unwrap(), CacheGcReport::default());
+    }
+}
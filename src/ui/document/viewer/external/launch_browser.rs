@@ -1,6 +1,7 @@
 use std::{
     path::Path,
     process::Stdio,
+    time::Duration,
 };
 use tokio::process::Command as AsyncCommand;
 
@@ -8,7 +9,12 @@ use crate::log_debug;
 
 use super::ExternalViewer;
 
+/// How long a freshly spawned browser has to fail before it counts as launched
+const EARLY_EXIT_GRACE: Duration = Duration::from_secs(1);
+
 impl ExternalViewer {
+    /// Open `html_path` in the first browser that starts and stays up. One that exits with an error
+    /// straight away (no display, say) is passed over for the next.
     pub(super) async fn launch_browser(&self, html_path: &Path) -> color_eyre::Result<()> {
         // Prioritize lightweight browsers suitable for Pi/embedded systems
         let browsers = [
@@ -22,6 +28,7 @@ impl ExternalViewer {
             "epiphany",         // GNOME Web, also lightweight
         ];
 
+        let mut failures = Vec::new();
         for browser in &browsers {
             if self.command_exists(browser).await {
                 let mut cmd = AsyncCommand::new(browser);
@@ -52,13 +59,37 @@ impl ExternalViewer {
                 cmd.stdout(Stdio::null());
                 cmd.stderr(Stdio::null());
 
-                if let Ok(_) = cmd.spawn() {
-                    log_debug!("Launched document viewer with: {}", browser);
-                    return Ok(());
+                let mut child = match cmd.spawn() {
+                    Ok(child) => child,
+                    Err(e) => {
+                        failures.push(format!("{}: {}", browser, e));
+                        continue;
+                    }
+                };
+
+                // Still running, or an opener that handed off and exited cleanly, means it launched
+                match tokio::time::timeout(EARLY_EXIT_GRACE, child.wait()).await {
+                    Err(_) => {}
+                    Ok(Ok(status)) if status.success() => {}
+                    Ok(Ok(status)) => {
+                        log_debug!("Document viewer {} exited early with {}", browser, status);
+                        failures.push(format!("{} exited with {}", browser, status));
+                        continue;
+                    }
+                    Ok(Err(e)) => {
+                        failures.push(format!("{}: {}", browser, e));
+                        continue;
+                    }
                 }
+
+                log_debug!("Launched document viewer with: {}", browser);
+                return Ok(());
             }
         }
 
+        if !failures.is_empty() {
+            return Err(color_eyre::eyre::eyre!("No browser could open the document ({})", failures.join("; ")));
+        }
         Err(color_eyre::eyre::eyre!(
             "No suitable browser found. Install one of: netsurf-gtk, surf, midori, chromium-browser"
         ))
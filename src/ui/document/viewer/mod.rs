@@ -4,13 +4,17 @@ mod strategies;
 mod view_document;
 mod supports_direct_viewing;
 mod get_direct_view_content;
+mod cache_dir;
+pub mod cache_gc;
 pub mod external;
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 pub use trait_document_view_strategy::DocumentViewStrategy;
 
 #[derive(Debug)]
 pub struct DocumentViewer {
     strategies: HashMap<String, Box<dyn DocumentViewStrategy>>,
+    cache_dir: PathBuf,
 }
@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+};
 
 use crate::ui::document::{
     viewer::{
@@ -14,6 +17,10 @@ use crate::ui::document::{
 
 impl DocumentViewer {
     pub fn new() -> Self {
+        Self::with_cache_dir(Self::default_cache_dir())
+    }
+
+    pub fn with_cache_dir(cache_dir: PathBuf) -> Self {
         let mut strategies: HashMap<String, Box<dyn DocumentViewStrategy>> = HashMap::new();
 
         // Register strategies
@@ -32,6 +39,6 @@ impl DocumentViewer {
             strategies.insert(ext.to_string(), Box::new(MediaViewStrategy));
         }
 
-        Self { strategies }
+        Self { strategies, cache_dir }
     }
 }
@@ -15,7 +15,9 @@ impl PdfViewStrategy {
     /// (e.g., for knowledge ingestion)
     pub(super) fn _parse_content(&self, file_path: &Path, cache_dir: &Path) -> color_eyre::Result<DocumentContent> {
         let pdf_cache_dir = cache_dir.join(format!("pdf_{}", Uuid::new_v4()));
-        fs::create_dir_all(&pdf_cache_dir)?;
+        fs::create_dir_all(&pdf_cache_dir).map_err(|e| {
+            color_eyre::eyre::eyre!("Failed to create PDF cache dir {}: {}", pdf_cache_dir.display(), e)
+        })?;
 
         let text = pdf_extract::extract_text(file_path)?;
         Ok(DocumentContent {
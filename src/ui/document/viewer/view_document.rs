@@ -1,4 +1,4 @@
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use crate::ui::document::{
     content::DocumentContent,
@@ -13,8 +13,8 @@ impl DocumentViewer {
             .to_lowercase();
 
         if let Some(strategy) = self.strategies.get(&extension) {
-            let cache_dir = PathBuf::from("./.cache/knowledge");
-            strategy.parse_content(file_path, &cache_dir)
+            let cache_dir = self.ensure_cache_dir()?;
+            strategy.parse_content(file_path, cache_dir)
         } else {
             Err(color_eyre::eyre::eyre!("Unsupported file type: {}", extension))
         }
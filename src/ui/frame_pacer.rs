@@ -0,0 +1,56 @@
+// src/ui/frame_pacer.rs
+//! Coalesces redraw requests. Render paths, blink ticks, bus messages and handlers all ask for a
+//! redraw, often several in the same tick; however many arrive, at most one render happens per
+//! frame interval, and a request is never dropped, only deferred to the next frame.
+
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+pub struct FramePacer {
+    frame_interval: Duration,
+    dirty: bool,
+    last_render: Option<Instant>,
+}
+
+impl FramePacer {
+    pub fn new(frame_interval: Duration) -> Self {
+        Self { frame_interval, dirty: true, last_render: None }
+    }
+
+    pub fn request(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Whether to render at `now`: something asked for it and the last frame is at least an
+    /// interval old. Clears the request, so anything asked for while rendering makes the next frame.
+    pub fn begin_frame(&mut self, now: Instant) -> bool {
+        let due = self.last_render.map_or(true, |at| now.duration_since(at) >= self.frame_interval);
+        if !(self.dirty && due) {
+            return false;
+        }
+        self.dirty = false;
+        self.last_render = Some(now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requests_within_a_frame_render_once() {
+        let frame = Duration::from_millis(33);
+        let start = Instant::now();
+        let mut pacer = FramePacer::new(frame);
+        assert!(pacer.begin_frame(start));
+        assert!(!pacer.begin_frame(start + frame));
+
+        for _ in 0..5 {
+            pacer.request();
+        }
+        assert!(!pacer.begin_frame(start + Duration::from_millis(10)));
+        assert!(pacer.begin_frame(start + frame));
+        assert!(!pacer.begin_frame(start + frame * 3));
+    }
+}
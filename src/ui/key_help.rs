@@ -0,0 +1,207 @@
+// src/ui/key_help.rs
+//! The `?` overlay and overview footer, both built from the keys that work in the current context
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::KeyCode,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, Paragraph, Widget},
+};
+
+use crate::app::{App, AppMode, ModuleSource, OverviewFocus};
+use crate::module::key_hints::group_key_hints;
+use crate::module::{ModuleManager, ModuleManagerView};
+
+/// Ctrl shortcuts have no plain `KeyCode`, so their hints are spelled out
+pub const FULLSCREEN_KEY: &str = "[^F]";
+const CONTROL_KEYS: &[(&str, &str)] = &[
+    (FULLSCREEN_KEY, "Fullscreen"),
+    ("[^Z]", "Undo"),
+    ("[^Y]", "Redo"),
+    ("[^E]", "Export View as Text"),
+    ("[^B]", "Bus Topics"),
+    ("[^T]", "Bus Message Inspector"),
+    ("[^P]", "Safe Mode (freeze actuators)"),
+    ("[^A]", "Control Audit Log"),
+    ("[^D]", "Diagnostics Self-Check"),
+    ("[^V]", "About (version and build)"),
+];
+
+/// A titled group of hints in the overlay
+#[derive(Debug, Clone)]
+pub struct KeyHelpSection {
+    pub title: String,
+    pub hints: Vec<(KeyCode, String)>,
+}
+
+impl App {
+    /// Keys of whatever has focus: a widget panel, a module list, or the open module's handler
+    pub fn focused_key_hints(&self) -> Option<KeyHelpSection> {
+        match &self.mode {
+            AppMode::Splash | AppMode::Loading => None,
+            AppMode::Overview => match self.overview_focus {
+                OverviewFocus::None => None,
+                OverviewFocus::Jukebox => Some(KeyHelpSection {
+                    title: "Jukebox".to_string(),
+                    hints: owned(&[
+                        (KeyCode::Char(' '), "Play/Pause"),
+                        (KeyCode::Left, "Prev/Next Track"),
+                        (KeyCode::Right, "Prev/Next Track"),
+                        (KeyCode::Up, "Browse"),
+                        (KeyCode::Down, "Browse"),
+                        (KeyCode::Enter, "Play Selected"),
+                        (KeyCode::Char('+'), "Volume"),
+                        (KeyCode::Char('-'), "Volume"),
+                        (KeyCode::Char('m'), "Library"),
+                    ]),
+                }),
+                OverviewFocus::Messages => Some(KeyHelpSection {
+                    title: "Messages".to_string(),
+                    hints: owned(&[
+                        (KeyCode::Up, "Scroll"),
+                        (KeyCode::Down, "Scroll"),
+                        (KeyCode::Enter, "Open"),
+                        (KeyCode::End, "Latest"),
+                        (KeyCode::Esc, "Dismiss"),
+                    ]),
+                }),
+                OverviewFocus::WastelandModules => {
+                    Some(panel_key_hints("Wasteland Modules", &self.wasteland_module_manager))
+                }
+                OverviewFocus::CoreModules => {
+                    Some(panel_key_hints("Core Modules", &self.core_module_manager))
+                }
+            },
+            AppMode::ModuleDetail(source, module_idx) => {
+                let module_manager = match source {
+                    ModuleSource::Wasteland => &self.wasteland_module_manager,
+                    ModuleSource::Core => &self.core_module_manager,
+                };
+                let mut section = module_key_hints(module_manager, *module_idx);
+                section.hints.push((KeyCode::Esc, "Back to Overview".to_string()));
+                Some(section)
+            }
+        }
+    }
+
+    /// Keys the app handles itself when the focused part passes them on
+    pub fn global_key_hints(&self) -> Vec<(KeyCode, String)> {
+        match &self.mode {
+            AppMode::Splash | AppMode::Loading => Vec::new(),
+            AppMode::Overview => {
+                let next_focus = match self.overview_focus {
+                    OverviewFocus::None => "Focus Wasteland Modules",
+                    OverviewFocus::WastelandModules => "Focus Messages",
+                    OverviewFocus::Messages => "Focus Core Modules",
+                    OverviewFocus::CoreModules => "Focus Jukebox",
+                    OverviewFocus::Jukebox => "Remove Overview Focus",
+                };
+                owned(&[
+                    (KeyCode::Tab, next_focus),
+                    (KeyCode::BackTab, "Previous Focus"),
+                    (KeyCode::Enter, "Select"),
+                    (KeyCode::Char('r'), "Refresh"),
+                    (KeyCode::Char('d'), self.ui_state.density.toggle().label()),
+                    (KeyCode::Char('p'), self.ui_state.palette.next().label()),
+                    (KeyCode::Char('?'), "Help"),
+                    (KeyCode::Char('q'), "Quit"),
+                ])
+            }
+            AppMode::ModuleDetail(..) => owned(&[(KeyCode::Char('?'), "Help")]),
+        }
+    }
+
+    /// Focused keys first, then the global ones (always last)
+    pub fn key_help_sections(&self) -> Vec<KeyHelpSection> {
+        let mut sections: Vec<KeyHelpSection> = self.focused_key_hints().into_iter().collect();
+        sections.push(KeyHelpSection { title: "Global".to_string(), hints: self.global_key_hints() });
+        sections
+    }
+}
+
+fn owned(hints: &[(KeyCode, &str)]) -> Vec<(KeyCode, String)> {
+    hints.iter().map(|(key, label)| (*key, label.to_string())).collect()
+}
+
+/// A module panel on the overview: list navigation and quick actions, or the open module's keys
+fn panel_key_hints(title: &str, module_manager: &ModuleManager) -> KeyHelpSection {
+    let module_idx = module_manager.selected_module;
+    if matches!(module_manager.current_view, ModuleManagerView::ModuleListView) {
+        let mut hints = owned(&[(KeyCode::Left, "Navigate"), (KeyCode::Right, "Navigate")]);
+        hints.extend(
+            module_manager
+                .quick_actions_for(module_idx)
+                .iter()
+                .map(|action| (KeyCode::Char(action.trigger), action.label.to_string())),
+        );
+        KeyHelpSection { title: title.to_string(), hints }
+    } else {
+        let mut section = module_key_hints(module_manager, module_idx);
+        section.hints.push((KeyCode::Esc, "Back to List".to_string()));
+        section
+    }
+}
+
+fn module_key_hints(module_manager: &ModuleManager, module_idx: usize) -> KeyHelpSection {
+    let title = module_manager
+        .get_modules()
+        .get(module_idx)
+        .map(|module| module.config.name.clone())
+        .unwrap_or_default();
+    let hints = module_manager
+        .key_hints_for(module_idx)
+        .into_iter()
+        .map(|(key, label)| (key, label.to_string()))
+        .collect();
+    KeyHelpSection { title, hints }
+}
+
+/// Centered box listing every section, drawn over the current screen
+pub fn render_key_help(sections: &[KeyHelpSection], area: Rect, buf: &mut Buffer) {
+    let mut lines = Vec::new();
+    for section in sections {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(section.title.clone()).style(
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ));
+        if section.hints.is_empty() {
+            lines.push(Line::from("  No shortcuts").style(Style::default().fg(Color::DarkGray)));
+        }
+        for (keys, label) in group_key_hints(&section.hints) {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<14}", keys), Style::default().fg(Color::Cyan)),
+                Span::raw(label),
+            ]));
+        }
+    }
+    for (keys, label) in CONTROL_KEYS {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<14}", keys), Style::default().fg(Color::Cyan)),
+            Span::raw(*label),
+        ]));
+    }
+
+    let width = area.width.min(56);
+    let height = area.height.min(lines.len() as u16 + 2);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    Clear.render(popup, buf);
+    Paragraph::new(lines)
+        .block(
+            Block::bordered()
+                .title(" ⌨ Keys - [?]/[Esc] Close ")
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(Color::Yellow)),
+        )
+        .style(Style::default().fg(Color::White))
+        .render(popup, buf);
+}
@@ -1,7 +1,20 @@
+pub mod about;
+pub mod audit_log;
+pub mod bus_inspector;
+pub mod bus_stats;
+pub mod diagnostics;
 pub mod document;
+pub mod frame_pacer;
+pub mod key_help;
+pub mod palette;
+pub mod safe_mode_banner;
+pub mod screen_snapshot;
 pub mod template;
 pub mod screens;
 pub mod style;
+pub mod text_export;
+pub mod toasts;
+pub mod ui_state;
 pub mod widgets;
 
 mod components;
@@ -19,7 +32,7 @@ use crate::{
 impl Widget for &mut App {
     fn render(self, area: Rect, buf: &mut Buffer) {
         match &self.mode {
-            AppMode::Splash => {},
+            AppMode::Splash | AppMode::Loading => {},
             AppMode::Overview => render_overview(self, area, buf),
             AppMode::ModuleDetail(_source, _module_idx) => {},
         }
@@ -0,0 +1,168 @@
+// src/ui/palette.rs
+//! Status colors shared by the card templates, switchable for colorblind users and bright displays.
+//!
+//! Templates ask for a [`Status`] color instead of hardcoding green/yellow/red. Outside the default
+//! palette each status also gets a symbol, so state never depends on color alone.
+
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::LazyLock;
+
+use crate::ui::style::AdaptiveColors;
+
+static ACTIVE: AtomicU8 = AtomicU8::new(0);
+
+/// What the terminal can show, so RGB palette colors degrade to its nearest indexed color
+static TERMINAL_COLORS: LazyLock<AdaptiveColors> = LazyLock::new(AdaptiveColors::detect);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Palette {
+    #[default]
+    Default,
+    /// Bright, bold colors for sunlight and washed-out panels
+    HighContrast,
+    /// Blue/yellow/vermillion, which stay apart for red-green colorblindness
+    Deuteranopia,
+}
+
+/// What a color is saying about a reading or device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Warning,
+    Danger,
+    Info,
+    /// Disconnected or no data
+    Inactive,
+}
+
+impl Palette {
+    const ALL: [Palette; 3] = [Palette::Default, Palette::HighContrast, Palette::Deuteranopia];
+
+    /// Config default from `PALETTE` (`default`, `high_contrast` or `deuteranopia`)
+    pub fn from_env() -> Self {
+        match std::env::var("PALETTE").unwrap_or_default().to_lowercase().replace('-', "_").as_str() {
+            "high_contrast" => Palette::HighContrast,
+            "deuteranopia" => Palette::Deuteranopia,
+            _ => Palette::Default,
+        }
+    }
+
+    /// The palette templates are currently drawn with
+    pub fn active() -> Self {
+        Self::ALL[ACTIVE.load(Ordering::Relaxed) as usize]
+    }
+
+    pub fn activate(self) {
+        let index = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        ACTIVE.store(index as u8, Ordering::Relaxed);
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Palette::Default => Palette::HighContrast,
+            Palette::HighContrast => Palette::Deuteranopia,
+            Palette::Deuteranopia => Palette::Default,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Palette::Default => "Default Colors",
+            Palette::HighContrast => "High Contrast",
+            Palette::Deuteranopia => "Colorblind Safe",
+        }
+    }
+
+    pub fn color(self, status: Status) -> Color {
+        match (self, status) {
+            (Palette::Default, Status::Ok) => Color::Green,
+            (Palette::Default, Status::Warning) => Color::Yellow,
+            (Palette::Default, Status::Danger) => Color::Red,
+            (Palette::Default, Status::Info) => Color::Cyan,
+            (Palette::Default, Status::Inactive) => Color::Gray,
+            (Palette::HighContrast, Status::Ok) => Color::LightGreen,
+            (Palette::HighContrast, Status::Warning) => Color::LightYellow,
+            (Palette::HighContrast, Status::Danger) => Color::LightRed,
+            (Palette::HighContrast, Status::Info) => Color::LightCyan,
+            (Palette::HighContrast, Status::Inactive) => Color::White,
+            (Palette::Deuteranopia, Status::Ok) => TERMINAL_COLORS.map_rgb_to_term_color(0, 114, 178),
+            (Palette::Deuteranopia, Status::Warning) => TERMINAL_COLORS.map_rgb_to_term_color(240, 228, 66),
+            (Palette::Deuteranopia, Status::Danger) => TERMINAL_COLORS.map_rgb_to_term_color(213, 94, 0),
+            (Palette::Deuteranopia, Status::Info) => TERMINAL_COLORS.map_rgb_to_term_color(86, 180, 233),
+            (Palette::Deuteranopia, Status::Inactive) => Color::Gray,
+        }
+    }
+}
+
+impl Status {
+    /// Color in the active palette
+    pub fn color(self) -> Color {
+        Palette::active().color(self)
+    }
+
+    /// Foreground style in the active palette; bold when high contrast
+    pub fn style(self) -> Style {
+        let style = Style::default().fg(self.color());
+        if Palette::active() == Palette::HighContrast {
+            style.add_modifier(Modifier::BOLD)
+        } else {
+            style
+        }
+    }
+
+    /// Shape that tells statuses apart without color; empty in the default palette
+    pub fn symbol(self) -> &'static str {
+        if Palette::active() == Palette::Default {
+            return "";
+        }
+        match self {
+            Status::Ok => "●",
+            Status::Warning => "▲",
+            Status::Danger => "■",
+            Status::Info => "◆",
+            Status::Inactive => "○",
+        }
+    }
+
+    /// `text` with the status symbol in front, when the palette uses symbols
+    pub fn mark(self, text: &str) -> String {
+        match self.symbol() {
+            "" => text.to_string(),
+            symbol => format!("{} {}", symbol, text),
+        }
+    }
+
+    /// Where `value` falls against warning and danger thresholds
+    pub fn from_thresholds(value: f64, warn: f64, danger: f64) -> Self {
+        if value >= danger {
+            Status::Danger
+        } else if value >= warn {
+            Status::Warning
+        } else {
+            Status::Ok
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statuses_stay_distinct_in_every_palette() {
+        for palette in Palette::ALL {
+            let colors = [Status::Ok, Status::Warning, Status::Danger].map(|s| palette.color(s));
+            assert_ne!(colors[0], colors[1], "{:?}", palette);
+            assert_ne!(colors[1], colors[2], "{:?}", palette);
+            assert_ne!(colors[0], colors[2], "{:?}", palette);
+        }
+
+        assert_eq!(Status::from_thresholds(90.0, 70.0, 85.0), Status::Danger);
+        assert_eq!(Status::from_thresholds(70.0, 70.0, 85.0), Status::Warning);
+        assert_eq!(Status::from_thresholds(10.0, 70.0, 85.0), Status::Ok);
+        assert_eq!(Palette::Deuteranopia.next(), Palette::Default);
+    }
+}
@@ -0,0 +1,41 @@
+// src/ui/safe_mode_banner.rs
+//! The red strip across the top of a module's detail view while safe mode freezes actuators.
+//! The overview says the same in its title.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Paragraph, Widget},
+};
+
+const BANNER: &str = " 🛑 SAFE MODE - actuators frozen, [^P] to resume ";
+
+pub fn render_safe_mode_banner(area: Rect, buf: &mut Buffer) {
+    if area.height == 0 {
+        return;
+    }
+
+    let strip = Rect { height: 1, ..area };
+    Paragraph::new(BANNER)
+        .style(Style::default().fg(Color::White).bg(Color::Red).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .render(strip, buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_banner_covers_the_top_row_only() {
+        let area = Rect::new(0, 0, 60, 3);
+        let mut buf = Buffer::empty(area);
+        render_safe_mode_banner(area, &mut buf);
+
+        let top: String = (0..area.width).map(|x| buf[(x, 0)].symbol()).collect();
+        assert!(top.contains("SAFE MODE"));
+        assert_eq!(buf[(0, 0)].bg, Color::Red);
+        assert_eq!(buf[(0, 1)].bg, Color::Reset);
+    }
+}
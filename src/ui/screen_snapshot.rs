@@ -0,0 +1,207 @@
+// src/ui/screen_snapshot.rs
+//! The last drawn frame as ANSI text, for watching a headless unit from elsewhere.
+//!
+//! With `SCREEN_SNAPSHOT_PATH` set, the latest redraw is written there (at most every
+//! `SCREEN_SNAPSHOT_SECS`), colors intact, so `cat` over ssh shows the dashboard as it is.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::style::{Color, Modifier};
+use ratatui::text::Span;
+use tokio::time::Instant;
+
+use crate::log_warn;
+use crate::util::fs::write_atomic;
+
+/// Minimum time between snapshot writes, overridable with `SCREEN_SNAPSHOT_SECS`
+const DEFAULT_SNAPSHOT_SECS: u64 = 5;
+
+const RESET: &str = "\x1b[0m";
+
+/// Writes the drawn buffer to a file, throttled so a busy dashboard doesn't hammer the disk
+#[derive(Debug)]
+pub struct ScreenSnapshot {
+    path: PathBuf,
+    interval: Duration,
+    last_written: Option<Instant>,
+    /// The newest frame not yet written, held until the interval allows it
+    pending: Option<Buffer>,
+}
+
+impl ScreenSnapshot {
+    pub fn new(path: PathBuf, interval: Duration) -> Self {
+        Self { path, interval, last_written: None, pending: None }
+    }
+
+    /// None unless `SCREEN_SNAPSHOT_PATH` is set
+    pub fn from_env() -> Option<Self> {
+        let path = std::env::var_os("SCREEN_SNAPSHOT_PATH").filter(|p| !p.is_empty())?;
+        let secs = std::env::var("SCREEN_SNAPSHOT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_SNAPSHOT_SECS);
+        Some(Self::new(PathBuf::from(path), Duration::from_secs(secs)))
+    }
+
+    /// Keep `buf` as the frame to write, writing it now if the interval allows
+    pub fn capture(&mut self, buf: &Buffer) {
+        self.pending = Some(buf.clone());
+        self.flush(Instant::now());
+    }
+
+    /// Write the pending frame once the interval has passed since the last write. Called every tick
+    /// as well, so the last frame before the dashboard goes quiet still lands. Failures are logged,
+    /// never fatal.
+    pub fn flush(&mut self, now: Instant) {
+        if self.last_written.is_some_and(|at| now.duration_since(at) < self.interval) {
+            return;
+        }
+        let Some(buf) = self.pending.take() else {
+            return;
+        };
+        self.last_written = Some(now);
+
+        // Atomic, so readers never see a half-written frame
+        if let Err(e) = write_atomic(&self.path, buffer_to_ansi(&buf)) {
+            log_warn!("Failed to write screen snapshot to {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+/// One line per buffer row, with SGR escapes wherever the style changes and a reset at each line end
+pub fn buffer_to_ansi(buf: &Buffer) -> String {
+    let area = buf.area;
+    let mut out = String::new();
+
+    for y in area.top()..area.bottom() {
+        let mut current: Option<&Cell> = None;
+        let mut skip = 0;
+
+        for x in area.left()..area.right() {
+            // Cells covered by the wide glyph before them
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+
+            let cell = &buf[(x, y)];
+            if cell.skip {
+                continue;
+            }
+            if current.map_or(true, |c| (c.fg, c.bg, c.modifier) != (cell.fg, cell.bg, cell.modifier)) {
+                out.push_str(&sgr(cell));
+                current = Some(cell);
+            }
+
+            let symbol = cell.symbol();
+            out.push_str(symbol);
+            skip = Span::raw(symbol).width().saturating_sub(1);
+        }
+
+        out.push_str(RESET);
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Full style for `cell`, starting from a reset so nothing carries over from the previous run
+fn sgr(cell: &Cell) -> String {
+    let mut codes = vec!["0".to_string()];
+
+    for (modifier, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if cell.modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+    codes.extend(color_code(cell.fg, false));
+    codes.extend(color_code(cell.bg, true));
+
+    let mut sgr = String::from("\x1b[");
+    let _ = write!(sgr, "{}m", codes.join(";"));
+    sgr
+}
+
+/// None for `Reset`, which leaves the viewer's own default
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let base = if background { 40 } else { 30 };
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => base,
+        Color::Red => base + 1,
+        Color::Green => base + 2,
+        Color::Yellow => base + 3,
+        Color::Blue => base + 4,
+        Color::Magenta => base + 5,
+        Color::Cyan => base + 6,
+        Color::Gray => base + 7,
+        Color::DarkGray => base + 60,
+        Color::LightRed => base + 61,
+        Color::LightGreen => base + 62,
+        Color::LightYellow => base + 63,
+        Color::LightBlue => base + 64,
+        Color::LightMagenta => base + 65,
+        Color::LightCyan => base + 66,
+        Color::White => base + 67,
+        Color::Indexed(i) => return Some(format!("{};5;{}", base + 8, i)),
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{};{};{}", base + 8, r, g, b)),
+    };
+    Some(code.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    #[test]
+    fn test_ansi_keeps_colors_and_resets_each_line() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        buf.set_string(0, 0, "ok", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
+        buf.set_string(0, 1, "hot", Style::default().fg(Color::Rgb(213, 94, 0)).bg(Color::Indexed(21)));
+
+        let ansi = buffer_to_ansi(&buf);
+        let lines: Vec<&str> = ansi.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("\x1b[0;1;32mok\x1b[0m  "));
+        assert!(lines[1].starts_with("\x1b[0;38;2;213;94;0;48;5;21mhot\x1b[0m "));
+        assert!(lines.iter().all(|line| line.ends_with(RESET)));
+    }
+
+    #[test]
+    fn test_latest_frame_is_written_once_the_interval_passes() {
+        let path = std::env::temp_dir().join(format!("survon_snapshot_{}", uuid::Uuid::new_v4()));
+        let mut snapshot = ScreenSnapshot::new(path.clone(), Duration::from_secs(60));
+        let frame = |text: &str| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+            buf.set_string(0, 0, text, Style::default());
+            buf
+        };
+
+        snapshot.capture(&frame("one"));
+        snapshot.capture(&frame("two"));
+        snapshot.capture(&frame("three"));
+        assert!(std::fs::read_to_string(&path).unwrap().contains("one"));
+
+        // No further redraws; the tick writes the newest frame
+        snapshot.flush(Instant::now() + Duration::from_secs(61));
+        assert!(std::fs::read_to_string(&path).unwrap().contains("three"));
+        assert!(snapshot.pending.is_none());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+// src/ui/screens/loading/mod.rs
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Gauge, Paragraph, Widget},
+};
+use std::time::{Duration, Instant};
+
+use crate::log_warn;
+use crate::module::HandlerInitProgress;
+
+/// Handlers taking longer than this are called out in the log and highlighted
+const SLOW_HANDLER_THRESHOLD: Duration = Duration::from_secs(2);
+/// Finished steps kept on screen
+const RECENT_STEPS: usize = 6;
+
+/// Progress of handler initialization, shown while `initialize_module_handlers` runs
+#[derive(Debug)]
+pub struct LoadingScreen {
+    current: Option<(HandlerInitProgress, Instant)>,
+    finished: Vec<(HandlerInitProgress, Duration)>,
+}
+
+impl LoadingScreen {
+    pub fn new() -> Self {
+        Self {
+            current: None,
+            finished: Vec::new(),
+        }
+    }
+
+    /// A new step started; the previous one is complete
+    pub fn record(&mut self, progress: HandlerInitProgress) {
+        self.finish_current();
+        self.current = Some((progress, Instant::now()));
+    }
+
+    /// Close out the step in flight (call once initialization returns)
+    pub fn finish_current(&mut self) {
+        if let Some((progress, started)) = self.current.take() {
+            let elapsed = started.elapsed();
+            if elapsed >= SLOW_HANDLER_THRESHOLD {
+                log_warn!(
+                    "🐢 Handler '{}' ({}) took {:.1}s to initialize",
+                    progress.handler_key, progress.namespace, elapsed.as_secs_f64()
+                );
+            }
+            self.finished.push((progress, elapsed));
+        }
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered()
+            .title(" Starting Survon ")
+            .title_alignment(Alignment::Center)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().fg(Color::Green));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),  // Current step
+                Constraint::Length(1),
+                Constraint::Length(1),  // Gauge
+                Constraint::Length(1),
+                Constraint::Min(1),     // Recent steps
+            ])
+            .margin(1)
+            .split(inner);
+
+        let (label, ratio) = match &self.current {
+            Some((p, _)) => (
+                format!("[{}] registering {}… {}/{}", p.namespace, p.handler_key, p.step, p.total),
+                p.step as f64 / p.total.max(1) as f64,
+            ),
+            None => ("Preparing modules…".to_string(), 0.0),
+        };
+
+        Paragraph::new(label)
+            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .render(chunks[0], buf);
+
+        Gauge::default()
+            .gauge_style(Style::default().fg(Color::Green).bg(Color::Black))
+            .ratio(ratio.clamp(0.0, 1.0))
+            .render(chunks[2], buf);
+
+        let lines: Vec<Line> = self.finished
+            .iter()
+            .rev()
+            .take(RECENT_STEPS)
+            .map(|(p, elapsed)| {
+                let color = if *elapsed >= SLOW_HANDLER_THRESHOLD { Color::Red } else { Color::DarkGray };
+                Line::from(vec![
+                    Span::styled("✓ ", Style::default().fg(Color::Green)),
+                    Span::styled(format!("{} ", p.handler_key), Style::default().fg(Color::Gray)),
+                    Span::styled(format!("{:.1}s", elapsed.as_secs_f64()), Style::default().fg(color)),
+                ])
+            })
+            .collect();
+
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .render(chunks[4], buf);
+    }
+}
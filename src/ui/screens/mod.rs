@@ -1,2 +1,3 @@
 pub mod splash;
 pub mod overview;
+pub mod loading;
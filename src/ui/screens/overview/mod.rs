@@ -7,10 +7,17 @@ use ratatui::{
     text::Line,
 };
 use crate::app::{App, OverviewFocus};
-use crate::module::ModuleManagerView;
+use crate::module::{key_hints::format_key_hints, ModuleManagerView};
+use crate::ui::key_help::FULLSCREEN_KEY;
+use crate::runtime::disk_watch::low_disk_space;
+use crate::runtime::dry_run::is_dry_run;
+use crate::runtime::safe_mode::is_safe_mode;
+use crate::util::disk::format_bytes;
 
 pub fn render_overview(app: &mut App, area: Rect, buf: &mut Buffer) {
-    let header_constraints = Constraint::Length(10);
+    let density = app.ui_state.density;
+
+    let header_constraints = Constraint::Length(density.header_height());
     let main_content_constraints = Constraint::Min(1);
     let footer_constraints = Constraint::Length(3);
 
@@ -55,15 +62,25 @@ pub fn render_overview(app: &mut App, area: Rect, buf: &mut Buffer) {
     let is_core_modules_list_view = matches!(app.core_module_manager.current_view, ModuleManagerView::ModuleListView);
     let is_messages_focused = matches!(app.overview_focus, OverviewFocus::Messages);
 
-    // Render title
-    let title = Paragraph::new(" 🏡 Survon - Smart Homestead OS ")
+    // Render title, flagging safe mode and dry-run so neither is left on by accident, then a
+    // nearly full disk before saves start failing
+    let (title_text, title_color) = if is_safe_mode() {
+        (" 🛑 SAFE MODE - actuators frozen, [^P] to resume ".to_string(), Color::Red)
+    } else if is_dry_run() {
+        (" 🧪 DRY RUN - actuators disabled ".to_string(), Color::Yellow)
+    } else if let Some(space) = low_disk_space() {
+        (format!(" 💾 LOW DISK SPACE - {} free ", format_bytes(space.available)), Color::Yellow)
+    } else {
+        (" 🏡 Survon - Smart Homestead OS ".to_string(), Color::Green)
+    };
+    let title = Paragraph::new(title_text)
         .block(
             Block::bordered()
                 .title(" Survon ")
                 .title_alignment(Alignment::Center)
                 .border_type(BorderType::Rounded)
         )
-        .style(Style::default().fg(Color::Green))
+        .style(Style::default().fg(title_color))
         .alignment(Alignment::Center);
     title.render(header_layout[0], buf);
 
@@ -190,33 +207,26 @@ pub fn render_overview(app: &mut App, area: Rect, buf: &mut Buffer) {
         }
     }
 
-    let wasteland_help_text: &str = {
-        if app.wasteland_module_manager.get_modules().is_empty() {
-            "No wasteland modules found."
-        } else if is_wasteland_modules_list_view {
-            "[←]/[→] Navigate Wasteland Modules"
-        } else {
-            "[Esc] Back to List"
-        }
-    };
-
-    let core_help_text: &str = {
-        if is_core_modules_list_view {
-            "[←]/[→] Navigate Core Modules"
-        } else {
-            "[Esc] Back to List"
+    // Footer shows the same hints as the `?` overlay: the focused part's keys, then the app's own
+    let focused_hints = if is_wasteland_modules_list_focused && app.wasteland_module_manager.get_modules().is_empty() {
+        "No wasteland modules found.  ".to_string()
+    } else {
+        match app.focused_key_hints() {
+            Some(section) if !section.hints.is_empty() => format!("{}  ", format_key_hints(&section.hints)),
+            _ => String::new(),
         }
     };
-
-    let focus_hint = match app.overview_focus {
-        OverviewFocus::None => "[Tab] Focus Wasteland Modules".to_string(),
-        OverviewFocus::WastelandModules => format!("{} [Tab] Focus Messages", wasteland_help_text),
-        OverviewFocus::Messages => "[↑]/[↓] Scroll  [Tab] Focus Core Modules".to_string(),
-        OverviewFocus::CoreModules => format!("{}  [Tab] Focus Jukebox", core_help_text),
-        OverviewFocus::Jukebox => "[Spc] ⏯  [←]/[→] ⏮/⏭  [+]/[-] 🔈  [m] Library  [Tab] Remove Overview Focus".to_string(),
+    let fullscreen_hint = if is_wasteland_modules_list_focused || is_core_modules_list_focused {
+        format!("{} Fullscreen  ", FULLSCREEN_KEY)
+    } else {
+        String::new()
     };
-
-    let help_text = format!("{}  [Ent] Select  [r] Refresh  [q] Quit", focus_hint);
+    let help_text = format!(
+        "{}{}{}",
+        focused_hints,
+        fullscreen_hint,
+        format_key_hints(&app.global_key_hints()),
+    );
 
     let help = Paragraph::new(help_text)
         .block(
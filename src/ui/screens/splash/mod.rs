@@ -6,12 +6,64 @@ use ratatui::{
     text::Line,
 };
 use std::time::{Duration, Instant};
+use color_eyre::{eyre::eyre, Result};
 use crate::log_error;
+use crate::runtime::reduced_motion::is_reduced_motion;
 use crate::util::{
     audio::SurvonAudioPlayer,
+    dirs::data_dirs,
     image::ImageRenderer,
 };
 
+/// How long the splash holds: from `SPLASH_MIN_MS` and `SPLASH_AUTO_ADVANCE_MS` or
+/// [`RuntimeBuilder::with_splash_timing`](crate::runtime::RuntimeBuilder::with_splash_timing)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplashTiming {
+    /// Keypresses before this are ignored; zero lets the first key skip straight to the dashboard
+    pub min_display: Duration,
+    /// Move on without a keypress once this has passed; `None` waits for a key
+    pub auto_advance: Option<Duration>,
+}
+
+impl Default for SplashTiming {
+    fn default() -> Self {
+        Self {
+            min_display: Duration::from_millis(2000),
+            auto_advance: None,
+        }
+    }
+}
+
+impl SplashTiming {
+    pub fn from_env() -> Result<Self> {
+        let millis = |name: &str| -> Result<Option<Duration>> {
+            match std::env::var(name) {
+                Ok(value) if !value.is_empty() => value
+                    .parse::<u64>()
+                    .map(|ms| Some(Duration::from_millis(ms)))
+                    .map_err(|_| eyre!("{} must be a whole number of milliseconds, not '{}'", name, value)),
+                _ => Ok(None),
+            }
+        };
+
+        let defaults = Self::default();
+        Ok(Self {
+            min_display: millis("SPLASH_MIN_MS")?.unwrap_or(defaults.min_display),
+            auto_advance: millis("SPLASH_AUTO_ADVANCE_MS")?,
+        })
+    }
+
+    /// Whether a keypress `elapsed` into the splash dismisses it
+    pub fn can_dismiss(&self, elapsed: Duration) -> bool {
+        elapsed >= self.min_display
+    }
+
+    /// Whether the splash should have moved on by itself after `elapsed`
+    pub fn should_auto_advance(&self, elapsed: Duration) -> bool {
+        self.auto_advance.is_some_and(|after| elapsed >= after)
+    }
+}
+
 #[derive(Debug)]
 pub struct SplashScreen {
     pub start_time: Instant,
@@ -20,21 +72,26 @@ pub struct SplashScreen {
     pub user_dismissed: bool,
     pub player: SurvonAudioPlayer,
     pub background_image: Option<ImageRenderer>,
+    pub timing: SplashTiming,
 }
 
 impl SplashScreen {
     pub fn new() -> Self {
+        let assets = data_dirs().assets;
         let mut player = SurvonAudioPlayer::new_with_audio_jack(
-            "assets/audio/theme_compressed.wav",
+            &assets.join("audio").join("theme_compressed.wav").to_string_lossy(),
             0.1
         );
 
-        if let Err(e) = player.play_looped() {
-            log_error!("Failed to play theme: {}", e);
+        // Without a device the player already warned once; play silently
+        if player.has_device() {
+            if let Err(e) = player.play_looped() {
+                log_error!("Failed to play theme: {}", e);
+            }
         }
 
         // Load background image
-        let background_image = ImageRenderer::from_path("assets/images/homestead-scene-3-wide.png")
+        let background_image = ImageRenderer::from_path(assets.join("images").join("homestead-scene-3-wide.png"))
             .ok();
 
         if background_image.is_none() {
@@ -48,25 +105,49 @@ impl SplashScreen {
             user_dismissed: false,
             player,
             background_image,
+            timing: SplashTiming::default(),
         }
     }
 
+    pub fn with_timing(mut self, timing: SplashTiming) -> Self {
+        self.timing = timing;
+        self
+    }
+
     pub fn bypass_theme(&mut self) -> bool {
-        if self.start_time.elapsed() >= Duration::from_millis(2000) {
-            self.is_running = false;
-            self.user_dismissed = true;
-            self.player.stop().ok();
+        if self.timing.can_dismiss(self.start_time.elapsed()) {
+            self.dismiss();
             true
         } else {
             false
         }
     }
 
-    pub fn is_complete(&self) -> bool {
+    /// Dismiss the splash if its auto-advance time has passed; true once it's dismissed either way
+    pub fn advance(&mut self) -> bool {
+        if !self.user_dismissed && self.timing.should_auto_advance(self.start_time.elapsed()) {
+            self.dismiss();
+        }
         self.user_dismissed
     }
 
+    pub fn is_complete(&self) -> bool {
+        self.user_dismissed || self.timing.should_auto_advance(self.start_time.elapsed())
+    }
+
+    fn dismiss(&mut self) {
+        self.is_running = false;
+        self.user_dismissed = true;
+        self.player.stop().ok();
+    }
+
     pub fn update(&mut self) {
+        // Frame 0 forever: each line keeps its own color instead of cycling
+        if is_reduced_motion() {
+            self.animation_frame = 0.0;
+            return;
+        }
+
         let elapsed = self.start_time.elapsed().as_millis() as f64;
         self.animation_frame = elapsed / 20.0;
     }
@@ -170,8 +251,10 @@ impl SplashScreen {
         let loading_y = tagline_y + 2;
         if loading_y < area.height {
             let elapsed = self.start_time.elapsed();
-            let message = if elapsed >= Duration::from_millis(2000) && !self.user_dismissed {
+            let message = if self.timing.can_dismiss(elapsed) && !self.user_dismissed {
                 "Press any key to continue".to_string()
+            } else if is_reduced_motion() {
+                "Loading...".to_string()
             } else {
                 let dots = ".".repeat(((self.animation_frame / 5.0) as usize % 4) + 1);
                 format!("Loading{}", dots)
@@ -199,3 +282,24 @@ impl SplashScreen {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing_gates_keypresses_and_auto_advance() {
+        let default = SplashTiming::default();
+        assert!(!default.can_dismiss(Duration::from_millis(1999)));
+        assert!(default.can_dismiss(Duration::from_millis(2000)));
+        assert!(!default.should_auto_advance(Duration::from_secs(3600)));
+
+        let kiosk = SplashTiming {
+            min_display: Duration::ZERO,
+            auto_advance: Some(Duration::from_millis(500)),
+        };
+        assert!(kiosk.can_dismiss(Duration::ZERO));
+        assert!(!kiosk.should_auto_advance(Duration::from_millis(499)));
+        assert!(kiosk.should_auto_advance(Duration::from_millis(500)));
+    }
+}
@@ -0,0 +1,61 @@
+// src/ui/template/calibration_overlay.rs
+
+//! The calibration wizard, drawn over whichever template a monitoring module uses. The handler
+//! publishes the wizard as lines in the `calibration` binding while it is open.
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, BorderType, Clear, Paragraph, Widget, Wrap};
+
+use crate::module::Module;
+
+/// Binding holding the wizard's lines, heading first
+pub const CALIBRATION: &str = "calibration";
+
+/// Draw the wizard along the bottom of `area` if the module has one open
+pub fn render_calibration_overlay(area: Rect, buf: &mut Buffer, module: &Module) {
+    let Some(lines) = module.config.binding_array(CALIBRATION) else {
+        return;
+    };
+    let mut lines = lines.iter().filter_map(|line| line.as_str());
+    let Some(heading) = lines.next() else {
+        return;
+    };
+    let body: Vec<Line> = lines.map(Line::from).collect();
+
+    let height = (body.len() as u16 + 2).min(area.height);
+    let rect = Rect { y: area.bottom() - height, height, ..area };
+
+    Clear.render(rect, buf);
+    Paragraph::new(body)
+        .block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(format!(" {} ", heading))
+                .style(Style::default().fg(Color::Yellow)),
+        )
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: true })
+        .render(rect, buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_util::TestModule;
+
+    #[test]
+    fn test_overlay_only_when_calibrating() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 8));
+        let module = TestModule::new("Tank", "monitoring", "gauge_card").build();
+        render_calibration_overlay(buf.area, &mut buf, &module);
+        assert_eq!(buf, Buffer::empty(buf.area));
+
+        let module = TestModule::new("Tank", "monitoring", "gauge_card")
+            .binding(CALIBRATION, serde_json::json!(["Calibrate a", "Raw reading now: 812"]))
+            .build();
+        render_calibration_overlay(buf.area, &mut buf, &module);
+        let row = |y: u16| -> String { (0..40).map(|x| buf[(x, y)].symbol()).collect() };
+        assert!(row(5).contains("Calibrate a"));
+        assert!(row(6).contains("Raw reading now: 812"));
+    }
+}
@@ -0,0 +1,89 @@
+// src/ui/template/empty_state.rs
+
+//! The shared "no data yet" card. A module shows it instead of its template while its handler
+//! reports `awaiting_data` or the template's [`UiTemplate::has_data`](super::UiTemplate::has_data)
+//! says there is nothing to draw, so a fresh module never passes off defaults as a reading.
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Widget, Wrap};
+
+use crate::module::Module;
+
+/// Binding a handler sets to `true` until its first sample arrives
+pub const AWAITING_DATA: &str = "awaiting_data";
+
+const WAITING_TEXT: &str = "Waiting for data…";
+
+/// Every binding in `required` is set and not null
+pub fn has_required_bindings(module: &Module, required: &[&str]) -> bool {
+    required
+        .iter()
+        .all(|key| module.config.bindings.get(*key).is_some_and(|v| !v.is_null()))
+}
+
+/// The handler says it has no samples yet
+pub fn is_awaiting_data(module: &Module) -> bool {
+    module.config.binding_or(AWAITING_DATA, false)
+}
+
+/// Overview card (`is_selected` is `Some`) with its own border and title, or detail content
+/// (`None`) inside the chrome that is already drawn
+pub fn render_empty_state(is_selected: Option<bool>, area: Rect, buf: &mut Buffer, module: &Module) {
+    let mut text = WAITING_TEXT.to_string();
+    let mut block = Block::default();
+
+    match is_selected {
+        Some(is_selected) => {
+            let display_name = module.config.binding_str("display_name").unwrap_or(&module.config.name);
+
+            block = block
+                .title(format!(" {} ", display_name))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(if is_selected { Color::White } else { Color::DarkGray }));
+        }
+        None => {
+            text.push_str("\n\nThis card fills in once the module reports its first reading.");
+        }
+    }
+
+    let inner = block.inner(area);
+    Widget::render(block, area, buf);
+
+    // Vertically centered in whatever room is left
+    let top = inner.height.saturating_sub(text.lines().count() as u16) / 2;
+    let message_area = Rect { y: inner.y + top, height: inner.height - top, ..inner };
+
+    Paragraph::new(text)
+        .style(Style::default().fg(Color::Gray).italic())
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .render(message_area, buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_util::TestModule;
+
+    #[test]
+    fn test_missing_null_and_awaiting_count_as_no_data() {
+        let module = TestModule::new("Tank", "monitoring", "gauge_card")
+            .binding("a", serde_json::json!(0))
+            .binding("max_value", serde_json::Value::Null)
+            .build();
+        assert!(has_required_bindings(&module, &["a"]));
+        assert!(!has_required_bindings(&module, &["a", "max_value"]));
+        assert!(!has_required_bindings(&module, &["history"]));
+        assert!(!is_awaiting_data(&module));
+
+        let module = TestModule::new("Tank", "monitoring", "gauge_card")
+            .binding(AWAITING_DATA, serde_json::json!(true))
+            .build();
+        assert!(is_awaiting_data(&module));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 5));
+        render_empty_state(Some(false), buf.area, &mut buf, &module);
+        let row: String = (0..30).map(|x| buf[(x, 2)].symbol()).collect();
+        assert!(row.contains("Waiting for data"));
+    }
+}
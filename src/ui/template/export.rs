@@ -0,0 +1,200 @@
+// src/ui/template/export.rs
+
+//! Converts an off-screen `Buffer` (see `preview`) into SVG, or PNG with the
+//! `template-preview-png` feature, for registry listings and documentation.
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::style::{Color, Modifier};
+
+const CELL_WIDTH: u32 = 9;
+const CELL_HEIGHT: u32 = 18;
+const DEFAULT_FG: &str = "#d0d0d0";
+const DEFAULT_BG: &str = "#1c1c1c";
+
+/// One `<rect>` per run of equal background, one `<text>` per non-blank cell
+pub fn buffer_to_svg(buf: &Buffer) -> String {
+    let area = buf.area;
+    let width = area.width as u32 * CELL_WIDTH;
+    let height = area.height as u32 * CELL_HEIGHT;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" \
+         font-family=\"DejaVu Sans Mono, Menlo, Consolas, monospace\" font-size=\"{fs}\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{bg}\"/>\n",
+        w = width,
+        h = height,
+        fs = CELL_HEIGHT - 4,
+        bg = DEFAULT_BG,
+    );
+
+    for y in 0..area.height {
+        // Backgrounds, merged across runs of the same color
+        let mut run: Option<(u16, String)> = None;
+        for x in 0..=area.width {
+            let bg = (x < area.width)
+                .then(|| colors(&buf[(area.x + x, area.y + y)]).1)
+                .filter(|bg| bg != DEFAULT_BG);
+
+            let continues = matches!((&run, &bg), (Some((_, a)), Some(b)) if a == b);
+            if !continues {
+                if let Some((start, color)) = run.take() {
+                    svg.push_str(&format!(
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+                        start as u32 * CELL_WIDTH,
+                        y as u32 * CELL_HEIGHT,
+                        (x - start) as u32 * CELL_WIDTH,
+                        CELL_HEIGHT,
+                        color,
+                    ));
+                }
+                run = bg.map(|color| (x, color));
+            }
+        }
+
+        // Glyphs, each pinned to its cell so wide and fallback glyphs can't drift
+        for x in 0..area.width {
+            let cell = &buf[(area.x + x, area.y + y)];
+            let symbol = cell.symbol();
+            if symbol.trim().is_empty() {
+                continue;
+            }
+
+            let (fg, _) = colors(cell);
+            let mut attrs = String::new();
+            if cell.modifier.contains(Modifier::BOLD) {
+                attrs.push_str(" font-weight=\"bold\"");
+            }
+            if cell.modifier.contains(Modifier::ITALIC) {
+                attrs.push_str(" font-style=\"italic\"");
+            }
+            if cell.modifier.contains(Modifier::UNDERLINED) {
+                attrs.push_str(" text-decoration=\"underline\"");
+            }
+            if cell.modifier.contains(Modifier::DIM) {
+                attrs.push_str(" opacity=\"0.6\"");
+            }
+
+            svg.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\" fill=\"{}\"{}>{}</text>\n",
+                x as u32 * CELL_WIDTH,
+                (y as u32 + 1) * CELL_HEIGHT - 5,
+                fg,
+                attrs,
+                escape_xml(symbol),
+            ));
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Rasterize `buffer_to_svg` with the system's monospace font
+#[cfg(feature = "template-preview-png")]
+pub fn buffer_to_png(buf: &Buffer) -> color_eyre::Result<Vec<u8>> {
+    use color_eyre::eyre::eyre;
+    use resvg::{tiny_skia, usvg};
+
+    let mut options = usvg::Options::default();
+    options.fontdb_mut().load_system_fonts();
+
+    let tree = usvg::Tree::from_str(&buffer_to_svg(buf), &options)?;
+    let size = tree.size().to_int_size();
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| eyre!("Preview has zero size"))?;
+
+    resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+
+    pixmap.encode_png().map_err(|e| eyre!("Failed to encode PNG: {}", e))
+}
+
+/// Foreground and background as hex, with `REVERSED` applied
+fn colors(cell: &Cell) -> (String, String) {
+    let fg = to_hex(cell.fg).unwrap_or_else(|| DEFAULT_FG.to_string());
+    let bg = to_hex(cell.bg).unwrap_or_else(|| DEFAULT_BG.to_string());
+
+    if cell.modifier.contains(Modifier::REVERSED) {
+        (bg, fg)
+    } else {
+        (fg, bg)
+    }
+}
+
+/// None for `Reset`, which takes the theme default
+fn to_hex(color: Color) -> Option<String> {
+    let (r, g, b) = match color {
+        Color::Reset => return None,
+        Color::Black => (0x00, 0x00, 0x00),
+        Color::Red => (0xcd, 0x31, 0x31),
+        Color::Green => (0x0d, 0xbc, 0x79),
+        Color::Yellow => (0xe5, 0xe5, 0x10),
+        Color::Blue => (0x24, 0x72, 0xc8),
+        Color::Magenta => (0xbc, 0x3f, 0xbc),
+        Color::Cyan => (0x11, 0xa8, 0xcd),
+        Color::Gray => (0xcc, 0xcc, 0xcc),
+        Color::DarkGray => (0x66, 0x66, 0x66),
+        Color::LightRed => (0xf1, 0x4c, 0x4c),
+        Color::LightGreen => (0x23, 0xd1, 0x8b),
+        Color::LightYellow => (0xf5, 0xf5, 0x43),
+        Color::LightBlue => (0x3b, 0x8e, 0xea),
+        Color::LightMagenta => (0xd6, 0x70, 0xd6),
+        Color::LightCyan => (0x29, 0xb8, 0xdb),
+        Color::White => (0xff, 0xff, 0xff),
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i) => indexed_rgb(i),
+    };
+    Some(format!("#{:02x}{:02x}{:02x}", r, g, b))
+}
+
+/// xterm 256-color palette
+fn indexed_rgb(i: u8) -> (u8, u8, u8) {
+    const BASE: [Color; 16] = [
+        Color::Black, Color::Red, Color::Green, Color::Yellow,
+        Color::Blue, Color::Magenta, Color::Cyan, Color::Gray,
+        Color::DarkGray, Color::LightRed, Color::LightGreen, Color::LightYellow,
+        Color::LightBlue, Color::LightMagenta, Color::LightCyan, Color::White,
+    ];
+
+    match i {
+        0..=15 => {
+            let hex = to_hex(BASE[i as usize]).unwrap_or_default();
+            let channel = |at: usize| u8::from_str_radix(&hex[at..at + 2], 16).unwrap_or(0);
+            (channel(1), channel(3), channel(5))
+        }
+        16..=231 => {
+            let level = |n: u8| if n == 0 { 0 } else { 55 + n * 40 };
+            let n = i - 16;
+            (level(n / 36), level((n / 6) % 6), level(n % 6))
+        }
+        _ => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    #[test]
+    fn test_svg_has_cells_and_escapes() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buf.set_string(0, 0, "a<b", Style::default().fg(Color::Red).bg(Color::Indexed(21)));
+
+        let svg = buffer_to_svg(&buf);
+        assert!(svg.contains("&lt;"));
+        assert!(svg.contains("fill=\"#cd3131\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"27\""));
+        assert_eq!(indexed_rgb(21), (0, 0, 255));
+    }
+}
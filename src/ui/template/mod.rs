@@ -6,6 +6,13 @@ use std::fmt::Debug;
 use std::any::Any;
 
 pub mod module_templates;
+pub mod preview;
+pub mod empty_state;
+pub mod calibration_overlay;
+#[cfg(feature = "template-preview")]
+pub mod export;
+
+pub use preview::{render_template_preview, render_template_preview_sized};
 
 /// Every UI widget implements this
 pub trait UiTemplate: Any + Send + Sync + Debug {
@@ -13,6 +20,12 @@ pub trait UiTemplate: Any + Send + Sync + Debug {
     fn render_detail(&self, area: Rect, buf: &mut Buffer, module: &mut Module);
     fn required_bindings(&self) -> &'static [&'static str];
     fn docs(&self) -> &'static str;
+
+    /// Whether there is anything to draw yet; when false the module shows the shared
+    /// "Waiting for data…" card instead. Defaults to every required binding being set.
+    fn has_data(&self, module: &Module) -> bool {
+        empty_state::has_required_bindings(module, self.required_bindings())
+    }
 }
 
 /// Factory type
@@ -35,6 +48,7 @@ lazy_static::lazy_static! {
         // Com templates
         map.insert("activity_card", activity_card_factory as TemplateFactory);
         map.insert("llm_card", llm_card_factory as TemplateFactory);
+        map.insert("knowledge_card", knowledge_card_factory as TemplateFactory);
 
         // System templates
         map.insert("overseer_card", overseer_card_factory as TemplateFactory);
@@ -75,6 +89,10 @@ fn llm_card_factory() -> Box<dyn UiTemplate> {
     Box::new(module_templates::knowledge::llm_card::LlmCard)
 }
 
+fn knowledge_card_factory() -> Box<dyn UiTemplate> {
+    Box::new(module_templates::knowledge::knowledge_card::KnowledgeCard)
+}
+
 fn overseer_card_factory() -> Box<dyn UiTemplate> {
     Box::new(module_templates::system::overseer_card::OverseerCard)
 }
@@ -1,9 +1,10 @@
 // src/ui/module_templates/com/activity_card.rs
 use ratatui::prelude::*;
 use ratatui::buffer::Buffer;
-use ratatui::widgets::{Block, Borders, List, ListItem, Widget};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Widget};
 
 use crate::module::Module;
+use crate::module::strategies::com::handler::{ActivityEntry, Severity};
 use crate::ui::template::UiTemplate;
 
 #[derive(Debug)]
@@ -11,9 +12,14 @@ pub struct ActivityCard;
 
 struct ViewData {
     status: String,
+    /// `[warn+]`, `[↑3]` etc. appended to the title
+    title_suffix: String,
     border_color: Color,
     items: Vec<(String, Style)>,
     module_name: String,
+    /// Outbound `messages` and the selected one, when the module can send
+    outbound: Vec<String>,
+    selected_message: usize,
 }
 
 impl ActivityCard {
@@ -24,29 +30,45 @@ impl ActivityCard {
         buf: &mut Buffer,
         module: &mut Module
     ) -> ViewData {
-        let module_name = module.config.name.clone();
+        let module_name = module.config.binding_str("display_name").unwrap_or(&module.config.name).to_string();
 
-        // Get the activity log from module bindings
-        let activities = module
+        // Structured entries from the com handler, or plain lines from the config
+        let activities: Vec<(String, Option<Severity>)> = match module
             .config
             .bindings
-            .get("activity_log")
-            .and_then(|v| v.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|v| v.as_str())
-                    .map(|s| s.to_string())
-                    .collect::<Vec<String>>()
-            })
-            .unwrap_or_else(Vec::new);
+            .get("activity_entries")
+            .and_then(|v| serde_json::from_value::<Vec<ActivityEntry>>(v.clone()).ok())
+        {
+            Some(entries) => entries
+                .iter()
+                .map(|e| (e.display_line(), Some(e.severity)))
+                .collect(),
+            None => module
+                .config
+                .binding_array("activity_log")
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| v.as_str())
+                        .map(|s| (s.to_string(), None))
+                        .collect()
+                })
+                .unwrap_or_else(Vec::new),
+        };
+
+        let scroll_offset = module.config.binding_u64("scroll_offset").unwrap_or(0) as usize;
+
+        let mut title_suffix = String::new();
+        if let Some(filter) = module.config.binding_str("severity_filter") {
+            if filter != "all" {
+                title_suffix.push_str(&format!(" [{}]", filter));
+            }
+        }
+        if scroll_offset > 0 {
+            title_suffix.push_str(&format!(" [↑{}]", scroll_offset));
+        }
 
         // Get optional status from bindings for color coding
-        let status = module
-            .config
-            .bindings
-            .get("status")
-            .and_then(|v| v.as_str())
-            .unwrap_or("active")
+        let status = module.config.binding_str("status").unwrap_or("active")
             .to_string();
 
         // Determine border color based on status
@@ -59,29 +81,32 @@ impl ActivityCard {
             }
         };
 
-        // Take last N items to fit the display (most recent at bottom)
-        let display_count = (area.height.saturating_sub(2)) as usize; // Account for borders
-        let recent_activities: Vec<String> = activities
-            .iter()
-            .rev()
-            .take(display_count)
-            .rev()
-            .cloned()
-            .collect();
+        let outbound: Vec<String> = module
+            .config
+            .binding_array("messages")
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(str::to_string).collect())
+            .unwrap_or_default();
+
+        let selected_message = module.config.binding_u64("selected_message").unwrap_or(0) as usize;
 
-        // Create list items with timestamps if available
-        let items: Vec<(String, Style)> = recent_activities
+        // Take last N items to fit the display (most recent at bottom), skipping any scrolled past
+        let send_bar_height = if outbound.is_empty() { 0 } else { 1 };
+        let display_count = (area.height.saturating_sub(2 + send_bar_height)) as usize; // Account for borders
+        let end = activities.len().saturating_sub(scroll_offset);
+        let start = end.saturating_sub(display_count);
+
+        // Color-code by severity, guessing from the text for plain lines
+        let items: Vec<(String, Style)> = activities[start..end]
             .iter()
-            .map(|activity| {
-                // Color-code different message types
-                let style = if activity.contains("ERROR") || activity.contains("FAIL") {
-                    Style::default().fg(Color::Red)
-                } else if activity.contains("WARN") {
-                    Style::default().fg(Color::Yellow)
-                } else if activity.contains("INFO") {
-                    Style::default().fg(Color::Cyan)
-                } else {
-                    Style::default().fg(Color::White)
+            .map(|(activity, severity)| {
+                let style = match severity {
+                    Some(Severity::Error) => Style::default().fg(Color::Red),
+                    Some(Severity::Warn) => Style::default().fg(Color::Yellow),
+                    Some(Severity::Info) => Style::default().fg(Color::Cyan),
+                    None if activity.contains("ERROR") || activity.contains("FAIL") => Style::default().fg(Color::Red),
+                    None if activity.contains("WARN") => Style::default().fg(Color::Yellow),
+                    None if activity.contains("INFO") => Style::default().fg(Color::Cyan),
+                    None => Style::default().fg(Color::White),
                 };
 
                 (activity.clone(), style)
@@ -90,60 +115,76 @@ impl ActivityCard {
 
         ViewData {
             status,
+            title_suffix,
             border_color,
             items,
             module_name,
+            outbound,
+            selected_message,
         }
     }
-}
 
-impl UiTemplate for ActivityCard {
-    fn render_overview_cta(&self, is_selected: bool, area: Rect, buf: &mut Buffer, module: &mut Module) {
+    fn render_card(&self, view: ViewData, area: Rect, buf: &mut Buffer) {
         let ViewData {
             status,
+            title_suffix,
             border_color,
             items,
             module_name,
-        } = self.get_view_data(is_selected, area, buf, module);
+            outbound,
+            selected_message,
+        } = view;
+
+        let block = Block::default()
+            .title(format!(" {} [{}]{} ", module_name, status.to_uppercase(), title_suffix))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color));
+
+        let inner = block.inner(area);
+        Widget::render(block, area, buf);
+
+        let (list_area, send_area) = if outbound.is_empty() {
+            (inner, None)
+        } else {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(inner);
+            (chunks[0], Some(chunks[1]))
+        };
 
         let list_items: Vec<ListItem> = items
             .iter()
             .map(|(text, style)| ListItem::new(text.as_str()).style(*style))
             .collect();
+        Widget::render(List::new(list_items), list_area, buf);
 
-        let list = List::new(list_items)
-            .block(
-                Block::default()
-                    .title(format!(" {} [{}] ", module_name, status.to_uppercase()))
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(border_color))
-            );
+        if let Some(send_area) = send_area {
+            let mut spans = vec![Span::styled("Send: ", Style::default().fg(Color::DarkGray))];
+            for (i, message) in outbound.iter().enumerate() {
+                let style = if i == selected_message {
+                    Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Gray)
+                };
+                spans.push(Span::styled(format!(" {} ", message), style));
+                spans.push(Span::raw(" "));
+            }
+            spans.push(Span::styled("[←→] pick [Enter] send", Style::default().fg(Color::DarkGray)));
+            Widget::render(Paragraph::new(Line::from(spans)), send_area, buf);
+        }
+    }
+}
 
-        Widget::render(list, area, buf);
+impl UiTemplate for ActivityCard {
+    fn render_overview_cta(&self, is_selected: bool, area: Rect, buf: &mut Buffer, module: &mut Module) {
+        let view = self.get_view_data(is_selected, area, buf, module);
+        self.render_card(view, area, buf);
     }
 
     fn render_detail(&self, area: Rect, buf: &mut Buffer, module: &mut Module) {
-        let ViewData {
-            status,
-            border_color,
-            items,
-            module_name,
-        } = self.get_view_data(false, area, buf, module);
-
-        let list_items: Vec<ListItem> = items
-            .iter()
-            .map(|(text, style)| ListItem::new(text.as_str()).style(*style))
-            .collect();
-
-        let list = List::new(list_items)
-            .block(
-                Block::default()
-                    .title(format!(" {} [{}] ", module_name, status.to_uppercase()))
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(border_color))
-            );
-
-        Widget::render(list, area, buf);
+        let view = self.get_view_data(false, area, buf, module);
+        self.render_card(view, area, buf);
     }
 
     fn required_bindings(&self) -> &'static [&'static str] {
@@ -151,7 +192,7 @@ impl UiTemplate for ActivityCard {
     }
 
     fn docs(&self) -> &'static str {
-        "Scrollable activity feed showing recent messages. Supports 'activity_log' (array of strings) and optional 'status' (active/idle/warning/error). Messages containing ERROR/FAIL/WARN/INFO are color-coded."
+        "Scrollable activity feed showing recent messages. Supports 'activity_log' (array of strings) and optional 'status' (active/idle/warning/error). The com handler keeps a bounded log fed from the module's bus_topic: [f] cycles the severity filter, [↑]/[↓] scroll, [End] jumps to newest. Optional 'messages' (array of strings) can be picked with [←]/[→] and sent on the bus_topic with [Enter]. Messages containing ERROR/FAIL/WARN/INFO are color-coded."
     }
 }
 
@@ -3,10 +3,8 @@ use ratatui::prelude::*;
 use ratatui::buffer::Buffer;
 use ratatui::widgets::{Block, Borders, ListItem, Paragraph, Widget};
 use ratatui::layout::{Alignment, Layout, Constraint, Direction};
-use serde_json::Value;
-use std::collections::HashMap;
-
-use crate::module::Module;
+use crate::module::{Module, ModuleConfig};
+use crate::ui::palette::Status;
 use crate::ui::template::UiTemplate;
 
 #[derive(Debug)]
@@ -24,13 +22,6 @@ struct ViewData<'a> {
     inner: Rect
 }
 
-fn get_str<'a>(bindings: &'a HashMap<String, Value>, key: &str, default: &'a str) -> &'a str {
-    bindings
-        .get(key)
-        .and_then(|v| v.as_str())
-        .unwrap_or(default)
-}
-
 struct ToggleConfig<'a> {
     on_label: &'a str,
     off_label: &'a str,
@@ -39,15 +30,12 @@ struct ToggleConfig<'a> {
 }
 
 impl<'a> ToggleConfig<'a> {
-    fn from_bindings(bindings: &'a HashMap<String, Value>) -> Self {
+    fn from_config(config: &'a ModuleConfig) -> Self {
         Self {
-            on_label: get_str(bindings, "toggle_on_label", "ON"),
-            off_label: get_str(bindings, "toggle_off_label", "OFF"),
-            state: bindings
-                .get("state")
-                .and_then(|v| v.as_bool())
-                .unwrap_or(false),
-            label: get_str(bindings, "label", "Toggle"),
+            on_label: config.binding_or("toggle_on_label", "ON"),
+            off_label: config.binding_or("toggle_off_label", "OFF"),
+            state: config.binding_or("state", false),
+            label: config.binding_or("label", "Toggle"),
         }
     }
 }
@@ -61,44 +49,29 @@ impl ToggleSwitch {
         module: &'a mut Module
     ) -> ViewData<'a> {
         // Get the state from module bindings
-        let state = module
-            .config
-            .bindings
-            .get("state")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+        let state = module.config.binding_or("state", false);
 
         // Get optional label
-        let label = module
-            .config
-            .bindings
-            .get("label")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let label = module.config.binding_str("label").unwrap_or("");
 
         // Get optional description
-        let description = module
-            .config
-            .bindings
-            .get("description")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let description = module.config.binding_str("description").unwrap_or("");
 
         // Determine colors based on state
-        let toggle_on_label = get_str(&module.config.bindings, "toggle_on_label", "ON");
-        let toggle_off_label = get_str(&module.config.bindings, "toggle_off_label", "OFF");
+        let toggle_on_label = module.config.binding_or("toggle_on_label", "ON");
+        let toggle_off_label = module.config.binding_or("toggle_off_label", "OFF");
 
         let (status_color, status_text, switch_visual) = if state {
-            (Color::Green, toggle_on_label, "[ ────── ● ]")
+            (Status::Ok.color(), toggle_on_label, "[ ────── ● ]")
         } else {
-            (Color::Red, toggle_off_label, "[ ● ────── ]")
+            (Status::Danger.color(), toggle_off_label, "[ ● ────── ]")
         };
 
         let border_color = if is_selected { Color:: White } else { status_color };
 
         // Create main container
         let block = Block::default()
-            .title(format!(" {} ", module.config.name))
+            .title(format!(" {} ", module.config.binding_str("display_name").unwrap_or(&module.config.name)))
             .borders(Borders::ALL)
             .border_style(Style::default().fg(border_color));
 
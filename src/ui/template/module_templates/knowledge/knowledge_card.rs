@@ -0,0 +1,268 @@
+// src/ui/template/module_templates/knowledge/knowledge_card.rs
+use crate::module::Module;
+use crate::module::strategies::llm::database::{MATCH_END, MATCH_START};
+use crate::ui::template::UiTemplate;
+use ratatui::prelude::*;
+use ratatui::buffer::Buffer;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, StatefulWidget, Widget, Wrap};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+#[derive(Debug)]
+pub struct KnowledgeCard;
+
+/// One row in the document/result list
+struct Entry {
+    heading: String,
+    detail: Option<String>,
+}
+
+struct ViewData<'a> {
+    module_name: &'a str,
+    description: &'a str,
+    document_count: u64,
+    search_query: &'a str,
+    searched_query: Option<&'a str>,
+    entries: Vec<Entry>,
+    selected_index: usize,
+}
+
+/// Width of the relevance bar in front of each search result
+const RELEVANCE_BAR_WIDTH: usize = 5;
+
+/// Bar showing `score` relative to the best result's `top_score`
+fn relevance_bar(score: f64, top_score: f64) -> String {
+    let ratio = if top_score > 0.0 { (score / top_score).clamp(0.0, 1.0) } else { 0.0 };
+    let filled = ((ratio * RELEVANCE_BAR_WIDTH as f64).round() as usize).max(1);
+    format!("{}{}", "▮".repeat(filled), "▯".repeat(RELEVANCE_BAR_WIDTH - filled))
+}
+
+/// Split a search snippet into spans, emphasizing the terms between match markers
+fn snippet_spans(snippet: &str) -> Vec<Span<'static>> {
+    let plain = Style::default().fg(Color::DarkGray);
+    let matched = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+
+    let mut spans = vec![Span::styled("  ", plain)];
+    let mut rest = snippet;
+    while let Some(start) = rest.find(MATCH_START) {
+        spans.push(Span::styled(rest[..start].to_string(), plain));
+        rest = &rest[start + MATCH_START.len()..];
+        let end = rest.find(MATCH_END).unwrap_or(rest.len());
+        spans.push(Span::styled(rest[..end].to_string(), matched));
+        rest = rest.get(end + MATCH_END.len()..).unwrap_or("");
+    }
+    spans.push(Span::styled(rest.to_string(), plain));
+    spans
+}
+
+impl KnowledgeCard {
+    fn get_view_data<'a>(&self, module: &'a Module) -> ViewData<'a> {
+        let config = &module.config;
+
+        let description = config.binding_or("description", "");
+        let document_count = config.binding_or("document_count", 0u64);
+        let search_query = config.binding_or("search_query", "");
+        let searched_query = config.binding_str("searched_query");
+
+        let entries = if searched_query.is_some() {
+            config
+                .binding_array("search_results")
+                .map(|arr| {
+                    let top_score = arr
+                        .iter()
+                        .filter_map(|r| r.get("score").and_then(|v| v.as_f64()))
+                        .fold(0.0, f64::max);
+
+                    arr.iter()
+                        .map(|r| {
+                            let file = r.get("path")
+                                .and_then(|v| v.as_str())
+                                .and_then(|p| std::path::Path::new(p).file_name())
+                                .map(|n| n.to_string_lossy().to_string())
+                                .unwrap_or_default();
+                            let title = r.get("title").and_then(|v| v.as_str()).unwrap_or("Untitled");
+                            let heading = match r.get("score").and_then(|v| v.as_f64()) {
+                                Some(score) => format!("{} {} ({})", relevance_bar(score, top_score), title, file),
+                                None => format!("{} ({})", title, file),
+                            };
+                            Entry {
+                                heading,
+                                detail: r.get("snippet").and_then(|v| v.as_str()).map(|s| s.to_string()),
+                            }
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        } else {
+            config
+                .binding_array("recent_documents")
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|d| d.get("name").and_then(|v| v.as_str()))
+                        .map(|name| Entry { heading: name.to_string(), detail: None })
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let selected_index = config.binding_or("selected_index", 0u64) as usize;
+
+        ViewData {
+            module_name: &module.config.name,
+            description,
+            document_count,
+            search_query,
+            searched_query,
+            entries,
+            selected_index,
+        }
+    }
+}
+
+impl UiTemplate for KnowledgeCard {
+    fn render_overview_cta(&self, is_selected: bool, area: Rect, buf: &mut Buffer, module: &mut Module) {
+        let ViewData {
+            module_name,
+            description,
+            document_count,
+            entries,
+            searched_query,
+            ..
+        } = self.get_view_data(module);
+
+        let border_color = if is_selected { Color::White } else { Color::Green };
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!("📖 {} document{}", document_count, if document_count == 1 { "" } else { "s" }),
+                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+            )),
+        ];
+        if !description.is_empty() {
+            lines.push(Line::from(Span::styled(description.to_string(), Style::default().fg(Color::Gray))));
+        }
+        // Only the recent list is meaningful on the overview
+        if searched_query.is_none() {
+            for entry in entries.iter().take(3) {
+                lines.push(Line::from(Span::styled(
+                    format!("• {}", entry.heading),
+                    Style::default().fg(Color::White),
+                )));
+            }
+        }
+
+        let card = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color))
+                    .title(format!(" {} ", module_name))
+            )
+            .wrap(Wrap { trim: true });
+        Widget::render(card, area, buf);
+    }
+
+    fn render_detail(&self, area: Rect, buf: &mut Buffer, module: &mut Module) {
+        let ViewData {
+            module_name,
+            document_count,
+            search_query,
+            searched_query,
+            entries,
+            selected_index,
+            ..
+        } = self.get_view_data(module);
+
+        // Layout: search box, document/result list, help
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),  // Search box
+                Constraint::Min(1),     // Documents or results
+                Constraint::Length(3),  // Help
+            ])
+            .split(area);
+
+        let search = Paragraph::new(format!("🔍 {}", search_query))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow))
+                    .title(format!(" Search {} ", module_name))
+            )
+            .style(Style::default().fg(Color::Yellow));
+        Widget::render(search, chunks[0], buf);
+
+        let list_title = match searched_query {
+            Some(query) => format!(" {} result{} for \"{}\" ", entries.len(), if entries.len() == 1 { "" } else { "s" }, query),
+            None => format!(" Recent documents ({}) ", document_count),
+        };
+        let list_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Green))
+            .title(list_title);
+
+        if entries.is_empty() {
+            let empty_text = if searched_query.is_some() {
+                "No matches. Try different words, or Backspace to clear."
+            } else {
+                "No documents found in this module's knowledge/ directory."
+            };
+            let empty = Paragraph::new(empty_text)
+                .block(list_block)
+                .style(Style::default().fg(Color::Gray))
+                .alignment(Alignment::Center);
+            Widget::render(empty, chunks[1], buf);
+        } else {
+            let items: Vec<ListItem> = entries
+                .into_iter()
+                .map(|entry| {
+                    let mut lines = vec![Line::from(Span::styled(entry.heading, Style::default().fg(Color::White)))];
+                    if let Some(detail) = entry.detail {
+                        lines.push(Line::from(snippet_spans(&detail)));
+                    }
+                    ListItem::new(lines)
+                })
+                .collect();
+
+            let list = List::new(items)
+                .block(list_block)
+                .highlight_style(Style::default().fg(Color::Black).bg(Color::Green).add_modifier(Modifier::BOLD))
+                .highlight_symbol("▶ ");
+
+            let mut state = ListState::default();
+            state.select(Some(selected_index));
+            StatefulWidget::render(list, chunks[1], buf, &mut state);
+        }
+
+        let help = Paragraph::new("Type to search • Enter: search/open • ↑↓: select • Esc: back")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan))
+                    .title(" Controls ")
+            )
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center);
+        Widget::render(help, chunks[2], buf);
+    }
+
+    fn required_bindings(&self) -> &'static [&'static str] {
+        &["recent_documents", "search_query", "search_results"]
+    }
+
+    fn docs(&self) -> &'static str {
+        "Knowledge browser. Lists the module's most recent documents and searches the \
+         knowledge index without an LLM. Bindings: recent_documents (array of {name, path}), \
+         search_query (string), searched_query (string|null), search_results (array of \
+         {title, path, snippet, score}; matched terms in snippet are wrapped in ⟦ ⟧ and highlighted, \
+         score draws a relevance bar), min_score (number, optional; hides weaker matches), selected_index (number), document_count (number)."
+    }
+}
+
+impl Default for KnowledgeCard {
+    fn default() -> Self {
+        Self
+    }
+}
@@ -31,18 +31,11 @@ impl LlmCard {
         let module_name = &module.config.name;
 
         // Get LLM state from module bindings
-        let model_info = module
-            .config
-            .bindings
-            .get("model_info")
-            .and_then(|v| v.as_str())
-            .unwrap_or("No model loaded");
+        let model_info = module.config.binding_str("model_info").unwrap_or("No model loaded");
 
         let chat_history = module
             .config
-            .bindings
-            .get("chat_history")
-            .and_then(|v| v.as_array())
+            .binding_array("chat_history")
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str())
@@ -51,26 +44,12 @@ impl LlmCard {
             })
             .unwrap_or_default();
 
-        let chat_input = module
-            .config
-            .bindings
-            .get("chat_input")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let chat_input = module.config.binding_str("chat_input").unwrap_or("");
 
-        let scroll_offset = module
-            .config
-            .bindings
-            .get("scroll_offset")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(0) as u16;
+        let scroll_offset = module.config.binding_u64("scroll_offset").unwrap_or(0) as u16;
 
         // Get current link index for highlighting
-        let current_link_index: Option<usize> = module
-            .config
-            .bindings
-            .get("current_link_index")
-            .and_then(|v| v.as_i64())
+        let current_link_index: Option<usize> = module.config.binding_i64("current_link_index")
             .map(|i| i as usize);
 
         ViewData {
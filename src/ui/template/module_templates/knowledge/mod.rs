@@ -1 +1,2 @@
 pub mod llm_card;
+pub mod knowledge_card;
@@ -5,6 +5,8 @@ use ratatui::{
 };
 
 use crate::module::Module;
+use crate::ui::palette::Status;
+use crate::util::format::ValueFormat;
 
 use super::{ChartCard, ViewData};
 
@@ -16,45 +18,51 @@ impl ChartCard {
         buf: &mut Buffer,
         module: &'a mut Module
     ) -> ViewData<'a> {
-        let module_name = &module.config.name;
+        let module_name = module.config.binding_str("display_name").unwrap_or(&module.config.name);
 
         // Get chart type from config
-        let chart_type = module.config.bindings
-            .get("chart_type")
-            .and_then(|v| v.as_str())
-            .unwrap_or("line");
+        let chart_type = module.config.binding_str("chart_type").unwrap_or("line");
 
         // Get history from handler (via bindings)
         let history = Self::get_history(module);
 
         // Get current values for display
-        let a = module.config.bindings.get("a").and_then(|v| v.as_f64()).unwrap_or(0.0);
-        let b = module.config.bindings.get("b").and_then(|v| v.as_f64()).unwrap_or(0.0);
-        let c = module.config.bindings.get("c").and_then(|v| v.as_i64()).unwrap_or(0);
+        let a = module.config.binding_or("a", 0.0);
+        let b = module.config.binding_or("b", 0.0);
+        let c = module.config.binding_i64("c").unwrap_or(0);
 
-        let is_connected = module.config.bindings.get("is_connected")
-            .and_then(|v| v.as_bool()).unwrap_or(true);
+        let is_connected = module.config.binding_or("is_connected", true);
 
-        let status_suffix = module.config.bindings.get("status_suffix")
-            .and_then(|v| v.as_str()).unwrap_or("");
+        let status_suffix = module.config.binding_str("status_suffix").unwrap_or("");
 
-        let unit = module.config.bindings.get("unit_of_measure_label")
-            .and_then(|v| v.as_str()).unwrap_or("units");
+        let mut value_format = ValueFormat::from_config(&module.config);
+        if value_format.unit.is_empty() {
+            value_format.unit = "units".to_string();
+        }
 
         let border_color = if !is_connected {
-            Color::Red
+            Status::Danger.color()
         } else if is_selected {
             Color::White
         } else {
-            Color::Green
+            Status::Ok.color()
         };
 
-        let chart_title = &module.config.name;
+        let chart_title = module.config.binding_str("display_name").unwrap_or(&module.config.name);
+
+        let time_range = module.config.binding_str("time_range").unwrap_or("live");
 
-        let max_value = module.config.bindings.get("max_value")
-            .and_then(|v| v.as_f64()).unwrap_or(100.0);
+        let configured_max = module.config.binding_or("max_value", 100.0);
 
-        let min_value = 0.0;
+        // Historical ranges scale to the data so long, flat stretches stay readable
+        let (min_value, max_value) = if time_range != "live" && !history.is_empty() {
+            let lo = history.iter().map(|(a, _, _)| *a).fold(f64::INFINITY, f64::min);
+            let hi = history.iter().map(|(a, _, _)| *a).fold(f64::NEG_INFINITY, f64::max);
+            let pad = ((hi - lo) * 0.1).max(1.0);
+            (lo - pad, hi + pad)
+        } else {
+            (0.0, configured_max)
+        };
 
         let connected_icon = if is_connected { "🔗" } else { "⛓️‍💥" };
 
@@ -68,11 +76,12 @@ impl ChartCard {
             is_connected,
             connected_icon,
             status_suffix,
-            unit,
+            value_format,
             border_color,
             chart_title,
             max_value,
             min_value,
+            time_range,
         }
     }
 }
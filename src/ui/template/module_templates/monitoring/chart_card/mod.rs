@@ -14,6 +14,7 @@ use ratatui::{
 };
 
 use crate::ui::template::UiTemplate;
+use crate::util::format::ValueFormat;
 
 #[derive(Debug)]
 pub struct ChartCard;
@@ -28,9 +29,10 @@ struct ViewData<'a> {
     is_connected: bool,
     connected_icon: &'a str,
     status_suffix: &'a str,
-    unit: &'a str, //  [of measure]
+    value_format: ValueFormat, // unit of measure, precision, scaling
     border_color: Color,
     chart_title: &'a str,
     min_value: f64,
     max_value: f64,
+    time_range: &'a str, // "live" or a persisted window like "1h"
 }
@@ -6,6 +6,7 @@ use ratatui::{
 };
 
 use crate::module::Module;
+use crate::ui::palette::Status;
 
 use super::{ChartCard, ViewData};
 
@@ -74,8 +75,8 @@ impl ChartCard {
             .bar_width(3)
             .bar_gap(1)
             .max(max_value as u64)
-            .bar_style(Style::default().fg(if is_connected { Color::Green } else { Color::DarkGray }))
-            .value_style(Style::default().fg(Color::Black).bg(if is_connected { Color::Green } else { Color::DarkGray }));
+            .bar_style(Style::default().fg(if is_connected { Status::Ok.color() } else { Color::DarkGray }))
+            .value_style(Style::default().fg(Color::Black).bg(if is_connected { Status::Ok.color() } else { Color::DarkGray }));
 
         Widget::render(bar_chart, area, buf);
     }
@@ -6,6 +6,7 @@ use ratatui::{
 };
 
 use crate::module::Module;
+use crate::ui::palette::Status;
 
 use super::{ChartCard, ViewData};
 
@@ -24,11 +25,12 @@ impl ChartCard {
             is_connected,
             connected_icon,
             status_suffix,
-            unit,
+            value_format,
             border_color,
             chart_title,
             min_value,
             max_value,
+            time_range,
             ..
         } = self.get_view_data(is_selected, area, buf, module);
 
@@ -92,9 +94,9 @@ impl ChartCard {
         }
 
         // Current value display
-        let value_text = format!("Cur: {:.1} {} (Last {} points)", a, unit, history.len());
+        let value_text = format!("Cur: {} (Last {} points, {}) [t] range", value_format.format(a), history.len(), time_range);
         let value_widget = Paragraph::new(value_text)
-            .style(Style::default().fg(if is_connected { Color::White } else { Color::Red }))
+            .style(Style::default().fg(if is_connected { Color::White } else { Status::Danger.color() }))
             .alignment(Alignment::Center);
         value_widget.render(chunks[1], buf);
     }
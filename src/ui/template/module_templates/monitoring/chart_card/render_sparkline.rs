@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::module::Module;
+use crate::ui::palette::Status;
 
 use super::{ChartCard, ViewData};
 
@@ -26,7 +27,7 @@ impl ChartCard {
             is_connected,
             connected_icon,
             status_suffix,
-            unit,
+            value_format,
             border_color,
             max_value,
             ..
@@ -79,13 +80,18 @@ impl ChartCard {
         let max_val = history.iter().map(|(val_a, _, _)| val_a).fold(f64::NEG_INFINITY, |a, &b| a.max(b));
 
         let value_text = if history.is_empty() {
-            format!("Cur: {:.1} {}", a, unit)
+            format!("Cur: {}", value_format.format(a))
         } else {
-            format!("Cur: {:.1} {} | Min: {:.1} | Max: {:.1}", a, unit, min_val, max_val)
+            format!(
+                "Cur: {} | Min: {} | Max: {}",
+                value_format.format(a),
+                value_format.format(min_val),
+                value_format.format(max_val),
+            )
         };
 
         let value_widget = Paragraph::new(value_text)
-            .style(Style::default().fg(if is_connected { Color::White } else { Color::Red }))
+            .style(Style::default().fg(if is_connected { Color::White } else { Status::Danger.color() }))
             .alignment(Alignment::Center);
         value_widget.render(chunks[1], buf);
     }
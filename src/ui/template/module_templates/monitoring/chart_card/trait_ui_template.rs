@@ -5,7 +5,7 @@ use ratatui::{
 
 use crate::{
     module::Module,
-    ui::template::UiTemplate,
+    ui::template::{empty_state::has_required_bindings, UiTemplate},
 };
 
 use super::ChartCard;
@@ -23,6 +23,11 @@ impl UiTemplate for ChartCard {
         &["a", "chart_type"]
     }
 
+    /// A chart needs at least one sample, not just the current reading
+    fn has_data(&self, module: &Module) -> bool {
+        has_required_bindings(module, self.required_bindings()) && !Self::get_history(module).is_empty()
+    }
+
     fn docs(&self) -> &'static str {
         "Multi-type chart display using SSP format. Key 'a' = primary sensor value. \
          Set 'chart_type' to 'line', 'bar', or 'sparkline'. Maintains history of last 50 points. \
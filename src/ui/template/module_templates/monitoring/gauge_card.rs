@@ -2,10 +2,14 @@
 // Add CMD window status indicator to existing gauge
 
 use crate::module::Module;
+use crate::ui::palette::Status;
 use crate::ui::template::UiTemplate;
+use crate::util::format::ValueFormat;
 use ratatui::prelude::*;
 use ratatui::buffer::Buffer;
 use ratatui::widgets::{Block, Borders, Gauge, Paragraph, Widget};
+use ratatui::widgets::canvas::{Canvas, Line as CanvasLine};
+use ratatui::symbols::Marker;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout};
 
 #[derive(Debug)]
@@ -14,7 +18,7 @@ pub struct GaugeCard;
 struct ViewData<'a> {
     value: f64,
     max_value: f64,
-    unit_label: &'a str,
+    value_text: String,
     display_name: &'a str,
     is_connected: bool,
     cmd_status: &'a str,
@@ -22,11 +26,18 @@ struct ViewData<'a> {
     percentage: u16,
     warn_threshold: f64,
     danger_threshold: f64,
-    gauge_color: Color,
+    status: Status,
     border_color: Color,
     connected_icon: &'a str,
+    gauge_style: &'a str,
+    start_angle: f64,
+    end_angle: f64,
+    show_ticks: bool,
 }
 
+/// Line segments used to approximate the dial arc
+const ARC_SEGMENTS: usize = 72;
+
 impl GaugeCard {
     fn get_view_data<'a>(
         &self,
@@ -36,83 +47,34 @@ impl GaugeCard {
         module: &'a mut Module
     ) -> ViewData<'a> {
         // Existing gauge value
-        let value = module
-            .config
-            .bindings
-            .get("a")  // Primary sensor value
-            .and_then(|v| v.as_f64())
-            .unwrap_or(0.0);
-
-        let max_value = module
-            .config
-            .bindings
-            .get("max_value")
-            .and_then(|v| v.as_f64())
-            .unwrap_or(100.0);
-
-        let unit_label = module
-            .config
-            .bindings
-            .get("unit_of_measure_label")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
-
-        let display_name = module
-            .config
-            .bindings
-            .get("display_name")
-            .and_then(|v| v.as_str())
-            .unwrap_or(&module.config.name);
+        let value = module.config.binding_or("a", 0.0);  // Primary sensor value
+
+        let max_value = module.config.binding_or("max_value", 100.0);
+
+        let value_text = ValueFormat::from_config(&module.config).format(value);
+
+        let display_name = module.config.binding_str("display_name").unwrap_or(&module.config.name);
 
         // Connection status
-        let is_connected = module
-            .config
-            .bindings
-            .get("is_connected")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+        let is_connected = module.config.binding_or("is_connected", false);
 
         // NEW: CMD window status
-        let cmd_status = module
-            .config
-            .bindings
-            .get("cmd_window_status")
-            .and_then(|v| v.as_str())
-            .unwrap_or("⚪ Unknown");
-
-        let device_mode = module
-            .config
-            .bindings
-            .get("device_mode")
-            .and_then(|v| v.as_str())
-            .unwrap_or("unknown");
+        let cmd_status = module.config.binding_str("cmd_window_status").unwrap_or("⚪ Unknown");
+
+        let device_mode = module.config.binding_str("device_mode").unwrap_or("unknown");
 
         // Calculate gauge percentage
         let percentage = ((value / max_value) * 100.0).clamp(0.0, 100.0) as u16;
 
         // Color based on thresholds
-        let warn_threshold = module
-            .config
-            .bindings
-            .get("warn_threshold")
-            .and_then(|v| v.as_f64())
-            .unwrap_or(70.0);
-
-        let danger_threshold = module
-            .config
-            .bindings
-            .get("danger_threshold")
-            .and_then(|v| v.as_f64())
-            .unwrap_or(85.0);
-
-        let gauge_color = if !is_connected {
-            Color::Gray
-        } else if value >= danger_threshold {
-            Color::Red
-        } else if value >= warn_threshold {
-            Color::Yellow
+        let warn_threshold = module.config.binding_or("warn_threshold", 70.0);
+
+        let danger_threshold = module.config.binding_or("danger_threshold", 85.0);
+
+        let status = if is_connected {
+            Status::from_thresholds(value, warn_threshold, danger_threshold)
         } else {
-            Color::Green
+            Status::Inactive
         };
 
         let border_color = if is_selected {
@@ -123,10 +85,20 @@ impl GaugeCard {
 
         let connected_icon = if is_connected { "🔗" } else { "⛓️‍💥" };
 
+        // Dial rendering (detail view): "bar" keeps the classic horizontal gauge
+        let gauge_style = module.config.binding_str("gauge_style").unwrap_or("bar");
+
+        // Degrees, counter-clockwise from 3 o'clock; the default is a 270° dial open at the bottom
+        let start_angle = module.config.binding_or("start_angle", 225.0);
+
+        let end_angle = module.config.binding_or("end_angle", -45.0);
+
+        let show_ticks = module.config.binding_or("show_ticks", true);
+
         ViewData {
             value,
             max_value,
-            unit_label,
+            value_text,
             display_name,
             is_connected,
             cmd_status,
@@ -134,23 +106,80 @@ impl GaugeCard {
             percentage,
             warn_threshold,
             danger_threshold,
-            gauge_color,
+            status,
             border_color,
             connected_icon,
+            gauge_style,
+            start_angle,
+            end_angle,
+            show_ticks,
         }
     }
+
+    /// Arc/needle dial on a braille canvas. Arc segments take the threshold color for
+    /// the value range they cover; "arc" dims everything past the current value,
+    /// "needle" keeps the full arc lit and points at the value.
+    fn render_dial(&self, area: Rect, buf: &mut Buffer, view: &ViewData) {
+        let max = if view.max_value > 0.0 { view.max_value } else { 100.0 };
+        let fraction = (view.value / max).clamp(0.0, 1.0);
+        let angle_at = |f: f64| (view.start_angle + (view.end_angle - view.start_angle) * f).to_radians();
+        let point = |f: f64, r: f64| (angle_at(f).cos() * r, angle_at(f).sin() * r);
+
+        let zone_color = |v: f64| {
+            if view.is_connected {
+                Status::from_thresholds(v, view.warn_threshold, view.danger_threshold).color()
+            } else {
+                Status::Inactive.color()
+            }
+        };
+
+        // Terminal cells are about twice as tall as wide; widen x so the dial stays round
+        let aspect = (area.width as f64 / (area.height.max(1) as f64 * 2.0)).max(1.0);
+        let is_needle = view.gauge_style == "needle";
+
+        let canvas = Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([-1.2 * aspect, 1.2 * aspect])
+            .y_bounds([-1.2, 1.2])
+            .paint(|ctx| {
+                for i in 0..ARC_SEGMENTS {
+                    let (f1, f2) = (i as f64 / ARC_SEGMENTS as f64, (i + 1) as f64 / ARC_SEGMENTS as f64);
+                    let color = if !is_needle && f1 >= fraction {
+                        Color::DarkGray
+                    } else {
+                        zone_color((f1 + f2) / 2.0 * max)
+                    };
+                    let ((x1, y1), (x2, y2)) = (point(f1, 1.0), point(f2, 1.0));
+                    ctx.draw(&CanvasLine::new(x1, y1, x2, y2, color));
+                }
+
+                if view.show_ticks {
+                    for threshold in [view.warn_threshold, view.danger_threshold] {
+                        let f = (threshold / max).clamp(0.0, 1.0);
+                        let ((x1, y1), (x2, y2)) = (point(f, 0.8), point(f, 1.15));
+                        ctx.draw(&CanvasLine::new(x1, y1, x2, y2, Color::White));
+                    }
+                }
+
+                if is_needle {
+                    let (x, y) = point(fraction, 0.85);
+                    ctx.draw(&CanvasLine::new(0.0, 0.0, x, y, zone_color(view.value)));
+                }
+            });
+
+        Widget::render(canvas, area, buf);
+    }
 }
 
 impl UiTemplate for GaugeCard {
     fn render_overview_cta(&self, is_selected: bool, area: Rect, buf: &mut Buffer, module: &mut Module) {
         let ViewData {
-            value,
-            unit_label,
+            value_text,
             display_name,
             cmd_status,
             device_mode,
             percentage,
-            gauge_color,
+            status,
             border_color,
             connected_icon,
             ..
@@ -175,23 +204,23 @@ impl UiTemplate for GaugeCard {
             .split(inner);
 
         // Gauge with value display
-        let gauge_label = format!("{:.1} {}", value, unit_label);
+        let gauge_label = status.mark(&value_text);
         let gauge = Gauge::default()
             .block(
                 Block::default()
                     .borders(Borders::NONE)
                     .border_style(Style::default().fg(border_color))
             )
-            .gauge_style(Style::default().fg(gauge_color))
+            .gauge_style(status.style())
             .percent(percentage)
             .label(gauge_label);
         Widget::render(gauge, chunks[1], buf);
 
         // NEW: CMD Window Status Indicator
         let cmd_color = match device_mode {
-            "cmd" => Color::Green,      // In CMD window
-            "data" => Color::Yellow,    // In DATA mode
-            _ => Color::Gray,           // Unknown
+            "cmd" => Status::Ok.color(),       // In CMD window
+            "data" => Status::Warning.color(), // In DATA mode
+            _ => Status::Inactive.color(),     // Unknown
         };
 
         let cmd_widget = Paragraph::new(cmd_status)
@@ -207,55 +236,64 @@ impl UiTemplate for GaugeCard {
     }
 
     fn render_detail(&self, area: Rect, buf: &mut Buffer, module: &mut Module) {
-        let ViewData {
-            value,
-            unit_label,
-            display_name,
-            cmd_status,
-            device_mode,
-            percentage,
-            gauge_color,
-            border_color,
-            connected_icon,
-            ..
-        } = self.get_view_data(false, area, buf, module);
+        let view = self.get_view_data(false, area, buf, module);
 
         let block = Block::default()
-            .title(format!(" {}{} ", connected_icon, display_name))
+            .title(format!(" {}{} ", view.connected_icon, view.display_name))
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(border_color));
+            .border_style(Style::default().fg(view.border_color));
 
         let inner = block.inner(area);
         Widget::render(block, area, buf);
 
+        let use_dial = matches!(view.gauge_style, "arc" | "needle") && inner.height >= 8;
+
         // Split inner area into sections
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(1),  // Label
-                Constraint::Length(1),  // Switch visual
-                Constraint::Length(3),  // Status text
-            ])
+            .constraints(if use_dial {
+                vec![
+                    Constraint::Min(4),     // Dial
+                    Constraint::Length(1),  // Value
+                    Constraint::Length(3),  // Status text
+                ]
+            } else {
+                vec![
+                    Constraint::Length(1),  // Label
+                    Constraint::Length(1),  // Switch visual
+                    Constraint::Length(3),  // Status text
+                ]
+            })
             .split(inner);
 
-        // Gauge with value display
-        let gauge_label = format!("{:.1} {}", value, unit_label);
-        let gauge = Gauge::default()
-            .block(
-                Block::default()
-                    .borders(Borders::NONE)
-                    .border_style(Style::default().fg(border_color))
-            )
-            .gauge_style(Style::default().fg(gauge_color))
-            .percent(percentage)
-            .label(gauge_label);
-        Widget::render(gauge, chunks[1], buf);
+        if use_dial {
+            self.render_dial(chunks[0], buf, &view);
+
+            let value_widget = Paragraph::new(view.status.mark(&view.value_text))
+                .style(view.status.style().add_modifier(Modifier::BOLD))
+                .alignment(Alignment::Center);
+            Widget::render(value_widget, chunks[1], buf);
+        } else {
+            // Gauge with value display
+            let gauge = Gauge::default()
+                .block(
+                    Block::default()
+                        .borders(Borders::NONE)
+                        .border_style(Style::default().fg(view.border_color))
+                )
+                .gauge_style(view.status.style())
+                .percent(view.percentage)
+                .label(view.status.mark(&view.value_text));
+            Widget::render(gauge, chunks[1], buf);
+        }
+
+        let ViewData { cmd_status, device_mode, .. } = view;
 
         // NEW: CMD Window Status Indicator
         let cmd_color = match device_mode {
-            "cmd" => Color::Green,      // In CMD window
-            "data" => Color::Yellow,    // In DATA mode
-            _ => Color::Gray,           // Unknown
+            "cmd" => Status::Ok.color(),       // In CMD window
+            "data" => Status::Warning.color(), // In DATA mode
+            _ => Status::Inactive.color(),     // Unknown
         };
 
         let cmd_widget = Paragraph::new(cmd_status)
@@ -276,7 +314,9 @@ impl UiTemplate for GaugeCard {
 
     fn docs(&self) -> &'static str {
         "Displays a gauge with value, connection status, and CMD window schedule. \
-         Shows when the device will accept commands based on its scheduled windows."
+         Shows when the device will accept commands based on its scheduled windows. \
+         Optional 'gauge_style' ('bar', 'arc', 'needle'), 'start_angle'/'end_angle' (degrees) \
+         and 'show_ticks' control the dial in the detail view."
     }
 }
 
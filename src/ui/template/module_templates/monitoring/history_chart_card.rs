@@ -1,5 +1,6 @@
 // src/ui/module_templates/monitoring/history_chart.rs
 use crate::module::Module;
+use crate::ui::palette::Status;
 use crate::ui::template::UiTemplate;
 use ratatui::prelude::*;
 use ratatui::buffer::Buffer;
@@ -10,7 +11,6 @@ use ratatui::symbols;
 pub struct HistoryChart;
 
 struct ViewData {
-    history: Vec<f64>,
     chart_title: String,
     module_name: String,
     y_label: String,
@@ -35,9 +35,7 @@ impl HistoryChart {
       // Get the history data from module bindings
       let history = module
           .config
-          .bindings
-          .get("history")
-          .and_then(|v| v.as_array())
+          .binding_array("history")
           .map(|arr| {
               arr.iter()
                   .filter_map(|v| v.as_f64())
@@ -46,37 +44,18 @@ impl HistoryChart {
           .unwrap_or_else(Vec::new);
 
       // Get optional configuration
-      let chart_title = module
-          .config
-          .bindings
-          .get("chart_title")
-          .and_then(|v| v.as_str())
-          .unwrap_or("History")
+      let chart_title = module.config.binding_str("chart_title").unwrap_or("History")
           .to_string();
 
-      let module_name = module.config.name.clone();
+      let module_name = module.config.binding_str("display_name").unwrap_or(&module.config.name).to_string();
 
-      let y_label = module
-          .config
-          .bindings
-          .get("y_label")
-          .and_then(|v| v.as_str())
-          .unwrap_or("Value")
+      let y_label = module.config.binding_str("y_label").unwrap_or("Value")
           .to_string();
 
-      let x_label = module
-          .config
-          .bindings
-          .get("x_label")
-          .and_then(|v| v.as_str())
-          .unwrap_or("Time")
+      let x_label = module.config.binding_str("x_label").unwrap_or("Time")
           .to_string();
 
-      let line_color = module
-          .config
-          .bindings
-          .get("line_color")
-          .and_then(|v| v.as_str())
+      let line_color = module.config.binding_str("line_color")
           .and_then(|s| match s.to_lowercase().as_str() {
               "red" => Some(Color::Red),
               "green" => Some(Color::Green),
@@ -97,7 +76,7 @@ impl HistoryChart {
           .collect();
 
       // Calculate bounds
-      let max_x = (history.len() - 1).max(1) as f64;
+      let max_x = history.len().saturating_sub(1).max(1) as f64;
       let min_y = history.iter().cloned().fold(f64::INFINITY, f64::min);
       let max_y = history.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
 
@@ -121,10 +100,9 @@ impl HistoryChart {
           format!("{:.1}", y_max),
       ];
 
-      let border_color = if is_selected { Color::White } else { Color::Green };
+      let border_color = if is_selected { Color::White } else { Status::Ok.color() };
 
       ViewData {
-          history,
           chart_title,
           module_name,
           y_label,
@@ -143,7 +121,6 @@ impl HistoryChart {
 impl UiTemplate for HistoryChart {
     fn render_overview_cta(&self, is_selected: bool, area: Rect, buf: &mut Buffer, module: &mut Module) {
         let ViewData {
-            history,
             module_name,
             chart_title,
             y_label,
@@ -157,16 +134,6 @@ impl UiTemplate for HistoryChart {
             border_color,
         } = self.get_view_data(is_selected, area, buf, module);
 
-        // If no data, show empty chart with message
-        if history.is_empty() {
-            let empty_block = Block::default()
-                .title(format!(" {} - No Data ", module_name))
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Gray));
-            Widget::render(empty_block, area, buf);
-            return;
-        }
-
         // Create label references from owned strings
         let x_label_refs: Vec<&str> = x_labels.iter().map(|s| s.as_str()).collect();
         let y_label_refs: Vec<&str> = y_labels.iter().map(|s| s.as_str()).collect();
@@ -210,7 +177,6 @@ impl UiTemplate for HistoryChart {
 
     fn render_detail(&self, area: Rect, buf: &mut Buffer, module: &mut Module) {
         let ViewData {
-            history,
             module_name,
             chart_title,
             y_label,
@@ -224,16 +190,6 @@ impl UiTemplate for HistoryChart {
             border_color,
         } = self.get_view_data(false, area, buf, module);
 
-        // If no data, show empty chart with message
-        if history.is_empty() {
-            let empty_block = Block::default()
-                .title(format!(" {} - No Data ", module_name))
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Gray));
-            Widget::render(empty_block, area, buf);
-            return;
-        }
-
         // Create label references from owned strings
         let x_label_refs: Vec<&str> = x_labels.iter().map(|s| s.as_str()).collect();
         let y_label_refs: Vec<&str> = y_labels.iter().map(|s| s.as_str()).collect();
@@ -279,6 +235,11 @@ impl UiTemplate for HistoryChart {
         &["history"]
     }
 
+    /// An empty `history` array counts as no data
+    fn has_data(&self, module: &Module) -> bool {
+        module.config.binding_array("history").is_some_and(|arr| !arr.is_empty())
+    }
+
     fn docs(&self) -> &'static str {
         "Line chart showing historical data over time. Required: 'history' (array of numbers). Optional: 'chart_title' (string), 'y_label' (string), 'x_label' (string), 'line_color' (red/green/blue/yellow/cyan/magenta/white)."
     }
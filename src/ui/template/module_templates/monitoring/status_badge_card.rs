@@ -1,6 +1,11 @@
 // src/ui/module_templates/monitoring/status_badge.rs
-use crate::module::Module;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::module::{Module, ModuleConfig, StatusState};
+use crate::ui::palette::Status;
 use crate::ui::template::UiTemplate;
+use crate::util::format::ValueFormat;
 use ratatui::prelude::*;
 use ratatui::buffer::Buffer;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap, Widget};
@@ -16,9 +21,9 @@ struct ViewData<'a> {
     message: &'a str,
     timestamp: &'a str,
     count: Option<String>,
-    icon: &'a str,
+    icon: String,
     color: Color,
-    status_display: &'a str,
+    status_display: String,
     border_color: Color,
 }
 
@@ -30,65 +35,31 @@ impl StatusBadge {
         buf: &mut Buffer,
         module: &'a mut Module
     ) -> ViewData<'a> {
-        let module_name = &module.config.name;
+        let module_name = module.config.binding_str("display_name").unwrap_or(&module.config.name);
 
         // Get the status from module bindings
-        let status = module
-            .config
-            .bindings
-            .get("status")
-            .and_then(|v| v.as_str())
-            .unwrap_or("unknown");
+        let status = module.config.binding_str("status").unwrap_or("unknown");
 
         // Get optional message
-        let message = module
-            .config
-            .bindings
-            .get("message")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let message = module.config.binding_str("message").unwrap_or("");
 
         // Get optional timestamp
-        let timestamp = module
-            .config
-            .bindings
-            .get("timestamp")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let timestamp = module.config.binding_str("timestamp").unwrap_or("");
 
         // Get optional count/value
-        let count = module
-            .config
-            .bindings
-            .get("count")
-            .and_then(|v| v.as_i64())
+        let count = module.config.binding_i64("count")
             .map(|n| n.to_string())
             .or_else(|| {
-                module.config.bindings
-                    .get("count")
-                    .and_then(|v| v.as_f64())
-                    .map(|n| format!("{:.1}", n))
+                module.config.binding_f64("count")
+                    .map(|n| ValueFormat::from_config(&module.config).format(n))
             });
 
-        // Determine styling based on status
-        let (icon, color, status_display) = match status.to_lowercase().as_str() {
-            "online" | "active" | "success" | "ok" | "operational" => {
-                ("✓", Color::Green, "OPERATIONAL")
-            }
-            "offline" | "inactive" | "down" | "error" | "failed" => {
-                ("✗", Color::Red, "ERROR")
-            }
-            "warning" | "degraded" | "slow" => {
-                ("⚠", Color::Yellow, "WARNING")
-            }
-            "pending" | "loading" | "starting" => {
-                ("⟳", Color::Cyan, "PENDING")
-            }
-            "maintenance" | "updating" => {
-                ("⚙", Color::Blue, "MAINTENANCE")
-            }
-            _ => {
-                ("?", Color::Gray, "UNKNOWN")
+        // A configured `states` mapping wins; otherwise style by the well-known status words
+        let (icon, color, status_display) = match Self::mapped_state(&module.config) {
+            Some(mapped) => mapped,
+            None => {
+                let (icon, color, display) = Self::default_state(status);
+                (icon.to_string(), color, display.to_string())
             }
         };
 
@@ -106,6 +77,66 @@ impl StatusBadge {
             border_color,
         }
     }
+
+    /// Look up the current reading in the `states` binding. The key is the `status`
+    /// string if set, otherwise the `a` reading (whole numbers without decimals, so `2` not `2.0`).
+    fn mapped_state(config: &ModuleConfig) -> Option<(String, Color, String)> {
+        let states: HashMap<String, StatusState> = config
+            .bindings
+            .get("states")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())?;
+
+        let key = match config.bindings.get("status") {
+            Some(serde_json::Value::String(s)) => s.clone(),
+            Some(serde_json::Value::Bool(b)) => b.to_string(),
+            Some(serde_json::Value::Number(n)) => Self::value_key(n.as_f64()?),
+            _ => Self::value_key(config.binding_f64("a")?),
+        };
+
+        let state = states
+            .get(&key)
+            .or_else(|| states.iter().find(|(k, _)| k.eq_ignore_ascii_case(&key)).map(|(_, v)| v))?;
+
+        let color = state
+            .color
+            .as_deref()
+            .and_then(|c| Color::from_str(c.trim()).ok())
+            .unwrap_or(Color::Gray);
+        let icon = state.icon.clone().unwrap_or_else(|| "●".to_string());
+
+        Some((icon, color, state.label.clone()))
+    }
+
+    fn value_key(value: f64) -> String {
+        if value.fract() == 0.0 && value.abs() < 1e15 {
+            format!("{}", value as i64)
+        } else {
+            value.to_string()
+        }
+    }
+
+    fn default_state(status: &str) -> (&'static str, Color, &'static str) {
+        match status.to_lowercase().as_str() {
+            "online" | "active" | "success" | "ok" | "operational" => {
+                ("✓", Status::Ok.color(), "OPERATIONAL")
+            }
+            "offline" | "inactive" | "down" | "error" | "failed" => {
+                ("✗", Status::Danger.color(), "ERROR")
+            }
+            "warning" | "degraded" | "slow" => {
+                ("⚠", Status::Warning.color(), "WARNING")
+            }
+            "pending" | "loading" | "starting" => {
+                ("⟳", Status::Info.color(), "PENDING")
+            }
+            "maintenance" | "updating" => {
+                ("⚙", Color::Blue, "MAINTENANCE")
+            }
+            _ => {
+                ("?", Status::Inactive.color(), "UNKNOWN")
+            }
+        }
+    }
 }
 
 impl UiTemplate for StatusBadge {
@@ -265,8 +296,13 @@ impl UiTemplate for StatusBadge {
         &["status"]
     }
 
+    /// `states` can be keyed by the `a` reading instead of `status`
+    fn has_data(&self, module: &Module) -> bool {
+        ["status", "a"].iter().any(|key| module.config.bindings.get(*key).is_some_and(|v| !v.is_null()))
+    }
+
     fn docs(&self) -> &'static str {
-        "Status badge showing system/service health. Required: 'status' (online/offline/warning/pending/maintenance/etc). Optional: 'message' (string), 'timestamp' (string), 'count' (number). Color-coded by status type."
+        "Status badge showing system/service health. Required: 'status' (online/offline/warning/pending/maintenance/etc). Optional: 'message' (string), 'timestamp' (string), 'count' (number), 'states' (map of value -> {label, color, icon}, keyed by 'status' or the 'a' reading, e.g. {\"2\": {label: FAULT, color: red}}). Color-coded by status type when no state matches."
     }
 }
 
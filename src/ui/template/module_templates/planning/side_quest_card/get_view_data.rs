@@ -15,27 +15,15 @@ impl SideQuestCard {
         buf: &mut Buffer,
         module: &'a mut Module
     ) -> ViewData<'a> {
-        let current_view = module
-            .config
-            .bindings
-            .get("current_view")
-            .and_then(|v| v.as_str())
-            .unwrap_or("QuestList");
+        let current_view = module.config.binding_str("current_view").unwrap_or("QuestList");
 
         let border_color = if is_selected { Color::White } else { Color::Magenta };
 
-        let selected_index = module
-            .config
-            .bindings
-            .get("selected_index")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(0) as usize;
+        let selected_index = module.config.binding_u64("selected_index").unwrap_or(0) as usize;
 
         let quests = module
             .config
-            .bindings
-            .get("quests")
-            .and_then(|v| v.as_array())
+            .binding_array("quests")
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str())
@@ -46,55 +34,19 @@ impl SideQuestCard {
 
         let quest_count = quests.len();
 
-        let status_message = module
-            .config
-            .bindings
-            .get("status_message")
-            .and_then(|v| v.as_str())
-            .filter(|s| !s.is_empty());
-
-        let has_status = status_message.is_some();
+        let create_step = module.config.binding_str("create_step").unwrap_or("Title");
 
-        let create_step = module
-            .config
-            .bindings
-            .get("create_step")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Title");
+        let form_title = module.config.binding_str("form_title").unwrap_or("");
 
-        let form_title = module
-            .config
-            .bindings
-            .get("form_title")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let form_description = module.config.binding_str("form_description").unwrap_or("(none)");
 
-        let form_description = module
-            .config
-            .bindings
-            .get("form_description")
-            .and_then(|v| v.as_str())
-            .unwrap_or("(none)");
+        let form_topic = module.config.binding_str("form_topic").unwrap_or("");
 
-        let form_topic = module
-            .config
-            .bindings
-            .get("form_topic")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
-
-        let form_urgency = module
-            .config
-            .bindings
-            .get("form_urgency")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let form_urgency = module.config.binding_str("form_urgency").unwrap_or("");
 
         let available_topics = module
             .config
-            .bindings
-            .get("available_topics")
-            .and_then(|v| v.as_array())
+            .binding_array("available_topics")
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str())
@@ -105,9 +57,7 @@ impl SideQuestCard {
 
         let urgency_options = module
             .config
-            .bindings
-            .get("urgency_options")
-            .and_then(|v| v.as_array())
+            .binding_array("urgency_options")
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str())
@@ -116,40 +66,15 @@ impl SideQuestCard {
             })
             .unwrap_or_default();
 
-        let selected_quest_title = module
-            .config
-            .bindings
-            .get("selected_quest_title")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Quest Details");
+        let selected_quest_title = module.config.binding_str("selected_quest_title").unwrap_or("Quest Details");
 
-        let selected_quest_description = module
-            .config
-            .bindings
-            .get("selected_quest_description")
-            .and_then(|v| v.as_str())
-            .unwrap_or("No description");
+        let selected_quest_description = module.config.binding_str("selected_quest_description").unwrap_or("No description");
 
-        let selected_quest_topic = module
-            .config
-            .bindings
-            .get("selected_quest_topic")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let selected_quest_topic = module.config.binding_str("selected_quest_topic").unwrap_or("");
 
-        let selected_quest_urgency = module
-            .config
-            .bindings
-            .get("selected_quest_urgency")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let selected_quest_urgency = module.config.binding_str("selected_quest_urgency").unwrap_or("");
 
-        let selected_quest_trigger = module
-            .config
-            .bindings
-            .get("selected_quest_trigger")
-            .and_then(|v| v.as_str())
-            .unwrap_or("No deadline");
+        let selected_quest_trigger = module.config.binding_str("selected_quest_trigger").unwrap_or("No deadline");
 
 
         ViewData {
@@ -158,8 +83,6 @@ impl SideQuestCard {
             selected_index,
             quests,
             quest_count,
-            status_message,
-            has_status,
             create_step,
             form_title,
             form_description,
@@ -22,8 +22,6 @@ struct ViewData<'a> {
     selected_index: usize,
     quests: Vec<String>,
     quest_count: usize,
-    status_message: Option<&'a str>,
-    has_status: bool,
     create_step: &'a str,
     form_title: &'a str,
     form_description: &'a str,
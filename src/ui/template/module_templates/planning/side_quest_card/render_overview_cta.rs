@@ -1,6 +1,6 @@
 use ratatui::{
     buffer::Buffer,
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::Rect,
     prelude::{Style, Widget},
     widgets::{Block, BorderType},
 };
@@ -22,9 +22,7 @@ impl SideQuestCard {
 
         let ViewData {
             border_color,
-            status_message,
             quest_count,
-            has_status,
             ..
         } = self.get_view_data(is_selected, area, buf, module);
 
@@ -39,32 +37,11 @@ impl SideQuestCard {
         let inner_area = container.inner(area);
         container.render(area, buf);
 
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(if has_status {
-                vec![
-                    Constraint::Min(1),
-                    Constraint::Length(3),
-                ]
-            } else {
-                vec![
-                    Constraint::Min(1),
-                ]
-            })
-            .split(inner_area);
-
         let overview_text = format!(
             "Manage Schedule Events & Tasks\n---\n[{}]",
             StringUtils::maybe_pluralize_count(quest_count, ("Open Item", "Open Items"))
         );
         let overview_component = UiComponent::empty_message(overview_text.as_str(), None);
-        Widget::render(overview_component, chunks[0], buf);
-
-        if has_status {
-            if let Some(status) = status_message {
-                let status_component = UiComponent::status(status);
-                Widget::render(status_component, chunks[1], buf);
-            }
-        }
+        Widget::render(overview_component, inner_area, buf);
     }
 }
@@ -22,27 +22,16 @@ impl SideQuestCard {
             selected_index,
             quests,
             quest_count,
-            status_message,
-            has_status,
             ..
         } = self.get_view_data(is_selected, area, buf, module);
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints(if has_status {
-                vec![
-                    Constraint::Length(3),  // Title
-                    Constraint::Min(1),     // Quest list
-                    Constraint::Length(3),  // Status
-                    Constraint::Length(3),  // Help
-                ]
-            } else {
-                vec![
-                    Constraint::Length(3),  // Title
-                    Constraint::Min(1),     // Quest list
-                    Constraint::Length(3),  // Help
-                ]
-            })
+            .constraints([
+                Constraint::Length(3),  // Title
+                Constraint::Min(1),     // Quest list
+                Constraint::Length(3),  // Help
+            ])
             .split(area);
 
         // Title
@@ -97,20 +86,9 @@ impl SideQuestCard {
             Widget::render(list, chunks[1], buf);
         }
 
-        // Status message if present
-        let help_index = if has_status {
-            if let Some(status) = status_message {
-                let status_component = UiComponent::status(status);
-                Widget::render(status_component, chunks[2], buf);
-            }
-            3
-        } else {
-            2
-        };
-
         // Help
         let help_text = "[n]: New | [c]: Complete | [d]: Delete | [Esc]: Back";
         let help_component = UiComponent::help(help_text);
-        Widget::render(help_component, chunks[help_index], buf);
+        Widget::render(help_component, chunks[2], buf);
     }
 }
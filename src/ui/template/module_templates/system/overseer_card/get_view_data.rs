@@ -16,48 +16,30 @@ impl OverseerCard {
         buf: &mut Buffer,
         module: &'a mut Module
     ) -> ViewData<'a> {
-        let current_view = module
-            .config
-            .bindings
-            .get("current_view")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Main");
-
-        let selected_index = module
-            .config
-            .bindings
-            .get("selected_index")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(0) as usize;
-
-        let status_message = module
-            .config
-            .bindings
-            .get("status_message")
-            .and_then(|v| v.as_str())
-            .filter(|s| !s.is_empty());
-
-        let known_count = module
-            .config
-            .bindings
-            .get("known_devices")
-            .and_then(|v| v.as_array())
-            .map(|arr| arr.len())
+        let current_view = module.config.binding_str("current_view").unwrap_or("Main");
+
+        let selected_index = module.config.binding_u64("selected_index").unwrap_or(0) as usize;
+
+        // The list binding only holds one page, so prefer the total
+        let known_count = module.config.binding_u64("known_device_total")
+            .map(|n| n as usize)
+            .or_else(|| {
+                module
+                    .config
+                    .binding_array("known_devices")
+                    .map(|arr| arr.len())
+            })
             .unwrap_or(0);
 
         let registry_count = module
             .config
-            .bindings
-            .get("module_list")
-            .and_then(|v| v.as_array())
+            .binding_array("module_list")
             .map(|arr| arr.len())
             .unwrap_or(0);
 
         let known_devices = module
             .config
-            .bindings
-            .get("known_devices")
-            .and_then(|v| v.as_array())
+            .binding_array("known_devices")
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str())
@@ -68,39 +50,23 @@ impl OverseerCard {
 
         let installed_count = module
             .config
-            .bindings
-            .get("installed_modules")
-            .and_then(|v| v.as_array())
+            .binding_array("installed_modules")
             .map(|arr| arr.len())
             .unwrap_or(0);
 
         let archived_count = module
             .config
-            .bindings
-            .get("archived_modules")
-            .and_then(|v| v.as_array())
+            .binding_array("archived_modules")
             .map(|arr| arr.len())
             .unwrap_or(0);
 
-        let is_scanning = module
-            .config
-            .bindings
-            .get("is_scanning")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+        let is_scanning = module.config.binding_or("is_scanning", false);
 
-        let scan_countdown = module
-            .config
-            .bindings
-            .get("scan_countdown")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(0) as u8;
+        let scan_countdown = module.config.binding_u64("scan_countdown").unwrap_or(0) as u8;
 
         let _pending_devices_arr = module
             .config
-            .bindings
-            .get("pending_devices")
-            .and_then(|v| v.as_array());
+            .binding_array("pending_devices");
 
         let pending_count = _pending_devices_arr
             .map(|arr| arr.len())
@@ -117,9 +83,7 @@ impl OverseerCard {
 
         let module_list = module
             .config
-            .bindings
-            .get("module_list")
-            .and_then(|v| v.as_array())
+            .binding_array("module_list")
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str())
@@ -130,9 +94,7 @@ impl OverseerCard {
 
         let installed_modules = module
             .config
-            .bindings
-            .get("installed_modules")
-            .and_then(|v| v.as_array())
+            .binding_array("installed_modules")
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str())
@@ -141,14 +103,13 @@ impl OverseerCard {
             })
             .unwrap_or_default();
 
-        let has_status = status_message.is_some();
+        let has_status = is_scanning;
 
         let border_color = if is_selected { Color::White } else { Color::Cyan };
 
         ViewData {
             current_view,
             selected_index,
-            status_message,
             border_color,
             module_list,
             installed_modules,
@@ -24,7 +24,6 @@ pub struct OverseerCard;
 struct ViewData<'a> {
     current_view: &'a str,
     selected_index: usize,
-    status_message: Option<&'a str>,
     border_color: Color,
     module_list: Vec<String>,
     installed_modules: Vec<String>,
@@ -8,6 +8,7 @@ use ratatui::{
 
 use crate::module::Module;
 use crate::ui::components::UiComponent;
+use crate::ui::palette::Status;
 use super::{ViewData, OverseerCard};
 
 impl OverseerCard {
@@ -17,10 +18,26 @@ impl OverseerCard {
         buf: &mut Buffer,
         module: &mut Module,
     ) {
+        let key_hints = module.key_hints_text().unwrap_or_default().to_string();
+        // Rename form replaces the list until saved or cancelled
+        let device_edit: Option<Vec<String>> = module
+            .config
+            .binding_array("device_edit")
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(|s| s.to_string())
+                    .collect()
+            });
+
+        let config = &module.config;
+        let device_offset = config.binding_u64("known_device_offset").unwrap_or(0) as usize;
+        let device_total = config.binding_u64("known_device_total").map(|n| n as usize);
+        let forget_prompt = config.binding::<String>("forget_prompt");
+
         let ViewData {
             border_color,
             selected_index,
-            status_message,
             has_status,
             is_scanning,
             scan_countdown,
@@ -28,6 +45,7 @@ impl OverseerCard {
             ..
         } = self.get_view_data(false, area, buf, module);
 
+        let has_status = has_status || forget_prompt.is_some();
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints(if has_status {
@@ -47,7 +65,8 @@ impl OverseerCard {
             .split(area);
 
         // Title
-        let title = Paragraph::new(format!("📡 All Known Devices ({})", known_devices.len()))
+        let device_total = device_total.unwrap_or(known_devices.len());
+        let title = Paragraph::new(format!("📡 All Known Devices ({})", device_total))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
@@ -58,30 +77,64 @@ impl OverseerCard {
         Widget::render(title, chunks[0], buf);
 
         // Device list
-        if known_devices.is_empty() {
+        if let Some(lines) = &device_edit {
+            let form_items: Vec<ListItem> = lines
+                .iter()
+                .enumerate()
+                .map(|(i, line)| {
+                    let style = if i == 0 {
+                        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                    } else if line.starts_with('▶') {
+                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::Gray)
+                    };
+                    ListItem::new(format!("  {}", line)).style(style)
+                })
+                .collect();
+
+            let form = List::new(form_items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Yellow))
+                        .title(" Edit Device ")
+                );
+            Widget::render(form, chunks[1], buf);
+        } else if known_devices.is_empty() {
             let empty_message = "No devices discovered yet.\n\nDevices will appear here when they're in range.\n\nPress '[r]' to refresh scanning.";
             let empty_message_component = UiComponent::empty_message(empty_message, Some(border_color));
             Widget::render(empty_message_component, chunks[1], buf);
         } else {
+            // Only build the rows that fit, scrolled so the selection stays visible
+            let visible_rows = (chunks[1].height.saturating_sub(2) as usize).max(1);
+            let scroll = selected_index.saturating_sub(visible_rows - 1);
+
             let list_items: Vec<ListItem> = known_devices
                 .iter()
                 .enumerate()
+                .skip(scroll)
+                .take(visible_rows)
                 .map(|(i, device)| {
                     // Parse device string format: "✓ Device Name (MAC) RSSI: -65 dBm"
                     let is_trusted = device.starts_with('✓');
                     let is_untrusted = device.starts_with('✗');
 
+                    let status = if is_trusted {
+                        Status::Ok
+                    } else if is_untrusted {
+                        Status::Danger
+                    } else {
+                        Status::Inactive
+                    };
+
                     let style = if i == selected_index {
                         Style::default()
                             .fg(Color::Black)
-                            .bg(if is_trusted { Color::Green } else { Color::Gray })
+                            .bg(if is_trusted { status.color() } else { Status::Inactive.color() })
                             .add_modifier(Modifier::BOLD)
-                    } else if is_trusted {
-                        Style::default().fg(Color::Green)
-                    } else if is_untrusted {
-                        Style::default().fg(Color::Red)
                     } else {
-                        Style::default().fg(Color::Gray)
+                        status.style()
                     };
 
                     let prefix = if i == selected_index { "▶ " } else { "  " };
@@ -94,18 +147,23 @@ impl OverseerCard {
                     Block::default()
                         .borders(Borders::ALL)
                         .border_style(Style::default().fg(border_color))
-                        .title(" All Known Devices ")
+                        .title(format!(
+                            " Devices {}-{} of {} ",
+                            device_offset + 1,
+                            device_offset + known_devices.len(),
+                            device_total
+                        ))
                 );
             Widget::render(list, chunks[1], buf);
         }
 
-        // Status message if present
+        // Scan progress or the forget confirmation
         let help_index = if has_status {
             if is_scanning {
                 let is_scanning_component = UiComponent::is_scanning(&scan_countdown);
                 Widget::render(is_scanning_component, chunks[2], buf);
-            } else if let Some(status) = status_message {
-                let status_component = UiComponent::status(status);
+            } else if let Some(prompt) = &forget_prompt {
+                let status_component = UiComponent::status(prompt);
                 Widget::render(status_component, chunks[2], buf);
             }
             3
@@ -114,8 +172,12 @@ impl OverseerCard {
         };
 
         // Help
-        let help_text = "[t] Toggle Trust  [d] Delete  [s] Scan  [p] Pending  [Esc] Back";
-        let help_component = UiComponent::help(help_text);
+        let help_text = if device_edit.is_some() {
+            format!("Type to edit  {}", key_hints)
+        } else {
+            key_hints
+        };
+        let help_component = UiComponent::help(&help_text);
         Widget::render(help_component, chunks[help_index], buf);
     }
 }
@@ -16,6 +16,7 @@ impl OverseerCard {
         buf: &mut Buffer,
         module: &mut Module,
     ) {
+        let key_hints = module.key_hints_text().unwrap_or_default().to_string();
         let ViewData {
             border_color,
             selected_index,
@@ -24,9 +25,7 @@ impl OverseerCard {
 
         let archived_modules = module
             .config
-            .bindings
-            .get("archived_modules")
-            .and_then(|v| v.as_array())
+            .binding_array("archived_modules")
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str())
@@ -35,6 +34,9 @@ impl OverseerCard {
             })
             .unwrap_or_default();
 
+        let archive_filter = module.config.binding_str("archive_filter").unwrap_or("")
+            .to_string();
+
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -45,7 +47,12 @@ impl OverseerCard {
             .split(area);
 
         // Title
-        let title = Paragraph::new(format!("📚 Archived Modules ({})", archived_modules.len()))
+        let title_text = if archive_filter.is_empty() {
+            format!("📚 Archived Modules ({})", archived_modules.len())
+        } else {
+            format!("📚 Archived Modules ({}) • filter: {}│", archived_modules.len(), archive_filter)
+        };
+        let title = Paragraph::new(title_text)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
@@ -57,7 +64,11 @@ impl OverseerCard {
 
         // Module list
         if archived_modules.is_empty() {
-            let empty_message = "No archived modules.";
+            let empty_message = if archive_filter.is_empty() {
+                "No archived modules."
+            } else {
+                "No archived modules match the filter."
+            };
             let empty_message_component = UiComponent::empty_message(empty_message, Some(border_color));
             Widget::render(empty_message_component, chunks[1], buf);
         } else {
@@ -90,8 +101,12 @@ impl OverseerCard {
         }
 
         // Help
-        let help_text = "↑/↓: Navigate • Enter: Restore Module • Esc: Back";
-        let help_component = UiComponent::help(help_text);
+        let help_text = if archive_filter.is_empty() {
+            format!("{}  Type to filter", key_hints)
+        } else {
+            key_hints
+        };
+        let help_component = UiComponent::help(&help_text);
         Widget::render(help_component, chunks[2], buf);
     }
 }
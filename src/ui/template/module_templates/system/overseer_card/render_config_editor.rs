@@ -6,6 +6,7 @@ use ratatui::{
 };
 
 use crate::module::Module;
+use crate::ui::palette::Status;
 use super::OverseerCard;
 
 impl OverseerCard {
@@ -16,40 +17,23 @@ impl OverseerCard {
         module: &mut Module,
     ) {
         // Extract editor state from bindings
-        let module_name = module.config.bindings
-            .get("editor_module_name")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Unknown");
-
-        let selected_field = module.config.bindings
-            .get("editor_selected_field")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(0) as usize;
-
-        let is_editing = module.config.bindings
-            .get("editor_is_editing")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
-
-        let fields = module.config.bindings
-            .get("editor_fields")
-            .and_then(|v| v.as_array())
-            .cloned()
+        let module_name = module.config.binding_str("editor_module_name").unwrap_or("Unknown");
+
+        let selected_field = module.config.binding_u64("editor_selected_field").unwrap_or(0) as usize;
+
+        let is_editing = module.config.binding_or("editor_is_editing", false);
+
+        let fields = module.config.binding_array("editor_fields")
+            .map(<[_]>::to_vec)
             .unwrap_or_default();
 
         let edit_buffer = if is_editing {
-            module.config.bindings
-                .get("editor_edit_buffer")
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
+            module.config.binding_str("editor_edit_buffer").unwrap_or("")
         } else {
             ""
         };
 
-        let cursor_pos = module.config.bindings
-            .get("editor_cursor_pos")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(0) as usize;
+        let cursor_pos = module.config.binding_u64("editor_cursor_pos").unwrap_or(0) as usize;
 
         // Render the editor
         let block = Block::default()
@@ -60,6 +44,36 @@ impl OverseerCard {
         let inner = block.inner(area);
         block.render(area, buf);
 
+        // Pending save: show what would change on disk instead of the fields
+        if let Some(diff) = module.config.binding_array("config_diff") {
+            buf.set_string(
+                inner.x + 1,
+                inner.y,
+                format!("Review {} change(s) before writing:", diff.len()),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            );
+
+            let max_y = inner.bottom().saturating_sub(1);
+            for (line, y) in diff.iter().filter_map(|v| v.as_str()).zip(inner.y + 2..max_y) {
+                let color = if line.contains(": + ") {
+                    Status::Ok.color()
+                } else if line.contains(": - ") {
+                    Status::Danger.color()
+                } else {
+                    Status::Warning.color()
+                };
+                buf.set_stringn(inner.x + 2, y, line, inner.width.saturating_sub(3) as usize, Style::default().fg(color));
+            }
+
+            buf.set_string(
+                area.x + 2,
+                area.bottom().saturating_sub(1),
+                "[Ent] Write changes  [Esc] Keep editing",
+                Style::default().fg(Color::DarkGray),
+            );
+            return;
+        }
+
         // Split into two columns if space permits
         let (left_area, right_area) = if inner.width > 80 {
             let chunks = Layout::default()
@@ -2,11 +2,12 @@ use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     prelude::{Color, Modifier, Style, Widget},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph},
 };
 
 use crate::module::Module;
 use crate::ui::components::UiComponent;
+use crate::ui::palette::Status;
 use super::{ViewData, OverseerCard};
 
 impl OverseerCard {
@@ -16,6 +17,13 @@ impl OverseerCard {
         buf: &mut Buffer,
         module: &mut Module,
     ) {
+        let key_hints = module.key_hints_text().unwrap_or_default().to_string();
+        let install_progress: Option<(String, f64)> = module
+            .config
+            .bindings
+            .get("install_progress")
+            .and_then(|p| Some((p.get("label")?.as_str()?.to_string(), p.get("ratio")?.as_f64()?)));
+
         let ViewData {
             border_color,
             selected_index,
@@ -25,12 +33,36 @@ impl OverseerCard {
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3),  // Title
-                Constraint::Min(1),     // Module list
-                Constraint::Length(3),  // Help
-            ])
+            .constraints(if install_progress.is_some() {
+                vec![
+                    Constraint::Length(3),  // Title
+                    Constraint::Min(1),     // Module list
+                    Constraint::Length(3),  // Install progress
+                    Constraint::Length(3),  // Help
+                ]
+            } else {
+                vec![
+                    Constraint::Length(3),  // Title
+                    Constraint::Min(1),     // Module list
+                    Constraint::Length(3),  // Help
+                ]
+            })
             .split(area);
+        let help_area = chunks[chunks.len() - 1];
+
+        if let Some((label, ratio)) = &install_progress {
+            let gauge = Gauge::default()
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Yellow))
+                        .title(" Installing ")
+                )
+                .gauge_style(Style::default().fg(Status::Ok.color()).bg(Color::Black))
+                .label(label.as_str())
+                .ratio(ratio.clamp(0.0, 1.0));
+            Widget::render(gauge, chunks[2], buf);
+        }
 
         // Title
         let title = Paragraph::new(format!("📦 Registry Modules ({})", module_list.len()))
@@ -43,6 +75,47 @@ impl OverseerCard {
             .alignment(Alignment::Center);
         Widget::render(title, chunks[0], buf);
 
+        // Capability review replaces the list until approved or cancelled
+        let install_review: Option<Vec<String>> = module
+            .config
+            .binding_array("install_review")
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(|s| s.to_string())
+                    .collect()
+            });
+
+        if let Some(lines) = install_review {
+            let review_items: Vec<ListItem> = lines
+                .iter()
+                .enumerate()
+                .map(|(i, line)| {
+                    let style = if i == 0 {
+                        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::Yellow)
+                    };
+                    ListItem::new(format!("  {}", line)).style(style)
+                })
+                .collect();
+
+            let review = List::new(review_items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Yellow))
+                        .title(" Requested capabilities ")
+                );
+            Widget::render(review, chunks[1], buf);
+
+            let help_component = UiComponent::help(&key_hints);
+            Widget::render(help_component, help_area, buf);
+            return;
+        }
+
+        let active_registry = module.config.binding_str("active_registry_label").unwrap_or("default");
+
         // Module list
         let list_items: Vec<ListItem> = module_list
             .iter()
@@ -67,13 +140,12 @@ impl OverseerCard {
                 Block::default()
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(border_color))
-                    .title(" Select module to install ")
+                    .title(format!(" Registry: {} ", active_registry))
             );
         Widget::render(list, chunks[1], buf);
 
         // Help
-        let help_text = "↑/↓: Navigate • Enter: Install • Esc: Back";
-        let help_component = UiComponent::help(help_text);
-        Widget::render(help_component, chunks[2], buf);
+        let help_component = UiComponent::help(&key_hints);
+        Widget::render(help_component, help_area, buf);
     }
 }
@@ -17,16 +17,15 @@ impl OverseerCard {
         buf: &mut Buffer,
         module: &mut Module,
     ) {
+        let key_hints = module.key_hints_text().unwrap_or_default().to_string();
         let ViewData {
             border_color,
             selected_index,
-            status_message,
             pending_count,
             known_count,
             registry_count,
             installed_count,
             archived_count,
-            is_scanning,
             scan_countdown,
             has_status,
             ..
@@ -38,6 +37,7 @@ impl OverseerCard {
             format!("📦 Install from Registry ({})", registry_count),
             format!("⚙️  Manage Installed Modules ({})", installed_count),
             format!("📚 View Archived Modules ({})", archived_count),
+            "✨ Create New Module".to_string(),
             "← Back".to_string(),
         ];
 
@@ -85,23 +85,17 @@ impl OverseerCard {
             );
         Widget::render(list, chunks[0], buf);
 
-        // Status message if present
+        // Scan progress if scanning
         let help_index = if has_status {
-            if is_scanning {
-                let is_scanning_component = UiComponent::is_scanning(&scan_countdown);
-                Widget::render(is_scanning_component, chunks[2], buf);
-            } else if let Some(status) = status_message {
-                let status_component = UiComponent::status(status);
-                Widget::render(status_component, chunks[1], buf);
-            }
+            let is_scanning_component = UiComponent::is_scanning(&scan_countdown);
+            Widget::render(is_scanning_component, chunks[1], buf);
             2
         } else {
             1
         };
 
         // Help
-        let help_text = "[s] Scan  [r] Refresh  [Esc] Back";
-        let help_component = UiComponent::help(help_text);
+        let help_component = UiComponent::help(&key_hints);
         Widget::render(help_component, chunks[help_index], buf);
     }
 }
@@ -7,6 +7,7 @@ use ratatui::{
 
 use crate::module::Module;
 use crate::ui::components::UiComponent;
+use crate::ui::palette::Status;
 use super::{ViewData, OverseerCard};
 
 impl OverseerCard {
@@ -16,6 +17,27 @@ impl OverseerCard {
         buf: &mut Buffer,
         module: &mut Module,
     ) {
+        let key_hints = module.key_hints_text().unwrap_or_default().to_string();
+        // Module name -> "installed → available" for modules with a newer registry version
+        let module_updates = module
+            .config
+            .bindings
+            .get("module_updates")
+            .and_then(|v| v.as_object())
+            .cloned()
+            .unwrap_or_default();
+
+        // Merge conflicts of a staged update replace the list until applied or cancelled
+        let update_review: Option<Vec<String>> = module
+            .config
+            .binding_array("update_review")
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(|s| s.to_string())
+                    .collect()
+            });
+
         let ViewData {
             border_color,
             selected_index,
@@ -43,6 +65,36 @@ impl OverseerCard {
             .alignment(Alignment::Center);
         Widget::render(title, chunks[0], buf);
 
+        if let Some(lines) = update_review {
+            let review_items: Vec<ListItem> = lines
+                .iter()
+                .enumerate()
+                .map(|(i, line)| {
+                    let style = if i == 0 {
+                        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                    } else if line.starts_with('▶') {
+                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::Gray)
+                    };
+                    ListItem::new(format!("  {}", line)).style(style)
+                })
+                .collect();
+
+            let review = List::new(review_items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Yellow))
+                        .title(" Update conflicts ")
+                );
+            Widget::render(review, chunks[1], buf);
+
+            let help_component = UiComponent::help(&key_hints);
+            Widget::render(help_component, chunks[2], buf);
+            return;
+        }
+
         // Module list
         let list_items: Vec<ListItem> = installed_modules
             .iter()
@@ -58,7 +110,13 @@ impl OverseerCard {
                 };
 
                 let prefix = if i == selected_index { "▶ " } else { "  " };
-                ListItem::new(format!("{}{}", prefix, item)).style(style)
+                match module_updates.get(item).and_then(|v| v.as_str()) {
+                    Some(update) => {
+                        let style = if i == selected_index { style } else { Status::Ok.style() };
+                        ListItem::new(format!("{}{}  ⬆ {}", prefix, item, update)).style(style)
+                    }
+                    None => ListItem::new(format!("{}{}", prefix, item)).style(style),
+                }
             })
             .collect();
 
@@ -72,8 +130,7 @@ impl OverseerCard {
         Widget::render(list, chunks[1], buf);
 
         // Help
-        let help_text = "↑/↓: Navigate • 'a': Archive Module • Esc: Back";
-        let help_component = UiComponent::help(help_text);
+        let help_component = UiComponent::help(&key_hints);
         Widget::render(help_component, chunks[2], buf);
     }
 }
@@ -23,12 +23,10 @@ impl OverseerCard {
 
         let ViewData {
             border_color,
-            status_message,
             is_scanning,
             scan_countdown,
             known_count,
             installed_count,
-            has_status,
             ..
         } = self.get_view_data(is_selected, area, buf, module);
 
@@ -45,7 +43,7 @@ impl OverseerCard {
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints(if has_status || is_scanning {
+            .constraints(if is_scanning {
                 vec![
                     Constraint::Min(1),
                     Constraint::Length(3),
@@ -68,11 +66,6 @@ impl OverseerCard {
         if is_scanning {
             let is_scanning_component = UiComponent::is_scanning(&scan_countdown);
             Widget::render(is_scanning_component, chunks[1], buf);
-        } else if has_status {
-            if let Some(status) = status_message {
-                let status_component = UiComponent::status(status);
-                Widget::render(status_component, chunks[1], buf);
-            }
         }
     }
 }
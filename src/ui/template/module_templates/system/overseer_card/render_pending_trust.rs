@@ -16,17 +16,17 @@ impl OverseerCard {
         buf: &mut Buffer,
         module: &mut Module,
     ) {
+        let key_hints = module.key_hints_text().unwrap_or_default().to_string();
         let ViewData {
             border_color,
             selected_index,
-            status_message,
             is_scanning,
             pending_devices,
             scan_countdown,
             ..
         } = self.get_view_data(false, area, buf, module);
 
-        let has_status = status_message.is_some() || is_scanning;
+        let has_status = is_scanning;
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints(if has_status {
@@ -90,23 +90,17 @@ impl OverseerCard {
             Widget::render(list, chunks[1], buf);
         }
 
-        // Status message if present
+        // Scan progress if scanning
         let help_index = if has_status {
-            if is_scanning {
-                let is_scanning_component = UiComponent::is_scanning(&scan_countdown);
-                Widget::render(is_scanning_component, chunks[2], buf);
-            } else if let Some(status) = status_message {
-                let status_component = UiComponent::status(status);
-                Widget::render(status_component, chunks[2], buf);
-            }
+            let is_scanning_component = UiComponent::is_scanning(&scan_countdown);
+            Widget::render(is_scanning_component, chunks[2], buf);
             3
         } else {
             2
         };
 
         // Help
-        let help_text = "[Ent] Trust  [i] Ignore  [s] Scan  [v] View All  [Esc] Back";
-        let help_component = UiComponent::help(help_text);
+        let help_component = UiComponent::help(&key_hints);
         Widget::render(help_component, chunks[help_index], buf);
     }
 }
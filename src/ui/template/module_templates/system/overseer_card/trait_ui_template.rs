@@ -23,7 +23,7 @@ impl UiTemplate for OverseerCard {
             "InstallRegistry" => self.render_install_registry(area, buf, module),
             "ManageModules" => self.render_manage_modules(area, buf, module),
             "ArchivedModules" => self.render_archived_modules(area, buf, module),
-            "EditConfig" => self.render_config_editor(area, buf, module),
+            "EditConfig" | "CreateNewModule" => self.render_config_editor(area, buf, module),
             _ => self.render_main_menu(is_selected, area, buf, module),
         }
     }
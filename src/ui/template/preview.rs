@@ -0,0 +1,80 @@
+// src/ui/template/preview.rs
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use color_eyre::{eyre::eyre, Result};
+use ratatui::prelude::*;
+
+use crate::module::{Module, ModuleConfig, ModuleRenderState};
+use crate::ui::template::get_template;
+
+/// Size used by `render_template_preview`, roughly a detail pane on an 80x24 terminal
+pub const PREVIEW_WIDTH: u16 = 60;
+pub const PREVIEW_HEIGHT: u16 = 20;
+
+/// Render `template_name`'s detail view with `bindings` into an off-screen buffer,
+/// without a terminal, handlers or a manifest on disk
+pub fn render_template_preview(
+    template_name: &str,
+    bindings: HashMap<String, serde_json::Value>,
+) -> Result<Buffer> {
+    render_template_preview_sized(template_name, bindings, PREVIEW_WIDTH, PREVIEW_HEIGHT)
+}
+
+pub fn render_template_preview_sized(
+    template_name: &str,
+    bindings: HashMap<String, serde_json::Value>,
+    width: u16,
+    height: u16,
+) -> Result<Buffer> {
+    let template = get_template(template_name)
+        .ok_or_else(|| eyre!("Unknown template: {}", template_name))?;
+
+    let mut module = preview_module(template_name, bindings)?;
+    let area = Rect::new(0, 0, width, height);
+    let mut buf = Buffer::empty(area);
+
+    template.render_detail(area, &mut buf, &mut module);
+
+    Ok(buf)
+}
+
+fn preview_module(template_name: &str, bindings: HashMap<String, serde_json::Value>) -> Result<Module> {
+    let name = bindings
+        .get("display_name")
+        .and_then(|v| v.as_str())
+        .unwrap_or(template_name)
+        .to_string();
+
+    let config: ModuleConfig = serde_json::from_value(serde_json::json!({
+        "name": name,
+        "module_type": "preview",
+        "bus_topic": "preview",
+        "template": template_name,
+        "bindings": bindings,
+    }))?;
+
+    Ok(Module {
+        config,
+        path: PathBuf::from("./preview"),
+        cached_template: None,
+        render_state: ModuleRenderState::default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renders_status_badge_off_screen() {
+        let bindings = HashMap::from([
+            ("status".to_string(), serde_json::json!("online")),
+        ]);
+        let buf = render_template_preview("status_badge_card", bindings).unwrap();
+
+        let text: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("OPERATIONAL"));
+        assert!(render_template_preview("no_such_card", HashMap::new()).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+// src/ui/text_export.rs
+//! What the current view says, as plain text: module names, readings, states and the keys that work.
+//! Unlike a buffer snapshot it is built from bindings, so screen readers and scripts get meaning, not cells.
+
+use std::path::{Path, PathBuf};
+
+use crate::app::{App, AppMode, ModuleSource, OverviewFocus};
+use crate::module::{key_hints::format_key_hints, ModuleManager};
+use crate::runtime::{dry_run::is_dry_run, safe_mode::is_safe_mode};
+use crate::util::dirs::data_dirs;
+
+/// What Ctrl+E names the view in the exports directory, unless `VIEW_EXPORT_PATH` says otherwise
+const DEFAULT_EXPORT_FILE: &str = "survon-view.txt";
+
+impl App {
+    /// The focused screen as text: a title line, the visible modules with their state, then the keys
+    pub fn view_as_text(&self) -> String {
+        let mut sections = Vec::new();
+        let title = match (is_safe_mode(), is_dry_run()) {
+            (true, _) => "Survon (SAFE MODE)",
+            (false, true) => "Survon (DRY RUN)",
+            (false, false) => "Survon",
+        };
+
+        match &self.mode {
+            AppMode::Splash | AppMode::Loading => sections.push(format!("{} - starting", title)),
+            AppMode::Overview => {
+                let focus = match self.overview_focus {
+                    OverviewFocus::None => "nothing",
+                    OverviewFocus::WastelandModules => "Wasteland Modules",
+                    OverviewFocus::Messages => "Messages",
+                    OverviewFocus::CoreModules => "Core Modules",
+                    OverviewFocus::Jukebox => "Jukebox",
+                };
+                sections.push(format!("{} - Overview, focus on {}", title, focus));
+                sections.push(modules_as_text("Wasteland Modules", &self.wasteland_module_manager, true));
+                sections.push(modules_as_text("Core Modules", &self.core_module_manager, true));
+            }
+            AppMode::ModuleDetail(source, module_idx) => {
+                let module_manager = match source {
+                    ModuleSource::Wasteland => &self.wasteland_module_manager,
+                    ModuleSource::Core => &self.core_module_manager,
+                };
+                sections.push(format!("{} - Module Detail", title));
+                if let Some(module) = module_manager.get_modules().get(*module_idx) {
+                    sections.push(module.text_summary());
+                }
+            }
+        }
+
+        let keys: Vec<String> = self
+            .key_help_sections()
+            .iter()
+            .filter(|section| !section.hints.is_empty())
+            .map(|section| format!("  {}: {}", section.title, format_key_hints(&section.hints)))
+            .collect();
+        if !keys.is_empty() {
+            sections.push(format!("Keys\n{}", keys.join("\n")));
+        }
+
+        sections.join("\n\n")
+    }
+
+    /// Write [`Self::view_as_text`] to `VIEW_EXPORT_PATH`, or the exports directory, returning where it went
+    pub fn export_view_text(&self) -> std::io::Result<PathBuf> {
+        let path = match std::env::var_os("VIEW_EXPORT_PATH") {
+            Some(path) => PathBuf::from(path),
+            None => {
+                let exports = data_dirs().exports;
+                std::fs::create_dir_all(&exports)?;
+                exports.join(DEFAULT_EXPORT_FILE)
+            }
+        };
+        std::fs::write(&path, self.view_as_text() + "\n")?;
+        Ok(path)
+    }
+}
+
+/// One line per displayable module in dashboard order, `>` marking the selection when `mark_selected`
+fn modules_as_text(title: &str, module_manager: &ModuleManager, mark_selected: bool) -> String {
+    let indices = module_manager.get_displayable_indices();
+    let mut lines = vec![format!("{} ({})", title, indices.len())];
+
+    for idx in indices {
+        let module = &module_manager.get_modules()[idx];
+        let marker = if mark_selected && idx == module_manager.selected_module { "> " } else { "  " };
+        for (i, line) in module.text_summary().lines().enumerate() {
+            lines.push(format!("{}{}", if i == 0 { marker } else { "  " }, line));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// `survon status --text`: every module's configured state, read from the manifests without starting the TUI.
+/// Live readings need the running app (Ctrl+E); here bindings are as written in each `config.yml`.
+pub fn status_text(core_manifests: &Path, wasteland_manifests: &Path) -> color_eyre::Result<String> {
+    let mut sections = vec!["Survon status".to_string()];
+
+    for (title, path, namespace) in [
+        ("Core Modules", core_manifests, "core"),
+        ("Wasteland Modules", wasteland_manifests, "wasteland"),
+    ] {
+        let mut module_manager = ModuleManager::new(path.to_path_buf(), namespace.to_string());
+        module_manager.discover_modules()?;
+        sections.push(modules_as_text(title, &module_manager, false));
+    }
+
+    Ok(sections.join("\n\n"))
+}
@@ -0,0 +1,55 @@
+// src/ui/toasts.rs
+//! Stacked toasts in the bottom-right corner, newest nearest the bottom
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, BorderType, Clear, Paragraph, Widget},
+};
+
+use crate::runtime::toast::{Toast, ToastLevel};
+
+const TOAST_HEIGHT: u16 = 3;
+const MAX_TOAST_WIDTH: u16 = 60;
+/// Keeps toasts clear of the overview's controls footer
+const BOTTOM_MARGIN: u16 = 3;
+
+pub fn render_toasts(toasts: &[Toast], area: Rect, buf: &mut Buffer) {
+    let mut bottom = area.bottom().saturating_sub(BOTTOM_MARGIN);
+
+    for toast in toasts.iter().rev() {
+        if bottom < area.y + TOAST_HEIGHT {
+            break;
+        }
+
+        let width = (toast.message.chars().count() as u16 + 4)
+            .clamp(20, MAX_TOAST_WIDTH)
+            .min(area.width);
+        let rect = Rect {
+            x: area.right() - width,
+            y: bottom - TOAST_HEIGHT,
+            width,
+            height: TOAST_HEIGHT,
+        };
+
+        let color = match toast.level {
+            ToastLevel::Info => Color::Cyan,
+            ToastLevel::Success => Color::Green,
+            ToastLevel::Warning => Color::Yellow,
+            ToastLevel::Error => Color::Red,
+        };
+
+        Clear.render(rect, buf);
+        Paragraph::new(toast.message.as_str())
+            .block(
+                Block::bordered()
+                    .border_type(BorderType::Rounded)
+                    .style(Style::default().fg(color)),
+            )
+            .style(Style::default().fg(Color::White))
+            .render(rect, buf);
+
+        bottom -= TOAST_HEIGHT;
+    }
+}
@@ -0,0 +1,112 @@
+// src/ui/ui_state.rs
+//! Dashboard preferences that survive restarts (stored in `module_state` under [`UI_STATE_KEY`]).
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    log_error,
+    ui::palette::Palette,
+    util::database::Database,
+};
+
+/// `module_state` row holding the serialized [`UiState`]
+const UI_STATE_KEY: &str = "__ui_state";
+
+/// How tightly the overview packs module cards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Density {
+    /// More, smaller cards - suits 7" panels
+    Compact,
+    #[default]
+    Expanded,
+}
+
+impl Density {
+    /// Config default from `OVERVIEW_DENSITY` (`compact` or `expanded`)
+    pub fn from_env() -> Self {
+        match std::env::var("OVERVIEW_DENSITY").unwrap_or_default().to_lowercase().as_str() {
+            "compact" => Density::Compact,
+            _ => Density::Expanded,
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            Density::Compact => Density::Expanded,
+            Density::Expanded => Density::Compact,
+        }
+    }
+
+    pub fn modules_per_row(self) -> usize {
+        match self {
+            Density::Compact => 4,
+            Density::Expanded => 3,
+        }
+    }
+
+    /// Height of one row of module cards
+    pub fn card_height(self) -> u16 {
+        match self {
+            Density::Compact => 6,
+            Density::Expanded => 8,
+        }
+    }
+
+    /// Height of the overview header (title + jukebox)
+    pub fn header_height(self) -> u16 {
+        match self {
+            Density::Compact => 7,
+            Density::Expanded => 10,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Density::Compact => "Compact",
+            Density::Expanded => "Expanded",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UiState {
+    #[serde(default)]
+    pub density: Density,
+    #[serde(default)]
+    pub palette: Palette,
+}
+
+impl UiState {
+    /// Saved state, or config defaults when nothing has been saved yet
+    pub fn load(database: &Database) -> Self {
+        match database.get_module_state(UI_STATE_KEY) {
+            Ok(Some(json)) => serde_json::from_str(&json).unwrap_or_else(|e| {
+                log_error!("Ignoring unreadable UI state: {}", e);
+                Self::from_env()
+            }),
+            Ok(None) => Self::from_env(),
+            Err(e) => {
+                log_error!("Failed to load UI state: {}", e);
+                Self::from_env()
+            }
+        }
+    }
+
+    pub fn save(&self, database: &Database) {
+        let result = serde_json::to_string(self)
+            .map_err(|e| e.to_string())
+            .and_then(|json| database.save_module_state(UI_STATE_KEY, &json).map_err(|e| e.to_string()));
+
+        if let Err(e) = result {
+            log_error!("Failed to save UI state: {}", e);
+        }
+    }
+
+    fn from_env() -> Self {
+        Self {
+            density: Density::from_env(),
+            palette: Palette::from_env(),
+        }
+    }
+}
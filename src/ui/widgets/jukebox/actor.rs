@@ -1,6 +1,8 @@
 use super::state::{JukeboxState, JukeboxIntent, JukeboxEvent, JukeboxStateMachine};
 use crate::util::io::bus::{BusMessage, MessageBus};
 use crate::util::audio::SurvonAudioPlayer;
+use crate::module::LastError;
+use crate::log_error;
 use tokio::sync::mpsc;
 use std::time::Duration;
 
@@ -12,6 +14,9 @@ pub struct JukeboxActor {
 
     // Side-effect handler (isolated!)
     audio_player: Option<SurvonAudioPlayer>,
+
+    /// Why the last track couldn't play, shown on the jukebox card
+    last_error: LastError,
 }
 
 impl JukeboxActor {
@@ -24,11 +29,17 @@ impl JukeboxActor {
             intent_rx,
             intent_tx: intent_tx.clone(),
             audio_player: None,
+            last_error: LastError::default(),
         };
 
         (actor, intent_tx)
     }
 
+    /// Shares the actor's playback failures, for the widget to show
+    pub fn last_error(&self) -> LastError {
+        self.last_error.clone()
+    }
+
     /// Run the actor event loop
     pub async fn run(mut self) {
         while let Some(intent) = self.intent_rx.recv().await {
@@ -46,16 +57,17 @@ impl JukeboxActor {
         // Update internal state
         self.state = new_state;
 
-        // Handle side effects (audio playback)
-        self.handle_side_effects(&events).await;
+        // Handle side effects (audio playback), which may add a playback error
+        let failures = self.handle_side_effects(&events).await;
 
         // Publish events to message bus
-        for event in events {
+        for event in events.into_iter().chain(failures) {
             self.publish_event(event).await;
         }
     }
 
-    async fn handle_side_effects(&mut self, events: &[JukeboxEvent]) {
+    async fn handle_side_effects(&mut self, events: &[JukeboxEvent]) -> Vec<JukeboxEvent> {
+        let mut failures = Vec::new();
         for event in events {
             match event {
                 JukeboxEvent::TrackStarted { track, .. } => {
@@ -70,11 +82,20 @@ impl JukeboxActor {
                         self.state.volume,
                     );
 
-                    if let Ok(_) = player.play() {
-                        self.audio_player = Some(player);
-
-                        // Spawn task to detect track end
-                        self.spawn_playback_monitor();
+                    match player.play() {
+                        Ok(_) => {
+                            self.last_error.clear();
+                            self.audio_player = Some(player);
+
+                            // Spawn task to detect track end
+                            self.spawn_playback_monitor();
+                        }
+                        Err(e) if player.has_device() => {
+                            log_error!("Failed to play {}: {}", track.file_path, e);
+                            self.last_error.set(e.clone());
+                            failures.push(JukeboxEvent::PlaybackError { track: track.clone(), error: e });
+                        }
+                        Err(_) => {}
                     }
                 }
 
@@ -93,6 +114,7 @@ impl JukeboxActor {
                 _ => {}
             }
         }
+        failures
     }
 
     fn spawn_playback_monitor(&self) {
@@ -41,7 +41,7 @@ pub trait JukeboxDatabase {
 
 impl JukeboxDatabase for Database {
     fn init_jukebox_schema(&self) -> Result<()> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
 
         conn.execute(
             "CREATE TABLE IF NOT EXISTS albums (
@@ -74,7 +74,7 @@ impl JukeboxDatabase for Database {
     }
 
     fn insert_album(&self, album: &Album) -> Result<i64> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         conn.execute(
             "INSERT INTO albums (module_name, title, artist, year, genre, credits)
              VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
@@ -91,7 +91,7 @@ impl JukeboxDatabase for Database {
     }
 
     fn get_all_albums(&self) -> Result<Vec<Album>> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         let mut stmt = conn.prepare(
             "SELECT id, module_name, title, artist, year, genre, credits
              FROM albums ORDER BY artist, title"
@@ -113,14 +113,14 @@ impl JukeboxDatabase for Database {
     }
 
     fn clear_albums(&self) -> Result<()> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         conn.execute("DELETE FROM tracks", [])?;
         conn.execute("DELETE FROM albums", [])?;
         Ok(())
     }
 
     fn insert_track(&self, track: &Track) -> Result<()> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         conn.execute(
             "INSERT INTO tracks (album_id, track_number, title, duration_seconds, file_path, artist)
              VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
@@ -137,7 +137,7 @@ impl JukeboxDatabase for Database {
     }
 
     fn get_tracks_for_album(&self, album_id: i64) -> Result<Vec<Track>> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         let mut stmt = conn.prepare(
             "SELECT id, album_id, track_number, title, duration_seconds, file_path, artist
              FROM tracks WHERE album_id = ?1 ORDER BY track_number"
@@ -6,6 +6,7 @@ use std::hash::{Hash, Hasher};
 use color_eyre::Result;
 use crate::module::{Module, ModuleManager};
 use crate::util::database::Database;
+use crate::util::dirs::data_dirs;
 use super::database::{JukeboxDatabase, Album, Track};
 use crate::{log_info, log_warn, log_debug};
 
@@ -61,27 +62,11 @@ impl<'a> JukeboxIngester<'a> {
         log_info!("  🎀 Processing: {}", config.name);
 
         // Extract album metadata from bindings
-        let title = config.bindings.get("title")
-            .and_then(|v| v.as_str())
-            .unwrap_or(&config.name)
-            .to_string();
-
-        let artist = config.bindings.get("artist")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Unknown Artist")
-            .to_string();
-
-        let year = config.bindings.get("year")
-            .and_then(|v| v.as_i64())
-            .map(|y| y as i32);
-
-        let genre = config.bindings.get("genre")
-            .and_then(|v| v.as_str())
-            .map(String::from);
-
-        let credits = config.bindings.get("credits")
-            .and_then(|v| v.as_str())
-            .map(String::from);
+        let title = config.binding_str("title").unwrap_or(&config.name).to_string();
+        let artist = config.binding_or("artist", "Unknown Artist").to_string();
+        let year = config.binding_i64("year").map(|y| y as i32);
+        let genre = config.binding::<String>("genre");
+        let credits = config.binding::<String>("credits");
 
         // Insert album
         let album = Album {
@@ -97,7 +82,7 @@ impl<'a> JukeboxIngester<'a> {
         let album_id = self.database.insert_album(&album)?;
 
         // Process tracklist
-        let track_count = if let Some(tracklist) = config.bindings.get("tracklist").and_then(|v| v.as_array()) {
+        let track_count = if let Some(tracklist) = config.binding_array("tracklist") {
             let mut count = 0;
 
             for (idx, track_value) in tracklist.iter().enumerate() {
@@ -170,8 +155,8 @@ impl<'a> JukeboxIngester<'a> {
         let mut hasher = DefaultHasher::new();
 
         // Hash all album module directories
-        for manifests_dir in ["./manifests/core", "./manifests/wasteland"] {
-            let path = std::path::PathBuf::from(manifests_dir);
+        let dirs = data_dirs();
+        for path in [dirs.core_modules, dirs.modules] {
             if !path.exists() {
                 continue;
             }
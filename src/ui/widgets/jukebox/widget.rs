@@ -15,6 +15,9 @@ use crate::util::{
     database::Database,
     io::bus::{MessageBus,BusMessage}
 };
+use crate::module::LastError;
+use crate::runtime::reduced_motion::is_reduced_motion;
+use crate::ui::palette::Status;
 use crate::ui::style::dim_unless_focused;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -48,6 +51,9 @@ pub struct JukeboxWidget {
 
     // Database for loading albums/tracks
     database: Database,
+
+    /// Playback failures recorded by the actor
+    last_error: LastError,
 }
 
 impl JukeboxWidget {
@@ -73,6 +79,7 @@ impl JukeboxWidget {
             eq_bars: [0; 16],
             eq_frame: 0,
             database,
+            last_error: LastError::default(),
         };
 
         widget.refresh_albums();
@@ -80,6 +87,12 @@ impl JukeboxWidget {
         Ok(widget)
     }
 
+    /// Show the failures `last_error` records, typically [`JukeboxActor::last_error`](super::actor::JukeboxActor::last_error)
+    pub fn with_last_error(mut self, last_error: LastError) -> Self {
+        self.last_error = last_error;
+        self
+    }
+
     fn refresh_albums(&mut self) {
         if let Ok(albums) = self.database.get_all_albums() {
             self.albums = albums;
@@ -209,6 +222,12 @@ impl JukeboxWidget {
 
         self.eq_frame = (self.eq_frame + 1) % 60;
 
+        // Steady mid-level bars while playing, flat when stopped
+        if is_reduced_motion() {
+            self.eq_bars = [if self.current_state.is_playing { 3 } else { 0 }; 16];
+            return;
+        }
+
         if self.current_state.is_playing {
             let mut rng = rand::thread_rng();
             for bar in &mut self.eq_bars {
@@ -257,9 +276,9 @@ impl JukeboxWidget {
             // Check if the file actually exists
             if !std::path::Path::new(&track.file_path).exists() {
                 vec![
-                    Line::from("♪ Audio Missing").fg(Color::Red),
+                    Line::from("♪ Audio Missing").fg(Status::Danger.color()),
                     Line::from(format!("  Track: {}", track.title)).fg(Color::Gray),
-                    Line::from("  Download audio files to play").fg(Color::Yellow),
+                    Line::from("  Download audio files to play").fg(Status::Warning.color()),
                 ]
             } else {
                 // Build lines conditionally based on what we have
@@ -283,6 +302,9 @@ impl JukeboxWidget {
                 }
 
                 lines.push(self.get_eq_visualizer());
+                if let Some((_, error)) = self.last_error.get() {
+                    lines.push(Line::from(format!("⚠ {}", error)).fg(Status::Danger.color()));
+                }
                 lines
             }
         } else {
@@ -333,11 +355,11 @@ impl JukeboxWidget {
         // Determine color based on average level
         let avg_level = self.eq_bars.iter().sum::<usize>() as f32 / self.eq_bars.len() as f32;
         let color = if avg_level > 5.0 {
-            Color::Red
+            Status::Danger.color()
         } else if avg_level > 3.0 {
-            Color::Yellow
+            Status::Warning.color()
         } else if avg_level > 0.0 {
-            Color::Green
+            Status::Ok.color()
         } else {
             Color::DarkGray
         };
@@ -406,7 +428,7 @@ impl JukeboxWidget {
                 let file_exists = std::path::Path::new(&track.file_path).exists();
 
                 let style = if !file_exists {
-                    Style::default().fg(Color::Red)
+                    Status::Danger.style()
                 } else if is_selected {
                     Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
                 } else {
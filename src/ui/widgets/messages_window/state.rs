@@ -5,8 +5,22 @@ use crate::util::io::bus::BusMessage;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessagesState {
     pub messages: Vec<BusMessage>,
+    /// Lines scrolled back from the newest message; 0 is live
     pub scroll_offset: usize,
     pub max_messages: usize,
+    /// Rows the panel can show, reported by the widget on render
+    #[serde(default = "default_visible_lines")]
+    pub visible_lines: usize,
+    /// Index into `messages` of the highlighted message
+    #[serde(default)]
+    pub selected: Option<usize>,
+    /// Show the selected message in full instead of the list
+    #[serde(default)]
+    pub expanded: bool,
+}
+
+fn default_visible_lines() -> usize {
+    10
 }
 
 impl Default for MessagesState {
@@ -15,10 +29,41 @@ impl Default for MessagesState {
             messages: Vec::new(),
             scroll_offset: 0,
             max_messages: 100,
+            visible_lines: default_visible_lines(),
+            selected: None,
+            expanded: false,
         }
     }
 }
 
+impl MessagesState {
+    /// Furthest the view can scroll back while still filling the panel
+    pub fn max_scroll(&self) -> usize {
+        self.messages.len().saturating_sub(self.visible_lines.max(1))
+    }
+
+    /// `[start, end)` of `messages` currently on screen
+    pub fn visible_range(&self) -> (usize, usize) {
+        let end = self.messages.len().saturating_sub(self.scroll_offset.min(self.max_scroll()));
+        let start = end.saturating_sub(self.visible_lines.max(1));
+        (start, end)
+    }
+
+    /// Adjust the scroll so the selected message is on screen
+    fn scroll_to_selected(&mut self) {
+        let Some(selected) = self.selected else { return };
+        let (start, end) = self.visible_range();
+        let total = self.messages.len();
+
+        if selected < start {
+            self.scroll_offset = total.saturating_sub(selected + self.visible_lines.max(1));
+        } else if selected >= end {
+            self.scroll_offset = total.saturating_sub(selected + 1);
+        }
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll());
+    }
+}
+
 // ----------------------------------------------------------------------------
 // INTENT - Messages that express what user wants to do
 // ----------------------------------------------------------------------------
@@ -31,6 +76,10 @@ pub enum MessagesIntent {
     ScrollToBottom,
     AddMessage(BusMessage),
     SetVisibleLines(usize),
+    /// Select the newest visible message, or open the selected one
+    Activate,
+    /// Close the open message, or clear the selection
+    Dismiss,
 }
 
 // ----------------------------------------------------------------------------
@@ -64,50 +113,118 @@ impl MessagesStateMachine {
             ScrollToBottom => Self::handle_scroll_to_bottom(state),
             AddMessage(msg) => Self::handle_add_message(state, msg),
             SetVisibleLines(lines) => Self::handle_set_visible_lines(state, lines),
+            Activate => Self::handle_activate(state),
+            Dismiss => Self::handle_dismiss(state),
         }
     }
 
+    fn changed(state: MessagesState) -> (MessagesState, Vec<MessagesEvent>) {
+        (state.clone(), vec![
+            MessagesEvent::Scrolled { offset: state.scroll_offset },
+            MessagesEvent::StateChanged(state),
+        ])
+    }
+
+    /// Toward older messages: moves the selection if there is one, otherwise the view
     fn handle_scroll_up(mut state: MessagesState) -> (MessagesState, Vec<MessagesEvent>) {
-        if state.scroll_offset > 0 {
-            state.scroll_offset -= 1;
-            (state.clone(), vec![
-                MessagesEvent::Scrolled { offset: state.scroll_offset },
-                MessagesEvent::StateChanged(state),
-            ])
-        } else {
-            (state, vec![])
+        if state.expanded {
+            return (state, vec![]);
+        }
+
+        match state.selected {
+            Some(selected) if selected > 0 => {
+                state.selected = Some(selected - 1);
+                state.scroll_to_selected();
+                Self::changed(state)
+            }
+            Some(_) => (state, vec![]),
+            None if state.scroll_offset < state.max_scroll() => {
+                state.scroll_offset += 1;
+                Self::changed(state)
+            }
+            None => (state, vec![]),
         }
     }
 
+    /// Toward newer messages
     fn handle_scroll_down(mut state: MessagesState) -> (MessagesState, Vec<MessagesEvent>) {
-        // Note: max scroll is calculated based on visible_lines which isn't in state
-        // We'll need to pass this contextually or store it
-        state.scroll_offset += 1;
-        (state.clone(), vec![
-            MessagesEvent::Scrolled { offset: state.scroll_offset },
-            MessagesEvent::StateChanged(state),
-        ])
+        if state.expanded {
+            return (state, vec![]);
+        }
+
+        match state.selected {
+            Some(selected) if selected + 1 < state.messages.len() => {
+                state.selected = Some(selected + 1);
+                state.scroll_to_selected();
+                Self::changed(state)
+            }
+            Some(_) => (state, vec![]),
+            None if state.scroll_offset > 0 => {
+                state.scroll_offset = state.scroll_offset.min(state.max_scroll()).saturating_sub(1);
+                Self::changed(state)
+            }
+            None => (state, vec![]),
+        }
     }
 
     fn handle_scroll_to_bottom(mut state: MessagesState) -> (MessagesState, Vec<MessagesEvent>) {
-        // This will be clamped by the widget based on visible lines
-        state.scroll_offset = state.messages.len();
-        (state.clone(), vec![
-            MessagesEvent::Scrolled { offset: state.scroll_offset },
-            MessagesEvent::StateChanged(state),
-        ])
+        state.scroll_offset = 0;
+        if state.selected.is_some() {
+            state.selected = state.messages.len().checked_sub(1);
+        }
+        Self::changed(state)
+    }
+
+    fn handle_activate(mut state: MessagesState) -> (MessagesState, Vec<MessagesEvent>) {
+        match state.selected {
+            Some(_) => state.expanded = !state.expanded,
+            None => {
+                let (_, end) = state.visible_range();
+                state.selected = end.checked_sub(1);
+            }
+        }
+        Self::changed(state)
+    }
+
+    fn handle_dismiss(mut state: MessagesState) -> (MessagesState, Vec<MessagesEvent>) {
+        if state.expanded {
+            state.expanded = false;
+        } else if state.selected.is_some() {
+            state.selected = None;
+        } else {
+            return (state, vec![]);
+        }
+        Self::changed(state)
     }
 
     fn handle_add_message(mut state: MessagesState, message: BusMessage) -> (MessagesState, Vec<MessagesEvent>) {
-        state.messages.push(message.clone());
+        // Messages arrive from several receivers, so keep the list in publish order
+        let position = state.messages
+            .partition_point(|existing| existing.order_key() <= message.order_key());
+        state.messages.insert(position, message.clone());
+
+        // Keep a scrolled-back view and the selection on the same messages
+        if state.scroll_offset > 0 {
+            state.scroll_offset += 1;
+        }
+        if let Some(selected) = state.selected.as_mut() {
+            if position <= *selected {
+                *selected += 1;
+            }
+        }
 
         // Trim old messages
         if state.messages.len() > state.max_messages {
             state.messages.remove(0);
-            if state.scroll_offset > 0 {
-                state.scroll_offset -= 1;
-            }
+            state.selected = match state.selected {
+                Some(0) => {
+                    state.expanded = false;
+                    None
+                }
+                other => other.map(|s| s - 1),
+            };
         }
+        state.scroll_offset = state.scroll_offset.min(state.max_scroll());
 
         let total = state.messages.len();
 
@@ -117,8 +234,63 @@ impl MessagesStateMachine {
         ])
     }
 
-    fn handle_set_visible_lines(state: MessagesState, _lines: usize) -> (MessagesState, Vec<MessagesEvent>) {
-        // This doesn't change state, just used for rendering calculations
-        (state, vec![])
+    fn handle_set_visible_lines(mut state: MessagesState, lines: usize) -> (MessagesState, Vec<MessagesEvent>) {
+        if state.visible_lines == lines {
+            return (state, vec![]);
+        }
+
+        state.visible_lines = lines;
+        state.scroll_offset = state.scroll_offset.min(state.max_scroll());
+        (state.clone(), vec![MessagesEvent::StateChanged(state)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with(count: usize, visible_lines: usize) -> MessagesState {
+        let mut state = MessagesState { visible_lines, ..Default::default() };
+        for i in 0..count {
+            let mut msg = BusMessage::new("t".to_string(), i.to_string(), "test".to_string());
+            msg.sequence = i as u64;
+            state = MessagesStateMachine::transition(state, MessagesIntent::AddMessage(msg)).0;
+        }
+        state
+    }
+
+    #[test]
+    fn test_scroll_clamps_at_both_ends() {
+        let mut state = state_with(5, 3);
+        for _ in 0..10 {
+            state = MessagesStateMachine::transition(state, MessagesIntent::ScrollUp).0;
+        }
+        assert_eq!(state.scroll_offset, 2);
+        assert_eq!(state.visible_range(), (0, 3));
+
+        for _ in 0..10 {
+            state = MessagesStateMachine::transition(state, MessagesIntent::ScrollDown).0;
+        }
+        assert_eq!(state.scroll_offset, 0);
+        assert_eq!(state.visible_range(), (2, 5));
+    }
+
+    #[test]
+    fn test_selection_follows_scroll_and_expands() {
+        let mut state = state_with(5, 3);
+        state = MessagesStateMachine::transition(state, MessagesIntent::Activate).0;
+        assert_eq!(state.selected, Some(4));
+
+        for _ in 0..4 {
+            state = MessagesStateMachine::transition(state, MessagesIntent::ScrollUp).0;
+        }
+        assert_eq!(state.selected, Some(0));
+        assert_eq!(state.visible_range(), (0, 3));
+
+        state = MessagesStateMachine::transition(state, MessagesIntent::Activate).0;
+        assert!(state.expanded);
+        state = MessagesStateMachine::transition(state, MessagesIntent::Dismiss).0;
+        state = MessagesStateMachine::transition(state, MessagesIntent::Dismiss).0;
+        assert_eq!((state.selected, state.expanded), (None, false));
     }
 }
@@ -2,8 +2,8 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style, Stylize},
-    text::Text,
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span, Text},
     widgets::{Block, BorderType, Paragraph, Widget, Wrap},
 };
 use tokio::sync::mpsc;
@@ -94,9 +94,19 @@ impl MessagesWidget {
         let _ = self.intent_tx.send(MessagesIntent::ScrollToBottom);
     }
 
-    fn is_at_bottom(&self) -> bool {
-        let max_scroll = self.current_state.messages.len().saturating_sub(self.visible_lines);
-        self.current_state.scroll_offset >= max_scroll
+    /// Select the newest visible message, or open/close the selected one
+    pub fn activate(&self) {
+        let _ = self.intent_tx.send(MessagesIntent::Activate);
+    }
+
+    /// Close the open message or clear the selection. False when there was
+    /// nothing to dismiss, so the key can fall through to global handling.
+    pub fn dismiss(&self) -> bool {
+        if self.current_state.selected.is_none() {
+            return false;
+        }
+        let _ = self.intent_tx.send(MessagesIntent::Dismiss);
+        true
     }
 
     pub fn render(&mut self, area: Rect, buf: &mut Buffer, is_focused: Option<bool>) {
@@ -107,60 +117,60 @@ impl MessagesWidget {
         self.visible_lines = (area.height.saturating_sub(2)) as usize;
         let _ = self.intent_tx.send(MessagesIntent::SetVisibleLines(self.visible_lines));
 
-        // Clamp scroll offset to valid range
-        let max_scroll = self.current_state.messages.len().saturating_sub(self.visible_lines);
-        let clamped_offset = self.current_state.scroll_offset.min(max_scroll);
+        let text_style = dim_unless_focused(is_focused, Style::default().fg(Color::White));
+        let border_style = dim_unless_focused(is_focused, Style::default().fg(Color::Yellow));
+
+        let state = &self.current_state;
+        let selected = state.selected.and_then(|i| state.messages.get(i));
+
+        if let (true, Some(msg)) = (state.expanded, selected) {
+            self.render_expanded(msg, area, buf, text_style, border_style);
+            return;
+        }
+
+        // Same clamping as the state machine, in case the panel resized since the last update
+        let mut state = state.clone();
+        state.visible_lines = self.visible_lines;
+        let (start, end) = state.visible_range();
+        let total = state.messages.len();
 
-        let content = if self.current_state.messages.is_empty() {
+        let content = if state.messages.is_empty() {
             Text::from("Message bus activity will appear here...\n\nWaiting for messages...\nPress Tab to focus this panel, then ↑/↓ to scroll")
         } else {
-            let total = self.current_state.messages.len();
-            let end = total.saturating_sub(clamped_offset);
-            let start = end.saturating_sub(self.visible_lines);
-
-            let lines: Vec<String> = self.current_state.messages[start..end]
+            // One row per message (no wrapping) so the visible count stays exact
+            let lines: Vec<Line> = state.messages[start..end]
                 .iter()
-                .map(|msg| {
-                    // Special formatting for scheduler events
-                    if msg.topic == "scheduler_event" {
-                        format_scheduler_event(msg)
+                .enumerate()
+                .map(|(i, msg)| {
+                    let text = format_message_line(msg);
+                    if state.selected == Some(start + i) {
+                        Line::from(Span::styled(
+                            text,
+                            Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD),
+                        ))
                     } else {
-                        format!(
-                            "[{}] {}: {}",
-                            msg.source,
-                            msg.topic,
-                            msg.payload
-                        )
+                        Line::from(text)
                     }
                 })
                 .collect();
 
-            Text::from(lines.join("\n"))
+            Text::from(lines)
         };
 
-        let title = if self.current_state.messages.is_empty() {
+        let title = if state.messages.is_empty() {
             " Message Bus ".to_string()
+        } else if end == total {
+            format!(" Message Bus ({}/{}) [LIVE] ", end - start, total)
         } else {
-            let total = self.current_state.messages.len();
-            let end = total.saturating_sub(clamped_offset);
-            let start = end.saturating_sub(self.visible_lines);
-
-            if clamped_offset == 0 {
-                format!(" Message Bus ({}/{}) [LIVE] ", total.min(self.visible_lines), total)
-            } else {
-                format!(
-                    " Message Bus ({}-{}/{}) → {} ",
-                    start + 1,
-                    end,
-                    total,
-                    clamped_offset
-                )
-            }
+            format!(
+                " Message Bus ({}-{}/{}) → {} ",
+                start + 1,
+                end,
+                total,
+                total - end
+            )
         };
 
-        let text_style = dim_unless_focused(is_focused, Style::default().fg(Color::White));
-        let border_style = dim_unless_focused(is_focused, Style::default().fg(Color::Yellow));
-
         let messages_widget = Paragraph::new(content)
             .block(
                 Block::bordered()
@@ -168,11 +178,57 @@ impl MessagesWidget {
                     .border_type(BorderType::Rounded)
                     .style(border_style)
             )
-            .style(text_style)
-            .wrap(Wrap { trim: true });
+            .style(text_style);
 
         messages_widget.render(area, buf);
     }
+
+    /// A single message in full: metadata, then the payload (pretty-printed when it's JSON)
+    fn render_expanded(&self, msg: &BusMessage, area: Rect, buf: &mut Buffer, text_style: Style, border_style: Style) {
+        let payload = serde_json::from_str::<serde_json::Value>(&msg.payload)
+            .ok()
+            .and_then(|v| serde_json::to_string_pretty(&v).ok())
+            .unwrap_or_else(|| msg.payload.clone());
+
+        let label = Style::default().fg(Color::DarkGray);
+        let mut lines = vec![
+            Line::from(vec![Span::styled("Topic:    ", label), Span::raw(msg.topic.clone())]),
+            Line::from(vec![Span::styled("Source:   ", label), Span::raw(msg.source.clone())]),
+            Line::from(vec![
+                Span::styled("Time:     ", label),
+                Span::raw(msg.timestamp.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S%.3f").to_string()),
+            ]),
+            Line::from(vec![Span::styled("Sequence: ", label), Span::raw(msg.sequence.to_string())]),
+            Line::from(""),
+        ];
+        lines.extend(payload.lines().map(|l| Line::from(l.to_string())));
+
+        let detail = Paragraph::new(Text::from(lines))
+            .block(
+                Block::bordered()
+                    .title(" Message [Enter/Esc] Close ")
+                    .border_type(BorderType::Rounded)
+                    .style(border_style)
+            )
+            .style(text_style)
+            .wrap(Wrap { trim: false });
+
+        detail.render(area, buf);
+    }
+}
+
+fn format_message_line(msg: &BusMessage) -> String {
+    // Special formatting for scheduler events
+    if msg.topic == "scheduler_event" {
+        format_scheduler_event(msg)
+    } else {
+        format!(
+            "[{}] {}: {}",
+            msg.source,
+            msg.topic,
+            msg.payload
+        )
+    }
 }
 
 /// Format scheduler events with nice icons and structure
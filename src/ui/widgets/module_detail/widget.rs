@@ -3,23 +3,35 @@ use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Stylize},
+    text::Line,
     widgets::{Block, BorderType, Paragraph, Widget},
 };
 use ratatui::prelude::Style;
-use crate::module::ModuleManager;
+use chrono::Utc;
+use crate::module::{last_updated::format_age, ModuleManager};
+use crate::ui::palette::Status;
 use crate::ui::style::dim_unless_focused;
+use crate::ui::ui_state::Density;
 
 #[derive(Debug)]
 pub struct ModuleDetailWidget {
     // No state machine needed - purely presentational
+    density: Density,
 }
 
 impl ModuleDetailWidget {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            density: Density::default(),
+        }
     }
 
-    /// Renders the title and help sections (the "chrome" around the template content)
+    pub fn set_density(&mut self, density: Density) {
+        self.density = density;
+    }
+
+    /// Renders the title and help sections (the "chrome" around the template content),
+    /// with the last error and how long ago the data was updated along the bottom border
     pub fn render_chrome(
         &self,
         module_manager: &ModuleManager,
@@ -47,13 +59,30 @@ impl ModuleDetailWidget {
             _ => "🤷🏻‍♂️️",
         };
 
-        let title = format!(" {} {} - Press [Esc] To Close Module Window ", icon, module.config.name);
+        let title = match self.density {
+            Density::Compact => format!(" {} {} ", icon, module.config.name),
+            Density::Expanded => format!(" {} {} - Press [Esc] To Close Module Window ", icon, module.config.name),
+        };
 
-        let container = Block::bordered()
+        let mut container = Block::bordered()
             .title(title)
             .style(border_style)
             .border_type(BorderType::Rounded);
 
+        if let Some(error) = module.last_error_message() {
+            container = container.title_bottom(
+                Line::from(format!(" ⚠ {} ", error)).style(Status::Danger.style())
+            );
+        }
+
+        if let Some(at) = module.last_updated() {
+            container = container.title_bottom(
+                Line::from(format!(" updated {} ", format_age(Utc::now() - at)))
+                    .right_aligned()
+                    .style(Style::default().fg(Color::DarkGray))
+            );
+        }
+
         let response = container.clone();
 
         container.render(area, buf);
@@ -63,6 +92,11 @@ impl ModuleDetailWidget {
 
     /// Returns the content area rect for template rendering
     pub fn get_content_area(&self, area: Rect) -> Rect {
+        // Compact mode gives the help rows back to the template
+        if self.density == Density::Compact {
+            return area;
+        }
+
         let main_layout = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -10,19 +10,27 @@ use std::time::Duration;
 
 use crate::log_error;
 use crate::module::{Module, ModuleManager};
+use crate::runtime::template_eviction::template_evict_rows;
+use crate::ui::palette::Status;
 use crate::ui::style::dim_unless_focused;
-
-const MODULES_PER_ROW: usize = 3;
+use crate::ui::ui_state::Density;
 
 #[derive(Debug)]
 pub struct ModulesListWidget {
     // No state machine needed - this widget is purely presentational
     // It displays ModuleManager's state and forwards interactions
+    density: Density,
 }
 
 impl ModulesListWidget {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            density: Density::default(),
+        }
+    }
+
+    pub fn set_density(&mut self, density: Density) {
+        self.density = density;
     }
 
     pub fn render(
@@ -54,25 +62,45 @@ impl ModulesListWidget {
                         .border_type(BorderType::Rounded)
                         .style(border_style)
                 )
-                .fg(Color::Red)
+                .fg(Status::Danger.color())
                 .alignment(Alignment::Center)
                 .wrap(Wrap { trim: true });
             empty_msg.render(area, buf);
             return;
         }
 
+        // Build a mapping: displayable_idx -> actual_module_idx (in pinned/sort_order order)
+        let displayable_to_actual = module_manager.get_displayable_indices();
+
+        // Calculate grid layout, scrolled so the selected module's row is on screen
+        let modules_per_row = self.density.modules_per_row();
+        let num_rows = (displayable_count + modules_per_row - 1) / modules_per_row;
+        let card_height = self.density.card_height();
+        let visible_rows = (area.height.saturating_sub(2) / card_height.max(1)).max(1) as usize;
+        let selected_row = displayable_to_actual
+            .iter()
+            .position(|&idx| idx == selected_idx)
+            .map(|position| position / modules_per_row);
+        let first_row = scroll_to_row(module_manager.first_visible_row, selected_row, visible_rows, num_rows);
+        module_manager.first_visible_row = first_row;
+        let shown_rows = first_row..num_rows.min(first_row + visible_rows);
+
+        let rows_note = if num_rows > visible_rows {
+            format!("rows {}-{}/{} ", shown_rows.start + 1, shown_rows.end, num_rows)
+        } else {
+            String::new()
+        };
+
         // Create main container
         let container = Block::bordered()
-            .title(format!(" {}Modules ({}/{} 👁️) ", title_namespace_prefix, displayable_count, modules_count))
+            .title(format!(" {}Modules ({}/{} 👁️) {}", title_namespace_prefix, displayable_count, modules_count, rows_note))
             .style(border_style)
             .border_type(BorderType::Rounded);
         let inner_area = container.inner(area);
         container.render(area, buf);
 
-        // Calculate grid layout
-        let num_rows = (displayable_count + MODULES_PER_ROW - 1) / MODULES_PER_ROW;
-        let row_constraints: Vec<Constraint> = (0..num_rows)
-            .map(|_| Constraint::Length(8))
+        let row_constraints: Vec<Constraint> = shown_rows.clone()
+            .map(|_| Constraint::Length(card_height))
             .collect();
 
         let rows = Layout::default()
@@ -82,25 +110,34 @@ impl ModulesListWidget {
 
         let blink_interval = Duration::from_millis(500);
 
-        // Build a mapping: displayable_idx -> actual_module_idx
-        let mut displayable_to_actual: Vec<usize> = Vec::new();
-        for (actual_idx, module) in module_manager.get_modules().iter().enumerate() {
-            if !module.config.template.is_empty() {
-                displayable_to_actual.push(actual_idx);
+        // Cards out of view never instantiate a template; far enough out, they give theirs back
+        if let Some(evict_rows) = template_evict_rows() {
+            let modules = module_manager.get_modules_mut();
+            for (displayable_idx, &actual_module_idx) in displayable_to_actual.iter().enumerate() {
+                let row_idx = displayable_idx / modules_per_row;
+                let distance = if row_idx < shown_rows.start {
+                    shown_rows.start - row_idx
+                } else {
+                    (row_idx + 1).saturating_sub(shown_rows.end)
+                };
+                if distance > evict_rows {
+                    if let Some(module) = modules.get_mut(actual_module_idx) {
+                        module.cached_template = None;
+                    }
+                }
             }
         }
 
-        // Render each row
-        for (row_idx, row) in rows.iter().enumerate() {
-            let start_idx = row_idx * MODULES_PER_ROW;
-            let end_idx = (start_idx + MODULES_PER_ROW).min(displayable_count);
+        // Render each visible row
+        for (row, row_idx) in rows.iter().zip(shown_rows) {
+            let start_idx = row_idx * modules_per_row;
 
             let cols = Layout::default()
                 .direction(Direction::Horizontal)
-                .constraints(vec![Constraint::Percentage(100 / 3); 3])
+                .constraints(vec![Constraint::Ratio(1, modules_per_row as u32); modules_per_row])
                 .split(*row);
 
-            for col_idx in 0..3 {
+            for col_idx in 0..modules_per_row {
                 let displayable_idx = start_idx + col_idx;
                 if displayable_idx >= displayable_count { break; }
 
@@ -143,10 +180,13 @@ impl ModulesListWidget {
     fn render_module_box(&self, module: &mut Module, is_selected: bool, area: Rect, buf: &mut Buffer) {
         // If module has a template, render it directly
         if ModuleManager::is_displayable_module(module) {
-            if let Err(e) = module.render_overview_cta(is_selected, area, buf) {
-                // If template fails, fall back to metadata view
-                log_error!("Template render failed: {}", e);
-                self.render_metadata_card(module, is_selected, area, buf);
+            match module.render_overview_cta(is_selected, area, buf) {
+                Ok(()) => self.render_icon_badge(module, area, buf),
+                Err(e) => {
+                    // If template fails, fall back to metadata view
+                    log_error!("Template render failed: {}", e);
+                    self.render_metadata_card(module, is_selected, area, buf);
+                }
             }
             return;
         }
@@ -155,21 +195,34 @@ impl ModulesListWidget {
         self.render_metadata_card(module, is_selected, area, buf);
     }
 
+    /// Templates own their title row, so the icon goes in the card's bottom-left border
+    fn render_icon_badge(&self, module: &Module, area: Rect, buf: &mut Buffer) {
+        if area.width < 6 || area.height < 2 {
+            return;
+        }
+
+        let style = module.config.accent_color()
+            .map(|color| Style::default().fg(color))
+            .unwrap_or_default();
+
+        buf.set_string(
+            area.x + 1,
+            area.y + area.height - 1,
+            format!(" {} ", module.config.display_icon()),
+            style,
+        );
+    }
+
     fn render_metadata_card(&self, module: &Module, is_selected: bool, area: Rect, buf: &mut Buffer) {
+        let accent = module.config.accent_color();
+
         let border_style = if is_selected {
             Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Color::Gray)
+            Style::default().fg(accent.unwrap_or(Color::Gray))
         };
 
-        let icon = match module.config.module_type.as_str() {
-            "com" => "🔌",
-            "entertainment" => "🎮",
-            "knowledge" => "📚",
-            "llm" => "🤖",
-            "monitoring" => "📊",
-            _ => "⚙️",
-        };
+        let icon = module.config.display_icon();
 
         let block = Block::bordered()
             .border_type(if is_selected { BorderType::Double } else { BorderType::Rounded })
@@ -195,11 +248,11 @@ impl ModulesListWidget {
         let title_style = if is_selected {
             Style::default().add_modifier(Modifier::BOLD).fg(Color::White)
         } else {
-            Style::default()
+            accent.map(|color| Style::default().fg(color)).unwrap_or_default()
         };
 
         let title_line = Line::from(vec![
-            Span::styled(format!("{} ", icon), Style::default()),
+            Span::styled(format!("{} ", icon), accent.map(|color| Style::default().fg(color)).unwrap_or_default()),
             Span::styled(&module.config.name, title_style),
         ]);
         Paragraph::new(title_line)
@@ -239,3 +292,34 @@ impl ModulesListWidget {
         }
     }
 }
+
+/// The first row to show: `first_row` unless that leaves the selected row off screen, and never
+/// so far down that the grid ends above the bottom of the viewport
+fn scroll_to_row(first_row: usize, selected_row: Option<usize>, visible_rows: usize, num_rows: usize) -> usize {
+    let mut first_row = first_row;
+    if let Some(selected_row) = selected_row {
+        if selected_row < first_row {
+            first_row = selected_row;
+        } else if selected_row >= first_row + visible_rows {
+            first_row = selected_row + 1 - visible_rows;
+        }
+    }
+    first_row.min(num_rows.saturating_sub(visible_rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_follows_selection() {
+        // Ten rows, three on screen
+        assert_eq!(scroll_to_row(0, Some(1), 3, 10), 0);
+        assert_eq!(scroll_to_row(0, Some(5), 3, 10), 3);
+        assert_eq!(scroll_to_row(3, Some(4), 3, 10), 3);
+        assert_eq!(scroll_to_row(3, Some(2), 3, 10), 2);
+        // Modules removed underneath the old position
+        assert_eq!(scroll_to_row(8, None, 3, 4), 1);
+        assert_eq!(scroll_to_row(2, Some(0), 3, 2), 0);
+    }
+}
@@ -3,9 +3,38 @@ use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::BufReader;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::thread;
-use crate::log_error;
+use std::time::Duration;
+use crate::{log_error, log_warn};
+
+const NO_AUDIO_DEVICE: &str = "No audio output device";
+const DEFAULT_RAMP: Duration = Duration::from_millis(20);
+/// Volume steps per fade-out; fade-ins are sample-accurate via `Source::fade_in`
+const RAMP_STEPS: u32 = 10;
+
+/// Fade length applied when playback starts and stops, to avoid clicks.
+/// Set `AUDIO_RAMP_MS` to override (0 disables).
+pub fn audio_ramp() -> Duration {
+    std::env::var("AUDIO_RAMP_MS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_RAMP)
+}
+
+/// Whether the default output device can be opened. Probed once per process;
+/// headless boxes log a single warning and get a no-op player from then on.
+pub fn audio_device_available() -> bool {
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+    *AVAILABLE.get_or_init(|| match OutputStream::try_default() {
+        Ok(_) => true,
+        Err(e) => {
+            log_warn!("🔇 {} ({}); audio playback disabled", NO_AUDIO_DEVICE, e);
+            false
+        }
+    })
+}
 
 pub trait AudioPlayer {
     fn play(&mut self, path: &str, repeat: bool) -> Result<(), String>;
@@ -16,6 +45,7 @@ pub trait AudioPlayer {
 
 struct AudioJackPlayer {
     volume: f32,
+    ramp: Duration,
     active_sinks: Arc<Mutex<HashMap<String, Arc<Sink>>>>,
 }
 
@@ -23,22 +53,47 @@ impl AudioJackPlayer {
     pub fn new(volume: f32) -> Self {
         Self {
             volume: volume.clamp(0.0, 1.0),
+            ramp: audio_ramp(),
             active_sinks: Arc::new(Mutex::new(HashMap::new())),
         }
     }
+
+    /// Ramp `sink` down to silence and stop it, off the caller's thread
+    fn fade_out_and_stop(sink: Arc<Sink>, ramp: Duration) {
+        if ramp.is_zero() {
+            sink.stop();
+            return;
+        }
+
+        thread::spawn(move || {
+            let start = sink.volume();
+            let step = ramp / RAMP_STEPS;
+            for i in (0..RAMP_STEPS).rev() {
+                sink.set_volume(start * i as f32 / RAMP_STEPS as f32);
+                thread::sleep(step);
+            }
+            sink.stop();
+        });
+    }
 }
 
 impl AudioPlayer for AudioJackPlayer {
     fn play(&mut self, path: &str, repeat: bool) -> Result<(), String> {
         let path = path.to_string();
         let volume = self.volume;
+        let ramp = self.ramp;
         let sinks = Arc::clone(&self.active_sinks);
 
         // Kill prior play of same path
         if let Some(old) = sinks.lock().unwrap().remove(&path) {
-            old.stop();
+            Self::fade_out_and_stop(old, ramp);
         }
 
+        // Opened and decoded here so a bad file is reported to the caller, not just logged
+        let file = File::open(&path).map_err(|e| format!("Failed to open audio file {}: {}", path, e))?;
+        let source = Decoder::new(BufReader::new(file))
+            .map_err(|e| format!("Failed to decode audio file {}: {}", path, e))?;
+
         let sinks_clone = Arc::clone(&sinks);
         thread::spawn(move || {
             let (_stream, handle) = match OutputStream::try_default() {
@@ -59,33 +114,23 @@ impl AudioPlayer for AudioJackPlayer {
 
             sink.set_volume(volume);
 
-            let file = match File::open(&path) {
-                Ok(f) => f,
-                Err(e) => {
-                    log_error!("Failed to open audio file {}: {}", path, e);
-                    return;
-                }
-            };
-
-            let source = match Decoder::new(BufReader::new(file)) {
-                Ok(s) => s,
-                Err(e) => {
-                    log_error!("Failed to decode audio file {}: {}", path, e);
-                    return;
-                }
-            };
-
+            // Fade in once, not on every repeat
             if repeat {
-                sink.append(source.repeat_infinite());
+                sink.append(source.repeat_infinite().fade_in(ramp));
             } else {
-                sink.append(source);
+                sink.append(source.fade_in(ramp));
             }
 
             let sink_arc = Arc::new(sink);
             sinks_clone.lock().unwrap().insert(path.clone(), sink_arc.clone());
 
             sink_arc.sleep_until_end();
-            sinks_clone.lock().unwrap().remove(&path);
+
+            // A replay of the same path may have taken our slot while we faded out
+            let mut sinks = sinks_clone.lock().unwrap();
+            if sinks.get(&path).is_some_and(|current| Arc::ptr_eq(current, &sink_arc)) {
+                sinks.remove(&path);
+            }
         });
 
         Ok(())
@@ -93,7 +138,7 @@ impl AudioPlayer for AudioJackPlayer {
 
     fn stop(&mut self, path: &str) -> Result<(), String> {
         if let Some(sink) = self.active_sinks.lock().unwrap().remove(path) {
-            sink.stop();
+            Self::fade_out_and_stop(sink, self.ramp);
             Ok(())
         } else {
             Err("No playback active".to_string())
@@ -126,25 +171,47 @@ impl AudioPlayer for GpioPwmPlayer {
     fn is_finished(&self, _: &str) -> bool { true }
 }
 
+/// Stands in for the audio jack when there is nothing to play through
+struct NoAudioPlayer;
+impl AudioPlayer for NoAudioPlayer {
+    fn play(&mut self, _: &str, _: bool) -> Result<(), String> { Err(NO_AUDIO_DEVICE.into()) }
+    fn stop(&mut self, _: &str) -> Result<(), String> { Err(NO_AUDIO_DEVICE.into()) }
+    fn set_volume(&mut self, _: f32) {}
+    fn is_finished(&self, _: &str) -> bool { true }
+}
+
 #[derive(Clone)]
 pub struct SurvonAudioPlayer {
     inner: Arc<Mutex<dyn AudioPlayer + Send>>,
     path: String,
+    has_device: bool,
 }
 
 impl std::fmt::Debug for SurvonAudioPlayer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SurvonAudioPlayer")
             .field("path", &self.path)
+            .field("has_device", &self.has_device)
             .finish_non_exhaustive()
     }
 }
 
 impl SurvonAudioPlayer {
+    /// Plays through the default output device, or is a no-op player whose
+    /// `play` returns a "no audio device" error when there isn't one
     pub fn new_with_audio_jack(path: &str, volume: f32) -> Self {
+        if !audio_device_available() {
+            return Self {
+                inner: Arc::new(Mutex::new(NoAudioPlayer)),
+                path: path.to_string(),
+                has_device: false,
+            };
+        }
+
         Self {
             inner: Arc::new(Mutex::new(AudioJackPlayer::new(volume))),
             path: path.to_string(),
+            has_device: true,
         }
     }
 
@@ -152,9 +219,15 @@ impl SurvonAudioPlayer {
         Self {
             inner: Arc::new(Mutex::new(GpioPwmPlayer)),
             path: path.to_string(),
+            has_device: true,
         }
     }
 
+    /// False when built without an output device; playback calls are no-ops
+    pub fn has_device(&self) -> bool {
+        self.has_device
+    }
+
     pub fn play(&mut self) -> Result<(), String> {
         self.inner.lock().unwrap().play(&self.path, false)
     }
@@ -176,3 +249,19 @@ impl SurvonAudioPlayer {
         self.inner.lock().unwrap().is_finished(&self.path)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undecodable_file_is_reported_to_the_caller() {
+        let path = std::env::temp_dir().join(format!("survon_not_audio_{}.mp3", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "not audio").unwrap();
+
+        let error = AudioJackPlayer::new(0.5).play(path.to_str().unwrap(), false).unwrap_err();
+        assert!(error.starts_with("Failed to decode audio file"), "{}", error);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
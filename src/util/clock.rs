@@ -0,0 +1,107 @@
+// src/util/clock.rs
+use chrono::{DateTime, Utc};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Source of "now" for time-dependent logic (blinking, connection timeouts,
+/// deadlines). Everything defaults to `SystemClock`; tests swap in a `MockClock`.
+pub trait Clock: Send + Sync + Debug {
+    fn now(&self) -> Instant;
+    fn utc_now(&self) -> DateTime<Utc>;
+
+    fn elapsed_since(&self, earlier: Instant) -> Duration {
+        self.now().saturating_duration_since(earlier)
+    }
+}
+
+pub type SharedClock = Arc<dyn Clock>;
+
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn utc_now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Frozen at creation; only moves when `advance` is called.
+/// Clones share the same time, so a test can keep one and hand another to a handler.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    start: Instant,
+    start_utc: DateTime<Utc>,
+    offset: Arc<Mutex<Duration>>,
+}
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self::starting_at(Utc::now())
+    }
+
+    pub fn starting_at(start_utc: DateTime<Utc>) -> Self {
+        Self {
+            start: Instant::now(),
+            start_utc,
+            offset: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        *self.offset.lock().unwrap() += by;
+    }
+
+    pub fn shared(&self) -> SharedClock {
+        Arc::new(self.clone())
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.start + *self.offset.lock().unwrap()
+    }
+
+    fn utc_now(&self) -> DateTime<Utc> {
+        let offset = *self.offset.lock().unwrap();
+        self.start_utc + chrono::Duration::from_std(offset).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::ModuleRenderState;
+
+    #[test]
+    fn test_mock_clock_drives_blinking() {
+        let clock = MockClock::new();
+        let mut state = ModuleRenderState::with_clock(clock.shared());
+        state.start_blinking();
+
+        let interval = Duration::from_millis(500);
+        assert!(!state.update_blink(interval));
+
+        clock.advance(Duration::from_millis(499));
+        assert!(!state.update_blink(interval));
+
+        clock.advance(Duration::from_millis(1));
+        assert!(state.update_blink(interval));
+        assert!(state.blink_state);
+        assert_eq!(clock.elapsed_since(state.last_blink), Duration::ZERO);
+    }
+}
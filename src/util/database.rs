@@ -1,31 +1,109 @@
 // src/database.rs
 // Core database struct with connection management only
 
-use rusqlite::{Connection, Result};
+use r2d2::{Pool, PooledConnection};
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::Result;
 use std::path::Path;
-use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::log_info;
+use crate::runtime::audit::AuditEntry;
+use crate::util::dirs::data_dirs;
+
+pub type ConnectionPool = Pool<SqliteConnectionManager>;
+
+/// Connections per file-backed pool. WAL lets readers run alongside the one writer.
+const POOL_SIZE: u32 = 4;
 
 #[derive(Clone)]
 pub struct Database {
-    pub(crate) app_conn: Arc<Mutex<Connection>>,
-    pub(crate) knowledge_conn: Arc<Mutex<Connection>>,
-    pub(crate) analytics_conn: Arc<Mutex<Connection>>,
+    pub(crate) app_conn: ConnectionPool,
+    pub(crate) knowledge_conn: ConnectionPool,
+    pub(crate) analytics_conn: ConnectionPool,
 }
 
-// Manual Debug implementation since Mutex<Connection> doesn't implement Debug
+// Manual Debug implementation since the pool manager doesn't implement Debug
 impl std::fmt::Debug for Database {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Database")
-            .field("app_conn", &"Arc<Mutex<Connection>>")
-            .field("knowledge_conn", &"Arc<Mutex<Connection>>")
-            .field("analytics_conn", &"Arc<Mutex<Connection>>")
+            .field("app_conn", &self.app_conn.state())
+            .field("knowledge_conn", &self.knowledge_conn.state())
+            .field("analytics_conn", &self.analytics_conn.state())
             .finish()
     }
 }
 
+/// One ordered schema change for a module.
+///
+/// Versions are per module and must only ever be appended; a version that has
+/// been recorded in `schema_version` is never run again.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    pub version: u32,
+    pub description: &'static str,
+    pub sql: &'static str,
+}
+
+/// Open a connection pool for one database file.
+///
+/// File-backed pools run in WAL mode so a long write (ingestion, telemetry)
+/// doesn't block readers. `:memory:` databases are private per connection,
+/// so those pools are capped at a single connection to keep one shared DB.
+fn open_pool(path: &Path) -> Result<ConnectionPool> {
+    let is_memory = path == Path::new(":memory:");
+
+    let (manager, max_size) = if is_memory {
+        (SqliteConnectionManager::memory(), 1)
+    } else {
+        let manager = SqliteConnectionManager::file(path).with_init(|conn| {
+            // journal_mode echoes the resulting mode back as a row
+            conn.pragma_update_and_check(None, "journal_mode", "WAL", |_| Ok(()))?;
+            conn.pragma_update(None, "synchronous", "NORMAL")?;
+            conn.busy_timeout(Duration::from_secs(5))
+        });
+        (manager, POOL_SIZE)
+    };
+
+    let builder = Pool::builder().max_size(max_size);
+    // An in-memory database lives only as long as its one connection, so it's never recycled
+    let builder = if is_memory {
+        builder.min_idle(Some(1)).idle_timeout(None).max_lifetime(None)
+    } else {
+        builder
+    };
+
+    builder
+        .build(manager)
+        .map_err(|e| {
+            rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_CANTOPEN),
+                Some(format!("Failed to open connection pool for {}: {}", path.display(), e))
+            )
+        })
+}
+
 impl Database {
+    /// A connection from `pool`, as an error rather than a panic when none can be opened
+    /// (the file is gone, unreadable or on a disk that's failing)
+    pub(crate) fn connection(pool: &ConnectionPool) -> Result<PooledConnection<SqliteConnectionManager>> {
+        pool.get().map_err(|e| {
+            rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_CANTOPEN),
+                Some(format!("No database connection available: {}", e))
+            )
+        })
+    }
+
+    /// Whether `error` is SQLite reporting the disk full, so callers can say so instead of
+    /// logging a generic failure
+    pub fn is_disk_full(error: &rusqlite::Error) -> bool {
+        error.sqlite_error_code() == Some(rusqlite::ErrorCode::DiskFull)
+    }
+
+    /// `survon.db`, `knowledge.db` and `analytics.db` in the database directory (see [`crate::util::dirs`])
     pub fn new_implied_all_schemas() -> Result<Self> {
-        let db_dir = std::path::PathBuf::from("./db");
+        let db_dir = data_dirs().database;
         if !db_dir.exists() {
             std::fs::create_dir_all(&db_dir).map_err(|e| {
                 rusqlite::Error::SqliteFailure(
@@ -42,15 +120,18 @@ impl Database {
         Self::new(&app_db_path, &knowledge_db_path, &analytics_db_path)
     }
 
-    pub fn new(app_db_path: &Path, knowledge_db_path: &Path, analytics_db_path: &Path) -> Result<Self> {
-        let app_conn = Connection::open(app_db_path)?;
-        let knowledge_conn = Connection::open(knowledge_db_path)?;
-        let analytics_conn = Connection::open(analytics_db_path)?;
+    /// Open every database as a private `:memory:` connection with all schemas
+    /// initialized. Nothing touches disk; contents are lost on drop.
+    pub fn in_memory() -> Result<Self> {
+        let memory = Path::new(":memory:");
+        Self::new(memory, memory, memory)
+    }
 
+    pub fn new(app_db_path: &Path, knowledge_db_path: &Path, analytics_db_path: &Path) -> Result<Self> {
         let db = Database {
-            app_conn: Arc::new(Mutex::new(app_conn)),
-            knowledge_conn: Arc::new(Mutex::new(knowledge_conn)),
-            analytics_conn: Arc::new(Mutex::new(analytics_conn)),
+            app_conn: open_pool(app_db_path)?,
+            knowledge_conn: open_pool(knowledge_db_path)?,
+            analytics_conn: open_pool(analytics_db_path)?,
         };
 
         // Initialize all module schemas
@@ -65,18 +146,35 @@ impl Database {
 
         // Module-specific initialization
         use crate::module::strategies::llm::database::LlmDatabase;
+        use crate::module::strategies::monitoring::database::MonitoringDatabase;
         use crate::module::strategies::overseer::database::OverseerDatabase;
         use crate::module::strategies::side_quest::database::SideQuestDatabase;
 
         self.init_llm_schema()?;
         self.init_overseer_schema()?;
         self.init_side_quest_schema()?;
+        self.init_monitoring_schema()?;
 
         Ok(())
     }
 
     fn init_core_tables(&self) -> Result<()> {
-        let conn = self.app_conn.lock().unwrap();
+        // Every database tracks its own applied migrations
+        for pool in [&self.app_conn, &self.knowledge_conn, &self.analytics_conn] {
+            let conn = Self::connection(pool)?;
+            conn.execute(
+                "CREATE TABLE IF NOT EXISTS schema_version (
+                    module TEXT NOT NULL,
+                    version INTEGER NOT NULL,
+                    description TEXT NOT NULL,
+                    applied_at INTEGER NOT NULL,
+                    PRIMARY KEY (module, version)
+                )",
+                [],
+            )?;
+        }
+
+        let conn = Self::connection(&self.app_conn)?;
 
         // Message bus logging
         conn.execute(
@@ -90,6 +188,24 @@ impl Database {
             [],
         )?;
 
+        // Every actuation, append-only (see `runtime::audit`)
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS audit_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                recorded_at INTEGER NOT NULL,
+                handler TEXT NOT NULL,
+                triggered_by TEXT NOT NULL,
+                device_id TEXT NOT NULL,
+                action TEXT NOT NULL,
+                outcome TEXT NOT NULL,
+                detail TEXT
+            );
+            CREATE TRIGGER IF NOT EXISTS audit_log_no_update BEFORE UPDATE ON audit_log
+            BEGIN SELECT RAISE(ABORT, 'audit_log is append-only'); END;
+            CREATE TRIGGER IF NOT EXISTS audit_log_no_delete BEFORE DELETE ON audit_log
+            BEGIN SELECT RAISE(ABORT, 'audit_log is append-only'); END;",
+        )?;
+
         // Generic module state storage
         conn.execute(
             "CREATE TABLE IF NOT EXISTS module_state (
@@ -104,13 +220,66 @@ impl Database {
         Ok(())
     }
 
+    /// Apply any of `migrations` not yet recorded for `module` on this connection.
+    /// Each step runs in its own transaction together with its version record.
+    pub(crate) fn apply_migrations(
+        &self,
+        pool: &ConnectionPool,
+        module: &str,
+        migrations: &[Migration],
+    ) -> Result<()> {
+        let mut conn = Self::connection(pool)?;
+
+        let current: u32 = conn.query_row(
+            "SELECT COALESCE(MAX(version), 0) FROM schema_version WHERE module = ?1",
+            rusqlite::params![module],
+            |row| row.get(0),
+        )?;
+
+        let mut pending: Vec<&Migration> = migrations
+            .iter()
+            .filter(|m| m.version > current)
+            .collect();
+        pending.sort_by_key(|m| m.version);
+
+        for migration in pending {
+            log_info!("Applying {} migration v{}: {}", module, migration.version, migration.description);
+
+            let applied_at = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs() as i64;
+
+            let tx = conn.transaction()?;
+            tx.execute_batch(migration.sql)?;
+            tx.execute(
+                "INSERT INTO schema_version (module, version, description, applied_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                rusqlite::params![module, migration.version, migration.description, applied_at],
+            )?;
+            tx.commit()?;
+        }
+
+        Ok(())
+    }
+
+    /// Highest migration version applied for `module`, or 0 if none
+    pub fn schema_version(&self, pool: &ConnectionPool, module: &str) -> Result<u32> {
+        let conn = Self::connection(pool)?;
+        conn.query_row(
+            "SELECT COALESCE(MAX(version), 0) FROM schema_version WHERE module = ?1",
+            rusqlite::params![module],
+            |row| row.get(0),
+        )
+    }
+
     pub fn log_bus_message(&self, topic: &str, payload: &str, source: &str) -> Result<()> {
         let timestamp = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_secs() as i64;
 
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         conn.execute(
             "INSERT INTO message_log (topic, payload, source, timestamp)
              VALUES (?1, ?2, ?3, ?4)",
@@ -120,13 +289,67 @@ impl Database {
         Ok(())
     }
 
+    /// Take a connection from each pool and make a write that's rolled back, for diagnostics
+    pub fn check_writable(&self) -> color_eyre::Result<()> {
+        for pool in [&self.app_conn, &self.knowledge_conn, &self.analytics_conn] {
+            let mut conn = pool.get()?;
+            let tx = conn.transaction()?;
+            tx.execute("CREATE TABLE diagnostics_probe (id INTEGER)", [])?;
+            tx.rollback()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn append_audit_entry(&self, entry: &AuditEntry) -> Result<()> {
+        let conn = Self::connection(&self.app_conn)?;
+        conn.execute(
+            "INSERT INTO audit_log (recorded_at, handler, triggered_by, device_id, action, outcome, detail)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            rusqlite::params![
+                entry.recorded_at,
+                entry.handler,
+                entry.trigger,
+                entry.device_id,
+                entry.action,
+                entry.outcome,
+                entry.detail,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// The newest `limit` audit entries, newest first
+    pub fn recent_audit_entries(&self, limit: usize) -> Result<Vec<AuditEntry>> {
+        let conn = Self::connection(&self.app_conn)?;
+        let mut stmt = conn.prepare(
+            "SELECT recorded_at, handler, triggered_by, device_id, action, outcome, detail
+             FROM audit_log ORDER BY id DESC LIMIT ?1"
+        )?;
+
+        let entries = stmt.query_map(rusqlite::params![limit as i64], |row| {
+            Ok(AuditEntry {
+                recorded_at: row.get(0)?,
+                handler: row.get(1)?,
+                trigger: row.get(2)?,
+                device_id: row.get(3)?,
+                action: row.get(4)?,
+                outcome: row.get(5)?,
+                detail: row.get(6)?,
+            })
+        })?;
+
+        entries.collect()
+    }
+
     pub fn save_module_state(&self, module_name: &str, state_data: &str) -> Result<()> {
         let timestamp = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_secs() as i64;
 
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         conn.execute(
             "INSERT OR REPLACE INTO module_state (module_name, state_data, updated_at)
              VALUES (?1, ?2, ?3)",
@@ -137,7 +360,7 @@ impl Database {
     }
 
     pub fn get_module_state(&self, module_name: &str) -> Result<Option<String>> {
-        let conn = self.app_conn.lock().unwrap();
+        let conn = Self::connection(&self.app_conn)?;
         let mut stmt = conn.prepare(
             "SELECT state_data FROM module_state WHERE module_name = ?1"
         )?;
@@ -152,3 +375,35 @@ impl Database {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: 1,
+            description: "create widgets",
+            sql: "CREATE TABLE widgets (id INTEGER PRIMARY KEY)",
+        },
+        Migration {
+            version: 2,
+            description: "add widget name",
+            sql: "ALTER TABLE widgets ADD COLUMN name TEXT",
+        },
+    ];
+
+    #[test]
+    fn test_migrations_only_run_once() {
+        let db = Database::in_memory().unwrap();
+
+        db.apply_migrations(&db.app_conn, "test", &TEST_MIGRATIONS[..1]).unwrap();
+        assert_eq!(db.schema_version(&db.app_conn, "test").unwrap(), 1);
+
+        // Re-running the full list must only apply the pending step
+        db.apply_migrations(&db.app_conn, "test", TEST_MIGRATIONS).unwrap();
+        db.apply_migrations(&db.app_conn, "test", TEST_MIGRATIONS).unwrap();
+        assert_eq!(db.schema_version(&db.app_conn, "test").unwrap(), 2);
+        assert_eq!(db.schema_version(&db.app_conn, "other").unwrap(), 0);
+    }
+}
@@ -0,0 +1,165 @@
+// src/util/dirs.rs
+//! Where the runtime keeps what it writes. Defaults follow platform conventions (e.g.
+//! `~/.local/share/survon` and `~/.cache/survon` on Linux, `~/Library/Application Support/...`
+//! on macOS); `SURVON_DATA_DIR` puts everything under one directory in the source-tree layout
+//! (`manifests/`, `db/`, `logs/`, `.cache/`, `assets/`), and each location can be overridden on its own.
+//! A working directory that already holds `manifests/` or `db/` is an existing install and keeps
+//! being used as the root.
+
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, RwLock};
+
+use directories::ProjectDirs;
+
+/// The directories in effect, from the environment until the runtime sets them
+static DATA_DIRS: LazyLock<RwLock<DataDirs>> = LazyLock::new(|| RwLock::new(DataDirs::from_env()));
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataDirs {
+    /// Installable (wasteland) module manifests
+    pub modules: PathBuf,
+    /// Built-in (core) module manifests
+    pub core_modules: PathBuf,
+    /// SQLite databases
+    pub database: PathBuf,
+    /// Parsed documents and the registry cache; safe to delete
+    pub cache: PathBuf,
+    pub logs: PathBuf,
+    /// View exports and support bundles
+    pub exports: PathBuf,
+    /// Bundled images and audio
+    pub assets: PathBuf,
+}
+
+impl DataDirs {
+    /// The platform's data, cache and state directories for Survon, or the working directory
+    /// when there's no home directory to put them under. Core manifests and assets not copied
+    /// into the data directory are read from beside the executable, where releases ship them.
+    pub fn platform() -> Self {
+        let Some(project) = ProjectDirs::from("io", "survon", "survon") else {
+            return Self::rooted(".");
+        };
+        let data = project.data_dir();
+        Self {
+            modules: data.join("manifests").join("wasteland"),
+            core_modules: shipped(data.join("manifests").join("core"), Path::new("manifests").join("core")),
+            database: data.join("db"),
+            cache: project.cache_dir().to_path_buf(),
+            logs: project.state_dir().unwrap_or(project.data_local_dir()).join("logs"),
+            exports: data.join("exports"),
+            assets: shipped(data.join("assets"), PathBuf::from("assets")),
+        }
+    }
+
+    /// Everything under `root`, laid out as in the source tree
+    pub fn rooted(root: impl AsRef<Path>) -> Self {
+        let root = root.as_ref();
+        Self {
+            modules: root.join("manifests").join("wasteland"),
+            core_modules: root.join("manifests").join("core"),
+            database: root.join("db"),
+            cache: root.join(".cache"),
+            logs: root.join("logs"),
+            exports: root.to_path_buf(),
+            assets: root.join("assets"),
+        }
+    }
+
+    /// Whether `dir` holds an install from before the platform defaults, in the source-tree layout
+    pub fn is_legacy_root(dir: impl AsRef<Path>) -> bool {
+        let dir = dir.as_ref();
+        dir.join("manifests").is_dir() || dir.join("db").is_dir()
+    }
+
+    /// [`Self::rooted`] at `SURVON_DATA_DIR` if set, or at the working directory if it's a
+    /// [legacy root](Self::is_legacy_root), otherwise [`Self::platform`], with any of
+    /// `SURVON_MODULES_DIR`, `SURVON_CORE_MODULES_DIR`, `SURVON_DB_DIR`, `SURVON_CACHE_DIR`,
+    /// `SURVON_LOG_DIR`, `SURVON_EXPORT_DIR` and `SURVON_ASSETS_DIR` taking precedence
+    pub fn from_env() -> Self {
+        Self::from_vars(
+            |name| std::env::var_os(name).filter(|v| !v.is_empty()).map(PathBuf::from),
+            Path::new("."),
+        )
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<PathBuf>, working_dir: &Path) -> Self {
+        let mut dirs = match var("SURVON_DATA_DIR") {
+            Some(root) => Self::rooted(root),
+            None if Self::is_legacy_root(working_dir) => Self::rooted(working_dir),
+            None => Self::platform(),
+        };
+        for (name, dir) in [
+            ("SURVON_MODULES_DIR", &mut dirs.modules),
+            ("SURVON_CORE_MODULES_DIR", &mut dirs.core_modules),
+            ("SURVON_DB_DIR", &mut dirs.database),
+            ("SURVON_CACHE_DIR", &mut dirs.cache),
+            ("SURVON_LOG_DIR", &mut dirs.logs),
+            ("SURVON_EXPORT_DIR", &mut dirs.exports),
+            ("SURVON_ASSETS_DIR", &mut dirs.assets),
+        ] {
+            if let Some(path) = var(name) {
+                *dir = path;
+            }
+        }
+        dirs
+    }
+}
+
+/// `installed` if it exists, otherwise `relative` beside the running executable if that does
+fn shipped(installed: PathBuf, relative: PathBuf) -> PathBuf {
+    if installed.exists() {
+        return installed;
+    }
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.parent()?.join(&relative)))
+        .filter(|beside_exe| beside_exe.exists())
+        .unwrap_or(installed)
+}
+
+/// The directories in effect
+pub fn data_dirs() -> DataDirs {
+    DATA_DIRS.read().unwrap().clone()
+}
+
+/// Use `dirs` from now on. Logging starts before the runtime is built, so the log directory
+/// only follows `SURVON_LOG_DIR` / `SURVON_DATA_DIR`.
+pub fn set_data_dirs(dirs: DataDirs) {
+    *DATA_DIRS.write().unwrap() = dirs;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_and_individual_overrides() {
+        let dirs = DataDirs::from_vars(|name| match name {
+            "SURVON_DATA_DIR" => Some(PathBuf::from("/srv/survon")),
+            "SURVON_LOG_DIR" => Some(PathBuf::from("/var/log/survon")),
+            _ => None,
+        }, Path::new("/nonexistent"));
+        assert_eq!(dirs.modules, Path::new("/srv/survon/manifests/wasteland"));
+        assert_eq!(dirs.database, Path::new("/srv/survon/db"));
+        assert_eq!(dirs.cache, Path::new("/srv/survon/.cache"));
+        assert_eq!(dirs.logs, Path::new("/var/log/survon"));
+        assert_eq!(dirs.exports, Path::new("/srv/survon"));
+        assert_eq!(dirs.assets, Path::new("/srv/survon/assets"));
+    }
+
+    #[test]
+    fn test_existing_install_in_working_dir_is_kept() {
+        let install = std::env::temp_dir().join(format!("survon_legacy_root_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(install.join("db")).unwrap();
+
+        let dirs = DataDirs::from_vars(|_| None, &install);
+        assert_eq!(dirs, DataDirs::rooted(&install));
+        assert_eq!(dirs.core_modules, install.join("manifests").join("core"));
+
+        // SURVON_DATA_DIR still wins
+        let dirs = DataDirs::from_vars(|name| (name == "SURVON_DATA_DIR").then(|| PathBuf::from("/srv/survon")), &install);
+        assert_eq!(dirs.database, Path::new("/srv/survon/db"));
+
+        std::fs::remove_dir_all(&install).unwrap();
+    }
+}
@@ -0,0 +1,123 @@
+// src/util/disk.rs
+//! Free space on the filesystem the runtime writes to. SD-card appliances fill up; writes check
+//! here first so a full disk is an error that says so rather than a torn file or a panic.
+
+use std::io;
+use std::path::Path;
+
+/// Below this much free space the runtime warns, overridable with `LOW_DISK_SPACE_MB`
+const DEFAULT_LOW_SPACE_MB: u64 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiskSpace {
+    /// Bytes an unprivileged process may still write
+    pub available: u64,
+    pub total: u64,
+}
+
+impl DiskSpace {
+    /// Space on the filesystem holding `path`
+    #[cfg(unix)]
+    pub fn of(path: &Path) -> io::Result<Self> {
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+
+        let c_path = CString::new(path.as_os_str().as_bytes())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+        if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Field widths differ between platforms
+        #[allow(clippy::unnecessary_cast)]
+        let block = stat.f_frsize as u64;
+        #[allow(clippy::unnecessary_cast)]
+        Ok(Self { available: stat.f_bavail as u64 * block, total: stat.f_blocks as u64 * block })
+    }
+
+    #[cfg(not(unix))]
+    pub fn of(_path: &Path) -> io::Result<Self> {
+        Err(io::Error::new(io::ErrorKind::Unsupported, "disk space checks need a unix target"))
+    }
+
+    pub fn is_low(&self) -> bool {
+        self.available < low_space_threshold()
+    }
+}
+
+/// `LOW_DISK_SPACE_MB` in bytes
+pub fn low_space_threshold() -> u64 {
+    std::env::var("LOW_DISK_SPACE_MB")
+        .ok()
+        .and_then(|mb| mb.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_LOW_SPACE_MB)
+        * 1024
+        * 1024
+}
+
+/// Fail with [`io::ErrorKind::StorageFull`] when `needed` bytes won't fit beside `path`.
+/// Filesystems that can't be measured are given the benefit of the doubt.
+pub fn ensure_space(path: &Path, needed: u64) -> io::Result<()> {
+    match DiskSpace::of(parent_dir(path)) {
+        Ok(space) if space.available < needed => Err(disk_full_error(path)),
+        _ => Ok(()),
+    }
+}
+
+/// Whether `error` means there was no room left to write
+pub fn is_disk_full(error: &io::Error) -> bool {
+    error.kind() == io::ErrorKind::StorageFull || error.raw_os_error() == Some(libc::ENOSPC)
+}
+
+/// A [`io::ErrorKind::StorageFull`] error naming the file that couldn't be written and the
+/// space that's left
+pub fn disk_full_error(path: &Path) -> io::Error {
+    let free = match DiskSpace::of(parent_dir(path)) {
+        Ok(space) => format!(" ({} free)", format_bytes(space.available)),
+        Err(_) => String::new(),
+    };
+    io::Error::new(io::ErrorKind::StorageFull, format!("Disk full: couldn't write {}{}", path.display(), free))
+}
+
+fn parent_dir(path: &Path) -> &Path {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    }
+}
+
+/// `1536` -> `1.5 KB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.1} {}", value, UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_disk_is_recognised_and_described() {
+        assert!(is_disk_full(&io::Error::from_raw_os_error(libc::ENOSPC)));
+        assert!(!is_disk_full(&io::Error::from(io::ErrorKind::PermissionDenied)));
+
+        let error = disk_full_error(&std::env::temp_dir().join("config.yml"));
+        assert!(is_disk_full(&error));
+        assert!(error.to_string().starts_with("Disk full: couldn't write "));
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(512), "512 B");
+
+        assert!(ensure_space(&std::env::temp_dir().join("probe"), 1).is_ok());
+        assert!(ensure_space(&std::env::temp_dir().join("probe"), u64::MAX).is_err());
+    }
+}
@@ -0,0 +1,373 @@
+use crate::module::ModuleConfig;
+
+/// How a module's readings are presented: unit conversion, scaling, precision and grouping.
+///
+/// Configured through optional bindings next to `unit_of_measure_label`:
+/// - `display_unit`: convert to this unit before display (e.g. `bar`, `°F`)
+/// - `auto_scale_unit`: pick the unit in the same family that keeps the number readable
+/// - `significant_figures`: round to this many significant figures
+/// - `display_precision`: fixed decimals when `significant_figures` is unset (default 1);
+///   `decimal_places` is the older name for the same thing
+/// - `rounding`: how the last digit is rounded, see [`Rounding`]
+/// - `thousands_separator`: group digits with commas (default true)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueFormat {
+    pub unit: String,
+    pub display_unit: Option<String>,
+    pub auto_scale: bool,
+    pub significant_figures: Option<usize>,
+    pub decimal_places: usize,
+    pub rounding: Rounding,
+    pub thousands_separator: bool,
+}
+
+/// How a value is rounded to the displayed precision
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Rounding {
+    /// `nearest`: halves away from zero, 23.45 -> 23.5
+    #[default]
+    Nearest,
+    /// `half_even`: halves to the even digit, 23.45 -> 23.4
+    HalfEven,
+    /// `truncate`: toward zero
+    Truncate,
+    /// `floor`: toward negative infinity
+    Floor,
+    /// `ceil`: toward positive infinity
+    Ceil,
+}
+
+impl Rounding {
+    pub const NAMES: &'static [&'static str] = &["nearest", "half_even", "truncate", "floor", "ceil"];
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim() {
+            "nearest" => Some(Rounding::Nearest),
+            "half_even" => Some(Rounding::HalfEven),
+            "truncate" => Some(Rounding::Truncate),
+            "floor" => Some(Rounding::Floor),
+            "ceil" => Some(Rounding::Ceil),
+            _ => None,
+        }
+    }
+
+    /// `value` rounded to `places` decimals; negative `places` round left of the point
+    pub fn round(self, value: f64, places: i32) -> f64 {
+        let scale = 10f64.powi(places);
+        let scaled = value * scale;
+        // 0.29 * 100 is 28.999999999999996; snap that back so floor and halves see what was meant
+        let scaled = match scaled.abs() < 1e6 {
+            true => (scaled * 1e9).round() / 1e9,
+            false => scaled,
+        };
+        let rounded = match self {
+            Rounding::Nearest => scaled.round(),
+            Rounding::HalfEven => scaled.round_ties_even(),
+            Rounding::Truncate => scaled.trunc(),
+            Rounding::Floor => scaled.floor(),
+            Rounding::Ceil => scaled.ceil(),
+        };
+        // Adding zero turns -0.0 into 0.0, so nothing renders as "-0.0"
+        rounded / scale + 0.0
+    }
+}
+
+/// A unit within a family, as a linear factor to the family's base unit
+struct LinearUnit {
+    symbols: &'static [&'static str],
+    factor: f64,
+    /// Offered by `auto_scale_unit`
+    auto: bool,
+}
+
+const PRESSURE: &[LinearUnit] = &[
+    LinearUnit { symbols: &["Pa"], factor: 1.0, auto: true },
+    LinearUnit { symbols: &["hPa", "mbar"], factor: 100.0, auto: false },
+    LinearUnit { symbols: &["kPa"], factor: 1_000.0, auto: true },
+    LinearUnit { symbols: &["bar"], factor: 100_000.0, auto: true },
+    LinearUnit { symbols: &["MPa"], factor: 1_000_000.0, auto: false },
+    LinearUnit { symbols: &["psi", "PSI"], factor: 6_894.757, auto: false },
+];
+
+const VOLUME: &[LinearUnit] = &[
+    LinearUnit { symbols: &["mL", "ml"], factor: 0.001, auto: true },
+    LinearUnit { symbols: &["L", "l"], factor: 1.0, auto: true },
+    LinearUnit { symbols: &["m³", "m3"], factor: 1_000.0, auto: true },
+    LinearUnit { symbols: &["gal"], factor: 3.785_411_784, auto: false },
+];
+
+const LENGTH: &[LinearUnit] = &[
+    LinearUnit { symbols: &["mm"], factor: 0.001, auto: true },
+    LinearUnit { symbols: &["cm"], factor: 0.01, auto: false },
+    LinearUnit { symbols: &["m"], factor: 1.0, auto: true },
+    LinearUnit { symbols: &["km"], factor: 1_000.0, auto: true },
+    LinearUnit { symbols: &["in"], factor: 0.0254, auto: false },
+    LinearUnit { symbols: &["ft"], factor: 0.3048, auto: false },
+];
+
+const FAMILIES: &[&[LinearUnit]] = &[PRESSURE, VOLUME, LENGTH];
+
+/// Units scaled with plain SI prefixes (mV, kW, MHz, ...)
+const SI_BASES: &[&str] = &["V", "A", "W", "Wh", "Hz", "g"];
+const SI_PREFIXES: &[(&str, f64)] = &[("m", 1e-3), ("", 1.0), ("k", 1e3), ("M", 1e6)];
+
+impl Default for ValueFormat {
+    fn default() -> Self {
+        Self {
+            unit: String::new(),
+            display_unit: None,
+            auto_scale: false,
+            significant_figures: None,
+            decimal_places: 1,
+            rounding: Rounding::default(),
+            thousands_separator: true,
+        }
+    }
+}
+
+impl ValueFormat {
+    pub fn from_config(config: &ModuleConfig) -> Self {
+        let defaults = Self::default();
+        let str_of = |key: &str| {
+            config
+                .binding_str(key)
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+        };
+
+        Self {
+            unit: str_of("unit_of_measure_label").unwrap_or_default(),
+            display_unit: str_of("display_unit"),
+            auto_scale: config.binding_or("auto_scale_unit", defaults.auto_scale),
+            significant_figures: config
+                .binding_u64("significant_figures")
+                .map(|n| n.clamp(1, 15) as usize),
+            decimal_places: ["display_precision", "decimal_places"]
+                .iter()
+                .find_map(|key| config.binding_u64(key))
+                .map(|n| n.min(10) as usize)
+                .unwrap_or(defaults.decimal_places),
+            rounding: str_of("rounding")
+                .and_then(|name| Rounding::parse(&name))
+                .unwrap_or(defaults.rounding),
+            thousands_separator: config.binding_or("thousands_separator", defaults.thousands_separator),
+        }
+    }
+
+    /// `value` (in `unit_of_measure_label`) formatted with its display unit, e.g. `1.2 bar`
+    pub fn format(&self, value: f64) -> String {
+        let (number, unit) = self.format_parts(value);
+        if unit.is_empty() {
+            number
+        } else {
+            format!("{} {}", number, unit)
+        }
+    }
+
+    /// Number and unit separately, for layouts that style them differently
+    pub fn format_parts(&self, value: f64) -> (String, String) {
+        if !value.is_finite() {
+            return ("--".to_string(), self.unit.clone());
+        }
+
+        let (value, unit) = self.convert(value);
+        (self.format_number(value), unit)
+    }
+
+    /// Converted into the display unit, without rendering
+    pub fn convert(&self, value: f64) -> (f64, String) {
+        let mut converted = (value, self.unit.clone());
+
+        if let Some(target) = &self.display_unit {
+            if let Some(v) = convert_between(value, &self.unit, target) {
+                converted = (v, target.clone());
+            }
+        }
+
+        if self.auto_scale {
+            if let Some(scaled) = auto_scale(converted.0, &converted.1) {
+                converted = scaled;
+            }
+        }
+
+        converted
+    }
+
+    fn format_number(&self, value: f64) -> String {
+        let places = match self.significant_figures {
+            Some(sig) if value != 0.0 => {
+                let magnitude = value.abs().log10().floor() as i32;
+                sig as i32 - 1 - magnitude
+            }
+            Some(sig) => sig as i32 - 1,
+            None => self.decimal_places as i32,
+        };
+
+        let value = self.rounding.round(value, places);
+        let text = format!("{:.*}", places.max(0) as usize, value);
+        if self.thousands_separator {
+            group_thousands(&text)
+        } else {
+            text
+        }
+    }
+}
+
+fn find_linear(symbol: &str) -> Option<(&'static [LinearUnit], &'static LinearUnit)> {
+    FAMILIES.iter().find_map(|family| {
+        family
+            .iter()
+            .find(|u| u.symbols.contains(&symbol))
+            .map(|u| (*family, u))
+    })
+}
+
+fn find_si(symbol: &str) -> Option<(&'static str, f64)> {
+    SI_PREFIXES.iter().find_map(|(prefix, factor)| {
+        let base = symbol.strip_prefix(prefix)?;
+        SI_BASES.iter().find(|b| **b == base).map(|b| (*b, *factor))
+    })
+}
+
+fn temperature_to_celsius(value: f64, unit: &str) -> Option<f64> {
+    match unit {
+        "°C" | "C" | "celsius" => Some(value),
+        "°F" | "F" | "fahrenheit" => Some((value - 32.0) * 5.0 / 9.0),
+        "K" | "kelvin" => Some(value - 273.15),
+        _ => None,
+    }
+}
+
+fn celsius_to(value: f64, unit: &str) -> Option<f64> {
+    match unit {
+        "°C" | "C" | "celsius" => Some(value),
+        "°F" | "F" | "fahrenheit" => Some(value * 9.0 / 5.0 + 32.0),
+        "K" | "kelvin" => Some(value + 273.15),
+        _ => None,
+    }
+}
+
+/// Convert between two units of the same family; None if they aren't compatible
+pub fn convert_between(value: f64, from: &str, to: &str) -> Option<f64> {
+    if from == to {
+        return Some(value);
+    }
+
+    if let (Some((family_a, a)), Some((family_b, b))) = (find_linear(from), find_linear(to)) {
+        return std::ptr::eq(family_a, family_b).then(|| value * a.factor / b.factor);
+    }
+
+    if let (Some((base_a, a)), Some((base_b, b))) = (find_si(from), find_si(to)) {
+        return (base_a == base_b).then(|| value * a / b);
+    }
+
+    temperature_to_celsius(value, from).and_then(|c| celsius_to(c, to))
+}
+
+/// Re-express `value` in the family unit that keeps it at or above 1, preferring the largest
+fn auto_scale(value: f64, unit: &str) -> Option<(f64, String)> {
+    if let Some((family, current)) = find_linear(unit) {
+        let base = value * current.factor;
+        let mut candidates: Vec<&LinearUnit> = family.iter().filter(|u| u.auto).collect();
+        candidates.sort_by(|a, b| b.factor.total_cmp(&a.factor));
+
+        let chosen = candidates
+            .iter()
+            .find(|u| (base / u.factor).abs() >= 1.0)
+            .or(candidates.last())?;
+        return Some((base / chosen.factor, chosen.symbols[0].to_string()));
+    }
+
+    if let Some((si_base, factor)) = find_si(unit) {
+        let base = value * factor;
+        let (prefix, prefix_factor) = SI_PREFIXES
+            .iter()
+            .rev()
+            .find(|(_, f)| (base / f).abs() >= 1.0)
+            .unwrap_or(&SI_PREFIXES[0]);
+        return Some((base / prefix_factor, format!("{}{}", prefix, si_base)));
+    }
+
+    None
+}
+
+/// `1234567.5` -> `1,234,567.5`
+fn group_thousands(text: &str) -> String {
+    let (sign, rest) = match text.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", text),
+    };
+    let (int_part, frac_part) = match rest.split_once('.') {
+        Some((i, f)) => (i, Some(f)),
+        None => (rest, None),
+    };
+
+    let mut grouped = String::with_capacity(int_part.len() + int_part.len() / 3);
+    for (i, ch) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+
+    match frac_part {
+        Some(frac) => format!("{}{}.{}", sign, grouped, frac),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_scale_pressure_and_grouping() {
+        let format = ValueFormat {
+            unit: "Pa".to_string(),
+            auto_scale: true,
+            ..Default::default()
+        };
+        assert_eq!(format.format(120_000.0), "1.2 bar");
+        assert_eq!(format.format(50_000.0), "50.0 kPa");
+
+        let plain = ValueFormat { unit: "L".to_string(), ..Default::default() };
+        assert_eq!(plain.format(1_234_567.0), "1,234,567.0 L");
+    }
+
+    #[test]
+    fn test_display_unit_and_significant_figures() {
+        let format = ValueFormat {
+            unit: "°C".to_string(),
+            display_unit: Some("°F".to_string()),
+            significant_figures: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(format.format(100.0), "212 °F");
+        assert_eq!(convert_between(1.0, "kW", "W"), Some(1000.0));
+        assert_eq!(convert_between(1.0, "bar", "L"), None);
+    }
+
+    #[test]
+    fn test_display_precision_and_rounding() {
+        let config = crate::util::test_util::TestModule::new("Greenhouse", "system", "")
+            .binding("unit_of_measure_label", serde_json::json!("°C"))
+            .binding("display_precision", serde_json::json!(1))
+            .binding("decimal_places", serde_json::json!(3))
+            .build()
+            .config;
+        let mut format = ValueFormat::from_config(&config);
+        assert_eq!(format.format(23.419999), "23.4 °C");
+        assert_eq!(format.format(23.45), "23.5 °C");
+        assert_eq!(format.format(-0.04), "0.0 °C");
+
+        format.rounding = Rounding::HalfEven;
+        assert_eq!(format.format(23.45), "23.4 °C");
+        format.rounding = Rounding::Floor;
+        format.decimal_places = 2;
+        assert_eq!(format.format(0.29), "0.29 °C");
+        assert_eq!(format.format(-1.001), "-1.01 °C");
+        assert_eq!(Rounding::parse("ceil"), Some(Rounding::Ceil));
+        assert_eq!(Rounding::parse("up"), None);
+    }
+}
@@ -0,0 +1,78 @@
+// src/util/fs.rs
+//! File writes that readers never see half done.
+
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::util::disk;
+
+/// Write `contents` beside `path` and rename it into place, so a crash or a concurrent reader
+/// sees either the old file or the new one, never a torn write. The new file is synced before
+/// the rename and the directory after it, so a power cut can't leave an empty file behind.
+///
+/// A full disk fails with [`io::ErrorKind::StorageFull`] naming `path`, before anything is
+/// written when it can be seen coming; the old file is left as it was.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let contents = contents.as_ref();
+    disk::ensure_space(path, contents.len() as u64)?;
+
+    let tmp = tmp_path(path);
+    write_synced(&tmp, contents)
+        .and_then(|()| std::fs::rename(&tmp, path))
+        .and_then(|()| sync_parent_dir(path))
+        .map_err(|e| {
+            let _ = std::fs::remove_file(&tmp);
+            if disk::is_disk_full(&e) {
+                disk::disk_full_error(path)
+            } else {
+                e
+            }
+        })
+}
+
+fn write_synced(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+/// Make the rename itself durable
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> io::Result<()> {
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    File::open(dir)?.sync_all()
+}
+
+/// Directories can't be opened for syncing here; the rename is as durable as the OS makes it
+#[cfg(not(unix))]
+fn sync_parent_dir(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+/// `config.yml` -> `config.yml.tmp`
+fn tmp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_atomic_replaces_without_leftovers() {
+        let dir = std::env::temp_dir().join(format!("survon_write_atomic_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.yml");
+
+        write_atomic(&path, "name: old\n").unwrap();
+        write_atomic(&path, "name: new\n").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "name: new\n");
+        assert!(!tmp_path(&path).exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -14,7 +14,12 @@ use btleplug::platform::Peripheral;
 use crate::log_info;
 use crate::log_warn;
 use crate::log_error;
+use crate::runtime::audit::{AuditOutcome, CommandOrigin};
+use crate::runtime::poll_interval::{poll_interval, PollTask};
+use crate::runtime::safe_mode::is_safe_mode;
+use crate::runtime::toast::{notify, ToastLevel};
 use crate::util::io::bus::{MessageBus, BusMessage};
+use crate::util::io::retry::{DeliveryResult, RetryPolicy, DELIVERY_RESULT_TOPIC};
 
 /// Command to be sent to a device
 #[derive(Debug, Clone)]
@@ -24,6 +29,8 @@ pub struct QueuedCommand {
     pub priority: CommandPriority,
     pub queued_at: Instant,
     pub max_age: Option<Duration>,  // Optional expiration
+    /// Who asked for it; the audit row is written under them once it's sent or dropped
+    pub origin: CommandOrigin,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -102,6 +109,9 @@ pub struct BleCommandScheduler {
 
     /// Message bus for publishing events
     message_bus: Option<MessageBus>,
+
+    /// Applied to every command write
+    retry_policy: RetryPolicy,
 }
 
 impl BleCommandScheduler {
@@ -112,6 +122,7 @@ impl BleCommandScheduler {
             peripherals: Arc::new(RwLock::new(HashMap::new())),
             tx_char_uuid: Uuid::parse_str("6e400002-b5a3-f393-e0a9-e50e24dcca9e").unwrap(),
             message_bus: None,
+            retry_policy: RetryPolicy::from_env(),
         }
     }
 
@@ -121,6 +132,12 @@ impl BleCommandScheduler {
         self
     }
 
+    /// Retry failed command writes according to `policy` instead of the `TRANSPORT_RETRY_*` default
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Register a peripheral for command sending
     pub async fn register_peripheral(&self, device_id: String, peripheral: Peripheral) {
         self.peripherals.write().await.insert(device_id, peripheral);
@@ -153,14 +170,11 @@ impl BleCommandScheduler {
                 &device_id,
                 serde_json::json!({
                     "priority": "CRITICAL",
-                    "action": command.command.get("payload")
-                        .and_then(|p| p.get("action"))
-                        .and_then(|a| a.as_str())
-                        .unwrap_or("unknown")
+                    "action": command_action(&command.command)
                 })
             ).await;
 
-            return self.send_command_now(&device_id, &command.command).await;
+            return self.deliver(&device_id, &command.command, &command.origin).await;
         }
 
         // Add to queue
@@ -180,10 +194,7 @@ impl BleCommandScheduler {
             &device_id,
             serde_json::json!({
                 "priority": format!("{:?}", priority),
-                "action": command.command.get("payload")
-                    .and_then(|p| p.get("action"))
-                    .and_then(|a| a.as_str())
-                    .unwrap_or("unknown"),
+                "action": command_action(&command.command),
                 "queue_size": queue_size
             })
         ).await;
@@ -241,7 +252,11 @@ impl BleCommandScheduler {
                 serde_json::json!({ "duration": cmd_dur_secs })
             ).await;
 
-            self.send_queued_commands(&device_id).await?;
+            if is_safe_mode() {
+                self.refuse_queued_commands(&device_id).await;
+            } else {
+                self.send_queued_commands(&device_id).await?;
+            }
         } else if window_imminent {
             log_info!("🟡 Device {} CMD window opens in <5s - preparing", device_id);
 
@@ -268,6 +283,33 @@ impl BleCommandScheduler {
         Ok(())
     }
 
+    /// Turn away every command queued for a device because safe mode froze actuators after they were
+    /// queued. Each is audited as refused and reported undelivered on `delivery_result`, and a toast says
+    /// how many were refused, so nothing waits on a command that will never go out.
+    async fn refuse_queued_commands(&self, device_id: &str) {
+        let refused = match self.command_queues.write().await.get_mut(device_id) {
+            Some(queue) => queue.drain(..).collect::<Vec<_>>(),
+            None => return,
+        };
+        if refused.is_empty() {
+            return;
+        }
+
+        for cmd in &refused {
+            let reason = "safe mode".to_string();
+            cmd.origin.record(device_id, command_action(&cmd.command), AuditOutcome::Refused, Some(reason.clone()));
+            self.report_delivery(device_id, &cmd.command, 0, Some(reason)).await;
+        }
+
+        log_warn!("🛑 Safe mode: refused {} queued command(s) for {}", refused.len(), device_id);
+        notify(ToastLevel::Warning, format!("🛑 Safe mode: refused {} queued command(s) for {}", refused.len(), device_id));
+        self.publish_event(
+            "commands_dropped",
+            device_id,
+            serde_json::json!({ "count": refused.len(), "reason": "safe_mode" })
+        ).await;
+    }
+
     /// Send all queued commands for a device
     async fn send_queued_commands(&self, device_id: &str) -> Result<()> {
         let mut queues = self.command_queues.write().await;
@@ -280,6 +322,7 @@ impl BleCommandScheduler {
         };
 
         let original_count = queue.len();
+
         log_info!("📤 Sending {} queued commands to {}", original_count, device_id);
 
         // Publish batch start
@@ -295,6 +338,7 @@ impl BleCommandScheduler {
             if let Some(max_age) = cmd.max_age {
                 if now.duration_since(cmd.queued_at) > max_age {
                     log_warn!("⏳ Dropping expired command for {}", device_id);
+                    cmd.origin.record(device_id, command_action(&cmd.command), AuditOutcome::Failed, Some("expired in queue".to_string()));
                     return false;
                 }
             }
@@ -318,18 +362,15 @@ impl BleCommandScheduler {
             log_info!("📨 Sending command to {}", device_id);
 
             // Small delay between commands to avoid overwhelming device
-            tokio::time::sleep(Duration::from_millis(100)).await;
+            tokio::time::sleep(poll_interval(PollTask::Send)).await;
 
-            match self.send_command_now(device_id, &cmd.command).await {
+            match self.deliver(device_id, &cmd.command, &cmd.origin).await {
                 Ok(_) => {
                     self.publish_event(
                         "command_sent",
                         device_id,
                         serde_json::json!({
-                            "action": cmd.command.get("payload")
-                                .and_then(|p| p.get("action"))
-                                .and_then(|a| a.as_str())
-                                .unwrap_or("unknown")
+                            "action": command_action(&cmd.command)
                         })
                     ).await;
                 }
@@ -354,8 +395,54 @@ impl BleCommandScheduler {
         Ok(())
     }
 
-    /// Send a single command immediately (used for critical commands)
+    /// Send a command, retrying per the retry policy, then audit the outcome under `origin` and report it on
+    /// `delivery_result`
+    async fn deliver(&self, device_id: &str, command: &serde_json::Value, origin: &CommandOrigin) -> Result<()> {
+        let (attempts, outcome) = self.retry_policy
+            .run(|| self.send_command_now(device_id, command))
+            .await;
+
+        let audit_outcome = if outcome.is_ok() { AuditOutcome::Sent } else { AuditOutcome::Failed };
+        let detail = outcome.as_ref().err().map(|e| e.to_string());
+        origin.record(device_id, command_action(command), audit_outcome, detail);
+
+        self.report_delivery(device_id, command, attempts, outcome.as_ref().err().map(|e| e.to_string())).await;
+
+        outcome.map_err(|e| e.wrap_err(format!("Delivery to '{}' failed after {} attempt(s)", device_id, attempts)))
+    }
+
+    /// Publish a command's `DeliveryResult`; delivered unless there's an `error`
+    async fn report_delivery(&self, device_id: &str, command: &serde_json::Value, attempts: u32, error: Option<String>) {
+        let Some(bus) = &self.message_bus else {
+            return;
+        };
+        let result = DeliveryResult {
+            device_id: device_id.to_string(),
+            topic: command.get("topic").and_then(|t| t.as_str()).unwrap_or(device_id).to_string(),
+            source: "ble_scheduler".to_string(),
+            sequence: 0,
+            delivered: error.is_none(),
+            attempts,
+            error,
+        };
+        match serde_json::to_string(&result) {
+            Ok(payload) => {
+                let _ = bus.publish(BusMessage::new(
+                    DELIVERY_RESULT_TOPIC.to_string(),
+                    payload,
+                    "ble_scheduler".to_string(),
+                )).await;
+            }
+            Err(e) => log_error!("Failed to serialize delivery result for {}: {}", device_id, e),
+        }
+    }
+
+    /// Write a single command to the device's TX characteristic
     async fn send_command_now(&self, device_id: &str, command: &serde_json::Value) -> Result<()> {
+        if is_safe_mode() {
+            return Err(color_eyre::eyre::eyre!("Safe mode is on; not sending to {}", device_id));
+        }
+
         let peripherals = self.peripherals.read().await;
         let peripheral = peripherals.get(device_id)
             .ok_or_else(|| color_eyre::eyre::eyre!("Device {} not registered", device_id))?;
@@ -448,6 +535,14 @@ pub struct QueueStatus {
 // HELPER FUNCTIONS FOR DISCOVERY MANAGER
 // ============================================================================
 
+/// The `payload.action` of a control command, for events and the audit log
+fn command_action(command: &serde_json::Value) -> &str {
+    command.get("payload")
+        .and_then(|p| p.get("action"))
+        .and_then(|a| a.as_str())
+        .unwrap_or("unknown")
+}
+
 /// Parse telemetry and extract schedule metadata
 pub fn extract_schedule_metadata(telemetry: &serde_json::Value) -> Option<serde_json::Value> {
     telemetry.get("m").cloned()
@@ -469,6 +564,7 @@ pub fn create_control_command(device_id: &str, action: &str, payload: Option<ser
 
 #[cfg(test)]
 mod tests {
+    use crate::runtime::audit::AuditTrigger;
     use super::*;
 
     #[tokio::test]
@@ -503,6 +599,7 @@ mod tests {
             priority: CommandPriority::Normal,
             queued_at: Instant::now(),
             max_age: None,
+            origin: CommandOrigin::new("test", AuditTrigger::Api),
         };
 
         scheduler.queue_command(cmd).await.unwrap();
@@ -512,6 +609,66 @@ mod tests {
         assert_eq!(queue.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_safe_mode_refuses_queued_commands() {
+        let (bus, _receiver) = MessageBus::new();
+        let mut events = bus.subscribe("scheduler_event".to_string()).await;
+        let mut results = bus.subscribe(DELIVERY_RESULT_TOPIC.to_string()).await;
+        let scheduler = BleCommandScheduler::new().with_message_bus(bus);
+
+        scheduler.queue_command(QueuedCommand {
+            device_id: "gate_valve".to_string(),
+            command: create_control_command("gate_valve", "open", None),
+            priority: CommandPriority::Normal,
+            queued_at: Instant::now(),
+            max_age: None,
+            origin: CommandOrigin::new("test", AuditTrigger::Api),
+        }).await.unwrap();
+
+        scheduler.refuse_queued_commands("gate_valve").await;
+
+        assert!(scheduler.command_queues.read().await["gate_valve"].is_empty());
+
+        let result: DeliveryResult = serde_json::from_str(&results.try_recv().unwrap().payload).unwrap();
+        assert!(!result.delivered);
+        assert_eq!(result.error.as_deref(), Some("safe mode"));
+
+        let mut seen = Vec::new();
+        while let Ok(message) = events.try_recv() {
+            let event: serde_json::Value = serde_json::from_str(&message.payload).unwrap();
+            seen.push(event["event"].as_str().unwrap().to_string());
+        }
+        assert!(seen.contains(&"commands_dropped".to_string()), "events: {:?}", seen);
+        assert!(!seen.iter().any(|e| e == "command_sent" || e == "batch_start"), "events: {:?}", seen);
+    }
+
+    #[tokio::test]
+    async fn test_critical_command_retries_and_reports_delivery() {
+        let (bus, _receiver) = MessageBus::new();
+        let mut results = bus.subscribe(DELIVERY_RESULT_TOPIC.to_string()).await;
+        let scheduler = BleCommandScheduler::new()
+            .with_message_bus(bus)
+            .with_retry_policy(RetryPolicy { max_attempts: 2, initial_backoff: Duration::ZERO, ..RetryPolicy::default() });
+
+        // Never registered, so every write fails
+        let sent = scheduler.queue_command(QueuedCommand {
+            device_id: "unpaired_valve".to_string(),
+            command: create_control_command("unpaired_valve", "close", None),
+            priority: CommandPriority::Critical,
+            queued_at: Instant::now(),
+            max_age: None,
+            origin: CommandOrigin::new("test", AuditTrigger::Api),
+        }).await;
+        assert!(sent.is_err());
+
+        let message = results.try_recv().unwrap();
+        let result: DeliveryResult = serde_json::from_str(&message.payload).unwrap();
+        assert_eq!(result.device_id, "unpaired_valve");
+        assert_eq!(result.source, "ble_scheduler");
+        assert_eq!(result.attempts, 2);
+        assert!(!result.delivered);
+    }
+
     #[tokio::test]
     async fn test_priority_sorting() {
         let scheduler = BleCommandScheduler::new();
@@ -523,6 +680,7 @@ mod tests {
             priority: CommandPriority::Low,
             queued_at: Instant::now(),
             max_age: None,
+            origin: CommandOrigin::new("test", AuditTrigger::Api),
         }).await.unwrap();
 
         // Then high priority
@@ -532,6 +690,7 @@ mod tests {
             priority: CommandPriority::High,
             queued_at: Instant::now(),
             max_age: None,
+            origin: CommandOrigin::new("test", AuditTrigger::Api),
         }).await.unwrap();
 
         let queues = scheduler.command_queues.read().await;
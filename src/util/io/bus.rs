@@ -1,16 +1,28 @@
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
 use tokio::sync::{mpsc, RwLock};
 use std::collections::HashMap;
 use color_eyre::Result;
-use crate::log_debug;
+use crate::{log_debug, log_warn};
+use crate::runtime::audit::CommandOrigin;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BusMessage {
     pub topic: String,
     pub payload: String,
     pub source: String,
-    pub timestamp: u64,
+    /// Set at construction, then re-stamped by the bus when published.
+    pub timestamp: DateTime<Utc>,
+    /// Monotonically increasing per bus. Zero until the message is published.
+    #[serde(default)]
+    pub sequence: u64,
+    /// Who asked for a device command, so the transport audits it under them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<CommandOrigin>,
 }
 
 impl BusMessage {
@@ -19,22 +31,92 @@ impl BusMessage {
             topic,
             payload,
             source,
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
+            timestamp: Utc::now(),
+            sequence: 0,
+            origin: None,
         }
     }
+
+    /// Attribute a device command to the handler and trigger that asked for it
+    pub fn with_origin(mut self, origin: CommandOrigin) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// How long ago this message was stamped.
+    pub fn age(&self) -> Duration {
+        Utc::now() - self.timestamp
+    }
+
+    /// Ordering key for recorders and panels: the bus sequence, which a clock step or two
+    /// messages in the same millisecond can't reorder. The timestamp is only for display.
+    pub fn order_key(&self) -> u64 {
+        self.sequence
+    }
 }
 
 pub type BusReceiver = mpsc::UnboundedReceiver<BusMessage>;
 pub type BusSender = mpsc::UnboundedSender<BusMessage>;
+pub type BusPredicate = Arc<dyn Fn(&BusMessage) -> bool + Send + Sync>;
+
+/// Whether `topic` matches one of `scope`'s patterns: an exact topic, or a prefix ending in `*`.
+/// Shared by `ModuleCapabilities` and scoped bus handles.
+pub fn topic_in_scope(scope: &[String], topic: &str) -> bool {
+    scope.iter().any(|pattern| match pattern.strip_suffix('*') {
+        Some(prefix) => topic.starts_with(prefix),
+        None => pattern == topic,
+    })
+}
+
+/// A topic subscriber, optionally gated by a predicate evaluated before delivery
+#[derive(Clone)]
+struct Subscriber {
+    sender: BusSender,
+    predicate: Option<BusPredicate>,
+}
+
+impl Subscriber {
+    fn accepts(&self, message: &BusMessage) -> bool {
+        self.predicate.as_ref().map_or(true, |predicate| predicate(message))
+    }
+}
+
+// Manual Debug implementation since predicates are opaque closures
+impl std::fmt::Debug for Subscriber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subscriber")
+            .field("sender", &self.sender)
+            .field("filtered", &self.predicate.is_some())
+            .finish()
+    }
+}
+
+/// Running counters for one topic, see [`MessageBus::stats`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TopicStats {
+    /// Messages published since startup
+    pub published: u64,
+    /// Deliveries that failed because the subscriber's receiver was gone. The subscriber is then
+    /// forgotten, so each one that goes away counts once.
+    pub dropped: u64,
+    /// Subscribers still listening, as of the last publish or subscribe
+    pub subscribers: usize,
+}
 
 #[derive(Debug, Clone)]
 pub struct MessageBus {
     sender: BusSender,
     // Use Arc<RwLock> so the bus can be cloned and subscribers can be modified
-    subscribers: Arc<RwLock<HashMap<String, Vec<BusSender>>>>,
+    subscribers: Arc<RwLock<HashMap<String, Vec<Subscriber>>>>,
+    // (prefix, subscriber) for every topic starting with the prefix. A std mutex so tools can
+    // subscribe from key handling.
+    prefix_subscribers: Arc<Mutex<Vec<(String, Subscriber)>>>,
+    // Shared across clones so every handle hands out from the same sequence
+    next_sequence: Arc<AtomicU64>,
+    // Scopes a topic must fall inside for this handle to publish it; none means unrestricted
+    publish_scopes: Arc<Vec<Vec<String>>>,
+    // Per-topic counters, shared across clones. A std mutex so `stats` works from render code.
+    stats: Arc<Mutex<HashMap<String, TopicStats>>>,
 }
 
 impl MessageBus {
@@ -45,34 +127,141 @@ impl MessageBus {
             Self {
                 sender,
                 subscribers: Arc::new(RwLock::new(HashMap::new())),
+                prefix_subscribers: Arc::new(Mutex::new(Vec::new())),
+                next_sequence: Arc::new(AtomicU64::new(1)),
+                publish_scopes: Arc::new(Vec::new()),
+                stats: Arc::new(Mutex::new(HashMap::new())),
             },
             receiver,
         )
     }
 
-    pub async fn publish(&self, message: BusMessage) -> Result<()> {
+    /// A handle onto the same bus that refuses to publish outside `topics`.
+    /// Handed to modules that declare `capabilities.publish_topics`. Scoping an already scoped
+    /// handle narrows it: a topic must then be inside every scope.
+    pub fn with_publish_scope(&self, topics: Vec<String>) -> Self {
+        let mut publish_scopes = self.publish_scopes.as_ref().clone();
+        publish_scopes.push(topics);
+        Self {
+            publish_scopes: Arc::new(publish_scopes),
+            ..self.clone()
+        }
+    }
+
+    /// Err if this handle is scoped and `topic` falls outside any of its scopes
+    fn check_scope(&self, topic: &str, source: &str) -> Result<()> {
+        if self.publish_scopes.iter().all(|scope| topic_in_scope(scope, topic)) {
+            return Ok(());
+        }
+        log_warn!("🚫 Refused publish to '{}' from '{}': outside declared capabilities", topic, source);
+        Err(color_eyre::eyre::eyre!("Topic '{}' is outside the module's declared publish scope", topic))
+    }
+
+    /// Stamp a message with the publish time and the next sequence number
+    fn stamp(&self, message: &mut BusMessage) {
+        message.timestamp = Utc::now();
+        message.sequence = self.next_sequence.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub async fn publish(&self, mut message: BusMessage) -> Result<()> {
+        self.check_scope(&message.topic, &message.source)?;
+        self.stamp(&mut message);
+
         // Send to main receiver
         self.sender.send(message.clone())?;
 
         // Send to topic subscribers
         let subscribers = self.subscribers.read().await;
+        let mut dropped = 0;
+        let mut listening = 0;
+        let mut closed = false;
         if let Some(subs) = subscribers.get(&message.topic) {
             for subscriber in subs {
-                let _ = subscriber.send(message.clone());
+                if subscriber.accepts(&message) && subscriber.sender.send(message.clone()).is_err() {
+                    dropped += 1;
+                }
+                if subscriber.sender.is_closed() {
+                    closed = true;
+                } else {
+                    listening += 1;
+                }
             }
         }
+        drop(subscribers);
+
+        // Forget receivers that are gone, as subscribe_prefix does, so each is only counted once
+        if closed {
+            if let Some(subs) = self.subscribers.write().await.get_mut(&message.topic) {
+                subs.retain(|subscriber| !subscriber.sender.is_closed());
+            }
+        }
+
+        self.feed_taps(&message);
+
+        self.update_stats(&message.topic, |stats| {
+            stats.published += 1;
+            stats.dropped += dropped;
+            stats.subscribers = listening;
+        });
 
         Ok(())
     }
 
+    /// Copy `message` to the prefix taps for debugging tools; left out of the topic's counters
+    fn feed_taps(&self, message: &BusMessage) {
+        self.prefix_subscribers.lock().unwrap().retain(|(prefix, subscriber)| {
+            if message.topic.starts_with(prefix.as_str()) {
+                let _ = subscriber.sender.send(message.clone());
+            }
+            !subscriber.sender.is_closed()
+        });
+    }
+
+    /// Per-topic publish, drop and subscriber counts since startup, for diagnostics
+    pub fn stats(&self) -> HashMap<String, TopicStats> {
+        self.stats.lock().unwrap().clone()
+    }
+
+    fn update_stats(&self, topic: &str, update: impl FnOnce(&mut TopicStats)) {
+        let mut stats = self.stats.lock().unwrap();
+        match stats.get_mut(topic) {
+            Some(topic_stats) => update(topic_stats),
+            None => update(stats.entry(topic.to_string()).or_default()),
+        }
+    }
+
     pub async fn subscribe(&self, topic: String) -> BusReceiver {
+        self.add_subscriber(topic, None).await
+    }
+
+    /// Subscribe to a topic, but only receive messages matching `predicate`.
+    /// The predicate runs inside `publish`, so keep it cheap.
+    pub async fn subscribe_filtered<F>(&self, topic: String, predicate: F) -> BusReceiver
+    where
+        F: Fn(&BusMessage) -> bool + Send + Sync + 'static,
+    {
+        self.add_subscriber(topic, Some(Arc::new(predicate))).await
+    }
+
+    /// Receive every message whose topic starts with `prefix` (`""` for all of them), for
+    /// tools like the bus inspector. Dropping the receiver unsubscribes.
+    pub fn subscribe_prefix(&self, prefix: impl Into<String>) -> BusReceiver {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let mut prefix_subscribers = self.prefix_subscribers.lock().unwrap();
+        prefix_subscribers.retain(|(_, subscriber)| !subscriber.sender.is_closed());
+        prefix_subscribers.push((prefix.into(), Subscriber { sender, predicate: None }));
+        receiver
+    }
+
+    async fn add_subscriber(&self, topic: String, predicate: Option<BusPredicate>) -> BusReceiver {
         let (sender, receiver) = mpsc::unbounded_channel();
 
         let mut subscribers = self.subscribers.write().await;
-        subscribers
-            .entry(topic)
-            .or_insert_with(Vec::new)
-            .push(sender);
+        let subs = subscribers.entry(topic.clone()).or_insert_with(Vec::new);
+        subs.push(Subscriber { sender, predicate });
+
+        let listening = subs.iter().filter(|s| !s.sender.is_closed()).count();
+        self.update_stats(&topic, |stats| stats.subscribers = listening);
 
         receiver
     }
@@ -87,9 +276,13 @@ impl MessageBus {
     }
 
     pub fn send_command(&self, topic: String, command: String, source: String) -> Result<()> {
-        let message = BusMessage::new(topic, command, source);
-        // This needs to be sync, so just send to main receiver
-        self.sender.send(message)?;
+        self.check_scope(&topic, &source)?;
+        let mut message = BusMessage::new(topic, command, source);
+        self.stamp(&mut message);
+        self.update_stats(&message.topic, |stats| stats.published += 1);
+        // This needs to be sync, so just send to main receiver (and the taps, which are sync too)
+        self.sender.send(message.clone())?;
+        self.feed_taps(&message);
         Ok(())
     }
 
@@ -132,3 +325,150 @@ pub mod messages {
         BusMessage::new("llm_response".to_string(), query, source)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_publish_assigns_increasing_sequence() {
+        let (bus, mut receiver) = MessageBus::new();
+
+        for payload in ["first", "second", "third"] {
+            bus.publish(BusMessage::new(
+                "sensor_data".to_string(),
+                payload.to_string(),
+                "test".to_string(),
+            )).await.unwrap();
+        }
+
+        let first = receiver.recv().await.unwrap();
+        let mut second = receiver.recv().await.unwrap();
+        let third = receiver.recv().await.unwrap();
+
+        // A clock stepping back between publishes doesn't reorder them
+        second.timestamp = first.timestamp - Duration::seconds(30);
+
+        assert!(first.sequence > 0);
+        assert!(first.order_key() < second.order_key());
+        assert!(second.order_key() < third.order_key());
+    }
+
+    #[tokio::test]
+    async fn test_command_origin_travels_with_the_message() {
+        use crate::runtime::audit::{AuditTrigger, CommandOrigin};
+
+        let (bus, mut receiver) = MessageBus::new();
+        let origin = CommandOrigin::new("valve_control", AuditTrigger::ManualKey);
+        bus.publish(BusMessage::new(
+            "device_command".to_string(),
+            "{}".to_string(),
+            "valve_control".to_string(),
+        ).with_origin(origin.clone())).await.unwrap();
+
+        let received = receiver.recv().await.unwrap();
+        assert_eq!(received.origin.as_ref(), Some(&origin));
+
+        let json = serde_json::to_string(&received).unwrap();
+        assert!(json.contains("\"trigger\":\"manual_key\""), "{}", json);
+        let plain = serde_json::to_string(&BusMessage::new("t".to_string(), "p".to_string(), "s".to_string())).unwrap();
+        assert!(!plain.contains("origin"));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_filtered_only_delivers_matches() {
+        let (bus, _receiver) = MessageBus::new();
+        let mut all = bus.subscribe("a01".to_string()).await;
+        let mut filtered = bus
+            .subscribe_filtered("a01".to_string(), |msg| msg.source == "a01")
+            .await;
+
+        for source in ["other", "a01"] {
+            bus.publish(BusMessage::new(
+                "a01".to_string(),
+                "{}".to_string(),
+                source.to_string(),
+            )).await.unwrap();
+        }
+
+        assert_eq!(all.recv().await.unwrap().source, "other");
+        assert_eq!(all.recv().await.unwrap().source, "a01");
+        assert_eq!(filtered.recv().await.unwrap().source, "a01");
+        assert!(filtered.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stats_count_publishes_subscribers_and_drops() {
+        let (bus, _receiver) = MessageBus::new();
+        let _listening = bus.subscribe("a01".to_string()).await;
+        let gone = bus.subscribe("a01".to_string()).await;
+        drop(gone);
+
+        for _ in 0..3 {
+            bus.publish(BusMessage::new("a01".to_string(), "{}".to_string(), "test".to_string())).await.unwrap();
+        }
+
+        // The gone receiver fails one send, then is pruned
+        let stats = bus.stats();
+        assert_eq!(stats["a01"], TopicStats { published: 3, dropped: 1, subscribers: 1 });
+        assert_eq!(bus.subscribers.read().await["a01"].len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_prefix_receives_matching_topics() {
+        let (bus, _receiver) = MessageBus::new();
+        let mut valves = bus.subscribe_prefix("valve_");
+        let mut everything = bus.subscribe_prefix("");
+
+        for topic in ["valve_main", "a01", "valve_garden"] {
+            bus.publish(BusMessage::new(topic.to_string(), "{}".to_string(), "test".to_string())).await.unwrap();
+        }
+
+        assert_eq!(valves.recv().await.unwrap().topic, "valve_main");
+        assert_eq!(valves.recv().await.unwrap().topic, "valve_garden");
+        assert!(valves.try_recv().is_err());
+        for topic in ["valve_main", "a01", "valve_garden"] {
+            assert_eq!(everything.recv().await.unwrap().topic, topic);
+        }
+        assert_eq!(bus.stats()["a01"].subscribers, 0);
+    }
+
+    #[tokio::test]
+    async fn test_send_command_reaches_prefix_taps() {
+        let (bus, _receiver) = MessageBus::new();
+        let mut valves = bus.subscribe_prefix("valve_");
+
+        bus.send_command("valve_main".to_string(), "open".to_string(), "test".to_string()).unwrap();
+        bus.send_command("a01".to_string(), "read".to_string(), "test".to_string()).unwrap();
+
+        let tapped = valves.recv().await.unwrap();
+        assert_eq!((tapped.topic.as_str(), tapped.payload.as_str()), ("valve_main", "open"));
+        assert!(valves.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_scoped_bus_refuses_out_of_scope_topics() {
+        let (bus, mut receiver) = MessageBus::new();
+        let scoped = bus.with_publish_scope(vec!["valve_*".to_string()]);
+
+        assert!(scoped.publish(BusMessage::new(
+            "com_input".to_string(),
+            "open_gate".to_string(),
+            "valve".to_string(),
+        )).await.is_err());
+        scoped.publish(BusMessage::new(
+            "valve_main".to_string(),
+            "open".to_string(),
+            "valve".to_string(),
+        )).await.unwrap();
+
+        assert_eq!(receiver.recv().await.unwrap().topic, "valve_main");
+        assert!(receiver.try_recv().is_err());
+
+        // Narrowed again, only topics inside both scopes pass
+        let narrowed = scoped.with_publish_scope(vec!["valve_main".to_string(), "com_input".to_string()]);
+        assert!(narrowed.publish(BusMessage::new("valve_garden".to_string(), "open".to_string(), "valve".to_string())).await.is_err());
+        assert!(narrowed.publish(BusMessage::new("com_input".to_string(), "open".to_string(), "valve".to_string())).await.is_err());
+        narrowed.publish(BusMessage::new("valve_main".to_string(), "close".to_string(), "valve".to_string())).await.unwrap();
+    }
+}
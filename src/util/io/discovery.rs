@@ -3,8 +3,12 @@
 
 use color_eyre::Result;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
 use tokio::{
+    task::JoinHandle,
     time::{timeout, Duration},
     sync::RwLock
 };
@@ -18,13 +22,18 @@ use futures::stream::StreamExt;
 
 use crate::util::{
     database::Database,
+    fs::write_atomic,
     io::{
         bus::{MessageBus, BusMessage},
         serial::{SspMessage, SourceInfo, Transport, MessageType},
         ble_scheduler::{BleCommandScheduler, QueuedCommand, CommandPriority, extract_schedule_metadata},
+        heartbeat::HeartbeatMonitor,
+        ssp::ChecksumPolicy,
     }
 };
-use crate::module::strategies::overseer::database::OverseerDatabase;
+use crate::module::strategies::overseer::database::{KnownDevice, OverseerDatabase};
+use crate::runtime::poll_interval::{poll_interval, PollTask};
+use crate::runtime::audit::{AuditOutcome, CommandOrigin};
 use crate::{log_info, log_warn, log_error};
 
 // Survon BLE Service UUID (custom UUID for field units)
@@ -32,6 +41,12 @@ const SURVON_SERVICE_UUID: &str = "6e400001-b5a3-f393-e0a9-e50e24dcca9e";
 const SURVON_TX_CHAR_UUID: &str = "6e400002-b5a3-f393-e0a9-e50e24dcca9e"; // Write to device
 const SURVON_RX_CHAR_UUID: &str = "6e400003-b5a3-f393-e0a9-e50e24dcca9e"; // Notifications from device
 
+/// How long to scan for a device being reconnected that isn't in the discovered list
+const RECONNECT_SCAN_SECS: u64 = 5;
+
+/// Liveness passes between reloads of the trusted device list from the database
+const LIVENESS_RELOAD_PASSES: u32 = 10;
+
 // Compact SSP registration response (new format)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CompactRegistrationResponse {
@@ -115,6 +130,22 @@ struct DiscoveredDevice {
     rssi: i16,
 }
 
+/// A spawned task that's aborted when this is dropped or replaced
+#[derive(Debug)]
+struct AbortOnDrop(JoinHandle<()>);
+
+impl AbortOnDrop {
+    fn abort(&self) {
+        self.0.abort();
+    }
+}
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
 /// Manages BLE device discovery and registration
 #[derive(Debug, Clone)]
 pub struct DiscoveryManager {
@@ -125,6 +156,14 @@ pub struct DiscoveryManager {
     modules_path: std::path::PathBuf,
     database: Database,
     command_scheduler: Arc<BleCommandScheduler>,
+    /// Applied to every complete message from a field unit
+    checksum_policy: ChecksumPolicy,
+    /// The notification listener running for each connected MAC, so reconnecting replaces it
+    listeners: Arc<Mutex<HashMap<String, AbortOnDrop>>>,
+    /// MAC -> when it was last heard from (unix seconds), written out in one batch per liveness pass
+    heard_from: Arc<Mutex<HashMap<String, i64>>>,
+    /// Set when a heartbeat interval changes, so the next liveness pass reloads devices
+    devices_changed: Arc<AtomicBool>,
 }
 
 impl DiscoveryManager {
@@ -141,9 +180,19 @@ impl DiscoveryManager {
             modules_path,
             database,
             command_scheduler,
+            checksum_policy: ChecksumPolicy::from_env(),
+            listeners: Arc::new(Mutex::new(HashMap::new())),
+            heard_from: Arc::new(Mutex::new(HashMap::new())),
+            devices_changed: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// Check field unit messages' checksums according to `policy` instead of the `SSP_REQUIRE_CHECKSUM` default
+    pub fn with_checksum_policy(mut self, policy: ChecksumPolicy) -> Self {
+        self.checksum_policy = policy;
+        self
+    }
+
     /// Start the discovery service
     pub async fn start(self: Arc<Self>) -> Result<()> {
         log_info!("Starting BLE Discovery Manager (manual scan mode)");
@@ -166,6 +215,11 @@ impl DiscoveryManager {
         Ok(())
     }
 
+    /// Whether `start` found a BLE adapter to use
+    pub async fn has_adapter(&self) -> bool {
+        self.adapter.read().await.is_some()
+    }
+
     /// Perform a single scan cycle (10 seconds)
     /// Returns number of new Survon devices discovered
     pub async fn scan_once(&self, duration_secs: u64) -> Result<usize> {
@@ -273,15 +327,36 @@ impl DiscoveryManager {
         Ok(discovered_count)
     }
 
+    /// Queue `action` for `device_id`. The audit row is written under `origin` once the command is sent,
+    /// refused or dropped, so callers don't record it themselves.
     pub async fn send_command(
         &self,
         device_id: String,
         action: &str,
         payload: Option<serde_json::Value>,
         priority: CommandPriority,
+        origin: CommandOrigin,
     ) -> Result<()> {
         log_info!("🎯 Command request: {} -> {}", device_id, action);
 
+        // Safe mode freezes actuators for maintenance; nothing is queued to send later
+        if crate::runtime::safe_mode::is_safe_mode() {
+            log_warn!("🛑 Safe mode: refused '{}' for {}", action, device_id);
+            origin.record(&device_id, action, AuditOutcome::Refused, Some("safe mode".to_string()));
+            return Err(color_eyre::eyre::eyre!("Safe mode is on; commands are disabled"));
+        }
+
+        if crate::runtime::dry_run::is_dry_run() {
+            origin.record(&device_id, action, AuditOutcome::DryRun, None);
+            return crate::runtime::dry_run::report_intended_action(
+                &self.message_bus,
+                "ble_scheduler",
+                &device_id,
+                action,
+                payload.as_ref(),
+            ).await;
+        }
+
         let command = crate::util::io::ble_scheduler::create_control_command(
             &device_id,
             action,
@@ -294,6 +369,7 @@ impl DiscoveryManager {
             priority,
             queued_at: tokio::time::Instant::now(),
             max_age: Some(Duration::from_secs(300)),  // Commands expire after 5 minutes
+            origin,
         };
 
         self.command_scheduler.queue_command(queued_cmd).await?;
@@ -345,7 +421,7 @@ impl DiscoveryManager {
         // 🔑 NEW: Clone self for auto-registration
         let self_clone = Arc::new(self.clone());
 
-        tokio::spawn(async move {
+        let listener = tokio::spawn(async move {
             log_info!("📻 BLE listener task started for {}", addr_clone);
 
             let mut current_peripheral = peripheral_clone;
@@ -363,7 +439,7 @@ impl DiscoveryManager {
                         let keepalive_char = rx_char_clone.clone();
                         let keepalive_addr = addr_clone.clone();
 
-                        let keepalive_handle = tokio::spawn(async move {
+                        let keepalive_handle = AbortOnDrop(tokio::spawn(async move {
                             let mut iteration = 0;
                             loop {
                                 tokio::time::sleep(Duration::from_secs(10)).await;
@@ -381,7 +457,7 @@ impl DiscoveryManager {
                                     }
                                 }
                             }
-                        });
+                        }));
 
                         let mut buffer = String::new();
                         let mut last_chunk_time = std::time::Instant::now();
@@ -426,8 +502,21 @@ impl DiscoveryManager {
                                             continue;
                                         }
 
+                                        // Drop frames that fail their `*HH` checksum, or lack one when it's required
+                                        let message = match self_clone.checksum_policy.verify(message) {
+                                            Ok(frame) => frame,
+                                            Err(e) => {
+                                                log_warn!("⚠️ Rejected SSP frame: {} ({})", e, message);
+                                                continue;
+                                            }
+                                        };
+
                                         log_info!("✅ COMPLETE MESSAGE ({} bytes): {}", message.len(), message);
 
+                                        // Any complete message counts as a heartbeat; the liveness loop stores it
+                                        self_clone.heard_from.lock().unwrap()
+                                            .insert(addr_clone.clone(), chrono::Utc::now().timestamp());
+
                                         // 🔑 AUTO-REGISTRATION: First telemetry message triggers registration
                                         if !device_registered {
                                             if let Ok(json) = serde_json::from_str::<serde_json::Value>(&message) {
@@ -462,6 +551,10 @@ impl DiscoveryManager {
                                                         commands: Vec::new(),
                                                     };
 
+                                                    if let Err(e) = self_clone.database.link_device_id(&addr_clone, device_id) {
+                                                        log_warn!("Failed to link {} to {}: {}", addr_clone, device_id, e);
+                                                    }
+
                                                     if let Err(e) = self_clone.handle_registration(capabilities).await {
                                                         log_error!("Failed to register device: {}", e);
                                                     } else {
@@ -569,6 +662,7 @@ impl DiscoveryManager {
                 tokio::time::sleep(Duration::from_secs(2)).await;
             }
         });
+        self.track_listener(address.clone(), listener);
 
         // 🔑 KEY CHANGE: Return immediately, don't wait for registration
         log_info!("✅ Listener spawned for {}, will auto-register from telemetry", address);
@@ -576,6 +670,14 @@ impl DiscoveryManager {
         Ok(())
     }
 
+    /// Keep `listener` as the one for `address`, stopping any it replaces so a reconnect
+    /// doesn't leave two tasks publishing the same device's telemetry
+    fn track_listener(&self, address: String, listener: JoinHandle<()>) {
+        if self.listeners.lock().unwrap().insert(address.clone(), AbortOnDrop(listener)).is_some() {
+            log_info!("🔁 Replaced the previous listener for {}", address);
+        }
+    }
+
     fn extract_one_json_message(buffer: &str) -> Result<(String, String), String> {
         let start = buffer.find('{').ok_or("No JSON start found")?;
 
@@ -721,14 +823,85 @@ impl DiscoveryManager {
     }
 
     pub async fn start_maintenance_task(self: Arc<Self>) {
+        let liveness = self.clone();
         tokio::spawn(async move {
             loop {
-                tokio::time::sleep(Duration::from_secs(60)).await;
+                tokio::time::sleep(poll_interval(PollTask::Scheduler)).await;
 
                 // Prune stale schedules
                 self.command_scheduler.prune_stale_schedules().await;
             }
         });
+
+        tokio::spawn(async move {
+            liveness.run_liveness_checks().await;
+        });
+    }
+
+    /// Publish `device.offline` when a trusted device misses its heartbeat window,
+    /// and `device.online` when it is heard from again. Heartbeats since the last pass are written
+    /// in one batch; devices are reloaded every `LIVENESS_RELOAD_PASSES` passes or after an interval changes.
+    async fn run_liveness_checks(&self) {
+        let mut monitor = HeartbeatMonitor::from_env();
+        let mut devices: Vec<KnownDevice> = Vec::new();
+        let mut passes_since_reload = LIVENESS_RELOAD_PASSES;
+        loop {
+            tokio::time::sleep(poll_interval(PollTask::Liveness)).await;
+
+            let heard = std::mem::take(&mut *self.heard_from.lock().unwrap());
+            if !heard.is_empty() {
+                if let Err(e) = self.database.record_device_heartbeats(&heard) {
+                    log_warn!("Failed to record {} heartbeat(s): {}", heard.len(), e);
+                }
+            }
+
+            if self.devices_changed.swap(false, Ordering::Relaxed) || passes_since_reload >= LIVENESS_RELOAD_PASSES {
+                match self.database.get_all_known_devices() {
+                    Ok(all) => {
+                        devices = all.into_iter().filter(|d| d.is_trusted).collect();
+                        passes_since_reload = 0;
+                    }
+                    Err(e) => log_error!("Liveness check failed to load devices: {}", e),
+                }
+            }
+            passes_since_reload += 1;
+            for device in &mut devices {
+                if let Some(&seen_at) = heard.get(&device.mac_address) {
+                    device.last_seen = device.last_seen.max(seen_at);
+                }
+            }
+            let now = chrono::Utc::now().timestamp();
+
+            for event in monitor.check(&devices, now) {
+                if event.online {
+                    log_info!("💚 {} ({}) is back online", event.device_name, event.mac_address);
+                } else {
+                    log_warn!("💔 {} ({}) missed its {}s heartbeat window", event.device_name, event.mac_address, event.heartbeat_interval_secs);
+                }
+
+                let payload = match serde_json::to_string(&event) {
+                    Ok(payload) => payload,
+                    Err(e) => {
+                        log_error!("Failed to serialize liveness event: {}", e);
+                        continue;
+                    }
+                };
+                if let Err(e) = self.message_bus.publish(BusMessage::new(
+                    event.topic().to_string(),
+                    payload,
+                    "discovery_manager".to_string(),
+                )).await {
+                    log_error!("Failed to publish {}: {}", event.topic(), e);
+                }
+            }
+        }
+    }
+
+    /// Expected heartbeat interval for one device; `None` restores the default, `Some(0)` stops tracking it
+    pub fn set_heartbeat_interval(&self, mac_address: &str, interval_secs: Option<u64>) -> Result<()> {
+        self.database.set_heartbeat_interval(mac_address, interval_secs)?;
+        self.devices_changed.store(true, Ordering::Relaxed);
+        Ok(())
     }
 
     /// Handle successful registration
@@ -788,6 +961,22 @@ impl DiscoveryManager {
         Ok(())
     }
 
+    /// Re-establish the connection to a known device, scanning briefly if it hasn't been seen this session
+    pub async fn reconnect_device(&self, mac_address: &str) -> Result<()> {
+        log_info!("🔄 Reconnect requested for {}", mac_address);
+
+        if !self.discovered_devices.read().await.contains_key(mac_address) {
+            self.scan_once(RECONNECT_SCAN_SECS).await?;
+        }
+
+        let peripheral = self.discovered_devices.read().await
+            .get(mac_address)
+            .map(|d| d.peripheral.clone())
+            .ok_or_else(|| color_eyre::eyre::eyre!("Device {} is not in range", mac_address))?;
+
+        self.register_device(peripheral, mac_address.to_string()).await
+    }
+
     /// Untrust a device
     pub async fn untrust_device(&self, mac_address: &str) -> Result<()> {
         log_info!("Untrusting device: {}", mac_address);
@@ -802,60 +991,28 @@ impl DiscoveryManager {
 
     /// Generate module config.yml for the registered device
     async fn generate_module_config(&self, capabilities: &DeviceCapabilities) -> Result<()> {
-        let module_path = self.modules_path.join(&capabilities.device_id);
-        std::fs::create_dir_all(&module_path)?;
-
-        let config_path = module_path.join("config.yml");
-
         // Determine template based on device type and capabilities
         let template = self.select_template(capabilities);
 
-        // Generate bindings based on sensors
-        let mut bindings = serde_yaml::Mapping::new();
-
+        // A binding per sensor, plus the standard ones
+        let mut config = crate::module::ModuleConfig::builder()
+            .name(format!("{} ({})", capabilities.device_id, capabilities.device_type))
+            .module_type("monitoring")
+            .bus_topic(capabilities.device_id.clone())
+            .template(template);
         for sensor in &capabilities.sensors {
-            bindings.insert(
-                serde_yaml::Value::String(sensor.name.clone()),
-                serde_yaml::Value::Number(serde_yaml::Number::from(0)),
-            );
+            config = config.binding(sensor.name.clone(), 0);
         }
+        let config = config
+            .binding("device_id", capabilities.device_id.clone())
+            .binding("firmware_version", capabilities.firmware_version.clone())
+            .binding("is_blinkable", true)
+            .build()?;
 
-        // Add standard bindings
-        bindings.insert(
-            serde_yaml::Value::String("device_id".to_string()),
-            serde_yaml::Value::String(capabilities.device_id.clone()),
-        );
-        bindings.insert(
-            serde_yaml::Value::String("firmware_version".to_string()),
-            serde_yaml::Value::String(capabilities.firmware_version.clone()),
-        );
-        bindings.insert(
-            serde_yaml::Value::String("is_blinkable".to_string()),
-            serde_yaml::Value::Bool(true),
-        );
+        let module_path = self.modules_path.join(&capabilities.device_id);
+        std::fs::create_dir_all(&module_path)?;
 
-        // Build config structure
-        let mut config = serde_yaml::Mapping::new();
-        config.insert(
-            serde_yaml::Value::String("name".to_string()),
-            serde_yaml::Value::String(format!("{} ({})", capabilities.device_id, capabilities.device_type)),
-        );
-        config.insert(
-            serde_yaml::Value::String("module_type".to_string()),
-            serde_yaml::Value::String("monitoring".to_string()),
-        );
-        config.insert(
-            serde_yaml::Value::String("bus_topic".to_string()),
-            serde_yaml::Value::String(capabilities.device_id.clone()),
-        );
-        config.insert(
-            serde_yaml::Value::String("template".to_string()),
-            serde_yaml::Value::String(template),
-        );
-        config.insert(
-            serde_yaml::Value::String("bindings".to_string()),
-            serde_yaml::Value::Mapping(bindings),
-        );
+        let config_path = module_path.join("config.yml");
 
         // Generate sample SSP payloads in comments
         let sample_payload = self.generate_sample_payload(capabilities);
@@ -871,7 +1028,7 @@ impl DiscoveryManager {
             yaml_content
         );
 
-        std::fs::write(config_path, full_content)?;
+        write_atomic(&config_path, full_content)?;
 
         log_info!("Generated module config at: {}", module_path.display());
 
@@ -953,3 +1110,40 @@ impl DiscoveryManager {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Sets its flag when the task holding it is dropped
+    struct Dropped(Arc<AtomicBool>);
+
+    impl Drop for Dropped {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replaces_the_previous_listener() {
+        let (bus, _receiver) = MessageBus::new();
+        let manager = DiscoveryManager::new(bus, std::env::temp_dir(), crate::util::test_util::in_memory_database());
+
+        let first_stopped = Arc::new(AtomicBool::new(false));
+        let guard = Dropped(first_stopped.clone());
+        manager.track_listener("AA:BB".to_string(), tokio::spawn(async move {
+            let _guard = guard;
+            std::future::pending::<()>().await;
+        }));
+        manager.track_listener("AA:BB".to_string(), tokio::spawn(std::future::pending::<()>()));
+
+        for _ in 0..100 {
+            if first_stopped.load(Ordering::SeqCst) {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        assert!(first_stopped.load(Ordering::SeqCst));
+        assert_eq!(manager.listeners.lock().unwrap().len(), 1);
+    }
+}
@@ -7,6 +7,11 @@ use tokio::sync::mpsc;
 /// The frequency at which tick events are emitted.
 const TICK_FPS: f64 = 30.0;
 
+/// Time between ticks, which is also the shortest time between renders
+pub fn tick_interval() -> Duration {
+    Duration::from_secs_f64(1.0 / TICK_FPS)
+}
+
 /// Representation of all possible events.
 #[derive(Clone, Debug)]
 pub enum Event {
@@ -28,6 +33,8 @@ pub enum AppEvent {
     // System
     Quit,
     RefreshModules,
+    /// Take an uninstalled or archived wasteland module off the dashboard, by name
+    RemoveModule(String),
     ShowOverview,
     NoOp,
 
@@ -91,7 +98,7 @@ impl EventTask {
     async fn run(self) -> color_eyre::Result<()> {
 
         // let tick_rate = Duration::from_millis(50); // claude claims this is smoother..
-        let tick_rate = Duration::from_secs_f64(1.0 / TICK_FPS); // but this is working soo..
+        let tick_rate = tick_interval(); // but this is working soo..
 
 
         let mut reader = crossterm::event::EventStream::new();
@@ -0,0 +1,85 @@
+// src/util/io/event_topics.rs
+//! The bus topics the module managers, the messages window and outbound transports listen on.
+//! The built-in list can be replaced with `EVENT_TOPICS` or added to with `EXTRA_EVENT_TOPICS`
+//! (comma-separated), so a new device's topic doesn't need a rebuild.
+
+use std::sync::{LazyLock, RwLock};
+
+/// Topics listened on unless `EVENT_TOPICS` says otherwise
+pub const DEFAULT_EVENT_TOPICS: &[&str] = &[
+    "com_input",
+    "sensor_data",
+    "navigation",
+    "pressure_sensor",
+    "network",
+    "system_status",
+    "llm_response",
+    "control",
+    "monitoring",
+    "device_discovered",
+    "device_registration",
+    "device_registered",
+    // Device topics from before these were configurable; kept so existing setups still work
+    "arduino_sensor_001",
+    "a01",
+];
+
+static EVENT_TOPICS: LazyLock<RwLock<Vec<String>>> = LazyLock::new(|| RwLock::new(event_topics_from_env()));
+
+/// The topics in effect
+pub fn get_all_event_message_topics() -> Vec<String> {
+    EVENT_TOPICS.read().unwrap().clone()
+}
+
+/// Listen on `topics` from now on. Subscriptions already made are kept.
+pub fn set_event_topics(topics: Vec<String>) {
+    *EVENT_TOPICS.write().unwrap() = topics;
+}
+
+/// [`DEFAULT_EVENT_TOPICS`], or `EVENT_TOPICS`, followed by `EXTRA_EVENT_TOPICS`
+pub fn event_topics_from_env() -> Vec<String> {
+    resolve(
+        std::env::var("EVENT_TOPICS").ok().as_deref(),
+        std::env::var("EXTRA_EVENT_TOPICS").ok().as_deref(),
+    )
+}
+
+fn resolve(replace: Option<&str>, extra: Option<&str>) -> Vec<String> {
+    let base: Vec<String> = match replace.filter(|list| !list.trim().is_empty()) {
+        Some(list) => parse_list(list),
+        None => DEFAULT_EVENT_TOPICS.iter().map(|topic| topic.to_string()).collect(),
+    };
+
+    let mut topics = Vec::new();
+    for topic in base.into_iter().chain(extra.map(parse_list).unwrap_or_default()) {
+        if !topics.contains(&topic) {
+            topics.push(topic);
+        }
+    }
+    topics
+}
+
+fn parse_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|topic| !topic.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topics_default_replace_and_extend() {
+        assert_eq!(resolve(None, None).len(), DEFAULT_EVENT_TOPICS.len());
+
+        let extended = resolve(None, Some("well_pump, a01,,"));
+        assert_eq!(extended.len(), DEFAULT_EVENT_TOPICS.len() + 1);
+        assert_eq!(extended.last().map(String::as_str), Some("well_pump"));
+
+        assert_eq!(resolve(Some("sensor_data, well_pump"), Some("greenhouse")), ["sensor_data", "well_pump", "greenhouse"]);
+        assert_eq!(resolve(Some("  "), None).len(), DEFAULT_EVENT_TOPICS.len());
+    }
+}
@@ -0,0 +1,126 @@
+// src/util/io/heartbeat.rs
+//! Device liveness - flags trusted devices that miss their expected heartbeat window
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::module::strategies::overseer::database::KnownDevice;
+
+pub const DEVICE_OFFLINE_TOPIC: &str = "device.offline";
+pub const DEVICE_ONLINE_TOPIC: &str = "device.online";
+
+/// Expected heartbeat interval for devices without their own, overridable with `DEVICE_HEARTBEAT_SECS`
+const DEFAULT_HEARTBEAT_SECS: u64 = 120;
+
+/// Payload of `device.offline` / `device.online`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LivenessEvent {
+    pub mac_address: String,
+    pub device_name: String,
+    pub last_seen: i64,
+    pub heartbeat_interval_secs: u64,
+    pub online: bool,
+}
+
+impl LivenessEvent {
+    pub fn topic(&self) -> &'static str {
+        if self.online { DEVICE_ONLINE_TOPIC } else { DEVICE_OFFLINE_TOPIC }
+    }
+}
+
+/// Remembers which trusted devices are offline so each transition is reported once
+#[derive(Debug)]
+pub struct HeartbeatMonitor {
+    default_interval_secs: u64,
+    offline: HashSet<String>,
+}
+
+impl HeartbeatMonitor {
+    pub fn new(default_interval_secs: u64) -> Self {
+        Self { default_interval_secs, offline: HashSet::new() }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var("DEVICE_HEARTBEAT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_HEARTBEAT_SECS),
+        )
+    }
+
+    /// Compare each trusted device's `last_seen` against its interval at `now` (unix seconds)
+    /// and return the devices that went offline or came back since the last check
+    pub fn check(&mut self, devices: &[KnownDevice], now: i64) -> Vec<LivenessEvent> {
+        let mut events = Vec::new();
+
+        for device in devices.iter().filter(|d| d.is_trusted) {
+            let interval = device.heartbeat_interval_secs.unwrap_or(self.default_interval_secs);
+            if interval == 0 {
+                self.offline.remove(&device.mac_address);
+                continue;
+            }
+
+            let overdue = now - device.last_seen > interval as i64;
+            let was_offline = self.offline.contains(&device.mac_address);
+            if overdue == was_offline {
+                continue;
+            }
+
+            if overdue {
+                self.offline.insert(device.mac_address.clone());
+            } else {
+                self.offline.remove(&device.mac_address);
+            }
+
+            events.push(LivenessEvent {
+                mac_address: device.mac_address.clone(),
+                device_name: device.device_name.clone(),
+                last_seen: device.last_seen,
+                heartbeat_interval_secs: interval,
+                online: !overdue,
+            });
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(mac: &str, last_seen: i64, interval: Option<u64>) -> KnownDevice {
+        KnownDevice {
+            mac_address: mac.to_string(),
+            device_name: format!("unit {}", mac),
+            device_type: None,
+            firmware_version: None,
+            first_seen: 0,
+            last_seen,
+            is_trusted: true,
+            rssi: None,
+            heartbeat_interval_secs: interval,
+            device_id: None,
+            custom_name: false,
+        }
+    }
+
+    #[test]
+    fn test_reports_each_transition_once() {
+        let mut monitor = HeartbeatMonitor::new(60);
+
+        let quiet = [device("aa", 1000, None), device("bb", 1000, Some(600)), device("cc", 0, Some(0))];
+        let events = monitor.check(&quiet, 1100);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].mac_address, "aa");
+        assert_eq!(events[0].topic(), DEVICE_OFFLINE_TOPIC);
+
+        assert!(monitor.check(&quiet, 1110).is_empty());
+
+        let back = [device("aa", 1105, None)];
+        let events = monitor.check(&back, 1110);
+        assert_eq!(events.len(), 1);
+        assert!(events[0].online);
+    }
+}
@@ -0,0 +1,138 @@
+// src/util/io/retry.rs
+//! Retry policy and delivery reporting for outbound transport sends
+
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::time::Duration;
+
+use crate::log_warn;
+
+/// Topic every routed outbound message reports its [`DeliveryResult`] on
+pub const DELIVERY_RESULT_TOPIC: &str = "delivery_result";
+
+/// How often and how patiently a failed send is retried
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Total tries, including the first; 1 means no retries
+    pub max_attempts: u32,
+    /// Wait before the first retry, doubled for each one after
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Fire-and-forget: one try, no retries
+    pub fn none() -> Self {
+        Self { max_attempts: 1, ..Self::default() }
+    }
+
+    /// The default, adjusted by `TRANSPORT_RETRY_ATTEMPTS` and `TRANSPORT_RETRY_BACKOFF_MS`
+    pub fn from_env() -> Self {
+        let mut policy = Self::default();
+        if let Some(attempts) = std::env::var("TRANSPORT_RETRY_ATTEMPTS").ok().and_then(|v| v.parse().ok()) {
+            policy.max_attempts = attempts;
+        }
+        if let Some(ms) = std::env::var("TRANSPORT_RETRY_BACKOFF_MS").ok().and_then(|v| v.parse().ok()) {
+            policy.initial_backoff = Duration::from_millis(ms);
+        }
+        policy
+    }
+
+    /// Wait before retry number `retry` (1-based)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+
+    /// Run `send` until it succeeds or the attempts run out, backing off in between.
+    /// Returns the number of attempts made along with the final outcome.
+    pub async fn run<T, F, Fut>(&self, mut send: F) -> (u32, color_eyre::Result<T>)
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = color_eyre::Result<T>>,
+    {
+        let max_attempts = self.max_attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            match send().await {
+                Ok(value) => return (attempt, Ok(value)),
+                Err(e) if attempt >= max_attempts => return (attempt, Err(e)),
+                Err(e) => {
+                    let wait = self.backoff(attempt);
+                    log_warn!("Send attempt {}/{} failed: {}; retrying in {:?}", attempt, max_attempts, e, wait);
+                    tokio::time::sleep(wait).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Outcome of delivering one outbound bus message, published on [`DELIVERY_RESULT_TOPIC`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeliveryResult {
+    pub device_id: String,
+    /// Topic and source of the bus message that was sent, so the sender can find its own results
+    pub topic: String,
+    pub source: String,
+    pub sequence: u64,
+    pub delivered: bool,
+    pub attempts: u32,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(300),
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(300));
+    }
+
+    #[tokio::test]
+    async fn test_run_retries_until_attempts_run_out() {
+        let policy = RetryPolicy { max_attempts: 3, initial_backoff: Duration::ZERO, ..RetryPolicy::default() };
+        let calls = Cell::new(0);
+
+        let (attempts, result) = policy
+            .run(|| {
+                calls.set(calls.get() + 1);
+                async { Err::<(), _>(color_eyre::eyre::eyre!("port busy")) }
+            })
+            .await;
+        assert_eq!((attempts, calls.get()), (3, 3));
+        assert!(result.is_err());
+
+        calls.set(0);
+        let (attempts, result) = policy
+            .run(|| {
+                calls.set(calls.get() + 1);
+                let ok = calls.get() == 2;
+                async move { if ok { Ok(()) } else { Err(color_eyre::eyre::eyre!("port busy")) } }
+            })
+            .await;
+        assert_eq!(attempts, 2);
+        assert!(result.is_ok());
+    }
+}
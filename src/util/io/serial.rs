@@ -174,7 +174,7 @@ impl SspMessage {
             protocol: "ssp/1.0".to_string(),
             msg_type: MessageType::Command, // or infer from topic
             topic: bus_msg.topic.clone(),
-            timestamp: bus_msg.timestamp,
+            timestamp: bus_msg.timestamp.timestamp() as u64,
             source: SourceInfo {
                 id: bus_msg.source.clone(),
                 transport,
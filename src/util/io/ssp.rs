@@ -0,0 +1,256 @@
+// src/util/io/ssp.rs
+//! Decoding of SSP telemetry, the compact wire format sensors and valves report in.
+//!
+//! A frame is a JSON object, either the full envelope
+//! `{"p":"ssp/1.0","t":"tel","i":"a01","s":1712345678,"d":{"a":72.5,"b":45,"c":335},"m":{...}}`
+//! or just the data object `{"a":72.5,"b":45,"c":335}` as the bus carries it. `a` and `b` are
+//! readings, `c` is an integer count, and any other numeric keys are extended values. `m` holds
+//! schedule metadata.
+//!
+//! A frame may end in an NMEA-style checksum, `*` and two hex digits: the XOR of every byte
+//! before the `*`. Frames that carry one are rejected when it doesn't match. Whether frames
+//! without one are accepted is up to the link's [`ChecksumPolicy`], and every frame a policy
+//! checks is counted in [`frame_stats`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde_json::{Map, Value};
+
+/// Envelope fields, never readings
+const ENVELOPE_KEYS: &[&str] = &["p", "t", "i", "s", "m"];
+
+static ACCEPTED: AtomicU64 = AtomicU64::new(0);
+static CHECKSUM_FAILURES: AtomicU64 = AtomicU64::new(0);
+static MISSING_CHECKSUMS: AtomicU64 = AtomicU64::new(0);
+
+/// Readings decoded from one frame; keys the frame didn't carry are `None`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SspFrame {
+    pub a: Option<f64>,
+    pub b: Option<f64>,
+    pub c: Option<i64>,
+    /// Numeric data keys beyond `a`/`b`/`c`
+    pub extended: BTreeMap<String, f64>,
+    /// Schedule metadata (`m`), when the frame is a full envelope that carries it
+    pub schedule: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SspError {
+    /// Nothing, or JSON that stops partway through
+    Truncated,
+    /// Not a JSON object, or invalid JSON
+    Malformed(String),
+    /// The `*HH` suffix doesn't match the frame
+    ChecksumMismatch { expected: u8, actual: u8 },
+    /// No `*HH` suffix on a link that requires one
+    MissingChecksum,
+    /// A data key holds something other than the number it should
+    BadValue { key: String, found: String },
+}
+
+impl fmt::Display for SspError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SspError::Truncated => write!(f, "truncated SSP frame"),
+            SspError::Malformed(e) => write!(f, "malformed SSP frame: {}", e),
+            SspError::ChecksumMismatch { expected, actual } => {
+                write!(f, "SSP checksum mismatch: frame says {:02X}, computed {:02X}", expected, actual)
+            }
+            SspError::MissingChecksum => write!(f, "SSP frame has no checksum"),
+            SspError::BadValue { key, found } => write!(f, "SSP value '{}' is not a number: {}", key, found),
+        }
+    }
+}
+
+impl std::error::Error for SspError {}
+
+/// XOR of every byte, as the `*HH` suffix carries it
+pub fn checksum(text: &str) -> u8 {
+    text.bytes().fold(0, |sum, byte| sum ^ byte)
+}
+
+/// `line` split into its body and the `*HH` checksum it ends with, if any
+fn split_checksum(line: &str) -> (&str, Option<u8>) {
+    let line = line.trim();
+    // A `*` inside a JSON string isn't a checksum marker; the suffix follows the closing brace
+    line.rsplit_once('*')
+        .filter(|(body, suffix)| body.ends_with('}') && suffix.len() == 2)
+        .and_then(|(body, suffix)| Some((body, Some(u8::from_str_radix(suffix, 16).ok()?))))
+        .unwrap_or((line, None))
+}
+
+/// `line` without its `*HH` checksum suffix, which must match if present
+pub fn verify_checksum(line: &str) -> Result<&str, SspError> {
+    let (body, expected) = split_checksum(line);
+    let actual = checksum(body);
+    match expected {
+        Some(expected) if expected != actual => Err(SspError::ChecksumMismatch { expected, actual }),
+        _ => Ok(body),
+    }
+}
+
+/// Whether a link accepts frames that carry no checksum
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ChecksumPolicy {
+    pub require: bool,
+}
+
+impl ChecksumPolicy {
+    /// Frames must carry a checksum
+    pub fn required() -> Self {
+        Self { require: true }
+    }
+
+    /// Accepts unsigned frames unless `SSP_REQUIRE_CHECKSUM` is `1` or `true`
+    pub fn from_env() -> Self {
+        let require = std::env::var("SSP_REQUIRE_CHECKSUM")
+            .is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
+        Self { require }
+    }
+
+    /// [`verify_checksum`], also rejecting unsigned frames when a checksum is required,
+    /// and counting the outcome in [`frame_stats`]
+    pub fn verify<'a>(&self, line: &'a str) -> Result<&'a str, SspError> {
+        let result = match split_checksum(line) {
+            (_, None) if self.require => Err(SspError::MissingChecksum),
+            _ => verify_checksum(line),
+        };
+        let counter = match &result {
+            Ok(_) => &ACCEPTED,
+            Err(SspError::MissingChecksum) => &MISSING_CHECKSUMS,
+            Err(_) => &CHECKSUM_FAILURES,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        result
+    }
+}
+
+/// Frames checked by any [`ChecksumPolicy`] since startup
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameStats {
+    pub accepted: u64,
+    pub checksum_failures: u64,
+    /// Unsigned frames dropped because a checksum was required
+    pub missing_checksums: u64,
+}
+
+impl FrameStats {
+    pub fn rejected(&self) -> u64 {
+        self.checksum_failures + self.missing_checksums
+    }
+}
+
+pub fn frame_stats() -> FrameStats {
+    FrameStats {
+        accepted: ACCEPTED.load(Ordering::Relaxed),
+        checksum_failures: CHECKSUM_FAILURES.load(Ordering::Relaxed),
+        missing_checksums: MISSING_CHECKSUMS.load(Ordering::Relaxed),
+    }
+}
+
+/// Decode a telemetry frame, full envelope or bare data object, checking its checksum if it has one
+pub fn decode(payload: &str) -> Result<SspFrame, SspError> {
+    let body = verify_checksum(payload)?;
+    if body.is_empty() {
+        return Err(SspError::Truncated);
+    }
+
+    let value: Value = serde_json::from_str(body).map_err(|e| match e.is_eof() {
+        true => SspError::Truncated,
+        false => SspError::Malformed(e.to_string()),
+    })?;
+    let Value::Object(object) = value else {
+        return Err(SspError::Malformed(format!("expected an object, got {}", value)));
+    };
+
+    let data = match object.get("d") {
+        Some(Value::Object(data)) => data,
+        Some(other) => return Err(SspError::Malformed(format!("'d' is not an object: {}", other))),
+        None => &object,
+    };
+
+    let mut frame = SspFrame {
+        a: number(data, "a")?,
+        b: number(data, "b")?,
+        c: number(data, "c")?.map(|c| c as i64),
+        extended: BTreeMap::new(),
+        schedule: object.get("m").cloned(),
+    };
+
+    // Without a `d` object the data shares the top level with envelope fields like the `s` timestamp
+    let bare = !object.contains_key("d");
+    for (key, value) in data {
+        if matches!(key.as_str(), "a" | "b" | "c") || (bare && ENVELOPE_KEYS.contains(&key.as_str())) {
+            continue;
+        }
+        if let Some(value) = value.as_f64() {
+            frame.extended.insert(key.clone(), value);
+        }
+    }
+
+    Ok(frame)
+}
+
+fn number(data: &Map<String, Value>, key: &str) -> Result<Option<f64>, SspError> {
+    match data.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value
+            .as_f64()
+            .map(Some)
+            .ok_or_else(|| SspError::BadValue { key: key.to_string(), found: value.to_string() }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decodes_envelopes_and_data_objects() {
+        let envelope = r#"{"p":"ssp/1.0","t":"tel","i":"a01","s":5,"d":{"a":72.5,"b":45,"c":335,"e":1.5},"m":{"mode":"data"}}"#;
+        let frame = decode(envelope).unwrap();
+        assert_eq!((frame.a, frame.b, frame.c), (Some(72.5), Some(45.0), Some(335)));
+        assert_eq!(frame.extended, BTreeMap::from([("e".to_string(), 1.5)]));
+        assert_eq!(frame.schedule, Some(serde_json::json!({"mode": "data"})));
+
+        let frame = decode(r#"{"a":1,"c":2}"#).unwrap();
+        assert_eq!((frame.a, frame.b, frame.c), (Some(1.0), None, Some(2)));
+    }
+
+    #[test]
+    fn test_rejects_bad_frames() {
+        let body = r#"{"a":1,"b":2}"#;
+        let signed = format!("{}*{:02X}", body, checksum(body));
+        assert_eq!(decode(&signed).unwrap().b, Some(2.0));
+
+        let tampered = signed.replace("\"b\":2", "\"b\":3");
+        assert!(matches!(decode(&tampered), Err(SspError::ChecksumMismatch { .. })));
+        assert_eq!(decode(r#"{"a":1,"b""#), Err(SspError::Truncated));
+        assert_eq!(decode(""), Err(SspError::Truncated));
+        assert!(matches!(decode("[1,2]"), Err(SspError::Malformed(_))));
+        assert!(matches!(decode(r#"{"a":"hot"}"#), Err(SspError::BadValue { .. })));
+        // A `*` inside a string is just text
+        assert_eq!(decode(r#"{"a":1,"note":"5*2"}"#).unwrap().a, Some(1.0));
+    }
+
+    #[test]
+    fn test_policy_decides_on_unsigned_frames_and_counts() {
+        let body = r#"{"a":1}"#;
+        let signed = format!("{}*{:02X}", body, checksum(body));
+        let before = frame_stats();
+
+        assert_eq!(ChecksumPolicy::default().verify(body), Ok(body));
+        assert_eq!(ChecksumPolicy::required().verify(&signed), Ok(body));
+        assert_eq!(ChecksumPolicy::required().verify(body), Err(SspError::MissingChecksum));
+        assert!(ChecksumPolicy::default().verify(&format!("{}*00", body)).is_err());
+
+        // Other tests verify frames concurrently, so only lower bounds hold
+        let after = frame_stats();
+        assert!(after.accepted >= before.accepted + 2);
+        assert!(after.missing_checksums > before.missing_checksums);
+        assert!(after.checksum_failures > before.checksum_failures);
+    }
+}
@@ -3,19 +3,40 @@
 
 use color_eyre::Result;
 use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, RwLock};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio_serial::SerialPortBuilderExt;
-use std::time::{Duration};
 
 use crate::util::io::{
     get_all_event_message_topics,
     bus::{BusMessage, BusReceiver, MessageBus},
+    retry::{DeliveryResult, RetryPolicy, DELIVERY_RESULT_TOPIC},
     serial::{SspMessage, SourceInfo, Transport, MessageType},
+    ssp::ChecksumPolicy,
 };
+use crate::runtime::poll_interval::{poll_interval, PollTask};
+use crate::runtime::audit::{AuditOutcome, AuditTrigger, CommandOrigin};
+use crate::runtime::safe_mode::is_safe_mode;
+use crate::runtime::watchdog::{self, Heartbeat};
 use crate::{log_info, log_warn, log_error};
 
+/// Topic for commands addressed to one device (by `device_id`, `target` or `i` in the payload),
+/// always forwarded to whichever transport last heard from it
+pub const DEVICE_COMMAND_TOPIC: &str = "device_command";
+
+/// Watchdog name of the outbound message handler
+const OUTBOUND_TASK: &str = "transport_outbound";
+/// Watchdog names of serial port listeners, followed by the port path
+const SERIAL_TASK_PREFIX: &str = "transport_serial_";
+
+/// A serial port the manager has listened on, and whether it's still attached
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortState {
+    pub path: String,
+    pub attached: bool,
+}
+
 /// Manages all transport connections and message routing
 #[derive(Clone)]
 pub struct TransportManager {
@@ -26,18 +47,44 @@ pub struct TransportManager {
     /// Topics that should be forwarded to external devices
     outbound_topics: Arc<RwLock<Vec<String>>>,
     known_ports: Arc<RwLock<HashSet<String>>>,
+    /// Applied to every routed outbound send
+    retry_policy: RetryPolicy,
+    /// Applied to every inbound serial frame
+    checksum_policy: ChecksumPolicy,
+    /// Target device -> its send queue, drained in order by one task per device
+    device_queues: Arc<Mutex<HashMap<String, mpsc::UnboundedSender<BusMessage>>>>,
 }
 
 impl TransportManager {
     pub fn new(message_bus: MessageBus) -> Self {
+        let mut outbound_topics = get_all_event_message_topics();
+        if !outbound_topics.iter().any(|topic| topic == DEVICE_COMMAND_TOPIC) {
+            outbound_topics.push(DEVICE_COMMAND_TOPIC.to_string());
+        }
+
         Self {
             routing_table: Arc::new(RwLock::new(HashMap::new())),
             message_bus,
-            outbound_topics: Arc::new(RwLock::new(get_all_event_message_topics())),
+            outbound_topics: Arc::new(RwLock::new(outbound_topics)),
             known_ports: Arc::new(RwLock::new(HashSet::new())),
+            retry_policy: RetryPolicy::from_env(),
+            checksum_policy: ChecksumPolicy::from_env(),
+            device_queues: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
+    /// Retry failed outbound sends according to `policy` instead of the `TRANSPORT_RETRY_*` default
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Check inbound serial frames' checksums according to `policy` instead of the `SSP_REQUIRE_CHECKSUM` default
+    pub fn with_checksum_policy(mut self, policy: ChecksumPolicy) -> Self {
+        self.checksum_policy = policy;
+        self
+    }
+
     /// Add a topic that should be forwarded to external devices
     pub async fn add_outbound_topic(&self, topic: String) {
         let mut topics = self.outbound_topics.write().await;
@@ -46,26 +93,44 @@ impl TransportManager {
         }
     }
 
+    /// Every port a listener was started on, sorted by path
+    pub async fn port_states(&self) -> Vec<PortState> {
+        let attached = self.detect_usb_ports();
+        let mut states: Vec<PortState> = self.known_ports
+            .read()
+            .await
+            .iter()
+            .map(|path| PortState { path: path.clone(), attached: attached.contains(path) })
+            .collect();
+        states.sort_by(|a, b| a.path.cmp(&b.path));
+        states
+    }
+
+    /// Devices heard from on any transport, and so reachable for outbound messages
+    pub async fn routed_device_count(&self) -> usize {
+        self.routing_table.read().await.len()
+    }
+
     /// Start the transport manager tasks
     pub async fn start(self) -> Result<()> {
         log_info!("Starting Transport Manager");
 
-        // Subscribe to outbound topics
-        let outbound_topics = self.outbound_topics.read().await.clone();
-        let mut receivers = Vec::new();
-
-        for topic in &outbound_topics {
-            let receiver = self.message_bus.subscribe(topic.clone()).await;
-            receivers.push(receiver);
-            log_info!("Transport Manager subscribed to outbound topic: {}", topic);
-        }
-
-        // Clone for the outbound handler
-        let manager_clone = self.clone();
+        // Outbound message handler, resubscribed whenever the watchdog restarts it
+        let manager = self.clone();
+        watchdog::supervise(OUTBOUND_TASK, move |heartbeat| {
+            let manager = manager.clone();
+            async move {
+                let outbound_topics = manager.outbound_topics.read().await.clone();
+                let mut receivers = Vec::new();
+
+                for topic in &outbound_topics {
+                    let receiver = manager.message_bus.subscribe(topic.clone()).await;
+                    receivers.push(receiver);
+                    log_info!("Transport Manager subscribed to outbound topic: {}", topic);
+                }
 
-        // Spawn outbound message handler
-        tokio::spawn(async move {
-            manager_clone.handle_outbound_messages(receivers).await;
+                manager.handle_outbound_messages(receivers, heartbeat).await;
+            }
         });
 
         // Start listening on available transports
@@ -76,7 +141,7 @@ impl TransportManager {
             let manager_clone = self.clone();
             tokio::spawn(async move {
                 loop {
-                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    tokio::time::sleep(poll_interval(PollTask::Ports)).await;
                     manager_clone.check_for_new_ports().await;
                 }
             });
@@ -91,38 +156,66 @@ impl TransportManager {
     }
 
     /// Handle messages from the bus that need to be sent to external devices
-    async fn handle_outbound_messages(&self, mut receivers: Vec<BusReceiver>) {
+    async fn handle_outbound_messages(&self, mut receivers: Vec<BusReceiver>, heartbeat: Heartbeat) {
         log_info!("Outbound message handler started");
 
         loop {
+            heartbeat.beat();
+
             // Poll all receivers
             for receiver in &mut receivers {
                 while let Ok(bus_msg) = receiver.try_recv() {
                     log_info!("Outbound message on topic '{}': {}", bus_msg.topic, bus_msg.payload);
 
-                    // Parse payload to determine target device
-                    if let Err(e) = self.route_outbound_message(&bus_msg).await {
-                        log_error!("Failed to route outbound message: {}", e);
+                    // Queued per device: a dead device can't hold up the rest, and a retried
+                    // command still reaches its device before the ones published after it
+                    let device_id = target_device(&bus_msg.payload);
+                    if let Err(mpsc::error::SendError(bus_msg)) = self.device_queue(&device_id).send(bus_msg) {
+                        log_error!("Send queue for '{}' is gone; dropping message on '{}'", device_id, bus_msg.topic);
                     }
                 }
             }
 
             // Small delay to avoid busy-waiting
-            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+            tokio::time::sleep(poll_interval(PollTask::Outbound)).await;
         }
     }
 
-    /// Route an outbound message to the appropriate device
+    /// The send queue for `device_id`, starting its task on first use
+    fn device_queue(&self, device_id: &str) -> mpsc::UnboundedSender<BusMessage> {
+        let mut queues = self.device_queues.lock().unwrap();
+        if let Some(queue) = queues.get(device_id).filter(|queue| !queue.is_closed()) {
+            return queue.clone();
+        }
+
+        let (queue, mut pending) = mpsc::unbounded_channel::<BusMessage>();
+        let manager = self.clone();
+        tokio::spawn(async move {
+            while let Some(bus_msg) = pending.recv().await {
+                if let Err(e) = manager.route_outbound_message(&bus_msg).await {
+                    log_error!("Failed to route outbound message: {}", e);
+                }
+            }
+        });
+        queues.insert(device_id.to_string(), queue.clone());
+        queue
+    }
+
+    /// Route an outbound message to the appropriate device, retrying per the retry policy
+    /// and reporting the outcome on `delivery_result`
     async fn route_outbound_message(&self, bus_msg: &BusMessage) -> Result<()> {
-        // Try to extract target device from payload
-        let target_device_id = self.extract_target_device(&bus_msg.payload).await?;
+        let target_device_id = target_device(&bus_msg.payload);
+
+        let target_source = {
+            let routing_table = self.routing_table.read().await;
 
-        let routing_table = self.routing_table.read().await;
+            log_info!("target_device_id: {:?}", target_device_id);
+            log_info!("Outbound message routing table: {:?}", routing_table);
 
-        log_info!("target_device_id: {:?}", target_device_id);
-        log_info!("Outbound message routing table: {:?}", routing_table);
+            routing_table.get(&target_device_id).cloned()
+        };
 
-        if let Some(target_source) = routing_table.get(&target_device_id) {
+        if let Some(target_source) = target_source {
             log_info!("Routing message to device '{}' via {:?}", target_device_id, target_source.transport);
 
             // Convert to SSP format
@@ -132,8 +225,53 @@ impl TransportManager {
                 target_source.address.clone(),
             );
 
-            // Send via appropriate transport
-            self.send_via_transport(&ssp_msg, target_source).await?;
+            // Safe mode freezes actuators only; registration replies, pings and other traffic still go out
+            let actuation = is_actuation(bus_msg);
+            let refused = actuation && is_safe_mode();
+            let (attempts, outcome) = if refused {
+                log_warn!("🛑 Safe mode: not forwarding '{}' to {}", bus_msg.topic, target_device_id);
+                (0, Err(color_eyre::eyre::eyre!("Safe mode is on; commands are disabled")))
+            } else {
+                self.retry_policy
+                    .run(|| self.send_via_transport(&ssp_msg, &target_source))
+                    .await
+            };
+
+            if actuation {
+                let audit_outcome = match &outcome {
+                    Ok(()) => AuditOutcome::Sent,
+                    Err(_) if refused => AuditOutcome::Refused,
+                    Err(_) => AuditOutcome::Failed,
+                };
+                // Audited under whoever asked for it; a bare bus command is the transport's own
+                bus_msg.origin.clone()
+                    .unwrap_or_else(|| CommandOrigin::new("transport_manager", AuditTrigger::BusCommand))
+                    .record(
+                        &target_device_id,
+                        &outbound_action(bus_msg),
+                        audit_outcome,
+                        outcome.as_ref().err().map(|e| e.to_string()),
+                    );
+            }
+
+            let result = DeliveryResult {
+                device_id: target_device_id.clone(),
+                topic: bus_msg.topic.clone(),
+                source: bus_msg.source.clone(),
+                sequence: bus_msg.sequence,
+                delivered: outcome.is_ok(),
+                attempts,
+                error: outcome.as_ref().err().map(|e| e.to_string()),
+            };
+            self.message_bus.publish(BusMessage::new(
+                DELIVERY_RESULT_TOPIC.to_string(),
+                serde_json::to_string(&result)?,
+                "transport_manager".to_string(),
+            )).await?;
+
+            if let Err(e) = outcome {
+                return Err(e.wrap_err(format!("Delivery to '{}' failed after {} attempt(s)", target_device_id, attempts)));
+            }
         } else {
             log_warn!("No routing info for device '{}', broadcasting to all transports", target_device_id);
         }
@@ -141,22 +279,6 @@ impl TransportManager {
         Ok(())
     }
 
-    /// Extract target device ID from message payload
-    async fn extract_target_device(&self, payload: &str) -> Result<String> {
-        // Try to parse as JSON and look for device_id or target fields
-        if let Ok(json) = serde_json::from_str::<serde_json::Value>(payload) {
-            if let Some(device_id) = json.get("device_id").and_then(|v| v.as_str()) {
-                return Ok(device_id.to_string());
-            }
-            if let Some(target) = json.get("target").and_then(|v| v.as_str()) {
-                return Ok(target.to_string());
-            }
-        }
-
-        // Default: use payload topic or generic broadcast
-        Ok("broadcast".to_string())
-    }
-
     /// Send message via the specified transport
     async fn send_via_transport(&self, ssp_msg: &SspMessage, target: &SourceInfo) -> Result<()> {
         let wire_format = ssp_msg.to_wire();
@@ -206,14 +328,7 @@ impl TransportManager {
             log_info!("  - {}", port);
         }
 
-        for port_path in ports {
-            let manager = self.clone();
-            tokio::spawn(async move {
-                if let Err(e) = manager.listen_serial_port(port_path.clone()).await {
-                    log_error!("Serial listener error on {}: {}", port_path, e);
-                }
-            });
-        }
+        self.ensure_port_listeners().await;
 
         Ok(())
     }
@@ -269,20 +384,35 @@ impl TransportManager {
             // Claim it first — this line is the entire fix
             known.insert(port.clone());
 
-            let manager = self.clone();
-            let port_clone = port.clone();
-            tokio::spawn(async move {
-                let _ = manager.listen_serial_port(port_clone).await;
-            });
+            self.supervise_port(port);
         }
     }
 
+    /// Keep a listener on `port_path` for as long as the runtime runs, reopening the port whenever it's
+    /// attached again. Supervised, so a listener that panics or wedges mid-frame is restarted.
+    fn supervise_port(&self, port_path: String) {
+        let manager = self.clone();
+        watchdog::supervise(&format!("{}{}", SERIAL_TASK_PREFIX, port_path), move |heartbeat| {
+            let (manager, port_path) = (manager.clone(), port_path.clone());
+            async move {
+                loop {
+                    if manager.detect_usb_ports().contains(&port_path) {
+                        if let Err(e) = manager.listen_serial_port(&port_path, &heartbeat).await {
+                            log_error!("Serial listener error on {}: {}", port_path, e);
+                        }
+                    }
+                    heartbeat.idle(tokio::time::sleep(poll_interval(PollTask::Ports))).await;
+                }
+            }
+        });
+    }
+
     /// Listen for inbound messages on a serial port (USB or BLE Friend)
-    async fn listen_serial_port(&self, port_path: String) -> Result<()> {
+    async fn listen_serial_port(&self, port_path: &str, heartbeat: &Heartbeat) -> Result<()> {
         log_info!("Starting serial listener on {}", port_path);
 
         // Open serial port
-        let port = tokio_serial::new(&port_path, 115200)
+        let port = tokio_serial::new(port_path, 115200)
             .open_native_async()?;
 
         let reader = BufReader::new(port);
@@ -290,7 +420,8 @@ impl TransportManager {
 
         log_info!("Serial port {} opened successfully, listening for SSP messages...", port_path);
 
-        while let Some(line) = lines.next_line().await? {
+        // A quiet port isn't a wedged one, so waiting for a line keeps the heartbeat going
+        while let Some(line) = heartbeat.idle(lines.next_line()).await? {
             // Skip empty lines and AT command responses
             let trimmed = line.trim();
 
@@ -316,8 +447,17 @@ impl TransportManager {
             // Only log raw if it's likely real data
             log_info!("Received raw line ({} bytes): {}", trimmed.len(), trimmed);
 
+            // Drop frames that fail their `*HH` checksum, or lack one when it's required
+            let frame = match self.checksum_policy.verify(trimmed) {
+                Ok(frame) => frame,
+                Err(e) => {
+                    log_warn!("Rejected SSP line: {} (line: {})", e, trimmed);
+                    continue;
+                }
+            };
+
             // Try to parse as SSP message
-            match SspMessage::parse_flexible(trimmed) {
+            match SspMessage::parse_flexible(frame) {
                 Ok(ssp_msg) => {
 
                     log_info!("PARSED SSP from {}: topic={}, type={:?}, payload={}",
@@ -343,6 +483,7 @@ impl TransportManager {
                         log_error!("Failed to publish inbound message: {}", e);
                     } else {
                         log_info!("✓ Published to message bus: topic={}", ssp_msg.topic);
+                        heartbeat.beat();
                     }
                 }
                 Err(e) => {
@@ -362,10 +503,56 @@ impl std::fmt::Debug for TransportManager {
             .field("routing_table", &"<RwLock>")
             .field("message_bus", &"<MessageBus>")
             .field("outbound_topics", &"<RwLock>")
+            .field("retry_policy", &self.retry_policy)
+            .field("checksum_policy", &self.checksum_policy)
             .finish()
     }
 }
 
+/// Extract target device ID from message payload
+fn target_device(payload: &str) -> String {
+    // Try to parse as JSON and look for device_id or target fields
+    if let Ok(json) = serde_json::from_str::<serde_json::Value>(payload) {
+        if let Some(device_id) = json.get("device_id").and_then(|v| v.as_str()) {
+            return device_id.to_string();
+        }
+        if let Some(target) = json.get("target").and_then(|v| v.as_str()) {
+            return target.to_string();
+        }
+        // SSP compact commands carry the device id as "i"
+        if let Some(device_id) = json.get("i").and_then(|v| v.as_str()) {
+            return device_id.to_string();
+        }
+    }
+
+    // Default: use payload topic or generic broadcast
+    "broadcast".to_string()
+}
+
+/// Whether an outbound message drives an actuator: anything on `device_command` or `control`, or an SSP
+/// command (`"t": "cmd"`) or control message (`"type": "control"`) on any other topic
+fn is_actuation(bus_msg: &BusMessage) -> bool {
+    if bus_msg.topic == DEVICE_COMMAND_TOPIC || bus_msg.topic == "control" {
+        return true;
+    }
+    serde_json::from_str::<serde_json::Value>(&bus_msg.payload)
+        .map(|json| json["t"] == "cmd" || json["type"] == "control")
+        .unwrap_or(false)
+}
+
+/// The action an outbound message asks for, for the audit log: `action` at the top level or in the
+/// SSP `d`/`payload` object, otherwise the topic it was published on
+fn outbound_action(bus_msg: &BusMessage) -> String {
+    serde_json::from_str::<serde_json::Value>(&bus_msg.payload)
+        .ok()
+        .and_then(|json| {
+            [&json, &json["d"], &json["payload"]]
+                .iter()
+                .find_map(|object| object.get("action").and_then(|a| a.as_str()).map(str::to_string))
+        })
+        .unwrap_or_else(|| bus_msg.topic.clone())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -406,4 +593,25 @@ mod tests {
         );
         assert_eq!(ssp_back.topic, "pressure_sensor");
     }
+
+    #[tokio::test]
+    async fn test_one_send_queue_per_device() {
+        let (bus, _receiver) = MessageBus::new();
+        let manager = TransportManager::new(bus);
+
+        let valve = manager.device_queue("v01");
+        assert!(valve.same_channel(&manager.device_queue("v01")));
+        assert!(!valve.same_channel(&manager.device_queue("v02")));
+        assert_eq!(target_device(r#"{"i":"v01","d":{"action":"open"}}"#), "v01");
+    }
+
+    #[test]
+    fn test_only_actuator_commands_are_gated() {
+        let message = |topic: &str, payload: &str| BusMessage::new(topic.to_string(), payload.to_string(), "test".to_string());
+
+        assert!(is_actuation(&message(DEVICE_COMMAND_TOPIC, r#"{"i":"v01","d":{"action":"open"}}"#)));
+        assert!(is_actuation(&message("a01", r#"{"t":"cmd","i":"a01"}"#)));
+        assert!(!is_actuation(&message("device_registered", r#"{"device_id":"a01"}"#)));
+        assert!(!is_actuation(&message("a01", r#"{"t":"ping","i":"a01"}"#)));
+    }
 }
@@ -1,13 +1,13 @@
 use color_eyre::Result;
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
+use sha2::{Digest, Sha256};
 use std::path::{Path, PathBuf};
 use std::fs;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use std::collections::HashMap;
 use crate::util::database::{Database};
-use crate::module::strategies::llm::database::{KnowledgeChunk, LlmDatabase};
+use crate::util::dirs::data_dirs;
+use crate::module::strategies::llm::database::{KnowledgeChunk, KnowledgeSource, LlmDatabase};
 use crate::{log_error, log_debug, log_info, log_warn};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +17,17 @@ pub struct ModuleConfig {
     pub domain: Option<String>,
 }
 
+/// What an ingestion pass did
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IngestReport {
+    /// New or changed files that were (re)chunked
+    pub indexed_files: usize,
+    pub unchanged_files: usize,
+    /// Files that disappeared and had their chunks removed
+    pub removed_files: usize,
+    pub chunks: usize,
+}
+
 pub struct KnowledgeIngester<'a> {
     database: &'a Database,
     modules_dir: PathBuf,
@@ -26,7 +37,7 @@ impl<'a> KnowledgeIngester<'a> {
     pub fn new(database: &'a Database) -> Self {
         Self {
             database,
-            modules_dir: PathBuf::from("./manifests/wasteland"),
+            modules_dir: data_dirs().modules,
         }
     }
 
@@ -37,20 +48,30 @@ impl<'a> KnowledgeIngester<'a> {
         match self.database.get_module_state("knowledge_checksum") {
             Ok(Some(stored_checksum)) => {
                 log_debug!("stored_checksum is {}", stored_checksum);
-                let stored: u64 = stored_checksum.parse().unwrap_or(0);
-                Ok(current_checksum != stored)
+                Ok(current_checksum != stored_checksum)
             }
             _ => Ok(true) // No checksum stored, need to ingest
         }
     }
 
-    pub fn ingest_all_knowledge(&self) -> Result<()> {
+    /// Drop the whole index and ingest every file again
+    pub fn ingest_all_knowledge(&self) -> Result<IngestReport> {
+        self.database.clear_knowledge()?;
+        self.ingest_changed_knowledge()
+    }
+
+    /// Re-chunk only files whose size, modified time and content changed since they were
+    /// last indexed, and drop chunks of files that no longer exist
+    pub fn ingest_changed_knowledge(&self) -> Result<IngestReport> {
         log_info!("Starting knowledge ingestion...");
 
-        // Clear existing knowledge
-        self.database.clear_knowledge()?;
+        let mut known = self.database.get_knowledge_sources()?;
+        if known.is_empty() {
+            // Chunks indexed before files were tracked can't be matched to a file; start clean
+            self.database.clear_knowledge()?;
+        }
 
-        let mut total_chunks = 0;
+        let mut report = IngestReport::default();
 
         // Find all knowledge modules
         for module_dir in fs::read_dir(&self.modules_dir)? {
@@ -76,50 +97,109 @@ impl<'a> KnowledgeIngester<'a> {
 
             let knowledge_dir = module_dir.path().join("knowledge");
             if knowledge_dir.exists() {
-                let chunks = self.process_knowledge_directory(&knowledge_dir, &config)?;
-                total_chunks += chunks;
+                self.process_directory_recursive(&knowledge_dir, &config, &mut known, &mut report)?;
             }
         }
 
+        // Anything not visited was indexed before but is gone now
+        for source_file in known.keys() {
+            log_info!("  Removing deleted file from index: {}", source_file);
+            self.database.clear_knowledge_for_source(source_file)?;
+            report.removed_files += 1;
+        }
+
         // Store new checksum
         let checksum = self.calculate_modules_checksum()?;
-        self.database.save_module_state("knowledge_checksum", &checksum.to_string())?;
+        self.database.save_module_state("knowledge_checksum", &checksum)?;
 
-        log_info!("Knowledge ingestion complete. Processed {} chunks.", total_chunks);
+        log_info!(
+            "Knowledge ingestion complete. Indexed {} files ({} chunks), {} unchanged, {} removed.",
+            report.indexed_files, report.chunks, report.unchanged_files, report.removed_files
+        );
 
-        Ok(())
-    }
-
-    fn process_knowledge_directory(&self, knowledge_dir: &Path, config: &ModuleConfig) -> Result<usize> {
-        let mut chunk_count = 0;
-
-        // Recursively process all files in knowledge directory and subdirectories
-        self.process_directory_recursive(knowledge_dir, config, &mut chunk_count)?;
-
-        Ok(chunk_count)
+        Ok(report)
     }
 
-    fn process_directory_recursive(&self, dir: &Path, config: &ModuleConfig, chunk_count: &mut usize) -> Result<()> {
+    /// Index changed files under `dir`, taking each visited file out of `known`
+    fn process_directory_recursive(
+        &self,
+        dir: &Path,
+        config: &ModuleConfig,
+        known: &mut HashMap<String, KnowledgeSource>,
+        report: &mut IngestReport,
+    ) -> Result<()> {
         for entry in fs::read_dir(dir)? {
             let entry = entry?;
             let path = entry.path();
 
             if path.is_dir() {
                 // Recursively process subdirectories
-                self.process_directory_recursive(&path, config, chunk_count)?;
+                self.process_directory_recursive(&path, config, known, report)?;
             } else if path.is_file() {
+                let source_file = path.to_string_lossy().to_string();
+                let previous = known.remove(&source_file);
+                let (source, changed) = Self::fingerprint(&path, config, previous.as_ref())?;
+
+                if !changed {
+                    report.unchanged_files += 1;
+                    if previous.as_ref() != Some(&source) {
+                        // Touched but identical; remember the new mtime to skip hashing next time
+                        self.database.upsert_knowledge_source(&source)?;
+                    }
+                    continue;
+                }
+
+                self.database.clear_knowledge_for_source(&source_file)?;
+
                 let chunks = self.process_file(&path, config)?;
-                *chunk_count += chunks.len();
+                report.chunks += chunks.len();
+                report.indexed_files += 1;
 
                 // Insert chunks into database
                 for chunk in chunks {
                     self.database.insert_knowledge_chunk(chunk)?;
                 }
+                self.database.upsert_knowledge_source(&source)?;
             }
         }
         Ok(())
     }
 
+    /// Current fingerprint of `path` and whether it differs from `previous`.
+    /// The content is only hashed when size or modified time moved.
+    fn fingerprint(path: &Path, config: &ModuleConfig, previous: Option<&KnowledgeSource>) -> Result<(KnowledgeSource, bool)> {
+        let metadata = fs::metadata(path)?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|m| m.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        let size = metadata.len();
+
+        if let Some(previous) = previous {
+            if previous.modified == modified && previous.size == size && previous.category == config.name {
+                return Ok((previous.clone(), false));
+            }
+        }
+
+        // Kept in knowledge_sources, so it has to come out the same on every toolchain
+        let content_hash: String = Sha256::digest(fs::read(path)?).iter().map(|b| format!("{:02x}", b)).collect();
+
+        let changed = previous.map_or(true, |p| p.content_hash != content_hash || p.category != config.name);
+
+        Ok((
+            KnowledgeSource {
+                source_file: path.to_string_lossy().to_string(),
+                category: config.name.clone(),
+                modified,
+                size,
+                content_hash,
+            },
+            changed,
+        ))
+    }
+
     fn process_file(&self, file_path: &Path, config: &ModuleConfig) -> Result<Vec<KnowledgeChunk>> {
         let extension = file_path.extension()
             .and_then(|ext| ext.to_str())
@@ -191,6 +271,8 @@ impl<'a> KnowledgeIngester<'a> {
                 body: paragraph.trim().to_string(),
                 chunk_index: index as i32,
                 metadata: metadata.clone(), // Use the provided metadata with image mappings
+                snippet: None,
+                score: None,
             };
             chunks.push(chunk);
         }
@@ -241,6 +323,8 @@ impl<'a> KnowledgeIngester<'a> {
                         "full_path": file_path.to_string_lossy(),
                         "paragraph_index": para_index,
                     }).to_string(),
+                        snippet: None,
+                        score: None,
                     };
 
                     all_chunks.push(chunk);
@@ -397,6 +481,8 @@ impl<'a> KnowledgeIngester<'a> {
                     "full_path": file_path.to_string_lossy(),
                     "all_domains": inferred_domains  // Store all inferred domains
                 }).to_string(),
+                snippet: None,
+                score: None,
             };
             chunks.push(chunk);
         }
@@ -413,15 +499,18 @@ impl<'a> KnowledgeIngester<'a> {
         }
     }
 
-    fn calculate_modules_checksum(&self) -> Result<u64> {
-        let mut hasher = DefaultHasher::new();
+    /// SHA-256 over every knowledge file's path and modified time, in a fixed order. Stored
+    /// between runs, so it mustn't depend on the toolchain the way DefaultHasher does.
+    fn calculate_modules_checksum(&self) -> Result<String> {
+        let mut hasher = Sha256::new();
 
         if !self.modules_dir.exists() {
-            return Ok(0);
+            return Ok(String::new());
         }
 
-        for module_dir in fs::read_dir(&self.modules_dir)? {
-            let module_dir = module_dir?;
+        let mut module_dirs = fs::read_dir(&self.modules_dir)?.collect::<std::io::Result<Vec<_>>>()?;
+        module_dirs.sort_by_key(|entry| entry.file_name());
+        for module_dir in module_dirs {
             if !module_dir.file_type()?.is_dir() {
                 continue;
             }
@@ -432,12 +521,13 @@ impl<'a> KnowledgeIngester<'a> {
             }
         }
 
-        Ok(hasher.finish())
+        Ok(hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect())
     }
 
-    fn hash_directory_recursive(&self, dir: &Path, hasher: &mut DefaultHasher) -> Result<()> {
-        for entry in fs::read_dir(dir)? {
-            let entry = entry?;
+    fn hash_directory_recursive(&self, dir: &Path, hasher: &mut Sha256) -> Result<()> {
+        let mut entries = fs::read_dir(dir)?.collect::<std::io::Result<Vec<_>>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
             let path = entry.path();
 
             if path.is_dir() {
@@ -445,11 +535,11 @@ impl<'a> KnowledgeIngester<'a> {
                 self.hash_directory_recursive(&path, hasher)?;
             } else if path.is_file() {
                 // Hash file path and modified time
-                path.hash(hasher);
+                hasher.update(path.to_string_lossy().as_bytes());
                 if let Ok(metadata) = fs::metadata(&path) {
                     if let Ok(modified) = metadata.modified() {
                         if let Ok(duration) = modified.duration_since(std::time::UNIX_EPOCH) {
-                            duration.as_secs().hash(hasher);
+                            hasher.update(duration.as_secs().to_le_bytes());
                         }
                     }
                 }
@@ -458,3 +548,39 @@ impl<'a> KnowledgeIngester<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reingest_only_touches_changed_files() {
+        let modules_dir = std::env::temp_dir().join(format!("survon_ingest_{}", Uuid::new_v4()));
+        let knowledge_dir = modules_dir.join("manuals").join("knowledge");
+        fs::create_dir_all(&knowledge_dir).unwrap();
+        fs::write(
+            modules_dir.join("manuals").join("config.yml"),
+            "name: manuals\nmodule_type: knowledge\n",
+        ).unwrap();
+        fs::write(knowledge_dir.join("water.txt"), "Boil water before drinking it.").unwrap();
+        fs::write(knowledge_dir.join("fire.txt"), "Keep the fire small at night.").unwrap();
+
+        let db = Database::in_memory().unwrap();
+        let ingester = KnowledgeIngester { database: &db, modules_dir: modules_dir.clone() };
+
+        let first = ingester.ingest_changed_knowledge().unwrap();
+        assert_eq!((first.indexed_files, first.unchanged_files), (2, 0));
+
+        let second = ingester.ingest_changed_knowledge().unwrap();
+        assert_eq!((second.indexed_files, second.unchanged_files), (0, 2));
+        assert!(!ingester.should_reingest().unwrap());
+
+        fs::remove_file(knowledge_dir.join("fire.txt")).unwrap();
+        let third = ingester.ingest_changed_knowledge().unwrap();
+        assert_eq!((third.unchanged_files, third.removed_files), (1, 1));
+        assert!(db.search_knowledge("fire", &[], 5).unwrap().is_empty());
+        assert_eq!(db.search_knowledge("water", &[], 5).unwrap().len(), 1);
+
+        let _ = fs::remove_dir_all(&modules_dir);
+    }
+}
@@ -3,8 +3,8 @@
 //! Logger Utility - Provides file-based logging for TUI applications
 use std::fs::{File, create_dir_all};
 use std::io::Write;
-use std::path::PathBuf;
-use std::sync::Mutex;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, RwLock};
 use std::sync::{LazyLock,OnceLock};
 use chrono::Local;
 
@@ -14,11 +14,61 @@ pub static DEBUG_ENABLED: OnceLock<bool> = OnceLock::new();
 
 
 pub static LOGGER: LazyLock<Logger> = LazyLock::new(|| {
-    Logger::new("./logs").expect("Failed to initialize logger")
+    Logger::new(crate::util::dirs::data_dirs().logs).expect("Failed to initialize logger")
 });
 
-/// Log severity levels
-#[derive(Debug, Clone, Copy)]
+/// Secret values interpolated into module configs, replaced with `MASK` in every log line
+static SECRETS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+const MASK: &str = "****";
+
+/// Values shorter than this aren't masked, or every log line would be riddled with `****`
+const MIN_SECRET_LEN: usize = 4;
+
+/// Register a secret value so it never reaches the log files
+pub fn register_secret(value: &str) {
+    if value.len() < MIN_SECRET_LEN {
+        return;
+    }
+    if let Ok(mut secrets) = SECRETS.write() {
+        if !secrets.iter().any(|s| s == value) {
+            secrets.push(value.to_string());
+        }
+    }
+}
+
+/// Replace every registered secret in `message` with `****`
+pub fn mask_secrets(message: &str) -> String {
+    let Ok(secrets) = SECRETS.read() else {
+        return message.to_string();
+    };
+    secrets.iter().fold(message.to_string(), |masked, secret| masked.replace(secret.as_str(), MASK))
+}
+
+/// Level thresholds, seeded from `DEBUG` and `LOG_LEVELS` on first use
+static FILTER: LazyLock<RwLock<LogFilter>> = LazyLock::new(|| {
+    let default = if std::env::var("DEBUG").unwrap_or_default() == "true" {
+        LogLevel::Debug
+    } else {
+        LogLevel::Info
+    };
+    RwLock::new(LogFilter::parse(&std::env::var("LOG_LEVELS").unwrap_or_default(), default))
+});
+
+/// Whether a `level` message for `target` (a handler key such as `monitoring_a01`) gets written
+pub fn enabled(level: LogLevel, target: Option<&str>) -> bool {
+    FILTER.read().map(|filter| level <= filter.level_for(target)).unwrap_or(true)
+}
+
+/// Override the level for `target` and everything under it (`monitoring` covers `monitoring_a01`)
+pub fn set_target_level(target: &str, level: LogLevel) {
+    if let Ok(mut filter) = FILTER.write() {
+        filter.set(target, level);
+    }
+}
+
+/// Log severity levels, least verbose first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LogLevel {
     Error,
     Warn,
@@ -36,7 +86,17 @@ impl LogLevel {
         }
     }
 
-    fn filename(&self) -> &str {
+    pub fn parse(level: &str) -> Option<Self> {
+        match level.trim().to_lowercase().as_str() {
+            "error" => Some(LogLevel::Error),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "info" => Some(LogLevel::Info),
+            "debug" => Some(LogLevel::Debug),
+            _ => None,
+        }
+    }
+
+    pub fn filename(&self) -> &str {
         match self {
             LogLevel::Error => "error.log",
             LogLevel::Warn => "warn.log",
@@ -46,6 +106,58 @@ impl LogLevel {
     }
 }
 
+/// A default level plus per-target overrides, e.g. `info,monitoring_a01=debug,llm=warn`
+#[derive(Debug, Clone)]
+pub struct LogFilter {
+    default: LogLevel,
+    targets: Vec<(String, LogLevel)>,
+}
+
+impl LogFilter {
+    /// A bare level replaces `default`; `target=level` entries override it per target.
+    /// Unrecognized entries are ignored.
+    pub fn parse(spec: &str, default: LogLevel) -> Self {
+        let mut filter = Self { default, targets: Vec::new() };
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    if let Some(level) = LogLevel::parse(level) {
+                        filter.set(target.trim(), level);
+                    }
+                }
+                None => {
+                    if let Some(level) = LogLevel::parse(directive) {
+                        filter.default = level;
+                    }
+                }
+            }
+        }
+        filter
+    }
+
+    pub fn set(&mut self, target: &str, level: LogLevel) {
+        match self.targets.iter_mut().find(|(t, _)| t == target) {
+            Some(entry) => entry.1 = level,
+            None => self.targets.push((target.to_string(), level)),
+        }
+    }
+
+    /// The most specific matching override, else the default
+    pub fn level_for(&self, target: Option<&str>) -> LogLevel {
+        let Some(target) = target else {
+            return self.default;
+        };
+        self.targets
+            .iter()
+            .filter(|(t, _)| {
+                target == t || (target.starts_with(t.as_str()) && target[t.len()..].starts_with('_'))
+            })
+            .max_by_key(|(t, _)| t.len())
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default)
+    }
+}
+
 /// Logger that writes to separate files by severity
 pub struct Logger {
     log_dir: PathBuf,
@@ -57,12 +169,12 @@ pub struct Logger {
 
 impl Logger {
     /// Create a new logger with the specified directory
-    pub fn new(log_dir: &str) -> std::io::Result<Self> {
+    pub fn new(log_dir: impl AsRef<Path>) -> std::io::Result<Self> {
         DEBUG_ENABLED.get_or_init(|| {
             std::env::var("DEBUG").unwrap_or_default() == "true"
         });
 
-        let log_dir = PathBuf::from(log_dir);
+        let log_dir = log_dir.as_ref().to_path_buf();
 
         // Create logs directory if it doesn't exist
         create_dir_all(&log_dir)?;
@@ -83,9 +195,12 @@ impl Logger {
     }
 
     /// Write a log entry to the appropriate file
-    fn write_log(&self, level: LogLevel, message: &str) {
+    fn write_log(&self, level: LogLevel, target: Option<&str>, message: &str) {
         let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S%.3f");
-        let formatted = format!("[{}] [{}] {}\n", timestamp, level.as_str(), message);
+        let formatted = match target {
+            Some(target) => format!("[{}] [{}] [{}] {}\n", timestamp, level.as_str(), target, mask_secrets(message)),
+            None => format!("[{}] [{}] {}\n", timestamp, level.as_str(), mask_secrets(message)),
+        };
 
         let file = match level {
             LogLevel::Error => &self.error_file,
@@ -102,57 +217,105 @@ impl Logger {
 
     /// Log an error message
     pub fn error(&self, message: &str) {
-        self.write_log(LogLevel::Error, message);
+        self.write_log(LogLevel::Error, None, message);
     }
 
     /// Log a warning message
     pub fn warn(&self, message: &str) {
-        self.write_log(LogLevel::Warn, message);
+        self.write_log(LogLevel::Warn, None, message);
     }
 
     /// Log an info message
     pub fn info(&self, message: &str) {
-        self.write_log(LogLevel::Info, message);
+        self.write_log(LogLevel::Info, None, message);
     }
 
     /// Log a debug message
     pub fn debug(&self, message: &str) {
-        self.write_log(LogLevel::Debug, message);
+        self.write_log(LogLevel::Debug, None, message);
+    }
+
+    /// Log a message attributed to `target`, shown as `[target]` after the level
+    pub fn log(&self, level: LogLevel, target: Option<&str>, message: &str) {
+        self.write_log(level, target, message);
+    }
+
+    /// Directory the per-level files (see [`LogLevel::filename`]) are written to
+    pub fn log_dir(&self) -> &Path {
+        &self.log_dir
     }
 }
 
-/// Convenience macro for error logging with formatting
+/// Convenience macro for error logging with formatting.
+/// `log_error!(target: key, ...)` attributes the line to a handler key for per-target levels.
 #[macro_export]
 macro_rules! log_error {
+    (target: $target:expr, $($arg:tt)*) => {{
+        let target: &str = $target;
+        if $crate::util::log::enabled($crate::util::log::LogLevel::Error, Some(target)) {
+            let message = format!($($arg)*);
+            $crate::util::log::LOGGER.log($crate::util::log::LogLevel::Error, Some(target), &message);
+        }
+    }};
     ($($arg:tt)*) => {{
-        let message = format!($($arg)*);
-        $crate::util::log::LOGGER.error(&message);
+        if $crate::util::log::enabled($crate::util::log::LogLevel::Error, None) {
+            let message = format!($($arg)*);
+            $crate::util::log::LOGGER.error(&message);
+        }
     }};
 }
 
-/// Convenience macro for warning logging with formatting
+/// Convenience macro for warning logging with formatting.
+/// `log_warn!(target: key, ...)` attributes the line to a handler key for per-target levels.
 #[macro_export]
 macro_rules! log_warn {
+    (target: $target:expr, $($arg:tt)*) => {{
+        let target: &str = $target;
+        if $crate::util::log::enabled($crate::util::log::LogLevel::Warn, Some(target)) {
+            let message = format!($($arg)*);
+            $crate::util::log::LOGGER.log($crate::util::log::LogLevel::Warn, Some(target), &message);
+        }
+    }};
     ($($arg:tt)*) => {{
-        let message = format!($($arg)*);
-        $crate::util::log::LOGGER.warn(&message);
+        if $crate::util::log::enabled($crate::util::log::LogLevel::Warn, None) {
+            let message = format!($($arg)*);
+            $crate::util::log::LOGGER.warn(&message);
+        }
     }};
 }
 
-/// Convenience macro for info logging with formatting
+/// Convenience macro for info logging with formatting.
+/// `log_info!(target: key, ...)` attributes the line to a handler key for per-target levels.
 #[macro_export]
 macro_rules! log_info {
+    (target: $target:expr, $($arg:tt)*) => {{
+        let target: &str = $target;
+        if $crate::util::log::enabled($crate::util::log::LogLevel::Info, Some(target)) {
+            let message = format!($($arg)*);
+            $crate::util::log::LOGGER.log($crate::util::log::LogLevel::Info, Some(target), &message);
+        }
+    }};
     ($($arg:tt)*) => {{
-        let message = format!($($arg)*);
-        $crate::util::log::LOGGER.info(&message);
+        if $crate::util::log::enabled($crate::util::log::LogLevel::Info, None) {
+            let message = format!($($arg)*);
+            $crate::util::log::LOGGER.info(&message);
+        }
     }};
 }
 
-/// Convenience macro for debug logging with formatting
+/// Convenience macro for debug logging with formatting.
+/// `log_debug!(target: key, ...)` attributes the line to a handler key for per-target levels.
 #[macro_export]
 macro_rules! log_debug {
+    (target: $target:expr, $($arg:tt)*) => {{
+        let target: &str = $target;
+        if $crate::util::log::enabled($crate::util::log::LogLevel::Debug, Some(target)) {
+            let message = format!($($arg)*);
+            $crate::util::log::LOGGER.log($crate::util::log::LogLevel::Debug, Some(target), &message);
+        }
+    }};
     ($($arg:tt)*) => {{
-        if *$crate::util::log::DEBUG_ENABLED.get().unwrap_or(&false) {
+        if $crate::util::log::enabled($crate::util::log::LogLevel::Debug, None) {
             let message = format!($($arg)*);
             $crate::util::log::LOGGER.debug(&message);
         }
@@ -183,4 +346,23 @@ mod tests {
         // Cleanup
         let _ = fs::remove_dir_all(temp_dir);
     }
+
+    #[test]
+    fn test_filter_prefers_most_specific_target() {
+        let filter = LogFilter::parse("warn, monitoring=info, monitoring_a01=debug, bogus", LogLevel::Info);
+
+        assert_eq!(filter.level_for(None), LogLevel::Warn);
+        assert_eq!(filter.level_for(Some("monitoring_a01")), LogLevel::Debug);
+        assert_eq!(filter.level_for(Some("monitoring_b02")), LogLevel::Info);
+        assert_eq!(filter.level_for(Some("monitoringx")), LogLevel::Warn);
+        assert_eq!(filter.level_for(Some("llm")), LogLevel::Warn);
+    }
+
+    #[test]
+    fn test_registered_secrets_are_masked() {
+        register_secret("sk-test-4f9a2c");
+        register_secret("abc");
+
+        assert_eq!(mask_secrets("key=sk-test-4f9a2c, id=abc"), "key=****, id=abc");
+    }
 }
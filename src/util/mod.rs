@@ -7,3 +7,12 @@ pub mod log;
 pub mod service;
 pub mod image;
 pub mod string;
+pub mod format;
+pub mod clock;
+pub mod fs;
+pub mod disk;
+pub mod dirs;
+pub mod version;
+
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
@@ -0,0 +1,201 @@
+// src/util/test_util.rs
+
+//! Test harness - in-memory stand-ins for the runtime services handlers depend on.
+//!
+//! Compiled for unit tests and behind the `test-util` feature so module authors
+//! can reuse it without pulling it into release builds.
+
+pub mod snapshot;
+
+pub use snapshot::assert_template_snapshot;
+
+use color_eyre::Result;
+use ratatui::crossterm::event::KeyCode;
+use std::{
+    any::Any,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use crate::{
+    module::{Module, ModuleConfig, ModuleConfigBuilder, ModuleHandler, ModuleRenderState},
+    util::{
+        database::Database,
+        io::{
+            bus::{BusMessage, BusReceiver, MessageBus},
+            discovery::DiscoveryManager,
+            event::AppEvent,
+        },
+    },
+};
+
+/// Database backed entirely by `:memory:` connections with every schema initialized
+pub fn in_memory_database() -> Database {
+    Database::in_memory().expect("Failed to open in-memory database")
+}
+
+/// Message bus plus the main receiver, so tests can observe everything published
+pub fn loopback_bus() -> (MessageBus, BusReceiver) {
+    MessageBus::new()
+}
+
+/// Drain every message currently waiting on a receiver without blocking
+pub fn drain(receiver: &mut BusReceiver) -> Vec<BusMessage> {
+    let mut messages = Vec::new();
+    while let Ok(message) = receiver.try_recv() {
+        messages.push(message);
+    }
+    messages
+}
+
+/// Discovery manager that never touches a BLE adapter (`start()` is never called)
+pub fn stub_discovery_manager(message_bus: &MessageBus, database: &Database) -> Arc<DiscoveryManager> {
+    Arc::new(DiscoveryManager::new(
+        message_bus.clone(),
+        PathBuf::from("./manifests/wasteland/"),
+        database.clone(),
+    ))
+}
+
+/// Builder for a [`Module`] that never reads a manifest from disk
+#[derive(Debug, Clone)]
+pub struct TestModule {
+    config: ModuleConfigBuilder,
+    path: PathBuf,
+}
+
+impl TestModule {
+    pub fn new(name: &str, module_type: &str, template: &str) -> Self {
+        Self {
+            config: ModuleConfig::builder()
+                .name(name)
+                .module_type(module_type)
+                .bus_topic(name)
+                .template(template),
+            path: PathBuf::from(format!("./manifests/test/{}", name)),
+        }
+    }
+
+    pub fn bus_topic(mut self, bus_topic: &str) -> Self {
+        self.config = self.config.bus_topic(bus_topic);
+        self
+    }
+
+    pub fn binding(mut self, key: &str, value: serde_json::Value) -> Self {
+        self.config = self.config.binding(key, value);
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: i32) -> Self {
+        self.config = self.config.sort_order(sort_order);
+        self
+    }
+
+    pub fn pinned(mut self) -> Self {
+        self.config = self.config.pinned(true);
+        self
+    }
+
+    pub fn path(mut self, path: PathBuf) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// Panics on a config `ConfigValidator` would reject, such as an unknown template
+    pub fn build(self) -> Module {
+        Module {
+            config: self.config.build().expect("invalid test module config"),
+            path: self.path,
+            cached_template: None,
+            render_state: ModuleRenderState::default(),
+        }
+    }
+}
+
+/// Handler that records everything it is asked to do
+#[derive(Debug, Default)]
+pub struct MockHandler {
+    pub module_type: String,
+    pub keys: Vec<KeyCode>,
+    pub events: Vec<AppEvent>,
+    pub binding_updates: usize,
+    pub reloads: usize,
+    /// Returned from every `handle_key` call
+    pub key_response: Option<AppEvent>,
+}
+
+impl MockHandler {
+    pub fn new(module_type: &str) -> Self {
+        Self {
+            module_type: module_type.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl ModuleHandler for MockHandler {
+    fn handle_key(&mut self, key_code: KeyCode, _module: &mut Module) -> Option<AppEvent> {
+        self.keys.push(key_code);
+        self.key_response.clone()
+    }
+
+    fn handle_event(&mut self, event: &AppEvent, _module: &mut Module) -> Result<bool> {
+        self.events.push(event.clone());
+        Ok(true)
+    }
+
+    fn update_bindings(&mut self, module: &mut Module) {
+        self.binding_updates += 1;
+        module.config.bindings.insert(
+            "mock_updates".to_string(),
+            serde_json::json!(self.binding_updates),
+        );
+    }
+
+    fn reload(&mut self, _module: &mut Module) {
+        self.reloads += 1;
+    }
+
+    fn module_type(&self) -> &str {
+        &self.module_type
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::strategies::side_quest::handler::SideQuestHandler;
+
+    #[tokio::test]
+    async fn test_side_quest_handler_with_harness() {
+        let database = in_memory_database();
+        let (bus, _receiver) = loopback_bus();
+        let mut module = TestModule::new("quests", "side_quest", "side_quest_card").build();
+
+        let mut handler = SideQuestHandler::new(database, bus);
+        assert!(handler.handle_key(KeyCode::Char('n'), &mut module).is_none());
+
+        handler.update_bindings(&mut module);
+        assert_eq!(module.config.bindings["current_view"], "CreateQuest");
+    }
+
+    #[test]
+    fn test_mock_handler_records_calls() {
+        let mut module = TestModule::new("mock", "system", "").build();
+        let mut handler = MockHandler::new("system");
+
+        handler.handle_key(KeyCode::Enter, &mut module);
+        handler.update_bindings(&mut module);
+
+        assert_eq!(handler.keys, vec![KeyCode::Enter]);
+        assert_eq!(module.config.bindings["mock_updates"], 1);
+    }
+}
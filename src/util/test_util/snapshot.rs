@@ -0,0 +1,212 @@
+// src/util/test_util/snapshot.rs
+
+//! Template snapshot tests: render a template off-screen and compare the cell
+//! grid (text plus colors) against a stored text file.
+//!
+//! Snapshots live in `<crate>/snapshots/templates/<name>.snap`. Run the tests with
+//! `UPDATE_SNAPSHOTS=1` to record new snapshots or accept changed output. Locally a missing
+//! snapshot is recorded on first run; under `CI` it fails, so an uncommitted one can't pass.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ratatui::buffer::Buffer;
+use ratatui::style::Modifier;
+
+use crate::ui::template::render_template_preview_sized;
+
+const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+const CI_ENV: &str = "CI";
+const STYLE_HEADER: &str = "--- styles ---";
+
+/// Render `template` with `bindings` at `width`x`height` and assert it matches
+/// the stored snapshot `name`. Panics with a line diff on mismatch.
+pub fn assert_template_snapshot(
+    name: &str,
+    template: &str,
+    bindings: HashMap<String, serde_json::Value>,
+    width: u16,
+    height: u16,
+) {
+    let buf = render_template_preview_sized(template, bindings, width, height)
+        .unwrap_or_else(|e| panic!("Failed to render {}: {}", template, e));
+
+    if let Err(diff) = check_snapshot(&buf, &snapshot_path(name), SnapshotMode::from_env()) {
+        panic!("{}", diff);
+    }
+}
+
+/// What [`check_snapshot`] may write
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SnapshotMode {
+    /// Compare only; a missing snapshot is a failure
+    Verify,
+    /// Record snapshots that don't exist yet, compare the rest
+    RecordMissing,
+    /// Overwrite every snapshot with the current output
+    Update,
+}
+
+impl SnapshotMode {
+    /// `Update` with `UPDATE_SNAPSHOTS=1`, `Verify` under `CI`, otherwise `RecordMissing`
+    pub fn from_env() -> Self {
+        let set = |name: &str| std::env::var(name).is_ok_and(|v| !v.is_empty() && v != "0" && v != "false");
+        if set(UPDATE_ENV) {
+            Self::Update
+        } else if set(CI_ENV) {
+            Self::Verify
+        } else {
+            Self::RecordMissing
+        }
+    }
+}
+
+/// `<CARGO_MANIFEST_DIR>/snapshots/templates/<name>.snap` of the crate under test
+pub fn snapshot_path(name: &str) -> PathBuf {
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(root)
+        .join("snapshots")
+        .join("templates")
+        .join(format!("{}.snap", name))
+}
+
+/// Compare `buf` against the file at `path`, writing it instead when `mode` allows.
+/// Err carries a readable diff, or says the snapshot is missing.
+pub fn check_snapshot(buf: &Buffer, path: &Path, mode: SnapshotMode) -> Result<(), String> {
+    let actual = buffer_to_snapshot(buf);
+
+    if !path.exists() && mode == SnapshotMode::Verify {
+        return Err(format!(
+            "Snapshot {} is missing (run locally or with {}=1 to record it, then commit it)",
+            path.display(),
+            UPDATE_ENV,
+        ));
+    }
+
+    if mode == SnapshotMode::Update || !path.exists() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(path, &actual).map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+
+    let expected = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    if expected == actual {
+        return Ok(());
+    }
+
+    Err(format!(
+        "Snapshot {} does not match (rerun with {}=1 to accept):\n{}",
+        path.display(),
+        UPDATE_ENV,
+        line_diff(&expected, &actual),
+    ))
+}
+
+/// The text grid, one row per line, then one line per run of identical style
+pub fn buffer_to_snapshot(buf: &Buffer) -> String {
+    let area = buf.area;
+    let mut text = String::new();
+    let mut styles = String::new();
+
+    for y in area.top()..area.bottom() {
+        let mut row = String::new();
+        let mut run: Option<(u16, String)> = None;
+
+        for x in area.left()..=area.right() {
+            let style = (x < area.right()).then(|| {
+                let cell = &buf[(x, y)];
+                row.push_str(cell.symbol());
+                style_label(cell.fg, cell.bg, cell.modifier)
+            });
+
+            let continues = matches!((&run, &style), (Some((_, a)), Some(b)) if a == b);
+            if !continues {
+                if let Some((start, label)) = run.take() {
+                    if label != "default" {
+                        styles.push_str(&format!("{},{}..{} {}\n", y, start, x, label));
+                    }
+                }
+                run = style.map(|label| (x, label));
+            }
+        }
+
+        text.push_str(row.trim_end());
+        text.push('\n');
+    }
+
+    format!("{}{}\n{}", text, STYLE_HEADER, styles)
+}
+
+fn style_label(fg: ratatui::style::Color, bg: ratatui::style::Color, modifier: Modifier) -> String {
+    use ratatui::style::Color;
+
+    let mut parts = Vec::new();
+    if fg != Color::Reset {
+        parts.push(format!("fg={}", fg));
+    }
+    if bg != Color::Reset {
+        parts.push(format!("bg={}", bg));
+    }
+    if !modifier.is_empty() {
+        parts.push(format!("mod={:?}", modifier));
+    }
+
+    if parts.is_empty() {
+        "default".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// `-`/`+` for lines that differ, with their line numbers
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut diff = String::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        let (e, a) = (expected.get(i), actual.get(i));
+        if e == a {
+            continue;
+        }
+        if let Some(e) = e {
+            diff.push_str(&format!("{:>4} - {}\n", i + 1, e));
+        }
+        if let Some(a) = a {
+            diff.push_str(&format!("{:>4} + {}\n", i + 1, a));
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::{Color, Style};
+
+    #[test]
+    fn test_snapshot_records_matches_and_diffs() {
+        let path = std::env::temp_dir()
+            .join(format!("survon_snapshot_{}", std::process::id()))
+            .join("badge.snap");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        buf.set_string(0, 0, "OK", Style::default().fg(Color::Green));
+
+        assert!(check_snapshot(&buf, &path, SnapshotMode::Verify).unwrap_err().contains("is missing"));
+        assert!(check_snapshot(&buf, &path, SnapshotMode::RecordMissing).is_ok()); // recorded
+        assert!(check_snapshot(&buf, &path, SnapshotMode::Verify).is_ok()); // matches
+
+        buf.set_string(0, 0, "OK", Style::default().fg(Color::Red));
+        let diff = check_snapshot(&buf, &path, SnapshotMode::RecordMissing).unwrap_err();
+        assert!(diff.contains("- 0,0..2 fg=Green"));
+        assert!(diff.contains("+ 0,0..2 fg=Red"));
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
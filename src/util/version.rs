@@ -0,0 +1,43 @@
+// src/util/version.rs
+
+//! Which build is running: crate version plus the git commit and build time captured by
+//! `build.rs`. Printed by `--version` and shown in the Ctrl+V overlay, with the
+//! [features](crate::runtime::features) compiled in.
+
+use chrono::DateTime;
+
+pub use crate::runtime::features::enabled_features;
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Short commit hash, or `unknown` when built outside a git checkout
+pub const GIT_HASH: &str = env!("SURVON_GIT_HASH");
+/// Unix seconds; `SOURCE_DATE_EPOCH` when set at build time
+pub const BUILD_TIMESTAMP: &str = env!("SURVON_BUILD_TIMESTAMP");
+
+/// The build time as `YYYY-MM-DD HH:MM UTC`
+pub fn build_date() -> String {
+    BUILD_TIMESTAMP
+        .parse::<i64>()
+        .ok()
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        .map(|at| at.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// `survon 0.1.0 (3f2a9c1e0b, built 2026-10-16 09:12 UTC)`
+pub fn version_line() -> String {
+    format!("survon {} ({}, built {})", VERSION, GIT_HASH, build_date())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_line_names_the_build() {
+        let line = version_line();
+        assert!(line.starts_with(&format!("survon {} (", VERSION)));
+        assert!(line.contains(GIT_HASH));
+        assert_ne!(build_date(), "unknown");
+    }
+}